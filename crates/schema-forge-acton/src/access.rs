@@ -5,8 +5,8 @@ use acton_service::middleware::Claims;
 use schema_forge_backend::entity::Entity;
 use schema_forge_backend::tenant::TenantConfig;
 use schema_forge_backend::TenantRef;
-use schema_forge_core::query::{FieldPath, Filter, Query};
-use schema_forge_core::types::{DynamicValue, SchemaDefinition};
+use schema_forge_core::query::{AggregateQuery, FieldPath, Filter, Query};
+use schema_forge_core::types::{DynamicValue, SchemaDefinition, DELETED_AT_FIELD};
 
 use serde::Serialize;
 
@@ -120,6 +120,10 @@ pub use schema_forge_backend::PLATFORM_ADMIN_ROLE;
 /// is the single source of truth: this function is a thin shim that
 /// builds the request and translates the engine's decision into a
 /// `ForgeError`.
+///
+/// Claims minted from an API key are first checked against the key's
+/// scope allowlist (see [`crate::api_key`]); an uncovered schema/action is
+/// `Forbidden` before `@access` is consulted.
 pub fn check_schema_access(
     store: &Arc<PolicyStore>,
     schema: &SchemaDefinition,
@@ -132,6 +136,8 @@ pub fn check_schema_access(
         });
     }
 
+    crate::api_key::check_api_key_scope(claims, schema.name.as_str(), action)?;
+
     let primary = action.primary_verb();
     let primary_decision = authorize(store, claims, primary, schema, None).map_err(|e| {
         ForgeError::Internal {
@@ -284,30 +290,99 @@ pub fn inject_tenant_scope(
     claims: Option<&Claims>,
     tenant_config: &Option<TenantConfig>,
 ) {
+    scope_filter(&mut query.filter, claims, tenant_config);
+}
+
+/// [`inject_tenant_scope`] for aggregate queries.
+pub fn inject_tenant_scope_aggregate(
+    query: &mut AggregateQuery,
+    claims: Option<&Claims>,
+    tenant_config: &Option<TenantConfig>,
+) {
+    scope_filter(&mut query.filter, claims, tenant_config);
+}
+
+fn scope_filter(
+    filter: &mut Option<Filter>,
+    claims: Option<&Claims>,
+    tenant_config: &Option<TenantConfig>,
+) {
+    if let Some(tenant_id) = tenant_scope(claims, tenant_config) {
+        let tenant_filter = Filter::eq(FieldPath::single("_tenant"), DynamicValue::Text(tenant_id));
+        *filter = Some(match filter.take() {
+            Some(existing) => Filter::and(vec![existing, tenant_filter]),
+            None => tenant_filter,
+        });
+    }
+}
+
+/// Whether the caller may supply `@hidden` fields in a request body.
+///
+/// `@hidden` fields never appear in responses, but [`PLATFORM_ADMIN_ROLE`]
+/// may still write them so operators can set server-side values (scores,
+/// flags) through the API. Everyone else is rejected at the request
+/// boundary.
+pub fn can_write_hidden_fields(claims: Option<&Claims>) -> bool {
+    claims.is_some_and(|c| c.has_role(PLATFORM_ADMIN_ROLE))
+}
+
+/// The tenant entity ID the caller is scoped to, or `None` when no
+/// tenant scoping applies (see [`inject_tenant_scope`]).
+pub(crate) fn tenant_scope(
+    claims: Option<&Claims>,
+    tenant_config: &Option<TenantConfig>,
+) -> Option<String> {
     let _config = match tenant_config {
         Some(c) if c.is_enabled() => c,
-        _ => return,
-    };
-    let claims = match claims {
-        Some(c) => c,
-        None => return,
+        _ => return None,
     };
+    let claims = claims?;
     if claims.has_role(PLATFORM_ADMIN_ROLE) {
-        return;
+        return None;
     }
     let tenant_chain: Vec<TenantRef> = claims
         .custom_claim_as::<Vec<TenantRef>>("tenant_chain")
         .unwrap_or_default();
-    if let Some(tenant_ref) = tenant_chain.last() {
-        let tenant_filter = Filter::eq(
-            FieldPath::single("_tenant"),
-            DynamicValue::Text(tenant_ref.entity_id.clone()),
-        );
-        query.filter = Some(match query.filter.take() {
-            Some(existing) => Filter::and(vec![existing, tenant_filter]),
-            None => tenant_filter,
-        });
+    tenant_chain.last().map(|t| t.entity_id.clone())
+}
+
+/// Whether `entity` falls inside the caller's tenant scope.
+///
+/// The in-memory counterpart of [`inject_tenant_scope`], for entities that
+/// did not come from a scoped query (e.g. the change feed). Returns `true`
+/// in every case where [`inject_tenant_scope`] would add no filter.
+pub fn entity_in_tenant_scope(
+    entity: &Entity,
+    claims: Option<&Claims>,
+    tenant_config: &Option<TenantConfig>,
+) -> bool {
+    match tenant_scope(claims, tenant_config) {
+        Some(tenant_id) => {
+            matches!(entity.fields.get("_tenant"), Some(DynamicValue::Text(t)) if *t == tenant_id)
+        }
+        None => true,
+    }
+}
+
+/// Treat a soft-deleted `entity` as missing unless `include_deleted`.
+///
+/// Fetching by id bypasses the `_deleted_at` guard that queries apply, so
+/// every single-entity read and write runs the fetched row through here.
+/// Writes pass `include_deleted: false`: a deleted row must be restored
+/// before it can change.
+pub fn reject_soft_deleted(
+    schema: &SchemaDefinition,
+    entity: &Entity,
+    include_deleted: bool,
+) -> Result<(), ForgeError> {
+    if include_deleted || !schema.is_soft_delete() || !entity.fields.contains_key(DELETED_AT_FIELD)
+    {
+        return Ok(());
     }
+    Err(ForgeError::EntityNotFound {
+        schema: schema.name.as_str().to_string(),
+        entity_id: entity.id.as_str().to_string(),
+    })
 }
 
 /// Inject `_tenant` field into entity fields on creation.
@@ -459,6 +534,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn inject_tenant_scope_aggregate_adds_filter_when_enabled() {
+        let tenant_config = make_enabled_tenant_config();
+        let tenant_id = EntityId::new("tenant");
+        let claims = make_claims_with_tenant(&["member"], tenant_id.as_str());
+        let mut query = AggregateQuery::new(SchemaId::new());
+
+        inject_tenant_scope_aggregate(&mut query, Some(&claims), &tenant_config);
+
+        assert_eq!(
+            query.filter,
+            Some(Filter::eq(
+                FieldPath::single("_tenant"),
+                DynamicValue::Text(tenant_id.as_str().to_string()),
+            ))
+        );
+    }
+
     #[test]
     fn inject_tenant_scope_noop_when_disabled() {
         let tenant_config: Option<TenantConfig> = None;
@@ -575,6 +668,53 @@ mod tests {
         assert!(!fields.contains_key("_tenant"));
     }
 
+    // -----------------------------------------------------------------------
+    // entity_in_tenant_scope tests
+    // -----------------------------------------------------------------------
+
+    fn make_tenant_entity(tenant: Option<&str>) -> Entity {
+        let mut fields = BTreeMap::new();
+        if let Some(t) = tenant {
+            fields.insert("_tenant".to_string(), DynamicValue::Text(t.to_string()));
+        }
+        Entity::new(SchemaName::new("Contact").unwrap(), fields)
+    }
+
+    #[test]
+    fn entity_in_tenant_scope_matches_deepest_tenant() {
+        let tenant_config = make_enabled_tenant_config();
+        let tenant_id = EntityId::new("tenant");
+        let claims = make_claims_with_tenant(&["member"], tenant_id.as_str());
+
+        let own = make_tenant_entity(Some(tenant_id.as_str()));
+        let other = make_tenant_entity(Some("tenant_other"));
+        let untagged = make_tenant_entity(None);
+
+        assert!(entity_in_tenant_scope(&own, Some(&claims), &tenant_config));
+        assert!(!entity_in_tenant_scope(
+            &other,
+            Some(&claims),
+            &tenant_config
+        ));
+        assert!(!entity_in_tenant_scope(
+            &untagged,
+            Some(&claims),
+            &tenant_config
+        ));
+    }
+
+    #[test]
+    fn entity_in_tenant_scope_unscoped_cases() {
+        let tenant_config = make_enabled_tenant_config();
+        let other = make_tenant_entity(Some("tenant_other"));
+        let admin = make_claims_with_tenant(&[PLATFORM_ADMIN_ROLE], "tenant_x");
+        let member = make_claims_with_tenant(&["member"], "tenant_x");
+
+        assert!(entity_in_tenant_scope(&other, Some(&admin), &tenant_config));
+        assert!(entity_in_tenant_scope(&other, None, &tenant_config));
+        assert!(entity_in_tenant_scope(&other, Some(&member), &None));
+    }
+
     #[test]
     fn inject_tenant_on_create_noop_when_empty_tenant_chain() {
         let tenant_config = make_enabled_tenant_config();
@@ -586,4 +726,69 @@ mod tests {
 
         assert!(!fields.contains_key("_tenant"));
     }
+
+    // -----------------------------------------------------------------------
+    // Role hierarchy tests
+    // -----------------------------------------------------------------------
+
+    fn viewer_only_store(
+        role_ranks: crate::authz::RoleRanks,
+    ) -> (Arc<PolicyStore>, SchemaDefinition) {
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Report").unwrap(),
+            vec![make_field("title")],
+            vec![Annotation::Access {
+                read: vec!["viewer".to_string()],
+                write: vec![],
+                delete: vec![],
+                cross_tenant_read: vec![],
+            }],
+        )
+        .unwrap();
+        let snapshot = crate::authz::store::PolicyStoreSnapshot::from_schemas(
+            std::slice::from_ref(&schema),
+            None,
+            role_ranks,
+            crate::authz::PrincipalClaimMappings::default(),
+        )
+        .unwrap();
+        (Arc::new(PolicyStore::new(snapshot)), schema)
+    }
+
+    #[test]
+    fn role_hierarchy_lets_editor_read_viewer_schema() {
+        let ranks = crate::authz::RoleRanks::empty()
+            .with_hierarchy(BTreeMap::from([(
+                "editor".to_string(),
+                vec!["viewer".to_string()],
+            )]))
+            .unwrap();
+        let (store, schema) = viewer_only_store(ranks);
+        let editor = make_claims(&["editor"]);
+
+        assert!(check_schema_access(&store, &schema, Some(&editor), AccessAction::Read).is_ok());
+        assert!(matches!(
+            check_schema_access(
+                &store,
+                &schema,
+                Some(&make_claims(&["guest"])),
+                AccessAction::Read
+            ),
+            Err(ForgeError::Forbidden { .. })
+        ));
+    }
+
+    #[test]
+    fn without_role_hierarchy_editor_cannot_read_viewer_schema() {
+        let (store, schema) = viewer_only_store(crate::authz::RoleRanks::empty());
+        let editor = make_claims(&["editor"]);
+
+        assert!(matches!(
+            check_schema_access(&store, &schema, Some(&editor), AccessAction::Read),
+            Err(ForgeError::Forbidden { .. })
+        ));
+        let viewer = make_claims(&["viewer"]);
+        assert!(check_schema_access(&store, &schema, Some(&viewer), AccessAction::Read).is_ok());
+    }
 }
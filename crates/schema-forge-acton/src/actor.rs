@@ -6,23 +6,25 @@
 //! Request-response messages embed a [`ReplyChannel`](crate::messages::ReplyChannel)
 //! that the handler uses to send the result back to the caller.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 use acton_service::prelude::*;
 use tracing::{debug, warn};
 
 use schema_forge_backend::auth::RecordAccessPolicy;
+use schema_forge_backend::entity::{validate_fields, Entity};
 use schema_forge_backend::error::BackendError;
 use schema_forge_backend::tenant::TenantConfig;
-use schema_forge_core::types::SchemaDefinition;
+use schema_forge_core::types::{DefaultValue, DynamicValue, FieldType, SchemaDefinition};
 
 use crate::hooks::HookDispatcher;
 use crate::messages::{
-    AggregateEntities, ApplyMigration, CountEntities, CreateEntity, DeleteEntity, GetEntity,
-    GetHookDispatcher, GetRecordAccessPolicy, GetSchema, GetSchemasBatch, GetStorageRegistry,
-    GetTenantConfig, InitForge, InsertSchema, ListSchemas, LoadSchemaMetadata, QueryEntities,
-    RemoveSchema, StoreSchemaMetadata, UpdateEntity, UpdateTenantConfig,
+    AggregateEntities, ApplyMigration, CountEntities, CreateEntities, CreateEntity, DeleteEntity,
+    GetEntity, GetHookDispatcher, GetRecordAccessPolicy, GetSchema, GetSchemasBatch,
+    GetStorageRegistry, GetTenantConfig, InitForge, InsertSchema, ListSchemas, LoadSchemaMetadata,
+    PatchEntity, QueryEntities, RemoveSchema, RestoreEntity, StoreSchemaMetadata, UpdateEntity,
+    UpdateTenantConfig,
 };
 use crate::state::DynForgeBackend;
 use crate::storage::StorageRegistry;
@@ -44,6 +46,10 @@ pub struct ForgeActor {
     pub(crate) hook_dispatcher: Option<Arc<dyn HookDispatcher>>,
     pub(crate) storage_registry: StorageRegistry,
     pub(crate) policy_store: Option<Arc<crate::authz::PolicyStore>>,
+    pub(crate) audit_sink: Option<Arc<dyn crate::audit::AuditSink>>,
+    pub(crate) entity_hooks: crate::entity_hooks::EntityHooks,
+    pub(crate) change_feed: crate::events::ChangeFeed,
+    pub(crate) idempotency: crate::idempotency::IdempotencyStore,
 }
 
 impl std::fmt::Debug for ForgeActor {
@@ -61,6 +67,8 @@ impl std::fmt::Debug for ForgeActor {
                 &self.hook_dispatcher.as_ref().map(|_| ".."),
             )
             .field("storage_backends", &self.storage_registry.len())
+            .field("audit_sink", &self.audit_sink.as_ref().map(|_| ".."))
+            .field("entity_hooks", &self.entity_hooks)
             .finish()
     }
 }
@@ -79,6 +87,10 @@ impl ForgeActor {
             hook_dispatcher: None,
             storage_registry: StorageRegistry::default(),
             policy_store: None,
+            audit_sink: None,
+            entity_hooks: crate::entity_hooks::EntityHooks::default(),
+            change_feed: crate::events::ChangeFeed::default(),
+            idempotency: crate::idempotency::IdempotencyStore::default(),
         }
     }
 }
@@ -108,6 +120,8 @@ fn configure_init(actor: &mut ManagedActor<Idle, ForgeActor>) {
         actor.model.tenant_config = msg.tenant_config.clone();
         actor.model.hook_dispatcher = msg.hook_dispatcher.clone();
         actor.model.storage_registry = msg.storage_registry.clone();
+        actor.model.audit_sink = msg.audit_sink.clone();
+        actor.model.entity_hooks = msg.entity_hooks.clone();
 
         // Lazy-init the policy store when the caller did not supply one. The
         // CLI / extension build paths always pass it, but tests and ad-hoc
@@ -212,6 +226,46 @@ fn configure_registry_reads(actor: &mut ManagedActor<Idle, ForgeActor>) {
         })
     });
 
+    actor.act_on::<crate::messages::GetAuditSink>(|actor, ctx| {
+        let sink = actor.model.audit_sink.clone();
+        let reply = ctx.message().reply.clone();
+        Reply::pending(async move {
+            reply.send(sink).await;
+        })
+    });
+
+    actor.act_on::<crate::messages::GetEntityHooks>(|actor, ctx| {
+        let hooks = actor.model.entity_hooks.clone();
+        let reply = ctx.message().reply.clone();
+        Reply::pending(async move {
+            reply.send(hooks).await;
+        })
+    });
+
+    actor.act_on::<crate::messages::GetChangeFeed>(|actor, ctx| {
+        let feed = actor.model.change_feed.clone();
+        let reply = ctx.message().reply.clone();
+        Reply::pending(async move {
+            reply.send(feed).await;
+        })
+    });
+
+    actor.act_on::<crate::messages::GetIdempotencyStore>(|actor, ctx| {
+        let store = actor.model.idempotency.clone();
+        let reply = ctx.message().reply.clone();
+        Reply::pending(async move {
+            reply.send(store).await;
+        })
+    });
+
+    actor.act_on::<crate::messages::GetBackend>(|actor, ctx| {
+        let backend = actor.model.backend.clone();
+        let reply = ctx.message().reply.clone();
+        Reply::pending(async move {
+            reply.send(backend).await;
+        })
+    });
+
     actor.act_on::<crate::messages::GetPolicyStore>(|actor, ctx| {
         let store = actor.model.policy_store.clone();
         let reply = ctx.message().reply.clone();
@@ -370,14 +424,98 @@ fn no_backend_error() -> BackendError {
     }
 }
 
+/// Fill every field the entity omits whose default is `sequence(...)` with
+/// the next value of its backend counter.
+///
+/// Integer fields take the bare counter value; all others take it as text
+/// behind the sequence prefix. A counter value is consumed even if the
+/// create that follows fails.
+pub async fn assign_sequence_defaults(
+    backend: &dyn DynForgeBackend,
+    schema: Option<&SchemaDefinition>,
+    entity: &mut Entity,
+) -> Result<(), BackendError> {
+    let Some(schema) = schema else {
+        return Ok(());
+    };
+    for field_def in &schema.fields {
+        let Some(default @ DefaultValue::Sequence { start, .. }) = field_def.default_value() else {
+            continue;
+        };
+        if entity.fields.contains_key(field_def.name.as_str()) {
+            continue;
+        }
+        let n = backend
+            .next_sequence_value(&entity.schema, &field_def.name, *start)
+            .await?;
+        let value = match field_def.field_type {
+            FieldType::Integer(_) => DynamicValue::Integer(n),
+            _ => DynamicValue::Text(default.format_sequence(n).unwrap_or_default()),
+        };
+        entity
+            .fields
+            .insert(field_def.name.as_str().to_string(), value);
+    }
+    Ok(())
+}
+
+/// [`validate_fields`] against the entity's schema, when it is registered.
+fn check_fields(
+    schema: Option<&SchemaDefinition>,
+    fields: &BTreeMap<String, DynamicValue>,
+) -> Result<(), BackendError> {
+    schema.map_or(Ok(()), |schema| validate_fields(schema, fields))
+}
+
+/// Evaluate the `@computed` fields of an entity read back from the backend.
+///
+/// A field whose expression fails is left null; the reason is logged at
+/// debug level rather than failing the read.
+fn apply_computed(entity: &mut Entity, schema: Option<&SchemaDefinition>) {
+    let Some(schema) = schema else {
+        return;
+    };
+    for (field, reason) in entity.apply_computed(schema) {
+        debug!(
+            schema = %entity.schema,
+            field = %field,
+            reason = %reason,
+            "computed field evaluated to null"
+        );
+    }
+}
+
+/// [`apply_computed`] over a backend result.
+fn with_computed(
+    result: Result<Entity, BackendError>,
+    schema: Option<&SchemaDefinition>,
+) -> Result<Entity, BackendError> {
+    result.map(|mut entity| {
+        apply_computed(&mut entity, schema);
+        entity
+    })
+}
+
 fn configure_backend_operations(actor: &mut ManagedActor<Idle, ForgeActor>) {
     actor.act_on::<CreateEntity>(|actor, ctx| {
         let backend = actor.model.backend.clone();
-        let entity = ctx.message().entity.clone();
+        let mut entity = ctx.message().entity.clone();
+        let schema_def = actor.model.registry.get(entity.schema.as_str()).cloned();
+        if let Some(schema_def) = &schema_def {
+            entity.strip_computed(schema_def);
+        }
         let reply = ctx.message().reply.clone();
         Reply::pending(async move {
             let result = match backend {
-                Some(b) => b.create(&entity).await,
+                Some(b) => {
+                    async {
+                        check_fields(schema_def.as_ref(), &entity.fields)?;
+                        assign_sequence_defaults(b.as_ref(), schema_def.as_ref(), &mut entity)
+                            .await?;
+                        with_computed(b.create(&entity).await, schema_def.as_ref())
+                    }
+                    .await
+                }
                 None => {
                     warn!("CreateEntity received but no backend is configured");
                     Err(no_backend_error())
@@ -387,14 +525,63 @@ fn configure_backend_operations(actor: &mut ManagedActor<Idle, ForgeActor>) {
         })
     });
 
+    actor.act_on::<CreateEntities>(|actor, ctx| {
+        let backend = actor.model.backend.clone();
+        let mut entities = ctx.message().entities.clone();
+        let schema_defs: Vec<_> = entities
+            .iter()
+            .map(|e| actor.model.registry.get(e.schema.as_str()).cloned())
+            .collect();
+        for (entity, schema_def) in entities.iter_mut().zip(&schema_defs) {
+            if let Some(schema_def) = schema_def {
+                entity.strip_computed(schema_def);
+            }
+        }
+        let reply = ctx.message().reply.clone();
+        Reply::pending(async move {
+            let result = match backend {
+                Some(b) => {
+                    async {
+                        for (index, (entity, schema_def)) in
+                            entities.iter().zip(&schema_defs).enumerate()
+                        {
+                            check_fields(schema_def.as_ref(), &entity.fields).map_err(|e| {
+                                BackendError::BatchItemFailed {
+                                    index,
+                                    reason: e.to_string(),
+                                }
+                            })?;
+                        }
+                        for (entity, schema_def) in entities.iter_mut().zip(&schema_defs) {
+                            assign_sequence_defaults(b.as_ref(), schema_def.as_ref(), entity)
+                                .await?;
+                        }
+                        let mut created = b.create_many(&entities).await?;
+                        for (entity, schema_def) in created.iter_mut().zip(&schema_defs) {
+                            apply_computed(entity, schema_def.as_ref());
+                        }
+                        Ok(created)
+                    }
+                    .await
+                }
+                None => {
+                    warn!("CreateEntities received but no backend is configured");
+                    Err(no_backend_error())
+                }
+            };
+            reply.send(result).await;
+        })
+    });
+
     actor.act_on::<GetEntity>(|actor, ctx| {
         let backend = actor.model.backend.clone();
         let schema = ctx.message().schema.clone();
+        let schema_def = actor.model.registry.get(schema.as_str()).cloned();
         let id = ctx.message().id.clone();
         let reply = ctx.message().reply.clone();
         Reply::pending(async move {
             let result = match backend {
-                Some(b) => b.get(&schema, &id).await,
+                Some(b) => with_computed(b.get(&schema, &id).await, schema_def.as_ref()),
                 None => {
                     warn!("GetEntity received but no backend is configured");
                     Err(no_backend_error())
@@ -406,11 +593,22 @@ fn configure_backend_operations(actor: &mut ManagedActor<Idle, ForgeActor>) {
 
     actor.act_on::<UpdateEntity>(|actor, ctx| {
         let backend = actor.model.backend.clone();
-        let entity = ctx.message().entity.clone();
+        let mut entity = ctx.message().entity.clone();
+        let schema_def = actor.model.registry.get(entity.schema.as_str()).cloned();
+        if let Some(schema_def) = &schema_def {
+            entity.strip_computed(schema_def);
+        }
+        let expected_version = ctx.message().expected_version;
         let reply = ctx.message().reply.clone();
         Reply::pending(async move {
             let result = match backend {
-                Some(b) => b.update(&entity).await,
+                Some(b) => match check_fields(schema_def.as_ref(), &entity.fields) {
+                    Ok(()) => with_computed(
+                        b.update(&entity, expected_version).await,
+                        schema_def.as_ref(),
+                    ),
+                    Err(e) => Err(e),
+                },
                 None => {
                     warn!("UpdateEntity received but no backend is configured");
                     Err(no_backend_error())
@@ -420,6 +618,35 @@ fn configure_backend_operations(actor: &mut ManagedActor<Idle, ForgeActor>) {
         })
     });
 
+    actor.act_on::<PatchEntity>(|actor, ctx| {
+        let backend = actor.model.backend.clone();
+        let schema = ctx.message().schema.clone();
+        let schema_def = actor.model.registry.get(schema.as_str()).cloned();
+        let id = ctx.message().id.clone();
+        let mut changes = ctx.message().changes.clone();
+        if let Some(schema_def) = &schema_def {
+            changes.retain(|name, _| !schema_def.field(name).is_some_and(|f| f.is_computed()));
+        }
+        let expected_version = ctx.message().expected_version;
+        let reply = ctx.message().reply.clone();
+        Reply::pending(async move {
+            let result = match backend {
+                Some(b) => match check_fields(schema_def.as_ref(), &changes) {
+                    Ok(()) => with_computed(
+                        b.patch(&schema, &id, changes, expected_version).await,
+                        schema_def.as_ref(),
+                    ),
+                    Err(e) => Err(e),
+                },
+                None => {
+                    warn!("PatchEntity received but no backend is configured");
+                    Err(no_backend_error())
+                }
+            };
+            reply.send(result).await;
+        })
+    });
+
     actor.act_on::<DeleteEntity>(|actor, ctx| {
         let backend = actor.model.backend.clone();
         let schema = ctx.message().schema.clone();
@@ -437,13 +664,42 @@ fn configure_backend_operations(actor: &mut ManagedActor<Idle, ForgeActor>) {
         })
     });
 
+    actor.act_on::<RestoreEntity>(|actor, ctx| {
+        let backend = actor.model.backend.clone();
+        let schema = ctx.message().schema.clone();
+        let schema_def = actor.model.registry.get(schema.as_str()).cloned();
+        let id = ctx.message().id.clone();
+        let reply = ctx.message().reply.clone();
+        Reply::pending(async move {
+            let result = match backend {
+                Some(b) => with_computed(b.restore(&schema, &id).await, schema_def.as_ref()),
+                None => {
+                    warn!("RestoreEntity received but no backend is configured");
+                    Err(no_backend_error())
+                }
+            };
+            reply.send(result).await;
+        })
+    });
+
     actor.act_on::<QueryEntities>(|actor, ctx| {
         let backend = actor.model.backend.clone();
         let query = ctx.message().query.clone();
+        let schema_def = actor
+            .model
+            .registry
+            .values()
+            .find(|s| s.id == query.schema)
+            .cloned();
         let reply = ctx.message().reply.clone();
         Reply::pending(async move {
             let result = match backend {
-                Some(b) => b.query(&query).await,
+                Some(b) => b.query(&query).await.map(|mut result| {
+                    for entity in &mut result.entities {
+                        apply_computed(entity, schema_def.as_ref());
+                    }
+                    result
+                }),
                 None => {
                     warn!("QueryEntities received but no backend is configured");
                     Err(no_backend_error())
@@ -0,0 +1,662 @@
+//! API-key authentication with per-schema scopes.
+//!
+//! User traffic is authenticated by the upstream acton-service token
+//! middleware, which injects [`Claims`] into request extensions. Integrations
+//! and partners instead present a static API key. An [`ApiKeyProvider`]
+//! resolves that key to an [`AuthContext`] carrying the roles the key acts
+//! as plus an allowlist of `Schema:verb` [`ApiKeyScope`]s.
+//!
+//! [`api_key_middleware`] plugs into the same extension point as the token
+//! middleware: it converts the resolved context into `Claims` (recording the
+//! scopes under [`API_KEY_SCOPES_CLAIM`]) so every downstream handler sees an
+//! ordinary principal. [`check_schema_access`](crate::access::check_schema_access)
+//! consults [`check_api_key_scope`] before evaluating `@access`, so a key can
+//! never exceed its scopes regardless of the roles it carries. Schema and
+//! user management additionally require the dedicated `admin` verb (see
+//! [`check_api_key_admin_scope`]), so a key carrying `platform_admin` is
+//! still confined to the schemas it was scoped to.
+//!
+//! ```ignore
+//! let provider: Arc<dyn ApiKeyProvider> = Arc::new(
+//!     StaticApiKeyProvider::new().with_key(
+//!         "sk_partner_123",
+//!         AuthContext::new("partner-acme", vec!["member".into()])
+//!             .with_scope("Contact:read".parse()?),
+//!     ),
+//! );
+//! let router = forge_routes()
+//!     .layer(axum::middleware::from_fn_with_state(provider, api_key_middleware));
+//! ```
+//!
+//! For tests and local development, [`NoopAuthProvider`] authenticates every
+//! key as one fixed principal, and [`HeaderRolesAuthProvider`] takes the
+//! principal's roles straight from the presented key.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use acton_service::middleware::Claims;
+use async_trait::async_trait;
+use axum::extract::{Request, State};
+use axum::http::header::AUTHORIZATION;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+
+use crate::access::{AccessAction, PLATFORM_ADMIN_ROLE};
+use crate::error::ForgeError;
+
+/// Header carrying an API key. `Authorization: Bearer <key>` is also
+/// accepted when the provider recognizes the bearer value.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Custom claim under which [`api_key_middleware`] records the key's scopes.
+///
+/// The value is a JSON array of scope strings (`["Contact:read"]`). Claims
+/// without this key are unscoped and fall through to `@access` unchanged.
+pub const API_KEY_SCOPES_CLAIM: &str = "forge_api_scopes";
+
+/// Subject prefix for principals authenticated by API key.
+const API_KEY_SUBJECT_PREFIX: &str = "apikey:";
+
+/// The operation half of an [`ApiKeyScope`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScopeVerb {
+    /// `read`: single-entity reads and collection listing.
+    Read,
+    /// `create`: creating new entities.
+    Create,
+    /// `update`: modifying existing entities.
+    Update,
+    /// `write`: shorthand for `create` + `update`.
+    Write,
+    /// `delete`: deleting entities.
+    Delete,
+    /// `admin`: schema management for the scoped schema; `User:admin`
+    /// grants user management. Covers no entity operations.
+    Admin,
+    /// `*`: every operation, including `admin`.
+    Any,
+}
+
+impl ScopeVerb {
+    /// All verbs accepted by [`FromStr`], in their canonical spelling.
+    pub const VARIANTS: &'static [&'static str] =
+        &["read", "create", "update", "write", "delete", "admin", "*"];
+
+    /// Returns the canonical string form of this verb.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Write => "write",
+            Self::Delete => "delete",
+            Self::Admin => "admin",
+            Self::Any => "*",
+        }
+    }
+
+    /// Returns `true` if this verb permits `action`.
+    pub fn covers(self, action: AccessAction) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Read => matches!(action, AccessAction::Read | AccessAction::List),
+            Self::Create => matches!(action, AccessAction::Create | AccessAction::Write),
+            Self::Update => matches!(action, AccessAction::Update | AccessAction::Write),
+            Self::Write => matches!(
+                action,
+                AccessAction::Create | AccessAction::Update | AccessAction::Write
+            ),
+            Self::Delete => matches!(action, AccessAction::Delete),
+            Self::Admin => false,
+        }
+    }
+
+    /// Returns `true` if this verb permits administrative operations.
+    pub fn covers_admin(self) -> bool {
+        matches!(self, Self::Admin | Self::Any)
+    }
+}
+
+impl fmt::Display for ScopeVerb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single `Schema:verb` grant. The schema half may be `*` to match every
+/// schema.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ApiKeyScope {
+    schema: Option<String>,
+    verb: ScopeVerb,
+}
+
+impl ApiKeyScope {
+    /// Create a scope for a single schema.
+    pub fn new(schema: impl Into<String>, verb: ScopeVerb) -> Self {
+        Self {
+            schema: Some(schema.into()),
+            verb,
+        }
+    }
+
+    /// Create a scope that applies to every schema.
+    pub fn any_schema(verb: ScopeVerb) -> Self {
+        Self { schema: None, verb }
+    }
+
+    /// Returns `true` if this scope permits `action` on `schema`.
+    pub fn permits(&self, schema: &str, action: AccessAction) -> bool {
+        self.matches_schema(schema) && self.verb.covers(action)
+    }
+
+    /// Returns `true` if this scope permits administrative operations on
+    /// `schema`.
+    pub fn permits_admin(&self, schema: &str) -> bool {
+        self.matches_schema(schema) && self.verb.covers_admin()
+    }
+
+    fn matches_schema(&self, schema: &str) -> bool {
+        self.schema.as_deref().is_none_or(|s| s == schema)
+    }
+}
+
+impl fmt::Display for ApiKeyScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.schema.as_deref().unwrap_or("*"), self.verb)
+    }
+}
+
+/// Error returned when a scope string is not `Schema:verb`.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ApiKeyScopeError {
+    /// The string has no `:` separator or an empty half.
+    #[error("invalid API key scope '{0}': expected 'Schema:verb'")]
+    Malformed(String),
+    /// The verb half is not one of [`ScopeVerb::VARIANTS`].
+    #[error("unknown API key scope verb '{verb}'; expected one of: {}", ScopeVerb::VARIANTS.join(", "))]
+    UnknownVerb { verb: String },
+}
+
+impl FromStr for ApiKeyScope {
+    type Err = ApiKeyScopeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (schema, verb) = s
+            .split_once(':')
+            .filter(|(schema, verb)| !schema.is_empty() && !verb.is_empty())
+            .ok_or_else(|| ApiKeyScopeError::Malformed(s.to_string()))?;
+        let verb = match verb {
+            "read" => ScopeVerb::Read,
+            "create" => ScopeVerb::Create,
+            "update" => ScopeVerb::Update,
+            "write" => ScopeVerb::Write,
+            "delete" => ScopeVerb::Delete,
+            "admin" => ScopeVerb::Admin,
+            "*" => ScopeVerb::Any,
+            other => {
+                return Err(ApiKeyScopeError::UnknownVerb {
+                    verb: other.to_string(),
+                })
+            }
+        };
+        Ok(if schema == "*" {
+            Self::any_schema(verb)
+        } else {
+            Self::new(schema, verb)
+        })
+    }
+}
+
+/// What kind of principal an [`AuthContext`] stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrincipalKind {
+    /// An API key. Its subject is `apikey:<key_id>`, so key traffic never
+    /// collides with a user id.
+    #[default]
+    ApiKey,
+    /// A user the provider vouches for directly (see [`NoopAuthProvider`]
+    /// and [`HeaderRolesAuthProvider`]). Its subject is the raw user id, so
+    /// `@owner` checks and audit actors match that user's own tokens.
+    User,
+}
+
+/// The principal an API key authenticates as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthContext {
+    /// Stable identifier for the key (not the secret itself), or the user
+    /// id for a [`PrincipalKind::User`] context.
+    pub key_id: String,
+    /// Whether `key_id` names a key or a user.
+    pub kind: PrincipalKind,
+    /// Roles the key acts as when `@access` is evaluated.
+    pub roles: Vec<String>,
+    /// Schema/verb allowlist. An empty list grants nothing.
+    pub scopes: Vec<ApiKeyScope>,
+}
+
+impl AuthContext {
+    /// Create a context with the given roles and no scopes.
+    pub fn new(key_id: impl Into<String>, roles: Vec<String>) -> Self {
+        Self {
+            key_id: key_id.into(),
+            kind: PrincipalKind::ApiKey,
+            roles,
+            scopes: Vec::new(),
+        }
+    }
+
+    /// Create a context that authenticates as the user `user_id`, with the
+    /// given roles and no scopes.
+    pub fn user(user_id: impl Into<String>, roles: Vec<String>) -> Self {
+        Self {
+            kind: PrincipalKind::User,
+            ..Self::new(user_id, roles)
+        }
+    }
+
+    /// Add a scope to the allowlist.
+    pub fn with_scope(mut self, scope: ApiKeyScope) -> Self {
+        self.scopes.push(scope);
+        self
+    }
+
+    /// Convert into `Claims` for downstream handlers, recording the scopes
+    /// under [`API_KEY_SCOPES_CLAIM`]. The subject follows [`PrincipalKind`].
+    pub fn to_claims(&self) -> Claims {
+        let scopes: Vec<serde_json::Value> = self
+            .scopes
+            .iter()
+            .map(|s| serde_json::Value::String(s.to_string()))
+            .collect();
+        let mut custom = HashMap::new();
+        custom.insert(
+            API_KEY_SCOPES_CLAIM.to_string(),
+            serde_json::Value::Array(scopes),
+        );
+        Claims {
+            sub: match self.kind {
+                PrincipalKind::ApiKey => format!("{API_KEY_SUBJECT_PREFIX}{}", self.key_id),
+                PrincipalKind::User => self.key_id.clone(),
+            },
+            roles: self.roles.clone(),
+            perms: vec![],
+            // Keys carry no in-token expiry; revocation is the provider's job.
+            exp: 9_999_999_999,
+            iat: None,
+            jti: None,
+            iss: None,
+            aud: None,
+            email: None,
+            username: None,
+            custom,
+        }
+    }
+}
+
+/// Resolves a presented API key to the principal it authenticates.
+#[async_trait]
+pub trait ApiKeyProvider: Send + Sync {
+    /// Returns the context for `key`, or `None` if the key is unknown or
+    /// revoked.
+    async fn authenticate(&self, key: &str) -> Option<AuthContext>;
+}
+
+/// In-memory [`ApiKeyProvider`] backed by a fixed key table.
+#[derive(Debug, Clone, Default)]
+pub struct StaticApiKeyProvider {
+    keys: HashMap<String, AuthContext>,
+}
+
+impl StaticApiKeyProvider {
+    /// Create an empty provider.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `key` as authenticating to `context`.
+    pub fn with_key(mut self, key: impl Into<String>, context: AuthContext) -> Self {
+        self.keys.insert(key.into(), context);
+        self
+    }
+}
+
+#[async_trait]
+impl ApiKeyProvider for StaticApiKeyProvider {
+    async fn authenticate(&self, key: &str) -> Option<AuthContext> {
+        self.keys.get(key).cloned()
+    }
+}
+
+/// [`ApiKeyProvider`] that ignores the presented key and always resolves to
+/// the same principal. Intended for tests and local development.
+///
+/// Every constructor except [`anonymous`](Self::anonymous) grants the
+/// `*:*` scope, so only `@access` and Cedar decide what the principal may do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoopAuthProvider {
+    context: Option<AuthContext>,
+}
+
+impl NoopAuthProvider {
+    /// Authenticate every key as `user_id` acting with `roles`.
+    pub fn with_roles_and_user(roles: Vec<String>, user_id: impl Into<String>) -> Self {
+        Self {
+            context: Some(
+                AuthContext::user(user_id, roles)
+                    .with_scope(ApiKeyScope::any_schema(ScopeVerb::Any)),
+            ),
+        }
+    }
+
+    /// Authenticate every key as an `admin` principal holding
+    /// [`PLATFORM_ADMIN_ROLE`].
+    pub fn admin() -> Self {
+        Self::with_roles_and_user(vec![PLATFORM_ADMIN_ROLE.to_string()], "admin")
+    }
+
+    /// Reject every key, leaving requests unauthenticated.
+    pub fn anonymous() -> Self {
+        Self { context: None }
+    }
+}
+
+#[async_trait]
+impl ApiKeyProvider for NoopAuthProvider {
+    async fn authenticate(&self, _key: &str) -> Option<AuthContext> {
+        self.context.clone()
+    }
+}
+
+/// Development-only [`ApiKeyProvider`] that reads the principal from the key
+/// header itself: `X-API-Key: alice:admin,member` authenticates as `alice`
+/// with roles `admin` and `member`. A key without `:` is a role list for
+/// the `dev` user.
+///
+/// Anyone can claim any role, so never install this outside local
+/// development.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeaderRolesAuthProvider;
+
+impl HeaderRolesAuthProvider {
+    /// User id assigned when the key names no user.
+    pub const DEFAULT_USER: &'static str = "dev";
+}
+
+#[async_trait]
+impl ApiKeyProvider for HeaderRolesAuthProvider {
+    async fn authenticate(&self, key: &str) -> Option<AuthContext> {
+        let (user, roles) = key.split_once(':').unwrap_or((Self::DEFAULT_USER, key));
+        let user = user.trim();
+        if user.is_empty() {
+            return None;
+        }
+        let roles = roles
+            .split(',')
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+            .map(str::to_string)
+            .collect();
+        Some(AuthContext::user(user, roles).with_scope(ApiKeyScope::any_schema(ScopeVerb::Any)))
+    }
+}
+
+/// Axum middleware that authenticates API keys through an [`ApiKeyProvider`].
+///
+/// Reads `X-API-Key`, falling back to `Authorization: Bearer <key>`. A
+/// resolved key injects the equivalent `Claims` into request extensions.
+/// An unknown `X-API-Key` is rejected with 401; an unknown bearer value is
+/// passed through untouched so user tokens keep flowing to the token
+/// middleware's `Claims`.
+pub async fn api_key_middleware(
+    State(provider): State<Arc<dyn ApiKeyProvider>>,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    let header_key = req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    if let Some(key) = header_key {
+        return match provider.authenticate(&key).await {
+            Some(ctx) => {
+                req.extensions_mut().insert(ctx.to_claims());
+                next.run(req).await
+            }
+            None => ForgeError::Unauthorized {
+                message: "invalid API key".to_string(),
+            }
+            .into_response(),
+        };
+    }
+
+    let bearer = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::to_string);
+    if let Some(key) = bearer {
+        if let Some(ctx) = provider.authenticate(&key).await {
+            req.extensions_mut().insert(ctx.to_claims());
+        }
+    }
+
+    next.run(req).await
+}
+
+/// Enforce API-key scopes carried in `claims` for `action` on `schema`.
+///
+/// Returns `Ok(())` for claims without [`API_KEY_SCOPES_CLAIM`] (ordinary
+/// user tokens) and for scoped claims that cover the request. Anything
+/// else, including a malformed scope list, is `Forbidden`.
+pub fn check_api_key_scope(
+    claims: Option<&Claims>,
+    schema: &str,
+    action: AccessAction,
+) -> Result<(), ForgeError> {
+    if scopes_permit(claims, |scope| scope.permits(schema, action)) {
+        Ok(())
+    } else {
+        Err(ForgeError::Forbidden {
+            message: format!(
+                "access denied: API key scopes do not cover {action:?} on schema '{schema}'"
+            ),
+        })
+    }
+}
+
+/// Enforce API-key scopes carried in `claims` for administrative operations
+/// on `schema`: schema management, or user management when `schema` is
+/// `User`.
+///
+/// Role checks alone are not enough here, because a key may act as
+/// `platform_admin` while being scoped to a handful of schemas. Claims
+/// without [`API_KEY_SCOPES_CLAIM`] pass through; scoped claims need an
+/// `admin` (or `*`) scope covering `schema`.
+pub fn check_api_key_admin_scope(claims: Option<&Claims>, schema: &str) -> Result<(), ForgeError> {
+    if scopes_permit(claims, |scope| scope.permits_admin(schema)) {
+        Ok(())
+    } else {
+        Err(ForgeError::Forbidden {
+            message: format!(
+                "access denied: API key scopes do not grant admin on schema '{schema}'"
+            ),
+        })
+    }
+}
+
+/// Returns `true` for unscoped claims, or when any recorded scope satisfies
+/// `permits`. A malformed scope list permits nothing.
+fn scopes_permit(claims: Option<&Claims>, permits: impl Fn(&ApiKeyScope) -> bool) -> bool {
+    let Some(raw) = claims.and_then(|c| c.custom.get(API_KEY_SCOPES_CLAIM)) else {
+        return true;
+    };
+    raw.as_array().is_some_and(|scopes| {
+        scopes.iter().any(|s| {
+            s.as_str()
+                .and_then(|s| s.parse::<ApiKeyScope>().ok())
+                .is_some_and(|scope| permits(&scope))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scoped_claims(scopes: &[&str]) -> Claims {
+        let mut ctx = AuthContext::new("test", vec!["member".into()]);
+        for s in scopes {
+            ctx = ctx.with_scope(s.parse().unwrap());
+        }
+        ctx.to_claims()
+    }
+
+    #[test]
+    fn scope_parses_and_displays() {
+        let scope: ApiKeyScope = "Contact:read".parse().unwrap();
+        assert_eq!(scope, ApiKeyScope::new("Contact", ScopeVerb::Read));
+        assert_eq!(scope.to_string(), "Contact:read");
+
+        let any: ApiKeyScope = "*:delete".parse().unwrap();
+        assert_eq!(any, ApiKeyScope::any_schema(ScopeVerb::Delete));
+        assert_eq!(any.to_string(), "*:delete");
+    }
+
+    #[test]
+    fn scope_rejects_malformed_and_unknown_verbs() {
+        assert!(matches!(
+            "Contact".parse::<ApiKeyScope>(),
+            Err(ApiKeyScopeError::Malformed(_))
+        ));
+        assert!(matches!(
+            ":read".parse::<ApiKeyScope>(),
+            Err(ApiKeyScopeError::Malformed(_))
+        ));
+        assert!(matches!(
+            "Contact:manage".parse::<ApiKeyScope>(),
+            Err(ApiKeyScopeError::UnknownVerb { .. })
+        ));
+    }
+
+    #[test]
+    fn verbs_cover_expected_actions() {
+        assert!(ScopeVerb::Read.covers(AccessAction::List));
+        assert!(!ScopeVerb::Read.covers(AccessAction::Create));
+        assert!(ScopeVerb::Write.covers(AccessAction::Update));
+        assert!(!ScopeVerb::Write.covers(AccessAction::Delete));
+        assert!(ScopeVerb::Any.covers(AccessAction::Delete));
+    }
+
+    #[test]
+    fn unscoped_claims_pass_through() {
+        assert!(check_api_key_scope(None, "Contact", AccessAction::Delete).is_ok());
+        let mut user = AuthContext::new("u", vec![]).to_claims();
+        user.custom.clear();
+        assert!(check_api_key_scope(Some(&user), "Contact", AccessAction::Delete).is_ok());
+    }
+
+    #[test]
+    fn scoped_claims_enforce_schema_and_verb() {
+        let claims = scoped_claims(&["Contact:read"]);
+        assert!(check_api_key_scope(Some(&claims), "Contact", AccessAction::List).is_ok());
+        assert!(check_api_key_scope(Some(&claims), "Contact", AccessAction::Read).is_ok());
+        assert!(matches!(
+            check_api_key_scope(Some(&claims), "Contact", AccessAction::Create),
+            Err(ForgeError::Forbidden { .. })
+        ));
+        assert!(matches!(
+            check_api_key_scope(Some(&claims), "Invoice", AccessAction::Read),
+            Err(ForgeError::Forbidden { .. })
+        ));
+    }
+
+    #[test]
+    fn admin_requires_dedicated_scope() {
+        let claims = scoped_claims(&["Contact:*", "Invoice:write"]);
+        assert!(check_api_key_admin_scope(Some(&claims), "Contact").is_ok());
+        assert!(matches!(
+            check_api_key_admin_scope(Some(&claims), "Invoice"),
+            Err(ForgeError::Forbidden { .. })
+        ));
+        assert!(check_api_key_admin_scope(Some(&claims), "User").is_err());
+
+        let admin = scoped_claims(&["User:admin"]);
+        assert!(check_api_key_admin_scope(Some(&admin), "User").is_ok());
+        assert!(check_api_key_scope(Some(&admin), "User", AccessAction::Read).is_err());
+        assert!(check_api_key_admin_scope(None, "User").is_ok());
+    }
+
+    #[test]
+    fn empty_scope_list_grants_nothing() {
+        let claims = scoped_claims(&[]);
+        assert!(check_api_key_scope(Some(&claims), "Contact", AccessAction::Read).is_err());
+    }
+
+    #[tokio::test]
+    async fn static_provider_resolves_known_keys_only() {
+        let provider = StaticApiKeyProvider::new()
+            .with_key("sk_1", AuthContext::new("partner", vec!["member".into()]));
+        let ctx = provider.authenticate("sk_1").await.unwrap();
+        assert_eq!(ctx.key_id, "partner");
+        assert_eq!(ctx.to_claims().sub, "apikey:partner");
+        assert!(provider.authenticate("sk_2").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn noop_provider_resolves_fixed_context() {
+        let provider = NoopAuthProvider::with_roles_and_user(vec!["member".into()], "alice");
+        let expected = AuthContext::user("alice", vec!["member".into()])
+            .with_scope(ApiKeyScope::any_schema(ScopeVerb::Any));
+        assert_eq!(
+            provider.authenticate("anything").await,
+            Some(expected.clone())
+        );
+        assert_eq!(provider.authenticate("").await, Some(expected.clone()));
+        assert_eq!(expected.to_claims().sub, "alice");
+    }
+
+    #[tokio::test]
+    async fn noop_admin_provider_is_platform_admin() {
+        let ctx = NoopAuthProvider::admin().authenticate("k").await.unwrap();
+        assert_eq!(ctx.key_id, "admin");
+        assert_eq!(ctx.roles, vec![PLATFORM_ADMIN_ROLE.to_string()]);
+        let claims = ctx.to_claims();
+        assert!(check_api_key_scope(Some(&claims), "Contact", AccessAction::Delete).is_ok());
+    }
+
+    #[tokio::test]
+    async fn noop_anonymous_provider_resolves_nothing() {
+        assert!(NoopAuthProvider::anonymous()
+            .authenticate("k")
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn header_roles_provider_parses_user_and_roles() {
+        let provider = HeaderRolesAuthProvider;
+        let expected = |user: &str, roles: &[&str]| {
+            Some(
+                AuthContext::user(user, roles.iter().map(|r| r.to_string()).collect())
+                    .with_scope(ApiKeyScope::any_schema(ScopeVerb::Any)),
+            )
+        };
+        assert_eq!(
+            provider.authenticate("alice:admin, member").await,
+            expected("alice", &["admin", "member"])
+        );
+        assert_eq!(
+            provider.authenticate("member").await,
+            expected("dev", &["member"])
+        );
+        assert_eq!(provider.authenticate("bob:").await, expected("bob", &[]));
+        assert_eq!(provider.authenticate(":admin").await, None);
+    }
+}
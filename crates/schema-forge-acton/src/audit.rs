@@ -0,0 +1,373 @@
+//! Structured audit trail for entity writes.
+//!
+//! Every successful create, update, and delete produces an [`AuditEvent`]
+//! that the entity routes hand to the configured [`AuditSink`]. The sink is
+//! optional: deployments that do not install one pay nothing beyond the
+//! actor round-trip that discovers its absence.
+//!
+//! Two sinks ship with the crate:
+//!
+//! - [`TracingAuditSink`] — emits one `tracing` event per write under the
+//!   `forge::audit` target. The default `[schema_forge.audit] sink`.
+//! - [`AuditLogSink`] — persists each event as a row in the opt-in
+//!   `AuditLog` system schema (see
+//!   [`seed_audit_log_schema_into_map`](crate::system::seed_audit_log_schema_into_map)).
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use schema_forge_backend::entity::Entity;
+use schema_forge_core::types::{DynamicValue, SchemaDefinition, SchemaName};
+use serde::{Deserialize, Serialize};
+
+use crate::state::DynForgeBackend;
+
+/// Name of the system schema [`AuditLogSink`] writes to.
+pub const AUDIT_LOG_SCHEMA_NAME: &str = "AuditLog";
+
+/// Which [`AuditSink`] `serve` installs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditSinkKind {
+    /// [`TracingAuditSink`].
+    #[default]
+    Tracing,
+    /// [`AuditLogSink`]; also seeds the `AuditLog` system schema.
+    AuditLog,
+    /// No audit events are recorded.
+    Disabled,
+}
+
+/// `[schema_forge.audit]` section of config.toml.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditConfig {
+    /// Destination for entity-write audit events (default: `tracing`).
+    #[serde(default)]
+    pub sink: AuditSinkKind,
+}
+
+impl AuditConfig {
+    /// Build the configured sink. Returns `None` when auditing is disabled.
+    pub fn build_sink(&self, backend: Arc<dyn DynForgeBackend>) -> Option<Arc<dyn AuditSink>> {
+        match self.sink {
+            AuditSinkKind::Tracing => Some(Arc::new(TracingAuditSink)),
+            AuditSinkKind::AuditLog => Some(Arc::new(AuditLogSink::new(backend))),
+            AuditSinkKind::Disabled => None,
+        }
+    }
+}
+
+/// The kind of write an [`AuditEvent`] records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    /// A new entity was created.
+    Create,
+    /// An existing entity was updated (PUT or PATCH).
+    Update,
+    /// An entity was deleted.
+    Delete,
+}
+
+impl AuditAction {
+    /// Returns the lowercase string form stored in the audit trail.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single audited write.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditEvent {
+    /// Subject of the caller's claims, or `None` for unauthenticated writes.
+    pub actor: Option<String>,
+    /// What happened to the entity.
+    pub action: AuditAction,
+    /// Schema the entity belongs to.
+    pub schema: String,
+    /// Identifier of the affected entity.
+    pub entity_id: String,
+    /// When the write completed.
+    pub timestamp: DateTime<Utc>,
+    /// Fields whose values changed. Every populated field for creates, the
+    /// before/after diff for updates, and empty for deletes.
+    pub changed_fields: Vec<String>,
+}
+
+impl AuditEvent {
+    /// Build an event stamped with the current time.
+    pub fn new(
+        actor: Option<String>,
+        action: AuditAction,
+        schema: impl Into<String>,
+        entity_id: impl Into<String>,
+        changed_fields: Vec<String>,
+    ) -> Self {
+        Self {
+            actor,
+            action,
+            schema: schema.into(),
+            entity_id: entity_id.into(),
+            timestamp: Utc::now(),
+            changed_fields,
+        }
+    }
+}
+
+/// Names of the stored fields whose values differ between `before` and
+/// `after`, in schema declaration order.
+///
+/// Values are compared with [`DynamicValue::canonical_eq`], so re-saving a
+/// JSON field with its keys reordered is not a change. A key absent from one
+/// side compares as `Null`, so clearing a field and setting a previously-unset
+/// one both count as changes. Derived and `@computed` fields are
+/// skipped: they are resolved at read time and never written.
+pub fn changed_fields(
+    schema: &SchemaDefinition,
+    before: &BTreeMap<String, DynamicValue>,
+    after: &BTreeMap<String, DynamicValue>,
+) -> Vec<String> {
+    schema
+        .fields
+        .iter()
+        .filter(|f| f.is_stored())
+        .map(|f| f.name.as_str())
+        .filter(|name| {
+            let old = before.get(*name).unwrap_or(&DynamicValue::Null);
+            let new = after.get(*name).unwrap_or(&DynamicValue::Null);
+            !old.canonical_eq(new)
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+/// Destination for [`AuditEvent`]s.
+///
+/// Implementations must not fail the request: a sink that cannot record an
+/// event should log the problem and return.
+#[async_trait]
+pub trait AuditSink: Send + Sync {
+    /// Record one audited write.
+    async fn record(&self, event: AuditEvent);
+}
+
+/// [`AuditSink`] that emits each event through `tracing`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingAuditSink;
+
+#[async_trait]
+impl AuditSink for TracingAuditSink {
+    async fn record(&self, event: AuditEvent) {
+        tracing::info!(
+            target: "forge::audit",
+            actor = event.actor.as_deref().unwrap_or("anonymous"),
+            action = event.action.as_str(),
+            schema = %event.schema,
+            entity_id = %event.entity_id,
+            timestamp = %event.timestamp.to_rfc3339(),
+            changed_fields = ?event.changed_fields,
+            "entity {}", event.action,
+        );
+    }
+}
+
+/// [`AuditSink`] that persists each event to the `AuditLog` system schema.
+///
+/// The schema must already exist; seed it at startup with
+/// [`seed_audit_log_schema_into_map`](crate::system::seed_audit_log_schema_into_map).
+pub struct AuditLogSink {
+    backend: Arc<dyn DynForgeBackend>,
+}
+
+impl AuditLogSink {
+    /// Create a sink writing through `backend`.
+    pub fn new(backend: Arc<dyn DynForgeBackend>) -> Self {
+        Self { backend }
+    }
+
+    fn to_entity(event: &AuditEvent) -> Entity {
+        let mut fields = BTreeMap::new();
+        fields.insert(
+            "actor".to_string(),
+            event
+                .actor
+                .clone()
+                .map_or(DynamicValue::Null, DynamicValue::Text),
+        );
+        fields.insert(
+            "action".to_string(),
+            DynamicValue::Text(event.action.as_str().to_string()),
+        );
+        fields.insert(
+            "target_schema".to_string(),
+            DynamicValue::Text(event.schema.clone()),
+        );
+        fields.insert(
+            "entity_id".to_string(),
+            DynamicValue::Text(event.entity_id.clone()),
+        );
+        fields.insert(
+            "timestamp".to_string(),
+            DynamicValue::DateTime(event.timestamp),
+        );
+        fields.insert(
+            "changed_fields".to_string(),
+            DynamicValue::Array(
+                event
+                    .changed_fields
+                    .iter()
+                    .cloned()
+                    .map(DynamicValue::Text)
+                    .collect(),
+            ),
+        );
+        let schema = SchemaName::new(AUDIT_LOG_SCHEMA_NAME).expect("AuditLog is a valid name");
+        Entity::new(schema, fields)
+    }
+}
+
+impl fmt::Debug for AuditLogSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuditLogSink").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl AuditSink for AuditLogSink {
+    async fn record(&self, event: AuditEvent) {
+        let entity = Self::to_entity(&event);
+        if let Err(e) = self.backend.create(&entity).await {
+            tracing::error!(
+                schema = %event.schema,
+                entity_id = %event.entity_id,
+                "failed to persist audit event: {e}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schema_forge_core::types::{
+        FieldDefinition, FieldName, FieldType, SchemaId, TextConstraints,
+    };
+
+    fn contact_schema() -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![
+                FieldDefinition::new(
+                    FieldName::new("name").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                ),
+                FieldDefinition::new(
+                    FieldName::new("email").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                ),
+                FieldDefinition::new(FieldName::new("active").unwrap(), FieldType::Boolean),
+            ],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn changed_fields_lists_only_differences() {
+        let schema = contact_schema();
+        let before = BTreeMap::from([
+            ("name".to_string(), DynamicValue::Text("Alice".into())),
+            ("email".to_string(), DynamicValue::Text("a@x.io".into())),
+        ]);
+        let after = BTreeMap::from([
+            ("name".to_string(), DynamicValue::Text("Alicia".into())),
+            ("email".to_string(), DynamicValue::Text("a@x.io".into())),
+        ]);
+        assert_eq!(changed_fields(&schema, &before, &after), vec!["name"]);
+    }
+
+    #[test]
+    fn changed_fields_ignores_reordered_json_keys() {
+        let mut schema = contact_schema();
+        schema.fields.push(FieldDefinition::new(
+            FieldName::new("prefs").unwrap(),
+            FieldType::Json,
+        ));
+        let before = BTreeMap::from([(
+            "prefs".to_string(),
+            DynamicValue::Json(serde_json::json!({"theme": "dark", "lang": "en"})),
+        )]);
+        let reordered: serde_json::Value =
+            serde_json::from_str(r#"{"lang": "en", "theme": "dark"}"#).unwrap();
+        let after = BTreeMap::from([("prefs".to_string(), DynamicValue::Json(reordered))]);
+        assert!(changed_fields(&schema, &before, &after).is_empty());
+
+        let changed = BTreeMap::from([(
+            "prefs".to_string(),
+            DynamicValue::Json(serde_json::json!({"theme": "light", "lang": "en"})),
+        )]);
+        assert_eq!(changed_fields(&schema, &before, &changed), vec!["prefs"]);
+    }
+
+    #[test]
+    fn changed_fields_treats_missing_as_null() {
+        let schema = contact_schema();
+        let before = BTreeMap::from([("active".to_string(), DynamicValue::Null)]);
+        let after = BTreeMap::from([("email".to_string(), DynamicValue::Text("b@x.io".into()))]);
+        assert_eq!(changed_fields(&schema, &before, &after), vec!["email"]);
+    }
+
+    #[test]
+    fn audit_config_defaults_to_tracing() {
+        let config: AuditConfig = toml::from_str("").unwrap();
+        assert_eq!(config.sink, AuditSinkKind::Tracing);
+        let config: AuditConfig = toml::from_str(r#"sink = "audit_log""#).unwrap();
+        assert_eq!(config.sink, AuditSinkKind::AuditLog);
+    }
+
+    #[test]
+    fn audit_action_serializes_lowercase() {
+        assert_eq!(
+            serde_json::to_value(AuditAction::Update).unwrap(),
+            serde_json::json!("update")
+        );
+        assert_eq!(AuditAction::Delete.to_string(), "delete");
+    }
+
+    #[test]
+    fn audit_log_entity_maps_event_fields() {
+        let event = AuditEvent::new(
+            Some("user:1".into()),
+            AuditAction::Update,
+            "Contact",
+            "contact_01",
+            vec!["name".into()],
+        );
+        let entity = AuditLogSink::to_entity(&event);
+        assert_eq!(entity.schema.as_str(), AUDIT_LOG_SCHEMA_NAME);
+        assert_eq!(
+            entity.field("action"),
+            Some(&DynamicValue::Text("update".into()))
+        );
+        assert_eq!(
+            entity.field("changed_fields"),
+            Some(&DynamicValue::Array(vec![DynamicValue::Text(
+                "name".into()
+            )]))
+        );
+    }
+}
@@ -86,14 +86,17 @@ pub fn build_principal_entities(
     let principal_uid_value = principal_uid(claims)?;
     let id = user_id_from_sub(&claims.sub);
 
-    let role_rank = role_ranks.max_rank(&claims.roles);
+    // Roles granted by the token plus everything they imply through the
+    // configured hierarchy; the rank, `roles` attribute, and group parents
+    // all see the expanded set.
+    let roles = role_ranks.expand_roles(&claims.roles);
+    let role_rank = role_ranks.max_rank(&roles);
 
     // Principal attributes
     let mut attrs: HashMap<String, RestrictedExpression> = HashMap::new();
     attrs.insert("id".into(), RestrictedExpression::new_string(id.to_string()));
     attrs.insert("role_rank".into(), RestrictedExpression::new_long(role_rank));
-    let role_set: Vec<RestrictedExpression> = claims
-        .roles
+    let role_set: Vec<RestrictedExpression> = roles
         .iter()
         .map(|r| RestrictedExpression::new_string(r.clone()))
         .collect();
@@ -119,7 +122,7 @@ pub fn build_principal_entities(
 
     let mut group_uids: HashSet<EntityUid> = HashSet::new();
     let mut group_entities: Vec<CedarEntity> = Vec::new();
-    for role in &claims.roles {
+    for role in &roles {
         let raw = format!("{GROUP_TYPE}::\"{role}\"");
         let uid = EntityUid::from_str(&raw).map_err(|e| AdapterError::InvalidIdentifier {
             value: raw.clone(),
@@ -293,19 +296,23 @@ pub fn build_resource_placeholder(
 /// Cedar (composites, raw JSON), matching the schema generator's output.
 fn default_cedar_expr(ft: &FieldType) -> Option<RestrictedExpression> {
     match ft {
-        FieldType::Text(_) | FieldType::RichText | FieldType::Enum(_) | FieldType::File(_) => {
-            Some(RestrictedExpression::new_string(String::new()))
-        }
-        FieldType::Integer(_) | FieldType::Float(_) | FieldType::DateTime => {
-            Some(RestrictedExpression::new_long(0))
-        }
+        FieldType::Text(_)
+        | FieldType::RichText(_)
+        | FieldType::Enum(_)
+        | FieldType::File(_)
+        | FieldType::Duration
+        | FieldType::Uuid => Some(RestrictedExpression::new_string(String::new())),
+        FieldType::Integer(_)
+        | FieldType::Float(_)
+        | FieldType::Decimal { .. }
+        | FieldType::DateTime => Some(RestrictedExpression::new_long(0)),
         FieldType::Boolean => Some(RestrictedExpression::new_bool(false)),
         FieldType::Relation { cardinality, .. } => match cardinality {
             Cardinality::One => Some(RestrictedExpression::new_string(String::new())),
             Cardinality::Many => Some(RestrictedExpression::new_set(Vec::new())),
             _ => None,
         },
-        FieldType::Array(_) => Some(RestrictedExpression::new_set(Vec::new())),
+        FieldType::Array(_, _) => Some(RestrictedExpression::new_set(Vec::new())),
         _ => None,
     }
 }
@@ -331,10 +338,20 @@ pub fn dynamic_to_cedar(value: &DynamicValue) -> Option<RestrictedExpression> {
         // numeric predicates over Float fields are already operating in
         // integer space.
         DynamicValue::Float(f) => Some(RestrictedExpression::new_long(*f as i64)),
+        // Same integer space as Float; saturate amounts beyond i64.
+        DynamicValue::Decimal(d) => Some(RestrictedExpression::new_long(
+            i64::try_from(d.trunc()).unwrap_or(if d.is_sign_negative() {
+                i64::MIN
+            } else {
+                i64::MAX
+            }),
+        )),
         DynamicValue::Boolean(b) => Some(RestrictedExpression::new_bool(*b)),
         DynamicValue::DateTime(dt) => {
             Some(RestrictedExpression::new_long(dt.timestamp_millis()))
         }
+        DynamicValue::Duration(d) => Some(RestrictedExpression::new_string(d.to_string())),
+        DynamicValue::Uuid(u) => Some(RestrictedExpression::new_string(u.to_string())),
         DynamicValue::Ref(id) => Some(RestrictedExpression::new_string(id.as_str().to_string())),
         DynamicValue::RefArray(ids) => {
             let items: Vec<RestrictedExpression> = ids
@@ -619,7 +619,7 @@ fn resolve_projection(
         (FieldType::Text(_), PrincipalClaimType::String) => UserFieldProjection::TextToString,
         (FieldType::Integer(_), PrincipalClaimType::Long) => UserFieldProjection::IntegerToLong,
         (FieldType::Boolean, PrincipalClaimType::Bool) => UserFieldProjection::BooleanToBool,
-        (FieldType::Array(inner), PrincipalClaimType::SetOfString) => match inner.as_ref() {
+        (FieldType::Array(inner, _), PrincipalClaimType::SetOfString) => match inner.as_ref() {
             FieldType::Text(_) => UserFieldProjection::TextArrayToSetOfString,
             FieldType::Relation {
                 cardinality: Cardinality::One,
@@ -648,9 +648,12 @@ fn resolve_projection(
             PrincipalClaimType::SetOfString,
         ) => UserFieldProjection::RelationManyToSetOfString,
         (
-            FieldType::RichText
+            FieldType::RichText(_)
             | FieldType::Float(_)
+            | FieldType::Decimal { .. }
             | FieldType::DateTime
+            | FieldType::Duration
+            | FieldType::Uuid
             | FieldType::Enum(_)
             | FieldType::Json
             | FieldType::Composite(_)
@@ -663,7 +666,7 @@ fn resolve_projection(
                 actual: field_type.to_string(),
             });
         }
-        (FieldType::Array(_), _) => {
+        (FieldType::Array(_, _), _) => {
             return Err(PrincipalClaimsError::UnprojectableFieldType {
                 name: claim_name.to_string(),
                 field: field_name.to_string(),
@@ -748,6 +751,9 @@ fn describe_dynamic_kind(v: &DynamicValue) -> String {
         DynamicValue::Float(_) => "float".into(),
         DynamicValue::Boolean(_) => "boolean".into(),
         DynamicValue::DateTime(_) => "datetime".into(),
+        DynamicValue::Duration(_) => "duration".into(),
+        DynamicValue::Uuid(_) => "uuid".into(),
+        DynamicValue::Decimal(_) => "decimal".into(),
         DynamicValue::Enum(_) => "enum".into(),
         DynamicValue::Json(_) => "json".into(),
         DynamicValue::Array(_) => "array".into(),
@@ -1123,7 +1129,7 @@ mod tests {
     #[test]
     fn resolve_rejects_richtext_json_file_datetime_enum_composite() {
         let bad = [
-            ("rt", FieldType::RichText),
+            ("rt", FieldType::rich_text()),
             ("js", FieldType::Json),
             ("dt", FieldType::DateTime),
             (
@@ -1162,9 +1168,7 @@ mod tests {
     fn resolve_rejects_integer_array() {
         let user = user_schema_with(vec![fd(
             "scores",
-            FieldType::Array(Box::new(FieldType::Integer(
-                IntegerConstraints::unconstrained(),
-            ))),
+            FieldType::array(FieldType::Integer(IntegerConstraints::unconstrained())),
         )]);
         let cfg = config_with_user_field("scores", PrincipalClaimType::SetOfString, "scores");
         let mut mappings = PrincipalClaimMappings::from_config(&cfg).unwrap();
@@ -1238,7 +1242,7 @@ mod tests {
     fn resolve_text_array_to_set() {
         let user = user_schema_with(vec![fd(
             "regions",
-            FieldType::Array(Box::new(FieldType::Text(TextConstraints::unconstrained()))),
+            FieldType::array(FieldType::Text(TextConstraints::unconstrained())),
         )]);
         let cfg = config_with_user_field("regions", PrincipalClaimType::SetOfString, "regions");
         let mut mappings = PrincipalClaimMappings::from_config(&cfg).unwrap();
@@ -1319,7 +1323,7 @@ mod tests {
     fn project_text_array_emits_set() {
         let user = user_schema_with(vec![fd(
             "regions",
-            FieldType::Array(Box::new(FieldType::Text(TextConstraints::unconstrained()))),
+            FieldType::array(FieldType::Text(TextConstraints::unconstrained())),
         )]);
         let mut mappings = PrincipalClaimMappings::from_config(&config_with_user_field(
             "regions",
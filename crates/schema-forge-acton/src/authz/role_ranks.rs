@@ -12,8 +12,13 @@
 //! absent from this map are surfaced as a compile-time error during policy
 //! validation, mirroring the secure-by-default posture of the rest of the
 //! engine.
+//!
+//! Alongside the ranks, [`RoleRanks::with_hierarchy`] installs role
+//! inheritance (`editor` implies `viewer`). The principal builder expands a
+//! user's roles through it, so a role satisfies every `@access` requirement
+//! of the roles it implies.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
 
 use serde::{Deserialize, Serialize};
@@ -75,6 +80,12 @@ pub enum RoleRanksError {
     /// A role name was empty.
     #[error("role names must be non-empty strings")]
     EmptyRoleName,
+    /// A role hierarchy entry tried to grant `platform_admin` by implication.
+    #[error(
+        "role '{role}' cannot imply 'platform_admin'; the platform superuser role \
+         must be granted explicitly"
+    )]
+    PlatformAdminImplied { role: String },
     /// A Cedar policy referenced a role name with no rank entry.
     #[error(
         "role '{role}' is referenced by a Cedar policy but is missing from role_ranks.toml; \
@@ -91,6 +102,9 @@ pub enum RoleRanksError {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RoleRanks {
     ranks: BTreeMap<String, RoleRank>,
+    /// Role → directly implied roles. Empty unless
+    /// [`RoleRanks::with_hierarchy`] was called.
+    implied: BTreeMap<String, Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -104,7 +118,10 @@ impl RoleRanks {
     pub fn empty() -> Self {
         let mut ranks = BTreeMap::new();
         ranks.insert(PLATFORM_ADMIN_ROLE.to_string(), PLATFORM_ADMIN_RANK);
-        Self { ranks }
+        Self {
+            ranks,
+            implied: BTreeMap::new(),
+        }
     }
 
     /// Loads ranks from a TOML file at `path`.
@@ -159,7 +176,51 @@ impl RoleRanks {
             ranks.insert(role, rank);
         }
 
-        Ok(Self { ranks })
+        Ok(Self {
+            ranks,
+            implied: BTreeMap::new(),
+        })
+    }
+
+    /// Installs a role hierarchy: each key implies every role in its list,
+    /// transitively. Cycles are harmless. Replaces any earlier hierarchy.
+    ///
+    /// No role may imply `platform_admin` — the superuser bypass stays an
+    /// explicit grant.
+    pub fn with_hierarchy(
+        mut self,
+        hierarchy: BTreeMap<String, Vec<String>>,
+    ) -> Result<Self, RoleRanksError> {
+        for (role, implied) in &hierarchy {
+            if role.is_empty() || implied.iter().any(String::is_empty) {
+                return Err(RoleRanksError::EmptyRoleName);
+            }
+            if implied.iter().any(|r| r == PLATFORM_ADMIN_ROLE) {
+                return Err(RoleRanksError::PlatformAdminImplied { role: role.clone() });
+            }
+        }
+        self.implied = hierarchy;
+        Ok(self)
+    }
+
+    /// Returns `roles` followed by every role they imply through the
+    /// hierarchy, without duplicates. Roles outside the hierarchy pass
+    /// through unchanged.
+    pub fn expand_roles(&self, roles: &[String]) -> Vec<String> {
+        let mut seen: BTreeSet<&str> = BTreeSet::new();
+        let mut expanded: Vec<String> = Vec::with_capacity(roles.len());
+        let mut pending: Vec<&str> = roles.iter().map(String::as_str).collect();
+        pending.reverse();
+        while let Some(role) = pending.pop() {
+            if !seen.insert(role) {
+                continue;
+            }
+            expanded.push(role.to_string());
+            if let Some(implied) = self.implied.get(role) {
+                pending.extend(implied.iter().rev().map(String::as_str));
+            }
+        }
+        expanded
     }
 
     /// Returns the rank for `role`, if registered.
@@ -318,4 +379,62 @@ member = 100
             .ensure_all_registered(["manager", "member", PLATFORM_ADMIN_ROLE])
             .unwrap();
     }
+
+    fn hierarchy(entries: &[(&str, &[&str])]) -> BTreeMap<String, Vec<String>> {
+        entries
+            .iter()
+            .map(|(role, implied)| {
+                (
+                    role.to_string(),
+                    implied.iter().map(|r| r.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn expand_roles_follows_hierarchy_transitively() {
+        let ranks = RoleRanks::empty()
+            .with_hierarchy(hierarchy(&[
+                ("admin", &["editor"]),
+                ("editor", &["viewer"]),
+            ]))
+            .unwrap();
+        assert_eq!(
+            ranks.expand_roles(&["admin".into()]),
+            vec!["admin", "editor", "viewer"]
+        );
+        assert_eq!(
+            ranks.expand_roles(&["viewer".into(), "editor".into()]),
+            vec!["viewer", "editor"]
+        );
+        assert_eq!(ranks.expand_roles(&["guest".into()]), vec!["guest"]);
+    }
+
+    #[test]
+    fn expand_roles_without_hierarchy_is_identity() {
+        let roles = vec!["editor".to_string(), "member".to_string()];
+        assert_eq!(RoleRanks::empty().expand_roles(&roles), roles);
+    }
+
+    #[test]
+    fn expand_roles_tolerates_cycles() {
+        let ranks = RoleRanks::empty()
+            .with_hierarchy(hierarchy(&[("a", &["b"]), ("b", &["a", "c"])]))
+            .unwrap();
+        assert_eq!(ranks.expand_roles(&["a".into()]), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn hierarchy_cannot_imply_platform_admin() {
+        let err = RoleRanks::empty()
+            .with_hierarchy(hierarchy(&[("owner", &[PLATFORM_ADMIN_ROLE])]))
+            .unwrap_err();
+        assert!(matches!(err, RoleRanksError::PlatformAdminImplied { role } if role == "owner"));
+
+        let err = RoleRanks::empty()
+            .with_hierarchy(hierarchy(&[("owner", &[""])]))
+            .unwrap_err();
+        assert!(matches!(err, RoleRanksError::EmptyRoleName));
+    }
 }
@@ -205,7 +205,9 @@ forbid (
 ///
 /// - the resource carries a `_tenant` reference (i.e., it's tenant-scoped), AND
 /// - the principal is not a member of that tenant via parent chain, AND
-/// - the principal is not `platform_admin`.
+/// - the principal is not `platform_admin`, AND
+/// - for `Read`/`List`, the principal holds none of the `@access`
+///   annotation's `cross_tenant_read` roles.
 ///
 /// The `resource has "_tenant"` precondition keeps the rule inert for
 /// non-tenant resources and for the schema-level placeholder (which has no
@@ -214,10 +216,34 @@ forbid (
 /// at the query layer).
 fn tenant_guard_forbid_policy(schema: &SchemaDefinition) -> CedarPolicy {
     let name = schema.name.as_str();
+    let cross_tenant_read: &[String] = match schema.access_annotation() {
+        Some(Annotation::Access {
+            cross_tenant_read, ..
+        }) => cross_tenant_read,
+        _ => &[],
+    };
+
+    let mut description = format!(
+        "Tenant isolation: forbid per-record actions on {name} when the principal is not a member of the resource's tenant"
+    );
+    let mut exemption = String::new();
+    if !cross_tenant_read.is_empty() {
+        let groups: Vec<String> = cross_tenant_read
+            .iter()
+            .map(|role| format!("principal in Forge::Group::\"{role}\""))
+            .collect();
+        exemption = format!(
+            "\n    && !(action in [Action::\"Read{name}\", Action::\"List{name}\"] && ({}))",
+            groups.join(" || ")
+        );
+        description.push_str(&format!(
+            "; cross_tenant_read roles [{}] may read across tenants",
+            cross_tenant_read.join(", ")
+        ));
+    }
+
     CedarPolicy {
-        description: format!(
-            "Forbid per-record actions on {name} when the principal is not a member of the resource's tenant"
-        ),
+        description,
         cedar_text: format!(
             r#"@id("forge.{lname}.tenant_guard")
 forbid (
@@ -232,7 +258,7 @@ forbid (
 ) when {{
     resource has "_tenant"
     && !(principal in resource["_tenant"])
-    && !(principal in Forge::Group::"platform_admin")
+    && !(principal in Forge::Group::"platform_admin"){exemption}
 }};"#,
             lname = name.to_ascii_lowercase()
         ),
@@ -408,7 +434,8 @@ fn field_action_policies(
     if roles.is_empty() {
         return vec![CedarPolicy {
             description: format!(
-                "Allow any authenticated user to {label} field {field_name} on {schema_name}"
+                "Allow any authenticated user to {label} field {field_name} on {schema_name} \
+                 via {action_uid}"
             ),
             cedar_text: format!(
                 r#"@id("forge.{lname}.field_{field_name}_{label}_authenticated")
@@ -426,7 +453,8 @@ permit (
             let suffix = sanitize_id(role);
             CedarPolicy {
                 description: format!(
-                    "Allow Forge::Group::\"{role}\" to {label} field {field_name} on {schema_name}"
+                    "Allow Forge::Group::\"{role}\" to {label} field {field_name} on \
+                     {schema_name} via {action_uid}"
                 ),
                 cedar_text: format!(
                     r#"@id("forge.{lname}.field_{field_name}_{label}_{suffix}")
@@ -525,6 +553,24 @@ mod tests {
         .unwrap()
     }
 
+    fn make_cross_tenant_schema() -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Ledger").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("name").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            )],
+            vec![Annotation::Access {
+                read: vec!["member".into()],
+                write: vec!["member".into()],
+                delete: vec!["admin".into()],
+                cross_tenant_read: vec!["auditor".into(), "support".into()],
+            }],
+        )
+        .unwrap()
+    }
+
     fn make_field_access_schema() -> SchemaDefinition {
         SchemaDefinition::new(
             SchemaId::new(),
@@ -688,6 +734,44 @@ mod tests {
             .any(|p| p.cedar_text.contains("Forge::Group::\"hr\"")));
     }
 
+    #[test]
+    fn field_access_descriptions_name_field_and_action() {
+        let policies = generate_cedar_policies(&make_field_access_schema());
+        let field_policies: Vec<&CedarPolicy> = policies
+            .iter()
+            .filter(|p| p.cedar_text.contains("FieldEmployee_"))
+            .collect();
+        assert_eq!(field_policies.len(), 2);
+        assert!(field_policies.iter().any(|p| p.description
+            == "Allow Forge::Group::\"hr\" to read field salary on Employee \
+                via Action::\"ReadFieldEmployee_salary\""));
+        assert!(field_policies.iter().any(|p| p.description
+            == "Allow Forge::Group::\"hr\" to write field salary on Employee \
+                via Action::\"WriteFieldEmployee_salary\""));
+        // `name` carries no @field_access, so nothing is emitted for it.
+        assert!(!policies
+            .iter()
+            .any(|p| p.cedar_text.contains("FieldEmployee_name")));
+    }
+
+    #[test]
+    fn no_field_policies_without_field_access() {
+        for schema in [
+            make_test_schema(),
+            make_access_schema(&["viewer"], &["editor"], &["admin"]),
+        ] {
+            let policies = generate_cedar_policies(&schema);
+            assert!(
+                !policies
+                    .iter()
+                    .any(|p| p.cedar_text.contains("ReadField")
+                        || p.cedar_text.contains("WriteField")),
+                "schema {} must not emit field policies",
+                schema.name.as_str()
+            );
+        }
+    }
+
     #[test]
     fn tenant_guard_emitted_for_every_schema() {
         // Every schema gets the tenant guard — it stays inert for resources
@@ -748,6 +832,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tenant_guard_exempts_cross_tenant_read_roles_on_read_only() {
+        let policies = generate_cedar_policies(&make_cross_tenant_schema());
+        let guard = policies
+            .iter()
+            .find(|p| p.cedar_text.contains("tenant_guard"))
+            .expect("tenant guard must be emitted");
+        assert!(guard.cedar_text.contains(
+            "&& !(action in [Action::\"ReadLedger\", Action::\"ListLedger\"] \
+             && (principal in Forge::Group::\"auditor\" || principal in Forge::Group::\"support\"))"
+        ));
+        assert!(guard.description.starts_with("Tenant isolation:"));
+        assert!(guard
+            .description
+            .contains("cross_tenant_read roles [auditor, support] may read across tenants"));
+
+        // Without cross_tenant_read roles the guard carries no exemption.
+        let plain = generate_cedar_policies(&make_test_schema());
+        let guard = plain
+            .iter()
+            .find(|p| p.cedar_text.contains("tenant_guard"))
+            .unwrap();
+        assert!(!guard.cedar_text.contains("Forge::Group::\"auditor\""));
+        assert!(!guard.description.contains("cross_tenant_read"));
+    }
+
     #[test]
     fn full_policy_set_validates_against_generated_schema() {
         // The contract: generated schema + generated policies must pass
@@ -760,6 +870,7 @@ mod tests {
             make_owner_schema(),
             make_access_schema(&["viewer"], &["editor"], &["admin"]),
             make_field_access_schema(),
+            make_cross_tenant_schema(),
             make_user_schema(),
         ];
 
@@ -232,11 +232,14 @@ fn write_per_field_actions(
 /// attributes; policies cannot test them.
 fn cedar_type_for(ft: &FieldType) -> Option<String> {
     match ft {
-        FieldType::Text(_) | FieldType::RichText => Some("String".into()),
+        FieldType::Text(_) | FieldType::RichText(_) => Some("String".into()),
         FieldType::Integer(_) => Some("Long".into()),
         FieldType::Float(_) => Some("Long".into()),
+        FieldType::Decimal { .. } => Some("Long".into()),
         FieldType::Boolean => Some("Bool".into()),
         FieldType::DateTime => Some("Long".into()),
+        FieldType::Duration => Some("String".into()),
+        FieldType::Uuid => Some("String".into()),
         FieldType::Enum(_) => Some("String".into()),
         FieldType::Json => None,
         FieldType::Relation { cardinality, .. } => match cardinality {
@@ -244,7 +247,7 @@ fn cedar_type_for(ft: &FieldType) -> Option<String> {
             Cardinality::Many => Some("Set<String>".into()),
             _ => None,
         },
-        FieldType::Array(inner) => cedar_type_for(inner).map(|t| format!("Set<{t}>")),
+        FieldType::Array(inner, _) => cedar_type_for(inner).map(|t| format!("Set<{t}>")),
         FieldType::Composite(_) => None,
         FieldType::File(_) => Some("String".into()),
         _ => None,
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::authz::principal_claims::PrincipalClaimsConfig;
@@ -36,19 +38,41 @@ pub struct SchemaForgeSettings {
     #[serde(default)]
     pub storage: crate::storage::StorageConfig,
 
-    /// Authorization configuration. Currently exposes operator-defined
-    /// PASETO custom-claim → Cedar `Forge::Principal` attribute mappings;
-    /// see [`crate::authz::principal_claims`].
+    /// Entity-write audit trail settings.
+    #[serde(default)]
+    pub audit: crate::audit::AuditConfig,
+
+    /// Authorization configuration: operator-defined PASETO custom-claim →
+    /// Cedar `Forge::Principal` attribute mappings (see
+    /// [`crate::authz::principal_claims`]) and the role hierarchy.
     #[serde(default)]
     pub authz: AuthzConfig,
+
+    /// Whether list endpoints honor `?debug=timing` and report backend
+    /// timings in a `_timing` block. Defaults to on in debug builds and off
+    /// in release builds.
+    #[serde(default = "default_debug_timing")]
+    pub debug_timing: bool,
+
+    /// Whether PUT and PATCH on entities must carry an `If-Match` header
+    /// (answering 428 otherwise). When off, `If-Match` is still honored
+    /// whenever a client sends it.
+    #[serde(default)]
+    pub require_if_match: bool,
+
+    /// How long an `Idempotency-Key` on entity creation is remembered, in
+    /// seconds (default: 86400). A repeat of the same body within the
+    /// window replays the original entity instead of creating another.
+    #[serde(default = "default_idempotency_window_secs")]
+    pub idempotency_window_secs: u64,
 }
 
 /// `[schema_forge.authz]` section of config.toml.
 ///
-/// Holds operator-defined extensions to the authz pipeline. Currently houses
-/// only [`AuthzConfig::principal_claims`] but kept as its own section so
-/// future authz knobs (custom-policy reload cadence, audit-sink override,
-/// etc.) have a stable home.
+/// Holds operator-defined extensions to the authz pipeline: currently
+/// [`AuthzConfig::principal_claims`] and [`AuthzConfig::role_hierarchy`],
+/// kept as their own section so future authz knobs (custom-policy reload
+/// cadence, audit-sink override, etc.) have a stable home.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AuthzConfig {
     /// Map of Cedar attribute name → claim mapping. Empty by default;
@@ -56,12 +80,27 @@ pub struct AuthzConfig {
     /// subsections.
     #[serde(default)]
     pub principal_claims: PrincipalClaimsConfig,
+
+    /// Role inheritance from `[schema_forge.authz.role_hierarchy]`: each
+    /// role maps to the roles it implies (`editor = ["viewer"]`), applied
+    /// transitively during access checks. Empty by default, so `@access`
+    /// matches roles exactly.
+    #[serde(default)]
+    pub role_hierarchy: BTreeMap<String, Vec<String>>,
 }
 
 fn default_route_prefix() -> String {
     "/forge".to_string()
 }
 
+fn default_debug_timing() -> bool {
+    cfg!(debug_assertions)
+}
+
+fn default_idempotency_window_secs() -> u64 {
+    86_400
+}
+
 impl Default for SchemaForgeSettings {
     fn default() -> Self {
         Self {
@@ -70,7 +109,11 @@ impl Default for SchemaForgeSettings {
             webhooks: crate::webhook::WebhookConfig::default(),
             hooks: crate::hooks::HooksConfig::default(),
             storage: crate::storage::StorageConfig::default(),
+            audit: crate::audit::AuditConfig::default(),
             authz: AuthzConfig::default(),
+            debug_timing: default_debug_timing(),
+            require_if_match: false,
+            idempotency_window_secs: default_idempotency_window_secs(),
         }
     }
 }
@@ -95,7 +138,11 @@ mod tests {
                 webhooks: crate::webhook::WebhookConfig::default(),
                 hooks: crate::hooks::HooksConfig::default(),
                 storage: crate::storage::StorageConfig::default(),
+                audit: crate::audit::AuditConfig::default(),
                 authz: AuthzConfig::default(),
+                debug_timing: true,
+                require_if_match: true,
+                idempotency_window_secs: 600,
             },
         };
         let json = serde_json::to_string(&config).unwrap();
@@ -103,6 +150,9 @@ mod tests {
         assert_eq!(back.schema_forge.route_prefix, "/api/forge");
         assert!(back.schema_forge.auto_generate_cedar_policies);
         assert!(back.schema_forge.authz.principal_claims.is_empty());
+        assert!(back.schema_forge.debug_timing);
+        assert!(back.schema_forge.require_if_match);
+        assert_eq!(back.schema_forge.idempotency_window_secs, 600);
     }
 
     #[test]
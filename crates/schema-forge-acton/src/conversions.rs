@@ -21,6 +21,10 @@ pub fn dynamic_value_to_json(value: &DynamicValue) -> serde_json::Value {
         DynamicValue::DateTime(dt) => {
             serde_json::Value::String(dt.to_rfc3339_opts(SecondsFormat::Millis, true))
         }
+        DynamicValue::Duration(d) => serde_json::Value::String(d.to_string()),
+        DynamicValue::Uuid(u) => serde_json::Value::String(u.to_string()),
+        // A string keeps every digit; a JSON number would round-trip via f64.
+        DynamicValue::Decimal(d) => serde_json::Value::String(d.to_string()),
         DynamicValue::Enum(s) => serde_json::Value::String(s.clone()),
         DynamicValue::Json(v) => v.clone(),
         DynamicValue::Array(arr) => {
@@ -68,6 +72,7 @@ pub fn entity_to_response(entity: &Entity, schema: &SchemaDefinition) -> EntityR
         id: entity.id.as_str().to_string(),
         schema: entity.schema.as_str().to_string(),
         fields,
+        version: (entity.version > 0).then_some(entity.version),
         permissions: None,
     }
 }
@@ -0,0 +1,322 @@
+//! In-process entity lifecycle hooks.
+//!
+//! Where [`crate::hooks`] calls out to external gRPC services named in a
+//! schema's `@hook` declarations, an [`EntityHook`] is Rust code compiled
+//! into the server and registered with
+//! [`SchemaForgeExtensionBuilder::with_entity_hook`](crate::extension::SchemaForgeExtensionBuilder::with_entity_hook).
+//! Every registered hook sees every schema; hooks that care about one
+//! schema check [`EntityHookContext::schema`] and return early otherwise.
+//!
+//! The REST entity routes and the GraphQL mutations run hooks through the
+//! same [`EntityHooks`] registry, so a rollup or a status transition
+//! behaves identically whichever API the write arrives on:
+//!
+//! 1. **`before_*`** runs after the request is converted to fields and
+//!    after any `@hook` dispatch, before persistence. Hooks run in
+//!    registration order, may mutate the field map, and may abort the
+//!    write by returning a [`ForgeError`] — typically
+//!    [`ForgeError::HookAborted`] (422) or [`ForgeError::Forbidden`] (403).
+//! 2. **`after_*`** runs on a background task once the write has
+//!    committed. The response does not wait for it and cannot be changed
+//!    by it.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use acton_service::middleware::Claims;
+use async_trait::async_trait;
+use schema_forge_backend::entity::Entity;
+use schema_forge_core::types::{DynamicValue, EntityId, SchemaDefinition};
+
+use crate::error::ForgeError;
+
+/// What an [`EntityHook`] knows about the write it is observing.
+#[derive(Debug, Clone, Copy)]
+pub struct EntityHookContext<'a> {
+    /// Schema of the entity being written.
+    pub schema: &'a SchemaDefinition,
+    /// Claims of the caller, or `None` for unauthenticated writes.
+    pub claims: Option<&'a Claims>,
+}
+
+/// Rust code run around entity creates, updates, and deletes.
+///
+/// Every method has a no-op default, so an implementation only overrides
+/// the events it cares about.
+#[async_trait]
+pub trait EntityHook: Send + Sync {
+    /// Runs before a new entity is persisted. `fields` is the entity about
+    /// to be written; changes to it are persisted.
+    async fn before_create(
+        &self,
+        _ctx: EntityHookContext<'_>,
+        _fields: &mut BTreeMap<String, DynamicValue>,
+    ) -> Result<(), ForgeError> {
+        Ok(())
+    }
+
+    /// Runs after a new entity has been persisted.
+    async fn after_create(&self, _ctx: EntityHookContext<'_>, _entity: &Entity) {}
+
+    /// Runs before an existing entity is updated. `fields` is the full
+    /// post-update field set, for partial updates (`PATCH`, GraphQL
+    /// `update*`) as well as full replacements (`PUT`); changes to it are
+    /// persisted.
+    async fn before_update(
+        &self,
+        _ctx: EntityHookContext<'_>,
+        _id: &EntityId,
+        _fields: &mut BTreeMap<String, DynamicValue>,
+    ) -> Result<(), ForgeError> {
+        Ok(())
+    }
+
+    /// Runs after an entity update has been persisted.
+    async fn after_update(&self, _ctx: EntityHookContext<'_>, _entity: &Entity) {}
+
+    /// Runs before an entity is deleted.
+    async fn before_delete(
+        &self,
+        _ctx: EntityHookContext<'_>,
+        _id: &EntityId,
+    ) -> Result<(), ForgeError> {
+        Ok(())
+    }
+
+    /// Runs after an entity has been deleted.
+    async fn after_delete(&self, _ctx: EntityHookContext<'_>, _id: &EntityId) {}
+}
+
+/// The write an `after_*` hook is notified of.
+enum AfterEvent {
+    Created(Entity),
+    Updated(Entity),
+    Deleted(EntityId),
+}
+
+/// The registered [`EntityHook`]s, in registration order.
+///
+/// Cheap to clone; an empty registry costs one `is_empty` check per write.
+#[derive(Clone, Default)]
+pub struct EntityHooks {
+    hooks: Vec<Arc<dyn EntityHook>>,
+}
+
+impl std::fmt::Debug for EntityHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EntityHooks")
+            .field("len", &self.hooks.len())
+            .finish()
+    }
+}
+
+impl EntityHooks {
+    /// Append a hook. Hooks run in the order they were registered.
+    pub fn register(&mut self, hook: Arc<dyn EntityHook>) {
+        self.hooks.push(hook);
+    }
+
+    /// Number of registered hooks.
+    pub fn len(&self) -> usize {
+        self.hooks.len()
+    }
+
+    /// Returns `true` if no hooks are registered.
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Run every `before_create` hook, stopping at the first error.
+    pub async fn before_create(
+        &self,
+        schema: &SchemaDefinition,
+        claims: Option<&Claims>,
+        fields: &mut BTreeMap<String, DynamicValue>,
+    ) -> Result<(), ForgeError> {
+        let ctx = EntityHookContext { schema, claims };
+        for hook in &self.hooks {
+            hook.before_create(ctx, fields).await?;
+        }
+        Ok(())
+    }
+
+    /// Run every `before_update` hook, stopping at the first error.
+    pub async fn before_update(
+        &self,
+        schema: &SchemaDefinition,
+        claims: Option<&Claims>,
+        id: &EntityId,
+        fields: &mut BTreeMap<String, DynamicValue>,
+    ) -> Result<(), ForgeError> {
+        let ctx = EntityHookContext { schema, claims };
+        for hook in &self.hooks {
+            hook.before_update(ctx, id, fields).await?;
+        }
+        Ok(())
+    }
+
+    /// Run every `before_delete` hook, stopping at the first error.
+    pub async fn before_delete(
+        &self,
+        schema: &SchemaDefinition,
+        claims: Option<&Claims>,
+        id: &EntityId,
+    ) -> Result<(), ForgeError> {
+        let ctx = EntityHookContext { schema, claims };
+        for hook in &self.hooks {
+            hook.before_delete(ctx, id).await?;
+        }
+        Ok(())
+    }
+
+    /// Notify every `after_create` hook on a background task.
+    pub fn after_create(
+        &self,
+        schema: &SchemaDefinition,
+        claims: Option<&Claims>,
+        entity: &Entity,
+    ) {
+        self.spawn_after(schema, claims, AfterEvent::Created(entity.clone()));
+    }
+
+    /// Notify every `after_update` hook on a background task.
+    pub fn after_update(
+        &self,
+        schema: &SchemaDefinition,
+        claims: Option<&Claims>,
+        entity: &Entity,
+    ) {
+        self.spawn_after(schema, claims, AfterEvent::Updated(entity.clone()));
+    }
+
+    /// Notify every `after_delete` hook on a background task.
+    pub fn after_delete(&self, schema: &SchemaDefinition, claims: Option<&Claims>, id: &EntityId) {
+        self.spawn_after(schema, claims, AfterEvent::Deleted(id.clone()));
+    }
+
+    fn spawn_after(&self, schema: &SchemaDefinition, claims: Option<&Claims>, event: AfterEvent) {
+        if self.hooks.is_empty() {
+            return;
+        }
+        let hooks = self.hooks.clone();
+        let schema = schema.clone();
+        let claims = claims.cloned();
+        tokio::spawn(async move {
+            let ctx = EntityHookContext {
+                schema: &schema,
+                claims: claims.as_ref(),
+            };
+            for hook in &hooks {
+                match &event {
+                    AfterEvent::Created(entity) => hook.after_create(ctx, entity).await,
+                    AfterEvent::Updated(entity) => hook.after_update(ctx, entity).await,
+                    AfterEvent::Deleted(id) => hook.after_delete(ctx, id).await,
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schema_forge_core::types::{
+        FieldDefinition, FieldName, FieldType, SchemaId, SchemaName, TextConstraints,
+    };
+    use tokio::sync::mpsc;
+
+    fn schema() -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Task").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("status").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            )],
+            Vec::new(),
+        )
+        .unwrap()
+    }
+
+    /// Sets `status` on create and refuses to delete anything.
+    struct DefaultStatus;
+
+    #[async_trait]
+    impl EntityHook for DefaultStatus {
+        async fn before_create(
+            &self,
+            _ctx: EntityHookContext<'_>,
+            fields: &mut BTreeMap<String, DynamicValue>,
+        ) -> Result<(), ForgeError> {
+            fields
+                .entry("status".into())
+                .or_insert_with(|| DynamicValue::Text("open".into()));
+            Ok(())
+        }
+
+        async fn before_delete(
+            &self,
+            ctx: EntityHookContext<'_>,
+            _id: &EntityId,
+        ) -> Result<(), ForgeError> {
+            Err(ForgeError::HookAborted {
+                reason: format!("{} records cannot be deleted", ctx.schema.name.as_str()),
+            })
+        }
+    }
+
+    /// Reports every created entity's id on a channel.
+    struct Notify(mpsc::UnboundedSender<String>);
+
+    #[async_trait]
+    impl EntityHook for Notify {
+        async fn after_create(&self, _ctx: EntityHookContext<'_>, entity: &Entity) {
+            let _ = self.0.send(entity.id.as_str().to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn before_hooks_mutate_fields_and_abort() {
+        let mut hooks = EntityHooks::default();
+        hooks.register(Arc::new(DefaultStatus));
+        let schema = schema();
+
+        let mut fields = BTreeMap::new();
+        hooks
+            .before_create(&schema, None, &mut fields)
+            .await
+            .unwrap();
+        assert_eq!(fields["status"], DynamicValue::Text("open".into()));
+
+        let err = hooks
+            .before_delete(&schema, None, &EntityId::new("task"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ForgeError::HookAborted { reason } if reason.contains("Task")));
+    }
+
+    #[tokio::test]
+    async fn after_hooks_run_in_the_background() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut hooks = EntityHooks::default();
+        hooks.register(Arc::new(DefaultStatus));
+        hooks.register(Arc::new(Notify(tx)));
+        let schema = schema();
+
+        let entity = Entity::for_schema(&schema, BTreeMap::new());
+        hooks.after_create(&schema, None, &entity);
+        assert_eq!(rx.recv().await.unwrap(), entity.id.as_str());
+    }
+
+    #[tokio::test]
+    async fn empty_registry_is_a_no_op() {
+        let hooks = EntityHooks::default();
+        assert!(hooks.is_empty());
+        let mut fields = BTreeMap::new();
+        hooks
+            .before_update(&schema(), None, &EntityId::new("task"), &mut fields)
+            .await
+            .unwrap();
+        assert!(fields.is_empty());
+    }
+}
@@ -26,6 +26,12 @@ pub enum ForgeError {
         reason: &'static str,
         message: String,
     },
+    /// An `If-Match` precondition did not hold: the entity changed since
+    /// the client read it. Maps to 412.
+    PreconditionFailed { message: String },
+    /// A write that must be conditional arrived without `If-Match`.
+    /// Maps to 428.
+    PreconditionRequired { message: String },
     /// Request body failed validation. Maps to 422.
     ValidationFailed { details: Vec<String> },
     /// Invalid schema name (not PascalCase). Maps to 400.
@@ -63,6 +69,12 @@ impl fmt::Display for ForgeError {
             Self::Conflict { reason, message } => {
                 write!(f, "conflict ({reason}): {message}")
             }
+            Self::PreconditionFailed { message } => {
+                write!(f, "precondition failed: {message}")
+            }
+            Self::PreconditionRequired { message } => {
+                write!(f, "precondition required: {message}")
+            }
             Self::ValidationFailed { details } => {
                 write!(f, "validation failed: {}", details.join("; "))
             }
@@ -111,6 +123,8 @@ impl ForgeError {
         match self {
             Self::SchemaNotFound { .. } | Self::EntityNotFound { .. } => StatusCode::NOT_FOUND,
             Self::SchemaAlreadyExists { .. } | Self::Conflict { .. } => StatusCode::CONFLICT,
+            Self::PreconditionFailed { .. } => StatusCode::PRECONDITION_FAILED,
+            Self::PreconditionRequired { .. } => StatusCode::PRECONDITION_REQUIRED,
             Self::ValidationFailed { .. } => StatusCode::UNPROCESSABLE_ENTITY,
             Self::InvalidSchemaName { .. }
             | Self::InvalidEntityId { .. }
@@ -131,6 +145,8 @@ impl ForgeError {
             Self::EntityNotFound { .. } => "entity_not_found",
             Self::SchemaAlreadyExists { .. } => "schema_already_exists",
             Self::Conflict { .. } => "conflict",
+            Self::PreconditionFailed { .. } => "precondition_failed",
+            Self::PreconditionRequired { .. } => "precondition_required",
             Self::ValidationFailed { .. } => "validation_failed",
             Self::InvalidSchemaName { .. } => "invalid_schema_name",
             Self::InvalidEntityId { .. } => "invalid_entity_id",
@@ -210,6 +226,29 @@ impl From<BackendError> for ForgeError {
                     "type mismatch for field '{field}': expected {expected}, got {actual}"
                 )],
             },
+            BackendError::BatchItemFailed { index, reason } => Self::ValidationFailed {
+                details: vec![format!("entities[{index}]: {reason}")],
+            },
+            BackendError::Conflict {
+                entity_id,
+                expected,
+                actual,
+                ..
+            } => Self::PreconditionFailed {
+                message: format!(
+                    "entity '{entity_id}' was modified: If-Match version {expected}, current version {actual}"
+                ),
+            },
+            BackendError::UniqueConflict { schema, fields } => Self::Conflict {
+                reason: "unique_violation",
+                message: match fields.as_slice() {
+                    [field] => format!("{field} must be unique"),
+                    _ => format!(
+                        "another {schema} already has these values for ({})",
+                        fields.join(", ")
+                    ),
+                },
+            },
             BackendError::MigrationFailed { step, reason } => Self::Internal {
                 message: format!("migration step failed ({step}): {reason}"),
             },
@@ -432,6 +471,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_backend_batch_item_failed() {
+        let backend_err = BackendError::BatchItemFailed {
+            index: 3,
+            reason: "duplicate id".into(),
+        };
+        let forge_err: ForgeError = backend_err.into();
+        assert!(matches!(
+            forge_err,
+            ForgeError::ValidationFailed { details } if details == vec!["entities[3]: duplicate id"]
+        ));
+    }
+
+    #[test]
+    fn from_backend_conflict_is_precondition_failed() {
+        let backend_err = BackendError::Conflict {
+            schema: "Contact".into(),
+            entity_id: "contact_abc".into(),
+            expected: 1,
+            actual: 2,
+        };
+        let forge_err: ForgeError = backend_err.into();
+        assert!(matches!(forge_err, ForgeError::PreconditionFailed { .. }));
+        assert_eq!(forge_err.status_code(), StatusCode::PRECONDITION_FAILED);
+    }
+
+    #[test]
+    fn from_backend_unique_conflict_is_conflict() {
+        let backend_err = BackendError::UniqueConflict {
+            schema: "Deal".into(),
+            fields: vec!["agency".into(), "stage".into()],
+        };
+        let forge_err: ForgeError = backend_err.into();
+        assert!(matches!(
+            &forge_err,
+            ForgeError::Conflict { reason: "unique_violation", message }
+                if message == "another Deal already has these values for (agency, stage)"
+        ));
+        assert_eq!(forge_err.status_code(), StatusCode::CONFLICT);
+
+        let forge_err: ForgeError = BackendError::UniqueConflict {
+            schema: "Contact".into(),
+            fields: vec!["email".into()],
+        }
+        .into();
+        assert!(matches!(
+            &forge_err,
+            ForgeError::Conflict { message, .. } if message == "email must be unique"
+        ));
+    }
+
     #[test]
     fn from_backend_connection_error() {
         let backend_err = BackendError::ConnectionError {
@@ -0,0 +1,146 @@
+//! In-process change feed for entity writes.
+//!
+//! Every successful create, update, and delete publishes an [`EntityChange`]
+//! on the [`ChangeFeed`] owned by the [`ForgeActor`](crate::ForgeActor).
+//! `GET /schemas/{schema}/events` subscribes to it and streams the changes
+//! as server-sent events, applying the subscriber's tenant, record-access,
+//! and `@field_access` scoping to each one.
+//!
+//! The feed is a `tokio::sync::broadcast` channel: publishing never blocks
+//! the write path, and a subscriber that falls more than
+//! [`CHANGE_FEED_CAPACITY`] events behind skips the backlog rather than
+//! holding it in memory.
+
+use std::fmt;
+
+use schema_forge_backend::entity::Entity;
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+/// Number of changes buffered per subscriber before the oldest are dropped.
+pub const CHANGE_FEED_CAPACITY: usize = 1024;
+
+/// The kind of write an [`EntityChange`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeOperation {
+    /// A new entity was created.
+    Created,
+    /// An existing entity was updated (PUT or PATCH).
+    Updated,
+    /// An entity was deleted.
+    Deleted,
+}
+
+impl ChangeOperation {
+    /// Returns the lowercase name, matching the webhook event names.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Deleted => "deleted",
+        }
+    }
+}
+
+impl fmt::Display for ChangeOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A committed entity write.
+#[derive(Debug, Clone)]
+pub struct EntityChange {
+    /// Schema the entity belongs to.
+    pub schema: String,
+    /// What happened to the entity.
+    pub operation: ChangeOperation,
+    /// Identifier of the affected entity.
+    pub entity_id: String,
+    /// Unfiltered entity state: the stored entity after a create or update,
+    /// and the last known state (when one was fetched) before a delete.
+    ///
+    /// Subscribers use it for tenant and record-access checks; only creates
+    /// and updates expose its fields, after `@field_access` filtering.
+    pub entity: Option<Entity>,
+}
+
+/// Broadcast fan-out for [`EntityChange`]s.
+///
+/// Cheap to clone; all clones publish to the same subscribers.
+#[derive(Debug, Clone)]
+pub struct ChangeFeed {
+    sender: broadcast::Sender<EntityChange>,
+}
+
+impl Default for ChangeFeed {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(CHANGE_FEED_CAPACITY);
+        Self { sender }
+    }
+}
+
+impl ChangeFeed {
+    /// Publish a change to every current subscriber. A no-op when nobody
+    /// is listening.
+    pub fn publish(&self, change: EntityChange) {
+        // `send` only fails when there are no receivers, which is fine.
+        let _ = self.sender.send(change);
+    }
+
+    /// Register a new subscriber. It receives changes published from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<EntityChange> {
+        self.sender.subscribe()
+    }
+
+    /// Whether any subscriber is currently listening. Write paths use this
+    /// to skip work that only exists to feed subscribers.
+    pub fn has_subscribers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(operation: ChangeOperation) -> EntityChange {
+        EntityChange {
+            schema: "Contact".to_string(),
+            operation,
+            entity_id: "contact_01".to_string(),
+            entity: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn subscribers_receive_published_changes() {
+        let feed = ChangeFeed::default();
+        assert!(!feed.has_subscribers());
+
+        let mut rx = feed.subscribe();
+        assert!(feed.has_subscribers());
+
+        feed.clone().publish(change(ChangeOperation::Updated));
+        let received = rx.recv().await.unwrap();
+        assert_eq!(received.operation, ChangeOperation::Updated);
+        assert_eq!(received.entity_id, "contact_01");
+    }
+
+    #[test]
+    fn publish_without_subscribers_is_noop() {
+        ChangeFeed::default().publish(change(ChangeOperation::Created));
+    }
+
+    #[test]
+    fn operation_names_match_webhook_events() {
+        for op in [
+            ChangeOperation::Created,
+            ChangeOperation::Updated,
+            ChangeOperation::Deleted,
+        ] {
+            assert!(crate::webhook::VALID_EVENTS.contains(&op.as_str()));
+        }
+    }
+}
@@ -14,6 +14,7 @@ use crate::storage::{StorageConfig, StorageRegistry};
 use acton_service::state::AppState;
 use schema_forge_backend::auth::RecordAccessPolicy;
 use schema_forge_backend::tenant::TenantConfig;
+use schema_forge_core::migration::{DiffEngine, MigrationPlan};
 use schema_forge_core::types::SchemaDefinition;
 
 // ---------------------------------------------------------------------------
@@ -46,6 +47,10 @@ pub struct InitForgeData {
     /// route layer can project User columns into PASETO `custom` claims at
     /// request time without re-parsing the config.
     pub principal_claims: Arc<crate::authz::principal_claims::PrincipalClaimMappings>,
+    /// Optional sink for entity-write audit events.
+    pub audit_sink: Option<Arc<dyn crate::audit::AuditSink>>,
+    /// In-process hooks run around every entity write.
+    pub entity_hooks: crate::entity_hooks::EntityHooks,
 }
 
 // ---------------------------------------------------------------------------
@@ -72,6 +77,9 @@ pub struct SchemaForgeExtensionBuilder {
     storage_config: StorageConfig,
     role_ranks: crate::authz::role_ranks::RoleRanks,
     principal_claims: crate::authz::principal_claims::PrincipalClaimMappings,
+    audit_sink: Option<Arc<dyn crate::audit::AuditSink>>,
+    entity_hooks: crate::entity_hooks::EntityHooks,
+    change_feed: Option<crate::events::ChangeFeed>,
 }
 
 impl SchemaForgeExtensionBuilder {
@@ -86,6 +94,9 @@ impl SchemaForgeExtensionBuilder {
             storage_config: StorageConfig::default(),
             role_ranks: crate::authz::role_ranks::RoleRanks::empty(),
             principal_claims: crate::authz::principal_claims::PrincipalClaimMappings::default(),
+            audit_sink: None,
+            entity_hooks: crate::entity_hooks::EntityHooks::default(),
+            change_feed: None,
         }
     }
 
@@ -188,6 +199,33 @@ impl SchemaForgeExtensionBuilder {
         self
     }
 
+    /// Set the sink that receives an [`AuditEvent`](crate::audit::AuditEvent)
+    /// for every successful entity write. Defaults to no auditing.
+    pub fn with_audit_sink<A: crate::audit::AuditSink + 'static>(mut self, sink: A) -> Self {
+        self.audit_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Publish GraphQL writes on `feed`, e.g. the
+    /// [`ForgeActor`](crate::ForgeActor)'s feed (via
+    /// [`GetChangeFeed`](crate::messages::GetChangeFeed)) so event-stream
+    /// subscribers see them. Defaults to a feed of its own.
+    pub fn with_change_feed(mut self, feed: crate::events::ChangeFeed) -> Self {
+        self.change_feed = Some(feed);
+        self
+    }
+
+    /// Register an in-process [`EntityHook`](crate::entity_hooks::EntityHook)
+    /// run around every entity create, update, and delete. May be called
+    /// more than once; hooks run in registration order.
+    pub fn with_entity_hook<H: crate::entity_hooks::EntityHook + 'static>(
+        mut self,
+        hook: H,
+    ) -> Self {
+        self.entity_hooks.register(Arc::new(hook));
+        self
+    }
+
     /// Build the `SchemaForgeExtension`.
     ///
     /// Loads existing schemas from the backend into the in-memory registry.
@@ -316,6 +354,9 @@ impl SchemaForgeExtensionBuilder {
             auth_store: self.auth_store,
             webhook_dispatcher,
             storage_registry,
+            audit_sink: self.audit_sink,
+            entity_hooks: self.entity_hooks,
+            change_feed: self.change_feed.unwrap_or_default(),
         };
 
         Ok(SchemaForgeExtension { state })
@@ -466,6 +507,8 @@ impl SchemaForgeExtension {
             storage_registry,
             policy_store,
             principal_claims: principal_claims_arc,
+            audit_sink: None,
+            entity_hooks: crate::entity_hooks::EntityHooks::default(),
         })
     }
 
@@ -547,6 +590,127 @@ impl SchemaForgeExtension {
     pub fn state(&self) -> &ForgeState {
         &self.state
     }
+
+    /// Apply schema definitions programmatically, the same way the schema
+    /// routes do.
+    ///
+    /// Each definition is diffed against its registered version (or treated
+    /// as new), and the resulting migration plan is applied to the backend.
+    /// The metadata is then stored, the registry updated, and the Cedar
+    /// policy bundle and GraphQL schema rebuilt. Returns one plan per input,
+    /// in input order; re-applying an unchanged definition yields an empty
+    /// plan and performs no backend writes. When nothing in the batch
+    /// changed, the policy bundle and GraphQL schema are left untouched.
+    ///
+    /// The whole batch is validated before anything is written: plans with
+    /// destructive steps, `@version` regressions, invalid inverse relations,
+    /// and schemas the Cedar bundle cannot compile are all rejected up front.
+    pub async fn apply_schemas(
+        &self,
+        schemas: &[SchemaDefinition],
+    ) -> Result<Vec<MigrationPlan>, ForgeError> {
+        // Pair inverse relations against the registry with the incoming
+        // definitions replacing any registered ones of the same name.
+        let mut batch = self.state.registry.list().await;
+        batch.retain(|s| !schemas.iter().any(|n| n.name == s.name));
+        let existing_count = batch.len();
+        batch.extend(schemas.iter().cloned());
+        schema_forge_core::inverse_relations::pair_inverse_relations(&mut batch).map_err(|e| {
+            ForgeError::ValidationFailed {
+                details: vec![e.to_string()],
+            }
+        })?;
+
+        // Plan every definition before touching the backend.
+        let mut staged: Vec<(SchemaDefinition, MigrationPlan, bool)> =
+            Vec::with_capacity(schemas.len());
+        for definition in batch.iter_mut().skip(existing_count) {
+            let mut changed = true;
+            let plan = match self.state.registry.get(definition.name.as_str()).await {
+                Some(existing) => {
+                    definition.id = existing.id.clone();
+                    changed = existing != *definition;
+                    let plan = DiffEngine::diff(&existing, definition);
+                    DiffEngine::validate_system_schema_protection(&existing, &plan).map_err(
+                        |e| ForgeError::ValidationFailed {
+                            details: vec![e.to_string()],
+                        },
+                    )?;
+                    if !plan.is_empty() {
+                        definition.check_version_advances(&existing).map_err(|e| {
+                            ForgeError::Conflict {
+                                reason: "schema_version_not_increased",
+                                message: e.to_string(),
+                            }
+                        })?;
+                    }
+                    plan
+                }
+                None => DiffEngine::create_new(definition),
+            };
+            if plan.has_destructive_steps() {
+                return Err(ForgeError::Conflict {
+                    reason: "destructive_migration",
+                    message: format!(
+                        "migration for schema '{}' contains destructive steps; apply it with \
+                         the CLI instead",
+                        definition.name.as_str()
+                    ),
+                });
+            }
+            staged.push((definition.clone(), plan, changed));
+        }
+
+        if staged.iter().all(|(_, _, changed)| !changed) {
+            tracing::info!(schemas = staged.len(), "no changes");
+            return Ok(staged.into_iter().map(|(_, plan, _)| plan).collect());
+        }
+
+        // Dry-run the Cedar bundle for the resulting registry so an invalid
+        // schema fails before any DB migration runs.
+        let current = self.state.policy_store.current();
+        let snapshot = crate::authz::store::PolicyStoreSnapshot::from_schemas(
+            &batch,
+            None,
+            current.role_ranks.clone(),
+            current.principal_claims.clone(),
+        )
+        .map_err(|e| ForgeError::ValidationFailed {
+            details: vec![format!("Cedar policy compilation failed: {e}")],
+        })?;
+
+        let mut plans = Vec::with_capacity(staged.len());
+        for (definition, plan, changed) in staged {
+            if !changed {
+                tracing::info!(schema = definition.name.as_str(), "no changes");
+                plans.push(plan);
+                continue;
+            }
+            if !plan.is_empty() {
+                self.state
+                    .backend
+                    .apply_migration(&definition.name, &plan.steps)
+                    .await
+                    .map_err(ForgeError::from)?;
+            }
+            self.state
+                .backend
+                .store_schema_metadata(&definition)
+                .await
+                .map_err(ForgeError::from)?;
+            self.state
+                .registry
+                .insert(definition.name.as_str().to_string(), definition)
+                .await;
+            plans.push(plan);
+        }
+
+        self.state.policy_store.swap(snapshot);
+        #[cfg(feature = "graphql")]
+        crate::graphql::rebuild_graphql_schema(&self.state).await;
+
+        Ok(plans)
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,14 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
 use acton_service::middleware::Claims;
+use schema_forge_backend::entity::Entity;
+use schema_forge_backend::error::BackendError;
+use schema_forge_core::query::{FieldPath, Filter, Query};
+use schema_forge_core::types::{DynamicValue, EntityId, FieldType, SchemaDefinition};
+use tokio::sync::Mutex;
 
+use crate::access::inject_tenant_scope;
 use crate::state::ForgeState;
 
 /// Request-scoped context inserted into every async-graphql request via `.data()`.
@@ -8,4 +17,125 @@ use crate::state::ForgeState;
 pub struct ForgeGraphqlContext {
     pub state: ForgeState,
     pub claims: Option<Claims>,
+    /// Relation lookups batched across the whole request.
+    pub relations: RelationLoader,
+}
+
+impl ForgeGraphqlContext {
+    /// Create a context with an empty relation cache.
+    pub fn new(state: ForgeState, claims: Option<Claims>) -> Self {
+        Self {
+            state,
+            claims,
+            relations: RelationLoader::default(),
+        }
+    }
+
+    /// Fetch the `target` entities referenced by `ids`, in `ids` order.
+    ///
+    /// Any ids queued with [`RelationLoader::prime`] for the same target
+    /// are fetched in the same tenant-scoped query, so the sibling
+    /// resolvers of a listed page are served from the cache. Ids that do
+    /// not resolve are skipped.
+    pub async fn load_related(
+        &self,
+        target: &SchemaDefinition,
+        ids: &[EntityId],
+    ) -> Result<Vec<Entity>, BackendError> {
+        let batch = self.relations.batch(target.name.as_str()).await;
+        let (found, fetched) = {
+            let mut batch = batch.lock().await;
+            let mut wanted = std::mem::take(&mut batch.pending);
+            wanted.extend(ids.iter().map(|id| id.as_str().to_string()));
+            wanted.retain(|id| !batch.loaded.contains_key(id));
+
+            let mut fetched = Vec::new();
+            if !wanted.is_empty() {
+                let mut query = Query::new(target.id.clone())
+                    .with_filter(Filter::In {
+                        path: FieldPath::single("id"),
+                        values: wanted.iter().cloned().map(DynamicValue::Text).collect(),
+                    })
+                    .without_total_count();
+                inject_tenant_scope(&mut query, self.claims.as_ref(), &self.state.tenant_config);
+                let result = self.state.backend.query(&query).await?;
+                for id in wanted {
+                    batch.loaded.insert(id, None);
+                }
+                for entity in result.entities {
+                    batch
+                        .loaded
+                        .insert(entity.id.as_str().to_string(), Some(entity.clone()));
+                    fetched.push(entity);
+                }
+            }
+
+            let found: Vec<Entity> = ids
+                .iter()
+                .filter_map(|id| batch.loaded.get(id.as_str()).cloned().flatten())
+                .collect();
+            (found, fetched)
+        };
+
+        // Queue the next level of relations only after releasing this
+        // target's lock, so mutually related schemas cannot deadlock.
+        self.relations.prime(target, &fetched).await;
+        Ok(found)
+    }
+}
+
+/// Per-request cache of relation targets, keyed by target schema name.
+///
+/// List resolvers [`prime`](Self::prime) it with every id their page
+/// references; the first relation resolver for a target then loads all of
+/// them in one query instead of one `get` per parent.
+#[derive(Default)]
+pub struct RelationLoader {
+    batches: Mutex<HashMap<String, Arc<Mutex<RelationBatch>>>>,
+}
+
+#[derive(Default)]
+struct RelationBatch {
+    /// Ids referenced by resolved parents but not fetched yet.
+    pending: HashSet<String>,
+    /// Fetched ids; `None` marks an id the backend did not return.
+    loaded: HashMap<String, Option<Entity>>,
+}
+
+impl RelationLoader {
+    async fn batch(&self, target: &str) -> Arc<Mutex<RelationBatch>> {
+        self.batches
+            .lock()
+            .await
+            .entry(target.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Queue every id that `entities` reference through `schema`'s
+    /// relation fields, to be fetched with the next load of each target.
+    pub async fn prime(&self, schema: &SchemaDefinition, entities: &[Entity]) {
+        for field in &schema.fields {
+            let FieldType::Relation { target, .. } = &field.field_type else {
+                continue;
+            };
+            let mut ids = Vec::new();
+            for entity in entities {
+                match entity.field(field.name.as_str()) {
+                    Some(DynamicValue::Ref(id)) => ids.push(id.as_str().to_string()),
+                    Some(DynamicValue::RefArray(refs)) => {
+                        ids.extend(refs.iter().map(|id| id.as_str().to_string()))
+                    }
+                    _ => {}
+                }
+            }
+            if ids.is_empty() {
+                continue;
+            }
+            let batch = self.batch(target.as_str()).await;
+            let mut batch = batch.lock().await;
+            let RelationBatch { pending, loaded } = &mut *batch;
+            pending.extend(ids.into_iter().filter(|id| !loaded.contains_key(id)));
+        }
+    }
 }
@@ -2,10 +2,12 @@ use async_graphql::dynamic::{Enum, InputObject, InputValue, TypeRef};
 use async_graphql::indexmap;
 use async_graphql::Value as GqlValue;
 use schema_forge_core::query::{FieldPath, Filter};
-use schema_forge_core::types::{Cardinality, FieldType, SchemaDefinition};
+use schema_forge_core::types::{
+    parse_decimal, parse_uuid, Cardinality, FieldType, IsoDuration, SchemaDefinition,
+};
 
-use super::type_mapping::{gql_value_to_json, DATETIME_SCALAR, JSON_SCALAR};
-use crate::routes::entities::json_to_entity_fields;
+use super::type_mapping::{gql_value_to_json, DATETIME_SCALAR, ENTITY_ID_SCALAR, JSON_SCALAR};
+use crate::routes::entities::{json_to_entity_fields, rich_text_value};
 
 /// Shared SortOrder enum (registered once).
 pub const SORT_ORDER_ENUM: &str = "SortOrder";
@@ -17,12 +19,13 @@ pub fn build_sort_order_enum() -> Enum {
         .item(async_graphql::dynamic::EnumItem::new("DESC"))
 }
 
-/// Build a `Create{Schema}Input` input object.
+/// Build a `Create{Schema}Input` input object. `@computed` fields are
+/// read-only and left out.
 pub fn build_create_input(schema: &SchemaDefinition) -> InputObject {
     let name = format!("Create{}Input", schema.name.as_str());
     let mut input = InputObject::new(&name);
 
-    for field in &schema.fields {
+    for field in schema.fields.iter().filter(|f| !f.is_computed()) {
         let field_name = field.name.as_str();
         let required = field.is_required();
         let type_ref = input_field_type_ref(
@@ -37,12 +40,13 @@ pub fn build_create_input(schema: &SchemaDefinition) -> InputObject {
     input
 }
 
-/// Build an `Update{Schema}Input` input object (all fields nullable).
+/// Build an `Update{Schema}Input` input object (all fields nullable,
+/// `@computed` fields left out).
 pub fn build_update_input(schema: &SchemaDefinition) -> InputObject {
     let name = format!("Update{}Input", schema.name.as_str());
     let mut input = InputObject::new(&name);
 
-    for field in &schema.fields {
+    for field in schema.fields.iter().filter(|f| !f.is_computed()) {
         let field_name = field.name.as_str();
         let type_ref =
             input_field_type_ref(schema.name.as_str(), field_name, &field.field_type, false);
@@ -58,7 +62,7 @@ pub fn build_filter_input(schema: &SchemaDefinition) -> InputObject {
     let filter_name = format!("{schema_name}Filter");
     let mut input = InputObject::new(&filter_name);
 
-    for field in &schema.fields {
+    for field in schema.fields.iter().filter(|f| !f.is_computed()) {
         let field_name = field.name.as_str();
         let ops = filter_ops_for_type(&field.field_type);
         for op in ops {
@@ -80,7 +84,7 @@ pub fn build_filter_input(schema: &SchemaDefinition) -> InputObject {
 pub fn build_sort_field_enum(schema: &SchemaDefinition) -> Enum {
     let name = format!("{}SortField", schema.name.as_str());
     let mut e = Enum::new(&name);
-    for field in &schema.fields {
+    for field in schema.fields.iter().filter(|f| !f.is_computed()) {
         e = e.item(async_graphql::dynamic::EnumItem::new(field.name.as_str()));
     }
     e
@@ -99,7 +103,7 @@ pub fn build_sort_input(schema: &SchemaDefinition) -> InputObject {
         .field(InputValue::new("order", TypeRef::named(SORT_ORDER_ENUM)))
 }
 
-/// Map a FieldType to an input TypeRef. Similar to output but uses ID for relations.
+/// Map a FieldType to an input TypeRef. Similar to output but uses `EntityId` for relations.
 fn input_field_type_ref(
     schema_name: &str,
     field_name: &str,
@@ -107,7 +111,7 @@ fn input_field_type_ref(
     required: bool,
 ) -> TypeRef {
     let base = match ft {
-        FieldType::Text(_) | FieldType::RichText => TypeRef::named(TypeRef::STRING),
+        FieldType::Text(_) | FieldType::RichText(_) => TypeRef::named(TypeRef::STRING),
         FieldType::Integer(_) => TypeRef::named(TypeRef::INT),
         FieldType::Float(_) => TypeRef::named(TypeRef::FLOAT),
         FieldType::Boolean => TypeRef::named(TypeRef::BOOLEAN),
@@ -121,16 +125,16 @@ fn input_field_type_ref(
             return match cardinality {
                 Cardinality::One => {
                     if required {
-                        TypeRef::named_nn(TypeRef::ID)
+                        TypeRef::named_nn(ENTITY_ID_SCALAR)
                     } else {
-                        TypeRef::named(TypeRef::ID)
+                        TypeRef::named(ENTITY_ID_SCALAR)
                     }
                 }
-                Cardinality::Many => TypeRef::named_nn_list(TypeRef::ID),
-                _ => TypeRef::named(TypeRef::ID),
+                Cardinality::Many => TypeRef::named_nn_list(ENTITY_ID_SCALAR),
+                _ => TypeRef::named(ENTITY_ID_SCALAR),
             };
         }
-        FieldType::Array(inner) => {
+        FieldType::Array(inner, _) => {
             let inner_ref = input_field_type_ref(schema_name, field_name, inner, false);
             return if required {
                 // Wrap in list with non-null
@@ -153,10 +157,13 @@ fn input_field_type_ref(
 /// Get the filter operators supported by a given field type.
 fn filter_ops_for_type(ft: &FieldType) -> Vec<&'static str> {
     match ft {
-        FieldType::Text(_) | FieldType::RichText | FieldType::Enum(_) => {
+        FieldType::Text(_) | FieldType::RichText(_) | FieldType::Enum(_) => {
             vec!["eq", "ne", "contains", "starts_with", "in"]
         }
-        FieldType::Integer(_) | FieldType::Float(_) | FieldType::DateTime => {
+        FieldType::Integer(_)
+        | FieldType::Float(_)
+        | FieldType::Decimal { .. }
+        | FieldType::DateTime => {
             vec!["eq", "ne", "gt", "gte", "lt", "lte", "in"]
         }
         FieldType::Boolean => {
@@ -179,7 +186,7 @@ fn filter_value_type_ref(schema_name: &str, field_name: &str, ft: &FieldType, op
 /// Get the scalar type name for a filter value.
 fn scalar_type_for_filter(schema_name: &str, field_name: &str, ft: &FieldType) -> String {
     match ft {
-        FieldType::Text(_) | FieldType::RichText => TypeRef::STRING.to_string(),
+        FieldType::Text(_) | FieldType::RichText(_) => TypeRef::STRING.to_string(),
         FieldType::Integer(_) => TypeRef::INT.to_string(),
         FieldType::Float(_) => TypeRef::FLOAT.to_string(),
         FieldType::Boolean => TypeRef::BOOLEAN.to_string(),
@@ -370,6 +377,16 @@ fn coerce_filter_value(
             .as_f64()
             .map(DynamicValue::Float)
             .ok_or_else(|| format!("expected float, got {value}")),
+        Some(FieldType::Decimal { scale }) => {
+            let s = match value {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Number(n) => n.to_string(),
+                _ => return Err(format!("expected decimal string, got {value}")),
+            };
+            parse_decimal(&s, *scale)
+                .map(DynamicValue::Decimal)
+                .map_err(|e| e.to_string())
+        }
         Some(FieldType::Boolean) => value
             .as_bool()
             .map(DynamicValue::Boolean)
@@ -382,11 +399,27 @@ fn coerce_filter_value(
                 .map(DynamicValue::DateTime)
                 .map_err(|e| format!("invalid datetime '{s}': {e}"))
         }
+        Some(FieldType::Duration) => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| format!("expected duration string, got {value}"))?;
+            IsoDuration::parse_input(s)
+                .map(DynamicValue::Duration)
+                .map_err(|e| e.to_string())
+        }
+        Some(FieldType::Uuid) => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| format!("expected UUID string, got {value}"))?;
+            parse_uuid(s)
+                .map(DynamicValue::Uuid)
+                .map_err(|e| e.to_string())
+        }
         Some(FieldType::Enum(_)) => value
             .as_str()
             .map(|s| DynamicValue::Enum(s.to_string()))
             .ok_or_else(|| format!("expected enum string, got {value}")),
-        Some(FieldType::Text(_) | FieldType::RichText) => value
+        Some(FieldType::Text(_) | FieldType::RichText(_)) => value
             .as_str()
             .map(|s| DynamicValue::Text(s.to_string()))
             .ok_or_else(|| format!("expected string, got {value}")),
@@ -440,10 +473,23 @@ pub fn gql_input_to_partial_fields(
         let json_value = gql_value_to_json(value);
         let field_def = schema.field(&key_str);
 
-        let dv = if let Some(def) = field_def {
-            coerce_filter_value(&json_value, Some(&def.field_type))
-        } else {
-            coerce_filter_value(&json_value, None)
+        if let (Some(def), Some("")) = (field_def, json_value.as_str()) {
+            if schema.empty_as_null(def) {
+                if def.is_required() {
+                    errors.push(format!("required field '{key_str}' must not be empty"));
+                } else {
+                    fields.insert(key_str, schema_forge_core::types::DynamicValue::Null);
+                }
+                continue;
+            }
+        }
+
+        let field_type = field_def.map(|def| &def.field_type);
+        let dv = match (field_type, json_value.as_str()) {
+            (Some(FieldType::RichText(constraints)), Some(html)) => {
+                rich_text_value(html, constraints)
+            }
+            _ => coerce_filter_value(&json_value, field_type),
         };
 
         match dv {
@@ -23,10 +23,9 @@ pub async fn graphql_handler(
     req: GraphQLRequest,
 ) -> GraphQLResponse {
     let schema = state.graphql_schema.load();
-    let request = req.into_inner().data(ForgeGraphqlContext {
-        state: state.clone(),
-        claims,
-    });
+    let request = req
+        .into_inner()
+        .data(ForgeGraphqlContext::new(state.clone(), claims));
     schema.execute(request).await.into()
 }
 
@@ -52,6 +51,14 @@ pub async fn rebuild_graphql_schema(state: &ForgeState) {
     }
 }
 
+/// Serialize the live GraphQL schema to SDL text.
+///
+/// Reads the schema most recently installed by [`rebuild_graphql_schema`],
+/// so the output always matches what `POST /forge/graphql` serves.
+pub fn export_sdl(state: &ForgeState) -> String {
+    state.graphql_schema.load().sdl()
+}
+
 /// Create an empty GraphQL schema wrapped for `ForgeState`.
 ///
 /// Useful for constructing `ForgeState` in non-GraphQL contexts (e.g. AI agent)
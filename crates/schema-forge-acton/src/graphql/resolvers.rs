@@ -2,8 +2,8 @@ use std::collections::BTreeMap;
 
 use async_graphql::dynamic::{FieldValue, ResolverContext};
 use async_graphql::{ErrorExtensions, Value as GqlValue};
-use schema_forge_backend::entity::Entity;
-use schema_forge_core::query::{validate_filter, FieldPath, SortOrder};
+use schema_forge_backend::entity::{validate_fields, Entity};
+use schema_forge_core::query::{validate_filter, Cursor, FieldPath, Query, SortOrder};
 use schema_forge_core::types::{DynamicValue, EntityId, SchemaDefinition, SchemaName};
 
 use super::context::ForgeGraphqlContext;
@@ -11,12 +11,22 @@ use super::input_types::{
     filter_input_to_filter, gql_input_to_entity_fields, gql_input_to_partial_fields,
 };
 use crate::access::{
-    check_schema_access, filter_entity_fields, inject_tenant_on_create, inject_tenant_scope,
-    AccessAction, FieldFilterDirection,
+    can_write_hidden_fields, check_schema_access, filter_entity_fields, inject_tenant_on_create,
+    inject_tenant_scope, reject_soft_deleted, AccessAction, FieldFilterDirection,
 };
+use crate::actor::assign_sequence_defaults;
+use crate::audit::changed_fields;
 use crate::error::ForgeError;
+use crate::events::ChangeOperation;
+use crate::post_write::{CommittedWrite, WebhookTarget, WriteObservers};
+use crate::routes::entities::{
+    fill_missing_defaults, missing_required_fields, reject_immutable_changes,
+};
+use crate::state::ForgeState;
+use crate::webhook::WEBHOOK_SUBSCRIPTION_SCHEMA;
 
 /// Entity data stored in resolver parent values.
+#[derive(Clone)]
 pub struct EntityFields {
     pub id: EntityId,
     pub schema: SchemaName,
@@ -27,11 +37,16 @@ pub struct EntityFields {
 ///
 /// Mirrors the REST-side `reject_hidden_fields_in_body` guard so a
 /// password_hash (or any other operator-marked secret) can't be supplied
-/// through the GraphQL mutation surface either.
+/// through the GraphQL mutation surface either. Platform admins are exempt,
+/// as on the REST side.
 fn reject_hidden_input(
     schema_def: &SchemaDefinition,
     input: &async_graphql::indexmap::IndexMap<async_graphql::Name, GqlValue>,
+    claims: Option<&acton_service::middleware::Claims>,
 ) -> Result<(), ForgeError> {
+    if can_write_hidden_fields(claims) {
+        return Ok(());
+    }
     let offenders: Vec<String> = input
         .keys()
         .filter_map(|key| {
@@ -54,6 +69,25 @@ fn reject_hidden_input(
     }
 }
 
+/// Where GraphQL mutations report committed writes, the same post-write
+/// steps the REST entity routes run. Webhook subscribers are only resolved
+/// for schemas that declare `@webhook` while webhooks are enabled.
+async fn write_observers(state: &ForgeState, schema_def: &SchemaDefinition) -> WriteObservers {
+    let webhooks = match &state.webhook_dispatcher {
+        Some(dispatcher) if schema_def.has_webhooks() => Some(WebhookTarget {
+            dispatcher: dispatcher.as_ref().clone(),
+            backend: state.backend.clone(),
+            subscriptions: state.registry.get(WEBHOOK_SUBSCRIPTION_SCHEMA).await,
+        }),
+        _ => None,
+    };
+    WriteObservers {
+        audit_sink: state.audit_sink.clone(),
+        change_feed: Some(state.change_feed.clone()),
+        webhooks,
+    }
+}
+
 /// Convert ForgeError to async_graphql::Error with extension codes.
 pub fn forge_error_to_gql(err: ForgeError) -> async_graphql::Error {
     let code = match &err {
@@ -61,6 +95,7 @@ pub fn forge_error_to_gql(err: ForgeError) -> async_graphql::Error {
         ForgeError::Forbidden { .. } => "FORBIDDEN",
         ForgeError::Unauthorized { .. } => "UNAUTHORIZED",
         ForgeError::ValidationFailed { .. } => "VALIDATION_ERROR",
+        ForgeError::Conflict { .. } | ForgeError::PreconditionFailed { .. } => "CONFLICT",
         ForgeError::InvalidQuery { .. }
         | ForgeError::InvalidSchemaName { .. }
         | ForgeError::InvalidEntityId { .. } => "BAD_REQUEST",
@@ -90,13 +125,15 @@ pub async fn resolve_get_entity<'a>(
         })
     })?;
 
-    let entity_id = EntityId::parse(&id_arg)
+    let entity_id = schema_def
+        .parse_entity_id(&id_arg)
         .map_err(|_| forge_error_to_gql(ForgeError::InvalidEntityId { id: id_arg.clone() }))?;
 
     let mut entity = match gql_ctx.state.backend.get(&schema, &entity_id).await {
         Ok(e) => e,
         Err(e) => return Err(forge_error_to_gql(ForgeError::from(e))),
     };
+    reject_soft_deleted(schema_def, &entity, false).map_err(forge_error_to_gql)?;
 
     // Record-level visibility check
     if let (Some(ref policy), Some(c)) = (&gql_ctx.state.record_access_policy, claims) {
@@ -110,6 +147,7 @@ pub async fn resolve_get_entity<'a>(
         }
     }
 
+    entity.apply_computed(schema_def);
     entity.strip_hidden(schema_def);
     filter_entity_fields(
         &gql_ctx.state.policy_store,
@@ -122,32 +160,18 @@ pub async fn resolve_get_entity<'a>(
     Ok(Some(entity_to_field_value(entity, type_name)))
 }
 
-/// Resolve a list of entities with filter/sort/pagination.
-pub async fn resolve_list_entities<'a>(
-    ctx: &ResolverContext<'a>,
-    _schema_name: &str,
-    schema_def: &SchemaDefinition,
-    type_name: &str,
-) -> async_graphql::Result<Option<FieldValue<'a>>> {
-    let gql_ctx = ctx.data::<ForgeGraphqlContext>()?;
-    let claims = gql_ctx.claims.as_ref();
+/// Page size for a connection field queried without `first`, unless the
+/// schema declares `@list(page_size: N)`.
+pub const DEFAULT_CONNECTION_PAGE_SIZE: usize = 50;
 
-    check_schema_access(&gql_ctx.state.policy_store, schema_def, claims, AccessAction::Read)
-        .map_err(forge_error_to_gql)?;
-
-    let mut query = schema_forge_core::query::Query::new(schema_def.id.clone());
-
-    // Parse limit
-    if let Some(limit_val) = ctx.args.get("limit") {
-        let limit = limit_val.i64()? as usize;
-        query = query.with_limit(limit);
-    }
-
-    // Parse offset
-    if let Some(offset_val) = ctx.args.get("offset") {
-        let offset = offset_val.i64()? as usize;
-        query = query.with_offset(offset);
-    }
+/// Build the tenant-scoped query for a list field from its `filter` and
+/// `sort` arguments.
+fn list_query(
+    ctx: &ResolverContext<'_>,
+    schema_def: &SchemaDefinition,
+    gql_ctx: &ForgeGraphqlContext,
+) -> async_graphql::Result<Query> {
+    let mut query = Query::new(schema_def.id.clone());
 
     // Parse filter
     if let Some(filter_accessor) = ctx.args.get("filter") {
@@ -193,7 +217,77 @@ pub async fn resolve_list_entities<'a>(
     }
 
     // Inject tenant scope
-    inject_tenant_scope(&mut query, claims, &gql_ctx.state.tenant_config);
+    inject_tenant_scope(
+        &mut query,
+        gql_ctx.claims.as_ref(),
+        &gql_ctx.state.tenant_config,
+    );
+    Ok(query)
+}
+
+/// Drop entities the caller may not see under the record access policy.
+async fn visible_entities(
+    gql_ctx: &ForgeGraphqlContext,
+    schema_def: &SchemaDefinition,
+    entities: Vec<Entity>,
+) -> Vec<Entity> {
+    match (&gql_ctx.state.record_access_policy, gql_ctx.claims.as_ref()) {
+        (Some(policy), Some(c)) => policy.filter_visible(schema_def, c, entities).await,
+        _ => entities,
+    }
+}
+
+/// Strip `@hidden` and read-restricted fields from a listed entity.
+fn readable_fields(
+    gql_ctx: &ForgeGraphqlContext,
+    schema_def: &SchemaDefinition,
+    mut entity: Entity,
+) -> EntityFields {
+    entity.apply_computed(schema_def);
+    // Strip @hidden fields before any further processing — these
+    // must never reach a GraphQL response, even at debug.
+    entity.strip_hidden(schema_def);
+    filter_entity_fields(
+        &gql_ctx.state.policy_store,
+        &mut entity,
+        schema_def,
+        gql_ctx.claims.as_ref(),
+        FieldFilterDirection::Read,
+    );
+    EntityFields {
+        id: entity.id,
+        schema: entity.schema,
+        fields: entity.fields,
+    }
+}
+
+/// Resolve a flat list of entities with filter/sort/limit/offset.
+pub async fn resolve_list_entities<'a>(
+    ctx: &ResolverContext<'a>,
+    _schema_name: &str,
+    schema_def: &SchemaDefinition,
+    type_name: &str,
+) -> async_graphql::Result<Option<FieldValue<'a>>> {
+    let gql_ctx = ctx.data::<ForgeGraphqlContext>()?;
+    let claims = gql_ctx.claims.as_ref();
+
+    check_schema_access(&gql_ctx.state.policy_store, schema_def, claims, AccessAction::Read)
+        .map_err(forge_error_to_gql)?;
+
+    let mut query = list_query(ctx, schema_def, gql_ctx)?;
+
+    // Parse limit
+    if let Some(limit_val) = ctx.args.get("limit") {
+        let limit = limit_val.i64()? as usize;
+        query = query.with_limit(limit);
+    }
+
+    // Parse offset
+    if let Some(offset_val) = ctx.args.get("offset") {
+        let offset = offset_val.i64()? as usize;
+        query = query.with_offset(offset);
+    }
+    query.limit = schema_def.effective_limit(query.limit);
 
     let result = gql_ctx
         .state
@@ -202,58 +296,131 @@ pub async fn resolve_list_entities<'a>(
         .await
         .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
 
-    // Record-level access filtering
-    let visible_entities =
-        if let (Some(ref policy), Some(c)) = (&gql_ctx.state.record_access_policy, claims) {
-            policy.filter_visible(schema_def, c, result.entities).await
-        } else {
-            result.entities
-        };
+    let visible = visible_entities(gql_ctx, schema_def, result.entities).await;
+    gql_ctx.relations.prime(schema_def, &visible).await;
+    let items: Vec<EntityFields> = visible
+        .into_iter()
+        .map(|entity| readable_fields(gql_ctx, schema_def, entity))
+        .collect();
+
+    Ok(Some(FieldValue::owned_any(ListData {
+        count: items.len(),
+        items,
+        type_name: type_name.to_string(),
+        total_count: result.total_count,
+    })))
+}
+
+/// Resolve a Relay-style connection with filter/sort/first/after.
+///
+/// Pages with the query layer's keyset cursors. One row beyond `first` is
+/// fetched to tell whether another page follows.
+pub async fn resolve_connection<'a>(
+    ctx: &ResolverContext<'a>,
+    schema_def: &SchemaDefinition,
+    type_name: &str,
+) -> async_graphql::Result<Option<FieldValue<'a>>> {
+    let gql_ctx = ctx.data::<ForgeGraphqlContext>()?;
+    let claims = gql_ctx.claims.as_ref();
+
+    check_schema_access(
+        &gql_ctx.state.policy_store,
+        schema_def,
+        claims,
+        AccessAction::Read,
+    )
+    .map_err(forge_error_to_gql)?;
 
-    let count = visible_entities.len();
-    let total_count = result.total_count;
+    let mut query = list_query(ctx, schema_def, gql_ctx)?;
 
-    let items: Vec<EntityFields> = visible_entities
+    let first = match ctx.args.get("first") {
+        Some(first) => Some(usize::try_from(first.i64()?).map_err(|_| {
+            forge_error_to_gql(ForgeError::InvalidQuery {
+                message: "first must not be negative".into(),
+            })
+        })?),
+        None => None,
+    };
+    let first = schema_def
+        .effective_limit(first)
+        .unwrap_or(DEFAULT_CONNECTION_PAGE_SIZE);
+    if let Some(after) = ctx.args.get("after") {
+        let cursor = Cursor::decode(after.string()?).map_err(|e| {
+            forge_error_to_gql(ForgeError::InvalidQuery {
+                message: e.to_string(),
+            })
+        })?;
+        query = query.with_after(cursor);
+    }
+    query = query.with_limit(first + 1);
+
+    let mut result = gql_ctx
+        .state
+        .backend
+        .query(&query)
+        .await
+        .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
+    let has_next_page = result.entities.len() > first;
+    result.entities.truncate(first);
+
+    // Cursors are taken before record filtering and field scrubbing, so
+    // the end cursor still advances past rows the caller cannot see.
+    let end_cursor = result.entities.last().map(|e| e.cursor(&query).encode());
+    let visible = visible_entities(gql_ctx, schema_def, result.entities).await;
+    gql_ctx.relations.prime(schema_def, &visible).await;
+    let edges = visible
         .into_iter()
-        .map(|mut entity| {
-            // Strip @hidden fields before any further processing — these
-            // must never reach a GraphQL response, even at debug.
-            entity.strip_hidden(schema_def);
-            filter_entity_fields(
-                &gql_ctx.state.policy_store,
-                &mut entity,
-                schema_def,
-                claims,
-                FieldFilterDirection::Read,
-            );
-            EntityFields {
-                id: entity.id.clone(),
-                schema: entity.schema.clone(),
-                fields: entity.fields,
-            }
+        .map(|entity| EdgeData {
+            cursor: entity.cursor(&query).encode(),
+            node: readable_fields(gql_ctx, schema_def, entity),
         })
         .collect();
 
     Ok(Some(FieldValue::owned_any(ConnectionData {
-        items,
+        edges,
         type_name: type_name.to_string(),
-        count,
-        total_count,
+        page_info: PageInfoData {
+            has_next_page,
+            end_cursor,
+        },
+        total_count: result.total_count,
     })))
 }
 
-/// Data for a connection response.
-pub struct ConnectionData {
+/// Data for a flat list response.
+pub struct ListData {
     pub items: Vec<EntityFields>,
     pub type_name: String,
     pub count: usize,
     pub total_count: Option<usize>,
 }
 
+/// Data for a Relay connection response.
+pub struct ConnectionData {
+    pub edges: Vec<EdgeData>,
+    pub type_name: String,
+    pub page_info: PageInfoData,
+    pub total_count: Option<usize>,
+}
+
+/// One connection edge: a node and the cursor positioned at it.
+#[derive(Clone)]
+pub struct EdgeData {
+    pub node: EntityFields,
+    pub cursor: String,
+}
+
+/// Relay `PageInfo` for a connection page.
+#[derive(Clone)]
+pub struct PageInfoData {
+    pub has_next_page: bool,
+    pub end_cursor: Option<String>,
+}
+
 /// Resolve create entity mutation.
 pub async fn resolve_create_entity<'a>(
     ctx: &ResolverContext<'a>,
-    schema_name: &str,
+    _schema_name: &str,
     schema_def: &SchemaDefinition,
     type_name: &str,
 ) -> async_graphql::Result<Option<FieldValue<'a>>> {
@@ -267,7 +434,7 @@ pub async fn resolve_create_entity<'a>(
     let input_obj = input_accessor.object()?;
 
     let input_map = input_obj.as_index_map();
-    reject_hidden_input(schema_def, input_map).map_err(forge_error_to_gql)?;
+    reject_hidden_input(schema_def, input_map, claims).map_err(forge_error_to_gql)?;
 
     let mut fields = gql_input_to_entity_fields(input_map, schema_def)
         .map_err(|errors| forge_error_to_gql(ForgeError::ValidationFailed { details: errors }))?;
@@ -275,13 +442,14 @@ pub async fn resolve_create_entity<'a>(
     // Inject tenant
     inject_tenant_on_create(&mut fields, claims, &gql_ctx.state.tenant_config);
 
-    let schema = SchemaName::new(schema_name).map_err(|_| {
-        forge_error_to_gql(ForgeError::InvalidSchemaName {
-            name: schema_name.to_string(),
-        })
-    })?;
+    gql_ctx
+        .state
+        .entity_hooks
+        .before_create(schema_def, claims, &mut fields)
+        .await
+        .map_err(forge_error_to_gql)?;
 
-    let mut entity = Entity::new(schema, fields);
+    let mut entity = Entity::for_schema(schema_def, fields);
     filter_entity_fields(
         &gql_ctx.state.policy_store,
         &mut entity,
@@ -289,6 +457,15 @@ pub async fn resolve_create_entity<'a>(
         claims,
         FieldFilterDirection::Write,
     );
+    validate_fields(schema_def, &entity.fields)
+        .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
+    assign_sequence_defaults(
+        gql_ctx.state.backend.as_ref(),
+        Some(schema_def),
+        &mut entity,
+    )
+    .await
+    .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
 
     let mut created = gql_ctx
         .state
@@ -297,6 +474,23 @@ pub async fn resolve_create_entity<'a>(
         .await
         .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
 
+    fill_missing_defaults(schema_def, &mut created.fields);
+    created.apply_computed(schema_def);
+    gql_ctx
+        .state
+        .entity_hooks
+        .after_create(schema_def, claims, &created);
+    write_observers(&gql_ctx.state, schema_def)
+        .await
+        .notify(CommittedWrite {
+            schema: schema_def,
+            claims,
+            operation: ChangeOperation::Created,
+            entity_id: created.id.as_str(),
+            entity: Some(created.clone()),
+            changed_fields: changed_fields(schema_def, &BTreeMap::new(), &created.fields),
+        })
+        .await;
     created.strip_hidden(schema_def);
     filter_entity_fields(
         &gql_ctx.state.policy_store,
@@ -330,17 +524,22 @@ pub async fn resolve_update_entity<'a>(
         })
     })?;
 
-    let entity_id = EntityId::parse(&id_arg)
+    let entity_id = schema_def
+        .parse_entity_id(&id_arg)
         .map_err(|_| forge_error_to_gql(ForgeError::InvalidEntityId { id: id_arg.clone() }))?;
 
+    // The stored entity backs the ownership check, the `@immutable` guard,
+    // and the required-field check on the merged result.
+    let existing = gql_ctx
+        .state
+        .backend
+        .get(&schema, &entity_id)
+        .await
+        .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
+    reject_soft_deleted(schema_def, &existing, false).map_err(forge_error_to_gql)?;
+
     // Record-level ownership check
     if let (Some(ref policy), Some(c)) = (&gql_ctx.state.record_access_policy, claims) {
-        let existing = gql_ctx
-            .state
-            .backend
-            .get(&schema, &entity_id)
-            .await
-            .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
         if !policy.can_modify(schema_def, c, &existing).await {
             return Err(forge_error_to_gql(ForgeError::Forbidden {
                 message: format!("not authorized to modify entity '{id_arg}'"),
@@ -352,27 +551,70 @@ pub async fn resolve_update_entity<'a>(
     let input_obj = input_accessor.object()?;
 
     let input_map = input_obj.as_index_map();
-    reject_hidden_input(schema_def, input_map).map_err(forge_error_to_gql)?;
+    reject_hidden_input(schema_def, input_map, claims).map_err(forge_error_to_gql)?;
 
     let fields = gql_input_to_partial_fields(input_map, schema_def)
         .map_err(|errors| forge_error_to_gql(ForgeError::ValidationFailed { details: errors }))?;
 
-    let mut entity = Entity::with_id(entity_id, schema, fields);
+    // Like REST `PATCH`, fields left out of the input keep their stored
+    // values; only the supplied ones, and any an entity hook changed, are
+    // written.
+    let mut merged = existing.fields.clone();
+    merged.extend(fields.clone());
+    gql_ctx
+        .state
+        .entity_hooks
+        .before_update(schema_def, claims, &entity_id, &mut merged)
+        .await
+        .map_err(forge_error_to_gql)?;
+
+    reject_immutable_changes(schema_def, &existing.fields, &merged).map_err(forge_error_to_gql)?;
+
+    let missing = missing_required_fields(schema_def, &merged);
+    if !missing.is_empty() {
+        return Err(forge_error_to_gql(ForgeError::ValidationFailed {
+            details: missing,
+        }));
+    }
+
+    let changes = merged
+        .into_iter()
+        .filter(|(k, v)| fields.contains_key(k) || existing.fields.get(k) != Some(v))
+        .collect();
+    let mut changes = Entity::with_id(entity_id, schema, changes);
     filter_entity_fields(
         &gql_ctx.state.policy_store,
-        &mut entity,
+        &mut changes,
         schema_def,
         claims,
         FieldFilterDirection::Write,
     );
+    validate_fields(schema_def, &changes.fields)
+        .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
 
     let mut updated = gql_ctx
         .state
         .backend
-        .update(&entity)
+        .patch(&changes.schema, &changes.id, changes.fields, None)
         .await
         .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
 
+    updated.apply_computed(schema_def);
+    gql_ctx
+        .state
+        .entity_hooks
+        .after_update(schema_def, claims, &updated);
+    write_observers(&gql_ctx.state, schema_def)
+        .await
+        .notify(CommittedWrite {
+            schema: schema_def,
+            claims,
+            operation: ChangeOperation::Updated,
+            entity_id: updated.id.as_str(),
+            entity: Some(updated.clone()),
+            changed_fields: changed_fields(schema_def, &existing.fields, &updated.fields),
+        })
+        .await;
     updated.strip_hidden(schema_def);
     filter_entity_fields(
         &gql_ctx.state.policy_store,
@@ -410,10 +652,13 @@ pub async fn resolve_delete_entity(
         })
     })?;
 
-    let entity_id = EntityId::parse(&id_arg)
+    let entity_id = schema_def
+        .parse_entity_id(&id_arg)
         .map_err(|_| forge_error_to_gql(ForgeError::InvalidEntityId { id: id_arg.clone() }))?;
 
-    // Record-level ownership check
+    // Record-level ownership check. The fetched entity is also the last
+    // known state reported once the delete commits.
+    let mut snapshot = None;
     if let (Some(ref policy), Some(c)) = (&gql_ctx.state.record_access_policy, claims) {
         let entity = gql_ctx
             .state
@@ -426,24 +671,54 @@ pub async fn resolve_delete_entity(
                 message: format!("not authorized to delete entity '{id_arg}'"),
             }));
         }
+        snapshot = Some(entity);
+    }
+    // Event-stream subscribers are scoped by tenant and record access, so
+    // they need the entity's last state.
+    if snapshot.is_none() && gql_ctx.state.change_feed.has_subscribers() {
+        snapshot = gql_ctx.state.backend.get(&schema, &entity_id).await.ok();
     }
 
+    gql_ctx
+        .state
+        .entity_hooks
+        .before_delete(schema_def, claims, &entity_id)
+        .await
+        .map_err(forge_error_to_gql)?;
+
     gql_ctx
         .state
         .backend
         .delete(&schema, &entity_id)
         .await
         .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
+    gql_ctx
+        .state
+        .entity_hooks
+        .after_delete(schema_def, claims, &entity_id);
+    write_observers(&gql_ctx.state, schema_def)
+        .await
+        .notify(CommittedWrite {
+            schema: schema_def,
+            claims,
+            operation: ChangeOperation::Deleted,
+            entity_id: entity_id.as_str(),
+            entity: snapshot,
+            changed_fields: Vec::new(),
+        })
+        .await;
 
     Ok(GqlValue::Boolean(true))
 }
 
 /// Resolve a relation field (Cardinality::One).
+///
+/// Served by the request's relation loader, so a listed page resolves
+/// each target schema in one batched query.
 pub async fn resolve_relation_one<'a>(
     ctx: &ResolverContext<'a>,
     parent: &EntityFields,
     field_name: &str,
-    target_schema_name: &str,
     target_schema_def: &SchemaDefinition,
     target_type_name: &str,
 ) -> async_graphql::Result<Option<FieldValue<'a>>> {
@@ -464,17 +739,16 @@ pub async fn resolve_relation_one<'a>(
     )
     .map_err(forge_error_to_gql)?;
 
-    let target_schema = SchemaName::new(target_schema_name).map_err(|_| {
-        forge_error_to_gql(ForgeError::InvalidSchemaName {
-            name: target_schema_name.to_string(),
-        })
-    })?;
-
-    let mut entity = match gql_ctx.state.backend.get(&target_schema, &ref_id).await {
-        Ok(e) => e,
-        Err(_) => return Ok(None),
+    let loaded = gql_ctx
+        .load_related(target_schema_def, std::slice::from_ref(&ref_id))
+        .await
+        .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
+    let Some(mut entity) = loaded.into_iter().next() else {
+        return Ok(None);
     };
 
+    entity.apply_computed(target_schema_def);
+    entity.strip_hidden(target_schema_def);
     filter_entity_fields(
         &gql_ctx.state.policy_store,
         &mut entity,
@@ -487,11 +761,13 @@ pub async fn resolve_relation_one<'a>(
 }
 
 /// Resolve a relation field (Cardinality::Many).
+///
+/// Batched through the request's relation loader like
+/// [`resolve_relation_one`]; unresolvable ids are skipped.
 pub async fn resolve_relation_many<'a>(
     ctx: &ResolverContext<'a>,
     parent: &EntityFields,
     field_name: &str,
-    target_schema_name: &str,
     target_schema_def: &SchemaDefinition,
     target_type_name: &str,
 ) -> async_graphql::Result<Option<FieldValue<'a>>> {
@@ -514,15 +790,15 @@ pub async fn resolve_relation_many<'a>(
     )
     .map_err(forge_error_to_gql)?;
 
-    let target_schema = SchemaName::new(target_schema_name).map_err(|_| {
-        forge_error_to_gql(ForgeError::InvalidSchemaName {
-            name: target_schema_name.to_string(),
-        })
-    })?;
+    let entities = gql_ctx
+        .load_related(target_schema_def, &ref_ids)
+        .await
+        .map_err(|e| forge_error_to_gql(ForgeError::from(e)))?;
 
-    let mut results = Vec::new();
-    for ref_id in ref_ids {
-        if let Ok(mut entity) = gql_ctx.state.backend.get(&target_schema, &ref_id).await {
+    let results = entities
+        .into_iter()
+        .map(|mut entity| {
+            entity.apply_computed(target_schema_def);
             entity.strip_hidden(target_schema_def);
             filter_entity_fields(
                 &gql_ctx.state.policy_store,
@@ -531,9 +807,9 @@ pub async fn resolve_relation_many<'a>(
                 claims,
                 FieldFilterDirection::Read,
             );
-            results.push(entity_to_field_value(entity, target_type_name));
-        }
-    }
+            entity_to_field_value(entity, target_type_name)
+        })
+        .collect::<Vec<_>>();
 
     Ok(Some(FieldValue::list(results)))
 }
@@ -594,6 +870,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn forge_error_to_gql_conflict() {
+        let err = ForgeError::PreconditionFailed {
+            message: "stale".into(),
+        };
+        let gql_err = forge_error_to_gql(err);
+        assert_eq!(extension_code(&gql_err).as_deref(), Some("CONFLICT"));
+    }
+
     #[test]
     fn forge_error_to_gql_bad_request() {
         let err = ForgeError::InvalidQuery {
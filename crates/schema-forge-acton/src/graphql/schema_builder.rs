@@ -8,22 +8,27 @@ use schema_forge_core::types::{Cardinality, FieldType, SchemaDefinition};
 
 use super::input_types::{
     build_create_input, build_filter_input, build_sort_field_enum, build_sort_input,
-    build_sort_order_enum, build_update_input,
+    build_sort_order_enum, build_update_input, SORT_ORDER_ENUM,
 };
 use super::resolvers::{
-    resolve_create_entity, resolve_delete_entity, resolve_get_entity, resolve_list_entities,
-    resolve_relation_many, resolve_relation_one, resolve_update_entity, ConnectionData,
-    EntityFields,
+    resolve_connection, resolve_create_entity, resolve_delete_entity, resolve_get_entity,
+    resolve_list_entities, resolve_relation_many, resolve_relation_one, resolve_update_entity,
+    ConnectionData, EdgeData, EntityFields, ListData, PageInfoData,
 };
 use super::type_mapping::{
-    dynamic_value_to_gql_value, field_type_to_type_ref, DATETIME_SCALAR, INT64_SCALAR, JSON_SCALAR,
+    dynamic_value_to_gql_value, entity_id_scalar, field_type_to_type_ref, schema_name_scalar,
+    DATETIME_SCALAR, ENTITY_ID_SCALAR, INT64_SCALAR, JSON_SCALAR, SCHEMA_NAME_SCALAR,
 };
 
+/// Name of the Relay `PageInfo` type shared by every connection.
+const PAGE_INFO_TYPE: &str = "PageInfo";
+
 /// Build a dynamic GraphQL schema from the given schema definitions.
 ///
 /// Skips system schemas. Registers query/mutation fields for each non-system schema.
 pub fn build_graphql_schema(schemas: &[SchemaDefinition]) -> Result<Schema, String> {
     let non_system: Vec<&SchemaDefinition> = schemas.iter().filter(|s| !s.is_system()).collect();
+    check_reserved_names(&non_system)?;
 
     // Build a lookup map for relation resolvers
     let schema_map: HashMap<String, &SchemaDefinition> = schemas
@@ -45,14 +50,20 @@ pub fn build_graphql_schema(schemas: &[SchemaDefinition]) -> Result<Schema, Stri
         dynamic::Type::Scalar(
             Scalar::new(INT64_SCALAR).description("64-bit integer serialized as string"),
         ),
+        dynamic::Type::Scalar(entity_id_scalar()),
+        dynamic::Type::Scalar(schema_name_scalar()),
         // Shared SortOrder enum
         dynamic::Type::Enum(build_sort_order_enum()),
+        // Shared Relay page info
+        dynamic::Type::Object(build_page_info_type()),
     ];
 
     for schema_def in &non_system {
         let schema_name = schema_def.name.as_str().to_string();
         let type_name = schema_name.clone();
         let connection_type_name = format!("{schema_name}Connection");
+        let edge_type_name = format!("{schema_name}Edge");
+        let list_type_name = format!("{schema_name}List");
         let filter_type_name = format!("{schema_name}Filter");
         let sort_input_name = format!("{schema_name}SortInput");
         let create_input_name = format!("Create{schema_name}Input");
@@ -62,9 +73,13 @@ pub fn build_graphql_schema(schemas: &[SchemaDefinition]) -> Result<Schema, Stri
         let object_type = build_output_type(schema_def, &schema_map, &type_name)?;
         types_to_register.push(dynamic::Type::Object(object_type));
 
-        // 2. Build connection type
-        let conn_type = build_connection_type(&connection_type_name, &type_name);
+        // 2. Build connection, edge, and flat list types
+        let (conn_type, edge_type) =
+            build_connection_types(&connection_type_name, &edge_type_name, &type_name);
         types_to_register.push(dynamic::Type::Object(conn_type));
+        types_to_register.push(dynamic::Type::Object(edge_type));
+        let list_type = build_list_type(&list_type_name, &type_name);
+        types_to_register.push(dynamic::Type::Object(list_type));
 
         // 3. Build input types
         types_to_register.push(dynamic::Type::InputObject(build_create_input(schema_def)));
@@ -100,22 +115,24 @@ pub fn build_graphql_schema(schemas: &[SchemaDefinition]) -> Result<Schema, Stri
                         inner_field.is_required(),
                     );
                     let field_name_owned = inner_field_name.clone();
-                    nested_obj =
-                        nested_obj.field(Field::new(&inner_field_name, inner_tr, move |ctx| {
-                            let field_name = field_name_owned.clone();
-                            FieldFuture::new(async move {
-                                let parent = ctx
-                                    .parent_value
-                                    .try_downcast_ref::<async_graphql::Value>()
-                                    .ok();
-                                if let Some(async_graphql::Value::Object(map)) = parent {
-                                    if let Some(val) = map.get(field_name.as_str()) {
-                                        return Ok(Some(FieldValue::value(val.clone())));
-                                    }
+                    let inner_deprecation = inner_field.deprecation();
+                    let nested_field = Field::new(&inner_field_name, inner_tr, move |ctx| {
+                        let field_name = field_name_owned.clone();
+                        FieldFuture::new(async move {
+                            let parent = ctx
+                                .parent_value
+                                .try_downcast_ref::<async_graphql::Value>()
+                                .ok();
+                            if let Some(async_graphql::Value::Object(map)) = parent {
+                                if let Some(val) = map.get(field_name.as_str()) {
+                                    return Ok(Some(FieldValue::value(val.clone())));
                                 }
-                                Ok(None)
-                            })
-                        }));
+                            }
+                            Ok(None)
+                        })
+                    });
+                    nested_obj =
+                        nested_obj.field(with_deprecation(nested_field, inner_deprecation));
                 }
                 types_to_register.push(dynamic::Type::Object(nested_obj));
             }
@@ -123,9 +140,11 @@ pub fn build_graphql_schema(schemas: &[SchemaDefinition]) -> Result<Schema, Stri
 
         // 6. Build query fields
         let get_field_name = lcfirst(&schema_name);
-        let list_field_name = pluralize(&get_field_name);
+        let connection_field_name = pluralize(&get_field_name);
+        let list_field_name = format!("{connection_field_name}List");
 
         query_field_names.push((get_field_name.clone(), schema_name.clone()));
+        query_field_names.push((connection_field_name.clone(), schema_name.clone()));
         query_field_names.push((list_field_name.clone(), schema_name.clone()));
 
         // Get by ID
@@ -133,43 +152,64 @@ pub fn build_graphql_schema(schemas: &[SchemaDefinition]) -> Result<Schema, Stri
             let sn = schema_name.clone();
             let sd = Arc::new((*schema_def).clone());
             let tn = type_name.clone();
-            query = query.field(
-                Field::new(&get_field_name, TypeRef::named(&type_name), move |ctx| {
-                    let sn = sn.clone();
+            let get_field = Field::new(&get_field_name, TypeRef::named(&type_name), move |ctx| {
+                let sn = sn.clone();
+                let sd = sd.clone();
+                let tn = tn.clone();
+                FieldFuture::new(async move { resolve_get_entity(&ctx, &sn, &sd, &tn).await })
+            })
+            .argument(InputValue::new("id", TypeRef::named_nn(ENTITY_ID_SCALAR)));
+            query = query.field(with_deprecation(get_field, schema_def.deprecation()));
+        }
+
+        // Relay connection
+        {
+            let sd = Arc::new((*schema_def).clone());
+            let tn = type_name.clone();
+            let connection_field = Field::new(
+                &connection_field_name,
+                TypeRef::named_nn(&connection_type_name),
+                move |ctx| {
                     let sd = sd.clone();
                     let tn = tn.clone();
-                    FieldFuture::new(async move { resolve_get_entity(&ctx, &sn, &sd, &tn).await })
-                })
-                .argument(InputValue::new("id", TypeRef::named_nn(TypeRef::ID))),
-            );
+                    FieldFuture::new(async move { resolve_connection(&ctx, &sd, &tn).await })
+                },
+            )
+            .argument(InputValue::new("filter", TypeRef::named(&filter_type_name)))
+            .argument(InputValue::new(
+                "sort",
+                TypeRef::named_list(&sort_input_name),
+            ))
+            .argument(InputValue::new("first", TypeRef::named(TypeRef::INT)))
+            .argument(InputValue::new("after", TypeRef::named(TypeRef::STRING)));
+            query = query.field(with_deprecation(connection_field, schema_def.deprecation()));
         }
 
-        // List/query
+        // Flat list with limit/offset
         {
             let sn = schema_name.clone();
             let sd = Arc::new((*schema_def).clone());
             let tn = type_name.clone();
-            query = query.field(
-                Field::new(
-                    &list_field_name,
-                    TypeRef::named_nn(&connection_type_name),
-                    move |ctx| {
-                        let sn = sn.clone();
-                        let sd = sd.clone();
-                        let tn = tn.clone();
-                        FieldFuture::new(
-                            async move { resolve_list_entities(&ctx, &sn, &sd, &tn).await },
-                        )
-                    },
-                )
-                .argument(InputValue::new("filter", TypeRef::named(&filter_type_name)))
-                .argument(InputValue::new(
-                    "sort",
-                    TypeRef::named_list(&sort_input_name),
-                ))
-                .argument(InputValue::new("limit", TypeRef::named(TypeRef::INT)))
-                .argument(InputValue::new("offset", TypeRef::named(TypeRef::INT))),
-            );
+            let list_field = Field::new(
+                &list_field_name,
+                TypeRef::named_nn(&list_type_name),
+                move |ctx| {
+                    let sn = sn.clone();
+                    let sd = sd.clone();
+                    let tn = tn.clone();
+                    FieldFuture::new(
+                        async move { resolve_list_entities(&ctx, &sn, &sd, &tn).await },
+                    )
+                },
+            )
+            .argument(InputValue::new("filter", TypeRef::named(&filter_type_name)))
+            .argument(InputValue::new(
+                "sort",
+                TypeRef::named_list(&sort_input_name),
+            ))
+            .argument(InputValue::new("limit", TypeRef::named(TypeRef::INT)))
+            .argument(InputValue::new("offset", TypeRef::named(TypeRef::INT)));
+            query = query.field(with_deprecation(list_field, schema_def.deprecation()));
         }
 
         // 7. Build mutation fields
@@ -211,7 +251,7 @@ pub fn build_graphql_schema(schemas: &[SchemaDefinition]) -> Result<Schema, Stri
                         async move { resolve_update_entity(&ctx, &sn, &sd, &tn).await },
                     )
                 })
-                .argument(InputValue::new("id", TypeRef::named_nn(TypeRef::ID)))
+                .argument(InputValue::new("id", TypeRef::named_nn(ENTITY_ID_SCALAR)))
                 .argument(InputValue::new(
                     "input",
                     TypeRef::named_nn(&update_input_name),
@@ -237,7 +277,7 @@ pub fn build_graphql_schema(schemas: &[SchemaDefinition]) -> Result<Schema, Stri
                         })
                     },
                 )
-                .argument(InputValue::new("id", TypeRef::named_nn(TypeRef::ID))),
+                .argument(InputValue::new("id", TypeRef::named_nn(ENTITY_ID_SCALAR))),
             );
         }
     }
@@ -289,15 +329,19 @@ fn build_output_type(
     let schema_name = schema_def.name.as_str().to_string();
     let mut obj = Object::new(type_name);
 
-    // Always include `id: ID!`
-    obj = obj.field(Field::new("id", TypeRef::named_nn(TypeRef::ID), |ctx| {
-        FieldFuture::new(async move {
-            let ef = ctx.parent_value.try_downcast_ref::<EntityFields>()?;
-            Ok(Some(FieldValue::value(async_graphql::Value::String(
-                ef.id.as_str().to_string(),
-            ))))
-        })
-    }));
+    // Always include `id: EntityId!`
+    obj = obj.field(Field::new(
+        "id",
+        TypeRef::named_nn(ENTITY_ID_SCALAR),
+        |ctx| {
+            FieldFuture::new(async move {
+                let ef = ctx.parent_value.try_downcast_ref::<EntityFields>()?;
+                Ok(Some(FieldValue::value(async_graphql::Value::String(
+                    ef.id.as_str().to_string(),
+                ))))
+            })
+        },
+    ));
 
     // Per-field resolvers
     for field_def in &schema_def.fields {
@@ -311,7 +355,6 @@ fn build_output_type(
                 cardinality,
             } => {
                 let target_name = target.as_str().to_string();
-                let target_type = target_name.clone();
                 // Relation type ref is always nullable
                 let type_ref = field_type_to_type_ref(&schema_name, &field_name, field_type, false);
 
@@ -319,10 +362,9 @@ fn build_output_type(
                 let card = *cardinality;
                 let fn_clone = field_name.clone();
 
-                obj = obj.field(Field::new(&field_name, type_ref, move |ctx| {
+                let field = Field::new(&field_name, type_ref, move |ctx| {
                     let fn_clone = fn_clone.clone();
-                    let target_name = target_name.clone();
-                    let target_type = target_type.clone();
+                    let target_type = target_name.clone();
                     let target_def = target_def.clone();
                     let card = card;
                     FieldFuture::new(async move {
@@ -332,31 +374,18 @@ fn build_output_type(
                         };
                         match card {
                             Cardinality::One => {
-                                resolve_relation_one(
-                                    &ctx,
-                                    parent,
-                                    &fn_clone,
-                                    &target_name,
-                                    &td,
-                                    &target_type,
-                                )
-                                .await
+                                resolve_relation_one(&ctx, parent, &fn_clone, &td, &target_type)
+                                    .await
                             }
                             Cardinality::Many => {
-                                resolve_relation_many(
-                                    &ctx,
-                                    parent,
-                                    &fn_clone,
-                                    &target_name,
-                                    &td,
-                                    &target_type,
-                                )
-                                .await
+                                resolve_relation_many(&ctx, parent, &fn_clone, &td, &target_type)
+                                    .await
                             }
                             _ => Ok(None),
                         }
                     })
-                }));
+                });
+                obj = obj.field(with_deprecation(field, field_def.deprecation()));
             }
             _ => {
                 // Non-relation field: resolve from EntityFields
@@ -366,7 +395,7 @@ fn build_output_type(
                 let ft_clone = field_type.clone();
                 let fn_clone = field_name.clone();
 
-                obj = obj.field(Field::new(&field_name, type_ref, move |ctx| {
+                let field = Field::new(&field_name, type_ref, move |ctx| {
                     let fn_clone = fn_clone.clone();
                     let ft_clone = ft_clone.clone();
                     FieldFuture::new(async move {
@@ -379,7 +408,8 @@ fn build_output_type(
                             None => Ok(None),
                         }
                     })
-                }));
+                });
+                obj = obj.field(with_deprecation(field, field_def.deprecation()));
             }
         }
     }
@@ -387,29 +417,31 @@ fn build_output_type(
     Ok(obj)
 }
 
-/// Build a `{Schema}Connection` type.
-fn build_connection_type(connection_name: &str, item_type_name: &str) -> Object {
+/// Mark `field` `@deprecated` when the source schema or field carries a
+/// `@deprecated` annotation.
+fn with_deprecation(field: Field, deprecation: Option<Option<&str>>) -> Field {
+    match deprecation {
+        Some(reason) => field.deprecation(reason),
+        None => field,
+    }
+}
+
+/// Build a `{Schema}List` type for the flat list field.
+fn build_list_type(list_name: &str, item_type_name: &str) -> Object {
     let item_tn = item_type_name.to_string();
 
-    Object::new(connection_name)
+    Object::new(list_name)
         .field(Field::new(
             "items",
             TypeRef::named_nn_list(item_type_name),
             move |ctx| {
                 let item_tn = item_tn.clone();
                 FieldFuture::new(async move {
-                    let conn = ctx.parent_value.try_downcast_ref::<ConnectionData>()?;
-                    let items: Vec<FieldValue> = conn
+                    let list = ctx.parent_value.try_downcast_ref::<ListData>()?;
+                    let items: Vec<FieldValue> = list
                         .items
                         .iter()
-                        .map(|ef| {
-                            FieldValue::owned_any(EntityFields {
-                                id: ef.id.clone(),
-                                schema: ef.schema.clone(),
-                                fields: ef.fields.clone(),
-                            })
-                            .with_type(item_tn.clone())
-                        })
+                        .map(|ef| FieldValue::owned_any(ef.clone()).with_type(item_tn.clone()))
                         .collect();
                     Ok(Some(FieldValue::list(items)))
                 })
@@ -420,9 +452,9 @@ fn build_connection_type(connection_name: &str, item_type_name: &str) -> Object
             TypeRef::named_nn(TypeRef::INT),
             |ctx| {
                 FieldFuture::new(async move {
-                    let conn = ctx.parent_value.try_downcast_ref::<ConnectionData>()?;
+                    let list = ctx.parent_value.try_downcast_ref::<ListData>()?;
                     Ok(Some(FieldValue::value(async_graphql::Value::Number(
-                        conn.count.into(),
+                        list.count.into(),
                     ))))
                 })
             },
@@ -432,8 +464,8 @@ fn build_connection_type(connection_name: &str, item_type_name: &str) -> Object
             TypeRef::named(TypeRef::INT),
             |ctx| {
                 FieldFuture::new(async move {
-                    let conn = ctx.parent_value.try_downcast_ref::<ConnectionData>()?;
-                    match conn.total_count {
+                    let list = ctx.parent_value.try_downcast_ref::<ListData>()?;
+                    match list.total_count {
                         Some(tc) => Ok(Some(FieldValue::value(async_graphql::Value::Number(
                             tc.into(),
                         )))),
@@ -444,6 +476,108 @@ fn build_connection_type(connection_name: &str, item_type_name: &str) -> Object
         ))
 }
 
+/// Build the `{Schema}Connection` and `{Schema}Edge` types for the Relay
+/// connection field.
+fn build_connection_types(
+    connection_name: &str,
+    edge_name: &str,
+    node_type_name: &str,
+) -> (Object, Object) {
+    let edge_tn = edge_name.to_string();
+    let connection = Object::new(connection_name)
+        .field(Field::new(
+            "edges",
+            TypeRef::named_nn_list_nn(edge_name),
+            move |ctx| {
+                let edge_tn = edge_tn.clone();
+                FieldFuture::new(async move {
+                    let conn = ctx.parent_value.try_downcast_ref::<ConnectionData>()?;
+                    let edges: Vec<FieldValue> = conn
+                        .edges
+                        .iter()
+                        .map(|edge| FieldValue::owned_any(edge.clone()).with_type(edge_tn.clone()))
+                        .collect();
+                    Ok(Some(FieldValue::list(edges)))
+                })
+            },
+        ))
+        .field(Field::new(
+            "pageInfo",
+            TypeRef::named_nn(PAGE_INFO_TYPE),
+            |ctx| {
+                FieldFuture::new(async move {
+                    let conn = ctx.parent_value.try_downcast_ref::<ConnectionData>()?;
+                    Ok(Some(FieldValue::owned_any(conn.page_info.clone())))
+                })
+            },
+        ))
+        .field(Field::new(
+            "totalCount",
+            TypeRef::named(TypeRef::INT),
+            |ctx| {
+                FieldFuture::new(async move {
+                    let conn = ctx.parent_value.try_downcast_ref::<ConnectionData>()?;
+                    Ok(conn
+                        .total_count
+                        .map(|tc| FieldValue::value(async_graphql::Value::Number(tc.into()))))
+                })
+            },
+        ));
+
+    let node_tn = node_type_name.to_string();
+    let edge = Object::new(edge_name)
+        .field(Field::new(
+            "node",
+            TypeRef::named_nn(node_type_name),
+            move |ctx| {
+                let node_tn = node_tn.clone();
+                FieldFuture::new(async move {
+                    let edge = ctx.parent_value.try_downcast_ref::<EdgeData>()?;
+                    Ok(Some(
+                        FieldValue::owned_any(edge.node.clone()).with_type(node_tn),
+                    ))
+                })
+            },
+        ))
+        .field(Field::new(
+            "cursor",
+            TypeRef::named_nn(TypeRef::STRING),
+            |ctx| {
+                FieldFuture::new(async move {
+                    let edge = ctx.parent_value.try_downcast_ref::<EdgeData>()?;
+                    Ok(Some(FieldValue::value(edge.cursor.clone())))
+                })
+            },
+        ));
+
+    (connection, edge)
+}
+
+/// Build the shared Relay `PageInfo` type.
+fn build_page_info_type() -> Object {
+    Object::new(PAGE_INFO_TYPE)
+        .field(Field::new(
+            "hasNextPage",
+            TypeRef::named_nn(TypeRef::BOOLEAN),
+            |ctx| {
+                FieldFuture::new(async move {
+                    let info = ctx.parent_value.try_downcast_ref::<PageInfoData>()?;
+                    Ok(Some(FieldValue::value(info.has_next_page)))
+                })
+            },
+        ))
+        .field(Field::new(
+            "endCursor",
+            TypeRef::named(TypeRef::STRING),
+            |ctx| {
+                FieldFuture::new(async move {
+                    let info = ctx.parent_value.try_downcast_ref::<PageInfoData>()?;
+                    Ok(info.end_cursor.clone().map(FieldValue::value))
+                })
+            },
+        ))
+}
+
 /// Lowercase the first character of a string.
 fn lcfirst(s: &str) -> String {
     let mut chars = s.chars();
@@ -472,6 +606,56 @@ fn pluralize(s: &str) -> String {
     }
 }
 
+/// Type names the generated schema always defines.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "Query",
+    "Mutation",
+    "Subscription",
+    "String",
+    "Int",
+    "Float",
+    "Boolean",
+    "ID",
+    DATETIME_SCALAR,
+    JSON_SCALAR,
+    INT64_SCALAR,
+    ENTITY_ID_SCALAR,
+    SCHEMA_NAME_SCALAR,
+    SORT_ORDER_ENUM,
+    PAGE_INFO_TYPE,
+];
+
+/// Reject schema names and enum variants that would produce invalid SDL.
+///
+/// A schema may not shadow a built-in or shared type, and `true`, `false`,
+/// and `null` cannot be GraphQL enum values.
+fn check_reserved_names(schemas: &[&SchemaDefinition]) -> Result<(), String> {
+    for schema_def in schemas {
+        let name = schema_def.name.as_str();
+        if RESERVED_TYPE_NAMES.contains(&name) {
+            return Err(format!(
+                "schema '{name}' collides with a reserved GraphQL type name; rename the schema"
+            ));
+        }
+        for field_def in &schema_def.fields {
+            let FieldType::Enum(variants) = &field_def.field_type else {
+                continue;
+            };
+            if let Some(variant) = variants
+                .iter()
+                .find(|v| matches!(v.as_str(), "true" | "false" | "null"))
+            {
+                return Err(format!(
+                    "enum variant '{variant}' of '{name}.{}' is a reserved GraphQL value; \
+                     rename the variant",
+                    field_def.name.as_str()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Check for field name collisions among query fields.
 fn check_field_collisions(names: &[(String, String)]) -> Result<(), String> {
     let mut seen: HashMap<&str, &str> = HashMap::new();
@@ -491,8 +675,8 @@ fn check_field_collisions(names: &[(String, String)]) -> Result<(), String> {
 mod tests {
     use super::*;
     use schema_forge_core::types::{
-        Annotation, FieldDefinition, FieldModifier, FieldName, IntegerConstraints, SchemaId,
-        SchemaName, TextConstraints,
+        Annotation, EnumVariants, FieldAnnotation, FieldDefinition, FieldModifier, FieldName,
+        IntegerConstraints, SchemaId, SchemaName, TextConstraints,
     };
 
     fn make_schema(name: &str, fields: Vec<FieldDefinition>) -> SchemaDefinition {
@@ -692,6 +876,102 @@ mod tests {
         assert!(check_field_collisions(&names).is_ok());
     }
 
+    #[test]
+    fn deprecated_fields_marked_in_sdl() {
+        let mut legacy = text_field("fax");
+        legacy.annotations.push(FieldAnnotation::Deprecated {
+            reason: Some("use email".into()),
+        });
+        let schemas = vec![SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![required_text_field("name"), legacy],
+            vec![Annotation::Deprecated { reason: None }],
+        )
+        .unwrap()];
+        let sdl = build_graphql_schema(&schemas).unwrap().sdl();
+        assert!(
+            sdl.contains("fax: String @deprecated(reason: \"use email\")"),
+            "{sdl}"
+        );
+        assert!(
+            sdl.contains("contact(id: EntityId!): Contact @deprecated"),
+            "{sdl}"
+        );
+        assert!(!sdl.contains("name: String! @deprecated"), "{sdl}");
+    }
+
+    #[tokio::test]
+    async fn malformed_entity_id_rejected_at_graphql_layer() {
+        let schemas = vec![make_schema("Contact", vec![required_text_field("name")])];
+        let schema = build_graphql_schema(&schemas).unwrap();
+
+        // Validation rejects the argument before the resolver runs, so no
+        // request context (backend, claims) is needed.
+        let response = schema
+            .execute(r#"{ contact(id: "not-a-type-id") { id } }"#)
+            .await;
+        assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+        let message = &response.errors[0].message;
+        assert!(
+            message.contains("Invalid value for argument \"id\"") && message.contains("EntityId"),
+            "unexpected error: {message}"
+        );
+    }
+
+    #[test]
+    fn entity_id_scalar_in_sdl() {
+        let schemas = vec![make_schema("Contact", vec![required_text_field("name")])];
+        let sdl = build_graphql_schema(&schemas).unwrap().sdl();
+        assert!(sdl.contains("scalar EntityId"), "{sdl}");
+        assert!(sdl.contains("scalar SchemaName"), "{sdl}");
+        assert!(sdl.contains("id: EntityId!"), "{sdl}");
+    }
+
+    #[test]
+    fn connection_and_flat_list_fields_in_sdl() {
+        let schemas = vec![make_schema("Contact", vec![required_text_field("name")])];
+        let sdl = build_graphql_schema(&schemas).unwrap().sdl();
+        assert!(
+            sdl.contains(
+                "contacts(filter: ContactFilter, sort: [ContactSortInput], first: Int, after: String): ContactConnection!"
+            ),
+            "{sdl}"
+        );
+        assert!(sdl.contains("edges: [ContactEdge!]!"), "{sdl}");
+        assert!(sdl.contains("pageInfo: PageInfo!"), "{sdl}");
+        assert!(sdl.contains("node: Contact!"), "{sdl}");
+        assert!(sdl.contains("hasNextPage: Boolean!"), "{sdl}");
+        assert!(
+            sdl.contains(
+                "contactsList(filter: ContactFilter, sort: [ContactSortInput], limit: Int, offset: Int): ContactList!"
+            ),
+            "{sdl}"
+        );
+    }
+
+    #[test]
+    fn reserved_type_names_are_rejected() {
+        for name in ["Query", "String", "PageInfo", "DateTime"] {
+            let err = build_graphql_schema(&[make_schema(name, vec![text_field("title")])])
+                .err()
+                .expect("reserved name must be rejected");
+            assert!(err.contains(&format!("schema '{name}'")), "{err}");
+        }
+    }
+
+    #[test]
+    fn reserved_enum_values_are_rejected() {
+        let field = FieldDefinition::new(
+            FieldName::new("flag").unwrap(),
+            FieldType::Enum(EnumVariants::new(vec!["true".into(), "false".into()]).unwrap()),
+        );
+        let err = build_graphql_schema(&[make_schema("Setting", vec![field])])
+            .err()
+            .expect("reserved enum value must be rejected");
+        assert!(err.contains("'true' of 'Setting.flag'"), "{err}");
+    }
+
     #[test]
     fn build_empty_schema_list() {
         let result = build_graphql_schema(&[]);
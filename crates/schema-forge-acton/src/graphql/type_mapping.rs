@@ -1,15 +1,56 @@
 use std::collections::BTreeMap;
 
-use async_graphql::dynamic::TypeRef;
+use async_graphql::dynamic::{Scalar, TypeRef};
 use async_graphql::indexmap;
 use async_graphql::Value as GqlValue;
 use async_graphql::{Name, Number};
-use schema_forge_core::types::{Cardinality, DynamicValue, FieldType, IntegerConstraints};
+use schema_forge_core::types::{
+    Cardinality, DynamicValue, EntityId, FieldType, IntegerConstraints, SchemaName,
+};
 
 /// Custom scalar names used in the GraphQL schema.
 pub const DATETIME_SCALAR: &str = "DateTime";
 pub const JSON_SCALAR: &str = "JSON";
 pub const INT64_SCALAR: &str = "Int64";
+pub const ENTITY_ID_SCALAR: &str = "EntityId";
+pub const SCHEMA_NAME_SCALAR: &str = "SchemaName";
+
+/// The `EntityId` scalar: a TypeID string (`{prefix}_{suffix}`, e.g.
+/// `contact_01h455vb4pex5vsknk084sn02q`).
+///
+/// Input values that do not parse as a TypeID are rejected during
+/// validation, before any resolver runs, so clients get a typed error
+/// instead of a backend lookup failure. Output values are always the
+/// canonical string form of [`EntityId`].
+pub fn entity_id_scalar() -> Scalar {
+    Scalar::new(ENTITY_ID_SCALAR)
+        .description(
+            "Entity identifier in TypeID format: a lowercase prefix, an underscore, \
+             and a 26-character base32 UUIDv7 suffix (e.g. contact_01h455vb4pex5vsknk084sn02q)",
+        )
+        .specified_by_url("https://github.com/jetify-com/typeid/tree/main/spec")
+        .validator(|value| match value {
+            GqlValue::String(s) => EntityId::parse(s).is_ok(),
+            _ => false,
+        })
+}
+
+/// The `SchemaName` scalar: a schema identifier as it appears in the DSL.
+///
+/// Documents and enforces the [`SchemaName`] constraints: PascalCase
+/// (`[A-Z][a-zA-Z0-9]*`) and not one of the names reserved by the Cedar
+/// policy generator.
+pub fn schema_name_scalar() -> Scalar {
+    Scalar::new(SCHEMA_NAME_SCALAR)
+        .description(
+            "Schema name: PascalCase matching [A-Z][a-zA-Z0-9]*, excluding names \
+             reserved by the authorization policy namespace",
+        )
+        .validator(|value| match value {
+            GqlValue::String(s) => SchemaName::new(s.as_str()).is_ok(),
+            _ => false,
+        })
+}
 
 /// Check whether integer constraints fit within i32 range.
 fn fits_i32(constraints: &IntegerConstraints) -> bool {
@@ -29,7 +70,7 @@ pub fn field_type_to_type_ref(
     required: bool,
 ) -> TypeRef {
     let base = match ft {
-        FieldType::Text(_) | FieldType::RichText => TypeRef::named(TypeRef::STRING),
+        FieldType::Text(_) | FieldType::RichText(_) => TypeRef::named(TypeRef::STRING),
         FieldType::Integer(constraints) => {
             if fits_i32(constraints) {
                 TypeRef::named(TypeRef::INT)
@@ -56,7 +97,7 @@ pub fn field_type_to_type_ref(
                 _ => TypeRef::named(target_name),
             };
         }
-        FieldType::Array(inner) => {
+        FieldType::Array(inner, _) => {
             let inner_ref = field_type_to_type_ref(schema_name, field_name, inner, false);
             return if required {
                 TypeRef::named_nn_list(inner_ref_to_name(&inner_ref))
@@ -135,10 +176,13 @@ pub fn dynamic_value_to_gql_value(dv: &DynamicValue, field_type: Option<&FieldTy
             .unwrap_or(GqlValue::Null),
         DynamicValue::Boolean(b) => GqlValue::Boolean(*b),
         DynamicValue::DateTime(dt) => GqlValue::String(dt.to_rfc3339()),
+        DynamicValue::Duration(d) => GqlValue::String(d.to_string()),
+        DynamicValue::Uuid(u) => GqlValue::String(u.to_string()),
+        DynamicValue::Decimal(d) => GqlValue::String(d.to_string()),
         DynamicValue::Json(v) => json_to_gql_value(v),
         DynamicValue::Array(arr) => {
             let inner_type = match field_type {
-                Some(FieldType::Array(inner)) => Some(inner.as_ref()),
+                Some(FieldType::Array(inner, _)) => Some(inner.as_ref()),
                 _ => None,
             };
             GqlValue::List(
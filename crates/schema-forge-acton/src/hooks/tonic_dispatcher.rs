@@ -396,6 +396,9 @@ fn scalar_value_for_field(value: &DynamicValue, kind: &Kind) -> Option<Value> {
         }),
         DynamicValue::Boolean(b) => Some(Value::Bool(*b)),
         DynamicValue::DateTime(dt) => Some(Value::String(dt.to_rfc3339())),
+        DynamicValue::Duration(d) => Some(Value::String(d.to_string())),
+        DynamicValue::Uuid(u) => Some(Value::String(u.to_string())),
+        DynamicValue::Decimal(d) => Some(Value::String(d.to_string())),
         DynamicValue::Enum(s) => Some(Value::String(s.clone())),
         DynamicValue::Json(j) => Some(Value::String(j.to_string())),
         DynamicValue::Ref(id) => Some(Value::String(id.to_string())),
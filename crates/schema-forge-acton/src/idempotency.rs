@@ -0,0 +1,357 @@
+//! `Idempotency-Key` support for entity creation.
+//!
+//! A client that retries `POST /schemas/{schema}/entities` after a lost
+//! response sends the same `Idempotency-Key` header on every attempt. The
+//! first attempt creates the entity and records its id under the key; a
+//! repeat within the configured window replays that entity instead of
+//! creating a duplicate. Each key also remembers a fingerprint of the body
+//! it was first sent with, and reusing the key for a different body is
+//! rejected rather than replayed.
+//!
+//! Keys are scoped per schema and per principal, so two callers (or two
+//! schemas) never collide on the same key. The store is held in memory by
+//! the [`ForgeActor`](crate::ForgeActor) and is therefore per-process.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use sha2::{Digest, Sha256};
+
+/// Request header carrying the client-chosen idempotency key.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Response header set to `true` on a replayed create.
+pub const IDEMPOTENT_REPLAY_HEADER: &str = "idempotent-replay";
+
+/// Longest accepted key, in bytes.
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
+/// Whether `key` is an acceptable idempotency key: 1 to
+/// [`MAX_IDEMPOTENCY_KEY_LEN`] visible ASCII characters.
+pub fn is_valid_idempotency_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= MAX_IDEMPOTENCY_KEY_LEN
+        && key.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// Fingerprint of a create request's field map, independent of the order
+/// its JSON keys arrived in.
+pub fn body_fingerprint(fields: &serde_json::Map<String, serde_json::Value>) -> String {
+    fn canonical(value: &serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => {
+                let mut entries: Vec<_> = map.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                serde_json::Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(k, v)| (k.clone(), canonical(v)))
+                        .collect(),
+                )
+            }
+            serde_json::Value::Array(items) => {
+                serde_json::Value::Array(items.iter().map(canonical).collect())
+            }
+            other => other.clone(),
+        }
+    }
+
+    let mut hasher = Sha256::new();
+    if let Ok(bytes) = serde_json::to_vec(&canonical(&serde_json::Value::Object(fields.clone()))) {
+        hasher.update(&bytes);
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// The namespace a key lives in: one schema, one principal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdempotencyScope {
+    /// Schema the entity is created in.
+    pub schema: String,
+    /// Subject of the caller's claims, or `None` for unauthenticated calls.
+    pub principal: Option<String>,
+    /// The client-supplied key.
+    pub key: String,
+}
+
+/// Result of [`IdempotencyStore::claim`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyClaim {
+    /// The key is new; the caller owns it until it completes or releases.
+    Fresh,
+    /// The key already produced this entity id; replay it.
+    Replay(String),
+    /// Another request holding the key has not finished yet.
+    InFlight,
+    /// The key was first used with a different request body.
+    Mismatch,
+}
+
+#[derive(Debug, Clone)]
+enum Slot {
+    Pending,
+    Completed(String),
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    slot: Slot,
+    fingerprint: String,
+    at: Instant,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    map: HashMap<IdempotencyScope, Entry>,
+    /// When expired entries are next swept out of `map`.
+    next_purge: Option<Instant>,
+}
+
+impl Entries {
+    /// Drop every entry older than `window`, at most once per window, so
+    /// keys that are never repeated do not accumulate.
+    fn purge_expired(&mut self, now: Instant, window: Duration) {
+        if self.next_purge.is_some_and(|at| now < at) {
+            return;
+        }
+        self.map.retain(|_, e| now.duration_since(e.at) < window);
+        self.next_purge = Some(now + window);
+    }
+}
+
+/// In-memory map from [`IdempotencyScope`] to the entity created under it.
+///
+/// Cheap to clone; clones share the same entries.
+#[derive(Debug, Clone, Default)]
+pub struct IdempotencyStore {
+    entries: Arc<Mutex<Entries>>,
+}
+
+impl IdempotencyStore {
+    /// Claim `scope` for a create whose body has `fingerprint` (see
+    /// [`body_fingerprint`]). An entry older than `window` counts as
+    /// absent, so a key may be reused once its window has passed.
+    pub fn claim(
+        &self,
+        scope: &IdempotencyScope,
+        fingerprint: &str,
+        window: Duration,
+    ) -> IdempotencyClaim {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.purge_expired(now, window);
+        let live = entries
+            .map
+            .get(scope)
+            .filter(|e| now.duration_since(e.at) < window);
+        match live {
+            Some(e) if e.fingerprint != fingerprint => IdempotencyClaim::Mismatch,
+            Some(Entry {
+                slot: Slot::Completed(id),
+                ..
+            }) => IdempotencyClaim::Replay(id.clone()),
+            Some(Entry {
+                slot: Slot::Pending,
+                ..
+            }) => IdempotencyClaim::InFlight,
+            None => {
+                entries.map.insert(
+                    scope.clone(),
+                    Entry {
+                        slot: Slot::Pending,
+                        fingerprint: fingerprint.to_string(),
+                        at: now,
+                    },
+                );
+                IdempotencyClaim::Fresh
+            }
+        }
+    }
+
+    /// Record the entity a fresh claim produced.
+    pub fn complete(&self, scope: &IdempotencyScope, fingerprint: &str, entity_id: &str) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.map.insert(
+            scope.clone(),
+            Entry {
+                slot: Slot::Completed(entity_id.to_string()),
+                fingerprint: fingerprint.to_string(),
+                at: Instant::now(),
+            },
+        );
+    }
+
+    /// Drop a fresh claim whose create failed, so a retry may try again.
+    pub fn release(&self, scope: &IdempotencyScope) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(Entry {
+            slot: Slot::Pending,
+            ..
+        }) = entries.map.get(scope)
+        {
+            entries.map.remove(scope);
+        }
+    }
+}
+
+/// A [`IdempotencyClaim::Fresh`] claim that is released on drop unless
+/// [`completed`](IdempotencyGuard::complete), so every early return from
+/// the create path frees the key.
+pub struct IdempotencyGuard {
+    store: IdempotencyStore,
+    scope: IdempotencyScope,
+    fingerprint: String,
+    completed: bool,
+}
+
+impl IdempotencyGuard {
+    /// Guard a fresh claim on `scope` for a body with `fingerprint`.
+    pub fn new(store: IdempotencyStore, scope: IdempotencyScope, fingerprint: String) -> Self {
+        Self {
+            store,
+            scope,
+            fingerprint,
+            completed: false,
+        }
+    }
+
+    /// Record the created entity and keep the key.
+    pub fn complete(mut self, entity_id: &str) {
+        self.store
+            .complete(&self.scope, &self.fingerprint, entity_id);
+        self.completed = true;
+    }
+}
+
+impl Drop for IdempotencyGuard {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.store.release(&self.scope);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(60);
+    const BODY: &str = "body";
+
+    fn scope(principal: Option<&str>, key: &str) -> IdempotencyScope {
+        IdempotencyScope {
+            schema: "Invoice".into(),
+            principal: principal.map(str::to_string),
+            key: key.into(),
+        }
+    }
+
+    #[test]
+    fn repeat_claim_replays_completed_entity() {
+        let store = IdempotencyStore::default();
+        let s = scope(Some("alice"), "k1");
+        assert_eq!(store.claim(&s, BODY, WINDOW), IdempotencyClaim::Fresh);
+        assert_eq!(store.claim(&s, BODY, WINDOW), IdempotencyClaim::InFlight);
+        store.complete(&s, BODY, "invoice_01");
+        assert_eq!(
+            store.claim(&s, BODY, WINDOW),
+            IdempotencyClaim::Replay("invoice_01".into())
+        );
+    }
+
+    #[test]
+    fn keys_are_scoped_per_principal() {
+        let store = IdempotencyStore::default();
+        store.claim(&scope(Some("alice"), "k1"), BODY, WINDOW);
+        store.complete(&scope(Some("alice"), "k1"), BODY, "invoice_01");
+        assert_eq!(
+            store.claim(&scope(Some("bob"), "k1"), BODY, WINDOW),
+            IdempotencyClaim::Fresh
+        );
+        assert_eq!(
+            store.claim(&scope(None, "k1"), BODY, WINDOW),
+            IdempotencyClaim::Fresh
+        );
+    }
+
+    #[test]
+    fn dropped_guard_releases_the_key() {
+        let store = IdempotencyStore::default();
+        let s = scope(Some("alice"), "k1");
+        assert_eq!(store.claim(&s, BODY, WINDOW), IdempotencyClaim::Fresh);
+        drop(IdempotencyGuard::new(store.clone(), s.clone(), BODY.into()));
+        assert_eq!(store.claim(&s, BODY, WINDOW), IdempotencyClaim::Fresh);
+
+        IdempotencyGuard::new(store.clone(), s.clone(), BODY.into()).complete("invoice_02");
+        assert_eq!(
+            store.claim(&s, BODY, WINDOW),
+            IdempotencyClaim::Replay("invoice_02".into())
+        );
+    }
+
+    #[test]
+    fn expired_entries_are_forgotten() {
+        let store = IdempotencyStore::default();
+        let s = scope(Some("alice"), "k1");
+        store.claim(&s, BODY, WINDOW);
+        store.complete(&s, BODY, "invoice_01");
+        assert_eq!(
+            store.claim(&s, BODY, Duration::ZERO),
+            IdempotencyClaim::Fresh
+        );
+    }
+
+    #[test]
+    fn expired_entries_are_purged_once_per_window() {
+        let store = IdempotencyStore::default();
+        for key in ["k1", "k2", "k3"] {
+            store.claim(&scope(Some("alice"), key), BODY, Duration::ZERO);
+        }
+        // Each zero-length window has passed by the next claim, which
+        // sweeps the earlier keys out.
+        assert_eq!(store.entries.lock().unwrap().map.len(), 1);
+
+        for key in ["k1", "k2", "k3"] {
+            store.claim(&scope(Some("alice"), key), BODY, WINDOW);
+        }
+        assert_eq!(store.entries.lock().unwrap().map.len(), 3);
+    }
+
+    #[test]
+    fn reuse_with_a_different_body_is_a_mismatch() {
+        let store = IdempotencyStore::default();
+        let s = scope(Some("alice"), "k1");
+        assert_eq!(store.claim(&s, BODY, WINDOW), IdempotencyClaim::Fresh);
+        assert_eq!(store.claim(&s, "other", WINDOW), IdempotencyClaim::Mismatch);
+        store.complete(&s, BODY, "invoice_01");
+        assert_eq!(store.claim(&s, "other", WINDOW), IdempotencyClaim::Mismatch);
+        assert_eq!(
+            store.claim(&s, BODY, WINDOW),
+            IdempotencyClaim::Replay("invoice_01".into())
+        );
+    }
+
+    #[test]
+    fn body_fingerprint_ignores_key_order() {
+        let fields = |json: &str| match serde_json::from_str(json).unwrap() {
+            serde_json::Value::Object(map) => map,
+            other => panic!("not an object: {other}"),
+        };
+        let a = body_fingerprint(&fields(r#"{"a": 1, "b": {"x": [1, 2], "y": null}}"#));
+        let b = body_fingerprint(&fields(r#"{"b": {"y": null, "x": [1, 2]}, "a": 1}"#));
+        let c = body_fingerprint(&fields(r#"{"a": 2, "b": {"x": [1, 2], "y": null}}"#));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn key_validation() {
+        assert!(is_valid_idempotency_key(
+            "8e03978e-40d5-43e8-bc93-6894a57f9324"
+        ));
+        assert!(!is_valid_idempotency_key(""));
+        assert!(!is_valid_idempotency_key("has space"));
+        assert!(!is_valid_idempotency_key(&"k".repeat(256)));
+    }
+}
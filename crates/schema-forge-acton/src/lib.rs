@@ -1,15 +1,22 @@
 pub mod access;
 pub mod actor;
+pub mod api_key;
+pub mod audit;
 pub mod authz;
 pub mod cedar;
 pub mod config;
+pub mod entity_hooks;
 pub mod conversions;
 pub mod error;
+pub mod events;
 pub mod extension;
 #[cfg(feature = "graphql")]
 pub mod graphql;
 pub mod hooks;
+pub mod idempotency;
 pub mod messages;
+pub mod openapi;
+pub(crate) mod post_write;
 pub mod routes;
 pub mod shared;
 pub mod shared_auth;
@@ -21,7 +28,13 @@ pub mod webhook;
 pub use access::{PLATFORM_ADMIN_ROLE, PUBLIC_ROLE};
 pub use acton_service;
 pub use actor::ForgeActor;
+pub use api_key::{
+    ApiKeyProvider, AuthContext, HeaderRolesAuthProvider, NoopAuthProvider, PrincipalKind,
+    StaticApiKeyProvider,
+};
+pub use audit::{AuditEvent, AuditSink};
 pub use config::SchemaForgeConfig;
+pub use entity_hooks::{EntityHook, EntityHookContext, EntityHooks};
 pub use error::ForgeError;
 pub use extension::{InitForgeData, SchemaForgeExtension};
 pub use hooks::HookDispatchActor;
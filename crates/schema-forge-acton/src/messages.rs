@@ -7,7 +7,7 @@
 //! uses to send the response back to the caller via a `tokio::sync::oneshot` channel.
 //! Fire-and-forget messages (mutations with no response) omit the reply channel.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 use schema_forge_backend::auth::RecordAccessPolicy;
@@ -16,7 +16,7 @@ use schema_forge_backend::error::BackendError;
 use schema_forge_backend::tenant::TenantConfig;
 use schema_forge_core::migration::MigrationStep;
 use schema_forge_core::query::{AggregateQuery, AggregateResult, Query};
-use schema_forge_core::types::{EntityId, SchemaDefinition, SchemaName};
+use schema_forge_core::types::{DynamicValue, EntityId, SchemaDefinition, SchemaName};
 use tokio::sync::{oneshot, Mutex};
 
 // ---------------------------------------------------------------------------
@@ -111,6 +111,50 @@ pub struct GetHookDispatcher {
     pub reply: ReplyChannel<Option<Arc<dyn crate::hooks::HookDispatcher>>>,
 }
 
+/// Retrieve the configured audit sink, if any.
+///
+/// Returns `None` when no sink was supplied at initialization; entity
+/// routes then skip audit event construction entirely.
+#[derive(Clone, Debug)]
+pub struct GetAuditSink {
+    pub reply: ReplyChannel<Option<Arc<dyn crate::audit::AuditSink>>>,
+}
+
+/// Retrieve the registered in-process entity hooks.
+///
+/// Always answered; the registry is empty when none were registered.
+#[derive(Clone, Debug)]
+pub struct GetEntityHooks {
+    pub reply: ReplyChannel<crate::entity_hooks::EntityHooks>,
+}
+
+/// Retrieve the `Idempotency-Key` store used by entity creation.
+///
+/// Always answered: the store exists from actor construction.
+#[derive(Clone, Debug)]
+pub struct GetIdempotencyStore {
+    pub reply: ReplyChannel<crate::idempotency::IdempotencyStore>,
+}
+
+/// Retrieve the entity change feed.
+///
+/// Always answered: the feed exists from actor construction, so
+/// subscribers may attach before any write happens.
+#[derive(Clone, Debug)]
+pub struct GetChangeFeed {
+    pub reply: ReplyChannel<crate::events::ChangeFeed>,
+}
+
+/// Retrieve the database backend.
+///
+/// Used for work that outlives a single actor round-trip, such as
+/// streaming a full export through `DynEntityStore::query_stream`.
+/// `None` before a backend is configured.
+#[derive(Clone, Debug)]
+pub struct GetBackend {
+    pub reply: ReplyChannel<Option<Arc<dyn crate::state::DynForgeBackend>>>,
+}
+
 /// Retrieve the compiled Cedar policy bundle from the actor.
 ///
 /// Returns `Some` once `InitForge` has populated the actor; `None` before
@@ -169,6 +213,13 @@ pub struct CreateEntity {
     pub reply: ReplyChannel<Result<Entity, BackendError>>,
 }
 
+/// Create several entities in one atomic backend write.
+#[derive(Clone, Debug)]
+pub struct CreateEntities {
+    pub entities: Vec<Entity>,
+    pub reply: ReplyChannel<Result<Vec<Entity>, BackendError>>,
+}
+
 /// Retrieve an entity by schema name and entity ID.
 #[derive(Clone, Debug)]
 pub struct GetEntity {
@@ -181,6 +232,19 @@ pub struct GetEntity {
 #[derive(Clone, Debug)]
 pub struct UpdateEntity {
     pub entity: Entity,
+    /// Version from the client's `If-Match`; `None` writes unconditionally.
+    pub expected_version: Option<u64>,
+    pub reply: ReplyChannel<Result<Entity, BackendError>>,
+}
+
+/// Merge field changes into an existing entity, leaving other fields as stored.
+#[derive(Clone, Debug)]
+pub struct PatchEntity {
+    pub schema: SchemaName,
+    pub id: EntityId,
+    pub changes: BTreeMap<String, DynamicValue>,
+    /// Version from the client's `If-Match`; `None` writes unconditionally.
+    pub expected_version: Option<u64>,
     pub reply: ReplyChannel<Result<Entity, BackendError>>,
 }
 
@@ -192,6 +256,14 @@ pub struct DeleteEntity {
     pub reply: ReplyChannel<Result<(), BackendError>>,
 }
 
+/// Clear the soft-delete stamp of an entity and return it.
+#[derive(Clone, Debug)]
+pub struct RestoreEntity {
+    pub schema: SchemaName,
+    pub id: EntityId,
+    pub reply: ReplyChannel<Result<Entity, BackendError>>,
+}
+
 /// Execute a query and return matching entities.
 #[derive(Clone, Debug)]
 pub struct QueryEntities {
@@ -253,6 +325,8 @@ pub struct InitForge {
     pub hook_dispatcher: Option<Arc<dyn crate::hooks::HookDispatcher>>,
     pub storage_registry: crate::storage::StorageRegistry,
     pub policy_store: Option<Arc<crate::authz::PolicyStore>>,
+    pub audit_sink: Option<Arc<dyn crate::audit::AuditSink>>,
+    pub entity_hooks: crate::entity_hooks::EntityHooks,
     pub reply: ReplyChannel<()>,
 }
 
@@ -271,6 +345,8 @@ impl std::fmt::Debug for InitForge {
                 &self.hook_dispatcher.as_ref().map(|_| ".."),
             )
             .field("storage_backends", &self.storage_registry.len())
+            .field("audit_sink", &self.audit_sink.as_ref().map(|_| ".."))
+            .field("entity_hooks", &self.entity_hooks)
             .field("reply", &self.reply)
             .finish()
     }
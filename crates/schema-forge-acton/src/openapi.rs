@@ -0,0 +1,542 @@
+//! OpenAPI 3.1 document for the entity REST routes.
+//!
+//! The document is derived from schema definitions alone, so the server
+//! builds it from the live registry on every `GET /openapi.json` and the
+//! CLI builds the same document from `.schema` files. Either way it cannot
+//! drift from the schemas it describes.
+//!
+//! Entity field objects come from
+//! [`to_json_schema`](schema_forge_core::json_schema::to_json_schema);
+//! OpenAPI 3.1 uses JSON Schema 2020-12, so they embed unchanged.
+
+use schema_forge_core::json_schema::to_json_schema;
+use schema_forge_core::types::SchemaDefinition;
+use serde_json::{json, Map, Value};
+
+/// OpenAPI version of every generated document.
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+/// Error responses shared by the entity routes: status, component name,
+/// and description. Every one carries the `Error` envelope.
+const ERROR_RESPONSES: &[(&str, &str, &str)] = &[
+    ("400", "BadRequest", "Malformed id or query parameters"),
+    ("401", "Unauthorized", "Missing or invalid bearer token"),
+    ("403", "Forbidden", "The caller may not perform this action"),
+    ("404", "NotFound", "Schema or entity not found"),
+    (
+        "412",
+        "PreconditionFailed",
+        "The entity changed since the `If-Match` version was read",
+    ),
+    (
+        "422",
+        "ValidationFailed",
+        "The entity fields failed validation",
+    ),
+];
+
+/// Build the OpenAPI document for the CRUD routes of `schemas`, with paths
+/// rooted at `base_path` (e.g. `/forge` or `/api/v1/forge`).
+///
+/// Schemas are emitted in name order so the output is stable.
+pub fn build_openapi(schemas: &[SchemaDefinition], base_path: &str) -> Value {
+    let base = base_path.trim_end_matches('/');
+    let mut sorted: Vec<&SchemaDefinition> = schemas.iter().collect();
+    sorted.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+
+    let mut paths = Map::new();
+    let mut components = Map::new();
+    components.insert("Error".into(), error_schema());
+    for schema in sorted {
+        let name = schema.name.as_str();
+        add_schema_components(&mut components, schema);
+
+        let mut collection = collection_path_item(name);
+        let mut item = item_path_item(name);
+        if schema.deprecation().is_some() {
+            for path_item in [&mut collection, &mut item] {
+                if let Some(operations) = path_item.as_object_mut() {
+                    for operation in operations.values_mut().filter(|v| v.is_object()) {
+                        operation["deprecated"] = Value::Bool(true);
+                    }
+                }
+            }
+        }
+        paths.insert(format!("{base}/schemas/{name}/entities"), collection);
+        paths.insert(format!("{base}/schemas/{name}/entities/{{id}}"), item);
+    }
+
+    let responses: Map<String, Value> = ERROR_RESPONSES
+        .iter()
+        .map(|(_, component, description)| {
+            (
+                component.to_string(),
+                json!({
+                    "description": description,
+                    "content": {
+                        "application/json": {
+                            "schema": { "$ref": "#/components/schemas/Error" }
+                        }
+                    }
+                }),
+            )
+        })
+        .collect();
+
+    json!({
+        "openapi": OPENAPI_VERSION,
+        "info": {
+            "title": "SchemaForge API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Auto-generated API from SchemaForge schema definitions",
+        },
+        "paths": paths,
+        "components": {
+            "schemas": components,
+            "parameters": list_parameters(),
+            "responses": responses,
+            "securitySchemes": {
+                "bearerAuth": {
+                    "type": "http",
+                    "scheme": "bearer",
+                    "bearerFormat": "PASETO",
+                }
+            },
+        },
+        "security": [{ "bearerAuth": [] }],
+    })
+}
+
+/// Flag a JSON-Schema object as deprecated: the standard `deprecated`
+/// keyword plus an `x-deprecated` note carrying the DSL reason (or `true`
+/// when `@deprecated` was given without one).
+fn mark_deprecated(schema: &mut Value, reason: Option<&str>) {
+    schema["deprecated"] = Value::Bool(true);
+    schema["x-deprecated"] = match reason {
+        Some(reason) => Value::String(reason.to_string()),
+        None => Value::Bool(true),
+    };
+}
+
+/// Insert `{Name}` (the field object), `{Name}Patch` (the same without
+/// `required`), `{Name}Entity`, and `{Name}List`.
+///
+/// `@hidden` fields are left out of all of them: the document is served to
+/// every caller, and those fields never appear in responses nor are they
+/// accepted from non-admin request bodies.
+fn add_schema_components(components: &mut Map<String, Value>, schema: &SchemaDefinition) {
+    let name = schema.name.as_str();
+
+    let mut fields = to_json_schema(schema);
+    if let Some(object) = fields.as_object_mut() {
+        object.remove("$schema");
+        for field in schema.fields.iter().filter(|f| f.is_hidden()) {
+            remove_property(object, field.name.as_str());
+        }
+    }
+    for field in &schema.fields {
+        if let Some(reason) = field.deprecation() {
+            mark_deprecated(&mut fields["properties"][field.name.as_str()], reason);
+        }
+    }
+    if let Some(reason) = schema.deprecation() {
+        mark_deprecated(&mut fields, reason);
+    }
+
+    let mut patch = fields.clone();
+    if let Some(object) = patch.as_object_mut() {
+        object.remove("required");
+        object.insert("title".into(), json!(format!("{name} (partial)")));
+    }
+
+    let entity = json!({
+        "type": "object",
+        "properties": {
+            "id": { "type": "string" },
+            "schema": { "const": name },
+            "fields": { "$ref": format!("#/components/schemas/{name}") },
+            "version": { "type": "integer", "minimum": 0 },
+            "permissions": { "type": "object" },
+        },
+        "required": ["id", "schema", "fields"],
+    });
+    let list = json!({
+        "type": "object",
+        "properties": {
+            "entities": {
+                "type": "array",
+                "items": { "$ref": format!("#/components/schemas/{name}Entity") },
+            },
+            "count": { "type": "integer", "minimum": 0 },
+            "total_count": { "type": ["integer", "null"], "minimum": 0 },
+            "next_cursor": { "type": "string" },
+            "permissions": { "type": "object" },
+        },
+        "required": ["entities", "count", "total_count"],
+    });
+
+    components.insert(name.to_string(), fields);
+    components.insert(format!("{name}Patch"), patch);
+    components.insert(format!("{name}Entity"), entity);
+    components.insert(format!("{name}List"), list);
+}
+
+/// Drop `property` from a JSON-Schema object's `properties` and `required`
+/// list, removing `required` altogether once it is empty.
+fn remove_property(object: &mut Map<String, Value>, property: &str) {
+    if let Some(properties) = object.get_mut("properties").and_then(Value::as_object_mut) {
+        properties.remove(property);
+    }
+    if let Some(required) = object.get_mut("required").and_then(Value::as_array_mut) {
+        required.retain(|r| r.as_str() != Some(property));
+        if required.is_empty() {
+            object.remove("required");
+        }
+    }
+}
+
+fn collection_path_item(name: &str) -> Value {
+    let parameters: Vec<Value> = ["limit", "offset", "after", "sort", "fields", "count"]
+        .iter()
+        .map(|p| json!({ "$ref": format!("#/components/parameters/{p}") }))
+        .collect();
+    json!({
+        "get": {
+            "operationId": format!("list{name}"),
+            "summary": format!("List {name} entities"),
+            "tags": [name],
+            "parameters": parameters,
+            "responses": responses(
+                "200",
+                json_body(&format!("{name}List")),
+                &["400", "401", "403"],
+            ),
+        },
+        "post": {
+            "operationId": format!("create{name}"),
+            "summary": format!("Create a {name} entity"),
+            "tags": [name],
+            "requestBody": request_body(name),
+            "responses": responses(
+                "201",
+                json_body(&format!("{name}Entity")),
+                &["401", "403", "422"],
+            ),
+        },
+    })
+}
+
+fn item_path_item(name: &str) -> Value {
+    json!({
+        "parameters": [{
+            "name": "id",
+            "in": "path",
+            "required": true,
+            "schema": { "type": "string" },
+        }],
+        "get": {
+            "operationId": format!("get{name}"),
+            "summary": format!("Get a {name} entity by ID"),
+            "tags": [name],
+            "parameters": [{ "$ref": "#/components/parameters/fields" }],
+            "responses": responses(
+                "200",
+                json_body(&format!("{name}Entity")),
+                &["400", "401", "403", "404"],
+            ),
+        },
+        "put": {
+            "operationId": format!("replace{name}"),
+            "summary": format!("Replace a {name} entity"),
+            "tags": [name],
+            "requestBody": request_body(name),
+            "responses": responses(
+                "200",
+                json_body(&format!("{name}Entity")),
+                &["400", "401", "403", "404", "412", "422"],
+            ),
+        },
+        "patch": {
+            "operationId": format!("update{name}"),
+            "summary": format!("Update some fields of a {name} entity"),
+            "tags": [name],
+            "requestBody": request_body(&format!("{name}Patch")),
+            "responses": responses(
+                "200",
+                json_body(&format!("{name}Entity")),
+                &["400", "401", "403", "404", "412", "422"],
+            ),
+        },
+        "delete": {
+            "operationId": format!("delete{name}"),
+            "summary": format!("Delete a {name} entity"),
+            "tags": [name],
+            "responses": responses(
+                "204",
+                json!({ "description": "Entity deleted" }),
+                &["400", "401", "403", "404"],
+            ),
+        },
+    })
+}
+
+/// The `{ "fields": ... }` envelope every entity write takes.
+fn request_body(fields_component: &str) -> Value {
+    json!({
+        "required": true,
+        "content": {
+            "application/json": {
+                "schema": {
+                    "type": "object",
+                    "properties": {
+                        "fields": { "$ref": format!("#/components/schemas/{fields_component}") },
+                    },
+                    "required": ["fields"],
+                }
+            }
+        }
+    })
+}
+
+fn json_body(component: &str) -> Value {
+    json!({
+        "description": "Success",
+        "content": {
+            "application/json": {
+                "schema": { "$ref": format!("#/components/schemas/{component}") }
+            }
+        }
+    })
+}
+
+fn responses(status: &str, success: Value, errors: &[&str]) -> Value {
+    let mut responses = Map::new();
+    responses.insert(status.to_string(), success);
+    for (code, component, _) in ERROR_RESPONSES {
+        if errors.contains(code) {
+            responses.insert(
+                code.to_string(),
+                json!({ "$ref": format!("#/components/responses/{component}") }),
+            );
+        }
+    }
+    Value::Object(responses)
+}
+
+/// The error envelope every failing route returns.
+fn error_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "error": { "type": "string", "description": "Machine-readable error kind" },
+            "message": { "type": "string" },
+            "reason": {
+                "type": "string",
+                "description": "Stable reason code, present on conflicts",
+            },
+        },
+        "required": ["error", "message"],
+    })
+}
+
+/// Query parameters of the list route, keyed by name.
+fn list_parameters() -> Value {
+    let param = |name: &str, schema: Value, description: &str| {
+        json!({
+            "name": name,
+            "in": "query",
+            "required": false,
+            "schema": schema,
+            "description": description,
+        })
+    };
+    json!({
+        "limit": param(
+            "limit",
+            json!({ "type": "integer", "minimum": 0 }),
+            "Maximum number of entities to return. Defaults to the schema's \
+             `@list(page_size)` and is clamped to its `max_page_size`",
+        ),
+        "offset": param(
+            "offset",
+            json!({ "type": "integer", "minimum": 0 }),
+            "Number of matching entities to skip",
+        ),
+        "after": param(
+            "after",
+            json!({ "type": "string" }),
+            "Cursor from a previous page's `next_cursor`",
+        ),
+        "sort": param(
+            "sort",
+            json!({ "type": "string" }),
+            "Comma-separated fields; prefix `-` or suffix `:desc` for descending",
+        ),
+        "fields": param(
+            "fields",
+            json!({ "type": "string" }),
+            "Comma-separated fields to return",
+        ),
+        "count": param(
+            "count",
+            json!({ "type": "boolean", "default": true }),
+            "Set to false to skip computing `total_count`",
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schema_forge_core::types::{
+        Annotation, FieldAnnotation, FieldDefinition, FieldModifier, FieldName, FieldType,
+        SchemaId, SchemaName, TextConstraints,
+    };
+
+    fn contact() -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![
+                FieldDefinition::with_modifiers(
+                    FieldName::new("name").unwrap(),
+                    FieldType::Text(TextConstraints::with_max_length(80)),
+                    vec![FieldModifier::Required],
+                ),
+                FieldDefinition::with_annotations(
+                    FieldName::new("phone").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                    vec![],
+                    vec![FieldAnnotation::Deprecated {
+                        reason: Some("use mobile".into()),
+                    }],
+                ),
+            ],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn crud_paths_reference_schema_components() {
+        let spec = build_openapi(&[contact()], "/api/v1/forge/");
+        assert_eq!(spec["openapi"], OPENAPI_VERSION);
+
+        let collection = &spec["paths"]["/api/v1/forge/schemas/Contact/entities"];
+        assert_eq!(
+            collection["get"]["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/ContactList"
+        );
+        let params: Vec<&str> = collection["get"]["parameters"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["$ref"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            params,
+            [
+                "#/components/parameters/limit",
+                "#/components/parameters/offset",
+                "#/components/parameters/after",
+                "#/components/parameters/sort",
+                "#/components/parameters/fields",
+                "#/components/parameters/count",
+            ]
+        );
+        assert_eq!(
+            collection["post"]["responses"]["422"]["$ref"],
+            "#/components/responses/ValidationFailed"
+        );
+
+        let item = &spec["paths"]["/api/v1/forge/schemas/Contact/entities/{id}"];
+        for method in ["get", "put", "patch", "delete"] {
+            assert!(item[method].is_object(), "missing {method}");
+        }
+        assert_eq!(
+            item["patch"]["requestBody"]["content"]["application/json"]["schema"]["properties"]
+                ["fields"]["$ref"],
+            "#/components/schemas/ContactPatch"
+        );
+    }
+
+    #[test]
+    fn components_carry_field_rules_and_error_envelope() {
+        let spec = build_openapi(&[contact()], "/forge");
+        let schemas = &spec["components"]["schemas"];
+
+        assert_eq!(schemas["Contact"]["required"], json!(["name"]));
+        assert_eq!(schemas["Contact"]["properties"]["name"]["maxLength"], 80);
+        assert!(schemas["Contact"].get("$schema").is_none());
+        assert!(schemas["ContactPatch"].get("required").is_none());
+        assert_eq!(
+            schemas["Contact"]["properties"]["phone"]["x-deprecated"],
+            "use mobile"
+        );
+        assert_eq!(schemas["Error"]["required"], json!(["error", "message"]));
+
+        assert_eq!(
+            spec["components"]["securitySchemes"]["bearerAuth"]["scheme"],
+            "bearer"
+        );
+        assert_eq!(spec["security"], json!([{ "bearerAuth": [] }]));
+    }
+
+    #[test]
+    fn hidden_fields_are_left_out_of_components() {
+        let mut schema = contact();
+        schema.fields.push(FieldDefinition::with_annotations(
+            FieldName::new("password_hash").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+            vec![FieldModifier::Required],
+            vec![FieldAnnotation::Hidden],
+        ));
+        let spec = build_openapi(&[schema], "/forge");
+        let schemas = &spec["components"]["schemas"];
+
+        for component in ["Contact", "ContactPatch"] {
+            let properties = schemas[component]["properties"].as_object().unwrap();
+            assert!(properties.contains_key("name"), "{component}");
+            assert!(!properties.contains_key("password_hash"), "{component}");
+        }
+        assert_eq!(schemas["Contact"]["required"], json!(["name"]));
+        assert!(!spec.to_string().contains("password_hash"));
+    }
+
+    #[test]
+    fn entity_version_admits_rows_written_before_versioning() {
+        let spec = build_openapi(&[contact()], "/forge");
+        assert_eq!(
+            spec["components"]["schemas"]["ContactEntity"]["properties"]["version"]["minimum"],
+            0
+        );
+    }
+
+    #[test]
+    fn deprecated_schema_marks_every_operation() {
+        let mut schema = contact();
+        schema
+            .annotations
+            .push(Annotation::Deprecated { reason: None });
+        let spec = build_openapi(&[schema], "/forge");
+
+        assert_eq!(
+            spec["components"]["schemas"]["Contact"]["x-deprecated"],
+            true
+        );
+        let item = &spec["paths"]["/forge/schemas/Contact/entities/{id}"];
+        for method in ["get", "put", "patch", "delete"] {
+            assert_eq!(item[method]["deprecated"], true, "{method}");
+        }
+        // Path-level parameters are not an operation.
+        assert!(item["parameters"].is_array());
+    }
+
+    #[test]
+    fn mark_deprecated_without_reason() {
+        let mut schema = json!({ "type": "object" });
+        mark_deprecated(&mut schema, None);
+        assert_eq!(schema["deprecated"], true);
+        assert_eq!(schema["x-deprecated"], true);
+    }
+}
@@ -0,0 +1,140 @@
+//! Side effects of a committed entity write, shared by the REST and
+//! GraphQL surfaces.
+//!
+//! [`WriteObservers::notify`] reports a [`CommittedWrite`] to, in order:
+//! 1. the [`AuditSink`], as an [`AuditEvent`]
+//! 2. the [`ChangeFeed`], for `GET /schemas/{schema}/events` subscribers
+//! 3. webhook subscribers: the schema's inline `@webhook` URL and every
+//!    active `WebhookSubscription` entity targeting it
+//!
+//! Each surface assembles its [`WriteObservers`] from its own state. Every
+//! step is best-effort: the write has already succeeded, so a missing sink
+//! or a failed subscription lookup never fails the request.
+
+use std::sync::Arc;
+
+use acton_service::middleware::Claims;
+use schema_forge_backend::entity::Entity;
+use schema_forge_core::types::SchemaDefinition;
+
+use crate::audit::{AuditAction, AuditEvent, AuditSink};
+use crate::events::{ChangeFeed, ChangeOperation, EntityChange};
+use crate::state::DynForgeBackend;
+use crate::webhook::{WebhookDispatcher, WebhookEvent};
+
+/// A write that has been committed to the backend.
+pub(crate) struct CommittedWrite<'a> {
+    pub schema: &'a SchemaDefinition,
+    pub claims: Option<&'a Claims>,
+    pub operation: ChangeOperation,
+    pub entity_id: &'a str,
+    /// The entity after a create or update, or its last known state
+    /// (when one was fetched) before a delete.
+    pub entity: Option<Entity>,
+    /// Fields the write changed, recorded in the audit event.
+    pub changed_fields: Vec<String>,
+}
+
+/// Where committed writes are reported. Absent parts are skipped.
+#[derive(Clone, Default)]
+pub(crate) struct WriteObservers {
+    pub audit_sink: Option<Arc<dyn AuditSink>>,
+    pub change_feed: Option<ChangeFeed>,
+    pub webhooks: Option<WebhookTarget>,
+}
+
+/// Webhook delivery for committed writes.
+#[derive(Clone)]
+pub(crate) struct WebhookTarget {
+    pub dispatcher: WebhookDispatcher,
+    /// Queried for runtime `WebhookSubscription` entities.
+    pub backend: Arc<dyn DynForgeBackend>,
+    /// The `WebhookSubscription` schema, when it is registered.
+    pub subscriptions: Option<SchemaDefinition>,
+}
+
+impl WriteObservers {
+    /// Report a committed write to every configured observer.
+    pub(crate) async fn notify(&self, write: CommittedWrite<'_>) {
+        let CommittedWrite {
+            schema,
+            claims,
+            operation,
+            entity_id,
+            entity,
+            changed_fields,
+        } = write;
+        let actor = claims.map(|c| c.sub.as_str());
+
+        if let Some(sink) = &self.audit_sink {
+            let action = match operation {
+                ChangeOperation::Created => AuditAction::Create,
+                ChangeOperation::Updated => AuditAction::Update,
+                ChangeOperation::Deleted => AuditAction::Delete,
+            };
+            sink.record(AuditEvent::new(
+                actor.map(String::from),
+                action,
+                schema.name.as_str(),
+                entity_id,
+                changed_fields,
+            ))
+            .await;
+        }
+
+        // Built before the entity moves into the change feed.
+        let webhook_event = if self.webhooks.is_some() {
+            match (operation, &entity) {
+                (ChangeOperation::Created, Some(entity)) => {
+                    Some(WebhookEvent::from_create(schema, entity, actor))
+                }
+                (ChangeOperation::Updated, Some(entity)) => {
+                    Some(WebhookEvent::from_update(schema, entity, actor))
+                }
+                (ChangeOperation::Deleted, _) => Some(WebhookEvent::from_delete(
+                    schema.name.as_str(),
+                    entity_id,
+                    actor,
+                )),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        if let Some(feed) = &self.change_feed {
+            feed.publish(EntityChange {
+                schema: schema.name.as_str().to_string(),
+                operation,
+                entity_id: entity_id.to_string(),
+                entity,
+            });
+        }
+
+        if let (Some(webhooks), Some(event)) = (&self.webhooks, webhook_event) {
+            webhooks.dispatch(schema, operation.as_str(), event).await;
+        }
+    }
+}
+
+impl WebhookTarget {
+    /// Fire `event` at every subscriber of `schema_def` for `event_type`.
+    /// Delivery happens in background tasks.
+    async fn dispatch(&self, schema_def: &SchemaDefinition, event_type: &str, event: WebhookEvent) {
+        if !schema_def.has_webhooks() || !schema_def.webhook_events().contains(&event_type) {
+            return;
+        }
+        let subs = self
+            .dispatcher
+            .resolve_subscriptions(
+                schema_def,
+                event_type,
+                self.backend.as_ref(),
+                self.subscriptions.as_ref(),
+            )
+            .await;
+        if !subs.is_empty() {
+            self.dispatcher.dispatch(event, subs);
+        }
+    }
+}
@@ -0,0 +1,367 @@
+//! Pure helpers for the CSV entity export (`GET /schemas/{schema}/entities.csv`).
+//!
+//! Records follow RFC 4180: fields are comma-separated, records end with
+//! CRLF, and any field containing a comma, quote, or line break is wrapped
+//! in double quotes with embedded quotes doubled. Cell values mirror what
+//! the generated views display for `@format("currency")` and
+//! `@format("percent")`, including the annotation's `locale` and
+//! `currency` options; everything else is emitted raw.
+
+use chrono::SecondsFormat;
+use schema_forge_core::types::{
+    DynamicValue, FieldDefinition, FormatType, DEFAULT_CURRENCY, DEFAULT_FORMAT_LOCALE,
+};
+
+use crate::conversions::dynamic_value_to_json;
+
+/// Turn a `snake_case` field name into a human label: `deal_value` becomes
+/// `Deal Value`.
+pub fn snake_to_label(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Quote a field when RFC 4180 requires it.
+pub fn escape_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Append one CRLF-terminated record to `out`.
+pub fn write_record<I, S>(cells: I, out: &mut String)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    for (i, cell) in cells.into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str(&escape_field(cell.as_ref()));
+    }
+    out.push_str("\r\n");
+}
+
+/// A field's `@format(...)` hint with its `locale` and `currency` options
+/// resolved to their defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellFormat<'a> {
+    pub format_type: FormatType,
+    pub locale: &'a str,
+    pub currency: &'a str,
+}
+
+impl<'a> CellFormat<'a> {
+    /// The display format declared on `field`, if any.
+    pub fn of(field: &'a FieldDefinition) -> Option<Self> {
+        field.format_type_hint().map(|format_type| Self {
+            format_type,
+            locale: field.format_locale().unwrap_or(DEFAULT_FORMAT_LOCALE),
+            currency: field.format_currency().unwrap_or(DEFAULT_CURRENCY),
+        })
+    }
+}
+
+/// Render a non-relation field value as a CSV cell (unescaped).
+///
+/// Null is the empty string, datetimes are ISO-8601 in UTC, enums are the
+/// raw variant, and array items are joined with `"; "`. `format` applies
+/// the currency or percent display format to numeric values.
+pub fn format_cell(value: &DynamicValue, format: Option<CellFormat<'_>>) -> String {
+    if let (Some(format), Some(n)) = (format, as_number(value)) {
+        match format.format_type {
+            FormatType::Currency => return format_currency(n, format.currency, format.locale),
+            FormatType::Percent => return format_percent(n, format.locale),
+            _ => {}
+        }
+    }
+    match value {
+        DynamicValue::Null => String::new(),
+        DynamicValue::Text(s) | DynamicValue::Enum(s) => s.clone(),
+        DynamicValue::Integer(i) => i.to_string(),
+        DynamicValue::Float(f) => f.to_string(),
+        DynamicValue::Boolean(b) => b.to_string(),
+        DynamicValue::DateTime(dt) => dt.to_rfc3339_opts(SecondsFormat::Millis, true),
+        DynamicValue::Duration(d) => d.to_string(),
+        DynamicValue::Uuid(u) => u.to_string(),
+        DynamicValue::Decimal(d) => d.to_string(),
+        DynamicValue::Ref(id) => id.as_str().to_string(),
+        DynamicValue::RefArray(ids) => ids
+            .iter()
+            .map(|id| id.as_str())
+            .collect::<Vec<_>>()
+            .join("; "),
+        DynamicValue::Array(items) => items
+            .iter()
+            .map(|item| format_cell(item, format))
+            .collect::<Vec<_>>()
+            .join("; "),
+        other => dynamic_value_to_json(other).to_string(),
+    }
+}
+
+fn as_number(value: &DynamicValue) -> Option<f64> {
+    match value {
+        DynamicValue::Integer(i) => Some(*i as f64),
+        DynamicValue::Float(f) => Some(*f),
+        DynamicValue::Decimal(d) => d.to_string().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Where a locale writes the currency symbol relative to the amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SymbolPlacement {
+    /// `$1,234.50`
+    Before,
+    /// `€ 1.234,50`
+    BeforeSpaced,
+    /// `1.234,50 €`
+    After,
+}
+
+/// Digit separators and currency placement for a locale's language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct NumberConventions {
+    group: &'static str,
+    decimal: char,
+    symbol: SymbolPlacement,
+}
+
+/// Conventions for a `language[-REGION]` tag, keyed on the language.
+/// Languages outside the table use the `en-US` conventions.
+fn conventions(locale: &str) -> NumberConventions {
+    let language = locale.split('-').next().unwrap_or(locale);
+    let (group, decimal, symbol) = match language {
+        "de" | "es" | "it" | "da" | "el" | "ro" | "hr" | "sl" => (".", ',', SymbolPlacement::After),
+        "nl" | "pt" | "id" => (".", ',', SymbolPlacement::BeforeSpaced),
+        "fr" => ("\u{202f}", ',', SymbolPlacement::After),
+        "sv" | "nb" | "no" | "fi" | "pl" | "cs" | "sk" | "ru" | "uk" | "hu" => {
+            ("\u{a0}", ',', SymbolPlacement::After)
+        }
+        _ => (",", '.', SymbolPlacement::Before),
+    };
+    NumberConventions {
+        group,
+        decimal,
+        symbol,
+    }
+}
+
+/// The display symbol for an ISO 4217 code; codes without a well-known
+/// symbol are written as the code itself (`CHF 1,234.50`).
+fn currency_symbol(code: &str) -> &str {
+    match code {
+        "USD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" => "¥",
+        "INR" => "₹",
+        "KRW" => "₩",
+        other => other,
+    }
+}
+
+/// `1234.5` becomes `$1,234.50` in `en-US` (matching the views' default)
+/// and `1.234,50 €` for `EUR` in `de-DE`.
+fn format_currency(n: f64, currency: &str, locale: &str) -> String {
+    let conventions = conventions(locale);
+    // Currencies without minor units render whole amounts.
+    let fixed = match currency {
+        "JPY" | "KRW" => format!("{:.0}", n.abs()),
+        _ => format!("{:.2}", n.abs()),
+    };
+    let (int_part, frac_part) = match fixed.split_once('.') {
+        Some((i, f)) => (i, format!("{}{f}", conventions.decimal)),
+        None => (fixed.as_str(), String::new()),
+    };
+    let is_zero = fixed.bytes().all(|b| b == b'0' || b == b'.');
+    let sign = if n < 0.0 && !is_zero { "-" } else { "" };
+    let amount = format!(
+        "{}{frac_part}",
+        group_thousands(int_part, conventions.group)
+    );
+    let symbol = currency_symbol(currency);
+    let spaced = symbol.len() == 3 && symbol.bytes().all(|b| b.is_ascii_uppercase());
+    match conventions.symbol {
+        SymbolPlacement::Before if !spaced => format!("{sign}{symbol}{amount}"),
+        SymbolPlacement::Before | SymbolPlacement::BeforeSpaced => {
+            format!("{sign}{symbol}\u{a0}{amount}")
+        }
+        SymbolPlacement::After => format!("{sign}{amount}\u{a0}{symbol}"),
+    }
+}
+
+/// Values in `[-1, 1]` are fractions (`0.125` becomes `12.5%`); larger
+/// magnitudes are taken as already scaled, as in the views. Separators
+/// follow `locale`.
+fn format_percent(n: f64, locale: &str) -> String {
+    let conventions = conventions(locale);
+    let scaled = if n.abs() <= 1.0 { n * 100.0 } else { n };
+    let fixed = format!("{:.2}", scaled.abs());
+    let fixed = fixed.trim_end_matches('0').trim_end_matches('.');
+    let (int_part, frac_part) = match fixed.split_once('.') {
+        Some((i, f)) => (i, format!("{}{f}", conventions.decimal)),
+        None => (fixed, String::new()),
+    };
+    let sign = if scaled < 0.0 && fixed != "0" {
+        "-"
+    } else {
+        ""
+    };
+    format!(
+        "{sign}{}{frac_part}%",
+        group_thousands(int_part, conventions.group)
+    )
+}
+
+fn group_thousands(digits: &str, separator: &str) -> String {
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3 * separator.len());
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push_str(separator);
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn labels_from_snake_case() {
+        assert_eq!(snake_to_label("deal_value"), "Deal Value");
+        assert_eq!(snake_to_label("name"), "Name");
+        assert_eq!(snake_to_label("ip_v4__addr"), "Ip V4 Addr");
+    }
+
+    #[test]
+    fn escapes_only_when_needed() {
+        assert_eq!(escape_field("plain"), "plain");
+        assert_eq!(escape_field("a,b"), "\"a,b\"");
+        assert_eq!(escape_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn records_end_with_crlf() {
+        let mut out = String::new();
+        write_record(["Name", "Notes"], &mut out);
+        write_record(["Acme, Inc.", ""], &mut out);
+        assert_eq!(out, "Name,Notes\r\n\"Acme, Inc.\",\r\n");
+    }
+
+    fn cell_format(format_type: FormatType, locale: &'static str) -> Option<CellFormat<'static>> {
+        Some(CellFormat {
+            format_type,
+            locale,
+            currency: DEFAULT_CURRENCY,
+        })
+    }
+
+    #[test]
+    fn currency_and_percent_formats() {
+        let currency = cell_format(FormatType::Currency, DEFAULT_FORMAT_LOCALE);
+        let percent = cell_format(FormatType::Percent, DEFAULT_FORMAT_LOCALE);
+        assert_eq!(
+            format_cell(&DynamicValue::Float(1234567.891), currency),
+            "$1,234,567.89"
+        );
+        assert_eq!(
+            format_cell(&DynamicValue::Integer(-42), currency),
+            "-$42.00"
+        );
+        assert_eq!(format_cell(&DynamicValue::Float(0.125), percent), "12.5%");
+        assert_eq!(format_cell(&DynamicValue::Integer(45), percent), "45%");
+        assert_eq!(format_cell(&DynamicValue::Null, currency), "");
+    }
+
+    #[test]
+    fn locale_swaps_separators_and_symbol_placement() {
+        let euro_de = Some(CellFormat {
+            format_type: FormatType::Currency,
+            locale: "de-DE",
+            currency: "EUR",
+        });
+        assert_eq!(
+            format_cell(&DynamicValue::Float(1234567.891), euro_de),
+            "1.234.567,89\u{a0}€"
+        );
+        assert_eq!(
+            format_cell(&DynamicValue::Integer(-42), euro_de),
+            "-42,00\u{a0}€"
+        );
+
+        let euro_nl = Some(CellFormat {
+            format_type: FormatType::Currency,
+            locale: "nl-NL",
+            currency: "EUR",
+        });
+        assert_eq!(
+            format_cell(&DynamicValue::Float(1234.5), euro_nl),
+            "€\u{a0}1.234,50"
+        );
+
+        let franc = Some(CellFormat {
+            format_type: FormatType::Currency,
+            locale: "en-US",
+            currency: "CHF",
+        });
+        assert_eq!(
+            format_cell(&DynamicValue::Float(1234.5), franc),
+            "CHF\u{a0}1,234.50"
+        );
+
+        let yen = Some(CellFormat {
+            format_type: FormatType::Currency,
+            locale: "ja-JP",
+            currency: "JPY",
+        });
+        assert_eq!(format_cell(&DynamicValue::Integer(123456), yen), "¥123,456");
+
+        let percent_fr = cell_format(FormatType::Percent, "fr-FR");
+        assert_eq!(
+            format_cell(&DynamicValue::Float(12345.5), percent_fr),
+            "12\u{202f}345,5%"
+        );
+    }
+
+    #[test]
+    fn raw_values() {
+        let dt = Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 0).unwrap();
+        assert_eq!(
+            format_cell(&DynamicValue::DateTime(dt), None),
+            "2026-03-01T09:30:00.000Z"
+        );
+        assert_eq!(
+            format_cell(&DynamicValue::Enum("Qualified".into()), None),
+            "Qualified"
+        );
+        assert_eq!(
+            format_cell(
+                &DynamicValue::Array(vec![
+                    DynamicValue::Text("a".into()),
+                    DynamicValue::Text("b".into())
+                ]),
+                None
+            ),
+            "a; b"
+        );
+        assert_eq!(format_cell(&DynamicValue::Boolean(true), None), "true");
+    }
+}
@@ -1,38 +1,62 @@
-use std::collections::{BTreeMap, HashMap, HashSet};
-use std::time::Duration;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::time::{Duration, Instant};
 
 use acton_service::middleware::Claims;
 use acton_service::prelude::ActorHandleInterface;
 use acton_service::state::AppState;
-use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
-use axum::response::IntoResponse;
+use axum::body::Body;
+use axum::extract::{OriginalUri, Path, Query, State};
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::response::{AppendHeaders, IntoResponse, Response};
 use axum::Json;
+use bytes::Bytes;
+use futures::StreamExt;
 use schema_forge_backend::entity::Entity;
-use schema_forge_core::query::{validate_filter, FieldPath, Filter, SortOrder};
+use schema_forge_core::query::{
+    validate_filter, validate_filter_with_targets, AggregateOp, AggregateQuery, Cursor, FieldPath,
+    Filter, SortOrder,
+};
 use schema_forge_core::types::{
-    Cardinality, DynamicValue, EntityId, FieldType, SchemaDefinition, SchemaName,
+    parse_decimal, parse_uuid, Cardinality, DefaultValue, DynamicValue, EntityId, FieldDefinition,
+    FieldName, FieldType, IsoDuration, RefLabelTemplate, RichTextConstraints, SchemaDefinition,
+    SchemaName, DELETED_AT_FIELD,
 };
 use serde::{Deserialize, Serialize};
 use tokio::sync::oneshot;
 use tracing::instrument;
 
-use super::query_params::{parse_fields_param, parse_filter_params, parse_sort_param};
+use super::csv;
+use super::query_params::{
+    coerce_string_value, filter_relation_targets, parse_datetime_filter, parse_expand_param,
+    parse_fields_param, parse_filter_key, parse_filter_params_with_targets, parse_sort_param,
+    FilterOp,
+};
 use crate::access::{
-    check_schema_access, entity_permissions, filter_entity_fields, inject_tenant_on_create,
-    inject_tenant_scope, schema_permissions, AccessAction, EntityPermissions,
+    can_write_hidden_fields, check_schema_access, entity_permissions, filter_entity_fields,
+    inject_tenant_on_create, inject_tenant_scope, inject_tenant_scope_aggregate,
+    reject_soft_deleted, schema_permissions, tenant_scope, AccessAction, EntityPermissions,
     FieldFilterDirection, OptionalClaims, SchemaPermissions,
 };
 use crate::actor::ForgeActor;
+use crate::audit::{changed_fields, AuditSink};
 use crate::config::SchemaForgeConfig;
+use crate::entity_hooks::EntityHooks;
 use crate::error::ForgeError;
+use crate::events::{ChangeFeed, ChangeOperation};
 use crate::hooks::{
     run_before_hook, DispatchHook, HookDispatchActor, HookDispatcher, HookInvocation, HooksConfig,
 };
+use crate::idempotency::{
+    body_fingerprint, is_valid_idempotency_key, IdempotencyClaim, IdempotencyGuard,
+    IdempotencyScope, IdempotencyStore, IDEMPOTENCY_KEY_HEADER, IDEMPOTENT_REPLAY_HEADER,
+};
 use crate::messages::{
-    CreateEntity, DeleteEntity, GetEntity, GetHookDispatcher, GetRecordAccessPolicy, GetSchema,
-    GetSchemasBatch, GetTenantConfig, QueryEntities, ReplyChannel, UpdateEntity,
+    AggregateEntities, CountEntities, CreateEntities, CreateEntity, DeleteEntity, GetAuditSink,
+    GetBackend, GetChangeFeed, GetEntity, GetEntityHooks, GetHookDispatcher, GetIdempotencyStore,
+    GetRecordAccessPolicy, GetSchema, GetSchemasBatch, GetTenantConfig, PatchEntity, QueryEntities,
+    ReplyChannel, RestoreEntity, UpdateEntity,
 };
+use crate::post_write::{CommittedWrite, WebhookTarget, WriteObservers};
 use schema_forge_core::types::HookEvent;
 use std::sync::Arc;
 
@@ -78,10 +102,40 @@ async fn fetch_policy_store(
     })
 }
 
+// ---------------------------------------------------------------------------
+// Audit helpers
+// ---------------------------------------------------------------------------
+
+/// Retrieve the audit sink from the actor, or `None` when auditing is not
+/// configured. Handlers skip before/after snapshots entirely in that case.
+async fn fetch_audit_sink(
+    forge: &acton_service::prelude::ActorHandle,
+) -> Option<Arc<dyn AuditSink>> {
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetAuditSink {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    ask_forge(rx).await.ok().flatten()
+}
+
 // ---------------------------------------------------------------------------
 // Hook dispatch helpers
 // ---------------------------------------------------------------------------
 
+/// Retrieve the in-process entity hooks from the actor. A lost round-trip
+/// yields an empty registry, like a deployment that registered none.
+async fn fetch_entity_hooks(forge: &acton_service::prelude::ActorHandle) -> EntityHooks {
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetEntityHooks {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    ask_forge(rx).await.unwrap_or_default()
+}
+
 /// Retrieve the hook dispatcher from the actor, or `None` if hooks are
 /// globally disabled or no dispatcher was wired at init time. Returns
 /// `None` (not an error) so callers can short-circuit with no extra cost
@@ -253,147 +307,65 @@ async fn fire_after_hook(state: &AppState<SchemaForgeConfig>, ctx: AfterHookCtx,
 }
 
 // ---------------------------------------------------------------------------
-// Webhook dispatch helper
+// Post-write helpers
 // ---------------------------------------------------------------------------
 
-/// Fire webhook notifications for a CRUD event, if the schema has webhooks enabled.
-///
-/// This is non-blocking: webhook delivery happens in background tasks.
-async fn dispatch_webhook(
+/// Retrieve the change feed that `GET /schemas/{schema}/events` subscribers
+/// listen on.
+async fn fetch_change_feed(
+    forge: &acton_service::prelude::ActorHandle,
+) -> Result<ChangeFeed, ForgeError> {
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetChangeFeed {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    ask_forge(rx).await
+}
+
+/// Collect where this request's committed writes are reported: the audit
+/// sink, the change feed, and, for schemas that declare `@webhook` while
+/// webhooks are enabled, webhook subscribers.
+async fn write_observers(
     state: &AppState<SchemaForgeConfig>,
+    forge: &acton_service::prelude::ActorHandle,
     schema_def: &SchemaDefinition,
-    event: crate::webhook::WebhookEvent,
-    event_type: &str,
-) {
+) -> WriteObservers {
     let webhook_config = &state.config().custom.schema_forge.webhooks;
-    let dispatcher = match crate::webhook::get_dispatcher(webhook_config) {
-        Some(d) => d,
-        None => return,
-    };
-
-    if !schema_def.has_webhooks() {
-        return;
-    }
-    if !schema_def.webhook_events().contains(&event_type) {
-        return;
-    }
-
-    // Resolve subscriptions: DSL inline + runtime (via actor query)
-    let mut subs = Vec::new();
-
-    // 1. Inline DSL subscription
-    if let Some(schema_forge_core::types::Annotation::Webhook {
-        url: Some(url),
-        secret,
-        ..
-    }) = schema_def.webhook_annotation()
-    {
-        subs.push(crate::webhook::ResolvedSubscription {
-            url: url.clone(),
-            secret: secret.clone(),
-            retry_count: None,
-            timeout_seconds: None,
-        });
-    }
-
-    // 2. Runtime subscriptions via actor query
-    let forge = match state.actor::<ForgeActor>() {
-        Some(f) => f,
-        None => {
-            if !subs.is_empty() {
-                dispatcher.dispatch(event, subs);
-            }
-            return;
-        }
-    };
-
-    // Query WebhookSubscription entities for this schema
-    let (tx, rx) = oneshot::channel();
-    let ws_schema = SchemaName::new("WebhookSubscription");
-    if let Ok(ws_name) = ws_schema {
-        // First get the WebhookSubscription schema def to get its ID
-        let (stx, srx) = oneshot::channel();
-        forge
-            .send(crate::messages::GetSchema {
-                name: ws_name.as_str().to_string(),
-                reply: ReplyChannel::new(stx),
-            })
-            .await;
-
-        if let Ok(Some(ws_def)) = ask_forge(srx).await {
-            use schema_forge_core::query::{FieldPath, Filter, Query as CoreQuery};
-            let query = CoreQuery::new(ws_def.id.clone()).with_filter(Filter::and(vec![
-                Filter::eq(
-                    FieldPath::parse("target_schema").unwrap(),
-                    DynamicValue::Text(schema_def.name.as_str().to_string()),
-                ),
-                Filter::eq(
-                    FieldPath::parse("active").unwrap(),
-                    DynamicValue::Boolean(true),
-                ),
-            ]));
-
+    let webhooks = match crate::webhook::get_dispatcher(webhook_config) {
+        Some(dispatcher) if schema_def.has_webhooks() => {
+            let (tx, rx) = oneshot::channel();
             forge
-                .send(QueryEntities {
-                    query,
+                .send(GetBackend {
                     reply: ReplyChannel::new(tx),
                 })
                 .await;
-
-            if let Ok(Ok(result)) = ask_forge(rx).await {
-                for entity in &result.entities {
-                    if let Some(sub) = resolve_runtime_subscription(entity, event_type) {
-                        subs.push(sub);
-                    }
-                }
-            }
-        }
-    }
-
-    if !subs.is_empty() {
-        dispatcher.dispatch(event, subs);
-    }
-}
-
-/// Convert a WebhookSubscription entity to a ResolvedSubscription.
-fn resolve_runtime_subscription(
-    entity: &Entity,
-    event_type: &str,
-) -> Option<crate::webhook::ResolvedSubscription> {
-    // Check event filter
-    if let Some(DynamicValue::Array(events)) = entity.fields.get("events") {
-        if !events.is_empty()
-            && !events
-                .iter()
-                .any(|e| matches!(e, DynamicValue::Text(t) if t == event_type))
-        {
-            return None;
+            let (stx, srx) = oneshot::channel();
+            forge
+                .send(GetSchema {
+                    name: crate::webhook::WEBHOOK_SUBSCRIPTION_SCHEMA.to_string(),
+                    reply: ReplyChannel::new(stx),
+                })
+                .await;
+            let subscriptions = ask_forge(srx).await.ok().flatten();
+            ask_forge(rx)
+                .await
+                .ok()
+                .flatten()
+                .map(|backend| WebhookTarget {
+                    dispatcher: dispatcher.clone(),
+                    backend,
+                    subscriptions,
+                })
         }
-    }
-
-    let url = match entity.fields.get("url") {
-        Some(DynamicValue::Text(u)) => u.clone(),
-        _ => return None,
-    };
-    let secret = match entity.fields.get("secret") {
-        Some(DynamicValue::Text(s)) if !s.is_empty() => Some(s.clone()),
         _ => None,
     };
-    let retry_count = match entity.fields.get("retry_count") {
-        Some(DynamicValue::Integer(n)) => Some(*n as u32),
-        _ => None,
-    };
-    let timeout_seconds = match entity.fields.get("timeout_seconds") {
-        Some(DynamicValue::Integer(n)) => Some(*n as u32),
-        _ => None,
-    };
-
-    Some(crate::webhook::ResolvedSubscription {
-        url,
-        secret,
-        retry_count,
-        timeout_seconds,
-    })
+    WriteObservers {
+        audit_sink: fetch_audit_sink(forge).await,
+        change_feed: fetch_change_feed(forge).await.ok(),
+        webhooks,
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -407,6 +379,20 @@ pub struct EntityRequest {
     pub fields: serde_json::Map<String, serde_json::Value>,
 }
 
+/// Request body for creating several entities in one call.
+#[derive(Debug, Deserialize)]
+pub struct BatchCreateRequest {
+    /// One JSON field map per entity, written in order.
+    pub entities: Vec<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Response body for the batch create endpoint.
+#[derive(Debug, Serialize)]
+pub struct BatchCreateResponse {
+    /// The created entities, in request order.
+    pub entities: Vec<EntityResponse>,
+}
+
 /// Response for a single entity.
 #[derive(Debug, Serialize)]
 pub struct EntityResponse {
@@ -416,6 +402,11 @@ pub struct EntityResponse {
     pub schema: String,
     /// The entity fields.
     pub fields: serde_json::Map<String, serde_json::Value>,
+    /// The stored version, also sent as the `ETag` header. Send it back in
+    /// `If-Match` on PUT/PATCH to reject writes over someone else's change.
+    /// Absent when the entity carries no version (e.g. projected reads).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
     /// Cedar-derived per-entity permissions for the calling user. Set on
     /// read paths (`GET` list/detail) so the client can render row-level
     /// affordances honestly; absent on write responses where the caller's
@@ -431,13 +422,36 @@ pub struct ListEntitiesResponse {
     pub entities: Vec<EntityResponse>,
     /// The count of entities in this response.
     pub count: usize,
-    /// The total count of matching entities before pagination, if available.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The total count of matching entities before pagination; `null` when
+    /// the caller skipped the count with `?count=false`.
     pub total_count: Option<usize>,
+    /// Opaque cursor for the next page; pass it back as `after` to continue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
     /// Schema-level permissions for the caller, populated on read paths so
     /// the client can render the "New" affordance without round-tripping.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub permissions: Option<SchemaPermissions>,
+    /// Backend timings, present only when `?debug=timing` was requested and
+    /// `debug_timing` is enabled in config.
+    #[serde(rename = "_timing", skip_serializing_if = "Option::is_none")]
+    pub timing: Option<ListTiming>,
+}
+
+/// Wall-clock milliseconds spent in each backend phase of a list request.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListTiming {
+    /// The page query.
+    pub query_ms: f64,
+    /// The `total_count` query; `None` when the count was skipped
+    /// (`?count=false`).
+    pub count_ms: Option<f64>,
+    /// Derived-collection population plus relation display resolution.
+    pub ref_resolve_ms: f64,
+}
+
+fn elapsed_ms(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
 }
 
 /// Request body for POST query endpoint.
@@ -455,6 +469,9 @@ pub struct EntityQueryBody {
     /// Number of entities to skip.
     #[serde(default)]
     pub offset: Option<usize>,
+    /// Opaque cursor from a previous page's `next_cursor`.
+    #[serde(default)]
+    pub after: Option<String>,
     /// Field projection — only return these fields in the response.
     #[serde(default)]
     pub fields: Option<Vec<String>>,
@@ -469,12 +486,75 @@ pub struct EntityQueryBody {
     /// extra COUNT(*) round-trip when the caller doesn't need it.
     #[serde(default = "default_true_bool")]
     pub count: bool,
+    /// Return soft-deleted entities of a `@soft_delete` schema as well.
+    #[serde(default)]
+    pub include_deleted: bool,
 }
 
 const fn default_true_bool() -> bool {
     true
 }
 
+/// Request body for the POST aggregate endpoint.
+#[derive(Debug, Deserialize)]
+pub struct AggregateBody {
+    /// Raw JSON filter, in the same format as the query endpoint.
+    #[serde(default)]
+    pub filter: Option<serde_json::Value>,
+    /// Metrics to compute: `count`, `sum:<field>`, `avg:<field>`,
+    /// `min:<field>`, or `max:<field>`.
+    pub metrics: Vec<String>,
+    /// Fields to group by. Empty means one row over all matching entities.
+    #[serde(default)]
+    pub group_by: Vec<String>,
+    /// Aggregate over soft-deleted entities as well.
+    #[serde(default)]
+    pub include_deleted: bool,
+}
+
+/// Response body for the POST aggregate endpoint.
+#[derive(Debug, Serialize)]
+pub struct AggregateResponse {
+    /// One row per group (a single row when `group_by` is empty).
+    pub rows: Vec<AggregateRow>,
+}
+
+/// One group of an aggregate response.
+#[derive(Debug, Serialize)]
+pub struct AggregateRow {
+    /// The group's value for each `group_by` field.
+    pub group: serde_json::Map<String, serde_json::Value>,
+    /// Metric name (as requested) mapped to its computed value.
+    pub metrics: BTreeMap<String, f64>,
+}
+
+/// Response body for the GET count endpoint.
+#[derive(Debug, Serialize)]
+pub struct CountResponse {
+    /// Number of entities matching the filter.
+    pub count: usize,
+}
+
+/// Request body for the POST bulk delete endpoint.
+#[derive(Debug, Deserialize)]
+pub struct BulkDeleteBody {
+    /// Raw JSON filter, in the same format as the query endpoint. Required
+    /// unless `?all=true` is set.
+    #[serde(default)]
+    pub filter: Option<serde_json::Value>,
+}
+
+/// Response body for the POST bulk delete endpoint.
+#[derive(Debug, Serialize)]
+pub struct BulkDeleteResponse {
+    /// Entities matching the filter, all of which the caller may delete.
+    pub matched: usize,
+    /// Entities deleted. Always zero on a dry run.
+    pub deleted: usize,
+    /// Whether this was a `?dry_run=true` request.
+    pub dry_run: bool,
+}
+
 /// A single sort clause in a POST query body.
 #[derive(Debug, Deserialize)]
 pub struct SortClause {
@@ -550,31 +630,52 @@ pub fn json_to_entity_fields_with_mode(
                 ));
                 continue;
             }
+            if def.is_computed() {
+                errors.push(format!(
+                    "field '{key}': is computed from other fields and cannot be written"
+                ));
+                continue;
+            }
+        }
+
+        // `@empty_as_null`: a blank string is stored as null, so a required
+        // field rejects it exactly like an absent one.
+        if let (Some(def), Some("")) = (field_def, value.as_str()) {
+            if schema.empty_as_null(def) {
+                if def.is_required() {
+                    errors.push(format!("required field '{key}' must not be empty"));
+                } else {
+                    fields.insert(key.clone(), DynamicValue::Null);
+                }
+                continue;
+            }
         }
 
         let dynamic_value = if let Some(def) = field_def {
-            convert_json_with_type_hint(value, &def.field_type)
+            convert_field_value(key, value, &def.field_type, &mut errors)
         } else {
             // Unknown field -- convert based on JSON type
-            convert_json_untyped(value)
+            match convert_json_untyped(value) {
+                Ok(dv) => Some(dv),
+                Err(msg) => {
+                    errors.push(format!("field '{key}': {msg}"));
+                    None
+                }
+            }
         };
 
-        match dynamic_value {
-            Ok(dv) => {
-                fields.insert(key.clone(), dv);
-            }
-            Err(msg) => {
-                errors.push(format!("field '{key}': {msg}"));
-            }
+        if let Some(dv) = dynamic_value {
+            fields.insert(key.clone(), dv);
         }
     }
 
     // Check for required fields that are missing — only in Replace mode.
-    // PATCH explicitly tolerates partial payloads. Derived fields are
-    // virtual and are never a legitimate source of "missing required".
+    // PATCH explicitly tolerates partial payloads. Derived and computed
+    // fields are virtual and are never a legitimate source of "missing
+    // required".
     if mode == ConversionMode::Replace {
         for field_def in &schema.fields {
-            if field_def.is_derived() {
+            if !field_def.is_stored() {
                 continue;
             }
             if field_def.is_required() && !json_fields.contains_key(field_def.name.as_str()) {
@@ -594,17 +695,147 @@ pub fn json_to_entity_fields_with_mode(
     }
 }
 
+/// Required fields that are absent or null in a fully merged field map.
+///
+/// Used by PATCH (REST and the GraphQL `update` mutation) after merging the
+/// request onto the stored entity: a patch that omits a required field is
+/// fine, one that nulls it out is not.
+pub(crate) fn missing_required_fields(
+    schema: &SchemaDefinition,
+    fields: &BTreeMap<String, DynamicValue>,
+) -> Vec<String> {
+    schema
+        .fields
+        .iter()
+        .filter(|f| f.is_required() && f.is_stored())
+        .filter(|f| matches!(fields.get(f.name.as_str()), None | Some(DynamicValue::Null)))
+        .map(|f| format!("required field '{}' is missing", f.name.as_str()))
+        .collect()
+}
+
+/// Convert one field's JSON value, recursing into composites and arrays so
+/// every error names the offending location: sub-fields by dotted path
+/// (`address.zip`) and array elements by index (`addresses[1].zip`).
+///
+/// Errors are appended to `errors`; `None` means the value was rejected.
+/// A submitted composite replaces the stored one wholesale, so its
+/// required sub-fields are enforced in both [`ConversionMode`]s.
+fn convert_field_value(
+    path: &str,
+    value: &serde_json::Value,
+    field_type: &FieldType,
+    errors: &mut Vec<String>,
+) -> Option<DynamicValue> {
+    match (field_type, value) {
+        (FieldType::Composite(sub_fields), serde_json::Value::Object(map)) => {
+            let before = errors.len();
+            let mut out = BTreeMap::new();
+            for (key, sub_value) in map {
+                let sub_path = format!("{path}.{key}");
+                let converted = match sub_fields.iter().find(|f| f.name.as_str() == key) {
+                    Some(def) => convert_field_value(&sub_path, sub_value, &def.field_type, errors),
+                    None => match convert_json_untyped(sub_value) {
+                        Ok(dv) => Some(dv),
+                        Err(msg) => {
+                            errors.push(format!("field '{sub_path}': {msg}"));
+                            None
+                        }
+                    },
+                };
+                if let Some(dv) = converted {
+                    out.insert(key.clone(), dv);
+                }
+            }
+            for def in sub_fields {
+                if def.is_required() && !map.contains_key(def.name.as_str()) {
+                    errors.push(format!(
+                        "required field '{path}.{}' is missing",
+                        def.name.as_str()
+                    ));
+                }
+            }
+            (errors.len() == before).then_some(DynamicValue::Composite(out))
+        }
+        (FieldType::Composite(_), serde_json::Value::Null) => Some(DynamicValue::Null),
+        (FieldType::Composite(_), other) => {
+            errors.push(format!("field '{path}': expected object, got {other}"));
+            None
+        }
+        (FieldType::Array(inner, constraints), serde_json::Value::Array(items)) => {
+            let before = errors.len();
+            if let Err(msg) = constraints.check_len(items.len()) {
+                errors.push(format!("field '{path}': {msg}"));
+            }
+            let converted: Vec<_> = items
+                .iter()
+                .enumerate()
+                .filter_map(|(i, item)| {
+                    convert_field_value(&format!("{path}[{i}]"), item, inner, errors)
+                })
+                .collect();
+            (errors.len() == before).then_some(DynamicValue::Array(converted))
+        }
+        _ => match convert_json_with_type_hint(value, field_type) {
+            Ok(dv) => Some(dv),
+            Err(msg) => {
+                errors.push(format!("field '{path}': {msg}"));
+                None
+            }
+        },
+    }
+}
+
+/// Sanitize a submitted rich text value when the field opts in, then
+/// check its plaintext length. The sanitized HTML is what gets stored.
+pub(crate) fn rich_text_value(
+    html: &str,
+    constraints: &RichTextConstraints,
+) -> Result<DynamicValue, String> {
+    let html = if constraints.sanitize {
+        sanitize_html(html, constraints.allowed_tags.as_deref())
+    } else {
+        html.to_string()
+    };
+    constraints.check_plaintext_len(&html)?;
+    Ok(DynamicValue::Text(html))
+}
+
+/// Strip everything outside the allowlist from `html`: `<script>` and
+/// `<style>` elements with their content, event-handler attributes, and
+/// `javascript:` URLs. `None` keeps ammonia's default tag allowlist.
+fn sanitize_html(html: &str, allowed_tags: Option<&[String]>) -> String {
+    let mut builder = ammonia::Builder::default();
+    if let Some(tags) = allowed_tags {
+        // ammonia panics if a tag is both allowed and content-stripped.
+        builder.tags(
+            tags.iter()
+                .map(String::as_str)
+                .filter(|t| !t.eq_ignore_ascii_case("script") && !t.eq_ignore_ascii_case("style"))
+                .collect(),
+        );
+    }
+    builder.clean(html).to_string()
+}
+
 /// Convert a JSON value to a DynamicValue using the field type as a hint.
 fn convert_json_with_type_hint(
     value: &serde_json::Value,
     field_type: &FieldType,
 ) -> Result<DynamicValue, String> {
     match field_type {
-        FieldType::Text(_) | FieldType::RichText => match value {
-            serde_json::Value::String(s) => Ok(DynamicValue::Text(s.clone())),
+        FieldType::Text(constraints) => match value {
+            serde_json::Value::String(s) => {
+                constraints.check_pattern(s)?;
+                Ok(DynamicValue::Text(s.clone()))
+            }
             serde_json::Value::Null => Ok(DynamicValue::Null),
             other => Ok(DynamicValue::Text(other.to_string())),
         },
+        FieldType::RichText(constraints) => match value {
+            serde_json::Value::String(s) => rich_text_value(s, constraints),
+            serde_json::Value::Null => Ok(DynamicValue::Null),
+            other => rich_text_value(&other.to_string(), constraints),
+        },
         FieldType::Integer(_) => match value {
             serde_json::Value::Number(n) => n
                 .as_i64()
@@ -621,6 +852,18 @@ fn convert_json_with_type_hint(
             serde_json::Value::Null => Ok(DynamicValue::Null),
             _ => Err(format!("expected float, got {value}")),
         },
+        // Strings are the exact form; numbers are read from their JSON text
+        // rather than through f64.
+        FieldType::Decimal { scale } => match value {
+            serde_json::Value::String(s) => parse_decimal(s, *scale)
+                .map(DynamicValue::Decimal)
+                .map_err(|e| e.to_string()),
+            serde_json::Value::Number(n) => parse_decimal(&n.to_string(), *scale)
+                .map(DynamicValue::Decimal)
+                .map_err(|e| e.to_string()),
+            serde_json::Value::Null => Ok(DynamicValue::Null),
+            _ => Err(format!("expected decimal string, got {value}")),
+        },
         FieldType::Boolean => match value {
             serde_json::Value::Bool(b) => Ok(DynamicValue::Boolean(*b)),
             serde_json::Value::Null => Ok(DynamicValue::Null),
@@ -636,6 +879,26 @@ fn convert_json_with_type_hint(
             serde_json::Value::Null => Ok(DynamicValue::Null),
             _ => Err(format!("expected datetime string, got {value}")),
         },
+        FieldType::Duration => match value {
+            serde_json::Value::String(s) => IsoDuration::parse_input(s)
+                .map(DynamicValue::Duration)
+                .map_err(|e| e.to_string()),
+            // A bare number is a count of seconds.
+            serde_json::Value::Number(n) => n
+                .as_u64()
+                .and_then(IsoDuration::from_seconds)
+                .map(DynamicValue::Duration)
+                .ok_or_else(|| format!("expected a whole number of seconds, got {n}")),
+            serde_json::Value::Null => Ok(DynamicValue::Null),
+            _ => Err(format!("expected duration string or seconds, got {value}")),
+        },
+        FieldType::Uuid => match value {
+            serde_json::Value::String(s) => parse_uuid(s)
+                .map(DynamicValue::Uuid)
+                .map_err(|e| e.to_string()),
+            serde_json::Value::Null => Ok(DynamicValue::Null),
+            _ => Err(format!("expected UUID string, got {value}")),
+        },
         FieldType::Enum(_) => match value {
             serde_json::Value::String(s) => Ok(DynamicValue::Enum(s.clone())),
             serde_json::Value::Null => Ok(DynamicValue::Null),
@@ -668,7 +931,7 @@ fn convert_json_with_type_hint(
             serde_json::Value::Null => Ok(DynamicValue::Null),
             _ => Err(format!("expected entity reference string, got {value}")),
         },
-        FieldType::Array(inner) => match value {
+        FieldType::Array(inner, _) => match value {
             serde_json::Value::Array(arr) => {
                 let items = arr
                     .iter()
@@ -727,7 +990,7 @@ fn coerce_dynamic_value_with_type_hint(
     field_type: &FieldType,
 ) -> Result<DynamicValue, String> {
     match field_type {
-        FieldType::Text(_) | FieldType::RichText => match value {
+        FieldType::Text(_) | FieldType::RichText(_) => match value {
             DynamicValue::Text(_) | DynamicValue::Null => Ok(value),
             other => Ok(DynamicValue::Text(other.to_string())),
         },
@@ -748,6 +1011,19 @@ fn coerce_dynamic_value_with_type_hint(
                 .map_err(|e| format!("invalid float '{s}': {e}")),
             other => Err(format!("expected float, got {other}")),
         },
+        FieldType::Decimal { scale } => match value {
+            DynamicValue::Decimal(_) | DynamicValue::Null => Ok(value),
+            DynamicValue::Integer(i) => parse_decimal(&i.to_string(), *scale)
+                .map(DynamicValue::Decimal)
+                .map_err(|e| e.to_string()),
+            DynamicValue::Float(f) => parse_decimal(&f.to_string(), *scale)
+                .map(DynamicValue::Decimal)
+                .map_err(|e| e.to_string()),
+            DynamicValue::Text(s) => parse_decimal(&s, *scale)
+                .map(DynamicValue::Decimal)
+                .map_err(|e| e.to_string()),
+            other => Err(format!("expected decimal, got {other}")),
+        },
         FieldType::Boolean => match value {
             DynamicValue::Boolean(_) | DynamicValue::Null => Ok(value),
             DynamicValue::Text(s) => s
@@ -764,6 +1040,25 @@ fn coerce_dynamic_value_with_type_hint(
                 .map_err(|e| format!("invalid datetime '{s}': {e}")),
             other => Err(format!("expected datetime, got {other}")),
         },
+        FieldType::Duration => match value {
+            DynamicValue::Duration(_) | DynamicValue::Null => Ok(value),
+            DynamicValue::Text(s) => IsoDuration::parse_input(&s)
+                .map(DynamicValue::Duration)
+                .map_err(|e| e.to_string()),
+            DynamicValue::Integer(n) => u64::try_from(n)
+                .ok()
+                .and_then(IsoDuration::from_seconds)
+                .map(DynamicValue::Duration)
+                .ok_or_else(|| format!("expected a whole number of seconds, got {n}")),
+            other => Err(format!("expected duration, got {other}")),
+        },
+        FieldType::Uuid => match value {
+            DynamicValue::Uuid(_) | DynamicValue::Null => Ok(value),
+            DynamicValue::Text(s) => parse_uuid(&s)
+                .map(DynamicValue::Uuid)
+                .map_err(|e| e.to_string()),
+            other => Err(format!("expected UUID, got {other}")),
+        },
         FieldType::Enum(_) => match value {
             DynamicValue::Enum(_) | DynamicValue::Null => Ok(value),
             DynamicValue::Text(s) => Ok(DynamicValue::Enum(s)),
@@ -789,7 +1084,7 @@ fn coerce_dynamic_value_with_type_hint(
             }
             other => Err(format!("expected entity reference, got {other}")),
         },
-        FieldType::Array(inner) => match value {
+        FieldType::Array(inner, _) => match value {
             DynamicValue::Array(items) => {
                 let coerced = items
                     .into_iter()
@@ -814,17 +1109,116 @@ fn entity_to_response(entity: &Entity, schema: &SchemaDefinition) -> EntityRespo
     crate::conversions::entity_to_response(entity, schema)
 }
 
+/// Convert a schema `default(...)` literal into the runtime value the
+/// backend would have stored for a field of `field_type`.
+///
+/// Returns `None` when the literal cannot represent a value of that type
+/// (e.g. a string default on a datetime field) — callers leave the field
+/// absent rather than guessing.
+fn default_to_dynamic_value(default: &DefaultValue, field_type: &FieldType) -> Option<DynamicValue> {
+    match (default, field_type) {
+        (DefaultValue::String(s), FieldType::Enum(_)) => Some(DynamicValue::Enum(s.clone())),
+        (DefaultValue::String(s), FieldType::Text(_) | FieldType::RichText(_)) => {
+            Some(DynamicValue::Text(s.clone()))
+        }
+        (DefaultValue::Integer(i), FieldType::Integer(_)) => Some(DynamicValue::Integer(*i)),
+        (DefaultValue::Integer(i), FieldType::Float(_)) => Some(DynamicValue::Float(*i as f64)),
+        (DefaultValue::Float(_), FieldType::Float(_)) => default.as_f64().map(DynamicValue::Float),
+        (DefaultValue::Boolean(b), FieldType::Boolean) => Some(DynamicValue::Boolean(*b)),
+        (DefaultValue::String(s), FieldType::Duration) => {
+            IsoDuration::parse(s).ok().map(DynamicValue::Duration)
+        }
+        (DefaultValue::String(s), FieldType::Uuid) => parse_uuid(s).ok().map(DynamicValue::Uuid),
+        (DefaultValue::Integer(i), FieldType::Decimal { scale }) => {
+            parse_decimal(&i.to_string(), *scale)
+                .ok()
+                .map(DynamicValue::Decimal)
+        }
+        (DefaultValue::Float(s), FieldType::Decimal { scale }) => {
+            parse_decimal(s, *scale).ok().map(DynamicValue::Decimal)
+        }
+        _ => None,
+    }
+}
+
+/// Insert the schema's `default(...)` value for every field the backend
+/// did not echo back on a create.
+///
+/// Only absent keys are filled: an explicit `null` returned by the backend
+/// is authoritative (the client asked for it) and is left untouched. Not
+/// for updates: a key missing from an existing row was never stored (e.g.
+/// the field was added after the row was written), and a later `GET`
+/// would not return a default for it either.
+pub(crate) fn fill_missing_defaults(
+    schema: &SchemaDefinition,
+    fields: &mut BTreeMap<String, DynamicValue>,
+) {
+    for field_def in &schema.fields {
+        if !field_def.is_stored() || fields.contains_key(field_def.name.as_str()) {
+            continue;
+        }
+        let Some(default) = field_def.default_value() else {
+            continue;
+        };
+        if let Some(value) = default_to_dynamic_value(default, &field_def.field_type) {
+            fields.insert(field_def.name.as_str().to_string(), value);
+        }
+    }
+}
+
+/// Bring a freshly persisted entity up to the shape a subsequent `GET`
+/// would return, so write responses are authoritative and clients never
+/// need to re-fetch after create/update.
+///
+/// Populates derived inverse collections; creates also get the schema
+/// defaults the backend did not echo back (see [`fill_missing_defaults`]).
+/// Must run before read-side field filtering so restricted fields are
+/// still scrubbed afterwards, and before `after_change` dispatch so hooks
+/// see the same post-persist view.
+async fn complete_written_entity(
+    forge: &acton_service::prelude::ActorHandle,
+    schema_def: &SchemaDefinition,
+    entity: &mut Entity,
+    claims: Option<&Claims>,
+    operation: ChangeOperation,
+) -> Result<(), ForgeError> {
+    if operation == ChangeOperation::Created {
+        fill_missing_defaults(schema_def, &mut entity.fields);
+    }
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetTenantConfig {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let tenant_config = ask_forge(rx).await?;
+    populate_derived_collections(
+        forge,
+        schema_def,
+        std::slice::from_mut(entity),
+        claims,
+        &tenant_config,
+    )
+    .await
+}
+
 /// Reject any client-supplied request body that names a `@hidden` field.
 ///
 /// `@hidden` fields are out-of-band: they're populated by privileged
 /// internal consumers (like `EntityAuthStore` writing a `password_hash`)
-/// and must never accept input from a user-facing endpoint. This guard
-/// lives at the request-deserialization boundary so `create`, `update`,
-/// and `patch` all share the same enforcement.
+/// and platform admins (see [`can_write_hidden_fields`]), never by
+/// ordinary callers. This guard lives at the request-deserialization
+/// boundary so `create`, `update`, and `patch` all share the same
+/// enforcement.
 fn reject_hidden_fields_in_body(
     schema: &SchemaDefinition,
     body_fields: &serde_json::Map<String, serde_json::Value>,
+    claims: Option<&Claims>,
 ) -> Result<(), ForgeError> {
+    if can_write_hidden_fields(claims) {
+        return Ok(());
+    }
     let offenders: Vec<String> = body_fields
         .keys()
         .filter(|name| {
@@ -847,24 +1241,68 @@ fn reject_hidden_fields_in_body(
     }
 }
 
-/// Convert a `DynamicValue` to a JSON value.
-#[cfg(test)]
-fn dynamic_value_to_json(value: &DynamicValue) -> serde_json::Value {
-    crate::conversions::dynamic_value_to_json(value)
-}
-
-/// Validate and parse a schema name from a path parameter.
-fn validate_schema_name(name: &str) -> Result<SchemaName, ForgeError> {
-    SchemaName::new(name).map_err(|_| ForgeError::InvalidSchemaName {
-        name: name.to_string(),
-    })
-}
-
-/// Convert a raw JSON value into a `Filter` using schema type hints.
+/// Reject a write that would change the stored value of an `@immutable`
+/// field.
 ///
-/// Accepts a JSON object with `"op"`, `"field"`, `"value"` / `"values"` / `"filters"` / `"filter"` keys.
-/// Values are plain JSON primitives — types are inferred from schema field definitions.
-pub fn json_to_filter(
+/// Only fields present in `incoming` are checked. A field with no stored
+/// value (absent or null) may still be set once, and re-sending the stored
+/// value is a no-op, so clients that round-trip the whole entity keep
+/// working.
+pub(crate) fn reject_immutable_changes(
+    schema: &SchemaDefinition,
+    existing: &BTreeMap<String, DynamicValue>,
+    incoming: &BTreeMap<String, DynamicValue>,
+) -> Result<(), ForgeError> {
+    let offenders: Vec<String> = schema
+        .fields
+        .iter()
+        .filter(|f| f.is_immutable())
+        .filter_map(|f| {
+            let name = f.name.as_str();
+            let new = incoming.get(name)?;
+            let old = existing.get(name)?;
+            if matches!(old, DynamicValue::Null) {
+                return None;
+            }
+            // Backends may hand stored values back untyped (an enum or
+            // duration as plain text); compare in the field's own type.
+            let old = coerce_dynamic_value_with_type_hint(old.clone(), &f.field_type)
+                .unwrap_or_else(|_| old.clone());
+            (old != *new).then(|| name.to_string())
+        })
+        .collect();
+    if offenders.is_empty() {
+        Ok(())
+    } else {
+        Err(ForgeError::ValidationFailed {
+            details: offenders
+                .into_iter()
+                .map(|name| format!("field '{name}' is immutable and cannot be changed"))
+                .collect(),
+        })
+    }
+}
+
+/// Convert a `DynamicValue` to a JSON value.
+#[cfg(test)]
+fn dynamic_value_to_json(value: &DynamicValue) -> serde_json::Value {
+    crate::conversions::dynamic_value_to_json(value)
+}
+
+/// Validate and parse a schema name from a path parameter.
+fn validate_schema_name(name: &str) -> Result<SchemaName, ForgeError> {
+    SchemaName::new(name).map_err(|_| ForgeError::InvalidSchemaName {
+        name: name.to_string(),
+    })
+}
+
+/// Convert a raw JSON value into a `Filter` using schema type hints.
+///
+/// Accepts a JSON object with `"op"`, `"field"`, `"value"` / `"values"` / `"filters"` / `"filter"` keys.
+/// An object without `"op"` is the flat shorthand: an implicit AND of its
+/// `field` / `field__op` entries (see [`flat_object_to_filter`]).
+/// Values are plain JSON primitives — types are inferred from schema field definitions.
+pub fn json_to_filter(
     value: &serde_json::Value,
     schema: &SchemaDefinition,
 ) -> Result<Filter, Vec<String>> {
@@ -872,10 +1310,12 @@ pub fn json_to_filter(
         .as_object()
         .ok_or_else(|| vec!["filter must be a JSON object".to_string()])?;
 
-    let op = obj
-        .get("op")
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| vec!["filter must have an 'op' field".to_string()])?;
+    let Some(op) = obj.get("op") else {
+        return flat_object_to_filter(obj, schema);
+    };
+    let op = op
+        .as_str()
+        .ok_or_else(|| vec!["filter 'op' must be a string".to_string()])?;
 
     match op {
         "and" | "or" => {
@@ -978,6 +1418,56 @@ pub fn json_to_filter(
     }
 }
 
+/// Convert a flat filter object such as `{"status": "open", "age__gt": 30}`
+/// into the AND of its entries.
+///
+/// Keys use the query-string form: a field path with an optional `__op`
+/// suffix, defaulting to `eq`. Every key must name a schema field, so a
+/// malformed operator object is rejected rather than read as fields. A
+/// single entry yields its comparison directly.
+fn flat_object_to_filter(
+    obj: &serde_json::Map<String, serde_json::Value>,
+    schema: &SchemaDefinition,
+) -> Result<Filter, Vec<String>> {
+    let mut filters = Vec::new();
+    let mut errors = Vec::new();
+    for (key, value) in obj {
+        let Some((field, op)) = parse_filter_key(key) else {
+            errors.push(format!("'{key}' is not a filter field"));
+            continue;
+        };
+        let known = FieldPath::parse(field).is_ok_and(|p| schema.field(p.root()).is_some());
+        if !known {
+            errors.push(format!("unknown filter field '{field}'"));
+            continue;
+        }
+        let op = match op {
+            FilterOp::Eq => "eq",
+            FilterOp::Ne => "ne",
+            FilterOp::Gt => "gt",
+            FilterOp::Gte => "gte",
+            FilterOp::Lt => "lt",
+            FilterOp::Lte => "lte",
+            FilterOp::Contains => "contains",
+            FilterOp::StartsWith => "startswith",
+            FilterOp::In => "in",
+        };
+        let leaf = serde_json::json!({ "op": op, "field": field, "value": value });
+        match json_to_filter(&leaf, schema) {
+            Ok(f) => filters.push(f),
+            Err(errs) => errors.extend(errs),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(if filters.len() == 1 {
+        filters.remove(0)
+    } else {
+        Filter::and(filters)
+    })
+}
+
 /// Coerce a JSON value to a `DynamicValue` for use in filter expressions.
 ///
 /// Uses the field type hint when available, otherwise falls back to untyped conversion.
@@ -985,6 +1475,10 @@ fn coerce_json_filter_value(
     value: &serde_json::Value,
     field_type: Option<&FieldType>,
 ) -> Result<DynamicValue, String> {
+    // Range bounds may be bare dates; stored values must be full datetimes.
+    if let (Some(FieldType::DateTime), serde_json::Value::String(s)) = (field_type, value) {
+        return parse_datetime_filter(s).map(DynamicValue::DateTime);
+    }
     if let Some(ft) = field_type {
         convert_json_with_type_hint(value, ft)
     } else {
@@ -999,8 +1493,9 @@ fn coerce_json_filter_value(
 ///
 /// When `resolve_relations` is `true`, each `Relation(One|Many)` field
 /// gets a sibling `<field>__display` entry on every row, populated from
-/// a batched `IN`-query against the target schema's `@display("...")`
-/// field. Clients pass `?resolve=false` to opt out.
+/// a batched `IN`-query against the target schema's `@ref_label("...")`
+/// template or `@display("...")` field. Clients pass `?resolve=false` to
+/// opt out.
 async fn execute_entity_query(
     state: &AppState<SchemaForgeConfig>,
     schema_def: &SchemaDefinition,
@@ -1009,8 +1504,11 @@ async fn execute_entity_query(
     projection: Option<&HashSet<String>>,
     resolve_relations: bool,
     include_total: bool,
+    collect_timing: bool,
 ) -> Result<ListEntitiesResponse, ForgeError> {
-    query.include_total = include_total;
+    // When timing, run the count as its own call so the two phases can be
+    // measured separately (backends otherwise compute it alongside the page).
+    query.include_total = include_total && !collect_timing;
     let forge = state
         .actor::<ForgeActor>()
         .expect("ForgeActor not registered");
@@ -1027,10 +1525,11 @@ async fn execute_entity_query(
 
     // Push field projection into the query for DB-level column selection
     if let Some(proj) = projection {
-        query.projection = Some(proj.iter().cloned().collect());
+        query.projection = Some(storage_projection(proj, schema_def, query));
     }
 
     // Execute query via actor
+    let query_start = Instant::now();
     let (tx, rx) = oneshot::channel();
     forge
         .send(QueryEntities {
@@ -1038,7 +1537,22 @@ async fn execute_entity_query(
             reply: ReplyChannel::new(tx),
         })
         .await;
-    let result = ask_forge(rx).await?.map_err(ForgeError::from)?;
+    let mut result = ask_forge(rx).await?.map_err(ForgeError::from)?;
+    let query_ms = elapsed_ms(query_start);
+
+    let mut count_ms = None;
+    if include_total && collect_timing {
+        let count_start = Instant::now();
+        let (tx, rx) = oneshot::channel();
+        forge
+            .send(CountEntities {
+                query: query.clone(),
+                reply: ReplyChannel::new(tx),
+            })
+            .await;
+        result.total_count = Some(ask_forge(rx).await?.map_err(ForgeError::from)?);
+        count_ms = Some(elapsed_ms(count_start));
+    }
 
     // Record-level access filtering (e.g. @owner)
     let (tx, rx) = oneshot::channel();
@@ -1062,6 +1576,7 @@ async fn execute_entity_query(
     // map. This happens BEFORE display resolution so the existing
     // relation-display machinery treats derived fields identically to
     // stored RefArrays.
+    let ref_resolve_start = Instant::now();
     populate_derived_collections(
         forge,
         schema_def,
@@ -1081,6 +1596,7 @@ async fn execute_entity_query(
     } else {
         HashMap::new()
     };
+    let ref_resolve_ms = elapsed_ms(ref_resolve_start);
 
     // Filter read-restricted fields, then apply optional field projection
     let policy_store = fetch_policy_store(state).await?;
@@ -1115,15 +1631,68 @@ async fn execute_entity_query(
         entities,
         count,
         total_count: result.total_count,
+        next_cursor: result.next_cursor,
         permissions,
+        timing: collect_timing.then_some(ListTiming {
+            query_ms,
+            count_ms,
+            ref_resolve_ms,
+        }),
     })
 }
 
+/// Columns to read from storage for a `?fields=` projection.
+///
+/// The response is trimmed to the requested fields, but the server itself
+/// needs a few more: sort fields to build `next_cursor`, and `@owner`
+/// fields for record-level access checks. `id` is always read by the
+/// backend. Returned in schema order.
+fn storage_projection(
+    requested: &HashSet<String>,
+    schema_def: &SchemaDefinition,
+    query: &schema_forge_core::query::Query,
+) -> Vec<String> {
+    // A requested `@computed` field is not a column; select the fields its
+    // expression reads instead. Walking backwards picks up computed fields
+    // that read other computed fields declared above them.
+    let mut needed = requested.clone();
+    for field in schema_def.fields.iter().rev() {
+        if let Some(expr) = field.computed_expr() {
+            if needed.contains(field.name.as_str()) {
+                needed.extend(expr.fields());
+            }
+        }
+    }
+    schema_def
+        .fields
+        .iter()
+        .filter(|f| !f.is_computed())
+        .filter(|f| {
+            let name = f.name.as_str();
+            needed.contains(name)
+                || f.has_owner()
+                || query.sort.iter().any(|(path, _)| path.root() == name)
+        })
+        .map(|f| f.name.as_str().to_string())
+        .collect()
+}
+
+/// How a target schema's entities are rendered as relation values.
+enum RelationLabel {
+    /// `@ref_label("...")` template.
+    Template(RefLabelTemplate),
+    /// `@display("...")` field.
+    Field(String),
+}
+
 /// Collect distinct relation IDs across `visible_entities` and resolve each
-/// target schema's `@display("...")` field in a single batched IN-query.
+/// target schema's label in a single batched IN-query.
+///
+/// The label comes from the target's `@ref_label("...")` template when
+/// present, otherwise from its `@display("...")` field.
 ///
 /// Returns a nested map: `field_name -> id -> display_value`. Fields whose
-/// target schema has no `@display(...)`, or whose target schema isn't
+/// target schema has neither annotation, or whose target schema isn't
 /// registered, simply don't appear in the result — callers must treat a
 /// missing entry as "fall back to the raw ID".
 async fn resolve_relation_displays(
@@ -1154,11 +1723,11 @@ async fn resolve_relation_displays(
     let target_names: Vec<String> = targets.keys().cloned().collect();
     let target_defs = fetch_schemas_batch(forge, target_names).await?;
 
-    // Build per-target query jobs, skipping targets with no display field,
+    // Build per-target query jobs, skipping targets with no label source,
     // no registered schema, or no referenced IDs.
     let mut jobs: Vec<(
-        Vec<String>,    // source field names sharing this target
-        String,         // display_field
+        Vec<String>,   // source field names sharing this target
+        RelationLabel, // how to render each target entity
         schema_forge_core::query::Query,
     )> = Vec::with_capacity(targets.len());
 
@@ -1166,7 +1735,11 @@ async fn resolve_relation_displays(
         let Some(target_def) = target_defs.get(&target_name) else {
             continue;
         };
-        let Some(display_field) = target_def.display_field().map(|s| s.to_string()) else {
+        let label = if let Some(template) = target_def.ref_label() {
+            RelationLabel::Template(template.clone())
+        } else if let Some(display_field) = target_def.display_field() {
+            RelationLabel::Field(display_field.to_string())
+        } else {
             continue;
         };
 
@@ -1197,7 +1770,18 @@ async fn resolve_relation_displays(
                 values: id_values,
             })
             .without_total_count();
-        display_query.projection = Some(vec!["id".to_string(), display_field.clone()]);
+        let mut projection = vec!["id".to_string()];
+        match &label {
+            RelationLabel::Template(template) => projection.extend(
+                template
+                    .fields()
+                    .into_iter()
+                    .filter(|f| target_def.field(f).is_some())
+                    .map(str::to_string),
+            ),
+            RelationLabel::Field(display_field) => projection.push(display_field.clone()),
+        }
+        display_query.projection = Some(projection);
         // Apply tenant scope so we never leak rows the caller couldn't
         // otherwise see through a direct list call.
         inject_tenant_scope(&mut display_query, claims, tenant_config);
@@ -1206,7 +1790,7 @@ async fn resolve_relation_displays(
             .iter()
             .map(|f| f.name.as_str().to_string())
             .collect();
-        jobs.push((source_fields, display_field, display_query));
+        jobs.push((source_fields, label, display_query));
     }
 
     if jobs.is_empty() {
@@ -1218,7 +1802,7 @@ async fn resolve_relation_displays(
     // serializing one-by-one on the actor mailbox.
     let futures_iter = jobs
         .into_iter()
-        .map(|(source_fields, display_field, query)| async move {
+        .map(|(source_fields, label, query)| async move {
             let (tx, rx) = oneshot::channel();
             forge
                 .send(QueryEntities {
@@ -1232,9 +1816,18 @@ async fn resolve_relation_displays(
             };
             let mut id_to_display: HashMap<String, String> = HashMap::new();
             for target_entity in target_result.entities {
-                if let Some(display_value) = target_entity.field(&display_field) {
-                    let rendered = display_value_to_string(display_value);
-                    id_to_display.insert(target_entity.id.as_str().to_string(), rendered);
+                let id = target_entity.id.as_str();
+                let rendered = match &label {
+                    RelationLabel::Template(template) => Some(
+                        template
+                            .render(id, |f| target_entity.field(f).map(display_value_to_string)),
+                    ),
+                    RelationLabel::Field(display_field) => target_entity
+                        .field(display_field)
+                        .map(display_value_to_string),
+                };
+                if let Some(rendered) = rendered {
+                    id_to_display.insert(id.to_string(), rendered);
                 }
             }
             (source_fields, id_to_display)
@@ -1560,18 +2153,292 @@ fn apply_relation_displays(
     }
 }
 
+/// Context bundle for [`expand_relations`]: the caller's authorization
+/// inputs, reused for every expanded target schema.
+struct ExpandCtx<'a> {
+    forge: &'a acton_service::prelude::ActorHandle,
+    policy_store: &'a Arc<crate::authz::PolicyStore>,
+    claims: Option<&'a Claims>,
+    tenant_config: &'a Option<schema_forge_backend::tenant::TenantConfig>,
+    record_access_policy: &'a Option<Arc<dyn schema_forge_backend::auth::RecordAccessPolicy>>,
+}
+
+/// Resolve each relation field named in `expand` into the target entities
+/// it references, serialized the same way as a detail response.
+///
+/// Issues one batched IN-query per expanded field. The caller must be able
+/// to read every expanded target schema; target records hidden by tenant
+/// scope or the record access policy, or that no longer exist, stay as
+/// raw IDs. Returns `field_name -> expanded JSON value`.
+async fn expand_relations(
+    ctx: &ExpandCtx<'_>,
+    parent_schema: &SchemaDefinition,
+    entity: &Entity,
+    expand: &[String],
+) -> Result<HashMap<String, serde_json::Value>, ForgeError> {
+    let mut relations = Vec::with_capacity(expand.len());
+    for field_name in expand {
+        let Some(FieldType::Relation {
+            target,
+            cardinality,
+        }) = parent_schema.field(field_name).map(|f| &f.field_type)
+        else {
+            continue;
+        };
+        let Some(value) = entity.field(field_name) else {
+            continue;
+        };
+        relations.push((field_name, target.as_str().to_string(), cardinality, value));
+    }
+    if relations.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let target_names = relations.iter().map(|(_, t, _, _)| t.clone()).collect();
+    let target_defs = fetch_schemas_batch(ctx.forge, target_names).await?;
+
+    let mut expanded = HashMap::new();
+    for (field_name, target_name, cardinality, value) in relations {
+        let target_def =
+            target_defs
+                .get(&target_name)
+                .ok_or_else(|| ForgeError::SchemaNotFound {
+                    name: target_name.clone(),
+                })?;
+        check_schema_access(ctx.policy_store, target_def, ctx.claims, AccessAction::Read)?;
+
+        let ids = relation_ids_in_order(value);
+        let mut by_id: HashMap<String, serde_json::Value> = HashMap::new();
+        if !ids.is_empty() {
+            let mut query = schema_forge_core::query::Query::new(target_def.id.clone())
+                .with_filter(Filter::In {
+                    path: FieldPath::single("id"),
+                    values: ids.iter().cloned().map(DynamicValue::Text).collect(),
+                })
+                .without_total_count();
+            inject_tenant_scope(&mut query, ctx.claims, ctx.tenant_config);
+            let (tx, rx) = oneshot::channel();
+            ctx.forge
+                .send(QueryEntities {
+                    query,
+                    reply: ReplyChannel::new(tx),
+                })
+                .await;
+            let mut targets = ask_forge(rx).await?.map_err(ForgeError::from)?.entities;
+            if let (Some(policy), Some(c)) = (ctx.record_access_policy, ctx.claims) {
+                targets = policy.filter_visible(target_def, c, targets).await;
+            }
+            for mut target in targets {
+                filter_entity_fields(
+                    ctx.policy_store,
+                    &mut target,
+                    target_def,
+                    ctx.claims,
+                    FieldFilterDirection::Read,
+                );
+                let json =
+                    serde_json::to_value(entity_to_response(&target, target_def)).map_err(|e| {
+                        ForgeError::Internal {
+                            message: format!("failed to serialize expanded entity: {e}"),
+                        }
+                    })?;
+                by_id.insert(target.id.as_str().to_string(), json);
+            }
+        }
+
+        let resolve = |id: &String| {
+            by_id
+                .get(id)
+                .cloned()
+                .unwrap_or_else(|| serde_json::Value::String(id.clone()))
+        };
+        let json = match cardinality {
+            Cardinality::One => match ids.first() {
+                Some(id) => resolve(id),
+                None => serde_json::Value::Null,
+            },
+            _ => serde_json::Value::Array(ids.iter().map(resolve).collect()),
+        };
+        expanded.insert(field_name.clone(), json);
+    }
+
+    Ok(expanded)
+}
+
+/// Like [`collect_relation_ids`], but keeps the order (and duplicates) of
+/// the stored value so `relation_many` expansions line up with the IDs.
+fn relation_ids_in_order(value: &DynamicValue) -> Vec<String> {
+    match value {
+        DynamicValue::Text(s) if !s.is_empty() => vec![s.clone()],
+        DynamicValue::Ref(id) => vec![id.as_str().to_string()],
+        DynamicValue::RefArray(ids) => ids.iter().map(|id| id.as_str().to_string()).collect(),
+        DynamicValue::Array(items) => items.iter().flat_map(relation_ids_in_order).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns true when the request carries `Prefer: return=minimal`
+/// (RFC 7240). Preferences are comma-separated and matched
+/// case-insensitively; anything else means `return=representation`.
+fn prefers_minimal_return(headers: &HeaderMap) -> bool {
+    headers
+        .get_all("prefer")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|pref| {
+            pref.split(';')
+                .next()
+                .is_some_and(|p| p.trim().eq_ignore_ascii_case("return=minimal"))
+        })
+}
+
+/// Reads the expected entity version from an `If-Match` header.
+///
+/// Accepts the strong (`"3"`) and weak (`W/"3"`) forms of the ETag this
+/// module emits; `*` matches any version. A missing header is only an
+/// error when `require` is set (`require_if_match` in the config).
+fn if_match_version(headers: &HeaderMap, require: bool) -> Result<Option<u64>, ForgeError> {
+    let Some(value) = headers.get(header::IF_MATCH) else {
+        if require {
+            return Err(ForgeError::PreconditionRequired {
+                message: "If-Match header is required for this update".into(),
+            });
+        }
+        return Ok(None);
+    };
+    let raw = value.to_str().unwrap_or_default().trim();
+    if raw == "*" {
+        return Ok(None);
+    }
+    raw.trim_start_matches("W/")
+        .trim_matches('"')
+        .parse::<u64>()
+        .map(Some)
+        .map_err(|_| ForgeError::PreconditionFailed {
+            message: format!("If-Match value '{raw}' is not an entity version"),
+        })
+}
+
+/// The `ETag` header for an entity, or nothing for unversioned records.
+fn etag_header(entity: &Entity) -> AppendHeaders<Option<(header::HeaderName, HeaderValue)>> {
+    AppendHeaders(
+        (entity.version > 0)
+            .then(|| HeaderValue::from_str(&format!("\"{}\"", entity.version)).ok())
+            .flatten()
+            .map(|v| (header::ETAG, v)),
+    )
+}
+
+/// Reads the `Idempotency-Key` header into a key scoped to `schema` and
+/// the caller. `None` when the client sent no key.
+fn idempotency_scope(
+    headers: &HeaderMap,
+    schema: &str,
+    claims: Option<&Claims>,
+) -> Result<Option<IdempotencyScope>, ForgeError> {
+    let Some(value) = headers.get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+    let key = value.to_str().unwrap_or_default();
+    if !is_valid_idempotency_key(key) {
+        return Err(ForgeError::InvalidQuery {
+            message: "Idempotency-Key must be 1 to 255 visible ASCII characters".into(),
+        });
+    }
+    Ok(Some(IdempotencyScope {
+        schema: schema.to_string(),
+        principal: claims.map(|c| c.sub.clone()),
+        key: key.to_string(),
+    }))
+}
+
+/// Retrieve the store that remembers `Idempotency-Key`s on creation.
+async fn fetch_idempotency_store(
+    forge: &acton_service::prelude::ActorHandle,
+) -> Result<IdempotencyStore, ForgeError> {
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetIdempotencyStore {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    ask_forge(rx).await
+}
+
+/// The answer to a create: a `Location` naming the entity, then the full
+/// entity or, under `Prefer: return=minimal`, an empty body. A replayed
+/// create answers `200` with `Idempotent-Replay: true` rather than `201`.
+fn created_response(
+    uri: &axum::http::Uri,
+    headers: &HeaderMap,
+    entity: &Entity,
+    schema_def: &SchemaDefinition,
+    replay: bool,
+) -> Result<Response, ForgeError> {
+    // `uri` is the path as the client sent it, including any prefix the
+    // router is nested under, so the Location resolves from the outside.
+    let location = format!(
+        "{}/{}",
+        uri.path().trim_end_matches('/'),
+        entity.id.as_str()
+    );
+    let location = HeaderValue::from_str(&location).map_err(|e| ForgeError::Internal {
+        message: format!("invalid Location header: {e}"),
+    })?;
+    let status = if replay {
+        StatusCode::OK
+    } else {
+        StatusCode::CREATED
+    };
+    let replay_header = AppendHeaders(replay.then(|| {
+        (
+            header::HeaderName::from_static(IDEMPOTENT_REPLAY_HEADER),
+            HeaderValue::from_static("true"),
+        )
+    }));
+    if prefers_minimal_return(headers) {
+        return Ok((
+            status,
+            [
+                (header::LOCATION, location),
+                (
+                    header::HeaderName::from_static("preference-applied"),
+                    HeaderValue::from_static("return=minimal"),
+                ),
+            ],
+            replay_header,
+        )
+            .into_response());
+    }
+    Ok((
+        status,
+        [(header::LOCATION, location)],
+        replay_header,
+        etag_header(entity),
+        Json(entity_to_response(entity, schema_def)),
+    )
+        .into_response())
+}
+
 // ---------------------------------------------------------------------------
 // Handlers
 // ---------------------------------------------------------------------------
 
 /// POST /schemas/{schema}/entities -- Create a new entity.
+///
+/// Always answers `201 Created` with a `Location` header naming the new
+/// entity. The body is the full entity unless the client sends
+/// `Prefer: return=minimal`, in which case it is empty.
 #[instrument(skip_all, fields(schema = %schema))]
 pub async fn create_entity(
     State(state): State<AppState<SchemaForgeConfig>>,
     Path(schema): Path<String>,
+    OriginalUri(uri): OriginalUri,
     OptionalClaims(claims): OptionalClaims,
+    headers: HeaderMap,
     Json(body): Json<EntityRequest>,
-) -> Result<impl IntoResponse, ForgeError> {
+) -> Result<Response, ForgeError> {
     let schema_name = validate_schema_name(&schema)?;
     let forge = state
         .actor::<ForgeActor>()
@@ -1614,8 +2481,65 @@ pub async fn create_entity(
         return Err(e);
     }
 
+    // Idempotency-Key: replay what an earlier attempt created, or hold the
+    // key until this attempt finishes. A failed attempt releases it.
+    let idempotency = match idempotency_scope(&headers, &schema, claims.as_ref())? {
+        Some(scope) => {
+            let store = fetch_idempotency_store(forge).await?;
+            let window =
+                Duration::from_secs(state.config().custom.schema_forge.idempotency_window_secs);
+            let fingerprint = body_fingerprint(&body.fields);
+            match store.claim(&scope, &fingerprint, window) {
+                IdempotencyClaim::Fresh => Some(IdempotencyGuard::new(store, scope, fingerprint)),
+                IdempotencyClaim::Replay(id) => {
+                    let entity_id = EntityId::parse(&id)
+                        .map_err(|_| ForgeError::InvalidEntityId { id: id.clone() })?;
+                    let (tx, rx) = oneshot::channel();
+                    forge
+                        .send(GetEntity {
+                            schema: schema_name,
+                            id: entity_id,
+                            reply: ReplyChannel::new(tx),
+                        })
+                        .await;
+                    let mut original = ask_forge(rx).await?.map_err(ForgeError::from)?;
+                    complete_written_entity(
+                        forge,
+                        &schema_def,
+                        &mut original,
+                        claims.as_ref(),
+                        ChangeOperation::Created,
+                    )
+                    .await?;
+                    filter_entity_fields(
+                        &policy_store,
+                        &mut original,
+                        &schema_def,
+                        claims.as_ref(),
+                        FieldFilterDirection::Read,
+                    );
+                    return created_response(&uri, &headers, &original, &schema_def, true);
+                }
+                IdempotencyClaim::InFlight => {
+                    return Err(ForgeError::Conflict {
+                        reason: "idempotency_key_in_use",
+                        message: "a request with this Idempotency-Key is still in progress".into(),
+                    });
+                }
+                IdempotencyClaim::Mismatch => {
+                    return Err(ForgeError::ValidationFailed {
+                        details: vec![
+                            "Idempotency-Key was already used with a different request body".into(),
+                        ],
+                    });
+                }
+            }
+        }
+        None => None,
+    };
+
     // Reject any client-supplied @hidden fields up front.
-    reject_hidden_fields_in_body(&schema_def, &body.fields)?;
+    reject_hidden_fields_in_body(&schema_def, &body.fields, claims.as_ref())?;
 
     // Convert JSON fields to DynamicValue fields
     let mut fields = json_to_entity_fields(&schema_def, &body.fields)
@@ -1669,9 +2593,13 @@ pub async fn create_entity(
         )
         .await?;
     }
+    let entity_hooks = fetch_entity_hooks(forge).await;
+    entity_hooks
+        .before_create(&schema_def, claims.as_ref(), &mut fields)
+        .await?;
 
     // Create the entity, filtering write-restricted fields
-    let mut entity = Entity::new(schema_name, fields);
+    let mut entity = Entity::for_schema(&schema_def, fields);
     filter_entity_fields(
         &policy_store,
         &mut entity,
@@ -1689,6 +2617,18 @@ pub async fn create_entity(
         })
         .await;
     let mut created = ask_forge(rx).await?.map_err(ForgeError::from)?;
+    if let Some(guard) = idempotency {
+        guard.complete(created.id.as_str());
+    }
+    complete_written_entity(
+        forge,
+        &schema_def,
+        &mut created,
+        claims.as_ref(),
+        ChangeOperation::Created,
+    )
+    .await?;
+    let audit_changes = changed_fields(&schema_def, &BTreeMap::new(), &created.fields);
 
     // after_change hook — handed off to HookDispatchActor for
     // detached dispatch under acton supervision.
@@ -1707,6 +2647,7 @@ pub async fn create_entity(
         )
         .await;
     }
+    entity_hooks.after_create(&schema_def, claims.as_ref(), &created);
 
     // Filter read-restricted fields from response
     filter_entity_fields(
@@ -1731,36 +2672,39 @@ pub async fn create_entity(
             )
             .await;
     }
+    write_observers(&state, forge, &schema_def)
+        .await
+        .notify(CommittedWrite {
+            schema: &schema_def,
+            claims: claims.as_ref(),
+            operation: ChangeOperation::Created,
+            entity_id: created.id.as_str(),
+            entity: Some(created.clone()),
+            changed_fields: audit_changes,
+        })
+        .await;
 
-    // Webhook: fire notifications
-    let webhook_event = crate::webhook::WebhookEvent::from_create(
-        &schema,
-        &created,
-        claims.as_ref().map(|c| c.sub.as_str()),
-    );
-    dispatch_webhook(&state, &schema_def, webhook_event, "created").await;
-
-    Ok((StatusCode::CREATED, Json(entity_to_response(&created, &schema_def))))
+    created_response(&uri, &headers, &created, &schema_def, false)
 }
 
-/// GET /schemas/{schema}/entities -- List/query entities.
+/// POST /schemas/{schema}/entities/batch -- Create several entities at once.
 ///
-/// Supports filter, sort, limit, and offset via query parameters.
-/// Filter params use Django-style syntax: `?field__op=value` (e.g. `?age__gt=25`).
-/// Sort uses `?sort=-age,name` (prefix `-` = descending) or `?sort=age:desc,name:asc`.
+/// Every entity is validated before anything is written; validation errors
+/// are reported per entity as `entities[i]: ...`. The write itself is
+/// atomic, so a backend failure on one entity rolls back the whole batch
+/// and names the failing index.
 #[instrument(skip_all, fields(schema = %schema))]
-pub async fn list_entities(
+pub async fn batch_create_entities(
     State(state): State<AppState<SchemaForgeConfig>>,
     Path(schema): Path<String>,
     OptionalClaims(claims): OptionalClaims,
-    Query(params): Query<HashMap<String, String>>,
+    Json(body): Json<BatchCreateRequest>,
 ) -> Result<impl IntoResponse, ForgeError> {
     let schema_name = validate_schema_name(&schema)?;
     let forge = state
         .actor::<ForgeActor>()
         .expect("ForgeActor not registered");
 
-    // Verify schema exists via actor
     let (tx, rx) = oneshot::channel();
     forge
         .send(GetSchema {
@@ -1773,132 +2717,199 @@ pub async fn list_entities(
     })?;
 
     let policy_store = fetch_policy_store(&state).await?;
-
-    // Access check
-    check_schema_access(
+    if let Err(e) = check_schema_access(
         &policy_store,
         &schema_def,
         claims.as_ref(),
-        AccessAction::Read,
-    )?;
-
-    // before_read hook gate (no entity_id, no fields — list scope).
-    let hooks_config = state.config().custom.schema_forge.hooks.clone();
-    if hooks_config.enabled && schema_def.hook_for(HookEvent::BeforeRead).is_some() {
-        if let Some(dispatcher) = fetch_hook_dispatcher(forge).await {
-            let mut empty = BTreeMap::new();
-            apply_read_hook(
-                BeforeHookCtx {
-                    dispatcher: dispatcher.as_ref(),
-                    hooks_config: &hooks_config,
-                    schema: &schema_def,
-                    event: HookEvent::BeforeRead,
-                    operation: "list",
-                    user: claims.as_ref(),
-                    entity_id: None,
-                },
-                &mut empty,
-            )
-            .await?;
+        AccessAction::Write,
+    ) {
+        if let Some(logger) = state.audit_logger() {
+            logger
+                .log_custom(
+                    "forge.access.denied",
+                    acton_service::audit::AuditSeverity::Warning,
+                    Some(serde_json::json!({
+                        "schema": &schema,
+                        "action": "write",
+                        "user": claims.as_ref().map(|c| &c.sub),
+                    })),
+                )
+                .await;
         }
+        return Err(e);
     }
 
-    // Build a query
-    let mut query = schema_forge_core::query::Query::new(schema_def.id.clone());
-
-    // Extract limit/offset
-    if let Some(limit_str) = params.get("limit") {
-        let limit = limit_str
-            .parse::<usize>()
-            .map_err(|_| ForgeError::InvalidQuery {
-                message: format!("invalid limit value '{limit_str}'"),
-            })?;
-        query = query.with_limit(limit);
-    }
-    if let Some(offset_str) = params.get("offset") {
-        let offset = offset_str
-            .parse::<usize>()
-            .map_err(|_| ForgeError::InvalidQuery {
-                message: format!("invalid offset value '{offset_str}'"),
-            })?;
-        query = query.with_offset(offset);
+    if body.entities.is_empty() {
+        return Err(ForgeError::InvalidQuery {
+            message: "batch must contain at least one entity".to_string(),
+        });
     }
 
-    // Parse sort
-    if let Some(sort_str) = params.get("sort") {
-        let sort_clauses =
-            parse_sort_param(sort_str).map_err(|e| ForgeError::InvalidQuery { message: e })?;
-        for (path, order) in sort_clauses {
-            query = query.with_sort(path, order);
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetTenantConfig {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let tenant_config = ask_forge(rx).await?;
+
+    let hooks_config = state.config().custom.schema_forge.hooks.clone();
+    let hook_dispatcher = if hooks_config.enabled && schema_def.has_hooks() {
+        fetch_hook_dispatcher(forge).await
+    } else {
+        None
+    };
+    let entity_hooks = fetch_entity_hooks(forge).await;
+
+    // Validate every entity before writing any of them.
+    let mut entities = Vec::with_capacity(body.entities.len());
+    let mut details = Vec::new();
+    for (index, json_fields) in body.entities.iter().enumerate() {
+        let prefix = |msg: String| format!("entities[{index}]: {msg}");
+        if let Err(ForgeError::ValidationFailed { details: errs }) =
+            reject_hidden_fields_in_body(&schema_def, json_fields, claims.as_ref())
+        {
+            details.extend(errs.into_iter().map(prefix));
+            continue;
         }
-    }
+        let mut fields = match json_to_entity_fields(&schema_def, json_fields) {
+            Ok(fields) => fields,
+            Err(errs) => {
+                details.extend(errs.into_iter().map(prefix));
+                continue;
+            }
+        };
+        inject_tenant_on_create(&mut fields, claims.as_ref(), &tenant_config);
+
+        if let Some(ref dispatcher) = hook_dispatcher {
+            for event in [HookEvent::BeforeValidate, HookEvent::BeforeChange] {
+                apply_before_hook(
+                    BeforeHookCtx {
+                        dispatcher: dispatcher.as_ref(),
+                        hooks_config: &hooks_config,
+                        schema: &schema_def,
+                        event,
+                        operation: "create",
+                        user: claims.as_ref(),
+                        entity_id: None,
+                    },
+                    &mut fields,
+                )
+                .await?;
+            }
+        }
+        entity_hooks
+            .before_create(&schema_def, claims.as_ref(), &mut fields)
+            .await?;
 
-    // Parse filter params
-    let filter =
-        parse_filter_params(&params, &schema_def).map_err(|errors| ForgeError::InvalidQuery {
-            message: errors.join("; "),
-        })?;
-    if let Some(f) = &filter {
-        validate_filter(f, &schema_def).map_err(|errors| ForgeError::InvalidQuery {
-            message: errors
-                .iter()
-                .map(|e| e.to_string())
-                .collect::<Vec<_>>()
-                .join("; "),
-        })?;
+        let mut entity = Entity::for_schema(&schema_def, fields);
+        filter_entity_fields(
+            &policy_store,
+            &mut entity,
+            &schema_def,
+            claims.as_ref(),
+            FieldFilterDirection::Write,
+        );
+        entities.push(entity);
     }
-    if let Some(f) = filter {
-        query = query.with_filter(f);
+    if !details.is_empty() {
+        return Err(ForgeError::ValidationFailed { details });
     }
 
-    // Parse field projection
-    let projection = if let Some(fields_str) = params.get("fields") {
-        Some(
-            parse_fields_param(fields_str, &schema_def)
-                .map_err(|e| ForgeError::InvalidQuery { message: e })?,
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(CreateEntities {
+            entities,
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let created = ask_forge(rx).await?.map_err(ForgeError::from)?;
+
+    let observers = write_observers(&state, forge, &schema_def).await;
+    let mut responses = Vec::with_capacity(created.len());
+    for mut entity in created {
+        complete_written_entity(
+            forge,
+            &schema_def,
+            &mut entity,
+            claims.as_ref(),
+            ChangeOperation::Created,
         )
-    } else {
-        None
-    };
+        .await?;
+        let audit_changes = changed_fields(&schema_def, &BTreeMap::new(), &entity.fields);
+
+        if let Some(dispatcher) = hook_dispatcher.clone() {
+            fire_after_hook(
+                &state,
+                AfterHookCtx {
+                    dispatcher,
+                    hooks_config: hooks_config.clone(),
+                    schema: schema_def.clone(),
+                    event: HookEvent::AfterChange,
+                    operation: "create".to_string(),
+                    user_id: claims.as_ref().map(|c| c.sub.clone()),
+                },
+                &entity,
+            )
+            .await;
+        }
+        entity_hooks.after_create(&schema_def, claims.as_ref(), &entity);
 
-    // ?resolve=false opts out of the default-on relation display join.
-    let resolve_relations = parse_truthy_flag(&params, "resolve");
-    // ?count=false opts out of the default-on total_count computation.
-    let include_total = parse_truthy_flag(&params, "count");
+        filter_entity_fields(
+            &policy_store,
+            &mut entity,
+            &schema_def,
+            claims.as_ref(),
+            FieldFilterDirection::Read,
+        );
 
-    let response = execute_entity_query(
-        &state,
-        &schema_def,
-        claims.as_ref(),
-        &mut query,
-        projection.as_ref(),
-        resolve_relations,
-        include_total,
-    )
-    .await?;
-    Ok(Json(response))
-}
+        if let Some(logger) = state.audit_logger() {
+            logger
+                .log_custom(
+                    "forge.entity.created",
+                    acton_service::audit::AuditSeverity::Informational,
+                    Some(serde_json::json!({
+                        "schema": schema,
+                        "entity_id": entity.id.as_str(),
+                        "user": claims.as_ref().map(|c| &c.sub),
+                    })),
+                )
+                .await;
+        }
+        observers
+            .notify(CommittedWrite {
+                schema: &schema_def,
+                claims: claims.as_ref(),
+                operation: ChangeOperation::Created,
+                entity_id: entity.id.as_str(),
+                entity: Some(entity.clone()),
+                changed_fields: audit_changes,
+            })
+            .await;
 
-/// Parse a query-string boolean flag with default-on semantics. Any truthy
-/// or unknown value keeps default-on; `false`/`0`/`no`/`off` opts out. We
-/// never 400 on unrecognized values — the client doesn't always control
-/// the exact spelling.
-fn parse_truthy_flag(params: &HashMap<String, String>, key: &str) -> bool {
-    !matches!(
-        params.get(key).map(|s| s.as_str()),
-        Some("false") | Some("0") | Some("no") | Some("off")
-    )
+        responses.push(entity_to_response(&entity, &schema_def));
+    }
+
+    Ok((
+        StatusCode::CREATED,
+        Json(BatchCreateResponse {
+            entities: responses,
+        }),
+    ))
 }
 
-/// POST /schemas/{schema}/entities/query -- Advanced query with JSON body.
+/// GET /schemas/{schema}/entities -- List/query entities.
 ///
-/// Accepts a full filter IR as JSON with plain values (schema-inferred types).
+/// Supports filter, sort, limit, and offset via query parameters, plus
+/// `?after=<cursor>` for keyset pagination from a previous `next_cursor`.
+/// Filter params use Django-style syntax: `?field__op=value` (e.g. `?age__gt=25`).
+/// Sort uses `?sort=-age,name` (prefix `-` = descending) or `?sort=age:desc,name:asc`.
 #[instrument(skip_all, fields(schema = %schema))]
-pub async fn query_entities(
+pub async fn list_entities(
     State(state): State<AppState<SchemaForgeConfig>>,
     Path(schema): Path<String>,
     OptionalClaims(claims): OptionalClaims,
-    Json(body): Json<EntityQueryBody>,
+    Query(params): Query<HashMap<String, String>>,
 ) -> Result<impl IntoResponse, ForgeError> {
     let schema_name = validate_schema_name(&schema)?;
     let forge = state
@@ -1927,7 +2938,7 @@ pub async fn query_entities(
         AccessAction::Read,
     )?;
 
-    // before_read hook gate (no entity_id, no fields — query scope).
+    // before_read hook gate (no entity_id, no fields — list scope).
     let hooks_config = state.config().custom.schema_forge.hooks.clone();
     if hooks_config.enabled && schema_def.hook_for(HookEvent::BeforeRead).is_some() {
         if let Some(dispatcher) = fetch_hook_dispatcher(forge).await {
@@ -1938,7 +2949,7 @@ pub async fn query_entities(
                     hooks_config: &hooks_config,
                     schema: &schema_def,
                     event: HookEvent::BeforeRead,
-                    operation: "query",
+                    operation: "list",
                     user: claims.as_ref(),
                     entity_id: None,
                 },
@@ -1951,90 +2962,89 @@ pub async fn query_entities(
     // Build a query
     let mut query = schema_forge_core::query::Query::new(schema_def.id.clone());
 
-    if let Some(limit) = body.limit {
+    // Extract limit/offset
+    if let Some(limit_str) = params.get("limit") {
+        let limit = limit_str
+            .parse::<usize>()
+            .map_err(|_| ForgeError::InvalidQuery {
+                message: format!("invalid limit value '{limit_str}'"),
+            })?;
         query = query.with_limit(limit);
     }
-    if let Some(offset) = body.offset {
+    // `@list(page_size, max_page_size)`: default an absent limit and clamp
+    // oversized ones.
+    query.limit = schema_def.effective_limit(query.limit);
+    if let Some(offset_str) = params.get("offset") {
+        let offset = offset_str
+            .parse::<usize>()
+            .map_err(|_| ForgeError::InvalidQuery {
+                message: format!("invalid offset value '{offset_str}'"),
+            })?;
         query = query.with_offset(offset);
     }
+    if let Some(after) = params.get("after") {
+        query = query.with_after(parse_cursor(after)?);
+    }
+    query = query.with_deleted(parse_opt_in_flag(&params, "include_deleted"));
 
-    // Parse sort clauses
-    if let Some(sort_clauses) = &body.sort {
-        for clause in sort_clauses {
-            let path = FieldPath::parse(&clause.field).map_err(|e| ForgeError::InvalidQuery {
-                message: format!("invalid sort field '{}': {e}", clause.field),
-            })?;
-            let order = match clause.order.as_deref() {
-                Some("desc") => SortOrder::Descending,
-                Some("asc") | None => SortOrder::Ascending,
-                Some(other) => {
-                    return Err(ForgeError::InvalidQuery {
-                        message: format!("invalid sort order '{other}', expected 'asc' or 'desc'"),
-                    });
-                }
-            };
+    // Parse sort
+    if let Some(sort_str) = params.get("sort") {
+        let sort_clauses =
+            parse_sort_param(sort_str).map_err(|e| ForgeError::InvalidQuery { message: e })?;
+        for (path, order) in sort_clauses {
             query = query.with_sort(path, order);
         }
     }
 
-    // Parse filter
-    if let Some(filter_json) = &body.filter {
-        let filter = json_to_filter(filter_json, &schema_def).map_err(|errors| {
-            ForgeError::InvalidQuery {
-                message: errors.join("; "),
-            }
-        })?;
-        validate_filter(&filter, &schema_def).map_err(|errors| ForgeError::InvalidQuery {
-            message: errors
-                .iter()
-                .map(|e| e.to_string())
-                .collect::<Vec<_>>()
-                .join("; "),
-        })?;
-        query = query.with_filter(filter);
+    if let Some(f) =
+        parse_list_filter(forge, &params, &schema_def, &policy_store, claims.as_ref()).await?
+    {
+        query = query.with_filter(f);
     }
 
-    // Validate field projection
-    let projection = if let Some(ref field_names) = body.fields {
-        let set: HashSet<String> = field_names.iter().cloned().collect();
-        let unknown: Vec<&str> = set
-            .iter()
-            .filter(|n| {
-                !schema_def
-                    .fields
-                    .iter()
-                    .any(|f| f.name.as_str() == n.as_str())
-            })
-            .map(String::as_str)
-            .collect();
-        if !unknown.is_empty() {
-            return Err(ForgeError::InvalidQuery {
-                message: format!("unknown fields: {}", unknown.join(", ")),
-            });
-        }
-        Some(set)
+    // Parse field projection
+    let projection = if let Some(fields_str) = params.get("fields") {
+        Some(
+            parse_fields_param(fields_str, &schema_def)
+                .map_err(|e| ForgeError::InvalidQuery { message: e })?,
+        )
     } else {
         None
     };
 
+    // ?resolve=false opts out of the default-on relation display join.
+    let resolve_relations = parse_truthy_flag(&params, "resolve");
+    // ?count=false opts out of the default-on total_count computation.
+    let include_total = parse_truthy_flag(&params, "count");
+    // ?debug=timing adds a `_timing` block, when config allows it.
+    let collect_timing = state.config().custom.schema_forge.debug_timing
+        && params
+            .get("debug")
+            .is_some_and(|v| v.split(',').any(|flag| flag.trim() == "timing"));
+
     let response = execute_entity_query(
         &state,
         &schema_def,
         claims.as_ref(),
         &mut query,
         projection.as_ref(),
-        body.resolve,
-        body.count,
+        resolve_relations,
+        include_total,
+        collect_timing,
     )
     .await?;
     Ok(Json(response))
 }
 
-/// GET /schemas/{schema}/entities/{id} -- Get entity by ID.
+/// GET /schemas/{schema}/entities/count -- Count matching entities.
+///
+/// Runs only the backend count: filter params and `include_deleted` work
+/// as on the list endpoint, tenant scope is applied, and no entity is
+/// loaded. Pagination, sort, and projection params are ignored.
 #[instrument(skip_all, fields(schema = %schema))]
-pub async fn get_entity(
+pub async fn count_entities(
     State(state): State<AppState<SchemaForgeConfig>>,
-    Path((schema, id)): Path<(String, String)>,
+    Path(schema): Path<String>,
     OptionalClaims(claims): OptionalClaims,
     Query(params): Query<HashMap<String, String>>,
 ) -> Result<impl IntoResponse, ForgeError> {
@@ -2043,7 +3053,6 @@ pub async fn get_entity(
         .actor::<ForgeActor>()
         .expect("ForgeActor not registered");
 
-    // Look up schema via actor
     let (tx, rx) = oneshot::channel();
     forge
         .send(GetSchema {
@@ -2056,8 +3065,6 @@ pub async fn get_entity(
     })?;
 
     let policy_store = fetch_policy_store(&state).await?;
-
-    // Access check
     check_schema_access(
         &policy_store,
         &schema_def,
@@ -2065,107 +3072,184 @@ pub async fn get_entity(
         AccessAction::Read,
     )?;
 
-    // Parse the entity ID
-    let entity_id =
-        EntityId::parse(&id).map_err(|_| ForgeError::InvalidEntityId { id: id.clone() })?;
-
-    // Resolve hook configuration up front. Only fetch the dispatcher when
-    // this schema actually declares a read-side hook — keeps the cost
-    // zero for the common case where reads are unhooked.
     let hooks_config = state.config().custom.schema_forge.hooks.clone();
-    let read_hook_dispatcher = if hooks_config.enabled
-        && (schema_def.hook_for(HookEvent::BeforeRead).is_some()
-            || schema_def.hook_for(HookEvent::AfterRead).is_some())
+    if hooks_config.enabled && schema_def.hook_for(HookEvent::BeforeRead).is_some() {
+        if let Some(dispatcher) = fetch_hook_dispatcher(forge).await {
+            let mut empty = BTreeMap::new();
+            apply_read_hook(
+                BeforeHookCtx {
+                    dispatcher: dispatcher.as_ref(),
+                    hooks_config: &hooks_config,
+                    schema: &schema_def,
+                    event: HookEvent::BeforeRead,
+                    operation: "count",
+                    user: claims.as_ref(),
+                    entity_id: None,
+                },
+                &mut empty,
+            )
+            .await?;
+        }
+    }
+
+    let mut query = schema_forge_core::query::Query::new(schema_def.id.clone())
+        .with_deleted(parse_opt_in_flag(&params, "include_deleted"));
+    if let Some(f) =
+        parse_list_filter(forge, &params, &schema_def, &policy_store, claims.as_ref()).await?
     {
-        fetch_hook_dispatcher(forge).await
-    } else {
-        None
-    };
+        query = query.with_filter(f);
+    }
 
-    // before_read hook (blocking; no fields yet — entity has not been fetched).
-    if let Some(ref dispatcher) = read_hook_dispatcher {
-        let mut empty_fields = BTreeMap::new();
-        apply_read_hook(
-            BeforeHookCtx {
-                dispatcher: dispatcher.as_ref(),
-                hooks_config: &hooks_config,
-                schema: &schema_def,
-                event: HookEvent::BeforeRead,
-                operation: "read",
-                user: claims.as_ref(),
-                entity_id: Some(entity_id.as_str().to_string()),
-            },
-            &mut empty_fields,
-        )
-        .await?;
-    }
-
-    // Get entity via actor
     let (tx, rx) = oneshot::channel();
     forge
-        .send(GetEntity {
-            schema: schema_name,
-            id: entity_id.clone(),
+        .send(GetTenantConfig {
             reply: ReplyChannel::new(tx),
         })
         .await;
-    let mut entity = ask_forge(rx).await?.map_err(ForgeError::from)?;
+    let tenant_config = ask_forge(rx).await?;
+    inject_tenant_scope(&mut query, claims.as_ref(), &tenant_config);
 
-    // Record-level visibility check
     let (tx, rx) = oneshot::channel();
     forge
-        .send(GetRecordAccessPolicy {
+        .send(CountEntities {
+            query,
             reply: ReplyChannel::new(tx),
         })
         .await;
-    let record_access_policy = ask_forge(rx).await?;
+    let count = ask_forge(rx).await?.map_err(ForgeError::from)?;
+    Ok(Json(CountResponse { count }))
+}
 
-    if let (Some(ref policy), Some(ref c)) = (&record_access_policy, &claims) {
-        let visible = policy
-            .filter_visible(&schema_def, c, vec![entity.clone()])
-            .await;
-        if visible.is_empty() {
-            return Err(ForgeError::Forbidden {
-                message: format!("not authorized to view entity '{id}'"),
-            });
+/// Parse and validate the `?field__op=value` filter params of a list-style
+/// request, then authorize it with [`authorize_filter`].
+///
+/// Dotted keys like `organization.region=EU` filter on a field of a to-one
+/// relation's target, so those schemas are loaded for type hints and
+/// validation; the backend resolves the traversal.
+async fn parse_list_filter(
+    forge: &acton_service::prelude::ActorHandle,
+    params: &HashMap<String, String>,
+    schema_def: &SchemaDefinition,
+    policy_store: &Arc<crate::authz::PolicyStore>,
+    claims: Option<&Claims>,
+) -> Result<Option<Filter>, ForgeError> {
+    let filter_targets: Vec<SchemaDefinition> =
+        fetch_schemas_batch(forge, filter_relation_targets(params, schema_def))
+            .await?
+            .into_values()
+            .collect();
+
+    let filter = parse_filter_params_with_targets(params, schema_def, &filter_targets).map_err(
+        |errors| ForgeError::InvalidQuery {
+            message: errors.join("; "),
+        },
+    )?;
+    let Some(filter) = filter else {
+        return Ok(None);
+    };
+    validate_filter_with_targets(&filter, schema_def, &filter_targets).map_err(|errors| {
+        ForgeError::InvalidQuery {
+            message: errors
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join("; "),
         }
-    }
+    })?;
+    authorize_filter(
+        forge,
+        filter,
+        schema_def,
+        &filter_targets,
+        policy_store,
+        claims,
+    )
+    .await
+    .map(Some)
+}
 
-    // Compute per-entity permissions against the unfiltered entity so any
-    // attribute-based Cedar policy (`@owner`, `@tenant`, etc.) sees the
-    // full record. Capture before field filtering strips read-restricted
-    // fields from the response payload.
-    let perms = entity_permissions(&policy_store, &schema_def, &entity, claims.as_ref());
+/// Parse, validate, and authorize the JSON `filter` of a query-style
+/// request body (query, aggregate, bulk delete).
+async fn parse_body_filter(
+    forge: &acton_service::prelude::ActorHandle,
+    filter_json: &serde_json::Value,
+    schema_def: &SchemaDefinition,
+    policy_store: &Arc<crate::authz::PolicyStore>,
+    claims: Option<&Claims>,
+) -> Result<Filter, ForgeError> {
+    let filter =
+        json_to_filter(filter_json, schema_def).map_err(|errors| ForgeError::InvalidQuery {
+            message: errors.join("; "),
+        })?;
+    validate_filter(&filter, schema_def).map_err(|errors| ForgeError::InvalidQuery {
+        message: errors
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>()
+            .join("; "),
+    })?;
 
-    // Filter read-restricted fields from response
-    filter_entity_fields(
-        &policy_store,
-        &mut entity,
-        &schema_def,
-        claims.as_ref(),
-        FieldFilterDirection::Read,
-    );
+    let mut paths = Vec::new();
+    collect_filter_paths(&filter, &mut paths);
+    let target_names: BTreeSet<String> = paths
+        .iter()
+        .filter(|path| !path.is_simple())
+        .filter_map(|path| schema_def.field(path.root()))
+        .filter_map(|field| match &field.field_type {
+            FieldType::Relation { target, .. } => Some(target.as_str().to_string()),
+            _ => None,
+        })
+        .collect();
+    let targets: Vec<SchemaDefinition> =
+        fetch_schemas_batch(forge, target_names.into_iter().collect())
+            .await?
+            .into_values()
+            .collect();
+    authorize_filter(forge, filter, schema_def, &targets, policy_store, claims).await
+}
 
-    // after_read hook (blocking; runs through the same call_before path
-    // so it can patch the response payload — e.g. redact, decorate).
-    if let Some(ref dispatcher) = read_hook_dispatcher {
-        apply_read_hook(
-            BeforeHookCtx {
-                dispatcher: dispatcher.as_ref(),
-                hooks_config: &hooks_config,
-                schema: &schema_def,
-                event: HookEvent::AfterRead,
-                operation: "read",
-                user: claims.as_ref(),
-                entity_id: Some(entity.id.as_str().to_string()),
-            },
-            &mut entity.fields,
-        )
-        .await?;
+/// Check that the caller may filter on every field `filter` names, and
+/// constrain its relation traversals to the caller's tenant.
+///
+/// A filter is a read of the fields it names: a `@hidden` field is
+/// rejected (400) and a `@field_access` field refused (403), on the schema
+/// itself and on relation targets alike, since matching on them would
+/// reveal values the caller never sees. A `relation.field` path also needs
+/// read access to the relation's target, one of `targets`. Under tenant
+/// scoping each traversal is ANDed with `relation._tenant = <tenant>`.
+async fn authorize_filter(
+    forge: &acton_service::prelude::ActorHandle,
+    filter: Filter,
+    schema_def: &SchemaDefinition,
+    targets: &[SchemaDefinition],
+    policy_store: &Arc<crate::authz::PolicyStore>,
+    claims: Option<&Claims>,
+) -> Result<Filter, ForgeError> {
+    let mut paths = Vec::new();
+    collect_filter_paths(&filter, &mut paths);
+
+    let mut relation_roots = BTreeSet::new();
+    for path in paths {
+        let Some(root) = schema_def.field(path.root()) else {
+            continue;
+        };
+        check_filterable(root, path)?;
+        let FieldType::Relation { target, .. } = &root.field_type else {
+            continue;
+        };
+        let Some(target) = targets.iter().find(|t| t.name == *target) else {
+            continue;
+        };
+        check_schema_access(policy_store, target, claims, AccessAction::Read)?;
+        if let Some(leaf) = target.field(path.leaf()) {
+            check_filterable(leaf, path)?;
+        }
+        relation_roots.insert(path.root().to_string());
     }
 
-    // Always fetch tenant config — both derived-collection population and
-    // relation-display resolution need it, and the call is cheap.
+    if relation_roots.is_empty() {
+        return Ok(filter);
+    }
     let (tx, rx) = oneshot::channel();
     forge
         .send(GetTenantConfig {
@@ -2173,62 +3257,105 @@ pub async fn get_entity(
         })
         .await;
     let tenant_config = ask_forge(rx).await?;
+    let Some(tenant_id) = tenant_scope(claims, &tenant_config) else {
+        return Ok(filter);
+    };
+    let mut scoped = vec![filter];
+    for root in relation_roots {
+        let path = FieldPath::from_segments(vec![root, "_tenant".to_string()]).map_err(|e| {
+            ForgeError::InvalidQuery {
+                message: e.to_string(),
+            }
+        })?;
+        scoped.push(Filter::eq(path, DynamicValue::Text(tenant_id.clone())));
+    }
+    Ok(Filter::and(scoped))
+}
 
-    // Populate derived inverse collection fields before serialization
-    // (issue #34). Wrapped in a single-element slice so the shared
-    // helper handles one or many entities the same way.
-    let mut single = [entity];
-    populate_derived_collections(
-        forge,
-        &schema_def,
-        &mut single,
-        claims.as_ref(),
-        &tenant_config,
-    )
-    .await?;
-    let [entity] = single;
-
-    let mut response = entity_to_response(&entity, &schema_def);
-    response.permissions = Some(perms);
+/// Reject filtering on `field` (reached through `path`) when it is
+/// `@hidden` or carries `@field_access`.
+fn check_filterable(field: &FieldDefinition, path: &FieldPath) -> Result<(), ForgeError> {
+    let message = || format!("field '{}' cannot be filtered", path.as_dotted());
+    if field.is_hidden() {
+        return Err(ForgeError::InvalidQuery { message: message() });
+    }
+    if field.field_access().is_some() {
+        return Err(ForgeError::Forbidden { message: message() });
+    }
+    Ok(())
+}
 
-    // Resolve relation display fields unless the caller opted out with
-    // `?resolve=false`. Reuses the same batched IN-query path the list
-    // endpoint uses — with a single source entity it's still one query
-    // per target schema, which is cheap.
-    if parse_truthy_flag(&params, "resolve") {
-        let entities_slice = std::slice::from_ref(&entity);
-        let display_map = resolve_relation_displays(
-            forge,
-            &schema_def,
-            entities_slice,
-            claims.as_ref(),
-            &tenant_config,
-        )
-        .await?;
-        apply_relation_displays(&mut response, &schema_def, &entity, &display_map);
+/// Push the field path of every leaf of `filter` into `out`.
+fn collect_filter_paths<'a>(filter: &'a Filter, out: &mut Vec<&'a FieldPath>) {
+    match filter {
+        Filter::Eq { path, .. }
+        | Filter::Ne { path, .. }
+        | Filter::Gt { path, .. }
+        | Filter::Gte { path, .. }
+        | Filter::Lt { path, .. }
+        | Filter::Lte { path, .. }
+        | Filter::Contains { path, .. }
+        | Filter::StartsWith { path, .. }
+        | Filter::In { path, .. } => out.push(path),
+        Filter::And { filters } | Filter::Or { filters } => {
+            for f in filters {
+                collect_filter_paths(f, out);
+            }
+        }
+        Filter::Not { filter } => collect_filter_paths(filter, out),
+        _ => {}
     }
+}
 
-    Ok(Json(response))
+/// Decode a client-supplied `after` cursor.
+fn parse_cursor(raw: &str) -> Result<Cursor, ForgeError> {
+    Cursor::decode(raw).map_err(|e| ForgeError::InvalidQuery {
+        message: e.to_string(),
+    })
 }
 
-/// PUT /schemas/{schema}/entities/{id} -- Update entity.
+/// Parse a query-string boolean flag with default-on semantics. Any truthy
+/// or unknown value keeps default-on; `false`/`0`/`no`/`off` opts out. We
+/// never 400 on unrecognized values — the client doesn't always control
+/// the exact spelling.
+fn parse_truthy_flag(params: &HashMap<String, String>, key: &str) -> bool {
+    !matches!(
+        params.get(key).map(|s| s.as_str()),
+        Some("false") | Some("0") | Some("no") | Some("off")
+    )
+}
+
+/// Parse a query-string boolean flag with default-off semantics: only
+/// `true`/`1`/`yes`/`on` opt in.
+fn parse_opt_in_flag(params: &HashMap<String, String>, key: &str) -> bool {
+    matches!(
+        params.get(key).map(|s| s.as_str()),
+        Some("true") | Some("1") | Some("yes") | Some("on")
+    )
+}
+
+/// GET /schemas/{schema}/entities.csv -- Stream entities as RFC 4180 CSV.
+///
+/// `?fields=a,b,c` picks the columns in order; without it every
+/// non-relation field is exported in schema order. The header row holds
+/// human labels, `@format("currency")`/`@format("percent")` values are
+/// formatted as in the views, and relation fields hold their target's
+/// display value. Filter, sort, and `include_deleted` params work as on the
+/// JSON list endpoint. Rows are fetched in batches with
+/// `DynEntityStore::query_stream`, so the export never holds the whole
+/// table in memory.
 #[instrument(skip_all, fields(schema = %schema))]
-pub async fn update_entity(
+pub async fn export_entities_csv(
     State(state): State<AppState<SchemaForgeConfig>>,
-    Path((schema, id)): Path<(String, String)>,
+    Path(schema): Path<String>,
     OptionalClaims(claims): OptionalClaims,
-    Json(body): Json<EntityRequest>,
-) -> Result<impl IntoResponse, ForgeError> {
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Response, ForgeError> {
     let schema_name = validate_schema_name(&schema)?;
     let forge = state
         .actor::<ForgeActor>()
         .expect("ForgeActor not registered");
 
-    // Parse the entity ID
-    let entity_id =
-        EntityId::parse(&id).map_err(|_| ForgeError::InvalidEntityId { id: id.clone() })?;
-
-    // Look up schema via actor
     let (tx, rx) = oneshot::channel();
     forge
         .send(GetSchema {
@@ -2241,31 +3368,80 @@ pub async fn update_entity(
     })?;
 
     let policy_store = fetch_policy_store(&state).await?;
-
-    // Access check
-    if let Err(e) = check_schema_access(
+    check_schema_access(
         &policy_store,
         &schema_def,
         claims.as_ref(),
-        AccessAction::Write,
-    ) {
-        if let Some(logger) = state.audit_logger() {
-            logger
-                .log_custom(
-                    "forge.access.denied",
-                    acton_service::audit::AuditSeverity::Warning,
-                    Some(serde_json::json!({
-                        "schema": &schema,
-                        "action": "write",
-                        "user": claims.as_ref().map(|c| &c.sub),
-                    })),
-                )
-                .await;
+        AccessAction::Read,
+    )?;
+
+    let hooks_config = state.config().custom.schema_forge.hooks.clone();
+    if hooks_config.enabled && schema_def.hook_for(HookEvent::BeforeRead).is_some() {
+        if let Some(dispatcher) = fetch_hook_dispatcher(forge).await {
+            let mut empty = BTreeMap::new();
+            apply_read_hook(
+                BeforeHookCtx {
+                    dispatcher: dispatcher.as_ref(),
+                    hooks_config: &hooks_config,
+                    schema: &schema_def,
+                    event: HookEvent::BeforeRead,
+                    operation: "export",
+                    user: claims.as_ref(),
+                    entity_id: None,
+                },
+                &mut empty,
+            )
+            .await?;
         }
-        return Err(e);
     }
 
-    // Record-level ownership check: fetch existing entity and verify ownership
+    // Columns, in output order. `@hidden` fields never leave the server.
+    let columns: Vec<schema_forge_core::types::FieldDefinition> = match params.get("fields") {
+        Some(raw) => {
+            parse_fields_param(raw, &schema_def)
+                .map_err(|e| ForgeError::InvalidQuery { message: e })?;
+            let mut seen = HashSet::new();
+            raw.split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty() && seen.insert(*name))
+                .filter_map(|name| schema_def.field(name))
+                .filter(|f| !f.is_hidden())
+                .cloned()
+                .collect()
+        }
+        None => schema_def
+            .fields
+            .iter()
+            .filter(|f| !f.is_hidden() && !f.is_derived())
+            .filter(|f| !matches!(f.field_type, FieldType::Relation { .. }))
+            .cloned()
+            .collect(),
+    };
+
+    let mut query = schema_forge_core::query::Query::new(schema_def.id.clone())
+        .with_deleted(parse_opt_in_flag(&params, "include_deleted"));
+    if let Some(sort_str) = params.get("sort") {
+        let sort_clauses =
+            parse_sort_param(sort_str).map_err(|e| ForgeError::InvalidQuery { message: e })?;
+        for (path, order) in sort_clauses {
+            query = query.with_sort(path, order);
+        }
+    }
+    if let Some(f) =
+        parse_list_filter(forge, &params, &schema_def, &policy_store, claims.as_ref()).await?
+    {
+        query = query.with_filter(f);
+    }
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetTenantConfig {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let tenant_config = ask_forge(rx).await?;
+    inject_tenant_scope(&mut query, claims.as_ref(), &tenant_config);
+
     let (tx, rx) = oneshot::channel();
     forge
         .send(GetRecordAccessPolicy {
@@ -2274,167 +3450,161 @@ pub async fn update_entity(
         .await;
     let record_access_policy = ask_forge(rx).await?;
 
-    if let (Some(ref policy), Some(ref c)) = (&record_access_policy, &claims) {
-        let (tx, rx) = oneshot::channel();
-        forge
-            .send(GetEntity {
-                schema: schema_name.clone(),
-                id: entity_id.clone(),
-                reply: ReplyChannel::new(tx),
-            })
-            .await;
-        let existing = ask_forge(rx).await?.map_err(ForgeError::from)?;
-        if !policy.can_modify(&schema_def, c, &existing).await {
-            return Err(ForgeError::Forbidden {
-                message: format!("not authorized to modify entity '{id}'"),
-            });
-        }
-    }
-
-    // Reject any client-supplied @hidden fields up front.
-    reject_hidden_fields_in_body(&schema_def, &body.fields)?;
-
-    // Convert JSON fields
-    let mut fields = json_to_entity_fields(&schema_def, &body.fields)
-        .map_err(|errors| ForgeError::ValidationFailed { details: errors })?;
-
-    // before_validate / before_change hooks. `before_validate` runs
-    // first so a hook can mutate or add fields before any
-    // persistence-side validation, then `before_change` runs on the
-    // (possibly mutated) fields.
-    let hooks_config = state.config().custom.schema_forge.hooks.clone();
-    let hook_dispatcher = if hooks_config.enabled && schema_def.has_hooks() {
-        fetch_hook_dispatcher(forge).await
-    } else {
-        None
-    };
-    if let Some(ref dispatcher) = hook_dispatcher {
-        apply_before_hook(
-            BeforeHookCtx {
-                dispatcher: dispatcher.as_ref(),
-                hooks_config: &hooks_config,
-                schema: &schema_def,
-                event: HookEvent::BeforeValidate,
-                operation: "update",
-                user: claims.as_ref(),
-                entity_id: Some(entity_id.as_str().to_string()),
-            },
-            &mut fields,
-        )
-        .await?;
-        apply_before_hook(
-            BeforeHookCtx {
-                dispatcher: dispatcher.as_ref(),
-                hooks_config: &hooks_config,
-                schema: &schema_def,
-                event: HookEvent::BeforeChange,
-                operation: "update",
-                user: claims.as_ref(),
-                entity_id: Some(entity_id.as_str().to_string()),
-            },
-            &mut fields,
-        )
-        .await?;
-    }
-
-    // Build entity with specific ID, filtering write-restricted fields
-    let mut entity = Entity::with_id(entity_id, schema_name, fields);
-    filter_entity_fields(
-        &policy_store,
-        &mut entity,
-        &schema_def,
-        claims.as_ref(),
-        FieldFilterDirection::Write,
-    );
-
-    // Update entity via actor
     let (tx, rx) = oneshot::channel();
     forge
-        .send(UpdateEntity {
-            entity,
+        .send(GetBackend {
             reply: ReplyChannel::new(tx),
         })
         .await;
-    let mut updated = ask_forge(rx).await?.map_err(ForgeError::from)?;
-
-    // after_change hook — handed off to HookDispatchActor for
-    // detached dispatch under acton supervision.
-    if let Some(dispatcher) = hook_dispatcher.clone() {
-        fire_after_hook(
-            &state,
-            AfterHookCtx {
-                dispatcher,
-                hooks_config: hooks_config.clone(),
-                schema: schema_def.clone(),
-                event: HookEvent::AfterChange,
-                operation: "update".to_string(),
-                user_id: claims.as_ref().map(|c| c.sub.clone()),
-            },
-            &updated,
-        )
-        .await;
-    }
+    let backend = ask_forge(rx).await?.ok_or(ForgeError::BackendUnavailable {
+        message: "no backend configured".into(),
+    })?;
 
-    // Filter read-restricted fields from response
-    filter_entity_fields(
-        &policy_store,
-        &mut updated,
-        &schema_def,
-        claims.as_ref(),
-        FieldFilterDirection::Read,
+    let mut header_row = String::new();
+    csv::write_record(
+        columns.iter().map(|f| csv::snake_to_label(f.name.as_str())),
+        &mut header_row,
     );
 
-    // Audit: entity updated
-    if let Some(logger) = state.audit_logger() {
-        logger
-            .log_custom(
-                "forge.entity.updated",
-                acton_service::audit::AuditSeverity::Informational,
-                Some(serde_json::json!({
-                    "schema": schema,
-                    "entity_id": updated.id.as_str(),
-                    "user": claims.as_ref().map(|c| &c.sub),
-                })),
-            )
-            .await;
-    }
+    let disposition = format!("attachment; filename=\"{}.csv\"", schema_def.name);
+
+    // The body must be 'static, so the export runs in its own task and
+    // hands each rendered batch over a bounded channel. When the client
+    // disconnects the send fails and the task stops paging.
+    let (chunk_tx, chunk_rx) = tokio::sync::mpsc::channel::<Result<Bytes, std::io::Error>>(4);
+    let forge = forge.clone();
+    tokio::spawn(async move {
+        let render = async {
+            let has_derived = columns.iter().any(|f| f.is_derived());
+            let has_relations = columns
+                .iter()
+                .any(|f| matches!(f.field_type, FieldType::Relation { .. }));
+            let mut batches = backend
+                .query_stream(&query)
+                .chunks(schema_forge_backend::stream::STREAM_BATCH_SIZE);
+            while let Some(batch) = batches.next().await {
+                let batch = batch.into_iter().collect::<Result<Vec<Entity>, _>>()?;
+                let mut batch = match (&record_access_policy, claims.as_ref()) {
+                    (Some(policy), Some(c)) => policy.filter_visible(&schema_def, c, batch).await,
+                    _ => batch,
+                };
+                for entity in &mut batch {
+                    entity.apply_computed(&schema_def);
+                }
+                if has_derived {
+                    populate_derived_collections(
+                        &forge,
+                        &schema_def,
+                        &mut batch,
+                        claims.as_ref(),
+                        &tenant_config,
+                    )
+                    .await?;
+                }
+                let display_map = if has_relations && !batch.is_empty() {
+                    resolve_relation_displays(
+                        &forge,
+                        &schema_def,
+                        &batch,
+                        claims.as_ref(),
+                        &tenant_config,
+                    )
+                    .await?
+                } else {
+                    HashMap::new()
+                };
+
+                let mut chunk = String::new();
+                for mut entity in batch {
+                    filter_entity_fields(
+                        &policy_store,
+                        &mut entity,
+                        &schema_def,
+                        claims.as_ref(),
+                        FieldFilterDirection::Read,
+                    );
+                    let cells = columns
+                        .iter()
+                        .map(|f| csv_cell(&entity, f, display_map.get(f.name.as_str())));
+                    csv::write_record(cells, &mut chunk);
+                }
+                if chunk_tx.send(Ok(Bytes::from(chunk))).await.is_err() {
+                    return Ok(());
+                }
+            }
+            Ok::<(), ForgeError>(())
+        };
+        if let Err(e) = render.await {
+            tracing::warn!(schema = %schema_def.name, error = %e, "CSV export aborted");
+            let _ = chunk_tx
+                .send(Err(std::io::Error::other(e.to_string())))
+                .await;
+        }
+    });
 
-    // Webhook: fire notifications
-    let webhook_event = crate::webhook::WebhookEvent::from_update(
-        &schema,
-        &updated,
-        claims.as_ref().map(|c| c.sub.as_str()),
+    let rows = futures::stream::unfold(chunk_rx, |mut rx| async move {
+        rx.recv().await.map(|chunk| (chunk, rx))
+    });
+    let body = Body::from_stream(
+        futures::stream::once(async move { Ok(Bytes::from(header_row)) }).chain(rows),
     );
-    dispatch_webhook(&state, &schema_def, webhook_event, "updated").await;
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("text/csv; charset=utf-8"),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                HeaderValue::from_str(&disposition).map_err(|e| ForgeError::Internal {
+                    message: e.to_string(),
+                })?,
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
 
-    Ok(Json(entity_to_response(&updated, &schema_def)))
+/// Render one CSV cell. Relation fields use their resolved display value,
+/// falling back to the raw ID; multiple values are joined with `"; "`.
+fn csv_cell(
+    entity: &Entity,
+    field: &schema_forge_core::types::FieldDefinition,
+    displays: Option<&HashMap<String, String>>,
+) -> String {
+    let Some(value) = entity.field(field.name.as_str()) else {
+        return String::new();
+    };
+    if matches!(field.field_type, FieldType::Relation { .. }) {
+        return relation_ids_in_order(value)
+            .into_iter()
+            .map(|id| match displays.and_then(|d| d.get(&id)) {
+                Some(label) => label.clone(),
+                None => id,
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+    }
+    csv::format_cell(value, csv::CellFormat::of(field))
 }
 
-/// PATCH /schemas/{schema}/entities/{id} -- Partially update entity.
+/// POST /schemas/{schema}/entities/query -- Advanced query with JSON body.
 ///
-/// Unlike PUT, which has full-replacement semantics and requires every
-/// required field in the payload, PATCH merges the supplied fields onto
-/// the existing entity. Fields omitted from the request body are
-/// preserved unchanged. The merged entity is then dispatched through the
-/// same `UpdateEntity` actor message used by PUT, so `before_change` /
-/// `after_change` hooks, webhooks, and audit logging fire identically.
+/// Accepts a full filter IR as JSON with plain values (schema-inferred types).
 #[instrument(skip_all, fields(schema = %schema))]
-pub async fn patch_entity(
+pub async fn query_entities(
     State(state): State<AppState<SchemaForgeConfig>>,
-    Path((schema, id)): Path<(String, String)>,
+    Path(schema): Path<String>,
     OptionalClaims(claims): OptionalClaims,
-    Json(body): Json<EntityRequest>,
+    Json(body): Json<EntityQueryBody>,
 ) -> Result<impl IntoResponse, ForgeError> {
     let schema_name = validate_schema_name(&schema)?;
     let forge = state
         .actor::<ForgeActor>()
         .expect("ForgeActor not registered");
 
-    // Parse the entity ID
-    let entity_id =
-        EntityId::parse(&id).map_err(|_| ForgeError::InvalidEntityId { id: id.clone() })?;
-
-    // Look up schema via actor
+    // Verify schema exists via actor
     let (tx, rx) = oneshot::channel();
     forge
         .send(GetSchema {
@@ -2449,210 +3619,248 @@ pub async fn patch_entity(
     let policy_store = fetch_policy_store(&state).await?;
 
     // Access check
-    if let Err(e) = check_schema_access(
+    check_schema_access(
         &policy_store,
         &schema_def,
         claims.as_ref(),
-        AccessAction::Write,
-    ) {
-        if let Some(logger) = state.audit_logger() {
-            logger
-                .log_custom(
-                    "forge.access.denied",
-                    acton_service::audit::AuditSeverity::Warning,
-                    Some(serde_json::json!({
-                        "schema": &schema,
-                        "action": "write",
-                        "user": claims.as_ref().map(|c| &c.sub),
-                    })),
-                )
-                .await;
+        AccessAction::Read,
+    )?;
+
+    // before_read hook gate (no entity_id, no fields — query scope).
+    let hooks_config = state.config().custom.schema_forge.hooks.clone();
+    if hooks_config.enabled && schema_def.hook_for(HookEvent::BeforeRead).is_some() {
+        if let Some(dispatcher) = fetch_hook_dispatcher(forge).await {
+            let mut empty = BTreeMap::new();
+            apply_read_hook(
+                BeforeHookCtx {
+                    dispatcher: dispatcher.as_ref(),
+                    hooks_config: &hooks_config,
+                    schema: &schema_def,
+                    event: HookEvent::BeforeRead,
+                    operation: "query",
+                    user: claims.as_ref(),
+                    entity_id: None,
+                },
+                &mut empty,
+            )
+            .await?;
         }
-        return Err(e);
     }
 
-    // Always fetch the existing entity — PATCH needs it both for the
-    // record-level ownership check and for the field merge step below.
-    let (tx, rx) = oneshot::channel();
-    forge
-        .send(GetEntity {
-            schema: schema_name.clone(),
-            id: entity_id.clone(),
-            reply: ReplyChannel::new(tx),
-        })
-        .await;
-    let existing = ask_forge(rx).await?.map_err(ForgeError::from)?;
+    // Build a query
+    let mut query = schema_forge_core::query::Query::new(schema_def.id.clone());
 
-    // Record-level ownership check
-    let (tx, rx) = oneshot::channel();
-    forge
-        .send(GetRecordAccessPolicy {
-            reply: ReplyChannel::new(tx),
-        })
-        .await;
-    let record_access_policy = ask_forge(rx).await?;
+    if let Some(limit) = body.limit {
+        query = query.with_limit(limit);
+    }
+    if let Some(offset) = body.offset {
+        query = query.with_offset(offset);
+    }
+    if let Some(after) = &body.after {
+        query = query.with_after(parse_cursor(after)?);
+    }
+    query = query.with_deleted(body.include_deleted);
 
-    if let (Some(ref policy), Some(ref c)) = (&record_access_policy, &claims) {
-        if !policy.can_modify(&schema_def, c, &existing).await {
-            return Err(ForgeError::Forbidden {
-                message: format!("not authorized to modify entity '{id}'"),
-            });
+    // Parse sort clauses
+    if let Some(sort_clauses) = &body.sort {
+        for clause in sort_clauses {
+            let path = FieldPath::parse(&clause.field).map_err(|e| ForgeError::InvalidQuery {
+                message: format!("invalid sort field '{}': {e}", clause.field),
+            })?;
+            let order = match clause.order.as_deref() {
+                Some("desc") => SortOrder::Descending,
+                Some("asc") | None => SortOrder::Ascending,
+                Some(other) => {
+                    return Err(ForgeError::InvalidQuery {
+                        message: format!("invalid sort order '{other}', expected 'asc' or 'desc'"),
+                    });
+                }
+            };
+            query = query.with_sort(path, order);
         }
     }
 
-    // Reject any client-supplied @hidden fields up front.
-    reject_hidden_fields_in_body(&schema_def, &body.fields)?;
-
-    // Convert only the fields supplied by the client. Merge mode skips
-    // the required-field check so partial payloads are valid.
-    let patch_fields =
-        json_to_entity_fields_with_mode(&schema_def, &body.fields, ConversionMode::Merge)
-            .map_err(|errors| ForgeError::ValidationFailed { details: errors })?;
-
-    // Merge the patch onto the existing entity's field map so hooks see
-    // the post-patch view of the entity. The merged map is only used to
-    // drive the hook path and to compute the final delta against the
-    // loaded baseline; it is NOT what gets written to the backend.
-    let mut merged = existing.fields.clone();
-    for (k, v) in patch_fields {
-        merged.insert(k, v);
+    // Parse filter
+    if let Some(filter_json) = &body.filter {
+        let filter = parse_body_filter(
+            forge,
+            filter_json,
+            &schema_def,
+            &policy_store,
+            claims.as_ref(),
+        )
+        .await?;
+        query = query.with_filter(filter);
     }
 
-    // before_change hook — operates on the already-merged field set so
-    // hooks see the post-patch state.
-    let hooks_config = state.config().custom.schema_forge.hooks.clone();
-    let hook_dispatcher = if hooks_config.enabled && schema_def.has_hooks() {
-        fetch_hook_dispatcher(forge).await
+    // Validate field projection
+    let projection = if let Some(ref field_names) = body.fields {
+        let set: HashSet<String> = field_names.iter().cloned().collect();
+        let unknown: Vec<&str> = set
+            .iter()
+            .filter(|n| {
+                !schema_def
+                    .fields
+                    .iter()
+                    .any(|f| f.name.as_str() == n.as_str())
+            })
+            .map(String::as_str)
+            .collect();
+        if !unknown.is_empty() {
+            return Err(ForgeError::InvalidQuery {
+                message: format!("unknown fields: {}", unknown.join(", ")),
+            });
+        }
+        Some(set)
     } else {
         None
     };
-    if let Some(ref dispatcher) = hook_dispatcher {
-        apply_before_hook(
-            BeforeHookCtx {
-                dispatcher: dispatcher.as_ref(),
-                hooks_config: &hooks_config,
-                schema: &schema_def,
-                event: HookEvent::BeforeValidate,
-                operation: "patch",
-                user: claims.as_ref(),
-                entity_id: Some(entity_id.as_str().to_string()),
-            },
-            &mut merged,
-        )
-        .await?;
-        apply_before_hook(
-            BeforeHookCtx {
-                dispatcher: dispatcher.as_ref(),
-                hooks_config: &hooks_config,
-                schema: &schema_def,
-                event: HookEvent::BeforeChange,
-                operation: "patch",
-                user: claims.as_ref(),
-                entity_id: Some(entity_id.as_str().to_string()),
-            },
-            &mut merged,
-        )
-        .await?;
-    }
 
-    // Compute the delta: only keys whose final value differs from the
-    // loaded baseline go to the backend. This keeps PATCH's SQL UPDATE
-    // actually partial, which makes the whole class of "null column
-    // being rebound with the wrong type" bugs structurally impossible
-    // (see issue #12). An unchanged field never hits bind_dynamic_value.
-    let mut delta = std::collections::BTreeMap::new();
-    for (k, v) in &merged {
-        match existing.fields.get(k) {
-            Some(existing_v) if existing_v == v => {}
-            _ => {
-                delta.insert(k.clone(), v.clone());
-            }
-        }
-    }
+    let response = execute_entity_query(
+        &state,
+        &schema_def,
+        claims.as_ref(),
+        &mut query,
+        projection.as_ref(),
+        body.resolve,
+        body.count,
+        false,
+    )
+    .await?;
+    Ok(Json(response))
+}
 
-    // Empty delta: nothing to write (the patch body was a no-op after
-    // merge, e.g. every patched field already held the requested value,
-    // or a before_change hook reverted the changes). Skip the backend
-    // round-trip and return the existing entity as-is.
-    let mut updated = if delta.is_empty() {
-        existing
-    } else {
-        let mut entity = Entity::with_id(entity_id, schema_name, delta);
-        filter_entity_fields(
-            &policy_store,
-            &mut entity,
-            &schema_def,
-            claims.as_ref(),
-            FieldFilterDirection::Write,
-        );
-        let (tx, rx) = oneshot::channel();
-        forge
-            .send(UpdateEntity {
-                entity,
-                reply: ReplyChannel::new(tx),
-            })
-            .await;
-        ask_forge(rx).await?.map_err(ForgeError::from)?
-    };
+/// POST /schemas/{schema}/entities/aggregate -- Aggregate metrics over entities.
+///
+/// Computes `count`, `sum`, `avg`, `min`, and `max` metrics over the entities
+/// matching an optional filter, optionally grouped by one or more fields.
+/// Numeric metrics on non-numeric fields are rejected with 422. Fields that
+/// are `@hidden` or carry `@field_access` cannot be aggregated or grouped,
+/// since per-field read checks need the individual entities.
+#[instrument(skip_all, fields(schema = %schema))]
+pub async fn aggregate_entities(
+    State(state): State<AppState<SchemaForgeConfig>>,
+    Path(schema): Path<String>,
+    OptionalClaims(claims): OptionalClaims,
+    Json(body): Json<AggregateBody>,
+) -> Result<impl IntoResponse, ForgeError> {
+    let schema_name = validate_schema_name(&schema)?;
+    let forge = state
+        .actor::<ForgeActor>()
+        .expect("ForgeActor not registered");
 
-    // after_change hook — dispatched via HookDispatchActor
-    if let Some(dispatcher) = hook_dispatcher.clone() {
-        fire_after_hook(
-            &state,
-            AfterHookCtx {
-                dispatcher,
-                hooks_config: hooks_config.clone(),
-                schema: schema_def.clone(),
-                event: HookEvent::AfterChange,
-                operation: "patch".to_string(),
-                user_id: claims.as_ref().map(|c| c.sub.clone()),
-            },
-            &updated,
-        )
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetSchema {
+            name: schema_name.as_str().to_string(),
+            reply: ReplyChannel::new(tx),
+        })
         .await;
-    }
+    let schema_def = ask_forge(rx).await?.ok_or(ForgeError::SchemaNotFound {
+        name: schema_name.as_str().to_string(),
+    })?;
 
-    // Filter read-restricted fields from response
-    filter_entity_fields(
+    let policy_store = fetch_policy_store(&state).await?;
+    check_schema_access(
         &policy_store,
-        &mut updated,
         &schema_def,
         claims.as_ref(),
-        FieldFilterDirection::Read,
-    );
+        AccessAction::Read,
+    )?;
 
-    // Audit: entity patched
-    if let Some(logger) = state.audit_logger() {
-        logger
-            .log_custom(
-                "forge.entity.patched",
-                acton_service::audit::AuditSeverity::Informational,
-                Some(serde_json::json!({
-                    "schema": schema,
-                    "entity_id": updated.id.as_str(),
-                    "user": claims.as_ref().map(|c| &c.sub),
-                })),
-            )
-            .await;
+    if body.metrics.is_empty() {
+        return Err(ForgeError::InvalidQuery {
+            message: "at least one metric is required".to_string(),
+        });
+    }
+    let mut query = AggregateQuery::new(schema_def.id.clone()).with_deleted(body.include_deleted);
+    for metric in &body.metrics {
+        let op = AggregateOp::parse(metric).map_err(|e| ForgeError::InvalidQuery {
+            message: e.to_string(),
+        })?;
+        query = query.with_op(op);
+    }
+    for field in &body.group_by {
+        let name = FieldName::new(field).map_err(|_| ForgeError::InvalidQuery {
+            message: format!("invalid group_by field '{field}'"),
+        })?;
+        query = query.with_group_by(name);
     }
 
-    // Webhook: fire notifications
-    let webhook_event = crate::webhook::WebhookEvent::from_update(
-        &schema,
-        &updated,
-        claims.as_ref().map(|c| c.sub.as_str()),
-    );
-    dispatch_webhook(&state, &schema_def, webhook_event, "updated").await;
+    let restricted: Vec<&str> = query
+        .ops
+        .iter()
+        .filter_map(|op| op.field().map(|path| path.root()))
+        .chain(query.group_by.iter().map(|g| g.as_str()))
+        .filter(|name| {
+            schema_def
+                .field(name)
+                .is_some_and(|f| f.is_hidden() || f.field_access().is_some())
+        })
+        .collect();
+    if let Some(name) = restricted.first() {
+        return Err(ForgeError::Forbidden {
+            message: format!("field '{name}' cannot be aggregated"),
+        });
+    }
+
+    if let Some(filter_json) = &body.filter {
+        let filter = parse_body_filter(
+            forge,
+            filter_json,
+            &schema_def,
+            &policy_store,
+            claims.as_ref(),
+        )
+        .await?;
+        query = query.with_filter(filter);
+    }
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetTenantConfig {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let tenant_config = ask_forge(rx).await?;
+    inject_tenant_scope_aggregate(&mut query, claims.as_ref(), &tenant_config);
 
-    Ok(Json(entity_to_response(&updated, &schema_def)))
+    let op_count = query.ops.len();
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(AggregateEntities {
+            query,
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let results = ask_forge(rx).await?.map_err(ForgeError::from)?;
+
+    // Backends return one result per metric per group, in metric order.
+    let rows = results
+        .chunks(op_count)
+        .map(|chunk| AggregateRow {
+            group: chunk[0]
+                .group
+                .iter()
+                .map(|(k, v)| (k.clone(), dynamic_value_to_json(v)))
+                .collect(),
+            metrics: chunk.iter().map(|r| (r.op.to_string(), r.value)).collect(),
+        })
+        .collect();
+    Ok(Json(AggregateResponse { rows }))
 }
 
-/// DELETE /schemas/{schema}/entities/{id} -- Delete entity.
+/// GET /schemas/{schema}/entities/{id} -- Get entity by ID.
+///
+/// Accepts `?fields=a,b` to project the returned fields and
+/// `?expand=company,tags` to replace relation IDs with the nested target
+/// entities. Expanded fields must be part of the projection, if any.
 #[instrument(skip_all, fields(schema = %schema))]
-pub async fn delete_entity(
+pub async fn get_entity(
     State(state): State<AppState<SchemaForgeConfig>>,
     Path((schema, id)): Path<(String, String)>,
     OptionalClaims(claims): OptionalClaims,
+    Query(params): Query<HashMap<String, String>>,
 ) -> Result<impl IntoResponse, ForgeError> {
     let schema_name = validate_schema_name(&schema)?;
     let forge = state
@@ -2674,227 +3882,1945 @@ pub async fn delete_entity(
     let policy_store = fetch_policy_store(&state).await?;
 
     // Access check
-    if let Err(e) = check_schema_access(
+    check_schema_access(
         &policy_store,
         &schema_def,
         claims.as_ref(),
-        AccessAction::Delete,
-    ) {
-        if let Some(logger) = state.audit_logger() {
-            logger
-                .log_custom(
-                    "forge.access.denied",
-                    acton_service::audit::AuditSeverity::Warning,
-                    Some(serde_json::json!({
-                        "schema": &schema,
-                        "action": "delete",
-                        "user": claims.as_ref().map(|c| &c.sub),
-                    })),
-                )
-                .await;
-        }
-        return Err(e);
-    }
-
-    let entity_id =
-        EntityId::parse(&id).map_err(|_| ForgeError::InvalidEntityId { id: id.clone() })?;
-
-    // Record-level ownership check: fetch entity first and verify ownership
-    let (tx, rx) = oneshot::channel();
-    forge
-        .send(GetRecordAccessPolicy {
-            reply: ReplyChannel::new(tx),
-        })
-        .await;
-    let record_access_policy = ask_forge(rx).await?;
+        AccessAction::Read,
+    )?;
 
-    if let (Some(ref policy), Some(ref c)) = (&record_access_policy, &claims) {
-        let (tx, rx) = oneshot::channel();
-        forge
-            .send(GetEntity {
-                schema: schema_name.clone(),
-                id: entity_id.clone(),
-                reply: ReplyChannel::new(tx),
-            })
-            .await;
-        let entity = ask_forge(rx).await?.map_err(ForgeError::from)?;
-        if !policy.can_delete(&schema_def, c, &entity).await {
-            return Err(ForgeError::Forbidden {
-                message: format!("not authorized to delete entity '{id}'"),
+    // Parse field projection and relation expansion
+    let projection = params
+        .get("fields")
+        .map(|fields_str| parse_fields_param(fields_str, &schema_def))
+        .transpose()
+        .map_err(|e| ForgeError::InvalidQuery { message: e })?;
+    let expand = params
+        .get("expand")
+        .map(|expand_str| parse_expand_param(expand_str, &schema_def))
+        .transpose()
+        .map_err(|e| ForgeError::InvalidQuery { message: e })?
+        .unwrap_or_default();
+    if let Some(ref proj) = projection {
+        if let Some(name) = expand.iter().find(|n| !proj.contains(*n)) {
+            return Err(ForgeError::InvalidQuery {
+                message: format!("expanded field '{name}' is not in the fields projection"),
             });
         }
     }
 
-    // before_delete / after_delete hook setup. Fetch entity snapshot when a
-    // hook is configured so the dispatcher sees the fields being deleted.
+    // Parse the entity ID
+    let entity_id = schema_def
+        .parse_entity_id(&id)
+        .map_err(|_| ForgeError::InvalidEntityId { id: id.clone() })?;
+
+    // Resolve hook configuration up front. Only fetch the dispatcher when
+    // this schema actually declares a read-side hook — keeps the cost
+    // zero for the common case where reads are unhooked.
     let hooks_config = state.config().custom.schema_forge.hooks.clone();
-    let hook_dispatcher = if hooks_config.enabled && schema_def.has_hooks() {
+    let read_hook_dispatcher = if hooks_config.enabled
+        && (schema_def.hook_for(HookEvent::BeforeRead).is_some()
+            || schema_def.hook_for(HookEvent::AfterRead).is_some())
+    {
         fetch_hook_dispatcher(forge).await
     } else {
         None
     };
-    let mut pre_delete_snapshot: Option<Entity> = None;
-    if hook_dispatcher.is_some()
-        && (hooks_config
-            .binding_for(schema_def.name.as_str(), HookEvent::BeforeDelete)
-            .is_some()
-            || hooks_config
-                .binding_for(schema_def.name.as_str(), HookEvent::AfterDelete)
-                .is_some())
-    {
-        let (tx, rx) = oneshot::channel();
-        forge
-            .send(GetEntity {
-                schema: schema_name.clone(),
-                id: entity_id.clone(),
-                reply: ReplyChannel::new(tx),
-            })
+
+    // before_read hook (blocking; no fields yet — entity has not been fetched).
+    if let Some(ref dispatcher) = read_hook_dispatcher {
+        let mut empty_fields = BTreeMap::new();
+        apply_read_hook(
+            BeforeHookCtx {
+                dispatcher: dispatcher.as_ref(),
+                hooks_config: &hooks_config,
+                schema: &schema_def,
+                event: HookEvent::BeforeRead,
+                operation: "read",
+                user: claims.as_ref(),
+                entity_id: Some(entity_id.as_str().to_string()),
+            },
+            &mut empty_fields,
+        )
+        .await?;
+    }
+
+    // Get entity via actor
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetEntity {
+            schema: schema_name,
+            id: entity_id.clone(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let mut entity = ask_forge(rx).await?.map_err(ForgeError::from)?;
+
+    // A soft-deleted entity reads as missing unless the caller asks for it.
+    reject_soft_deleted(
+        &schema_def,
+        &entity,
+        parse_opt_in_flag(&params, "include_deleted"),
+    )?;
+
+    // Record-level visibility check
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetRecordAccessPolicy {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let record_access_policy = ask_forge(rx).await?;
+
+    if let (Some(ref policy), Some(ref c)) = (&record_access_policy, &claims) {
+        let visible = policy
+            .filter_visible(&schema_def, c, vec![entity.clone()])
             .await;
-        if let Ok(Ok(e)) = ask_forge(rx).await {
-            pre_delete_snapshot = Some(e);
+        if visible.is_empty() {
+            return Err(ForgeError::Forbidden {
+                message: format!("not authorized to view entity '{id}'"),
+            });
         }
     }
-    if let (Some(ref dispatcher), Some(snapshot)) = (&hook_dispatcher, &pre_delete_snapshot) {
-        let mut fields = snapshot.fields.clone();
-        apply_before_hook(
+
+    // Compute per-entity permissions against the unfiltered entity so any
+    // attribute-based Cedar policy (`@owner`, `@tenant`, etc.) sees the
+    // full record. Capture before field filtering strips read-restricted
+    // fields from the response payload.
+    let perms = entity_permissions(&policy_store, &schema_def, &entity, claims.as_ref());
+
+    // Filter read-restricted fields from response
+    filter_entity_fields(
+        &policy_store,
+        &mut entity,
+        &schema_def,
+        claims.as_ref(),
+        FieldFilterDirection::Read,
+    );
+
+    // after_read hook (blocking; runs through the same call_before path
+    // so it can patch the response payload — e.g. redact, decorate).
+    if let Some(ref dispatcher) = read_hook_dispatcher {
+        apply_read_hook(
             BeforeHookCtx {
                 dispatcher: dispatcher.as_ref(),
                 hooks_config: &hooks_config,
                 schema: &schema_def,
-                event: HookEvent::BeforeDelete,
-                operation: "delete",
+                event: HookEvent::AfterRead,
+                operation: "read",
                 user: claims.as_ref(),
-                entity_id: Some(entity_id.as_str().to_string()),
+                entity_id: Some(entity.id.as_str().to_string()),
             },
-            &mut fields,
+            &mut entity.fields,
+        )
+        .await?;
+    }
+
+    // Always fetch tenant config — both derived-collection population and
+    // relation-display resolution need it, and the call is cheap.
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetTenantConfig {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let tenant_config = ask_forge(rx).await?;
+
+    // Populate derived inverse collection fields before serialization
+    // (issue #34). Wrapped in a single-element slice so the shared
+    // helper handles one or many entities the same way.
+    let mut single = [entity];
+    populate_derived_collections(
+        forge,
+        &schema_def,
+        &mut single,
+        claims.as_ref(),
+        &tenant_config,
+    )
+    .await?;
+    let [mut entity] = single;
+
+    if let Some(ref proj) = projection {
+        entity.fields.retain(|k, _| proj.contains(k));
+    }
+
+    let etag = etag_header(&entity);
+    let mut response = entity_to_response(&entity, &schema_def);
+    response.permissions = Some(perms);
+
+    // Resolve relation display fields unless the caller opted out with
+    // `?resolve=false`. Reuses the same batched IN-query path the list
+    // endpoint uses — with a single source entity it's still one query
+    // per target schema, which is cheap.
+    if parse_truthy_flag(&params, "resolve") {
+        let entities_slice = std::slice::from_ref(&entity);
+        let display_map = resolve_relation_displays(
+            forge,
+            &schema_def,
+            entities_slice,
+            claims.as_ref(),
+            &tenant_config,
         )
         .await?;
+        apply_relation_displays(&mut response, &schema_def, &entity, &display_map);
+    }
+
+    if !expand.is_empty() {
+        let ctx = ExpandCtx {
+            forge,
+            policy_store: &policy_store,
+            claims: claims.as_ref(),
+            tenant_config: &tenant_config,
+            record_access_policy: &record_access_policy,
+        };
+        let expanded = expand_relations(&ctx, &schema_def, &entity, &expand).await?;
+        response.fields.extend(expanded);
+    }
+
+    Ok((etag, Json(response)))
+}
+
+/// GET /schemas/{schema}/entities/by/{field}/{value} -- Get the entity
+/// whose `unique` field equals `value`.
+///
+/// `value` is coerced by the field's type as in filter params. A field
+/// that is not declared `unique` is a 400 and no match is a 404; a match
+/// is served exactly like `GET /schemas/{schema}/entities/{id}`, so the
+/// same query params, access checks, and read hooks apply.
+#[instrument(skip_all, fields(schema = %schema))]
+pub async fn get_entity_by_field(
+    State(state): State<AppState<SchemaForgeConfig>>,
+    Path((schema, field, value)): Path<(String, String, String)>,
+    OptionalClaims(claims): OptionalClaims,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Response, ForgeError> {
+    let schema_name = validate_schema_name(&schema)?;
+    let forge = state
+        .actor::<ForgeActor>()
+        .expect("ForgeActor not registered");
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetSchema {
+            name: schema_name.as_str().to_string(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let schema_def = ask_forge(rx).await?.ok_or(ForgeError::SchemaNotFound {
+        name: schema_name.as_str().to_string(),
+    })?;
+
+    let policy_store = fetch_policy_store(&state).await?;
+    check_schema_access(
+        &policy_store,
+        &schema_def,
+        claims.as_ref(),
+        AccessAction::Read,
+    )?;
+
+    let field_def = schema_def
+        .field(&field)
+        .filter(|f| f.is_unique())
+        .ok_or_else(|| ForgeError::InvalidQuery {
+            message: format!("field '{field}' is not a unique field of '{schema_name}'"),
+        })?;
+    let key = coerce_string_value(&value, Some(&field_def.field_type)).map_err(|e| {
+        ForgeError::InvalidQuery {
+            message: format!("field '{field}': {e}"),
+        }
+    })?;
+
+    let mut query = schema_forge_core::query::Query::new(schema_def.id.clone())
+        .with_filter(Filter::eq(FieldPath::single(field.as_str()), key))
+        .with_deleted(parse_opt_in_flag(&params, "include_deleted"))
+        .with_limit(1);
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetTenantConfig {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let tenant_config = ask_forge(rx).await?;
+    inject_tenant_scope(&mut query, claims.as_ref(), &tenant_config);
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(QueryEntities {
+            query,
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let result = ask_forge(rx).await?.map_err(ForgeError::from)?;
+    let Some(entity) = result.entities.into_iter().next() else {
+        return Err(ForgeError::EntityNotFound {
+            schema: schema_name.as_str().to_string(),
+            entity_id: format!("{field}={value}"),
+        });
+    };
+
+    get_entity(
+        State(state),
+        Path((schema, entity.id.as_str().to_string())),
+        OptionalClaims(claims),
+        Query(params),
+    )
+    .await
+    .map(IntoResponse::into_response)
+}
+
+/// PUT /schemas/{schema}/entities/{id} -- Update entity.
+///
+/// An `If-Match` header carrying the entity's `ETag` makes the write
+/// conditional: a stale version answers `412 Precondition Failed`.
+#[instrument(skip_all, fields(schema = %schema))]
+pub async fn update_entity(
+    State(state): State<AppState<SchemaForgeConfig>>,
+    Path((schema, id)): Path<(String, String)>,
+    OptionalClaims(claims): OptionalClaims,
+    headers: HeaderMap,
+    Json(body): Json<EntityRequest>,
+) -> Result<impl IntoResponse, ForgeError> {
+    let schema_name = validate_schema_name(&schema)?;
+    let expected_version = if_match_version(
+        &headers,
+        state.config().custom.schema_forge.require_if_match,
+    )?;
+    let forge = state
+        .actor::<ForgeActor>()
+        .expect("ForgeActor not registered");
+
+    // Look up schema via actor
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetSchema {
+            name: schema_name.as_str().to_string(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let schema_def = ask_forge(rx).await?.ok_or(ForgeError::SchemaNotFound {
+        name: schema_name.as_str().to_string(),
+    })?;
+
+    // Parse the entity ID against the schema's id prefix
+    let entity_id = schema_def
+        .parse_entity_id(&id)
+        .map_err(|_| ForgeError::InvalidEntityId { id: id.clone() })?;
+
+    let policy_store = fetch_policy_store(&state).await?;
+
+    // Access check
+    if let Err(e) = check_schema_access(
+        &policy_store,
+        &schema_def,
+        claims.as_ref(),
+        AccessAction::Write,
+    ) {
+        if let Some(logger) = state.audit_logger() {
+            logger
+                .log_custom(
+                    "forge.access.denied",
+                    acton_service::audit::AuditSeverity::Warning,
+                    Some(serde_json::json!({
+                        "schema": &schema,
+                        "action": "write",
+                        "user": claims.as_ref().map(|c| &c.sub),
+                    })),
+                )
+                .await;
+        }
+        return Err(e);
+    }
+
+    // Record-level ownership check: fetch existing entity and verify ownership
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetRecordAccessPolicy {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let record_access_policy = ask_forge(rx).await?;
+
+    // The stored entity is needed for the ownership check, as the "before"
+    // side of the audit diff, for the `@immutable` guard, and to refuse
+    // soft-deleted rows; fetch it once if any applies.
+    let observers = write_observers(&state, forge, &schema_def).await;
+    let has_immutable = schema_def.fields.iter().any(|f| f.is_immutable());
+    let existing = if (record_access_policy.is_some() && claims.is_some())
+        || observers.audit_sink.is_some()
+        || has_immutable
+        || schema_def.is_soft_delete()
+    {
+        let (tx, rx) = oneshot::channel();
+        forge
+            .send(GetEntity {
+                schema: schema_name.clone(),
+                id: entity_id.clone(),
+                reply: ReplyChannel::new(tx),
+            })
+            .await;
+        let existing = ask_forge(rx).await?.map_err(ForgeError::from)?;
+        reject_soft_deleted(&schema_def, &existing, false)?;
+        Some(existing)
+    } else {
+        None
+    };
+
+    if let (Some(ref policy), Some(ref c), Some(ref existing)) =
+        (&record_access_policy, &claims, &existing)
+    {
+        if !policy.can_modify(&schema_def, c, existing).await {
+            return Err(ForgeError::Forbidden {
+                message: format!("not authorized to modify entity '{id}'"),
+            });
+        }
+    }
+
+    // Reject any client-supplied @hidden fields up front.
+    reject_hidden_fields_in_body(&schema_def, &body.fields, claims.as_ref())?;
+
+    // Convert JSON fields
+    let mut fields = json_to_entity_fields(&schema_def, &body.fields)
+        .map_err(|errors| ForgeError::ValidationFailed { details: errors })?;
+
+    // before_validate / before_change hooks. `before_validate` runs
+    // first so a hook can mutate or add fields before any
+    // persistence-side validation, then `before_change` runs on the
+    // (possibly mutated) fields.
+    let hooks_config = state.config().custom.schema_forge.hooks.clone();
+    let hook_dispatcher = if hooks_config.enabled && schema_def.has_hooks() {
+        fetch_hook_dispatcher(forge).await
+    } else {
+        None
+    };
+    if let Some(ref dispatcher) = hook_dispatcher {
+        apply_before_hook(
+            BeforeHookCtx {
+                dispatcher: dispatcher.as_ref(),
+                hooks_config: &hooks_config,
+                schema: &schema_def,
+                event: HookEvent::BeforeValidate,
+                operation: "update",
+                user: claims.as_ref(),
+                entity_id: Some(entity_id.as_str().to_string()),
+            },
+            &mut fields,
+        )
+        .await?;
+        apply_before_hook(
+            BeforeHookCtx {
+                dispatcher: dispatcher.as_ref(),
+                hooks_config: &hooks_config,
+                schema: &schema_def,
+                event: HookEvent::BeforeChange,
+                operation: "update",
+                user: claims.as_ref(),
+                entity_id: Some(entity_id.as_str().to_string()),
+            },
+            &mut fields,
+        )
+        .await?;
+    }
+    let entity_hooks = fetch_entity_hooks(forge).await;
+    entity_hooks
+        .before_update(&schema_def, claims.as_ref(), &entity_id, &mut fields)
+        .await?;
+
+    if let Some(ref existing) = existing {
+        reject_immutable_changes(&schema_def, &existing.fields, &fields)?;
+    }
+
+    // Build entity with specific ID, filtering write-restricted fields
+    let mut entity = Entity::with_id(entity_id, schema_name, fields);
+    filter_entity_fields(
+        &policy_store,
+        &mut entity,
+        &schema_def,
+        claims.as_ref(),
+        FieldFilterDirection::Write,
+    );
+
+    // Update entity via actor
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(UpdateEntity {
+            entity,
+            expected_version,
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let mut updated = ask_forge(rx).await?.map_err(ForgeError::from)?;
+    complete_written_entity(
+        forge,
+        &schema_def,
+        &mut updated,
+        claims.as_ref(),
+        ChangeOperation::Updated,
+    )
+    .await?;
+    let audit_changes = existing
+        .as_ref()
+        .map(|e| changed_fields(&schema_def, &e.fields, &updated.fields))
+        .unwrap_or_default();
+
+    // after_change hook — handed off to HookDispatchActor for
+    // detached dispatch under acton supervision.
+    if let Some(dispatcher) = hook_dispatcher.clone() {
+        fire_after_hook(
+            &state,
+            AfterHookCtx {
+                dispatcher,
+                hooks_config: hooks_config.clone(),
+                schema: schema_def.clone(),
+                event: HookEvent::AfterChange,
+                operation: "update".to_string(),
+                user_id: claims.as_ref().map(|c| c.sub.clone()),
+            },
+            &updated,
+        )
+        .await;
+    }
+    entity_hooks.after_update(&schema_def, claims.as_ref(), &updated);
+
+    // Filter read-restricted fields from response
+    filter_entity_fields(
+        &policy_store,
+        &mut updated,
+        &schema_def,
+        claims.as_ref(),
+        FieldFilterDirection::Read,
+    );
+
+    // Audit: entity updated
+    if let Some(logger) = state.audit_logger() {
+        logger
+            .log_custom(
+                "forge.entity.updated",
+                acton_service::audit::AuditSeverity::Informational,
+                Some(serde_json::json!({
+                    "schema": schema,
+                    "entity_id": updated.id.as_str(),
+                    "user": claims.as_ref().map(|c| &c.sub),
+                })),
+            )
+            .await;
+    }
+    observers
+        .notify(CommittedWrite {
+            schema: &schema_def,
+            claims: claims.as_ref(),
+            operation: ChangeOperation::Updated,
+            entity_id: updated.id.as_str(),
+            entity: Some(updated.clone()),
+            changed_fields: audit_changes,
+        })
+        .await;
+
+    Ok((
+        etag_header(&updated),
+        Json(entity_to_response(&updated, &schema_def)),
+    ))
+}
+
+/// PATCH /schemas/{schema}/entities/{id} -- Partially update entity.
+///
+/// Unlike PUT, which has full-replacement semantics and requires every
+/// required field in the payload, PATCH merges the supplied fields onto
+/// the existing entity. Fields omitted from the request body are
+/// preserved unchanged; required-field validation only fails if the
+/// merged result lacks a required value. The changed fields are written
+/// through `PatchEntity` (backed by `EntityStore::patch`), and
+/// `before_change` / `after_change` hooks, webhooks, and audit logging
+/// fire exactly as they do for PUT, and `If-Match` is honored the same way.
+#[instrument(skip_all, fields(schema = %schema))]
+pub async fn patch_entity(
+    State(state): State<AppState<SchemaForgeConfig>>,
+    Path((schema, id)): Path<(String, String)>,
+    OptionalClaims(claims): OptionalClaims,
+    headers: HeaderMap,
+    Json(body): Json<EntityRequest>,
+) -> Result<impl IntoResponse, ForgeError> {
+    let schema_name = validate_schema_name(&schema)?;
+    let expected_version = if_match_version(
+        &headers,
+        state.config().custom.schema_forge.require_if_match,
+    )?;
+    let forge = state
+        .actor::<ForgeActor>()
+        .expect("ForgeActor not registered");
+
+    // Look up schema via actor
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetSchema {
+            name: schema_name.as_str().to_string(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let schema_def = ask_forge(rx).await?.ok_or(ForgeError::SchemaNotFound {
+        name: schema_name.as_str().to_string(),
+    })?;
+
+    // Parse the entity ID against the schema's id prefix
+    let entity_id = schema_def
+        .parse_entity_id(&id)
+        .map_err(|_| ForgeError::InvalidEntityId { id: id.clone() })?;
+
+    let policy_store = fetch_policy_store(&state).await?;
+
+    // Access check
+    if let Err(e) = check_schema_access(
+        &policy_store,
+        &schema_def,
+        claims.as_ref(),
+        AccessAction::Write,
+    ) {
+        if let Some(logger) = state.audit_logger() {
+            logger
+                .log_custom(
+                    "forge.access.denied",
+                    acton_service::audit::AuditSeverity::Warning,
+                    Some(serde_json::json!({
+                        "schema": &schema,
+                        "action": "write",
+                        "user": claims.as_ref().map(|c| &c.sub),
+                    })),
+                )
+                .await;
+        }
+        return Err(e);
+    }
+
+    // Always fetch the existing entity — PATCH needs it both for the
+    // record-level ownership check and for the field merge step below.
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetEntity {
+            schema: schema_name.clone(),
+            id: entity_id.clone(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let existing = ask_forge(rx).await?.map_err(ForgeError::from)?;
+    reject_soft_deleted(&schema_def, &existing, false)?;
+
+    // Record-level ownership check
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetRecordAccessPolicy {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let record_access_policy = ask_forge(rx).await?;
+
+    if let (Some(ref policy), Some(ref c)) = (&record_access_policy, &claims) {
+        if !policy.can_modify(&schema_def, c, &existing).await {
+            return Err(ForgeError::Forbidden {
+                message: format!("not authorized to modify entity '{id}'"),
+            });
+        }
+    }
+
+    // Reject any client-supplied @hidden fields up front.
+    reject_hidden_fields_in_body(&schema_def, &body.fields, claims.as_ref())?;
+
+    // Convert only the fields supplied by the client. Merge mode skips
+    // the required-field check so partial payloads are valid.
+    let patch_fields =
+        json_to_entity_fields_with_mode(&schema_def, &body.fields, ConversionMode::Merge)
+            .map_err(|errors| ForgeError::ValidationFailed { details: errors })?;
+
+    // Merge the patch onto the existing entity's field map so hooks see
+    // the post-patch view of the entity. The merged map is only used to
+    // drive the hook path and to compute the final delta against the
+    // loaded baseline; it is NOT what gets written to the backend.
+    let mut merged = existing.fields.clone();
+    for (k, v) in patch_fields {
+        merged.insert(k, v);
+    }
+
+    // before_change hook — operates on the already-merged field set so
+    // hooks see the post-patch state.
+    let hooks_config = state.config().custom.schema_forge.hooks.clone();
+    let hook_dispatcher = if hooks_config.enabled && schema_def.has_hooks() {
+        fetch_hook_dispatcher(forge).await
+    } else {
+        None
+    };
+    if let Some(ref dispatcher) = hook_dispatcher {
+        apply_before_hook(
+            BeforeHookCtx {
+                dispatcher: dispatcher.as_ref(),
+                hooks_config: &hooks_config,
+                schema: &schema_def,
+                event: HookEvent::BeforeValidate,
+                operation: "patch",
+                user: claims.as_ref(),
+                entity_id: Some(entity_id.as_str().to_string()),
+            },
+            &mut merged,
+        )
+        .await?;
+        apply_before_hook(
+            BeforeHookCtx {
+                dispatcher: dispatcher.as_ref(),
+                hooks_config: &hooks_config,
+                schema: &schema_def,
+                event: HookEvent::BeforeChange,
+                operation: "patch",
+                user: claims.as_ref(),
+                entity_id: Some(entity_id.as_str().to_string()),
+            },
+            &mut merged,
+        )
+        .await?;
+    }
+    let entity_hooks = fetch_entity_hooks(forge).await;
+    entity_hooks
+        .before_update(&schema_def, claims.as_ref(), &entity_id, &mut merged)
+        .await?;
+
+    reject_immutable_changes(&schema_def, &existing.fields, &merged)?;
+
+    let missing = missing_required_fields(&schema_def, &merged);
+    if !missing.is_empty() {
+        return Err(ForgeError::ValidationFailed { details: missing });
+    }
+
+    let observers = write_observers(&state, forge, &schema_def).await;
+    let audit_before = observers
+        .audit_sink
+        .as_ref()
+        .map(|_| existing.fields.clone());
+
+    // Compute the delta: only keys whose final value differs from the
+    // loaded baseline go to the backend. This keeps PATCH's SQL UPDATE
+    // actually partial, which makes the whole class of "null column
+    // being rebound with the wrong type" bugs structurally impossible
+    // (see issue #12). An unchanged field never hits bind_dynamic_value.
+    let mut delta = std::collections::BTreeMap::new();
+    for (k, v) in &merged {
+        match existing.fields.get(k) {
+            Some(existing_v) if existing_v == v => {}
+            _ => {
+                delta.insert(k.clone(), v.clone());
+            }
+        }
+    }
+
+    // Empty delta: nothing to write (the patch body was a no-op after
+    // merge, e.g. every patched field already held the requested value,
+    // or a before_change hook reverted the changes). Skip the backend
+    // round-trip and return the existing entity as-is — provided it is
+    // still at the version the client expected.
+    let mut updated = if delta.is_empty() {
+        existing
+            .expect_version(expected_version)
+            .map_err(ForgeError::from)?
+    } else {
+        // @field_access write checks apply to every changed field.
+        let mut entity = Entity::with_id(entity_id, schema_name, delta);
+        filter_entity_fields(
+            &policy_store,
+            &mut entity,
+            &schema_def,
+            claims.as_ref(),
+            FieldFilterDirection::Write,
+        );
+        let (tx, rx) = oneshot::channel();
+        forge
+            .send(PatchEntity {
+                schema: entity.schema,
+                id: entity.id,
+                changes: entity.fields,
+                expected_version,
+                reply: ReplyChannel::new(tx),
+            })
+            .await;
+        ask_forge(rx).await?.map_err(ForgeError::from)?
+    };
+    complete_written_entity(
+        forge,
+        &schema_def,
+        &mut updated,
+        claims.as_ref(),
+        ChangeOperation::Updated,
+    )
+    .await?;
+    let audit_changes = audit_before
+        .map(|before| changed_fields(&schema_def, &before, &updated.fields))
+        .unwrap_or_default();
+
+    // after_change hook — dispatched via HookDispatchActor
+    if let Some(dispatcher) = hook_dispatcher.clone() {
+        fire_after_hook(
+            &state,
+            AfterHookCtx {
+                dispatcher,
+                hooks_config: hooks_config.clone(),
+                schema: schema_def.clone(),
+                event: HookEvent::AfterChange,
+                operation: "patch".to_string(),
+                user_id: claims.as_ref().map(|c| c.sub.clone()),
+            },
+            &updated,
+        )
+        .await;
+    }
+    entity_hooks.after_update(&schema_def, claims.as_ref(), &updated);
+
+    // Filter read-restricted fields from response
+    filter_entity_fields(
+        &policy_store,
+        &mut updated,
+        &schema_def,
+        claims.as_ref(),
+        FieldFilterDirection::Read,
+    );
+
+    // Audit: entity patched
+    if let Some(logger) = state.audit_logger() {
+        logger
+            .log_custom(
+                "forge.entity.patched",
+                acton_service::audit::AuditSeverity::Informational,
+                Some(serde_json::json!({
+                    "schema": schema,
+                    "entity_id": updated.id.as_str(),
+                    "user": claims.as_ref().map(|c| &c.sub),
+                })),
+            )
+            .await;
+    }
+    observers
+        .notify(CommittedWrite {
+            schema: &schema_def,
+            claims: claims.as_ref(),
+            operation: ChangeOperation::Updated,
+            entity_id: updated.id.as_str(),
+            entity: Some(updated.clone()),
+            changed_fields: audit_changes,
+        })
+        .await;
+
+    Ok((
+        etag_header(&updated),
+        Json(entity_to_response(&updated, &schema_def)),
+    ))
+}
+
+/// DELETE /schemas/{schema}/entities/{id} -- Delete entity.
+#[instrument(skip_all, fields(schema = %schema))]
+pub async fn delete_entity(
+    State(state): State<AppState<SchemaForgeConfig>>,
+    Path((schema, id)): Path<(String, String)>,
+    OptionalClaims(claims): OptionalClaims,
+) -> Result<impl IntoResponse, ForgeError> {
+    let schema_name = validate_schema_name(&schema)?;
+    let forge = state
+        .actor::<ForgeActor>()
+        .expect("ForgeActor not registered");
+
+    // Look up schema via actor
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetSchema {
+            name: schema_name.as_str().to_string(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let schema_def = ask_forge(rx).await?.ok_or(ForgeError::SchemaNotFound {
+        name: schema_name.as_str().to_string(),
+    })?;
+
+    let policy_store = fetch_policy_store(&state).await?;
+
+    // Access check
+    if let Err(e) = check_schema_access(
+        &policy_store,
+        &schema_def,
+        claims.as_ref(),
+        AccessAction::Delete,
+    ) {
+        if let Some(logger) = state.audit_logger() {
+            logger
+                .log_custom(
+                    "forge.access.denied",
+                    acton_service::audit::AuditSeverity::Warning,
+                    Some(serde_json::json!({
+                        "schema": &schema,
+                        "action": "delete",
+                        "user": claims.as_ref().map(|c| &c.sub),
+                    })),
+                )
+                .await;
+        }
+        return Err(e);
+    }
+
+    let entity_id = schema_def
+        .parse_entity_id(&id)
+        .map_err(|_| ForgeError::InvalidEntityId { id: id.clone() })?;
+
+    // Record-level ownership check: fetch entity first and verify ownership
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetRecordAccessPolicy {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let record_access_policy = ask_forge(rx).await?;
+
+    if let (Some(ref policy), Some(ref c)) = (&record_access_policy, &claims) {
+        let (tx, rx) = oneshot::channel();
+        forge
+            .send(GetEntity {
+                schema: schema_name.clone(),
+                id: entity_id.clone(),
+                reply: ReplyChannel::new(tx),
+            })
+            .await;
+        let entity = ask_forge(rx).await?.map_err(ForgeError::from)?;
+        if !policy.can_delete(&schema_def, c, &entity).await {
+            return Err(ForgeError::Forbidden {
+                message: format!("not authorized to delete entity '{id}'"),
+            });
+        }
+    }
+
+    // before_delete / after_delete hook setup. Fetch entity snapshot when a
+    // hook is configured so the dispatcher sees the fields being deleted.
+    let hooks_config = state.config().custom.schema_forge.hooks.clone();
+    let hook_dispatcher = if hooks_config.enabled && schema_def.has_hooks() {
+        fetch_hook_dispatcher(forge).await
+    } else {
+        None
+    };
+    let mut pre_delete_snapshot: Option<Entity> = None;
+    if hook_dispatcher.is_some()
+        && (hooks_config
+            .binding_for(schema_def.name.as_str(), HookEvent::BeforeDelete)
+            .is_some()
+            || hooks_config
+                .binding_for(schema_def.name.as_str(), HookEvent::AfterDelete)
+                .is_some())
+    {
+        let (tx, rx) = oneshot::channel();
+        forge
+            .send(GetEntity {
+                schema: schema_name.clone(),
+                id: entity_id.clone(),
+                reply: ReplyChannel::new(tx),
+            })
+            .await;
+        if let Ok(Ok(e)) = ask_forge(rx).await {
+            pre_delete_snapshot = Some(e);
+        }
+    }
+    if let (Some(ref dispatcher), Some(snapshot)) = (&hook_dispatcher, &pre_delete_snapshot) {
+        let mut fields = snapshot.fields.clone();
+        apply_before_hook(
+            BeforeHookCtx {
+                dispatcher: dispatcher.as_ref(),
+                hooks_config: &hooks_config,
+                schema: &schema_def,
+                event: HookEvent::BeforeDelete,
+                operation: "delete",
+                user: claims.as_ref(),
+                entity_id: Some(entity_id.as_str().to_string()),
+            },
+            &mut fields,
+        )
+        .await?;
+    }
+    let entity_hooks = fetch_entity_hooks(forge).await;
+    entity_hooks
+        .before_delete(&schema_def, claims.as_ref(), &entity_id)
+        .await?;
+
+    // Event-stream subscribers are scoped by tenant and record access, so
+    // they need the entity's last state. Only fetch it when someone is
+    // listening and the hook path did not already.
+    let change_snapshot = match pre_delete_snapshot.clone() {
+        Some(snapshot) => Some(snapshot),
+        None if fetch_change_feed(forge).await?.has_subscribers() => {
+            let (tx, rx) = oneshot::channel();
+            forge
+                .send(GetEntity {
+                    schema: schema_name.clone(),
+                    id: entity_id.clone(),
+                    reply: ReplyChannel::new(tx),
+                })
+                .await;
+            ask_forge(rx).await?.ok()
+        }
+        None => None,
+    };
+
+    // Delete entity via actor
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(DeleteEntity {
+            schema: schema_name,
+            id: entity_id.clone(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    ask_forge(rx).await?.map_err(ForgeError::from)?;
+    entity_hooks.after_delete(&schema_def, claims.as_ref(), &entity_id);
+
+    // after_delete hook — handed off to HookDispatchActor for
+    // detached dispatch under acton supervision.
+    if let (Some(dispatcher), Some(snapshot)) = (hook_dispatcher.clone(), pre_delete_snapshot) {
+        fire_after_hook(
+            &state,
+            AfterHookCtx {
+                dispatcher,
+                hooks_config: hooks_config.clone(),
+                schema: schema_def.clone(),
+                event: HookEvent::AfterDelete,
+                operation: "delete".to_string(),
+                user_id: claims.as_ref().map(|c| c.sub.clone()),
+            },
+            &snapshot,
+        )
+        .await;
+    }
+
+    // Audit: entity deleted
+    if let Some(logger) = state.audit_logger() {
+        logger
+            .log_custom(
+                "forge.entity.deleted",
+                acton_service::audit::AuditSeverity::Warning,
+                Some(serde_json::json!({
+                    "schema": schema,
+                    "entity_id": id,
+                    "user": claims.as_ref().map(|c| &c.sub),
+                })),
+            )
+            .await;
+    }
+    write_observers(&state, forge, &schema_def)
+        .await
+        .notify(CommittedWrite {
+            schema: &schema_def,
+            claims: claims.as_ref(),
+            operation: ChangeOperation::Deleted,
+            entity_id: &id,
+            entity: change_snapshot,
+            changed_fields: Vec::new(),
+        })
+        .await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Whether `filter` matches every entity: an `and` of nothing, as the
+/// flat shorthand `{}` produces.
+fn is_unconstrained(filter: &Filter) -> bool {
+    matches!(filter, Filter::And { filters } if filters.iter().all(is_unconstrained))
+}
+
+/// POST /schemas/{schema}/entities/delete -- Delete every entity matching
+/// a filter.
+///
+/// The body carries a `filter` in the same format as the query endpoint.
+/// A missing or empty filter is refused unless `?all=true` is set, so a
+/// malformed request cannot empty the table. The match set is tenant
+/// scoped, and every matched entity must pass the record-level delete
+/// check (e.g. `@owner`); one denial fails the whole request and nothing
+/// is deleted. `?dry_run=true` runs every check and reports the match
+/// count without deleting. Hooks, audit, change events, and webhooks fire
+/// per entity as for a single delete.
+#[instrument(skip_all, fields(schema = %schema))]
+pub async fn bulk_delete_entities(
+    State(state): State<AppState<SchemaForgeConfig>>,
+    Path(schema): Path<String>,
+    OptionalClaims(claims): OptionalClaims,
+    Query(params): Query<HashMap<String, String>>,
+    Json(body): Json<BulkDeleteBody>,
+) -> Result<impl IntoResponse, ForgeError> {
+    let schema_name = validate_schema_name(&schema)?;
+    let forge = state
+        .actor::<ForgeActor>()
+        .expect("ForgeActor not registered");
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetSchema {
+            name: schema_name.as_str().to_string(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let schema_def = ask_forge(rx).await?.ok_or(ForgeError::SchemaNotFound {
+        name: schema_name.as_str().to_string(),
+    })?;
+
+    let policy_store = fetch_policy_store(&state).await?;
+    if let Err(e) = check_schema_access(
+        &policy_store,
+        &schema_def,
+        claims.as_ref(),
+        AccessAction::Delete,
+    ) {
+        if let Some(logger) = state.audit_logger() {
+            logger
+                .log_custom(
+                    "forge.access.denied",
+                    acton_service::audit::AuditSeverity::Warning,
+                    Some(serde_json::json!({
+                        "schema": &schema,
+                        "action": "delete",
+                        "user": claims.as_ref().map(|c| &c.sub),
+                    })),
+                )
+                .await;
+        }
+        return Err(e);
+    }
+
+    let dry_run = parse_opt_in_flag(&params, "dry_run");
+    let filter = match &body.filter {
+        Some(filter_json) => {
+            let filter = parse_body_filter(
+                forge,
+                filter_json,
+                &schema_def,
+                &policy_store,
+                claims.as_ref(),
+            )
+            .await?;
+            Some(filter).filter(|f| !is_unconstrained(f))
+        }
+        None => None,
+    };
+    if filter.is_none() && !parse_opt_in_flag(&params, "all") {
+        return Err(ForgeError::InvalidQuery {
+            message: "bulk delete requires a non-empty filter; set all=true to delete every entity"
+                .into(),
+        });
+    }
+
+    let mut query = schema_forge_core::query::Query::new(schema_def.id.clone());
+    if let Some(filter) = filter {
+        query = query.with_filter(filter);
+    }
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetTenantConfig {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let tenant_config = ask_forge(rx).await?;
+    inject_tenant_scope(&mut query, claims.as_ref(), &tenant_config);
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetRecordAccessPolicy {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let record_access_policy = ask_forge(rx).await?;
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetBackend {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let backend = ask_forge(rx).await?.ok_or(ForgeError::BackendUnavailable {
+        message: "no backend configured".into(),
+    })?;
+
+    // Hooks and event-stream subscribers need each entity's last state;
+    // otherwise only the ids are kept.
+    let hooks_config = state.config().custom.schema_forge.hooks.clone();
+    let hook_dispatcher = if !dry_run && hooks_config.enabled && schema_def.has_hooks() {
+        fetch_hook_dispatcher(forge).await
+    } else {
+        None
+    };
+    let keep_snapshots = !dry_run
+        && (hook_dispatcher.is_some() || fetch_change_feed(forge).await?.has_subscribers());
+
+    let mut matched: Vec<(EntityId, Option<Entity>)> = Vec::new();
+    let mut stream = backend.query_stream(&query);
+    while let Some(entity) = stream.next().await {
+        let entity = entity.map_err(ForgeError::from)?;
+        if let (Some(policy), Some(c)) = (&record_access_policy, &claims) {
+            if !policy.can_delete(&schema_def, c, &entity).await {
+                return Err(ForgeError::Forbidden {
+                    message: format!("not authorized to delete entity '{}'", entity.id.as_str()),
+                });
+            }
+        }
+        let snapshot = keep_snapshots.then(|| entity.clone());
+        matched.push((entity.id, snapshot));
+    }
+    drop(stream);
+
+    if dry_run || matched.is_empty() {
+        return Ok(Json(BulkDeleteResponse {
+            matched: matched.len(),
+            deleted: 0,
+            dry_run,
+        }));
+    }
+
+    let entity_hooks = fetch_entity_hooks(forge).await;
+    for (entity_id, snapshot) in &matched {
+        if let (Some(dispatcher), Some(snapshot)) = (&hook_dispatcher, snapshot) {
+            let mut fields = snapshot.fields.clone();
+            apply_before_hook(
+                BeforeHookCtx {
+                    dispatcher: dispatcher.as_ref(),
+                    hooks_config: &hooks_config,
+                    schema: &schema_def,
+                    event: HookEvent::BeforeDelete,
+                    operation: "delete",
+                    user: claims.as_ref(),
+                    entity_id: Some(entity_id.as_str().to_string()),
+                },
+                &mut fields,
+            )
+            .await?;
+        }
+        entity_hooks
+            .before_delete(&schema_def, claims.as_ref(), entity_id)
+            .await?;
+    }
+
+    // Only the checked ids are deleted, so rows that started matching
+    // after the scan are left alone. Chunked to bound the IN list.
+    let mut deleted = 0;
+    for chunk in matched.chunks(schema_forge_backend::stream::STREAM_BATCH_SIZE) {
+        let ids = chunk
+            .iter()
+            .map(|(id, _)| DynamicValue::Text(id.as_str().to_string()))
+            .collect();
+        let id_filter = Filter::in_set(FieldPath::single("id"), ids);
+        let scoped = match &query.filter {
+            Some(filter) => Filter::and(vec![filter.clone(), id_filter]),
+            None => id_filter,
+        };
+        let delete_query =
+            schema_forge_core::query::Query::new(schema_def.id.clone()).with_filter(scoped);
+        deleted += backend
+            .delete_by_query(&delete_query)
+            .await
+            .map_err(ForgeError::from)?;
+    }
+
+    if let Some(logger) = state.audit_logger() {
+        logger
+            .log_custom(
+                "forge.entity.bulk_deleted",
+                acton_service::audit::AuditSeverity::Warning,
+                Some(serde_json::json!({
+                    "schema": schema,
+                    "deleted": deleted,
+                    "user": claims.as_ref().map(|c| &c.sub),
+                })),
+            )
+            .await;
+    }
+    let matched_count = matched.len();
+    let observers = write_observers(&state, forge, &schema_def).await;
+    for (entity_id, snapshot) in matched {
+        let id = entity_id.as_str().to_string();
+        entity_hooks.after_delete(&schema_def, claims.as_ref(), &entity_id);
+        if let (Some(dispatcher), Some(snapshot)) = (hook_dispatcher.clone(), &snapshot) {
+            fire_after_hook(
+                &state,
+                AfterHookCtx {
+                    dispatcher,
+                    hooks_config: hooks_config.clone(),
+                    schema: schema_def.clone(),
+                    event: HookEvent::AfterDelete,
+                    operation: "delete".to_string(),
+                    user_id: claims.as_ref().map(|c| c.sub.clone()),
+                },
+                snapshot,
+            )
+            .await;
+        }
+        observers
+            .notify(CommittedWrite {
+                schema: &schema_def,
+                claims: claims.as_ref(),
+                operation: ChangeOperation::Deleted,
+                entity_id: &id,
+                entity: snapshot,
+                changed_fields: Vec::new(),
+            })
+            .await;
+    }
+
+    Ok(Json(BulkDeleteResponse {
+        matched: matched_count,
+        deleted,
+        dry_run,
+    }))
+}
+
+/// POST /schemas/{schema}/entities/{id}/restore -- Undo a soft delete.
+///
+/// Only valid for `@soft_delete` schemas. Requires update access, since
+/// restoring brings the record back into every listing.
+#[instrument(skip_all, fields(schema = %schema))]
+pub async fn restore_entity(
+    State(state): State<AppState<SchemaForgeConfig>>,
+    Path((schema, id)): Path<(String, String)>,
+    OptionalClaims(claims): OptionalClaims,
+) -> Result<impl IntoResponse, ForgeError> {
+    let schema_name = validate_schema_name(&schema)?;
+    let forge = state
+        .actor::<ForgeActor>()
+        .expect("ForgeActor not registered");
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetSchema {
+            name: schema_name.as_str().to_string(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let schema_def = ask_forge(rx).await?.ok_or(ForgeError::SchemaNotFound {
+        name: schema_name.as_str().to_string(),
+    })?;
+
+    let policy_store = fetch_policy_store(&state).await?;
+    check_schema_access(
+        &policy_store,
+        &schema_def,
+        claims.as_ref(),
+        AccessAction::Update,
+    )?;
+
+    if !schema_def.is_soft_delete() {
+        return Err(ForgeError::InvalidQuery {
+            message: format!("schema '{schema}' does not use @soft_delete"),
+        });
+    }
+
+    let entity_id = schema_def
+        .parse_entity_id(&id)
+        .map_err(|_| ForgeError::InvalidEntityId { id: id.clone() })?;
+
+    // Record-level ownership check against the deleted record.
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetRecordAccessPolicy {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let record_access_policy = ask_forge(rx).await?;
+
+    if let (Some(ref policy), Some(ref c)) = (&record_access_policy, &claims) {
+        let (tx, rx) = oneshot::channel();
+        forge
+            .send(GetEntity {
+                schema: schema_name.clone(),
+                id: entity_id.clone(),
+                reply: ReplyChannel::new(tx),
+            })
+            .await;
+        let entity = ask_forge(rx).await?.map_err(ForgeError::from)?;
+        if !policy.can_modify(&schema_def, c, &entity).await {
+            return Err(ForgeError::Forbidden {
+                message: format!("not authorized to restore entity '{id}'"),
+            });
+        }
+    }
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(RestoreEntity {
+            schema: schema_name,
+            id: entity_id,
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let mut restored = ask_forge(rx).await?.map_err(ForgeError::from)?;
+
+    write_observers(&state, forge, &schema_def)
+        .await
+        .notify(CommittedWrite {
+            schema: &schema_def,
+            claims: claims.as_ref(),
+            operation: ChangeOperation::Updated,
+            entity_id: &id,
+            entity: Some(restored.clone()),
+            changed_fields: vec![DELETED_AT_FIELD.to_string()],
+        })
+        .await;
+
+    filter_entity_fields(
+        &policy_store,
+        &mut restored,
+        &schema_def,
+        claims.as_ref(),
+        FieldFilterDirection::Read,
+    );
+    Ok((
+        etag_header(&restored),
+        Json(entity_to_response(&restored, &schema_def)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schema_forge_core::types::{
+        Annotation, Cardinality, FieldAnnotation, FieldDefinition, FieldModifier, FieldName,
+        SchemaId, TextConstraints,
+    };
+
+    fn make_test_schema() -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![
+                FieldDefinition::with_modifiers(
+                    FieldName::new("name").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                    vec![FieldModifier::Required],
+                ),
+                FieldDefinition::new(
+                    FieldName::new("age").unwrap(),
+                    FieldType::Integer(
+                        schema_forge_core::types::IntegerConstraints::unconstrained(),
+                    ),
+                ),
+                FieldDefinition::new(FieldName::new("active").unwrap(), FieldType::Boolean),
+            ],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn json_to_entity_fields_basic() {
+        let schema = make_test_schema();
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert("name".into(), serde_json::json!("Alice"));
+        json_fields.insert("age".into(), serde_json::json!(30));
+        json_fields.insert("active".into(), serde_json::json!(true));
+
+        let result = json_to_entity_fields(&schema, &json_fields).unwrap();
+        assert_eq!(
+            result.get("name"),
+            Some(&DynamicValue::Text("Alice".into()))
+        );
+        assert_eq!(result.get("age"), Some(&DynamicValue::Integer(30)));
+        assert_eq!(result.get("active"), Some(&DynamicValue::Boolean(true)));
+    }
+
+    #[test]
+    fn storage_projection_adds_sort_fields_in_schema_order() {
+        let schema = make_test_schema();
+        let requested: HashSet<String> = ["active".to_string()].into();
+        let query = schema_forge_core::query::Query::new(schema.id.clone())
+            .with_sort(FieldPath::single("name"), SortOrder::Ascending);
+        assert_eq!(
+            storage_projection(&requested, &schema, &query),
+            vec!["name".to_string(), "active".to_string()]
+        );
+    }
+
+    fn make_computed_schema() -> SchemaDefinition {
+        let computed = |name: &str, field_type: FieldType, expr: &str| {
+            FieldDefinition::with_annotations(
+                FieldName::new(name).unwrap(),
+                field_type,
+                vec![FieldModifier::Required],
+                vec![FieldAnnotation::Computed {
+                    expr: schema_forge_core::types::ComputedExpr::new(expr).unwrap(),
+                }],
+            )
+        };
+        let mut schema = make_test_schema();
+        schema.fields.push(computed(
+            "label",
+            FieldType::Text(TextConstraints::unconstrained()),
+            "upper(name)",
+        ));
+        schema.fields.push(computed(
+            "badge",
+            FieldType::Text(TextConstraints::unconstrained()),
+            "label + ' ' + age",
+        ));
+        schema
+    }
+
+    #[test]
+    fn storage_projection_reads_the_inputs_of_computed_fields() {
+        let schema = make_computed_schema();
+        let requested: HashSet<String> = ["badge".to_string()].into();
+        let query = schema_forge_core::query::Query::new(schema.id.clone());
+        assert_eq!(
+            storage_projection(&requested, &schema, &query),
+            vec!["name".to_string(), "age".to_string()]
+        );
+    }
+
+    #[test]
+    fn json_to_entity_fields_rejects_computed_fields() {
+        let schema = make_computed_schema();
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert("name".into(), serde_json::json!("Alice"));
+        json_fields.insert("label".into(), serde_json::json!("ALICE"));
+
+        let errors = json_to_entity_fields(&schema, &json_fields).unwrap_err();
+        assert_eq!(
+            errors,
+            vec!["field 'label': is computed from other fields and cannot be written".to_string()]
+        );
+
+        let mut fields = BTreeMap::new();
+        fields.insert("name".to_string(), DynamicValue::Text("Alice".into()));
+        assert!(missing_required_fields(&schema, &fields).is_empty());
+    }
+
+    #[test]
+    fn json_to_entity_fields_missing_required() {
+        let schema = make_test_schema();
+        let json_fields = serde_json::Map::new(); // missing "name" which is required
+
+        let result = json_to_entity_fields(&schema, &json_fields);
+        assert!(result.is_err());
+        let errors = result.unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("required") && e.contains("name")));
+    }
+
+    #[test]
+    fn missing_required_fields_checks_merged_values() {
+        let schema = make_test_schema();
+        let mut fields = BTreeMap::new();
+        fields.insert("name".to_string(), DynamicValue::Text("Alice".into()));
+        assert!(missing_required_fields(&schema, &fields).is_empty());
+
+        fields.insert("name".to_string(), DynamicValue::Null);
+        assert_eq!(
+            missing_required_fields(&schema, &fields),
+            vec!["required field 'name' is missing".to_string()]
+        );
+    }
+
+    fn make_empty_as_null_schema(annotations: Vec<Annotation>) -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![
+                FieldDefinition::with_modifiers(
+                    FieldName::new("name").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                    vec![FieldModifier::Required],
+                ),
+                FieldDefinition::with_annotations(
+                    FieldName::new("nickname").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                    vec![],
+                    vec![FieldAnnotation::EmptyAsNull],
+                ),
+            ],
+            annotations,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn json_to_entity_fields_empty_as_null_coerces_blank_text() {
+        let schema = make_empty_as_null_schema(vec![]);
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert("name".into(), serde_json::json!(""));
+        json_fields.insert("nickname".into(), serde_json::json!(""));
+
+        let result = json_to_entity_fields(&schema, &json_fields).unwrap();
+        assert_eq!(result.get("name"), Some(&DynamicValue::Text(String::new())));
+        assert_eq!(result.get("nickname"), Some(&DynamicValue::Null));
+    }
+
+    #[test]
+    fn json_to_entity_fields_empty_as_null_fails_required() {
+        let schema = make_empty_as_null_schema(vec![Annotation::EmptyAsNull]);
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert("name".into(), serde_json::json!(""));
+
+        for mode in [ConversionMode::Replace, ConversionMode::Merge] {
+            let errors = json_to_entity_fields_with_mode(&schema, &json_fields, mode).unwrap_err();
+            assert_eq!(
+                errors,
+                vec!["required field 'name' must not be empty".to_string()]
+            );
+        }
+
+        json_fields.insert("name".into(), serde_json::json!("Alice"));
+        let result = json_to_entity_fields(&schema, &json_fields).unwrap();
+        assert_eq!(
+            result.get("name"),
+            Some(&DynamicValue::Text("Alice".into()))
+        );
+    }
+
+    fn make_immutable_schema() -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Account").unwrap(),
+            vec![
+                FieldDefinition::with_annotations(
+                    FieldName::new("sku").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                    vec![],
+                    vec![FieldAnnotation::Immutable],
+                ),
+                FieldDefinition::new(
+                    FieldName::new("name").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                ),
+            ],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    fn text_fields(pairs: &[(&str, &str)]) -> BTreeMap<String, DynamicValue> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), DynamicValue::Text(v.to_string())))
+            .collect()
+    }
+
+    #[test]
+    fn immutable_field_can_be_set_when_unset() {
+        let schema = make_immutable_schema();
+        let incoming = text_fields(&[("sku", "A-1")]);
+
+        assert!(reject_immutable_changes(&schema, &BTreeMap::new(), &incoming).is_ok());
+        let mut existing = BTreeMap::new();
+        existing.insert("sku".to_string(), DynamicValue::Null);
+        assert!(reject_immutable_changes(&schema, &existing, &incoming).is_ok());
+    }
+
+    #[test]
+    fn immutable_field_change_is_rejected() {
+        let schema = make_immutable_schema();
+        let existing = text_fields(&[("sku", "A-1"), ("name", "Old")]);
+        let incoming = text_fields(&[("sku", "B-2"), ("name", "New")]);
+
+        let err = reject_immutable_changes(&schema, &existing, &incoming).unwrap_err();
+        match err {
+            ForgeError::ValidationFailed { details } => assert_eq!(
+                details,
+                vec!["field 'sku' is immutable and cannot be changed".to_string()]
+            ),
+            other => panic!("expected ValidationFailed, got {other:?}"),
+        }
+
+        let cleared: BTreeMap<String, DynamicValue> =
+            [("sku".to_string(), DynamicValue::Null)].into();
+        assert!(reject_immutable_changes(&schema, &existing, &cleared).is_err());
+    }
+
+    #[test]
+    fn immutable_field_resend_same_value_is_allowed() {
+        let schema = make_immutable_schema();
+        let existing = text_fields(&[("sku", "A-1"), ("name", "Old")]);
+
+        let same = text_fields(&[("sku", "A-1"), ("name", "New")]);
+        assert!(reject_immutable_changes(&schema, &existing, &same).is_ok());
+        let omitted = text_fields(&[("name", "New")]);
+        assert!(reject_immutable_changes(&schema, &existing, &omitted).is_ok());
+    }
+
+    fn make_article_schema(body: RichTextConstraints) -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Article").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("body").unwrap(),
+                FieldType::RichText(body),
+            )],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn json_to_entity_fields_sanitizes_rich_text() {
+        let schema = make_article_schema(RichTextConstraints {
+            sanitize: true,
+            ..RichTextConstraints::unconstrained()
+        });
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert(
+            "body".into(),
+            serde_json::json!("<p onclick=\"steal()\">Hi<script>alert(1)</script></p>"),
+        );
+
+        let result = json_to_entity_fields(&schema, &json_fields).unwrap();
+        assert_eq!(
+            result.get("body"),
+            Some(&DynamicValue::Text("<p>Hi</p>".into()))
+        );
+    }
+
+    #[test]
+    fn json_to_entity_fields_rich_text_allowlist_and_opt_out() {
+        let schema = make_article_schema(RichTextConstraints {
+            sanitize: true,
+            allowed_tags: Some(vec!["em".into()]),
+            ..RichTextConstraints::unconstrained()
+        });
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert("body".into(), serde_json::json!("<p><em>Hi</em></p>"));
+        let result = json_to_entity_fields(&schema, &json_fields).unwrap();
+        assert_eq!(
+            result.get("body"),
+            Some(&DynamicValue::Text("<em>Hi</em>".into()))
+        );
+
+        let raw = "<p>Hi<script>alert(1)</script></p>";
+        let schema = make_article_schema(RichTextConstraints::unconstrained());
+        json_fields.insert("body".into(), serde_json::json!(raw));
+        let result = json_to_entity_fields(&schema, &json_fields).unwrap();
+        assert_eq!(result.get("body"), Some(&DynamicValue::Text(raw.into())));
+    }
+
+    #[test]
+    fn json_to_entity_fields_rejects_over_length_rich_text() {
+        let schema = make_article_schema(RichTextConstraints::with_max_plaintext_length(5));
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert(
+            "body".into(),
+            serde_json::json!("<p><strong>Hello</strong></p>"),
+        );
+        assert!(json_to_entity_fields(&schema, &json_fields).is_ok());
+
+        json_fields.insert("body".into(), serde_json::json!("<p>Hello, world</p>"));
+        let errors = json_to_entity_fields(&schema, &json_fields).unwrap_err();
+        assert_eq!(
+            errors,
+            vec!["field 'body': expected at most 5 characters of text, got 12".to_string()]
+        );
+    }
+
+    #[test]
+    fn json_to_entity_fields_checks_text_pattern() {
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Product").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("sku").unwrap(),
+                FieldType::Text(
+                    TextConstraints::with_max_length(20).and_pattern(r"^[A-Z]{3}-\d{4}$"),
+                ),
+            )],
+            vec![],
+        )
+        .unwrap();
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert("sku".into(), serde_json::json!("ABC-1234"));
+        assert!(json_to_entity_fields(&schema, &json_fields).is_ok());
+
+        json_fields.insert("sku".into(), serde_json::json!("abc-12"));
+        let errors = json_to_entity_fields(&schema, &json_fields).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                r"field 'sku': value 'abc-12' does not match pattern '^[A-Z]{3}-\d{4}$'"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn json_to_entity_fields_type_mismatch() {
+        let schema = make_test_schema();
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert("name".into(), serde_json::json!("Alice"));
+        json_fields.insert("age".into(), serde_json::json!("not a number"));
+
+        let result = json_to_entity_fields(&schema, &json_fields);
+        assert!(result.is_err());
+    }
+
+    fn make_address_schema() -> SchemaDefinition {
+        let address = FieldType::Composite(vec![
+            FieldDefinition::with_modifiers(
+                FieldName::new("street").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![FieldModifier::Required],
+            ),
+            FieldDefinition::with_modifiers(
+                FieldName::new("zip").unwrap(),
+                FieldType::Integer(schema_forge_core::types::IntegerConstraints::unconstrained()),
+                vec![FieldModifier::Required],
+            ),
+        ]);
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Customer").unwrap(),
+            vec![
+                FieldDefinition::new(FieldName::new("address").unwrap(), address.clone()),
+                FieldDefinition::new(
+                    FieldName::new("previous").unwrap(),
+                    FieldType::array(address),
+                ),
+            ],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn json_to_entity_fields_composite_converts_sub_fields() {
+        let schema = make_address_schema();
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert(
+            "address".into(),
+            serde_json::json!({"street": "1 Main St", "zip": 12345}),
+        );
+
+        let result = json_to_entity_fields(&schema, &json_fields).unwrap();
+        let Some(DynamicValue::Composite(address)) = result.get("address") else {
+            panic!("expected composite, got {:?}", result.get("address"));
+        };
+        assert_eq!(address.get("zip"), Some(&DynamicValue::Integer(12345)));
+    }
+
+    #[test]
+    fn json_to_entity_fields_composite_missing_required_sub_field() {
+        let schema = make_address_schema();
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert("address".into(), serde_json::json!({"street": "1 Main St"}));
+
+        let errors = json_to_entity_fields(&schema, &json_fields).unwrap_err();
+        assert_eq!(
+            errors,
+            vec!["required field 'address.zip' is missing".to_string()]
+        );
+
+        // PATCH replaces the whole composite, so the check still applies.
+        let errors = json_to_entity_fields_with_mode(&schema, &json_fields, ConversionMode::Merge)
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            vec!["required field 'address.zip' is missing".to_string()]
+        );
     }
 
-    // Delete entity via actor
-    let (tx, rx) = oneshot::channel();
-    forge
-        .send(DeleteEntity {
-            schema: schema_name,
-            id: entity_id,
-            reply: ReplyChannel::new(tx),
-        })
-        .await;
-    ask_forge(rx).await?.map_err(ForgeError::from)?;
+    #[test]
+    fn json_to_entity_fields_composite_wrong_typed_sub_field() {
+        let schema = make_address_schema();
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert(
+            "address".into(),
+            serde_json::json!({"street": "1 Main St", "zip": "abc"}),
+        );
 
-    // after_delete hook — handed off to HookDispatchActor for
-    // detached dispatch under acton supervision.
-    if let (Some(dispatcher), Some(snapshot)) = (hook_dispatcher.clone(), pre_delete_snapshot) {
-        fire_after_hook(
-            &state,
-            AfterHookCtx {
-                dispatcher,
-                hooks_config: hooks_config.clone(),
-                schema: schema_def.clone(),
-                event: HookEvent::AfterDelete,
-                operation: "delete".to_string(),
-                user_id: claims.as_ref().map(|c| c.sub.clone()),
-            },
-            &snapshot,
-        )
-        .await;
+        let errors = json_to_entity_fields(&schema, &json_fields).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(
+            errors[0].starts_with("field 'address.zip': expected integer"),
+            "{errors:?}"
+        );
     }
 
-    // Audit: entity deleted
-    if let Some(logger) = state.audit_logger() {
-        logger
-            .log_custom(
-                "forge.entity.deleted",
-                acton_service::audit::AuditSeverity::Warning,
-                Some(serde_json::json!({
-                    "schema": schema,
-                    "entity_id": id,
-                    "user": claims.as_ref().map(|c| &c.sub),
-                })),
-            )
-            .await;
-    }
+    #[test]
+    fn json_to_entity_fields_composite_array_uses_indexed_paths() {
+        let schema = make_address_schema();
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert(
+            "previous".into(),
+            serde_json::json!([
+                {"street": "1 Main St", "zip": 12345},
+                {"zip": true},
+            ]),
+        );
 
-    // Webhook: fire notifications
-    let webhook_event = crate::webhook::WebhookEvent::from_delete(
-        &schema,
-        &id,
-        claims.as_ref().map(|c| c.sub.as_str()),
-    );
-    dispatch_webhook(&state, &schema_def, webhook_event, "deleted").await;
+        let errors = json_to_entity_fields(&schema, &json_fields).unwrap_err();
+        assert!(
+            errors
+                .iter()
+                .any(|e| e.starts_with("field 'previous[1].zip': expected integer")),
+            "{errors:?}"
+        );
+        assert!(
+            errors.contains(&"required field 'previous[1].street' is missing".to_string()),
+            "{errors:?}"
+        );
+    }
 
-    Ok(StatusCode::NO_CONTENT)
-}
+    #[test]
+    fn json_to_entity_fields_composite_rejects_non_object() {
+        let schema = make_address_schema();
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert("address".into(), serde_json::json!("1 Main St"));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use schema_forge_core::types::{
-        Cardinality, FieldDefinition, FieldModifier, FieldName, SchemaId, TextConstraints,
-    };
+        let errors = json_to_entity_fields(&schema, &json_fields).unwrap_err();
+        assert!(errors[0].starts_with("field 'address': expected object"));
+    }
 
-    fn make_test_schema() -> SchemaDefinition {
+    fn make_tagged_schema() -> SchemaDefinition {
         SchemaDefinition::new(
             SchemaId::new(),
-            SchemaName::new("Contact").unwrap(),
-            vec![
-                FieldDefinition::with_modifiers(
-                    FieldName::new("name").unwrap(),
-                    FieldType::Text(TextConstraints::unconstrained()),
-                    vec![FieldModifier::Required],
-                ),
-                FieldDefinition::new(
-                    FieldName::new("age").unwrap(),
-                    FieldType::Integer(
-                        schema_forge_core::types::IntegerConstraints::unconstrained(),
-                    ),
+            SchemaName::new("Post").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("tags").unwrap(),
+                FieldType::Array(
+                    Box::new(FieldType::Text(TextConstraints::unconstrained())),
+                    schema_forge_core::types::ArrayConstraints::with_bounds(Some(1), Some(3))
+                        .unwrap(),
                 ),
-                FieldDefinition::new(FieldName::new("active").unwrap(), FieldType::Boolean),
-            ],
+            )],
             vec![],
         )
         .unwrap()
     }
 
     #[test]
-    fn json_to_entity_fields_basic() {
-        let schema = make_test_schema();
+    fn json_to_entity_fields_array_under_min_fails() {
+        let schema = make_tagged_schema();
         let mut json_fields = serde_json::Map::new();
-        json_fields.insert("name".into(), serde_json::json!("Alice"));
-        json_fields.insert("age".into(), serde_json::json!(30));
-        json_fields.insert("active".into(), serde_json::json!(true));
+        json_fields.insert("tags".into(), serde_json::json!([]));
 
-        let result = json_to_entity_fields(&schema, &json_fields).unwrap();
+        let errors = json_to_entity_fields(&schema, &json_fields).unwrap_err();
         assert_eq!(
-            result.get("name"),
-            Some(&DynamicValue::Text("Alice".into()))
+            errors,
+            vec!["field 'tags': expected at least 1 elements, got 0".to_string()]
         );
-        assert_eq!(result.get("age"), Some(&DynamicValue::Integer(30)));
-        assert_eq!(result.get("active"), Some(&DynamicValue::Boolean(true)));
     }
 
     #[test]
-    fn json_to_entity_fields_missing_required() {
-        let schema = make_test_schema();
-        let json_fields = serde_json::Map::new(); // missing "name" which is required
+    fn json_to_entity_fields_array_over_max_fails() {
+        let schema = make_tagged_schema();
+        let mut json_fields = serde_json::Map::new();
+        json_fields.insert("tags".into(), serde_json::json!(["a", "b", "c", "d"]));
 
-        let result = json_to_entity_fields(&schema, &json_fields);
-        assert!(result.is_err());
-        let errors = result.unwrap_err();
-        assert!(errors
-            .iter()
-            .any(|e| e.contains("required") && e.contains("name")));
+        let errors = json_to_entity_fields(&schema, &json_fields).unwrap_err();
+        assert_eq!(
+            errors,
+            vec!["field 'tags': expected at most 3 elements, got 4".to_string()]
+        );
     }
 
     #[test]
-    fn json_to_entity_fields_type_mismatch() {
-        let schema = make_test_schema();
+    fn json_to_entity_fields_array_in_range_passes() {
+        let schema = make_tagged_schema();
         let mut json_fields = serde_json::Map::new();
-        json_fields.insert("name".into(), serde_json::json!("Alice"));
-        json_fields.insert("age".into(), serde_json::json!("not a number"));
+        json_fields.insert("tags".into(), serde_json::json!(["a", "b"]));
 
-        let result = json_to_entity_fields(&schema, &json_fields);
-        assert!(result.is_err());
+        let result = json_to_entity_fields(&schema, &json_fields).unwrap();
+        assert_eq!(
+            result.get("tags"),
+            Some(&DynamicValue::Array(vec![
+                DynamicValue::Text("a".into()),
+                DynamicValue::Text("b".into()),
+            ]))
+        );
     }
 
     #[test]
@@ -3181,7 +6107,7 @@ mod tests {
 
     #[test]
     fn convert_array_with_type_hint() {
-        let field_type = FieldType::Array(Box::new(FieldType::Boolean));
+        let field_type = FieldType::array(FieldType::Boolean);
         let json = serde_json::json!([true, false, true]);
         let result = convert_json_with_type_hint(&json, &field_type).unwrap();
         assert!(matches!(result, DynamicValue::Array(arr) if arr.len() == 3));
@@ -3315,6 +6241,78 @@ mod tests {
         assert!(matches!(filter, Filter::And { ref filters } if filters.len() == 2));
     }
 
+    #[test]
+    fn json_to_filter_datetime_range_accepts_bare_date() {
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Opportunity").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("close_date").unwrap(),
+                FieldType::DateTime,
+            )],
+            vec![],
+        )
+        .unwrap();
+        let json = serde_json::json!({"op": "lt", "field": "close_date", "value": "2026-07-01"});
+        let filter = json_to_filter(&json, &schema).unwrap();
+        assert!(matches!(
+            filter,
+            Filter::Lt { value: DynamicValue::DateTime(dt), .. }
+                if dt.to_rfc3339() == "2026-07-01T00:00:00+00:00"
+        ));
+    }
+
+    #[test]
+    fn json_to_filter_flat_object_is_implicit_and() {
+        let schema = make_test_schema();
+        let json = serde_json::json!({"name": "Alice", "age__gt": 25});
+        let filter = json_to_filter(&json, &schema).unwrap();
+        let Filter::And { filters } = filter else {
+            panic!("expected an AND, got {filter:?}");
+        };
+        assert_eq!(filters.len(), 2);
+        assert!(filters.iter().any(|f| matches!(
+            f,
+            Filter::Gt { value, .. } if *value == DynamicValue::Integer(25)
+        )));
+    }
+
+    #[test]
+    fn json_to_filter_flat_single_entry_is_leaf() {
+        let schema = make_test_schema();
+        let json = serde_json::json!({"age__in": [20, 30]});
+        let filter = json_to_filter(&json, &schema).unwrap();
+        assert!(matches!(filter, Filter::In { ref values, .. } if values.len() == 2));
+    }
+
+    #[test]
+    fn json_to_filter_flat_object_nests_under_or() {
+        let schema = make_test_schema();
+        let json = serde_json::json!({
+            "op": "or",
+            "filters": [
+                {"name": "Alice"},
+                {"age__gte": 30, "active": true}
+            ]
+        });
+        let filter = json_to_filter(&json, &schema).unwrap();
+        let Filter::Or { filters } = filter else {
+            panic!("expected an OR, got {filter:?}");
+        };
+        assert!(matches!(filters[0], Filter::Eq { .. }));
+        assert!(matches!(&filters[1], Filter::And { filters } if filters.len() == 2));
+    }
+
+    #[test]
+    fn json_to_filter_flat_object_rejects_unknown_fields() {
+        let schema = make_test_schema();
+        let json = serde_json::json!({"nickname": "Al", "limit": 5});
+        let errors = json_to_filter(&json, &schema).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.contains(&"'limit' is not a filter field".to_string()));
+        assert!(errors.contains(&"unknown filter field 'nickname'".to_string()));
+    }
+
     // -- EntityQueryBody fields deserialization --
 
     #[test]
@@ -3421,6 +6419,126 @@ mod tests {
         assert_eq!(result, DynamicValue::Null);
     }
 
+    #[test]
+    fn convert_duration_validates_iso_string() {
+        let result =
+            convert_json_with_type_hint(&serde_json::json!("P3DT4H"), &FieldType::Duration)
+                .unwrap();
+        assert_eq!(
+            result,
+            DynamicValue::Duration(IsoDuration::parse("P3DT4H").unwrap())
+        );
+
+        let err = convert_json_with_type_hint(&serde_json::json!("3 days"), &FieldType::Duration)
+            .unwrap_err();
+        assert!(
+            err.contains("invalid duration '3 days'"),
+            "unexpected error: {err}"
+        );
+        assert!(convert_json_with_type_hint(&serde_json::json!(-3), &FieldType::Duration).is_err());
+        assert!(
+            convert_json_with_type_hint(&serde_json::json!(1.5), &FieldType::Duration).is_err()
+        );
+    }
+
+    #[test]
+    fn convert_duration_accepts_shorthand_and_seconds() {
+        let shorthand =
+            convert_json_with_type_hint(&serde_json::json!("2h 30m"), &FieldType::Duration)
+                .unwrap();
+        assert_eq!(
+            shorthand,
+            DynamicValue::Duration(IsoDuration::parse("PT2H30M").unwrap())
+        );
+        let seconds =
+            convert_json_with_type_hint(&serde_json::json!(9000), &FieldType::Duration).unwrap();
+        assert_eq!(seconds, shorthand);
+    }
+
+    #[test]
+    fn convert_uuid_validates_and_canonicalizes() {
+        let result = convert_json_with_type_hint(
+            &serde_json::json!("67E55044-10B1-426F-9247-BB680E5FE0C8"),
+            &FieldType::Uuid,
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            DynamicValue::Uuid(parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap())
+        );
+
+        let err = convert_json_with_type_hint(&serde_json::json!("not-a-uuid"), &FieldType::Uuid)
+            .unwrap_err();
+        assert!(
+            err.contains("invalid UUID 'not-a-uuid'"),
+            "unexpected error: {err}"
+        );
+        assert!(convert_json_with_type_hint(&serde_json::json!(42), &FieldType::Uuid).is_err());
+    }
+
+    #[test]
+    fn coerce_uuid_from_text() {
+        let result = coerce_dynamic_value_with_type_hint(
+            DynamicValue::Text("67e55044-10b1-426f-9247-bb680e5fe0c8".into()),
+            &FieldType::Uuid,
+        )
+        .unwrap();
+        assert!(matches!(result, DynamicValue::Uuid(_)));
+        assert!(coerce_dynamic_value_with_type_hint(
+            DynamicValue::Text("67e55044".into()),
+            &FieldType::Uuid
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn convert_decimal_keeps_exact_digits() {
+        let ft = FieldType::Decimal { scale: 2 };
+        let expected = DynamicValue::Decimal(parse_decimal("19.99", 2).unwrap());
+        assert_eq!(
+            convert_json_with_type_hint(&serde_json::json!("19.99"), &ft).unwrap(),
+            expected
+        );
+        assert_eq!(
+            convert_json_with_type_hint(&serde_json::json!(19.99), &ft).unwrap(),
+            expected
+        );
+
+        let err = convert_json_with_type_hint(&serde_json::json!("19.999"), &ft).unwrap_err();
+        assert!(
+            err.contains("more than 2 fractional digit"),
+            "unexpected error: {err}"
+        );
+        assert!(convert_json_with_type_hint(&serde_json::json!(true), &ft).is_err());
+    }
+
+    #[test]
+    fn coerce_decimal_from_text_and_float() {
+        let ft = FieldType::Decimal { scale: 2 };
+        let expected = DynamicValue::Decimal(parse_decimal("0.30", 2).unwrap());
+        assert_eq!(
+            coerce_dynamic_value_with_type_hint(DynamicValue::Text("0.3".into()), &ft).unwrap(),
+            expected
+        );
+        assert_eq!(
+            coerce_dynamic_value_with_type_hint(DynamicValue::Float(0.3), &ft).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn coerce_duration_from_text() {
+        let result = coerce_dynamic_value_with_type_hint(
+            DynamicValue::Text("PT90M".into()),
+            &FieldType::Duration,
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            DynamicValue::Duration(IsoDuration::parse("PT90M").unwrap())
+        );
+    }
+
     #[test]
     fn coerce_enum_from_text() {
         let enum_type = FieldType::Enum(
@@ -3484,7 +6602,7 @@ mod tests {
 
     #[test]
     fn coerce_array_of_datetime_recurses() {
-        let array_type = FieldType::Array(Box::new(FieldType::DateTime));
+        let array_type = FieldType::array(FieldType::DateTime);
         let result = coerce_dynamic_value_with_type_hint(
             DynamicValue::Array(vec![
                 DynamicValue::Text("2025-04-12T10:00:00Z".into()),
@@ -3522,4 +6640,92 @@ mod tests {
         .unwrap();
         assert_eq!(result, DynamicValue::Integer(42));
     }
+
+    #[test]
+    fn fill_missing_defaults_only_fills_absent_keys() {
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Task").unwrap(),
+            vec![
+                FieldDefinition::with_modifiers(
+                    FieldName::new("active").unwrap(),
+                    FieldType::Boolean,
+                    vec![FieldModifier::Default {
+                        value: DefaultValue::Boolean(true),
+                    }],
+                ),
+                FieldDefinition::with_modifiers(
+                    FieldName::new("priority").unwrap(),
+                    FieldType::Enum(
+                        schema_forge_core::types::EnumVariants::new(vec![
+                            "low".into(),
+                            "high".into(),
+                        ])
+                        .unwrap(),
+                    ),
+                    vec![FieldModifier::Default {
+                        value: DefaultValue::String("low".into()),
+                    }],
+                ),
+                FieldDefinition::with_modifiers(
+                    FieldName::new("note").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                    vec![FieldModifier::Default {
+                        value: DefaultValue::String("n/a".into()),
+                    }],
+                ),
+            ],
+            vec![],
+        )
+        .unwrap();
+
+        let mut fields = BTreeMap::new();
+        fields.insert("note".to_string(), DynamicValue::Null);
+        fill_missing_defaults(&schema, &mut fields);
+
+        assert_eq!(fields.get("active"), Some(&DynamicValue::Boolean(true)));
+        assert_eq!(fields.get("priority"), Some(&DynamicValue::Enum("low".into())));
+        // Explicit null from the backend is authoritative.
+        assert_eq!(fields.get("note"), Some(&DynamicValue::Null));
+    }
+
+    #[test]
+    fn default_to_dynamic_value_rejects_mismatched_types() {
+        assert_eq!(
+            default_to_dynamic_value(&DefaultValue::String("x".into()), &FieldType::DateTime),
+            None
+        );
+        assert_eq!(
+            default_to_dynamic_value(
+                &DefaultValue::Integer(3),
+                &FieldType::Float(schema_forge_core::types::FloatConstraints::unconstrained())
+            ),
+            Some(DynamicValue::Float(3.0))
+        );
+    }
+
+    #[test]
+    fn prefers_minimal_return_parses_prefer_header() {
+        let headers = |values: &[&str]| {
+            let mut h = HeaderMap::new();
+            for v in values {
+                h.append("prefer", HeaderValue::from_str(v).unwrap());
+            }
+            h
+        };
+        assert!(!prefers_minimal_return(&headers(&[])));
+        assert!(prefers_minimal_return(&headers(&["return=minimal"])));
+        assert!(prefers_minimal_return(&headers(&["Return=Minimal"])));
+        assert!(prefers_minimal_return(&headers(&[
+            "respond-async, return=minimal; x=1"
+        ])));
+        assert!(prefers_minimal_return(&headers(&[
+            "wait=5",
+            "return=minimal"
+        ])));
+        assert!(!prefers_minimal_return(&headers(&[
+            "return=representation"
+        ])));
+        assert!(!prefers_minimal_return(&headers(&["handling=lenient"])));
+    }
 }
@@ -0,0 +1,243 @@
+//! Live entity change stream: `GET /schemas/{schema}/events`.
+//!
+//! Streams the schema's creates, updates, and deletes as server-sent
+//! events, sourced from the actor's [`ChangeFeed`](crate::events::ChangeFeed).
+//! Each SSE message has the operation as its `event:` name and a JSON body:
+//!
+//! ```json
+//! {"operation": "updated", "schema": "Contact", "id": "contact_01", "fields": {...}}
+//! ```
+//!
+//! `fields` is present for creates and updates only. Scoping is evaluated
+//! per subscriber against the claims presented when the stream was opened:
+//! - schema-level `Read` access is checked once, up front
+//! - changes outside the caller's tenant or hidden by the record-access
+//!   policy are skipped
+//! - `@hidden` and read-restricted (`@field_access`) fields are stripped
+//!   from `fields`
+//!
+//! A subscriber that falls too far behind receives a single `lagged` event
+//! carrying the number of skipped changes, and should refetch.
+
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+
+use acton_service::middleware::Claims;
+use acton_service::prelude::ActorHandleInterface;
+use acton_service::state::AppState;
+use axum::extract::{Path, State};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures::Stream;
+use schema_forge_backend::auth::RecordAccessPolicy;
+use schema_forge_backend::tenant::TenantConfig;
+use schema_forge_core::types::{SchemaDefinition, SchemaName};
+use serde::Serialize;
+use tokio::sync::{broadcast, oneshot};
+use tracing::instrument;
+
+use crate::access::{
+    check_schema_access, entity_in_tenant_scope, filter_entity_fields, tenant_scope, AccessAction,
+    FieldFilterDirection, OptionalClaims,
+};
+use crate::actor::ForgeActor;
+use crate::authz::PolicyStore;
+use crate::config::SchemaForgeConfig;
+use crate::conversions::entity_to_response;
+use crate::error::ForgeError;
+use crate::events::{ChangeOperation, EntityChange};
+use crate::messages::{
+    GetChangeFeed, GetPolicyStore, GetRecordAccessPolicy, GetSchema, GetTenantConfig, ReplyChannel,
+};
+
+/// Timeout for actor request-response round-trips.
+const ACTOR_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Await an actor response with a timeout.
+async fn ask_forge<T>(rx: oneshot::Receiver<T>) -> Result<T, ForgeError> {
+    tokio::time::timeout(ACTOR_TIMEOUT, rx)
+        .await
+        .map_err(|_| ForgeError::Internal {
+            message: "forge actor timeout".into(),
+        })?
+        .map_err(|_| ForgeError::Internal {
+            message: "forge actor unavailable".into(),
+        })
+}
+
+/// JSON body of a change event.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangeEventPayload {
+    pub operation: ChangeOperation,
+    pub schema: String,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Everything needed to scope changes for one subscriber, captured when
+/// the stream is opened.
+struct Subscriber {
+    schema_def: SchemaDefinition,
+    claims: Option<Claims>,
+    policy_store: Arc<PolicyStore>,
+    tenant_config: Option<TenantConfig>,
+    record_access_policy: Option<Arc<dyn RecordAccessPolicy>>,
+}
+
+impl Subscriber {
+    /// Scope a change to this subscriber. Returns `None` when the change
+    /// belongs to another schema or is not visible to the caller.
+    async fn payload_for(&self, change: EntityChange) -> Option<ChangeEventPayload> {
+        if change.schema != self.schema_def.name.as_str() {
+            return None;
+        }
+
+        match change.entity {
+            Some(ref entity) => {
+                if !entity_in_tenant_scope(entity, self.claims.as_ref(), &self.tenant_config) {
+                    return None;
+                }
+                if let (Some(policy), Some(claims)) = (&self.record_access_policy, &self.claims) {
+                    let visible = policy
+                        .filter_visible(&self.schema_def, claims, vec![entity.clone()])
+                        .await;
+                    if visible.is_empty() {
+                        return None;
+                    }
+                }
+            }
+            // A delete whose prior state could not be read: only callers
+            // with no tenant or record scoping may see it.
+            None => {
+                let scoped = tenant_scope(self.claims.as_ref(), &self.tenant_config).is_some()
+                    || (self.record_access_policy.is_some() && self.claims.is_some());
+                if scoped {
+                    return None;
+                }
+            }
+        }
+
+        let fields = match (change.operation, change.entity) {
+            (ChangeOperation::Deleted, _) | (_, None) => None,
+            (_, Some(mut entity)) => {
+                filter_entity_fields(
+                    &self.policy_store,
+                    &mut entity,
+                    &self.schema_def,
+                    self.claims.as_ref(),
+                    FieldFilterDirection::Read,
+                );
+                Some(entity_to_response(&entity, &self.schema_def).fields)
+            }
+        };
+
+        Some(ChangeEventPayload {
+            operation: change.operation,
+            schema: change.schema,
+            id: change.entity_id,
+            fields,
+        })
+    }
+}
+
+/// `GET /schemas/{schema}/events` — stream the schema's entity changes.
+#[instrument(skip_all, fields(schema = %schema))]
+pub async fn stream_entity_events(
+    State(state): State<AppState<SchemaForgeConfig>>,
+    Path(schema): Path<String>,
+    OptionalClaims(claims): OptionalClaims,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ForgeError> {
+    let schema_name = SchemaName::new(&schema).map_err(|_| ForgeError::InvalidSchemaName {
+        name: schema.clone(),
+    })?;
+    let forge = state
+        .actor::<ForgeActor>()
+        .ok_or_else(|| ForgeError::Internal {
+            message: "ForgeActor not registered".into(),
+        })?;
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetSchema {
+            name: schema_name.as_str().to_string(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let schema_def = ask_forge(rx).await?.ok_or(ForgeError::SchemaNotFound {
+        name: schema_name.as_str().to_string(),
+    })?;
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetPolicyStore {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let policy_store = ask_forge(rx).await?.ok_or_else(|| ForgeError::Internal {
+        message: "Cedar policy store not initialized — InitForge has not run".into(),
+    })?;
+
+    check_schema_access(
+        &policy_store,
+        &schema_def,
+        claims.as_ref(),
+        AccessAction::Read,
+    )?;
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetTenantConfig {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let tenant_config = ask_forge(rx).await?;
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetRecordAccessPolicy {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let record_access_policy = ask_forge(rx).await?;
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(GetChangeFeed {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let receiver = ask_forge(rx).await?.subscribe();
+
+    let subscriber = Arc::new(Subscriber {
+        schema_def,
+        claims,
+        policy_store,
+        tenant_config,
+        record_access_policy,
+    });
+
+    let stream = futures::stream::unfold(
+        (receiver, subscriber),
+        |(mut receiver, subscriber)| async move {
+            loop {
+                let event = match receiver.recv().await {
+                    Ok(change) => match subscriber.payload_for(change).await {
+                        Some(payload) => Event::default()
+                            .event(payload.operation.as_str())
+                            .json_data(&payload)
+                            .unwrap_or_else(|e| Event::default().comment(e.to_string())),
+                        None => continue,
+                    },
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        Event::default().event("lagged").data(skipped.to_string())
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                };
+                return Some((Ok(event), (receiver, subscriber)));
+            }
+        },
+    );
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
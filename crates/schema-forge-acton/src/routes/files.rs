@@ -782,6 +782,9 @@ fn dynamic_inner_to_json(value: &DynamicValue) -> serde_json::Value {
             .unwrap_or(serde_json::Value::Null),
         DynamicValue::Boolean(b) => serde_json::Value::Bool(*b),
         DynamicValue::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
+        DynamicValue::Duration(d) => serde_json::Value::String(d.to_string()),
+        DynamicValue::Uuid(u) => serde_json::Value::String(u.to_string()),
+        DynamicValue::Decimal(d) => serde_json::Value::String(d.to_string()),
         DynamicValue::Json(v) => v.clone(),
         DynamicValue::Array(arr) => {
             serde_json::Value::Array(arr.iter().map(dynamic_inner_to_json).collect())
@@ -826,6 +829,7 @@ async fn persist_attachment(
     forge
         .send(UpdateEntity {
             entity: updated,
+            expected_version: None,
             reply: ReplyChannel::new(tx),
         })
         .await;
@@ -1,5 +1,7 @@
 pub mod auth;
+pub mod csv;
 pub mod entities;
+pub mod events;
 pub mod files;
 pub mod meta;
 pub mod permissions;
@@ -38,15 +40,45 @@ pub fn forge_routes() -> Router<AppState<SchemaForgeConfig>> {
                 .put(schemas::update_schema)
                 .delete(schemas::delete_schema),
         )
+        .route("/openapi.json", get(schemas::get_openapi))
         // Entity CRUD (nested under schema)
         .route(
             "/schemas/{schema}/entities",
             post(entities::create_entity).get(entities::list_entities),
         )
+        .route(
+            "/schemas/{schema}/entities.csv",
+            get(entities::export_entities_csv),
+        )
+        // Live change stream (server-sent events)
+        .route(
+            "/schemas/{schema}/events",
+            get(events::stream_entity_events),
+        )
+        .route(
+            "/schemas/{schema}/entities/count",
+            get(entities::count_entities),
+        )
         .route(
             "/schemas/{schema}/entities/query",
             post(entities::query_entities),
         )
+        .route(
+            "/schemas/{schema}/entities/batch",
+            post(entities::batch_create_entities),
+        )
+        .route(
+            "/schemas/{schema}/entities/aggregate",
+            post(entities::aggregate_entities),
+        )
+        .route(
+            "/schemas/{schema}/entities/delete",
+            post(entities::bulk_delete_entities),
+        )
+        .route(
+            "/schemas/{schema}/entities/by/{field}/{value}",
+            get(entities::get_entity_by_field),
+        )
         .route(
             "/schemas/{schema}/entities/{id}",
             get(entities::get_entity)
@@ -54,6 +86,10 @@ pub fn forge_routes() -> Router<AppState<SchemaForgeConfig>> {
                 .patch(entities::patch_entity)
                 .delete(entities::delete_entity),
         )
+        .route(
+            "/schemas/{schema}/entities/{id}/restore",
+            post(entities::restore_entity),
+        )
         // File fields (presigned upload, confirm, and download)
         .route(
             "/schemas/{schema}/entities/{id}/fields/{field}/upload-url",
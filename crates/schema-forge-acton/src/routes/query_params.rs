@@ -1,10 +1,28 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use schema_forge_core::query::{FieldPath, Filter, SortOrder};
-use schema_forge_core::types::{DynamicValue, FieldType, SchemaDefinition};
+use schema_forge_core::types::{
+    parse_decimal, parse_uuid, DynamicValue, FieldType, IsoDuration, SchemaDefinition,
+};
 
 /// Reserved query parameter names that are not filter fields.
-const RESERVED_PARAMS: &[&str] = &["limit", "offset", "sort", "fields", "count", "resolve"];
+///
+/// Every other key on a list request is parsed as `field[__op]=value`, so
+/// a control parameter missing from this set is rejected as an unknown
+/// field. The set covers pagination (`limit`, `offset`, `after`), shaping
+/// (`sort`, `fields`), the default-on `count` and `resolve` flags, the
+/// `debug` switch, and the `include_deleted` opt-in.
+const RESERVED_PARAMS: &[&str] = &[
+    "limit",
+    "offset",
+    "after",
+    "sort",
+    "fields",
+    "count",
+    "resolve",
+    "debug",
+    "include_deleted",
+];
 
 /// Supported filter operators parsed from `field__op` suffixes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -71,23 +89,42 @@ pub fn coerce_string_value(
             .parse::<f64>()
             .map(DynamicValue::Float)
             .map_err(|_| format!("expected float, got '{raw}'")),
-        Some(FieldType::Boolean) => match raw {
-            "true" | "1" => Ok(DynamicValue::Boolean(true)),
-            "false" | "0" => Ok(DynamicValue::Boolean(false)),
+        Some(FieldType::Decimal { scale }) => parse_decimal(raw, *scale)
+            .map(DynamicValue::Decimal)
+            .map_err(|e| e.to_string()),
+        Some(FieldType::Boolean) => match raw.to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" | "on" => Ok(DynamicValue::Boolean(true)),
+            "false" | "0" | "no" | "off" => Ok(DynamicValue::Boolean(false)),
             _ => Err(format!("expected boolean (true/false), got '{raw}'")),
         },
-        Some(FieldType::DateTime) => raw
-            .parse::<chrono::DateTime<chrono::Utc>>()
-            .map(DynamicValue::DateTime)
-            .map_err(|e| format!("invalid datetime '{raw}': {e}")),
+        Some(FieldType::DateTime) => parse_datetime_filter(raw).map(DynamicValue::DateTime),
+        Some(FieldType::Duration) => IsoDuration::parse_input(raw)
+            .map(DynamicValue::Duration)
+            .map_err(|e| e.to_string()),
+        Some(FieldType::Uuid) => parse_uuid(raw)
+            .map(DynamicValue::Uuid)
+            .map_err(|e| e.to_string()),
         Some(FieldType::Enum(_)) => Ok(DynamicValue::Enum(raw.to_string())),
-        Some(FieldType::Text(_) | FieldType::RichText) | None => {
+        Some(FieldType::Text(_) | FieldType::RichText(_)) | None => {
             Ok(DynamicValue::Text(raw.to_string()))
         }
         Some(_) => Ok(DynamicValue::Text(raw.to_string())),
     }
 }
 
+/// Parse a datetime filter bound: RFC 3339, or a bare `YYYY-MM-DD` date
+/// meaning midnight UTC, so `?closed_at__gte=2026-01-01` works.
+pub fn parse_datetime_filter(raw: &str) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    raw.parse::<chrono::DateTime<chrono::Utc>>().or_else(|e| {
+        chrono::NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+            .map(|d| d.and_time(chrono::NaiveTime::MIN).and_utc())
+            .map_err(|_| format!("invalid datetime '{raw}': {e}"))
+    })
+}
+
+/// Boolean filter value meaning "either true or false" (no constraint).
+pub const ANY_BOOLEAN: &str = "any";
+
 /// Parse a sort parameter string into a list of `(FieldPath, SortOrder)` pairs.
 ///
 /// Supports two syntaxes:
@@ -134,10 +171,24 @@ pub fn parse_sort_param(sort: &str) -> Result<Vec<(FieldPath, SortOrder)>, Strin
 /// Each parameter key is parsed via `parse_filter_key`. Values are coerced using
 /// schema field type hints. Multiple filter params are AND-combined.
 ///
+/// A boolean field filtered with the sentinel [`ANY_BOOLEAN`] adds no
+/// constraint, so UI toggles can clear the filter without dropping the param.
+///
 /// Returns `Ok(None)` when no filter parameters are present.
 pub fn parse_filter_params(
     params: &HashMap<String, String>,
     schema: &SchemaDefinition,
+) -> Result<Option<Filter>, Vec<String>> {
+    parse_filter_params_with_targets(params, schema, &[])
+}
+
+/// Like [`parse_filter_params`], but coerces values of one-level relation
+/// filters (`organization.region=EU`) using the field type on the
+/// relation's target schema, looked up in `targets`.
+pub fn parse_filter_params_with_targets(
+    params: &HashMap<String, String>,
+    schema: &SchemaDefinition,
+    targets: &[SchemaDefinition],
 ) -> Result<Option<Filter>, Vec<String>> {
     let mut filters = Vec::new();
     let mut errors = Vec::new();
@@ -148,7 +199,11 @@ pub fn parse_filter_params(
             None => continue, // Reserved or unrecognized operator
         };
 
-        let field_type = schema.field(field_name).map(|fd| &fd.field_type);
+        let field_type = filter_field_type(field_name, schema, targets);
+        if matches!(field_type, Some(FieldType::Boolean)) && value.eq_ignore_ascii_case(ANY_BOOLEAN)
+        {
+            continue;
+        }
         let path = match FieldPath::parse(field_name) {
             Ok(p) => p,
             Err(e) => {
@@ -209,6 +264,52 @@ pub fn parse_filter_params(
     })
 }
 
+/// Names of the relation targets traversed by dotted filter keys such as
+/// `organization.region`, sorted and deduplicated.
+///
+/// Keys whose root is not a relation field are ignored; their errors are
+/// reported when the filter itself is validated.
+pub fn filter_relation_targets(
+    params: &HashMap<String, String>,
+    schema: &SchemaDefinition,
+) -> Vec<String> {
+    let mut targets = BTreeSet::new();
+    for key in params.keys() {
+        let Some((field_name, _)) = parse_filter_key(key) else {
+            continue;
+        };
+        let Some((root, _)) = field_name.split_once('.') else {
+            continue;
+        };
+        if let Some(FieldType::Relation { target, .. }) =
+            schema.field(root).map(|fd| &fd.field_type)
+        {
+            targets.insert(target.as_str().to_string());
+        }
+    }
+    targets.into_iter().collect()
+}
+
+/// Type hint for a filter field: the schema's own field for plain names,
+/// or the target schema's field for a `relation.field` path.
+fn filter_field_type<'a>(
+    field_name: &str,
+    schema: &'a SchemaDefinition,
+    targets: &'a [SchemaDefinition],
+) -> Option<&'a FieldType> {
+    let Some((root, leaf)) = field_name.split_once('.') else {
+        return schema.field(field_name).map(|fd| &fd.field_type);
+    };
+    let FieldType::Relation { target, .. } = &schema.field(root)?.field_type else {
+        return None;
+    };
+    targets
+        .iter()
+        .find(|t| t.name == *target)?
+        .field(leaf)
+        .map(|fd| &fd.field_type)
+}
+
 /// Parse a comma-separated `fields` parameter into a validated set of field names.
 ///
 /// Each name is trimmed and checked against the schema's field definitions.
@@ -239,12 +340,44 @@ pub fn parse_fields_param(
     Ok(names.into_iter().map(String::from).collect())
 }
 
+/// Parse a comma-separated `expand` parameter into relation field names.
+///
+/// Each name must be a relation field on `schema`. Duplicates are dropped
+/// and the first-seen order is kept.
+pub fn parse_expand_param(value: &str, schema: &SchemaDefinition) -> Result<Vec<String>, String> {
+    let mut names: Vec<String> = Vec::new();
+    for name in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+    }
+    if names.is_empty() {
+        return Err("expand parameter must contain at least one field name".to_string());
+    }
+
+    let invalid: Vec<&str> = names
+        .iter()
+        .filter(|n| {
+            !matches!(
+                schema.field(n).map(|f| &f.field_type),
+                Some(FieldType::Relation { .. })
+            )
+        })
+        .map(String::as_str)
+        .collect();
+    if !invalid.is_empty() {
+        return Err(format!("not relation fields: {}", invalid.join(", ")));
+    }
+
+    Ok(names)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use schema_forge_core::types::{
-        EnumVariants, FieldDefinition, FieldModifier, FieldName, IntegerConstraints, SchemaId,
-        SchemaName, TextConstraints,
+        Cardinality, EnumVariants, FieldDefinition, FieldModifier, FieldName, IntegerConstraints,
+        SchemaId, SchemaName, TextConstraints,
     };
 
     fn test_schema() -> SchemaDefinition {
@@ -348,6 +481,11 @@ mod tests {
         assert_eq!(parse_filter_key("offset"), None);
     }
 
+    #[test]
+    fn parse_filter_key_reserved_after() {
+        assert_eq!(parse_filter_key("after"), None);
+    }
+
     #[test]
     fn parse_filter_key_reserved_sort() {
         assert_eq!(parse_filter_key("sort"), None);
@@ -365,6 +503,16 @@ mod tests {
 
     // -- coerce_string_value tests --
 
+    #[test]
+    fn coerce_datetime_accepts_bare_date() {
+        let result = coerce_string_value("2026-01-01", Some(&FieldType::DateTime)).unwrap();
+        let expected = "2026-01-01T00:00:00Z"
+            .parse::<chrono::DateTime<chrono::Utc>>()
+            .unwrap();
+        assert_eq!(result, DynamicValue::DateTime(expected));
+        assert!(coerce_string_value("2026-13-01", Some(&FieldType::DateTime)).is_err());
+    }
+
     #[test]
     fn coerce_integer() {
         let result = coerce_string_value(
@@ -401,6 +549,24 @@ mod tests {
         assert_eq!(result.unwrap(), DynamicValue::Boolean(true));
     }
 
+    #[test]
+    fn coerce_boolean_spellings() {
+        for raw in ["true", "TRUE", "1", "yes", "Yes", "on", "ON"] {
+            assert_eq!(
+                coerce_string_value(raw, Some(&FieldType::Boolean)).unwrap(),
+                DynamicValue::Boolean(true),
+                "{raw}"
+            );
+        }
+        for raw in ["false", "False", "0", "no", "NO", "off", "Off"] {
+            assert_eq!(
+                coerce_string_value(raw, Some(&FieldType::Boolean)).unwrap(),
+                DynamicValue::Boolean(false),
+                "{raw}"
+            );
+        }
+    }
+
     #[test]
     fn coerce_boolean_invalid() {
         let result = coerce_string_value("maybe", Some(&FieldType::Boolean));
@@ -454,6 +620,26 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn coerce_uuid_rejects_malformed() {
+        let result = coerce_string_value(
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            Some(&FieldType::Uuid),
+        );
+        assert!(matches!(result.unwrap(), DynamicValue::Uuid(_)));
+        assert!(coerce_string_value("67e55044", Some(&FieldType::Uuid)).is_err());
+    }
+
+    #[test]
+    fn coerce_decimal() {
+        let ft = FieldType::Decimal { scale: 2 };
+        assert_eq!(
+            coerce_string_value("100.5", Some(&ft)).unwrap(),
+            DynamicValue::Decimal(parse_decimal("100.50", 2).unwrap())
+        );
+        assert!(coerce_string_value("1e3", Some(&ft)).is_err());
+    }
+
     // -- parse_sort_param tests --
 
     #[test]
@@ -582,6 +768,46 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parse_filter_params_boolean_yes_no() {
+        let schema = test_schema();
+        for (raw, expected) in [("yes", true), ("off", false)] {
+            let params = HashMap::from([("active".to_string(), raw.to_string())]);
+            let filter = parse_filter_params(&params, &schema).unwrap().unwrap();
+            assert!(matches!(
+                filter,
+                Filter::Eq { ref value, .. } if *value == DynamicValue::Boolean(expected)
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_filter_params_boolean_any_is_no_constraint() {
+        let schema = test_schema();
+        for raw in ["any", "ANY"] {
+            let params = HashMap::from([("active".to_string(), raw.to_string())]);
+            assert!(parse_filter_params(&params, &schema).unwrap().is_none());
+        }
+
+        let params = HashMap::from([
+            ("active".to_string(), "any".to_string()),
+            ("age__gt".to_string(), "25".to_string()),
+        ]);
+        let filter = parse_filter_params(&params, &schema).unwrap().unwrap();
+        assert!(matches!(filter, Filter::Gt { .. }));
+    }
+
+    #[test]
+    fn parse_filter_params_any_on_text_field_is_literal() {
+        let schema = test_schema();
+        let params = HashMap::from([("name".to_string(), "any".to_string())]);
+        let filter = parse_filter_params(&params, &schema).unwrap().unwrap();
+        assert!(matches!(
+            filter,
+            Filter::Eq { ref value, .. } if *value == DynamicValue::Text("any".into())
+        ));
+    }
+
     #[test]
     fn parse_filter_params_in_operator() {
         let schema = test_schema();
@@ -669,6 +895,16 @@ mod tests {
         assert_eq!(parse_filter_key("resolve"), None);
     }
 
+    #[test]
+    fn parse_filter_key_reserved_debug() {
+        assert_eq!(parse_filter_key("debug"), None);
+    }
+
+    #[test]
+    fn parse_filter_key_reserved_include_deleted() {
+        assert_eq!(parse_filter_key("include_deleted"), None);
+    }
+
     #[test]
     fn parse_filter_params_skips_count_opt_out() {
         let schema = test_schema();
@@ -685,6 +921,65 @@ mod tests {
         ));
     }
 
+    fn project_schema() -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Project").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("organization").unwrap(),
+                FieldType::Relation {
+                    target: SchemaName::new("Organization").unwrap(),
+                    cardinality: Cardinality::One,
+                },
+            )],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    fn organization_schema() -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Organization").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("headcount").unwrap(),
+                FieldType::Integer(IntegerConstraints::unconstrained()),
+            )],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn filter_relation_targets_collects_traversed_relations() {
+        let params = HashMap::from([
+            ("organization.headcount__gt".to_string(), "10".to_string()),
+            ("organization.region".to_string(), "EU".to_string()),
+            ("name".to_string(), "x".to_string()),
+            ("limit".to_string(), "5".to_string()),
+        ]);
+        assert_eq!(
+            filter_relation_targets(&params, &project_schema()),
+            vec!["Organization".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_filter_params_with_targets_coerces_target_field() {
+        let params = HashMap::from([("organization.headcount__gt".to_string(), "10".to_string())]);
+        let filter =
+            parse_filter_params_with_targets(&params, &project_schema(), &[organization_schema()])
+                .unwrap()
+                .unwrap();
+        assert_eq!(
+            filter,
+            Filter::gt(
+                FieldPath::parse("organization.headcount").unwrap(),
+                DynamicValue::Integer(10)
+            )
+        );
+    }
+
     // -- parse_fields_param tests --
 
     #[test]
@@ -738,4 +1033,21 @@ mod tests {
             HashSet::from(["name".to_string(), "age".to_string()])
         );
     }
+
+    // -- parse_expand_param tests --
+
+    #[test]
+    fn parse_expand_param_accepts_relation_fields() {
+        let result = parse_expand_param(" organization ,organization", &project_schema()).unwrap();
+        assert_eq!(result, vec!["organization".to_string()]);
+    }
+
+    #[test]
+    fn parse_expand_param_rejects_non_relation_fields() {
+        let err = parse_expand_param("organization,headcount", &organization_schema()).unwrap_err();
+        assert!(err.contains("organization"));
+        assert!(err.contains("headcount"));
+
+        assert!(parse_expand_param(",", &project_schema()).is_err());
+    }
 }
@@ -3,14 +3,14 @@ use std::time::Duration;
 use acton_service::middleware::Claims;
 use acton_service::prelude::ActorHandleInterface;
 use acton_service::state::AppState;
-use axum::extract::{Path, State};
+use axum::extract::{OriginalUri, Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 use schema_forge_core::migration::DiffEngine;
 use schema_forge_core::types::{
     Annotation, FieldDefinition, FieldModifier, FieldName, FieldType, SchemaDefinition, SchemaId,
-    SchemaName, TextConstraints,
+    SchemaName, TextConstraints, MAX_DECIMAL_SCALE,
 };
 use serde::{Deserialize, Serialize};
 use tokio::sync::oneshot;
@@ -21,6 +21,7 @@ use crate::access::{
     PLATFORM_ADMIN_ROLE,
 };
 use crate::actor::ForgeActor;
+use crate::api_key::check_api_key_admin_scope;
 use crate::config::SchemaForgeConfig;
 use crate::error::ForgeError;
 use crate::messages::{
@@ -156,15 +157,15 @@ fn require_auth(claims: &Option<Claims>) -> Result<&Claims, ForgeError> {
     })
 }
 
-/// Require the `platform_admin` role. Returns 403 if the caller lacks it.
-fn require_admin(claims: &Claims) -> Result<(), ForgeError> {
-    if claims.has_role(PLATFORM_ADMIN_ROLE) {
-        Ok(())
-    } else {
-        Err(ForgeError::Forbidden {
+/// Require the `platform_admin` role and, for API-key principals, an
+/// `admin` scope covering `schema`. Returns 403 if either is missing.
+fn require_admin(claims: &Claims, schema: &str) -> Result<(), ForgeError> {
+    if !claims.has_role(PLATFORM_ADMIN_ROLE) {
+        return Err(ForgeError::Forbidden {
             message: "schema management requires platform_admin role".to_string(),
-        })
+        });
     }
+    check_api_key_admin_scope(Some(claims), schema)
 }
 
 // ---------------------------------------------------------------------------
@@ -183,6 +184,14 @@ pub struct CreateSchemaRequest {
     pub annotations: Vec<serde_json::Value>,
 }
 
+/// Query parameters for `PUT /schemas/{name}`.
+#[derive(Debug, Default, Deserialize)]
+pub struct UpdateSchemaParams {
+    /// Apply even when the body's `@version` does not advance the stored one.
+    #[serde(default)]
+    pub force: bool,
+}
+
 /// A field in a create/update schema request.
 #[derive(Debug, Deserialize)]
 pub struct FieldDefinitionRequest {
@@ -190,7 +199,7 @@ pub struct FieldDefinitionRequest {
     pub name: String,
     /// The field type specification as a JSON value.
     pub field_type: serde_json::Value,
-    /// Modifiers: "required", "indexed".
+    /// Modifiers: "required", "indexed", "unique".
     #[serde(default)]
     pub modifiers: Vec<String>,
 }
@@ -262,6 +271,7 @@ fn request_field_to_definition(
         match m.as_str() {
             "required" => modifiers.push(FieldModifier::Required),
             "indexed" => modifiers.push(FieldModifier::Indexed),
+            "unique" => modifiers.push(FieldModifier::Unique),
             other => {
                 return Err(ForgeError::ValidationFailed {
                     details: vec![format!("unknown modifier '{other}'")],
@@ -281,13 +291,14 @@ fn request_field_to_definition(
 ///
 /// Supports:
 /// - `"Text"` / `{"type": "Text"}` / `{"type": "Text", "data": {"max_length": 255}}`
-/// - `"Integer"`, `"Float"`, `"Boolean"`, `"DateTime"`, `"RichText"`, `"Json"`
+/// - `"Integer"`, `"Float"`, `"Boolean"`, `"DateTime"`, `"Duration"`, `"Uuid"`, `"RichText"`, `"Json"`
+/// - `"Decimal"` / `{"type": "Decimal", "data": {"scale": 4}}` (scale defaults to 2)
 fn parse_field_type(value: &serde_json::Value) -> Result<FieldType, ForgeError> {
     // Handle simple string like "Text", "Boolean", etc.
     if let Some(s) = value.as_str() {
         return match s {
             "Text" => Ok(FieldType::Text(TextConstraints::unconstrained())),
-            "RichText" => Ok(FieldType::RichText),
+            "RichText" => Ok(FieldType::rich_text()),
             "Integer" => Ok(FieldType::Integer(
                 schema_forge_core::types::IntegerConstraints::unconstrained(),
             )),
@@ -296,6 +307,9 @@ fn parse_field_type(value: &serde_json::Value) -> Result<FieldType, ForgeError>
             )),
             "Boolean" => Ok(FieldType::Boolean),
             "DateTime" => Ok(FieldType::DateTime),
+            "Duration" => Ok(FieldType::Duration),
+            "Uuid" => Ok(FieldType::Uuid),
+            "Decimal" => Ok(FieldType::Decimal { scale: 2 }),
             "Json" => Ok(FieldType::Json),
             other => Err(ForgeError::ValidationFailed {
                 details: vec![format!("unknown field type '{other}'")],
@@ -308,7 +322,7 @@ fn parse_field_type(value: &serde_json::Value) -> Result<FieldType, ForgeError>
         if let Some(type_str) = obj.get("type").and_then(|v| v.as_str()) {
             return match type_str {
                 "Text" => Ok(FieldType::Text(TextConstraints::unconstrained())),
-                "RichText" => Ok(FieldType::RichText),
+                "RichText" => Ok(FieldType::rich_text()),
                 "Integer" => Ok(FieldType::Integer(
                     schema_forge_core::types::IntegerConstraints::unconstrained(),
                 )),
@@ -317,6 +331,24 @@ fn parse_field_type(value: &serde_json::Value) -> Result<FieldType, ForgeError>
                 )),
                 "Boolean" => Ok(FieldType::Boolean),
                 "DateTime" => Ok(FieldType::DateTime),
+                "Duration" => Ok(FieldType::Duration),
+                "Uuid" => Ok(FieldType::Uuid),
+                "Decimal" => {
+                    let scale = obj
+                        .get("data")
+                        .and_then(|d| d.get("scale"))
+                        .map_or(Some(2), |s| s.as_u64());
+                    match scale.and_then(|s| u8::try_from(s).ok()) {
+                        Some(scale) if scale <= MAX_DECIMAL_SCALE => {
+                            Ok(FieldType::Decimal { scale })
+                        }
+                        _ => Err(ForgeError::ValidationFailed {
+                            details: vec![format!(
+                                "decimal scale must be an integer from 0 to {MAX_DECIMAL_SCALE}"
+                            )],
+                        }),
+                    }
+                }
                 "Json" => Ok(FieldType::Json),
                 other => Err(ForgeError::ValidationFailed {
                     details: vec![format!("unknown field type '{other}'")],
@@ -330,6 +362,24 @@ fn parse_field_type(value: &serde_json::Value) -> Result<FieldType, ForgeError>
     })
 }
 
+/// Keep the `@version` annotation from a request's annotation list, in the
+/// same JSON shape `SchemaResponse` emits (`{"annotation": "Version",
+/// "version": 2}`). Other annotations are not accepted over REST.
+fn request_annotations(annotations: &[serde_json::Value]) -> Result<Vec<Annotation>, ForgeError> {
+    let mut kept = Vec::new();
+    for value in annotations {
+        if value.get("annotation").and_then(|v| v.as_str()) != Some("Version") {
+            continue;
+        }
+        let annotation: Annotation =
+            serde_json::from_value(value.clone()).map_err(|e| ForgeError::ValidationFailed {
+                details: vec![format!("invalid version annotation: {e}")],
+            })?;
+        kept.push(annotation);
+    }
+    Ok(kept)
+}
+
 /// Project a `FieldDefinition` to a `FieldResponse`, walking `Composite`
 /// and `Array` recursively so every level uses the same string-modifier
 /// projection as the top level.
@@ -362,11 +412,17 @@ fn field_type_to_json(field_type: &FieldType) -> serde_json::Value {
                 "data": sub_responses,
             })
         }
-        FieldType::Array(inner) => {
-            serde_json::json!({
+        FieldType::Array(inner, constraints) => {
+            let mut out = serde_json::json!({
                 "type": "Array",
                 "data": field_type_to_json(inner),
-            })
+            });
+            // Element-count bounds ride alongside `data` so clients that
+            // only read the element type keep working.
+            if !constraints.is_unconstrained() {
+                out["constraints"] = serde_json::to_value(constraints).unwrap_or_default();
+            }
+            out
         }
         other => serde_json::to_value(other).unwrap_or_default(),
     }
@@ -407,7 +463,7 @@ pub async fn create_schema(
     Json(body): Json<CreateSchemaRequest>,
 ) -> Result<impl IntoResponse, ForgeError> {
     let claims = require_auth(&claims)?;
-    if let Err(e) = require_admin(claims) {
+    if let Err(e) = require_admin(claims, &body.name) {
         if let Some(logger) = state.audit_logger() {
             logger
                 .log_custom(
@@ -465,7 +521,7 @@ pub async fn create_schema(
         schema_id,
         schema_name.clone(),
         fields,
-        Vec::<Annotation>::new(),
+        request_annotations(&body.annotations)?,
     )
     .map_err(|e| ForgeError::ValidationFailed {
         details: vec![e.to_string()],
@@ -629,16 +685,50 @@ pub async fn get_schema(
     Ok(Json(response))
 }
 
+/// GET /openapi.json -- OpenAPI 3.1 document for the entity routes of every
+/// schema the caller can read.
+///
+/// Built from the registry on each request, so schema changes show up
+/// immediately. Paths are rooted wherever the router is mounted, taken
+/// from the request URI.
+#[instrument(skip_all)]
+pub async fn get_openapi(
+    State(state): State<AppState<SchemaForgeConfig>>,
+    OriginalUri(uri): OriginalUri,
+    OptionalClaims(claims): OptionalClaims,
+) -> Result<impl IntoResponse, ForgeError> {
+    let claims = require_auth(&claims)?;
+    let forge = state
+        .actor::<ForgeActor>()
+        .expect("ForgeActor not registered");
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(ListSchemas {
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    let mut schemas = ask_forge(rx).await?;
+    let policy_store = fetch_policy_store(&state).await?;
+    schemas.retain(|schema| {
+        check_schema_access(&policy_store, schema, Some(claims), AccessAction::Read).is_ok()
+    });
+
+    let base_path = uri.path().trim_end_matches("/openapi.json");
+    Ok(Json(crate::openapi::build_openapi(&schemas, base_path)))
+}
+
 /// PUT /schemas/{name} -- Update an existing schema (triggers migration). Requires platform_admin role.
 #[instrument(skip_all)]
 pub async fn update_schema(
     State(state): State<AppState<SchemaForgeConfig>>,
     Path(name): Path<String>,
+    Query(params): Query<UpdateSchemaParams>,
     OptionalClaims(claims): OptionalClaims,
     Json(body): Json<CreateSchemaRequest>,
 ) -> Result<impl IntoResponse, ForgeError> {
     let claims = require_auth(&claims)?;
-    if let Err(e) = require_admin(claims) {
+    if let Err(e) = require_admin(claims, &name) {
         if let Some(logger) = state.audit_logger() {
             logger
                 .log_custom(
@@ -702,7 +792,7 @@ pub async fn update_schema(
         old_schema.id.clone(),
         schema_name.clone(),
         fields,
-        Vec::<Annotation>::new(),
+        request_annotations(&body.annotations)?,
     )
     .map_err(|e| ForgeError::ValidationFailed {
         details: vec![e.to_string()],
@@ -720,6 +810,17 @@ pub async fn update_schema(
     // 5. Compute diff and generate migration plan
     let plan = DiffEngine::diff(&old_schema, &new_definition);
 
+    // 5a. Refuse to move `@version` backwards (an out-of-date client would
+    // otherwise undo newer changes) unless the caller passed `?force=true`.
+    if !plan.is_empty() && !params.force {
+        new_definition
+            .check_version_advances(&old_schema)
+            .map_err(|e| ForgeError::Conflict {
+                reason: "schema_version_not_increased",
+                message: e.to_string(),
+            })?;
+    }
+
     // 6. Apply migration steps via actor
     let step_count = plan.steps.len();
     if !plan.is_empty() {
@@ -789,7 +890,7 @@ pub async fn delete_schema(
     OptionalClaims(claims): OptionalClaims,
 ) -> Result<impl IntoResponse, ForgeError> {
     let claims = require_auth(&claims)?;
-    if let Err(e) = require_admin(claims) {
+    if let Err(e) = require_admin(claims, &name) {
         if let Some(logger) = state.audit_logger() {
             logger
                 .log_custom(
@@ -936,11 +1037,12 @@ mod tests {
         let req = FieldDefinitionRequest {
             name: "email".into(),
             field_type: serde_json::json!("Text"),
-            modifiers: vec!["required".into(), "indexed".into()],
+            modifiers: vec!["required".into(), "indexed".into(), "unique".into()],
         };
         let def = request_field_to_definition(&req).unwrap();
         assert!(def.is_required());
         assert!(def.is_indexed());
+        assert!(def.is_unique());
     }
 
     #[test]
@@ -1081,7 +1183,7 @@ mod tests {
             SchemaName::new("Post").unwrap(),
             vec![FieldDefinition::new(
                 FieldName::new("tags").unwrap(),
-                FieldType::Array(Box::new(FieldType::Text(TextConstraints::unconstrained()))),
+                FieldType::array(FieldType::Text(TextConstraints::unconstrained())),
             )],
             vec![],
         )
@@ -1098,6 +1200,25 @@ mod tests {
             .get("data")
             .expect("array data must be present");
         assert_eq!(inner.get("type").and_then(|v| v.as_str()), Some("Text"));
+        assert!(tags.field_type.get("constraints").is_none());
+    }
+
+    #[test]
+    fn schema_to_response_array_exposes_length_constraints() {
+        let field = FieldDefinition::new(
+            FieldName::new("tags").unwrap(),
+            FieldType::Array(
+                Box::new(FieldType::Text(TextConstraints::unconstrained())),
+                schema_forge_core::types::ArrayConstraints::with_bounds(Some(1), Some(10)).unwrap(),
+            ),
+        );
+
+        let response = field_definition_to_response(&field);
+        assert_eq!(response.field_type["data"]["type"].as_str(), Some("Text"));
+        assert_eq!(
+            response.field_type["constraints"],
+            serde_json::json!({"min_items": 1, "max_items": 10})
+        );
     }
 
     #[test]
@@ -1114,6 +1235,8 @@ mod tests {
                 },
                 FieldAnnotation::Format {
                     format_type: FormatType::Currency,
+                    locale: None,
+                    currency: None,
                 },
             ],
         );
@@ -1216,7 +1339,7 @@ mod tests {
             SchemaName::new("Post").unwrap(),
             vec![FieldDefinition::new(
                 FieldName::new("categories").unwrap(),
-                FieldType::Array(Box::new(inner_composite)),
+                FieldType::array(inner_composite),
             )],
             vec![],
         )
@@ -41,6 +41,7 @@ use crate::access::{
     check_schema_access, AccessAction, OptionalClaims, PLATFORM_ADMIN_ROLE,
 };
 use crate::actor::ForgeActor;
+use crate::api_key::check_api_key_admin_scope;
 use crate::authz::engine::authorize;
 use crate::authz::namespace::ActionVerb;
 use crate::authz::PolicyStore;
@@ -65,6 +66,14 @@ fn require_auth(claims: &Option<Claims>) -> Result<&Claims, ForgeError> {
     })
 }
 
+/// Require an `admin` scope on `User` for API-key principals.
+///
+/// User writes are authorized per target via Cedar rather than through
+/// [`check_schema_access`], so they must consult key scopes explicitly: a
+/// key acting as `platform_admin` but scoped to other schemas is refused.
+fn require_user_admin_scope(claims: &Claims) -> Result<(), ForgeError> {
+    check_api_key_admin_scope(Some(claims), "User")
+}
 
 /// Fetch the User schema definition from the registry.
 async fn fetch_user_schema(
@@ -452,6 +461,7 @@ pub async fn create_user(
     Json(body): Json<CreateUserRequest>,
 ) -> Result<impl IntoResponse, ForgeError> {
     let claims = require_auth(&claims)?;
+    require_user_admin_scope(claims)?;
 
     let user_schema = fetch_user_schema(&state).await?;
     let policy_store = fetch_policy_store(&state).await?;
@@ -548,6 +558,7 @@ pub async fn delete_user(
     OptionalClaims(claims): OptionalClaims,
 ) -> Result<impl IntoResponse, ForgeError> {
     let claims = require_auth(&claims)?;
+    require_user_admin_scope(claims)?;
 
     let prefixed = format!("user:{username}");
     if claims.sub == prefixed || claims.sub == username {
@@ -630,6 +641,7 @@ pub async fn update_user(
     Json(body): Json<UpdateUserRequest>,
 ) -> Result<impl IntoResponse, ForgeError> {
     let claims = require_auth(&claims)?;
+    require_user_admin_scope(claims)?;
     let user_schema = fetch_user_schema(&state).await?;
     let policy_store = fetch_policy_store(&state).await?;
 
@@ -764,6 +776,7 @@ pub async fn change_password(
     Json(body): Json<ChangePasswordRequest>,
 ) -> Result<impl IntoResponse, ForgeError> {
     let claims = require_auth(&claims)?;
+    require_user_admin_scope(claims)?;
 
     validate_password(&body.password)?;
 
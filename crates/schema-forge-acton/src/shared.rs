@@ -76,8 +76,17 @@ pub async fn resolve_ref_display(
         let mut query = schema_forge_core::query::Query::new(target_schema.id.clone())
             .with_filter(Filter::in_set(FieldPath::single("id"), id_values));
 
-        // Project only the display field to minimize data transfer
-        if let Some(ref df) = display_field {
+        // Project only the label fields to minimize data transfer
+        if let Some(template) = target_schema.ref_label() {
+            query = query.with_projection(
+                template
+                    .fields()
+                    .into_iter()
+                    .filter(|f| target_schema.field(f).is_some())
+                    .map(str::to_string)
+                    .collect(),
+            );
+        } else if let Some(ref df) = display_field {
             query = query.with_projection(vec![df.clone()]);
         }
 
@@ -96,8 +105,8 @@ pub async fn resolve_ref_display(
     ref_display
 }
 
-/// Resolve a human-readable label for an entity, using the display field
-/// annotation or falling back to the first text field.
+/// Resolve a human-readable label for an entity, using the `@ref_label`
+/// template, then the display field annotation, then the first text field.
 fn resolve_entity_label(
     entity: &Entity,
     schema: &SchemaDefinition,
@@ -105,6 +114,15 @@ fn resolve_entity_label(
 ) -> String {
     let id_str = entity.id.as_str().to_string();
 
+    if let Some(template) = schema.ref_label() {
+        return template.render(&id_str, |f| {
+            entity.field(f).map(|v| match v {
+                DynamicValue::Text(s) => s.clone(),
+                other => other.to_string(),
+            })
+        });
+    }
+
     if let Some(df) = display_field {
         return entity
             .field(df)
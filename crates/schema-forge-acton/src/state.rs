@@ -1,15 +1,16 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 
+use futures::Stream;
 use schema_forge_backend::entity::{Entity, QueryResult};
 use schema_forge_backend::error::BackendError;
 use schema_forge_backend::traits::{EntityStore, SchemaBackend};
 use schema_forge_backend::user_store::{AuthStore, ForgeUser};
 use schema_forge_core::migration::MigrationStep;
 use schema_forge_core::query::{AggregateQuery, AggregateResult, Query};
-use schema_forge_core::types::{EntityId, SchemaDefinition, SchemaName};
+use schema_forge_core::types::{DynamicValue, EntityId, FieldName, SchemaDefinition, SchemaName};
 use sync_wrapper::SyncFuture;
 use tokio::sync::RwLock;
 
@@ -109,6 +110,12 @@ pub trait DynEntityStore: Send + Sync {
         entity: &'a Entity,
     ) -> Pin<Box<dyn Future<Output = Result<Entity, BackendError>> + Send + Sync + 'a>>;
 
+    /// Create several entities atomically.
+    fn create_many<'a>(
+        &'a self,
+        entities: &'a [Entity],
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Entity>, BackendError>> + Send + Sync + 'a>>;
+
     /// Retrieve an entity by schema name and entity ID.
     fn get<'a>(
         &'a self,
@@ -116,10 +123,20 @@ pub trait DynEntityStore: Send + Sync {
         id: &'a EntityId,
     ) -> Pin<Box<dyn Future<Output = Result<Entity, BackendError>> + Send + Sync + 'a>>;
 
-    /// Update an existing entity.
+    /// Update an existing entity, optionally guarded by its expected version.
     fn update<'a>(
         &'a self,
         entity: &'a Entity,
+        expected_version: Option<u64>,
+    ) -> Pin<Box<dyn Future<Output = Result<Entity, BackendError>> + Send + Sync + 'a>>;
+
+    /// Merge the given field changes into an existing entity.
+    fn patch<'a>(
+        &'a self,
+        schema: &'a SchemaName,
+        id: &'a EntityId,
+        changes: BTreeMap<String, DynamicValue>,
+        expected_version: Option<u64>,
     ) -> Pin<Box<dyn Future<Output = Result<Entity, BackendError>> + Send + Sync + 'a>>;
 
     /// Delete an entity by schema name and entity ID.
@@ -129,12 +146,34 @@ pub trait DynEntityStore: Send + Sync {
         id: &'a EntityId,
     ) -> Pin<Box<dyn Future<Output = Result<(), BackendError>> + Send + Sync + 'a>>;
 
+    /// Clear the soft-delete stamp of an entity.
+    fn restore<'a>(
+        &'a self,
+        schema: &'a SchemaName,
+        id: &'a EntityId,
+    ) -> Pin<Box<dyn Future<Output = Result<Entity, BackendError>> + Send + Sync + 'a>>;
+
     /// Execute a query and return matching entities.
     fn query<'a>(
         &'a self,
         query: &'a Query,
     ) -> Pin<Box<dyn Future<Output = Result<QueryResult, BackendError>> + Send + Sync + 'a>>;
 
+    /// Stream every entity matching a query in bounded batches.
+    ///
+    /// Unlike the futures above, the stream is only `Send`: it is consumed
+    /// by route handlers and CLI commands, never inside an `act_on` body.
+    fn query_stream<'a>(
+        &'a self,
+        query: &'a Query,
+    ) -> Pin<Box<dyn Stream<Item = Result<Entity, BackendError>> + Send + 'a>>;
+
+    /// Delete every entity matching a query; returns how many were deleted.
+    fn delete_by_query<'a>(
+        &'a self,
+        query: &'a Query,
+    ) -> Pin<Box<dyn Future<Output = Result<usize, BackendError>> + Send + Sync + 'a>>;
+
     /// Count entities matching a query (ignoring limit/offset).
     fn count<'a>(
         &'a self,
@@ -146,6 +185,14 @@ pub trait DynEntityStore: Send + Sync {
         &'a self,
         query: &'a AggregateQuery,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<AggregateResult>, BackendError>> + Send + Sync + 'a>>;
+
+    /// Atomically advance a field's sequence counter and return the new value.
+    fn next_sequence_value<'a>(
+        &'a self,
+        schema: &'a SchemaName,
+        field: &'a FieldName,
+        start: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<i64, BackendError>> + Send + Sync + 'a>>;
 }
 
 /// Blanket impl: any concrete `EntityStore` automatically implements `DynEntityStore`.
@@ -166,6 +213,13 @@ impl<T: EntityStore + 'static> DynEntityStore for T {
         Box::pin(SyncFuture::new(EntityStore::create(self, entity)))
     }
 
+    fn create_many<'a>(
+        &'a self,
+        entities: &'a [Entity],
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Entity>, BackendError>> + Send + Sync + 'a>> {
+        Box::pin(SyncFuture::new(EntityStore::create_many(self, entities)))
+    }
+
     fn get<'a>(
         &'a self,
         schema: &'a SchemaName,
@@ -177,8 +231,29 @@ impl<T: EntityStore + 'static> DynEntityStore for T {
     fn update<'a>(
         &'a self,
         entity: &'a Entity,
+        expected_version: Option<u64>,
     ) -> Pin<Box<dyn Future<Output = Result<Entity, BackendError>> + Send + Sync + 'a>> {
-        Box::pin(SyncFuture::new(EntityStore::update(self, entity)))
+        Box::pin(SyncFuture::new(EntityStore::update(
+            self,
+            entity,
+            expected_version,
+        )))
+    }
+
+    fn patch<'a>(
+        &'a self,
+        schema: &'a SchemaName,
+        id: &'a EntityId,
+        changes: BTreeMap<String, DynamicValue>,
+        expected_version: Option<u64>,
+    ) -> Pin<Box<dyn Future<Output = Result<Entity, BackendError>> + Send + Sync + 'a>> {
+        Box::pin(SyncFuture::new(EntityStore::patch(
+            self,
+            schema,
+            id,
+            changes,
+            expected_version,
+        )))
     }
 
     fn delete<'a>(
@@ -189,6 +264,14 @@ impl<T: EntityStore + 'static> DynEntityStore for T {
         Box::pin(SyncFuture::new(EntityStore::delete(self, schema, id)))
     }
 
+    fn restore<'a>(
+        &'a self,
+        schema: &'a SchemaName,
+        id: &'a EntityId,
+    ) -> Pin<Box<dyn Future<Output = Result<Entity, BackendError>> + Send + Sync + 'a>> {
+        Box::pin(SyncFuture::new(EntityStore::restore(self, schema, id)))
+    }
+
     fn query<'a>(
         &'a self,
         query: &'a Query,
@@ -196,6 +279,20 @@ impl<T: EntityStore + 'static> DynEntityStore for T {
         Box::pin(SyncFuture::new(EntityStore::query(self, query)))
     }
 
+    fn query_stream<'a>(
+        &'a self,
+        query: &'a Query,
+    ) -> Pin<Box<dyn Stream<Item = Result<Entity, BackendError>> + Send + 'a>> {
+        Box::pin(EntityStore::query_stream(self, query))
+    }
+
+    fn delete_by_query<'a>(
+        &'a self,
+        query: &'a Query,
+    ) -> Pin<Box<dyn Future<Output = Result<usize, BackendError>> + Send + Sync + 'a>> {
+        Box::pin(SyncFuture::new(EntityStore::delete_by_query(self, query)))
+    }
+
     fn count<'a>(
         &'a self,
         query: &'a Query,
@@ -210,6 +307,17 @@ impl<T: EntityStore + 'static> DynEntityStore for T {
     {
         Box::pin(SyncFuture::new(EntityStore::aggregate(self, query)))
     }
+
+    fn next_sequence_value<'a>(
+        &'a self,
+        schema: &'a SchemaName,
+        field: &'a FieldName,
+        start: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<i64, BackendError>> + Send + Sync + 'a>> {
+        Box::pin(SyncFuture::new(EntityStore::next_sequence_value(
+            self, schema, field, start,
+        )))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -402,16 +510,25 @@ impl SchemaRegistry {
         }
     }
 
-    /// Get a schema definition by name.
+    /// Get a schema definition by name, cloning only that definition.
+    ///
+    /// Prefer this over [`list`](Self::list) when a single schema is needed.
     pub async fn get(&self, name: &str) -> Option<SchemaDefinition> {
         let guard = self.inner.read().await;
         guard.get(name).cloned()
     }
 
-    /// List all schema definitions in the registry.
+    /// List all schema definitions in the registry, ordered by name.
+    ///
+    /// The result is a point-in-time snapshot: it is collected under a single
+    /// read lock, so a concurrent `insert` or `remove` is either fully
+    /// reflected or not at all.
     pub async fn list(&self) -> Vec<SchemaDefinition> {
         let guard = self.inner.read().await;
-        guard.values().cloned().collect()
+        let mut schemas: Vec<SchemaDefinition> = guard.values().cloned().collect();
+        drop(guard);
+        schemas.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+        schemas
     }
 
     /// Insert or update a schema definition in the cache.
@@ -480,6 +597,12 @@ pub struct ForgeState {
     /// Registry of S3-compatible storage backends bound to their `bucket:` name.
     /// Empty when no `[schema_forge.storage]` config is provided.
     pub storage_registry: crate::storage::StorageRegistry,
+    /// Optional sink for entity-write audit events.
+    pub audit_sink: Option<Arc<dyn crate::audit::AuditSink>>,
+    /// In-process hooks run around every entity write.
+    pub entity_hooks: crate::entity_hooks::EntityHooks,
+    /// Change feed that GraphQL mutations publish their writes to.
+    pub change_feed: crate::events::ChangeFeed,
 }
 
 #[cfg(test)]
@@ -520,6 +643,52 @@ mod tests {
         assert_eq!(list.len(), 2);
     }
 
+    #[tokio::test]
+    async fn registry_list_is_ordered_by_name() {
+        let registry = SchemaRegistry::new();
+        for name in ["Invoice", "Company", "Contact"] {
+            registry
+                .insert(name.to_string(), make_test_schema(name))
+                .await;
+        }
+        let names: Vec<String> = registry
+            .list()
+            .await
+            .iter()
+            .map(|s| s.name.as_str().to_string())
+            .collect();
+        assert_eq!(names, vec!["Company", "Contact", "Invoice"]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn registry_list_snapshot_not_torn_by_concurrent_insert() {
+        let registry = SchemaRegistry::new();
+        let writer = {
+            let registry = registry.clone();
+            tokio::spawn(async move {
+                for i in 0..200 {
+                    let name = format!("Schema{i:03}");
+                    registry.insert(name.clone(), make_test_schema(&name)).await;
+                }
+            })
+        };
+
+        let mut last_len = 0;
+        while !writer.is_finished() {
+            let list = registry.list().await;
+            // Inserts happen in name order, so a consistent snapshot is
+            // always a contiguous prefix of the final list.
+            for (i, schema) in list.iter().enumerate() {
+                assert_eq!(schema.name.as_str(), format!("Schema{i:03}"));
+            }
+            assert!(list.len() >= last_len, "snapshot went backwards");
+            last_len = list.len();
+            tokio::task::yield_now().await;
+        }
+        writer.await.unwrap();
+        assert_eq!(registry.list().await.len(), 200);
+    }
+
     #[tokio::test]
     async fn registry_remove() {
         let registry = SchemaRegistry::new();
@@ -67,42 +67,59 @@ pub async fn seed_system_schemas_into_map(
     registry: &mut HashMap<String, SchemaDefinition>,
     backend: &dyn DynForgeBackend,
 ) -> Result<(), ForgeError> {
-    let dsl_texts = system_schemas::all_system_schemas();
-
-    for dsl_text in dsl_texts {
-        let definitions =
-            schema_forge_dsl::parse(dsl_text).map_err(|errors| ForgeError::Internal {
-                message: format!("Failed to parse system schema: {:?}", errors),
-            })?;
-
-        for definition in definitions {
-            let name = definition.name.clone();
-            let name_str = name.as_str().to_string();
+    for dsl_text in system_schemas::all_system_schemas() {
+        seed_dsl_into_map(dsl_text, registry, backend).await?;
+    }
+    Ok(())
+}
 
-            let existing = backend
-                .load_schema_metadata(&name)
-                .await
-                .map_err(ForgeError::from)?;
+/// Seed the opt-in `AuditLog` system schema into the registry map.
+///
+/// Not part of [`system_schemas::all_system_schemas`]: only deployments that
+/// install [`crate::audit::AuditLogSink`] need the table. Idempotent.
+pub async fn seed_audit_log_schema_into_map(
+    registry: &mut HashMap<String, SchemaDefinition>,
+    backend: &dyn DynForgeBackend,
+) -> Result<(), ForgeError> {
+    seed_dsl_into_map(system_schemas::AUDIT_LOG_SCHEMA, registry, backend).await
+}
 
-            if let Some(existing_def) = existing {
-                registry.insert(name_str, existing_def);
-                continue;
-            }
+async fn seed_dsl_into_map(
+    dsl_text: &str,
+    registry: &mut HashMap<String, SchemaDefinition>,
+    backend: &dyn DynForgeBackend,
+) -> Result<(), ForgeError> {
+    let definitions = schema_forge_dsl::parse(dsl_text).map_err(|errors| ForgeError::Internal {
+        message: format!("Failed to parse system schema: {:?}", errors),
+    })?;
+
+    for definition in definitions {
+        let name = definition.name.clone();
+        let name_str = name.as_str().to_string();
+
+        let existing = backend
+            .load_schema_metadata(&name)
+            .await
+            .map_err(ForgeError::from)?;
+
+        if let Some(existing_def) = existing {
+            registry.insert(name_str, existing_def);
+            continue;
+        }
 
-            let plan = DiffEngine::create_new(&definition);
+        let plan = DiffEngine::create_new(&definition);
 
-            backend
-                .apply_migration(&name, &plan.steps)
-                .await
-                .map_err(ForgeError::from)?;
+        backend
+            .apply_migration(&name, &plan.steps)
+            .await
+            .map_err(ForgeError::from)?;
 
-            backend
-                .store_schema_metadata(&definition)
-                .await
-                .map_err(ForgeError::from)?;
+        backend
+            .store_schema_metadata(&definition)
+            .await
+            .map_err(ForgeError::from)?;
 
-            registry.insert(name_str, definition);
-        }
+        registry.insert(name_str, definition);
     }
 
     Ok(())
@@ -163,6 +180,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn audit_log_schema_parses_and_is_opt_in() {
+        let schemas = schema_forge_dsl::parse(system_schemas::AUDIT_LOG_SCHEMA).unwrap();
+        assert_eq!(schemas.len(), 1);
+        assert_eq!(schemas[0].name.as_str(), "AuditLog");
+        assert!(schemas[0]
+            .annotations
+            .iter()
+            .any(|a| matches!(a, Annotation::System)));
+        assert!(!system_schemas::all_system_schemas().contains(&system_schemas::AUDIT_LOG_SCHEMA));
+    }
+
     #[test]
     fn all_system_schemas_returns_three() {
         assert_eq!(system_schemas::all_system_schemas().len(), 3);
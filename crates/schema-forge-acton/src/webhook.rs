@@ -1,5 +1,6 @@
+use std::collections::VecDeque;
 use std::net::IpAddr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use hmac::{Hmac, KeyInit, Mac};
@@ -10,7 +11,8 @@ use serde::Serialize;
 use sha2::Sha256;
 use tracing::{debug, error, warn};
 
-use crate::state::{DynForgeBackend, SchemaRegistry};
+use crate::conversions::entity_to_response;
+use crate::state::DynForgeBackend;
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -27,6 +29,9 @@ pub fn get_dispatcher(config: &WebhookConfig) -> Option<&'static WebhookDispatch
     Some(DISPATCHER.get_or_init(|| WebhookDispatcher::new(config.clone())))
 }
 
+/// Name of the system schema holding runtime webhook subscriptions.
+pub const WEBHOOK_SUBSCRIPTION_SCHEMA: &str = "WebhookSubscription";
+
 /// Valid webhook event types.
 pub const VALID_EVENTS: &[&str] = &["created", "updated", "deleted"];
 
@@ -35,68 +40,80 @@ pub const VALID_EVENTS: &[&str] = &["created", "updated", "deleted"];
 // ---------------------------------------------------------------------------
 
 /// The JSON payload delivered to webhook subscribers.
+///
+/// The body is signed as-is: subscribers verify `X-SchemaForge-Signature`
+/// against the raw request bytes before parsing.
 #[derive(Debug, Clone, Serialize)]
 pub struct WebhookEvent {
     /// Unique delivery ID (UUID v4).
     pub event_id: String,
     /// Event type: `entity.created`, `entity.updated`, or `entity.deleted`.
-    pub event_type: String,
+    pub event: String,
     /// Schema name the entity belongs to.
     pub schema: String,
-    /// Entity ID.
-    pub entity_id: String,
+    /// The entity as `{"id": ..., "fields": {...}}`. Deletes carry only the id.
+    pub entity: serde_json::Value,
     /// ISO 8601 timestamp.
     pub timestamp: String,
     /// User who triggered the event.
     pub actor: Option<String>,
-    /// Entity fields (present for create/update, absent for delete).
-    pub payload: Option<serde_json::Value>,
 }
 
 impl WebhookEvent {
     /// Build an event from a create operation.
-    pub fn from_create(schema: &str, entity: &Entity, actor: Option<&str>) -> Self {
-        Self {
-            event_id: uuid::Uuid::new_v4().to_string(),
-            event_type: "entity.created".to_string(),
-            schema: schema.to_string(),
-            entity_id: entity.id.as_str().to_string(),
-            timestamp: now_iso8601(),
-            actor: actor.map(String::from),
-            payload: Some(entity_fields_to_json(entity)),
-        }
+    pub fn from_create(schema: &SchemaDefinition, entity: &Entity, actor: Option<&str>) -> Self {
+        Self::new(
+            "entity.created",
+            schema.name.as_str(),
+            entity_to_json(entity, schema),
+            actor,
+        )
     }
 
     /// Build an event from an update operation.
-    pub fn from_update(schema: &str, entity: &Entity, actor: Option<&str>) -> Self {
-        Self {
-            event_id: uuid::Uuid::new_v4().to_string(),
-            event_type: "entity.updated".to_string(),
-            schema: schema.to_string(),
-            entity_id: entity.id.as_str().to_string(),
-            timestamp: now_iso8601(),
-            actor: actor.map(String::from),
-            payload: Some(entity_fields_to_json(entity)),
-        }
+    pub fn from_update(schema: &SchemaDefinition, entity: &Entity, actor: Option<&str>) -> Self {
+        Self::new(
+            "entity.updated",
+            schema.name.as_str(),
+            entity_to_json(entity, schema),
+            actor,
+        )
     }
 
-    /// Build an event from a delete operation (no payload — entity is gone).
+    /// Build an event from a delete operation (id only — entity is gone).
     pub fn from_delete(schema: &str, entity_id: &str, actor: Option<&str>) -> Self {
+        Self::new(
+            "entity.deleted",
+            schema,
+            serde_json::json!({ "id": entity_id }),
+            actor,
+        )
+    }
+
+    fn new(event: &str, schema: &str, entity: serde_json::Value, actor: Option<&str>) -> Self {
         Self {
             event_id: uuid::Uuid::new_v4().to_string(),
-            event_type: "entity.deleted".to_string(),
+            event: event.to_string(),
             schema: schema.to_string(),
-            entity_id: entity_id.to_string(),
+            entity,
             timestamp: now_iso8601(),
             actor: actor.map(String::from),
-            payload: None,
         }
     }
+
+    /// The entity ID this event refers to.
+    pub fn entity_id(&self) -> Option<&str> {
+        self.entity.get("id").and_then(|v| v.as_str())
+    }
 }
 
-/// Convert entity fields to a JSON value.
-fn entity_fields_to_json(entity: &Entity) -> serde_json::Value {
-    serde_json::to_value(&entity.fields).unwrap_or(serde_json::Value::Null)
+/// Convert an entity to its `{"id", "fields"}` JSON form, omitting
+/// `@hidden` fields exactly as REST responses do.
+fn entity_to_json(entity: &Entity, schema: &SchemaDefinition) -> serde_json::Value {
+    serde_json::json!({
+        "id": entity.id.as_str(),
+        "fields": entity_to_response(entity, schema).fields,
+    })
 }
 
 /// Get current UTC time as RFC 3339 string.
@@ -134,6 +151,11 @@ pub struct WebhookConfig {
     /// Allowed URL schemes (default: `["https"]`).
     #[serde(default = "default_allowed_schemes")]
     pub allowed_url_schemes: Vec<String>,
+
+    /// Number of undeliverable events retained in the dead-letter log
+    /// (default: 1000). Oldest entries are evicted first.
+    #[serde(default = "default_dead_letter_capacity")]
+    pub dead_letter_capacity: usize,
 }
 
 fn default_retry_count() -> u32 {
@@ -148,6 +170,9 @@ fn default_max_concurrent() -> usize {
 fn default_allowed_schemes() -> Vec<String> {
     vec!["https".to_string()]
 }
+fn default_dead_letter_capacity() -> usize {
+    1000
+}
 
 impl Default for WebhookConfig {
     fn default() -> Self {
@@ -158,6 +183,7 @@ impl Default for WebhookConfig {
             max_concurrent_deliveries: default_max_concurrent(),
             signing_secret: None,
             allowed_url_schemes: default_allowed_schemes(),
+            dead_letter_capacity: default_dead_letter_capacity(),
         }
     }
 }
@@ -180,6 +206,71 @@ pub struct ResolvedSubscription {
     pub timeout_seconds: Option<u32>,
 }
 
+// ---------------------------------------------------------------------------
+// Dead-letter log
+// ---------------------------------------------------------------------------
+
+/// An event that could not be delivered to a subscriber.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookDeadLetter {
+    /// Subscriber URL the delivery was addressed to.
+    pub url: String,
+    /// The undelivered event.
+    pub event: WebhookEvent,
+    /// Number of delivery attempts made.
+    pub attempts: u32,
+    /// Description of the final failure.
+    pub last_error: String,
+}
+
+/// Bounded in-memory log of undeliverable events.
+///
+/// Each entry is also emitted at `error` level under the
+/// `forge::webhook::dead_letter` target so log pipelines retain the full
+/// record after eviction.
+#[derive(Debug, Clone)]
+struct DeadLetterLog {
+    entries: Arc<Mutex<VecDeque<WebhookDeadLetter>>>,
+    capacity: usize,
+}
+
+impl DeadLetterLog {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(VecDeque::new())),
+            capacity,
+        }
+    }
+
+    fn push(&self, letter: WebhookDeadLetter) {
+        error!(
+            target: "forge::webhook::dead_letter",
+            url = %letter.url,
+            event_id = %letter.event.event_id,
+            event = %letter.event.event,
+            schema = %letter.event.schema,
+            entity_id = letter.event.entity_id().unwrap_or_default(),
+            attempts = letter.attempts,
+            last_error = %letter.last_error,
+            payload = %serde_json::to_string(&letter.event).unwrap_or_default(),
+            "webhook moved to dead-letter log"
+        );
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(letter);
+    }
+
+    fn snapshot(&self) -> Vec<WebhookDeadLetter> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.iter().cloned().collect()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // WebhookDispatcher
 // ---------------------------------------------------------------------------
@@ -187,12 +278,15 @@ pub struct ResolvedSubscription {
 /// Non-blocking webhook delivery engine.
 ///
 /// Spawns background `tokio` tasks for each delivery, with retry and
-/// exponential backoff. Never blocks the calling HTTP handler.
+/// exponential backoff. Never blocks the calling HTTP handler. Deliveries
+/// that exhaust their retries (or are rejected with a 4xx) land in the
+/// dead-letter log, readable via [`WebhookDispatcher::dead_letters`].
 #[derive(Clone)]
 pub struct WebhookDispatcher {
     client: reqwest::Client,
     config: WebhookConfig,
     semaphore: Arc<tokio::sync::Semaphore>,
+    dead_letters: DeadLetterLog,
 }
 
 impl WebhookDispatcher {
@@ -201,13 +295,20 @@ impl WebhookDispatcher {
         let semaphore = Arc::new(tokio::sync::Semaphore::new(
             config.max_concurrent_deliveries,
         ));
+        let dead_letters = DeadLetterLog::new(config.dead_letter_capacity);
         Self {
             client: reqwest::Client::new(),
             config,
             semaphore,
+            dead_letters,
         }
     }
 
+    /// Snapshot of the dead-letter log, oldest first.
+    pub fn dead_letters(&self) -> Vec<WebhookDeadLetter> {
+        self.dead_letters.snapshot()
+    }
+
     /// Fire-and-forget: spawn a background delivery task for each subscription.
     ///
     /// Returns immediately — webhook delivery never blocks the API response.
@@ -217,6 +318,7 @@ impl WebhookDispatcher {
             let event = event.clone();
             let config = self.config.clone();
             let semaphore = self.semaphore.clone();
+            let dead_letters = self.dead_letters.clone();
             tokio::spawn(async move {
                 let _permit = match semaphore.acquire().await {
                     Ok(p) => p,
@@ -225,7 +327,14 @@ impl WebhookDispatcher {
                         return;
                     }
                 };
-                deliver_with_retry(&client, &event, &sub, &config).await;
+                if let Err(failure) = deliver_with_retry(&client, &event, &sub, &config).await {
+                    dead_letters.push(WebhookDeadLetter {
+                        url: sub.url,
+                        event,
+                        attempts: failure.attempts,
+                        last_error: failure.last_error,
+                    });
+                }
             });
         }
     }
@@ -233,13 +342,14 @@ impl WebhookDispatcher {
     /// Resolve all active subscriptions for a schema + event type.
     ///
     /// Merges inline DSL subscriptions (from `@webhook(url: "...")`) with
-    /// runtime `WebhookSubscription` entities from the database.
+    /// runtime `WebhookSubscription` entities from the database, queried
+    /// through `subscription_schema` when that system schema is registered.
     pub async fn resolve_subscriptions(
         &self,
         schema_def: &SchemaDefinition,
         event_type: &str,
         backend: &dyn DynForgeBackend,
-        registry: &SchemaRegistry,
+        subscription_schema: Option<&SchemaDefinition>,
     ) -> Vec<ResolvedSubscription> {
         let mut subs = Vec::new();
 
@@ -259,8 +369,11 @@ impl WebhookDispatcher {
         }
 
         // 2. Runtime subscriptions from WebhookSubscription entities
+        let Some(ws_def) = subscription_schema else {
+            return subs;
+        };
         let schema_name = schema_def.name.as_str();
-        match query_webhook_subscriptions(backend, registry, schema_name, event_type).await {
+        match query_webhook_subscriptions(backend, ws_def, schema_name, event_type).await {
             Ok(runtime_subs) => subs.extend(runtime_subs),
             Err(e) => {
                 warn!(
@@ -279,13 +392,19 @@ impl WebhookDispatcher {
 // Delivery internals
 // ---------------------------------------------------------------------------
 
+/// Why a delivery gave up.
+struct DeliveryFailure {
+    attempts: u32,
+    last_error: String,
+}
+
 /// Deliver a webhook event with exponential backoff retry.
 async fn deliver_with_retry(
     client: &reqwest::Client,
     event: &WebhookEvent,
     subscription: &ResolvedSubscription,
     config: &WebhookConfig,
-) {
+) -> Result<(), DeliveryFailure> {
     let max_retries = subscription
         .retry_count
         .unwrap_or(config.default_retry_count);
@@ -299,12 +418,16 @@ async fn deliver_with_retry(
         Ok(b) => b,
         Err(e) => {
             error!(error = %e, "failed to serialize webhook event");
-            return;
+            return Err(DeliveryFailure {
+                attempts: 0,
+                last_error: format!("serialization failed: {e}"),
+            });
         }
     };
 
     let signature = compute_signature(subscription, config, &body);
 
+    let mut last_error = String::new();
     for attempt in 0..=max_retries {
         if attempt > 0 {
             let backoff = Duration::from_millis(500 * 2u64.pow(attempt - 1));
@@ -314,7 +437,7 @@ async fn deliver_with_retry(
         let mut request = client
             .post(&subscription.url)
             .header("Content-Type", "application/json")
-            .header("X-SchemaForge-Event", &event.event_type)
+            .header("X-SchemaForge-Event", &event.event)
             .header("X-SchemaForge-Delivery", &event.event_id)
             .timeout(timeout)
             .body(body.clone());
@@ -331,7 +454,7 @@ async fn deliver_with_retry(
                     attempt,
                     "webhook delivered"
                 );
-                return;
+                return Ok(());
             }
             Ok(resp) if resp.status().is_client_error() => {
                 // 4xx = misconfigured subscription, don't retry
@@ -339,25 +462,39 @@ async fn deliver_with_retry(
                     url = %subscription.url,
                     status = %resp.status(),
                     event_id = %event.event_id,
+                    event = %event.event,
+                    schema = %event.schema,
+                    entity_id = event.entity_id().unwrap_or_default(),
                     "webhook rejected with client error, not retrying"
                 );
-                return;
+                return Err(DeliveryFailure {
+                    attempts: attempt + 1,
+                    last_error: format!("rejected with status {}", resp.status()),
+                });
             }
             Ok(resp) => {
                 warn!(
                     url = %subscription.url,
                     status = %resp.status(),
+                    event = %event.event,
+                    schema = %event.schema,
+                    entity_id = event.entity_id().unwrap_or_default(),
                     attempt,
                     "webhook delivery failed with server error"
                 );
+                last_error = format!("server error {}", resp.status());
             }
             Err(e) => {
                 warn!(
                     url = %subscription.url,
                     error = %e,
+                    event = %event.event,
+                    schema = %event.schema,
+                    entity_id = event.entity_id().unwrap_or_default(),
                     attempt,
                     "webhook delivery failed"
                 );
+                last_error = e.to_string();
             }
         }
     }
@@ -365,9 +502,16 @@ async fn deliver_with_retry(
     error!(
         url = %subscription.url,
         event_id = %event.event_id,
+        event = %event.event,
+        schema = %event.schema,
+        entity_id = event.entity_id().unwrap_or_default(),
         max_retries,
         "webhook delivery exhausted all retries"
     );
+    Err(DeliveryFailure {
+        attempts: max_retries + 1,
+        last_error,
+    })
 }
 
 /// Compute HMAC-SHA256 signature for the request body.
@@ -395,19 +539,10 @@ fn compute_signature(
 /// Query `WebhookSubscription` entities from the database.
 async fn query_webhook_subscriptions(
     backend: &dyn DynForgeBackend,
-    registry: &SchemaRegistry,
+    ws_def: &SchemaDefinition,
     target_schema: &str,
     event_type: &str,
 ) -> Result<Vec<ResolvedSubscription>, schema_forge_backend::error::BackendError> {
-    // Look up the WebhookSubscription schema to get its ID
-    let ws_def = match registry.get("WebhookSubscription").await {
-        Some(def) => def,
-        None => {
-            // WebhookSubscription schema not registered — no runtime subscriptions
-            return Ok(Vec::new());
-        }
-    };
-
     let target_path =
         FieldPath::parse("target_schema").expect("target_schema is a valid field path");
     let active_path = FieldPath::parse("active").expect("active is a valid field path");
@@ -646,6 +781,7 @@ mod tests {
             max_concurrent_deliveries: 50,
             signing_secret: Some("secret".to_string()),
             allowed_url_schemes: vec!["https".to_string(), "http".to_string()],
+            dead_letter_capacity: 10,
         };
         let json = serde_json::to_string(&config).unwrap();
         let back: WebhookConfig = serde_json::from_str(&json).unwrap();
@@ -654,5 +790,105 @@ mod tests {
         assert_eq!(back.default_timeout_seconds, 15);
         assert_eq!(back.max_concurrent_deliveries, 50);
         assert_eq!(back.signing_secret.as_deref(), Some("secret"));
+        assert_eq!(back.dead_letter_capacity, 10);
+    }
+
+    #[test]
+    fn webhook_event_payload_shape() {
+        let delete = WebhookEvent::from_delete("Contact", "contact_01", Some("user:1"));
+        let json = serde_json::to_value(&delete).unwrap();
+        assert_eq!(json["event"], "entity.deleted");
+        assert_eq!(json["schema"], "Contact");
+        assert_eq!(json["entity"]["id"], "contact_01");
+        assert!(json["timestamp"].is_string());
+        assert_eq!(delete.entity_id(), Some("contact_01"));
+    }
+
+    #[test]
+    fn webhook_event_omits_hidden_fields() {
+        use schema_forge_core::types::{
+            FieldAnnotation, FieldDefinition, FieldName, FieldType, SchemaId, SchemaName,
+            TextConstraints,
+        };
+        use std::collections::BTreeMap;
+
+        let text = || FieldType::Text(TextConstraints::unconstrained());
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("User").unwrap(),
+            vec![
+                FieldDefinition::new(FieldName::new("email").unwrap(), text()),
+                FieldDefinition::with_annotations(
+                    FieldName::new("password_hash").unwrap(),
+                    text(),
+                    vec![],
+                    vec![FieldAnnotation::Hidden],
+                ),
+            ],
+            vec![],
+        )
+        .unwrap();
+        let entity = Entity::new(
+            schema.name.clone(),
+            BTreeMap::from([
+                ("email".to_string(), DynamicValue::Text("a@b.c".into())),
+                ("password_hash".to_string(), DynamicValue::Text("x".into())),
+            ]),
+        );
+
+        for event in [
+            WebhookEvent::from_create(&schema, &entity, None),
+            WebhookEvent::from_update(&schema, &entity, None),
+        ] {
+            let json = serde_json::to_value(&event).unwrap();
+            assert_eq!(json["schema"], "User");
+            assert_eq!(json["entity"]["fields"]["email"], "a@b.c");
+            assert!(json["entity"]["fields"].get("password_hash").is_none());
+        }
+    }
+
+    #[test]
+    fn dead_letter_log_evicts_oldest() {
+        let log = DeadLetterLog::new(2);
+        for i in 0..3 {
+            log.push(WebhookDeadLetter {
+                url: format!("https://example.com/{i}"),
+                event: WebhookEvent::from_delete("Contact", "contact_01", None),
+                attempts: 1,
+                last_error: "boom".into(),
+            });
+        }
+        let urls: Vec<_> = log.snapshot().into_iter().map(|d| d.url).collect();
+        assert_eq!(urls, vec!["https://example.com/1", "https://example.com/2"]);
+    }
+
+    #[tokio::test]
+    async fn unreachable_subscriber_lands_in_dead_letter_log() {
+        let dispatcher = WebhookDispatcher::new(WebhookConfig {
+            enabled: true,
+            default_retry_count: 0,
+            default_timeout_seconds: 1,
+            ..Default::default()
+        });
+        // Port 9 (discard) on loopback is reliably closed in test sandboxes.
+        dispatcher.dispatch(
+            WebhookEvent::from_delete("Contact", "contact_01", None),
+            vec![ResolvedSubscription {
+                url: "http://127.0.0.1:9/hook".to_string(),
+                secret: None,
+                retry_count: None,
+                timeout_seconds: None,
+            }],
+        );
+        for _ in 0..50 {
+            if !dispatcher.dead_letters().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        let letters = dispatcher.dead_letters();
+        assert_eq!(letters.len(), 1);
+        assert_eq!(letters[0].attempts, 1);
+        assert_eq!(letters[0].event.event, "entity.deleted");
     }
 }
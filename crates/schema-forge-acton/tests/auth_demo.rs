@@ -112,6 +112,8 @@ async fn build_test_app_state(
             hook_dispatcher: None,
             storage_registry: schema_forge_acton::storage::StorageRegistry::default(),
             policy_store: None,
+            audit_sink: None,
+            entity_hooks: Default::default(),
             reply: ReplyChannel::new(tx),
         })
         .await;
@@ -532,6 +534,20 @@ async fn demo_record_ownership() {
     assert_eq!(status, StatusCode::FORBIDDEN);
     println!("    Bob DELETE: {} ({})", status, json["error"]);
 
+    // --- Bob tries to bulk delete a match set containing Alice's note ---
+    println!("  Bob tries to bulk delete Alice's note");
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Note/entities/delete",
+        Some(serde_json::json!({"filter": {"content__contains": "private"}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    println!("    Bob BULK DELETE: {} ({})", status, json["error"]);
+    let (status, _) = json_request(&app, Method::GET, &path, None).await;
+    assert_ne!(status, StatusCode::NOT_FOUND);
+
     // --- Admin can modify anyone's note ---
     println!("  Admin overrides ownership check");
     let admin_claims = make_test_claims_with_sub(
@@ -25,14 +25,15 @@ use schema_forge_acton::messages::{
     ApplyMigration, InitForge, InsertSchema, ReplyChannel, StoreSchemaMetadata,
 };
 use schema_forge_acton::routes::forge_routes;
-use schema_forge_acton::ForgeActor;
+use schema_forge_acton::{EntityHook, EntityHookContext, EntityHooks, ForgeActor, ForgeError};
+use schema_forge_backend::entity::Entity;
 use schema_forge_core::migration::DiffEngine;
 use schema_forge_core::types::{
     Annotation, DynamicValue, FieldDefinition, FieldModifier, FieldName, FieldType, HookEvent,
     SchemaDefinition, SchemaId, SchemaName, TextConstraints,
 };
 use schema_forge_surrealdb::SurrealBackend;
-use tokio::sync::oneshot;
+use tokio::sync::{mpsc, oneshot};
 use tower::ServiceExt;
 
 // ---------------------------------------------------------------------------
@@ -103,6 +104,15 @@ fn translation_schema_with_hooks() -> SchemaDefinition {
 async fn setup(
     hooks_config: HooksConfig,
     dispatcher: Option<Arc<MockHookDispatcher>>,
+) -> AppState<SchemaForgeConfig> {
+    setup_with_entity_hooks(hooks_config, dispatcher, EntityHooks::default()).await
+}
+
+/// [`setup`], additionally registering in-process entity hooks.
+async fn setup_with_entity_hooks(
+    hooks_config: HooksConfig,
+    dispatcher: Option<Arc<MockHookDispatcher>>,
+    entity_hooks: EntityHooks,
 ) -> AppState<SchemaForgeConfig> {
     use acton_service::service_builder::ServiceBuilder;
 
@@ -140,6 +150,8 @@ async fn setup(
                 .map(|d| d as Arc<dyn schema_forge_acton::hooks::HookDispatcher>),
             storage_registry: schema_forge_acton::storage::StorageRegistry::default(),
             policy_store: None,
+            audit_sink: None,
+            entity_hooks,
             reply: ReplyChannel::new(tx),
         })
         .await;
@@ -607,6 +619,7 @@ impl schema_forge_acton::hooks::HookDispatcher for WriteBackDispatcher {
         self.forge
             .send(schema_forge_acton::messages::UpdateEntity {
                 entity,
+                expected_version: None,
                 reply: schema_forge_acton::messages::ReplyChannel::new(tx),
             })
             .await;
@@ -695,6 +708,8 @@ async fn after_change_writeback_to_trigger_entity_is_eventually_consistent() {
             hook_dispatcher: Some(writeback),
             storage_registry: schema_forge_acton::storage::StorageRegistry::default(),
             policy_store: None,
+            audit_sink: None,
+            entity_hooks: Default::default(),
             reply: schema_forge_acton::messages::ReplyChannel::new(init_tx),
         })
         .await;
@@ -851,3 +866,80 @@ async fn hook_not_invoked_when_disabled() {
     assert!(dispatcher.before_calls().await.is_empty());
     assert!(dispatcher.after_calls().await.is_empty());
 }
+
+// ---------------------------------------------------------------------------
+// In-process entity hooks
+// ---------------------------------------------------------------------------
+
+/// Fills in `translated_text` on create, refuses sources marked secret, and
+/// reports every created entity's id.
+struct TranslateHook(mpsc::UnboundedSender<String>);
+
+#[async_trait::async_trait]
+impl EntityHook for TranslateHook {
+    async fn before_create(
+        &self,
+        _ctx: EntityHookContext<'_>,
+        fields: &mut std::collections::BTreeMap<String, DynamicValue>,
+    ) -> Result<(), ForgeError> {
+        if fields.get("source_text") == Some(&DynamicValue::Text("secret".into())) {
+            return Err(ForgeError::Forbidden {
+                message: "secret sources cannot be translated".into(),
+            });
+        }
+        fields.insert(
+            "translated_text".into(),
+            DynamicValue::Text("translated".into()),
+        );
+        Ok(())
+    }
+
+    async fn after_create(&self, _ctx: EntityHookContext<'_>, entity: &Entity) {
+        let _ = self.0.send(entity.id.as_str().to_string());
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn entity_hook_mutates_fields_and_observes_create() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut entity_hooks = EntityHooks::default();
+    entity_hooks.register(Arc::new(TranslateHook(tx)));
+    let state = setup_with_entity_hooks(HooksConfig::default(), None, entity_hooks).await;
+    let router = test_router(state);
+
+    let (status, json) = post_entity(
+        &router,
+        "Translation",
+        serde_json::json!({"source_text": "hello"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "response body: {json}");
+    assert_eq!(json["fields"]["translated_text"], "translated");
+
+    let created_id = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("after_create hook did not run")
+        .unwrap();
+    assert_eq!(json["id"], created_id);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn entity_hook_error_aborts_create() {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut entity_hooks = EntityHooks::default();
+    entity_hooks.register(Arc::new(TranslateHook(tx)));
+    let state = setup_with_entity_hooks(HooksConfig::default(), None, entity_hooks).await;
+    let router = test_router(state);
+
+    let (status, _json) = post_entity(
+        &router,
+        "Translation",
+        serde_json::json!({"source_text": "secret"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(
+        rx.try_recv().is_err(),
+        "aborted write must not reach after_create"
+    );
+}
@@ -56,6 +56,14 @@ struct TestForgeInit {
 ///
 /// Must be called from a multi-threaded tokio runtime (ServiceBuilder::build uses block_in_place).
 async fn build_test_app_state(init: TestForgeInit) -> AppState<SchemaForgeConfig> {
+    build_test_app_state_with_audit_sink(init, None).await
+}
+
+/// Like [`build_test_app_state`], additionally installing an audit sink.
+async fn build_test_app_state_with_audit_sink(
+    init: TestForgeInit,
+    audit_sink: Option<Arc<dyn schema_forge_acton::AuditSink>>,
+) -> AppState<SchemaForgeConfig> {
     use acton_service::service_builder::ServiceBuilder;
 
     let config = Config::<SchemaForgeConfig>::default();
@@ -80,6 +88,8 @@ async fn build_test_app_state(init: TestForgeInit) -> AppState<SchemaForgeConfig
             hook_dispatcher: init.hook_dispatcher,
             storage_registry: schema_forge_acton::storage::StorageRegistry::default(),
             policy_store: None,
+            audit_sink,
+            entity_hooks: Default::default(),
             reply: ReplyChannel::new(tx),
         })
         .await;
@@ -280,6 +290,137 @@ async fn update_schema_triggers_migration() {
     assert_eq!(json["fields"].as_array().unwrap().len(), 2);
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn adding_id_prefix_keeps_existing_rows_reachable() {
+    let app = test_app().await;
+
+    let schema = |annotations: serde_json::Value| {
+        serde_json::json!({
+            "name": "Invoice",
+            "fields": [{"name": "number", "field_type": "Text"}],
+            "annotations": annotations
+        })
+    };
+    json_request(
+        &app,
+        Method::POST,
+        "/schemas",
+        Some(schema(serde_json::json!([]))),
+    )
+    .await;
+    let (_, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Invoice/entities",
+        Some(serde_json::json!({ "fields": { "number": "INV-1" } })),
+    )
+    .await;
+    let old_id = created["id"].as_str().unwrap().to_string();
+    assert!(old_id.starts_with("invoice_"), "got: {old_id}");
+
+    let (status, json) = json_request(
+        &app,
+        Method::PUT,
+        "/schemas/Invoice",
+        Some(schema(
+            serde_json::json!([{"annotation": "IdPrefix", "prefix": "inv"}]),
+        )),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+
+    let path = format!("/schemas/Invoice/entities/{old_id}");
+    let (status, json) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+    assert_eq!(json["fields"]["number"], "INV-1");
+    let (status, json) = json_request(
+        &app,
+        Method::PATCH,
+        &path,
+        Some(serde_json::json!({ "fields": { "number": "INV-1A" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+
+    let (_, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Invoice/entities",
+        Some(serde_json::json!({ "fields": { "number": "INV-2" } })),
+    )
+    .await;
+    assert!(created["id"].as_str().unwrap().starts_with("inv_"));
+
+    let foreign = schema_forge_core::types::EntityId::new("contact");
+    let (status, _) = json_request(
+        &app,
+        Method::GET,
+        &format!("/schemas/Invoice/entities/{foreign}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn update_schema_enforces_version_monotonicity() {
+    let app = test_app().await;
+
+    let versioned = |version: u32, fields: &[&str]| {
+        let fields: Vec<_> = fields
+            .iter()
+            .map(|f| serde_json::json!({"name": f, "field_type": "Text"}))
+            .collect();
+        serde_json::json!({
+            "name": "Contact",
+            "fields": fields,
+            "annotations": [{"annotation": "Version", "version": version}]
+        })
+    };
+
+    let (status, _) = json_request(
+        &app,
+        Method::POST,
+        "/schemas",
+        Some(versioned(1, &["name"])),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // Version 2 over stored version 1 succeeds.
+    let (status, json) = json_request(
+        &app,
+        Method::PUT,
+        "/schemas/Contact",
+        Some(versioned(2, &["name", "email"])),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+    assert_eq!(json["annotations"][0]["version"], 2);
+
+    // Version 1 over stored version 2 is rejected without force.
+    let (status, json) = json_request(
+        &app,
+        Method::PUT,
+        "/schemas/Contact",
+        Some(versioned(1, &["name"])),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT, "body: {json}");
+    let (_, json) = json_request(&app, Method::GET, "/schemas/Contact", None).await;
+    assert_eq!(json["fields"].as_array().unwrap().len(), 2);
+
+    // ... and applied with it.
+    let (status, _) = json_request(
+        &app,
+        Method::PUT,
+        "/schemas/Contact?force=true",
+        Some(versioned(1, &["name"])),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn delete_schema_removes_from_registry() {
     let app = test_app().await;
@@ -373,270 +514,210 @@ async fn create_entity_returns_201() {
     assert_eq!(json["fields"]["age"], 30);
 }
 
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn create_entity_for_missing_schema_returns_404() {
-    let app = test_app().await;
+/// POST an entity with an optional `Prefer` header, returning the status,
+/// the `Location` header, and the raw body.
+async fn create_with_prefer(
+    app: &Router,
+    path: &str,
+    body: serde_json::Value,
+    prefer: Option<&str>,
+) -> (StatusCode, Option<String>, Vec<u8>) {
+    let mut request = Request::builder()
+        .method(Method::POST)
+        .uri(path)
+        .header("content-type", "application/json");
+    if let Some(prefer) = prefer {
+        request = request.header("prefer", prefer);
+    }
+    let request = request
+        .body(Body::from(serde_json::to_vec(&body).unwrap()))
+        .unwrap();
 
-    let entity_body = serde_json::json!({
-        "fields": {
-            "name": "Alice"
-        }
-    });
-    let (status, json) = json_request(
-        &app,
-        Method::POST,
-        "/schemas/Missing/entities",
-        Some(entity_body),
-    )
-    .await;
-    assert_eq!(status, StatusCode::NOT_FOUND);
-    assert_eq!(json["error"], "schema_not_found");
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let location = response
+        .headers()
+        .get("location")
+        .map(|v| v.to_str().unwrap().to_string());
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, location, body.to_vec())
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn get_entity_returns_200() {
+async fn create_entity_sets_location_and_honors_prefer() {
     let app = test_app().await;
-
-    // Create schema
     let schema_body = serde_json::json!({
         "name": "Contact",
-        "fields": [
-            {"name": "name", "field_type": "Text", "modifiers": ["required"]}
-        ]
+        "fields": [{"name": "name", "field_type": "Text"}]
     });
     json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    let entity = serde_json::json!({ "fields": { "name": "Alice" } });
 
-    // Create entity
-    let entity_body = serde_json::json!({
-        "fields": { "name": "Alice" }
-    });
-    let (_, created) = json_request(
+    // Default and explicit `return=representation`: full body.
+    for prefer in [None, Some("return=representation")] {
+        let (status, location, body) =
+            create_with_prefer(&app, "/schemas/Contact/entities", entity.clone(), prefer).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["fields"]["name"], "Alice");
+        let id = json["id"].as_str().unwrap();
+        assert_eq!(
+            location.as_deref(),
+            Some(format!("/schemas/Contact/entities/{id}").as_str())
+        );
+    }
+
+    // `return=minimal`: empty body, Location still points at the entity.
+    let (status, location, body) = create_with_prefer(
         &app,
-        Method::POST,
         "/schemas/Contact/entities",
-        Some(entity_body),
+        entity,
+        Some("return=minimal"),
     )
     .await;
-    let entity_id = created["id"].as_str().unwrap();
-
-    // Get entity
-    let path = format!("/schemas/Contact/entities/{entity_id}");
-    let (status, json) = json_request(&app, Method::GET, &path, None).await;
-    assert_eq!(status, StatusCode::OK);
-    assert_eq!(json["id"], entity_id);
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(body.is_empty());
+    let location = location.expect("Location header");
+    let (status, json) = json_request(&app, Method::GET, &location, None).await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
     assert_eq!(json["fields"]["name"], "Alice");
 }
 
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn get_missing_entity_returns_404() {
-    let app = test_app().await;
-
-    // Create schema
-    let schema_body = serde_json::json!({
-        "name": "Contact",
-        "fields": [{"name": "name", "field_type": "Text"}]
-    });
-    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+/// POST an entity with an `Idempotency-Key`, returning the status, the
+/// `Idempotent-Replay` header, and the JSON body.
+async fn create_with_idempotency_key(
+    app: &Router,
+    body: serde_json::Value,
+    key: &str,
+) -> (StatusCode, Option<String>, serde_json::Value) {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/schemas/Contact/entities")
+        .header("content-type", "application/json")
+        .header("idempotency-key", key)
+        .body(Body::from(serde_json::to_vec(&body).unwrap()))
+        .unwrap();
 
-    // Use a valid but non-existent entity ID
-    let fake_id = schema_forge_core::types::EntityId::new("contact");
-    let path = format!("/schemas/Contact/entities/{}", fake_id.as_str());
-    let (status, _) = json_request(&app, Method::GET, &path, None).await;
-    assert_eq!(status, StatusCode::NOT_FOUND);
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let replay = response
+        .headers()
+        .get("idempotent-replay")
+        .map(|v| v.to_str().unwrap().to_string());
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json = serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null);
+    (status, replay, json)
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn update_entity_returns_200() {
+async fn create_entity_replays_repeated_idempotency_key() {
     let app = test_app().await;
-
-    // Create schema
     let schema_body = serde_json::json!({
         "name": "Contact",
-        "fields": [
-            {"name": "name", "field_type": "Text", "modifiers": ["required"]},
-            {"name": "age", "field_type": "Integer"}
-        ]
+        "fields": [{"name": "name", "field_type": "Text"}]
     });
     json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    let entity = serde_json::json!({ "fields": { "name": "Alice" } });
+
+    let (status, replay, first) = create_with_idempotency_key(&app, entity.clone(), "k-1").await;
+    assert_eq!(status, StatusCode::CREATED, "body: {first}");
+    assert_eq!(replay, None);
+
+    // A retry with the same key returns the original entity and creates
+    // nothing.
+    let (status, replay, second) = create_with_idempotency_key(&app, entity.clone(), "k-1").await;
+    assert_eq!(status, StatusCode::OK, "body: {second}");
+    assert_eq!(replay.as_deref(), Some("true"));
+    assert_eq!(second["id"], first["id"]);
+    assert_eq!(second["fields"]["name"], "Alice");
+
+    // Reusing the key for a different body is a client error, not a replay.
+    let retry = serde_json::json!({ "fields": { "name": "Bob" } });
+    let (status, replay, json) = create_with_idempotency_key(&app, retry, "k-1").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "body: {json}");
+    assert_eq!(replay, None);
+
+    // A different key is a different create.
+    let (status, _, third) = create_with_idempotency_key(&app, entity, "k-2").await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_ne!(third["id"], first["id"]);
 
-    // Create entity
-    let entity_body = serde_json::json!({
-        "fields": { "name": "Alice", "age": 30 }
-    });
-    let (_, created) = json_request(
-        &app,
-        Method::POST,
-        "/schemas/Contact/entities",
-        Some(entity_body),
-    )
-    .await;
-    let entity_id = created["id"].as_str().unwrap();
+    let (_, json) = json_request(&app, Method::GET, "/schemas/Contact/entities", None).await;
+    assert_eq!(json["total_count"], 2);
 
-    // Update entity
-    let update_body = serde_json::json!({
-        "fields": { "name": "Alice Updated", "age": 31 }
-    });
-    let path = format!("/schemas/Contact/entities/{entity_id}");
-    let (status, json) = json_request(&app, Method::PUT, &path, Some(update_body)).await;
-    assert_eq!(status, StatusCode::OK);
-    assert_eq!(json["fields"]["name"], "Alice Updated");
-    assert_eq!(json["fields"]["age"], 31);
+    let (status, _, _) =
+        create_with_idempotency_key(&app, serde_json::json!({ "fields": {} }), "has space").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn delete_entity_returns_204() {
+async fn create_entity_for_missing_schema_returns_404() {
     let app = test_app().await;
 
-    // Create schema
-    let schema_body = serde_json::json!({
-        "name": "Contact",
-        "fields": [{"name": "name", "field_type": "Text", "modifiers": ["required"]}]
-    });
-    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
-
-    // Create entity
     let entity_body = serde_json::json!({
-        "fields": { "name": "Alice" }
+        "fields": {
+            "name": "Alice"
+        }
     });
-    let (_, created) = json_request(
+    let (status, json) = json_request(
         &app,
         Method::POST,
-        "/schemas/Contact/entities",
+        "/schemas/Missing/entities",
         Some(entity_body),
     )
     .await;
-    let entity_id = created["id"].as_str().unwrap();
-
-    // Delete entity
-    let path = format!("/schemas/Contact/entities/{entity_id}");
-    let (status, _) = json_request(&app, Method::DELETE, &path, None).await;
-    assert_eq!(status, StatusCode::NO_CONTENT);
-
-    // Verify it is gone
-    let (status, _) = json_request(&app, Method::GET, &path, None).await;
     assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(json["error"], "schema_not_found");
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn delete_missing_entity_returns_404() {
-    let app = test_app().await;
-
-    // Create schema
-    let schema_body = serde_json::json!({
-        "name": "Contact",
-        "fields": [{"name": "name", "field_type": "Text"}]
-    });
-    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
-
-    let fake_id = schema_forge_core::types::EntityId::new("contact");
-    let path = format!("/schemas/Contact/entities/{}", fake_id.as_str());
-    let (status, _) = json_request(&app, Method::DELETE, &path, None).await;
-    assert_eq!(status, StatusCode::NOT_FOUND);
-}
-
-// ---------------------------------------------------------------------------
-// Cedar policy generation tests
-// ---------------------------------------------------------------------------
-
-#[test]
-fn cedar_policies_generated_for_schema() {
-    use schema_forge_acton::cedar::generate_cedar_policies;
+async fn create_entity_response_includes_server_applied_defaults() {
     use schema_forge_core::types::{
-        FieldDefinition, FieldName, FieldType, SchemaDefinition, SchemaId, SchemaName,
+        DefaultValue, FieldDefinition, FieldModifier, FieldName, FieldType, SchemaId, SchemaName,
         TextConstraints,
     };
 
-    let schema = SchemaDefinition::new(
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+    let backend = Arc::new(backend);
+
+    let task = SchemaDefinition::new(
         SchemaId::new(),
-        SchemaName::new("Contact").unwrap(),
-        vec![FieldDefinition::new(
-            FieldName::new("name").unwrap(),
-            FieldType::Text(TextConstraints::unconstrained()),
-        )],
+        SchemaName::new("Task").unwrap(),
+        vec![
+            FieldDefinition::with_modifiers(
+                FieldName::new("title").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![FieldModifier::Required],
+            ),
+            FieldDefinition::with_modifiers(
+                FieldName::new("is_active").unwrap(),
+                FieldType::Boolean,
+                vec![FieldModifier::Default {
+                    value: DefaultValue::Boolean(true),
+                }],
+            ),
+        ],
         vec![],
     )
     .unwrap();
 
-    let policies = generate_cedar_policies(&schema);
-    // Secure-by-default: a schema with no @access yields no user-facing
-    // permits. The two unconditional rules are the schema-admin permit and
-    // the tenant-isolation forbid (inert when the resource has no
-    // `_tenant`). Read/Create/Delete actions are not opened up to any role.
-    assert_eq!(policies.len(), 2);
-    assert!(policies
-        .iter()
-        .any(|p| p.cedar_text.contains("UpdateSchema") && p.cedar_text.contains("schema-admin")));
-    assert!(policies
-        .iter()
-        .any(|p| p.cedar_text.contains("tenant_guard")));
-}
-
-// ---------------------------------------------------------------------------
-// Extension builder tests
-// ---------------------------------------------------------------------------
-
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn extension_builder_with_backend_loads_schemas() {
-    use schema_forge_acton::SchemaForgeExtension;
-
-    let backend = SurrealBackend::connect_memory("test", "test")
+    let plan = schema_forge_core::migration::DiffEngine::create_new(&task);
+    backend
+        .apply_migration(&task.name, &plan.steps)
         .await
-        .expect("failed to connect to in-memory SurrealDB");
-
-    let builder = SchemaForgeExtension::builder().with_backend(backend);
-    let extension = builder.build().await.expect("failed to build extension");
-
-    // Registry should contain exactly the 3 system schemas after seeding.
-    // Cedar policies own role and permission definitions, so neither is
-    // represented as a database schema.
-    let schemas = extension.registry().list().await;
-    assert_eq!(schemas.len(), 3);
-    let names: Vec<String> = schemas
-        .iter()
-        .map(|s| s.name.as_str().to_string())
-        .collect();
-    assert!(names.contains(&"User".to_string()));
-    assert!(names.contains(&"TenantMembership".to_string()));
-    assert!(names.contains(&"WebhookSubscription".to_string()));
-    assert!(!names.contains(&"Role".to_string()));
-    assert!(!names.contains(&"Permission".to_string()));
-}
-
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn extension_register_routes_nests_under_forge() {
-    // Build actor-backed AppState and nest forge routes under /forge
-    let state = test_app_state().await;
-    let router: Router = Router::new();
-    let forge_router: Router<()> = forge_routes().with_state(state);
-    let router = router.nest("/forge", forge_router);
-
-    // Test that we can hit /forge/schemas (requires auth)
-    let mut request = Request::builder()
-        .method(Method::GET)
-        .uri("/forge/schemas")
-        .header("content-type", "application/json")
-        .body(Body::empty())
-        .unwrap();
-    request
-        .extensions_mut()
-        .insert(make_test_claims(&["platform_admin"]));
-
-    let response = router.oneshot(request).await.unwrap();
-    assert_eq!(response.status(), StatusCode::OK);
-}
-
-// ---------------------------------------------------------------------------
-// Auth integration tests
-// ---------------------------------------------------------------------------
-
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn request_with_admin_claims_succeeds() {
-    let backend = SurrealBackend::connect_memory("test", "test")
+        .expect("apply migration");
+    backend
+        .store_schema_metadata(&task)
         .await
-        .expect("failed to connect to in-memory SurrealDB");
+        .expect("store metadata");
+
+    let mut registry = HashMap::new();
+    registry.insert("Task".to_string(), task);
     let state = build_test_app_state(TestForgeInit {
-        backend: Arc::new(backend),
-        registry: HashMap::new(),
+        backend,
+        registry,
         tenant_config: None,
         record_access_policy: None,
         hook_dispatcher: None,
@@ -644,58 +725,74 @@ async fn request_with_admin_claims_succeeds() {
     .await;
     let app = test_app_with_claims_state(state, make_test_claims(&["platform_admin"]));
 
-    // Create a schema to verify the request goes through
-    let body = serde_json::json!({
-        "name": "Contact",
-        "fields": [{"name": "name", "field_type": "Text"}]
-    });
-    let (status, json) = json_request(&app, Method::POST, "/schemas", Some(body)).await;
-    assert_eq!(status, StatusCode::CREATED);
-    assert_eq!(json["name"], "Contact");
+    // `is_active` is omitted: the response must carry the default without
+    // the client having to re-GET.
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Task/entities",
+        Some(serde_json::json!({ "fields": { "title": "Write docs" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "body: {json}");
+    assert_eq!(json["fields"]["title"], "Write docs");
+    assert_eq!(json["fields"]["is_active"], true);
+
+    // The follow-up GET agrees with the write response.
+    let path = format!("/schemas/Task/entities/{}", json["id"].as_str().unwrap());
+    let (status, fetched) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(fetched["fields"]["is_active"], json["fields"]["is_active"]);
 }
 
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn request_without_claims_returns_401() {
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_creates_get_unique_gapless_sequence_numbers() {
     use schema_forge_core::types::{
-        Annotation, FieldDefinition, FieldName, FieldType, SchemaDefinition, SchemaId, SchemaName,
+        DefaultValue, FieldDefinition, FieldModifier, FieldName, FieldType, SchemaId, SchemaName,
         TextConstraints,
     };
 
-    let backend = SurrealBackend::connect_memory("test", "test")
-        .await
-        .expect("failed to connect to in-memory SurrealDB");
+    let backend = Arc::new(
+        SurrealBackend::connect_memory("test", "test")
+            .await
+            .expect("failed to connect to in-memory SurrealDB"),
+    );
 
-    // Register a schema so the route doesn't 404 before reaching auth check
-    let schema = SchemaDefinition::new(
+    let invoice = SchemaDefinition::new(
         SchemaId::new(),
-        SchemaName::new("Contact").unwrap(),
-        vec![FieldDefinition::new(
-            FieldName::new("name").unwrap(),
-            FieldType::Text(TextConstraints::unconstrained()),
-        )],
-        vec![Annotation::Access {
-            read: vec!["viewer".to_string()],
-            write: vec!["editor".to_string()],
-            delete: vec!["admin".to_string()],
-            cross_tenant_read: vec![],
-        }],
+        SchemaName::new("Invoice").unwrap(),
+        vec![
+            FieldDefinition::with_modifiers(
+                FieldName::new("number").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![FieldModifier::Default {
+                    value: DefaultValue::Sequence {
+                        prefix: "INV-".into(),
+                        start: 1000,
+                    },
+                }],
+            ),
+            FieldDefinition::new(
+                FieldName::new("customer").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            ),
+        ],
+        vec![],
     )
     .unwrap();
 
-    let backend = Arc::new(backend);
-    let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
+    let plan = schema_forge_core::migration::DiffEngine::create_new(&invoice);
     backend
-        .apply_migration(&schema.name, &plan.steps)
+        .apply_migration(&invoice.name, &plan.steps)
         .await
         .expect("apply migration");
     backend
-        .store_schema_metadata(&schema)
+        .store_schema_metadata(&invoice)
         .await
         .expect("store metadata");
 
     let mut registry = HashMap::new();
-    registry.insert("Contact".to_string(), schema.clone());
-
+    registry.insert("Invoice".to_string(), invoice);
     let state = build_test_app_state(TestForgeInit {
         backend,
         registry,
@@ -704,72 +801,115 @@ async fn request_without_claims_returns_401() {
         hook_dispatcher: None,
     })
     .await;
-    // No Claims injected — requests should get 401
-    let app = test_app_with_state(state);
+    let app = test_app_with_claims_state(state, make_test_claims(&["platform_admin"]));
 
-    let (status, json) = json_request(&app, Method::GET, "/schemas/Contact/entities", None).await;
-    assert_eq!(status, StatusCode::UNAUTHORIZED);
-    assert_eq!(json["error"], "unauthorized");
-}
+    let handles: Vec<_> = (0..10)
+        .map(|i| {
+            let app = app.clone();
+            tokio::spawn(async move {
+                json_request(
+                    &app,
+                    Method::POST,
+                    "/schemas/Invoice/entities",
+                    Some(serde_json::json!({ "fields": { "customer": format!("c{i}") } })),
+                )
+                .await
+            })
+        })
+        .collect();
 
-// ---------------------------------------------------------------------------
-// Access control integration tests
-// ---------------------------------------------------------------------------
+    let mut numbers = Vec::new();
+    for handle in handles {
+        let (status, json) = handle.await.unwrap();
+        assert_eq!(status, StatusCode::CREATED, "body: {json}");
+        numbers.push(json["fields"]["number"].as_str().unwrap().to_string());
+    }
+    numbers.sort();
+    let expected: Vec<String> = (1001..=1010).map(|n| format!("INV-{n}")).collect();
+    assert_eq!(numbers, expected);
 
-/// Helper to create an AppState with a pre-registered schema with @access annotations,
-/// and build a test app with the given Claims.
-async fn access_test_state(
-    user_roles: Vec<String>,
-    schema_read_roles: Vec<String>,
-    schema_write_roles: Vec<String>,
-    schema_delete_roles: Vec<String>,
-) -> (AppState<SchemaForgeConfig>, Router) {
+    // An explicit value is kept and does not advance the counter.
+    let (_, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Invoice/entities",
+        Some(serde_json::json!({ "fields": { "number": "MANUAL-1" } })),
+    )
+    .await;
+    assert_eq!(json["fields"]["number"], "MANUAL-1");
+    let (_, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Invoice/entities",
+        Some(serde_json::json!({ "fields": { "customer": "next" } })),
+    )
+    .await;
+    assert_eq!(json["fields"]["number"], "INV-1011");
+}
+
+#[tokio::test]
+async fn relation_display_uses_ref_label_template() {
     use schema_forge_core::types::{
-        Annotation, FieldDefinition, FieldName, FieldType, SchemaDefinition, SchemaId, SchemaName,
-        TextConstraints,
+        Annotation, Cardinality, FieldDefinition, FieldName, FieldType, RefLabelTemplate, SchemaId,
+        SchemaName, TextConstraints,
     };
 
-    let backend = SurrealBackend::connect_memory("test", "test")
-        .await
-        .expect("failed to connect to in-memory SurrealDB");
+    let backend = Arc::new(
+        SurrealBackend::connect_memory("test", "test")
+            .await
+            .expect("failed to connect to in-memory SurrealDB"),
+    );
 
-    // Create a schema with @access annotation
-    let schema = SchemaDefinition::new(
+    let text = |name: &str| {
+        FieldDefinition::new(
+            FieldName::new(name).unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )
+    };
+    let company = SchemaDefinition::new(
         SchemaId::new(),
-        SchemaName::new("Article").unwrap(),
+        SchemaName::new("Company").unwrap(),
+        vec![text("name"), text("code")],
         vec![
+            Annotation::Display {
+                field: FieldName::new("name").unwrap(),
+            },
+            Annotation::RefLabel {
+                template: RefLabelTemplate::new("{name} ({code})").unwrap(),
+            },
+        ],
+    )
+    .unwrap();
+    let contact = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Contact").unwrap(),
+        vec![
+            text("email"),
             FieldDefinition::new(
-                FieldName::new("title").unwrap(),
-                FieldType::Text(TextConstraints::unconstrained()),
-            ),
-            FieldDefinition::new(
-                FieldName::new("body").unwrap(),
-                FieldType::Text(TextConstraints::unconstrained()),
+                FieldName::new("company").unwrap(),
+                FieldType::Relation {
+                    target: SchemaName::new("Company").unwrap(),
+                    cardinality: Cardinality::One,
+                },
             ),
         ],
-        vec![Annotation::Access {
-            read: schema_read_roles,
-            write: schema_write_roles,
-            delete: schema_delete_roles,
-            cross_tenant_read: vec![],
-        }],
+        vec![],
     )
     .unwrap();
 
     let mut registry = HashMap::new();
-    registry.insert("Article".to_string(), schema.clone());
-
-    // Apply migration so the backend table exists
-    let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
-    let backend = Arc::new(backend);
-    backend
-        .apply_migration(&schema.name, &plan.steps)
-        .await
-        .expect("failed to apply migration");
-    backend
-        .store_schema_metadata(&schema)
-        .await
-        .expect("failed to store metadata");
+    for schema in [company, contact] {
+        let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
+        backend
+            .apply_migration(&schema.name, &plan.steps)
+            .await
+            .expect("apply migration");
+        backend
+            .store_schema_metadata(&schema)
+            .await
+            .expect("store metadata");
+        registry.insert(schema.name.as_str().to_string(), schema);
+    }
 
     let state = build_test_app_state(TestForgeInit {
         backend,
@@ -779,195 +919,101 @@ async fn access_test_state(
         hook_dispatcher: None,
     })
     .await;
+    let app = test_app_with_claims_state(state, make_test_claims(&["platform_admin"]));
 
-    let claims = make_test_claims(&user_roles.iter().map(|s| s.as_str()).collect::<Vec<_>>());
-    let app = test_app_with_claims_state(state.clone(), claims);
-    (state, app)
-}
-
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn authenticated_request_with_matching_role_succeeds() {
-    let (_state, app) = access_test_state(
-        vec!["editor".to_string()],
-        vec!["viewer".to_string(), "editor".to_string()],
-        vec!["editor".to_string()],
-        vec!["admin".to_string()],
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Company/entities",
+        Some(serde_json::json!({ "fields": { "name": "Acme Corp", "code": "C123" } })),
     )
     .await;
+    assert_eq!(status, StatusCode::CREATED, "body: {json}");
+    let company_id = json["id"].as_str().unwrap().to_string();
 
-    // Create entity -- user has "editor" role which is in write roles
-    let entity_body = serde_json::json!({
-        "fields": {
-            "title": "Hello World",
-            "body": "Content here"
-        }
-    });
     let (status, json) = json_request(
         &app,
         Method::POST,
-        "/schemas/Article/entities",
-        Some(entity_body),
+        "/schemas/Contact/entities",
+        Some(serde_json::json!({
+            "fields": { "email": "a@acme.test", "company": company_id }
+        })),
     )
     .await;
+    assert_eq!(status, StatusCode::CREATED, "body: {json}");
+
+    let (status, json) = json_request(&app, Method::GET, "/schemas/Contact/entities", None).await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
     assert_eq!(
-        status,
-        StatusCode::CREATED,
-        "expected 201, got {status} with body: {json}"
+        json["entities"][0]["fields"]["company__display"],
+        "Acme Corp (C123)"
     );
-    assert_eq!(json["schema"], "Article");
-    assert_eq!(json["fields"]["title"], "Hello World");
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn authenticated_request_with_wrong_role_gets_403() {
-    let (_state, app) = access_test_state(
-        vec!["viewer".to_string()],
-        vec!["viewer".to_string()],
-        vec!["editor".to_string()],
-        vec!["admin".to_string()],
-    )
-    .await;
-
-    // Create entity -- user has "viewer" role, write requires "editor"
-    let entity_body = serde_json::json!({
-        "fields": {
-            "title": "Forbidden Article",
-            "body": "Should fail"
-        }
-    });
-    let (status, json) = json_request(
-        &app,
-        Method::POST,
-        "/schemas/Article/entities",
-        Some(entity_body),
-    )
-    .await;
-    assert_eq!(status, StatusCode::FORBIDDEN);
-    assert_eq!(json["error"], "forbidden");
-}
-
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn request_without_claims_on_access_controlled_schema_returns_401() {
+async fn update_entity_response_omits_defaults_never_stored() {
+    use schema_forge_acton::state::DynEntityStore;
+    use schema_forge_backend::entity::Entity;
     use schema_forge_core::types::{
-        Annotation, FieldDefinition, FieldName, FieldType, SchemaDefinition, SchemaId, SchemaName,
-        TextConstraints,
+        DefaultValue, DynamicValue, FieldDefinition, FieldModifier, FieldName, FieldType, SchemaId,
+        SchemaName, TextConstraints,
     };
 
-    let backend = SurrealBackend::connect_memory("test", "test")
-        .await
-        .expect("failed to connect to in-memory SurrealDB");
-
-    // Schema with restrictive @access
-    let schema = SchemaDefinition::new(
+    let backend = Arc::new(
+        SurrealBackend::connect_memory("test", "test")
+            .await
+            .expect("failed to connect to in-memory SurrealDB"),
+    );
+    let title = FieldDefinition::new(
+        FieldName::new("title").unwrap(),
+        FieldType::Text(TextConstraints::unconstrained()),
+    );
+    let v1 = SchemaDefinition::new(
         SchemaId::new(),
-        SchemaName::new("Secret").unwrap(),
-        vec![FieldDefinition::new(
-            FieldName::new("data").unwrap(),
-            FieldType::Text(TextConstraints::unconstrained()),
-        )],
-        vec![Annotation::Access {
-            read: vec!["classified".to_string()],
-            write: vec!["classified".to_string()],
-            delete: vec!["classified".to_string()],
-            cross_tenant_read: vec![],
-        }],
+        SchemaName::new("Task").unwrap(),
+        vec![title.clone()],
+        vec![],
     )
     .unwrap();
-
-    let mut registry = HashMap::new();
-    registry.insert("Secret".to_string(), schema.clone());
-
-    let backend = Arc::new(backend);
-    let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
-    backend
-        .apply_migration(&schema.name, &plan.steps)
-        .await
-        .expect("failed to apply migration");
+    let plan = schema_forge_core::migration::DiffEngine::create_new(&v1);
     backend
-        .store_schema_metadata(&schema)
+        .apply_migration(&v1.name, &plan.steps)
         .await
-        .expect("failed to store metadata");
-
-    // No Claims injected — should get 401
-    let state = build_test_app_state(TestForgeInit {
-        backend,
-        registry,
-        tenant_config: None,
-        record_access_policy: None,
-        hook_dispatcher: None,
-    })
-    .await;
-    let app = test_app_with_state(state);
-
-    let entity_body = serde_json::json!({
-        "fields": { "data": "top secret" }
-    });
-    let (status, _json) = json_request(
-        &app,
-        Method::POST,
-        "/schemas/Secret/entities",
-        Some(entity_body),
-    )
-    .await;
-    assert_eq!(status, StatusCode::UNAUTHORIZED);
-}
-
-#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn field_filtering_hides_restricted_fields() {
-    use schema_forge_core::types::{
-        FieldAnnotation, FieldDefinition, FieldName, FieldType, SchemaDefinition, SchemaId,
-        SchemaName, TextConstraints,
-    };
-
-    let backend = SurrealBackend::connect_memory("test", "test")
+        .expect("apply migration");
+    let old_row = backend
+        .create(&Entity::for_schema(
+            &v1,
+            [("title".to_string(), DynamicValue::Text("Old task".into()))]
+                .into_iter()
+                .collect(),
+        ))
         .await
-        .expect("failed to connect to in-memory SurrealDB");
+        .expect("create old row");
 
-    // Schema with a field-level access restriction
-    // @access with empty lists = all authenticated users permitted (testing field-level, not schema-level)
-    use schema_forge_core::types::Annotation;
-    let schema = SchemaDefinition::new(
-        SchemaId::new(),
-        SchemaName::new("Employee").unwrap(),
+    // `is_active` arrives after the row was written, so the row never
+    // stored it.
+    let v2 = SchemaDefinition::new(
+        v1.id.clone(),
+        v1.name.clone(),
         vec![
-            FieldDefinition::new(
-                FieldName::new("name").unwrap(),
-                FieldType::Text(TextConstraints::unconstrained()),
-            ),
-            FieldDefinition::with_annotations(
-                FieldName::new("salary").unwrap(),
-                FieldType::Text(TextConstraints::unconstrained()),
-                vec![],
-                vec![FieldAnnotation::FieldAccess {
-                    read: vec!["hr".to_string()],
-                    write: vec!["hr".to_string()],
+            title,
+            FieldDefinition::with_modifiers(
+                FieldName::new("is_active").unwrap(),
+                FieldType::Boolean,
+                vec![FieldModifier::Default {
+                    value: DefaultValue::Boolean(true),
                 }],
             ),
         ],
-        vec![Annotation::Access {
-            read: vec![],
-            write: vec![],
-            delete: vec![],
-            cross_tenant_read: vec![],
-        }],
+        vec![],
     )
     .unwrap();
-
-    let mut registry = HashMap::new();
-    registry.insert("Employee".to_string(), schema.clone());
-
-    let backend = Arc::new(backend);
-    let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
-    backend
-        .apply_migration(&schema.name, &plan.steps)
-        .await
-        .expect("failed to apply migration");
     backend
-        .store_schema_metadata(&schema)
+        .store_schema_metadata(&v2)
         .await
-        .expect("failed to store metadata");
-
-    // User with "member" role (not "hr")
+        .expect("store metadata");
+    let mut registry = HashMap::new();
+    registry.insert("Task".to_string(), v2);
     let state = build_test_app_state(TestForgeInit {
         backend,
         registry,
@@ -976,431 +1022,3194 @@ async fn field_filtering_hides_restricted_fields() {
         hook_dispatcher: None,
     })
     .await;
-    let app = test_app_with_claims_state(state, make_test_claims(&["member"]));
+    let app = test_app_with_claims_state(state, make_test_claims(&["platform_admin"]));
 
-    // Create entity with both fields
+    let path = format!("/schemas/Task/entities/{}", old_row.id.as_str());
+    for method in [Method::PUT, Method::PATCH] {
+        let (status, json) = json_request(
+            &app,
+            method.clone(),
+            &path,
+            Some(serde_json::json!({ "fields": { "title": "Renamed" } })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{method}: {json}");
+        assert_eq!(json["fields"]["title"], "Renamed");
+
+        // The write response agrees with a follow-up GET.
+        let (_, fetched) = json_request(&app, Method::GET, &path, None).await;
+        assert_eq!(
+            json["fields"].get("is_active"),
+            fetched["fields"].get("is_active"),
+            "{method}"
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_entity_returns_200() {
+    let app = test_app().await;
+
+    // Create schema
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [
+            {"name": "name", "field_type": "Text", "modifiers": ["required"]}
+        ]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    // Create entity
     let entity_body = serde_json::json!({
-        "fields": {
-            "name": "Alice",
-            "salary": "100000"
-        }
+        "fields": { "name": "Alice" }
     });
-    let (status, json) = json_request(
+    let (_, created) = json_request(
         &app,
         Method::POST,
-        "/schemas/Employee/entities",
+        "/schemas/Contact/entities",
         Some(entity_body),
     )
     .await;
-    assert_eq!(status, StatusCode::CREATED);
-
-    // The response should include "name" but NOT "salary" (filtered by read access)
-    assert_eq!(json["fields"]["name"], "Alice");
-    assert!(
-        json["fields"].get("salary").is_none()
-            || json["fields"]["salary"] == serde_json::Value::Null,
-        "salary field should be filtered from response, got: {:?}",
-        json["fields"]
-    );
+    let entity_id = created["id"].as_str().unwrap();
 
-    // Get the entity back and verify salary is still filtered
-    let entity_id = json["id"].as_str().unwrap();
-    let path = format!("/schemas/Employee/entities/{entity_id}");
-    let (status, get_json) = json_request(&app, Method::GET, &path, None).await;
+    // Get entity
+    let path = format!("/schemas/Contact/entities/{entity_id}");
+    let (status, json) = json_request(&app, Method::GET, &path, None).await;
     assert_eq!(status, StatusCode::OK);
-    assert_eq!(get_json["fields"]["name"], "Alice");
-    assert!(
-        get_json["fields"].get("salary").is_none()
-            || get_json["fields"]["salary"] == serde_json::Value::Null,
-        "salary field should be filtered from GET response, got: {:?}",
-        get_json["fields"]
-    );
+    assert_eq!(json["id"], entity_id);
+    assert_eq!(json["fields"]["name"], "Alice");
 }
 
-// ---------------------------------------------------------------------------
-// Issue #10 regression tests: GET → modify → PUT round-trip + PATCH support
-// ---------------------------------------------------------------------------
-
-/// Regression for issue #10: POST → GET → PUT (with the GET response body
-/// echoed back unchanged) must succeed with 200. The pre-fix behavior
-/// returned 502 for two unrelated reasons: GET emitted a `+00:00` datetime
-/// suffix that the client could echo back fine but that downstream null
-/// coercion and full-replacement validation tripped over; and the postgres
-/// binder bound `null` fields as `text`, mismatching typed columns. This
-/// test exercises the round-trip on the in-memory SurrealDB backend (which
-/// shares the field-conversion code path with postgres), guaranteeing the
-/// JSON serialization and required-field checks both accept the round-tripped
-/// payload.
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn put_round_trip_after_get_returns_200() {
+async fn get_entity_with_fields_returns_projection() {
     let app = test_app().await;
 
-    // Schema: required name + optional updated_at datetime + optional notes.
     let schema_body = serde_json::json!({
         "name": "Contact",
         "fields": [
-            {"name": "name", "field_type": "Text", "modifiers": ["required"]},
-            {"name": "updated_at", "field_type": "DateTime"},
-            {"name": "notes", "field_type": "Text"}
+            {"name": "name", "field_type": "Text"},
+            {"name": "email", "field_type": "Text"}
         ]
     });
     json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
-
-    // POST an entity that exercises a datetime field.
-    let create_body = serde_json::json!({
-        "fields": {
-            "name": "Alice",
-            "updated_at": "2026-04-13T12:00:00Z",
-            "notes": "initial"
-        }
-    });
-    let (create_status, created) = json_request(
+    let (_, created) = json_request(
         &app,
         Method::POST,
         "/schemas/Contact/entities",
-        Some(create_body),
+        Some(serde_json::json!({ "fields": { "name": "Alice", "email": "a@x.test" } })),
     )
     .await;
-    assert_eq!(create_status, StatusCode::CREATED);
-    let entity_id = created["id"].as_str().unwrap().to_string();
-    let path = format!("/schemas/Contact/entities/{entity_id}");
+    let entity_id = created["id"].as_str().unwrap();
 
-    // GET the entity — capture its serialized fields verbatim.
-    let (get_status, fetched) = json_request(&app, Method::GET, &path, None).await;
-    assert_eq!(get_status, StatusCode::OK);
-    let fetched_fields = fetched["fields"].clone();
+    let path = format!("/schemas/Contact/entities/{entity_id}?fields=name");
+    let (status, json) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+    assert_eq!(json["id"], entity_id);
+    assert_eq!(json["fields"]["name"], "Alice");
+    assert!(json["fields"].get("email").is_none(), "body: {json}");
 
-    // The GET response must serialize datetimes with a `Z` suffix
-    // (issue #10 fix #1) so generic clients consuming the format are happy.
-    let serialized_dt = fetched_fields["updated_at"].as_str().unwrap();
-    assert!(
-        serialized_dt.ends_with('Z'),
-        "expected datetime to end in 'Z', got {serialized_dt}"
-    );
+    let path = format!("/schemas/Contact/entities/{entity_id}?fields=bogus");
+    let (status, _) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
 
-    // PUT the GET response body back unchanged. Pre-fix this returned 502;
-    // post-fix it must return 200 because (a) the round-tripped datetime
-    // re-parses cleanly and (b) the full payload satisfies the required-
-    // field check.
-    let put_body = serde_json::json!({ "fields": fetched_fields });
-    let (put_status, put_json) = json_request(&app, Method::PUT, &path, Some(put_body)).await;
-    assert_eq!(
-        put_status,
-        StatusCode::OK,
-        "round-trip PUT should succeed, got {put_status} body={put_json}"
-    );
-    assert_eq!(put_json["fields"]["name"], "Alice");
-}
-
-/// Regression for issue #10: PATCH must merge a partial payload onto the
-/// existing entity, preserving fields that are not mentioned in the
-/// request body — including required ones.
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn patch_entity_merges_partial_fields() {
+async fn list_projection_keeps_id_and_pages_on_unprojected_sort() {
     let app = test_app().await;
 
-    // Schema with two required fields plus one optional field. PATCH must
-    // be able to update only `notes` without re-supplying `name` or `email`.
     let schema_body = serde_json::json!({
         "name": "Contact",
         "fields": [
-            {"name": "name", "field_type": "Text", "modifiers": ["required"]},
-            {"name": "email", "field_type": "Text", "modifiers": ["required"]},
-            {"name": "notes", "field_type": "Text"}
+            {"name": "name", "field_type": "Text"},
+            {"name": "email", "field_type": "Text"},
+            {"name": "rank", "field_type": "Integer"}
         ]
     });
     json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    for (name, rank) in [("Alice", 3), ("Bob", 1), ("Carol", 2)] {
+        let fields = serde_json::json!({ "name": name, "email": "x@y.test", "rank": rank });
+        json_request(
+            &app,
+            Method::POST,
+            "/schemas/Contact/entities",
+            Some(serde_json::json!({ "fields": fields })),
+        )
+        .await;
+    }
 
-    let create_body = serde_json::json!({
-        "fields": {
-            "name": "Alice",
-            "email": "alice@example.com",
-            "notes": "initial"
-        }
-    });
-    let (_, created) = json_request(
+    let (status, page) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities?fields=name&sort=rank&limit=2",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {page}");
+    let entities = page["entities"].as_array().unwrap();
+    assert_eq!(entities[0]["fields"]["name"], "Bob");
+    assert_eq!(entities[1]["fields"]["name"], "Carol");
+    for entity in entities {
+        assert!(entity["id"].is_string());
+        assert_eq!(entity["fields"].as_object().unwrap().len(), 1, "{entity}");
+    }
+
+    let cursor = page["next_cursor"].as_str().unwrap();
+    let (_, page) = json_request(
+        &app,
+        Method::GET,
+        &format!("/schemas/Contact/entities?fields=name&sort=rank&limit=2&after={cursor}"),
+        None,
+    )
+    .await;
+    let entities = page["entities"].as_array().unwrap();
+    assert_eq!(entities.len(), 1);
+    assert_eq!(entities[0]["fields"]["name"], "Alice");
+
+    let (status, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities?fields=name,nickname",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(
+        json.to_string().contains("nickname"),
+        "unexpected body: {json}"
+    );
+}
+
+/// Build an app with `Company` and a `Contact` that points at it through a
+/// `relation_one` (`company`) and a `relation_many` (`partners`) field.
+async fn company_contact_app() -> Router {
+    use schema_forge_core::types::{
+        Cardinality, FieldDefinition, FieldName, FieldType, SchemaId, SchemaName, TextConstraints,
+    };
+
+    let backend = Arc::new(
+        SurrealBackend::connect_memory("test", "test")
+            .await
+            .expect("failed to connect to in-memory SurrealDB"),
+    );
+    let text = |name: &str| {
+        FieldDefinition::new(
+            FieldName::new(name).unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )
+    };
+    let relation = |name: &str, cardinality: Cardinality| {
+        FieldDefinition::new(
+            FieldName::new(name).unwrap(),
+            FieldType::Relation {
+                target: SchemaName::new("Company").unwrap(),
+                cardinality,
+            },
+        )
+    };
+    let company = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Company").unwrap(),
+        vec![text("name")],
+        vec![],
+    )
+    .unwrap();
+    let contact = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Contact").unwrap(),
+        vec![
+            text("email"),
+            relation("company", Cardinality::One),
+            relation("partners", Cardinality::Many),
+        ],
+        vec![],
+    )
+    .unwrap();
+
+    let mut registry = HashMap::new();
+    for schema in [company, contact] {
+        let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
+        backend
+            .apply_migration(&schema.name, &plan.steps)
+            .await
+            .expect("apply migration");
+        backend
+            .store_schema_metadata(&schema)
+            .await
+            .expect("store metadata");
+        registry.insert(schema.name.as_str().to_string(), schema);
+    }
+
+    let state = build_test_app_state(TestForgeInit {
+        backend,
+        registry,
+        tenant_config: None,
+        record_access_policy: None,
+        hook_dispatcher: None,
+    })
+    .await;
+    test_app_with_claims_state(state, make_test_claims(&["platform_admin"]))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_entity_with_expand_nests_related_entities() {
+    let app = company_contact_app().await;
+
+    let mut company_ids = Vec::new();
+    for name in ["Acme Corp", "Globex"] {
+        let (status, json) = json_request(
+            &app,
+            Method::POST,
+            "/schemas/Company/entities",
+            Some(serde_json::json!({ "fields": { "name": name } })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED, "body: {json}");
+        company_ids.push(json["id"].as_str().unwrap().to_string());
+    }
+    let (status, json) = json_request(
         &app,
         Method::POST,
         "/schemas/Contact/entities",
-        Some(create_body),
+        Some(serde_json::json!({
+            "fields": {
+                "email": "a@acme.test",
+                "company": company_ids[0],
+                "partners": [company_ids[1], company_ids[0]]
+            }
+        })),
     )
     .await;
-    let entity_id = created["id"].as_str().unwrap().to_string();
-    let path = format!("/schemas/Contact/entities/{entity_id}");
+    assert_eq!(status, StatusCode::CREATED, "body: {json}");
+    let contact_id = json["id"].as_str().unwrap().to_string();
 
-    // Patch only the notes field.
-    let patch_body = serde_json::json!({ "fields": { "notes": "patched" } });
-    let (patch_status, patched) = json_request(&app, Method::PATCH, &path, Some(patch_body)).await;
-    assert_eq!(
-        patch_status,
-        StatusCode::OK,
-        "PATCH should succeed, got {patch_status} body={patched}"
+    let path = format!("/schemas/Contact/entities/{contact_id}?expand=company,partners");
+    let (status, json) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+    let company = &json["fields"]["company"];
+    assert_eq!(company["id"], company_ids[0].as_str(), "body: {json}");
+    assert_eq!(company["schema"], "Company");
+    assert_eq!(company["fields"]["name"], "Acme Corp");
+    let partners = json["fields"]["partners"]
+        .as_array()
+        .expect("partners array");
+    assert_eq!(partners.len(), 2);
+    assert_eq!(partners[0]["fields"]["name"], "Globex");
+    assert_eq!(partners[1]["fields"]["name"], "Acme Corp");
+
+    // Without `expand` the relation stays an ID.
+    let path = format!("/schemas/Contact/entities/{contact_id}");
+    let (_, json) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(json["fields"]["company"], company_ids[0].as_str());
+
+    // Expanding a non-relation field, or one outside the projection, is rejected.
+    for query in ["expand=email", "fields=email&expand=company"] {
+        let path = format!("/schemas/Contact/entities/{contact_id}?{query}");
+        let (status, _) = json_request(&app, Method::GET, &path, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{query}");
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn relation_filter_requires_read_access_to_target() {
+    use schema_forge_core::types::{
+        Annotation, Cardinality, FieldAnnotation, FieldDefinition, FieldName, FieldType, SchemaId,
+        SchemaName, TextConstraints,
+    };
+
+    let backend = Arc::new(
+        SurrealBackend::connect_memory("test", "test")
+            .await
+            .expect("failed to connect to in-memory SurrealDB"),
     );
+    let text = |name: &str| {
+        FieldDefinition::new(
+            FieldName::new(name).unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )
+    };
+    let relation = |name: &str, target: &str| {
+        FieldDefinition::new(
+            FieldName::new(name).unwrap(),
+            FieldType::Relation {
+                target: SchemaName::new(target).unwrap(),
+                cardinality: Cardinality::One,
+            },
+        )
+    };
+    let access = |read: &str| Annotation::Access {
+        read: vec![read.to_string()],
+        write: vec![read.to_string()],
+        delete: vec![read.to_string()],
+        cross_tenant_read: vec![],
+    };
+    let company = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Company").unwrap(),
+        vec![
+            text("name"),
+            FieldDefinition::with_annotations(
+                FieldName::new("credit_score").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![],
+                vec![FieldAnnotation::Hidden],
+            ),
+        ],
+        vec![access("sales")],
+    )
+    .unwrap();
+    let vault = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Vault").unwrap(),
+        vec![text("name")],
+        vec![access("auditor")],
+    )
+    .unwrap();
+    let contact = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Contact").unwrap(),
+        vec![
+            text("email"),
+            relation("company", "Company"),
+            relation("vault", "Vault"),
+            FieldDefinition::with_annotations(
+                FieldName::new("notes").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![],
+                vec![FieldAnnotation::Hidden],
+            ),
+        ],
+        vec![access("sales")],
+    )
+    .unwrap();
 
-    // The unmodified required fields must still be present and unchanged.
-    assert_eq!(patched["fields"]["name"], "Alice");
-    assert_eq!(patched["fields"]["email"], "alice@example.com");
-    assert_eq!(patched["fields"]["notes"], "patched");
+    let mut registry = HashMap::new();
+    for schema in [company, vault, contact] {
+        let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
+        backend
+            .apply_migration(&schema.name, &plan.steps)
+            .await
+            .expect("apply migration");
+        backend
+            .store_schema_metadata(&schema)
+            .await
+            .expect("store metadata");
+        registry.insert(schema.name.as_str().to_string(), schema);
+    }
+    let state = build_test_app_state(TestForgeInit {
+        backend,
+        registry,
+        tenant_config: None,
+        record_access_policy: None,
+        hook_dispatcher: None,
+    })
+    .await;
+    let app = test_app_with_claims_state(state, make_test_claims(&["sales"]));
 
-    // Confirm via GET that the merge was actually persisted.
-    let (_, fetched) = json_request(&app, Method::GET, &path, None).await;
-    assert_eq!(fetched["fields"]["name"], "Alice");
-    assert_eq!(fetched["fields"]["email"], "alice@example.com");
-    assert_eq!(fetched["fields"]["notes"], "patched");
+    let (status, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities?company.name=Acme",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+
+    // The caller cannot read Vault, so cannot probe it through a filter.
+    for path in [
+        "/schemas/Contact/entities?vault.name=Main",
+        "/schemas/Contact/entities/count?vault.name=Main",
+        "/schemas/Contact/entities.csv?vault.name=Main",
+    ] {
+        let (status, _) = json_request(&app, Method::GET, path, None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{path}");
+    }
+    // Body filters are authorized the same way.
+    let vault_filter = serde_json::json!({ "filter": { "vault.name__contains": "Main" } });
+    for path in [
+        "/schemas/Contact/entities/query",
+        "/schemas/Contact/entities/delete?dry_run=true",
+    ] {
+        let (status, json) =
+            json_request(&app, Method::POST, path, Some(vault_filter.clone())).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{path}: {json}");
+    }
+
+    // Hidden fields are never filterable, on the schema or a target.
+    let (status, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities?company.credit_score=AAA",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "body: {json}");
+    assert!(
+        json.to_string().contains("company.credit_score"),
+        "unexpected body: {json}"
+    );
+    for field in ["notes", "company.credit_score"] {
+        let body = serde_json::json!({ "filter": { format!("{field}__contains"): "A" } });
+        for path in [
+            "/schemas/Contact/entities/query",
+            "/schemas/Contact/entities/delete?dry_run=true",
+        ] {
+            let (status, json) = json_request(&app, Method::POST, path, Some(body.clone())).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{path} {field}: {json}");
+            assert!(json.to_string().contains(field), "unexpected body: {json}");
+        }
+    }
 }
 
-/// Regression for issue #12 and the partial-PATCH structural fix.
-///
-/// When PATCH only touches one field, the backend must receive an entity
-/// containing *only* that delta — not the full merged row. This keeps the
-/// backend's UPDATE narrow and structurally prevents the "null column gets
-/// rebound with the wrong type" class of bugs (#12 was one instance of it
-/// involving relation-many columns stored as text[]).
-///
-/// The observable surface of "narrow UPDATE" is that an unrelated field
-/// that was never included in the patch body is still present and
-/// unchanged after the round-trip. This test covers that semantic on a
-/// schema that mixes scalar, array, and nullable fields.
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn patch_entity_narrow_update_preserves_untouched_nullable_fields() {
+async fn get_missing_entity_returns_404() {
     let app = test_app().await;
 
-    // Schema with a required scalar, a required scalar we will patch, and
-    // two optional typed fields that stay null through the whole lifecycle.
-    // DateTime is the salient case: its null binding used to fall through
-    // to text before #10 landed typed-null binding.
+    // Create schema
     let schema_body = serde_json::json!({
-        "name": "Task",
+        "name": "Contact",
+        "fields": [{"name": "name", "field_type": "Text"}]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    // Use a valid but non-existent entity ID
+    let fake_id = schema_forge_core::types::EntityId::new("contact");
+    let path = format!("/schemas/Contact/entities/{}", fake_id.as_str());
+    let (status, _) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn update_entity_returns_200() {
+    let app = test_app().await;
+
+    // Create schema
+    let schema_body = serde_json::json!({
+        "name": "Contact",
         "fields": [
             {"name": "name", "field_type": "Text", "modifiers": ["required"]},
-            {"name": "duration_days", "field_type": "Float", "modifiers": ["required"]},
-            {"name": "early_start", "field_type": "DateTime"},
-            {"name": "total_slack", "field_type": "Float"}
+            {"name": "age", "field_type": "Integer"}
         ]
     });
     json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
 
-    // Create without setting `early_start` or `total_slack` so they stay null.
-    let create_body = serde_json::json!({
-        "fields": {
-            "name": "Discovery",
-            "duration_days": 5.0
-        }
-    });
-    let (_, created) = json_request(
+    // Create entity
+    let entity_body = serde_json::json!({
+        "fields": { "name": "Alice", "age": 30 }
+    });
+    let (_, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(entity_body),
+    )
+    .await;
+    let entity_id = created["id"].as_str().unwrap();
+
+    // Update entity
+    let update_body = serde_json::json!({
+        "fields": { "name": "Alice Updated", "age": 31 }
+    });
+    let path = format!("/schemas/Contact/entities/{entity_id}");
+    let (status, json) = json_request(&app, Method::PUT, &path, Some(update_body)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["fields"]["name"], "Alice Updated");
+    assert_eq!(json["fields"]["age"], 31);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn duplicate_unique_value_returns_409() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [
+            {"name": "name", "field_type": "Text"},
+            {"name": "email", "field_type": "Text", "modifiers": ["unique"]}
+        ]
+    });
+    let (status, json) = json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    assert_eq!(status, StatusCode::CREATED, "body: {json}");
+
+    let mut ids = Vec::new();
+    for (name, email) in [("Alice", "alice@example.com"), ("Bob", "bob@example.com")] {
+        let body = serde_json::json!({ "fields": { "name": name, "email": email } });
+        let (status, json) =
+            json_request(&app, Method::POST, "/schemas/Contact/entities", Some(body)).await;
+        assert_eq!(status, StatusCode::CREATED, "body: {json}");
+        ids.push(json["id"].as_str().unwrap().to_string());
+    }
+
+    let assert_conflict = |status: StatusCode, json: &serde_json::Value| {
+        assert_eq!(status, StatusCode::CONFLICT, "body: {json}");
+        assert_eq!(json["reason"], "unique_violation");
+        assert_eq!(json["message"], "email must be unique");
+    };
+
+    let body = serde_json::json!({
+        "fields": { "name": "Alice Again", "email": "alice@example.com" }
+    });
+    let (status, json) =
+        json_request(&app, Method::POST, "/schemas/Contact/entities", Some(body)).await;
+    assert_conflict(status, &json);
+
+    let path = format!("/schemas/Contact/entities/{}", ids[1]);
+    let body = serde_json::json!({ "fields": { "name": "Bob", "email": "alice@example.com" } });
+    let (status, json) = json_request(&app, Method::PUT, &path, Some(body)).await;
+    assert_conflict(status, &json);
+
+    // The rejected update left Bob's email untouched.
+    let (_, json) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(json["fields"]["email"], "bob@example.com");
+}
+
+/// Send a request with an optional `If-Match` header, returning the status,
+/// the `ETag` header, and the JSON body.
+async fn request_with_if_match(
+    app: &Router,
+    method: Method,
+    path: &str,
+    body: Option<serde_json::Value>,
+    if_match: Option<&str>,
+) -> (StatusCode, Option<String>, serde_json::Value) {
+    let mut request = Request::builder()
+        .method(method)
+        .uri(path)
+        .header("content-type", "application/json");
+    if let Some(if_match) = if_match {
+        request = request.header("if-match", if_match);
+    }
+    let body = match body {
+        Some(v) => Body::from(serde_json::to_vec(&v).unwrap()),
+        None => Body::empty(),
+    };
+    let response = app
+        .clone()
+        .oneshot(request.body(body).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let etag = response
+        .headers()
+        .get("etag")
+        .map(|v| v.to_str().unwrap().to_string());
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let json = serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null);
+    (status, etag, json)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn if_match_rejects_stale_writes_with_412() {
+    let app = test_app().await;
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [{"name": "name", "field_type": "Text", "modifiers": ["required"]}]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    let (_, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(serde_json::json!({"fields": {"name": "Alice"}})),
+    )
+    .await;
+    let path = format!(
+        "/schemas/Contact/entities/{}",
+        created["id"].as_str().unwrap()
+    );
+
+    let (status, etag, json) = request_with_if_match(&app, Method::GET, &path, None, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(etag.as_deref(), Some("\"1\""));
+    assert_eq!(json["version"], 1);
+
+    // A matching If-Match succeeds and bumps the version.
+    let (status, etag, json) = request_with_if_match(
+        &app,
+        Method::PUT,
+        &path,
+        Some(serde_json::json!({"fields": {"name": "Bob"}})),
+        Some("\"1\""),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(etag.as_deref(), Some("\"2\""));
+    assert_eq!(json["version"], 2);
+
+    // The version the client loaded is now stale on PUT and PATCH alike.
+    for method in [Method::PUT, Method::PATCH] {
+        let (status, _, json) = request_with_if_match(
+            &app,
+            method,
+            &path,
+            Some(serde_json::json!({"fields": {"name": "Carol"}})),
+            Some("\"1\""),
+        )
+        .await;
+        assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+        assert_eq!(json["error"], "precondition_failed");
+    }
+
+    // PATCH with the current version goes through; no If-Match stays
+    // unconditional.
+    let (status, etag, _) = request_with_if_match(
+        &app,
+        Method::PATCH,
+        &path,
+        Some(serde_json::json!({"fields": {"name": "Carol"}})),
+        Some("W/\"2\""),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(etag.as_deref(), Some("\"3\""));
+    let (status, _, json) = request_with_if_match(
+        &app,
+        Method::PUT,
+        &path,
+        Some(serde_json::json!({"fields": {"name": "Dave"}})),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["fields"]["name"], "Dave");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn delete_entity_returns_204() {
+    let app = test_app().await;
+
+    // Create schema
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [{"name": "name", "field_type": "Text", "modifiers": ["required"]}]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    // Create entity
+    let entity_body = serde_json::json!({
+        "fields": { "name": "Alice" }
+    });
+    let (_, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(entity_body),
+    )
+    .await;
+    let entity_id = created["id"].as_str().unwrap();
+
+    // Delete entity
+    let path = format!("/schemas/Contact/entities/{entity_id}");
+    let (status, _) = json_request(&app, Method::DELETE, &path, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // Verify it is gone
+    let (status, _) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn delete_missing_entity_returns_404() {
+    let app = test_app().await;
+
+    // Create schema
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [{"name": "name", "field_type": "Text"}]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    let fake_id = schema_forge_core::types::EntityId::new("contact");
+    let path = format!("/schemas/Contact/entities/{}", fake_id.as_str());
+    let (status, _) = json_request(&app, Method::DELETE, &path, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn soft_delete_hides_entity_until_restored() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [{"name": "name", "field_type": "Text", "modifiers": ["required"]}],
+        "annotations": [{"annotation": "SoftDelete"}]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    let (_, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(serde_json::json!({ "fields": { "name": "Alice" } })),
+    )
+    .await;
+    let entity_id = created["id"].as_str().unwrap();
+    let path = format!("/schemas/Contact/entities/{entity_id}");
+
+    let (status, _) = json_request(&app, Method::DELETE, &path, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (_, list) = json_request(&app, Method::GET, "/schemas/Contact/entities", None).await;
+    assert_eq!(list["entities"].as_array().unwrap().len(), 0);
+    let (_, list) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities?include_deleted=true",
+        None,
+    )
+    .await;
+    assert_eq!(list["entities"].as_array().unwrap().len(), 1);
+    assert!(list["entities"][0]["fields"]["_deleted_at"].is_string());
+
+    // Deleting twice is a 404: the entity is already gone from view.
+    let (status, _) = json_request(&app, Method::DELETE, &path, None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, restored) =
+        json_request(&app, Method::POST, &format!("{path}/restore"), None).await;
+    assert_eq!(status, StatusCode::OK, "unexpected body: {restored}");
+    assert_eq!(restored["fields"]["name"], "Alice");
+    assert!(restored["fields"].get("_deleted_at").is_none());
+
+    let (status, _) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn soft_deleted_entity_rejects_put_and_patch() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [{"name": "name", "field_type": "Text", "modifiers": ["required"]}],
+        "annotations": [{"annotation": "SoftDelete"}]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    let (_, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(serde_json::json!({ "fields": { "name": "Alice" } })),
+    )
+    .await;
+    let entity_id = created["id"].as_str().unwrap();
+    let path = format!("/schemas/Contact/entities/{entity_id}");
+    let (status, _) = json_request(&app, Method::DELETE, &path, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let body = serde_json::json!({ "fields": { "name": "Mallory" } });
+    let (status, _) = json_request(&app, Method::PUT, &path, Some(body.clone())).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = json_request(&app, Method::PATCH, &path, Some(body)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, json) = json_request(
+        &app,
+        Method::GET,
+        &format!("{path}?include_deleted=true"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["fields"]["name"], "Alice");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn count_endpoint_applies_list_filters_and_soft_delete() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [
+            {"name": "name", "field_type": "Text"},
+            {"name": "age", "field_type": "Integer"}
+        ],
+        "annotations": [{"annotation": "SoftDelete"}]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    let mut ids = Vec::new();
+    for (name, age) in [("Alice", 30), ("Bob", 17), ("Carol", 12)] {
+        let (_, created) = json_request(
+            &app,
+            Method::POST,
+            "/schemas/Contact/entities",
+            Some(serde_json::json!({ "fields": { "name": name, "age": age } })),
+        )
+        .await;
+        ids.push(created["id"].as_str().unwrap().to_string());
+    }
+
+    let (status, json) =
+        json_request(&app, Method::GET, "/schemas/Contact/entities/count", None).await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+    assert_eq!(json, serde_json::json!({ "count": 3 }));
+
+    // Pagination params are ignored; filters match the list endpoint.
+    let (_, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities/count?age__lt=18&limit=1",
+        None,
+    )
+    .await;
+    assert_eq!(json["count"], 2);
+
+    let path = format!("/schemas/Contact/entities/{}", ids[1]);
+    json_request(&app, Method::DELETE, &path, None).await;
+    let (_, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities/count?age__lt=18",
+        None,
+    )
+    .await;
+    assert_eq!(json["count"], 1);
+    let (_, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities/count?age__lt=18&include_deleted=true",
+        None,
+    )
+    .await;
+    assert_eq!(json["count"], 2);
+
+    let (status, _) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities/count?nickname=x",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+/// A `Member` schema with a unique `email` and a non-unique `name`, plus
+/// one member, Alice. Returns the app and Alice's id.
+async fn member_app() -> (Router, String) {
+    let app = test_app().await;
+    let schema_body = serde_json::json!({
+        "name": "Member",
+        "fields": [
+            {"name": "email", "field_type": "Text", "modifiers": ["unique"]},
+            {"name": "name", "field_type": "Text"}
+        ]
+    });
+    let (status, _) = json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Member/entities",
+        Some(serde_json::json!({ "fields": { "email": "alice@example.com", "name": "Alice" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "body: {created}");
+    let id = created["id"].as_str().unwrap().to_string();
+    (app, id)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_by_unique_field_returns_entity() {
+    let (app, id) = member_app().await;
+
+    let (status, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Member/entities/by/email/alice@example.com",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+    assert_eq!(json["id"], id);
+    assert_eq!(json["fields"]["name"], "Alice");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_by_unique_field_miss_returns_404() {
+    let (app, _) = member_app().await;
+
+    let (status, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Member/entities/by/email/bob@example.com",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND, "body: {json}");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_by_non_unique_field_returns_400() {
+    let (app, _) = member_app().await;
+
+    for path in [
+        "/schemas/Member/entities/by/name/Alice",
+        "/schemas/Member/entities/by/nickname/Al",
+    ] {
+        let (status, json) = json_request(&app, Method::GET, path, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{path}: {json}");
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn bulk_delete_removes_matching_entities() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [
+            {"name": "name", "field_type": "Text"},
+            {"name": "age", "field_type": "Integer"}
+        ]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    for (name, age) in [("Alice", 30), ("Bob", 17), ("Carol", 12)] {
+        json_request(
+            &app,
+            Method::POST,
+            "/schemas/Contact/entities",
+            Some(serde_json::json!({ "fields": { "name": name, "age": age } })),
+        )
+        .await;
+    }
+
+    // A missing or empty filter never empties the table by accident.
+    for body in [serde_json::json!({}), serde_json::json!({ "filter": {} })] {
+        let (status, json) = json_request(
+            &app,
+            Method::POST,
+            "/schemas/Contact/entities/delete",
+            Some(body),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "body: {json}");
+    }
+
+    let minors = serde_json::json!({ "filter": { "age__lt": 18 } });
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities/delete?dry_run=true",
+        Some(minors.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+    assert_eq!(json["matched"], 2);
+    assert_eq!(json["deleted"], 0);
+    assert_eq!(json["dry_run"], true);
+
+    let (_, json) = json_request(&app, Method::GET, "/schemas/Contact/entities", None).await;
+    assert_eq!(json["total_count"], 3);
+
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities/delete",
+        Some(minors),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+    assert_eq!(json["deleted"], 2);
+
+    let (_, json) = json_request(&app, Method::GET, "/schemas/Contact/entities", None).await;
+    assert_eq!(json["total_count"], 1);
+    assert_eq!(json["entities"][0]["fields"]["name"], "Alice");
+
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities/delete?all=true",
+        Some(serde_json::json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+    assert_eq!(json["deleted"], 1);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn csv_export_streams_labeled_escaped_rows() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [
+            {"name": "full_name", "field_type": "Text"},
+            {"name": "age", "field_type": "Integer"}
+        ]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(serde_json::json!({ "fields": { "full_name": "Doe, \"JD\" John", "age": 41 } })),
+    )
+    .await;
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/schemas/Contact/entities.csv?fields=age,full_name")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/csv; charset=utf-8"
+    );
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(
+        std::str::from_utf8(&bytes).unwrap(),
+        "Age,Full Name\r\n41,\"Doe, \"\"JD\"\" John\"\r\n"
+    );
+
+    let (status, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities.csv?fields=age,nickname",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(
+        json.to_string().contains("nickname"),
+        "unexpected body: {json}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn list_count_false_skips_total_without_filtering() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [{"name": "name", "field_type": "Text"}]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(serde_json::json!({ "fields": { "name": "Alice" } })),
+    )
+    .await;
+
+    let (status, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities?count=false&fields=name&sort=name",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+    assert_eq!(json["count"], 1);
+    assert_eq!(json.get("total_count"), Some(&serde_json::Value::Null));
+
+    let (_, json) = json_request(&app, Method::GET, "/schemas/Contact/entities", None).await;
+    assert_eq!(json["total_count"], 1);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn list_entities_timing_block_only_when_requested() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [{"name": "name", "field_type": "Text"}]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(serde_json::json!({ "fields": { "name": "Alice" } })),
+    )
+    .await;
+
+    let (status, json) = json_request(&app, Method::GET, "/schemas/Contact/entities", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(json.get("_timing").is_none(), "unexpected _timing: {json}");
+
+    let (status, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities?debug=timing",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["count"], 1);
+    assert_eq!(json["total_count"], 1);
+    let timing = &json["_timing"];
+    assert!(timing["query_ms"].is_number(), "missing query_ms: {json}");
+    assert!(timing["count_ms"].is_number(), "missing count_ms: {json}");
+    assert!(timing["ref_resolve_ms"].is_number());
+
+    let (_, json) = json_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities?debug=timing&count=false",
+        None,
+    )
+    .await;
+    assert!(json["_timing"]["count_ms"].is_null());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn aggregate_entities_groups_and_validates_metrics() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Deal",
+        "fields": [
+            {"name": "stage", "field_type": "Text"},
+            {"name": "value", "field_type": "Integer"}
+        ]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+    for (stage, value) in [("Open", 100), ("Open", 250), ("Won", 400)] {
+        json_request(
+            &app,
+            Method::POST,
+            "/schemas/Deal/entities",
+            Some(serde_json::json!({ "fields": { "stage": stage, "value": value } })),
+        )
+        .await;
+    }
+
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Deal/entities/aggregate",
+        Some(serde_json::json!({
+            "metrics": ["count", "sum:value", "max:value"],
+            "group_by": ["stage"]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "unexpected body: {json}");
+    let rows = json["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 2);
+    let open = rows
+        .iter()
+        .find(|r| r["group"]["stage"] == "Open")
+        .expect("missing Open group");
+    assert_eq!(open["metrics"]["count"], 2.0);
+    assert_eq!(open["metrics"]["sum:value"], 350.0);
+    assert_eq!(open["metrics"]["max:value"], 250.0);
+
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Deal/entities/aggregate",
+        Some(serde_json::json!({ "metrics": ["avg:stage"] })),
+    )
+    .await;
+    assert_eq!(
+        status,
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "unexpected body: {json}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn batch_create_entities_is_all_or_nothing() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [
+            {"name": "name", "field_type": "Text", "modifiers": ["required"]},
+            {"name": "age", "field_type": "Integer"}
+        ]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities/batch",
+        Some(serde_json::json!({
+            "entities": [
+                { "name": "Alice", "age": 30 },
+                { "name": "Bob" }
+            ]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "unexpected body: {json}");
+    let created = json["entities"].as_array().unwrap();
+    assert_eq!(created.len(), 2);
+    assert_eq!(created[0]["fields"]["name"], "Alice");
+    assert_eq!(created[1]["fields"]["name"], "Bob");
+
+    // The second entity is missing a required field and the third has a
+    // type error: nothing from this batch may be written.
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities/batch",
+        Some(serde_json::json!({
+            "entities": [
+                { "name": "Carol" },
+                { "age": 41 },
+                { "name": "Dave", "age": "old" }
+            ]
+        })),
+    )
+    .await;
+    assert_eq!(
+        status,
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "unexpected body: {json}"
+    );
+    let message = json.to_string();
+    assert!(message.contains("entities[1]"), "unexpected body: {json}");
+    assert!(message.contains("entities[2]"), "unexpected body: {json}");
+
+    let (_, json) = json_request(&app, Method::GET, "/schemas/Contact/entities", None).await;
+    assert_eq!(json["entities"].as_array().unwrap().len(), 2);
+}
+
+// ---------------------------------------------------------------------------
+// Cedar policy generation tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn cedar_policies_generated_for_schema() {
+    use schema_forge_acton::cedar::generate_cedar_policies;
+    use schema_forge_core::types::{
+        FieldDefinition, FieldName, FieldType, SchemaDefinition, SchemaId, SchemaName,
+        TextConstraints,
+    };
+
+    let schema = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Contact").unwrap(),
+        vec![FieldDefinition::new(
+            FieldName::new("name").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )],
+        vec![],
+    )
+    .unwrap();
+
+    let policies = generate_cedar_policies(&schema);
+    // Secure-by-default: a schema with no @access yields no user-facing
+    // permits. The two unconditional rules are the schema-admin permit and
+    // the tenant-isolation forbid (inert when the resource has no
+    // `_tenant`). Read/Create/Delete actions are not opened up to any role.
+    assert_eq!(policies.len(), 2);
+    assert!(policies
+        .iter()
+        .any(|p| p.cedar_text.contains("UpdateSchema") && p.cedar_text.contains("schema-admin")));
+    assert!(policies
+        .iter()
+        .any(|p| p.cedar_text.contains("tenant_guard")));
+}
+
+// ---------------------------------------------------------------------------
+// Extension builder tests
+// ---------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn extension_builder_with_backend_loads_schemas() {
+    use schema_forge_acton::SchemaForgeExtension;
+
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+
+    let builder = SchemaForgeExtension::builder().with_backend(backend);
+    let extension = builder.build().await.expect("failed to build extension");
+
+    // Registry should contain exactly the 3 system schemas after seeding.
+    // Cedar policies own role and permission definitions, so neither is
+    // represented as a database schema.
+    let schemas = extension.registry().list().await;
+    assert_eq!(schemas.len(), 3);
+    let names: Vec<String> = schemas
+        .iter()
+        .map(|s| s.name.as_str().to_string())
+        .collect();
+    assert!(names.contains(&"User".to_string()));
+    assert!(names.contains(&"TenantMembership".to_string()));
+    assert!(names.contains(&"WebhookSubscription".to_string()));
+    assert!(!names.contains(&"Role".to_string()));
+    assert!(!names.contains(&"Permission".to_string()));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn extension_apply_schemas_registers_and_makes_queryable() {
+    use schema_forge_acton::state::DynEntityStore;
+    use schema_forge_acton::SchemaForgeExtension;
+    use schema_forge_backend::entity::Entity;
+    use schema_forge_core::query::Query;
+    use schema_forge_core::types::{
+        DynamicValue, FieldDefinition, FieldName, FieldType, SchemaId, SchemaName, TextConstraints,
+    };
+
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+    let extension = SchemaForgeExtension::builder()
+        .with_backend(backend)
+        .build()
+        .await
+        .expect("failed to build extension");
+
+    let contact = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Contact").unwrap(),
+        vec![FieldDefinition::new(
+            FieldName::new("name").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )],
+        vec![],
+    )
+    .unwrap();
+
+    let plans = extension
+        .apply_schemas(std::slice::from_ref(&contact))
+        .await
+        .expect("apply_schemas");
+    assert_eq!(plans.len(), 1);
+    assert!(!plans[0].is_empty());
+
+    let registered = extension
+        .registry()
+        .get("Contact")
+        .await
+        .expect("Contact registered");
+    assert_eq!(registered.id, contact.id);
+
+    let state = extension.state();
+    let entity = Entity::new(
+        contact.name.clone(),
+        [("name".to_string(), DynamicValue::Text("Ada".into()))]
+            .into_iter()
+            .collect(),
+    );
+    state.backend.create(&entity).await.expect("create entity");
+    let result = state
+        .backend
+        .query(&Query::new(registered.id.clone()))
+        .await
+        .expect("query entities");
+    assert_eq!(result.entities.len(), 1);
+    assert_eq!(
+        result.entities[0].field("name"),
+        Some(&DynamicValue::Text("Ada".into()))
+    );
+
+    // Re-applying the same definition is a no-op.
+    let plans = extension
+        .apply_schemas(std::slice::from_ref(&contact))
+        .await
+        .expect("re-apply");
+    assert!(plans[0].is_empty());
+}
+
+/// Wraps a `SurrealBackend` and counts schema writes and entity reads, so
+/// tests can assert how often a path reached the backend.
+struct CountingBackend {
+    inner: SurrealBackend,
+    migrations: Arc<std::sync::atomic::AtomicUsize>,
+    metadata_writes: Arc<std::sync::atomic::AtomicUsize>,
+    gets: Arc<std::sync::atomic::AtomicUsize>,
+    queries: Arc<std::sync::atomic::AtomicUsize>,
+}
+
+mod counting_backend {
+    use std::collections::BTreeMap;
+    use std::sync::atomic::Ordering;
+
+    use schema_forge_backend::entity::{Entity, QueryResult};
+    use schema_forge_backend::error::BackendError;
+    use schema_forge_backend::traits::{EntityStore, SchemaBackend};
+    use schema_forge_core::migration::MigrationStep;
+    use schema_forge_core::query::{AggregateQuery, AggregateResult, Query};
+    use schema_forge_core::types::{
+        DynamicValue, EntityId, FieldName, SchemaDefinition, SchemaName,
+    };
+
+    use super::CountingBackend;
+
+    impl SchemaBackend for CountingBackend {
+        async fn apply_migration(
+            &self,
+            schema_name: &SchemaName,
+            steps: &[MigrationStep],
+        ) -> Result<(), BackendError> {
+            self.migrations.fetch_add(1, Ordering::SeqCst);
+            self.inner.apply_migration(schema_name, steps).await
+        }
+
+        async fn store_schema_metadata(
+            &self,
+            definition: &SchemaDefinition,
+        ) -> Result<(), BackendError> {
+            self.metadata_writes.fetch_add(1, Ordering::SeqCst);
+            self.inner.store_schema_metadata(definition).await
+        }
+
+        async fn load_schema_metadata(
+            &self,
+            name: &SchemaName,
+        ) -> Result<Option<SchemaDefinition>, BackendError> {
+            self.inner.load_schema_metadata(name).await
+        }
+
+        async fn list_schema_metadata(&self) -> Result<Vec<SchemaDefinition>, BackendError> {
+            self.inner.list_schema_metadata().await
+        }
+    }
+
+    impl EntityStore for CountingBackend {
+        async fn create(&self, entity: &Entity) -> Result<Entity, BackendError> {
+            self.inner.create(entity).await
+        }
+
+        async fn create_many(&self, entities: &[Entity]) -> Result<Vec<Entity>, BackendError> {
+            self.inner.create_many(entities).await
+        }
+
+        async fn get(&self, schema: &SchemaName, id: &EntityId) -> Result<Entity, BackendError> {
+            self.gets.fetch_add(1, Ordering::SeqCst);
+            self.inner.get(schema, id).await
+        }
+
+        async fn update(
+            &self,
+            entity: &Entity,
+            expected_version: Option<u64>,
+        ) -> Result<Entity, BackendError> {
+            self.inner.update(entity, expected_version).await
+        }
+
+        async fn patch(
+            &self,
+            schema: &SchemaName,
+            id: &EntityId,
+            changes: BTreeMap<String, DynamicValue>,
+            expected_version: Option<u64>,
+        ) -> Result<Entity, BackendError> {
+            self.inner
+                .patch(schema, id, changes, expected_version)
+                .await
+        }
+
+        async fn delete(&self, schema: &SchemaName, id: &EntityId) -> Result<(), BackendError> {
+            self.inner.delete(schema, id).await
+        }
+
+        async fn restore(
+            &self,
+            schema: &SchemaName,
+            id: &EntityId,
+        ) -> Result<Entity, BackendError> {
+            self.inner.restore(schema, id).await
+        }
+
+        async fn query(&self, query: &Query) -> Result<QueryResult, BackendError> {
+            self.queries.fetch_add(1, Ordering::SeqCst);
+            self.inner.query(query).await
+        }
+
+        async fn count(&self, query: &Query) -> Result<usize, BackendError> {
+            self.inner.count(query).await
+        }
+
+        async fn aggregate(
+            &self,
+            query: &AggregateQuery,
+        ) -> Result<Vec<AggregateResult>, BackendError> {
+            self.inner.aggregate(query).await
+        }
+
+        async fn next_sequence_value(
+            &self,
+            schema: &SchemaName,
+            field: &FieldName,
+            start: i64,
+        ) -> Result<i64, BackendError> {
+            self.inner.next_sequence_value(schema, field, start).await
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn extension_apply_schemas_is_noop_when_unchanged() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use schema_forge_acton::SchemaForgeExtension;
+    use schema_forge_core::types::{
+        FieldDefinition, FieldName, FieldType, SchemaId, SchemaName, TextConstraints,
+    };
+
+    let migrations = Arc::new(AtomicUsize::new(0));
+    let metadata_writes = Arc::new(AtomicUsize::new(0));
+    let backend = CountingBackend {
+        inner: SurrealBackend::connect_memory("test", "test")
+            .await
+            .expect("failed to connect to in-memory SurrealDB"),
+        migrations: migrations.clone(),
+        metadata_writes: metadata_writes.clone(),
+        gets: Arc::default(),
+        queries: Arc::default(),
+    };
+    let extension = SchemaForgeExtension::builder()
+        .with_backend(backend)
+        .build()
+        .await
+        .expect("failed to build extension");
+
+    let make_contact = || {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("name").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            )],
+            vec![],
+        )
+        .unwrap()
+    };
+
+    let plans = extension
+        .apply_schemas(&[make_contact()])
+        .await
+        .expect("first apply");
+    assert!(!plans[0].is_empty());
+    assert_eq!(migrations.load(Ordering::SeqCst), 1);
+    assert_eq!(metadata_writes.load(Ordering::SeqCst), 1);
+    let policies_before = Arc::as_ptr(&extension.state().policy_store.current());
+
+    // A freshly parsed copy gets a new id but is otherwise identical.
+    let plans = extension
+        .apply_schemas(&[make_contact()])
+        .await
+        .expect("second apply");
+    assert_eq!(plans.len(), 1);
+    assert!(plans[0].is_empty());
+    assert_eq!(migrations.load(Ordering::SeqCst), 1);
+    assert_eq!(metadata_writes.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        Arc::as_ptr(&extension.state().policy_store.current()),
+        policies_before
+    );
+}
+
+#[cfg(feature = "graphql")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn graphql_relations_are_fetched_in_one_batch() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use schema_forge_acton::SchemaForgeExtension;
+    use schema_forge_backend::entity::Entity;
+    use schema_forge_core::types::{
+        Cardinality, DynamicValue, FieldDefinition, FieldName, FieldType, SchemaId, SchemaName,
+        TextConstraints,
+    };
+
+    let gets = Arc::new(AtomicUsize::new(0));
+    let queries = Arc::new(AtomicUsize::new(0));
+    let backend = CountingBackend {
+        inner: SurrealBackend::connect_memory("test", "test")
+            .await
+            .expect("failed to connect to in-memory SurrealDB"),
+        migrations: Arc::default(),
+        metadata_writes: Arc::default(),
+        gets: gets.clone(),
+        queries: queries.clone(),
+    };
+    let extension = SchemaForgeExtension::builder()
+        .with_backend(backend)
+        .build()
+        .await
+        .expect("failed to build extension");
+
+    let text = |name: &str| {
+        FieldDefinition::new(
+            FieldName::new(name).unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )
+    };
+    let agency = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Agency").unwrap(),
+        vec![text("name")],
+        vec![],
+    )
+    .unwrap();
+    let opportunity = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Opportunity").unwrap(),
+        vec![
+            text("title"),
+            FieldDefinition::new(
+                FieldName::new("agency").unwrap(),
+                FieldType::Relation {
+                    target: SchemaName::new("Agency").unwrap(),
+                    cardinality: Cardinality::One,
+                },
+            ),
+        ],
+        vec![],
+    )
+    .unwrap();
+    extension
+        .apply_schemas(&[agency.clone(), opportunity.clone()])
+        .await
+        .expect("apply schemas");
+
+    let backend = &extension.state().backend;
+    let mut agency_ids = Vec::new();
+    for name in ["DOE", "NASA", "NSF"] {
+        let created = backend
+            .create(&Entity::new(
+                agency.name.clone(),
+                [("name".to_string(), DynamicValue::Text(name.into()))]
+                    .into_iter()
+                    .collect(),
+            ))
+            .await
+            .expect("create agency");
+        agency_ids.push(created.id);
+    }
+    for i in 0..100 {
+        let agency_id = agency_ids[i % agency_ids.len()].clone();
+        backend
+            .create(&Entity::new(
+                opportunity.name.clone(),
+                [
+                    (
+                        "title".to_string(),
+                        DynamicValue::Text(format!("Grant {i}")),
+                    ),
+                    ("agency".to_string(), DynamicValue::Ref(agency_id)),
+                ]
+                .into_iter()
+                .collect(),
+            ))
+            .await
+            .expect("create opportunity");
+    }
+
+    let claims = make_test_claims(&["platform_admin"]);
+    let app = extension
+        .register_graphql_routes(Router::new())
+        .layer(axum::middleware::from_fn(
+            move |mut req: axum::extract::Request, next: axum::middleware::Next| {
+                let claims = claims.clone();
+                async move {
+                    req.extensions_mut().insert(claims);
+                    next.run(req).await
+                }
+            },
+        ));
+
+    gets.store(0, Ordering::SeqCst);
+    queries.store(0, Ordering::SeqCst);
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/forge/graphql",
+        Some(serde_json::json!({
+            "query": "{ opportunities(first: 100) { edges { node { title agency { name } } } } }"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(json["errors"].is_null(), "unexpected errors: {json}");
+
+    let edges = json["data"]["opportunities"]["edges"].as_array().unwrap();
+    assert_eq!(edges.len(), 100);
+    assert!(edges
+        .iter()
+        .all(|e| e["node"]["agency"]["name"].is_string()));
+    // One query for the page and one for every referenced agency.
+    assert_eq!(queries.load(Ordering::SeqCst), 2);
+    assert_eq!(gets.load(Ordering::SeqCst), 0);
+}
+
+/// Build an extension over an in-memory backend with `schemas` applied,
+/// and return its GraphQL router authenticated as a platform admin.
+#[cfg(feature = "graphql")]
+async fn graphql_app(schemas: &[SchemaDefinition]) -> Router {
+    graphql_app_with(schema_forge_acton::SchemaForgeExtension::builder(), schemas).await
+}
+
+/// [`graphql_app`] from a pre-configured extension builder.
+#[cfg(feature = "graphql")]
+async fn graphql_app_with(
+    builder: schema_forge_acton::extension::SchemaForgeExtensionBuilder,
+    schemas: &[SchemaDefinition],
+) -> Router {
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+    let extension = builder
+        .with_backend(backend)
+        .build()
+        .await
+        .expect("failed to build extension");
+    extension
+        .apply_schemas(schemas)
+        .await
+        .expect("apply schemas");
+
+    let claims = make_test_claims(&["platform_admin"]);
+    extension
+        .register_graphql_routes(Router::new())
+        .layer(axum::middleware::from_fn(
+            move |mut req: axum::extract::Request, next: axum::middleware::Next| {
+                let claims = claims.clone();
+                async move {
+                    req.extensions_mut().insert(claims);
+                    next.run(req).await
+                }
+            },
+        ))
+}
+
+/// Run a GraphQL document against a [`graphql_app`] router, asserting it
+/// succeeds, and return its `data`.
+#[cfg(feature = "graphql")]
+async fn graphql_data(app: &Router, query: &str) -> serde_json::Value {
+    let (status, json) = json_request(
+        app,
+        Method::POST,
+        "/forge/graphql",
+        Some(serde_json::json!({ "query": query })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(json["errors"].is_null(), "unexpected errors: {json}");
+    json["data"].clone()
+}
+
+#[cfg(feature = "graphql")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn graphql_create_assigns_sequence_defaults() {
+    use schema_forge_core::types::{
+        DefaultValue, FieldDefinition, FieldModifier, FieldName, FieldType, SchemaId, SchemaName,
+        TextConstraints,
+    };
+
+    let invoice = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Invoice").unwrap(),
+        vec![
+            FieldDefinition::with_modifiers(
+                FieldName::new("number").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![FieldModifier::Default {
+                    value: DefaultValue::Sequence {
+                        prefix: "INV-".into(),
+                        start: 1000,
+                    },
+                }],
+            ),
+            FieldDefinition::new(
+                FieldName::new("customer").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            ),
+        ],
+        vec![],
+    )
+    .unwrap();
+    let app = graphql_app(&[invoice]).await;
+
+    for expected in ["INV-1001", "INV-1002"] {
+        let data = graphql_data(
+            &app,
+            r#"mutation { createInvoice(input: { customer: "Acme" }) { number } }"#,
+        )
+        .await;
+        assert_eq!(data["createInvoice"]["number"], expected);
+    }
+}
+
+#[cfg(feature = "graphql")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn graphql_sdl_export_tracks_applied_schemas() {
+    use schema_forge_acton::graphql::export_sdl;
+    use schema_forge_acton::SchemaForgeExtension;
+    use schema_forge_core::types::{
+        FieldDefinition, FieldName, FieldType, SchemaId, SchemaName, TextConstraints,
+    };
+
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+    let extension = SchemaForgeExtension::builder()
+        .with_backend(backend)
+        .build()
+        .await
+        .expect("failed to build extension");
+    assert!(!export_sdl(extension.state()).contains("type Contact"));
+
+    let contact = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Contact").unwrap(),
+        vec![FieldDefinition::new(
+            FieldName::new("name").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )],
+        vec![],
+    )
+    .unwrap();
+    extension
+        .apply_schemas(&[contact])
+        .await
+        .expect("apply schemas");
+
+    let sdl = export_sdl(extension.state());
+    assert!(sdl.contains("type Contact"), "{sdl}");
+    assert!(sdl.contains("): ContactConnection!"), "{sdl}");
+    assert!(sdl.contains("createContact("), "{sdl}");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn extension_register_routes_nests_under_forge() {
+    // Build actor-backed AppState and nest forge routes under /forge
+    let state = test_app_state().await;
+    let router: Router = Router::new();
+    let forge_router: Router<()> = forge_routes().with_state(state);
+    let router = router.nest("/forge", forge_router);
+
+    // Test that we can hit /forge/schemas (requires auth)
+    let mut request = Request::builder()
+        .method(Method::GET)
+        .uri("/forge/schemas")
+        .header("content-type", "application/json")
+        .body(Body::empty())
+        .unwrap();
+    request
+        .extensions_mut()
+        .insert(make_test_claims(&["platform_admin"]));
+
+    let response = router.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+// ---------------------------------------------------------------------------
+// Auth integration tests
+// ---------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn request_with_admin_claims_succeeds() {
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+    let state = build_test_app_state(TestForgeInit {
+        backend: Arc::new(backend),
+        registry: HashMap::new(),
+        tenant_config: None,
+        record_access_policy: None,
+        hook_dispatcher: None,
+    })
+    .await;
+    let app = test_app_with_claims_state(state, make_test_claims(&["platform_admin"]));
+
+    // Create a schema to verify the request goes through
+    let body = serde_json::json!({
+        "name": "Contact",
+        "fields": [{"name": "name", "field_type": "Text"}]
+    });
+    let (status, json) = json_request(&app, Method::POST, "/schemas", Some(body)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(json["name"], "Contact");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn request_without_claims_returns_401() {
+    use schema_forge_core::types::{
+        Annotation, FieldDefinition, FieldName, FieldType, SchemaDefinition, SchemaId, SchemaName,
+        TextConstraints,
+    };
+
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+
+    // Register a schema so the route doesn't 404 before reaching auth check
+    let schema = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Contact").unwrap(),
+        vec![FieldDefinition::new(
+            FieldName::new("name").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )],
+        vec![Annotation::Access {
+            read: vec!["viewer".to_string()],
+            write: vec!["editor".to_string()],
+            delete: vec!["admin".to_string()],
+            cross_tenant_read: vec![],
+        }],
+    )
+    .unwrap();
+
+    let backend = Arc::new(backend);
+    let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
+    backend
+        .apply_migration(&schema.name, &plan.steps)
+        .await
+        .expect("apply migration");
+    backend
+        .store_schema_metadata(&schema)
+        .await
+        .expect("store metadata");
+
+    let mut registry = HashMap::new();
+    registry.insert("Contact".to_string(), schema.clone());
+
+    let state = build_test_app_state(TestForgeInit {
+        backend,
+        registry,
+        tenant_config: None,
+        record_access_policy: None,
+        hook_dispatcher: None,
+    })
+    .await;
+    // No Claims injected — requests should get 401
+    let app = test_app_with_state(state);
+
+    let (status, json) = json_request(&app, Method::GET, "/schemas/Contact/entities", None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(json["error"], "unauthorized");
+}
+
+// ---------------------------------------------------------------------------
+// Access control integration tests
+// ---------------------------------------------------------------------------
+
+/// Helper to create an AppState with a pre-registered schema with @access annotations,
+/// and build a test app with the given Claims.
+async fn access_test_state(
+    user_roles: Vec<String>,
+    schema_read_roles: Vec<String>,
+    schema_write_roles: Vec<String>,
+    schema_delete_roles: Vec<String>,
+) -> (AppState<SchemaForgeConfig>, Router) {
+    use schema_forge_core::types::{
+        Annotation, FieldDefinition, FieldName, FieldType, SchemaDefinition, SchemaId, SchemaName,
+        TextConstraints,
+    };
+
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+
+    // Create a schema with @access annotation
+    let schema = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Article").unwrap(),
+        vec![
+            FieldDefinition::new(
+                FieldName::new("title").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            ),
+            FieldDefinition::new(
+                FieldName::new("body").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            ),
+        ],
+        vec![Annotation::Access {
+            read: schema_read_roles,
+            write: schema_write_roles,
+            delete: schema_delete_roles,
+            cross_tenant_read: vec![],
+        }],
+    )
+    .unwrap();
+
+    let mut registry = HashMap::new();
+    registry.insert("Article".to_string(), schema.clone());
+
+    // Apply migration so the backend table exists
+    let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
+    let backend = Arc::new(backend);
+    backend
+        .apply_migration(&schema.name, &plan.steps)
+        .await
+        .expect("failed to apply migration");
+    backend
+        .store_schema_metadata(&schema)
+        .await
+        .expect("failed to store metadata");
+
+    let state = build_test_app_state(TestForgeInit {
+        backend,
+        registry,
+        tenant_config: None,
+        record_access_policy: None,
+        hook_dispatcher: None,
+    })
+    .await;
+
+    let claims = make_test_claims(&user_roles.iter().map(|s| s.as_str()).collect::<Vec<_>>());
+    let app = test_app_with_claims_state(state.clone(), claims);
+    (state, app)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn authenticated_request_with_matching_role_succeeds() {
+    let (_state, app) = access_test_state(
+        vec!["editor".to_string()],
+        vec!["viewer".to_string(), "editor".to_string()],
+        vec!["editor".to_string()],
+        vec!["admin".to_string()],
+    )
+    .await;
+
+    // Create entity -- user has "editor" role which is in write roles
+    let entity_body = serde_json::json!({
+        "fields": {
+            "title": "Hello World",
+            "body": "Content here"
+        }
+    });
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Article/entities",
+        Some(entity_body),
+    )
+    .await;
+    assert_eq!(
+        status,
+        StatusCode::CREATED,
+        "expected 201, got {status} with body: {json}"
+    );
+    assert_eq!(json["schema"], "Article");
+    assert_eq!(json["fields"]["title"], "Hello World");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn authenticated_request_with_wrong_role_gets_403() {
+    let (_state, app) = access_test_state(
+        vec!["viewer".to_string()],
+        vec!["viewer".to_string()],
+        vec!["editor".to_string()],
+        vec!["admin".to_string()],
+    )
+    .await;
+
+    // Create entity -- user has "viewer" role, write requires "editor"
+    let entity_body = serde_json::json!({
+        "fields": {
+            "title": "Forbidden Article",
+            "body": "Should fail"
+        }
+    });
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Article/entities",
+        Some(entity_body),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(json["error"], "forbidden");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn request_without_claims_on_access_controlled_schema_returns_401() {
+    use schema_forge_core::types::{
+        Annotation, FieldDefinition, FieldName, FieldType, SchemaDefinition, SchemaId, SchemaName,
+        TextConstraints,
+    };
+
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+
+    // Schema with restrictive @access
+    let schema = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Secret").unwrap(),
+        vec![FieldDefinition::new(
+            FieldName::new("data").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )],
+        vec![Annotation::Access {
+            read: vec!["classified".to_string()],
+            write: vec!["classified".to_string()],
+            delete: vec!["classified".to_string()],
+            cross_tenant_read: vec![],
+        }],
+    )
+    .unwrap();
+
+    let mut registry = HashMap::new();
+    registry.insert("Secret".to_string(), schema.clone());
+
+    let backend = Arc::new(backend);
+    let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
+    backend
+        .apply_migration(&schema.name, &plan.steps)
+        .await
+        .expect("failed to apply migration");
+    backend
+        .store_schema_metadata(&schema)
+        .await
+        .expect("failed to store metadata");
+
+    // No Claims injected — should get 401
+    let state = build_test_app_state(TestForgeInit {
+        backend,
+        registry,
+        tenant_config: None,
+        record_access_policy: None,
+        hook_dispatcher: None,
+    })
+    .await;
+    let app = test_app_with_state(state);
+
+    let entity_body = serde_json::json!({
+        "fields": { "data": "top secret" }
+    });
+    let (status, _json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Secret/entities",
+        Some(entity_body),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn field_filtering_hides_restricted_fields() {
+    use schema_forge_core::types::{
+        FieldAnnotation, FieldDefinition, FieldName, FieldType, SchemaDefinition, SchemaId,
+        SchemaName, TextConstraints,
+    };
+
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+
+    // Schema with a field-level access restriction
+    // @access with empty lists = all authenticated users permitted (testing field-level, not schema-level)
+    use schema_forge_core::types::Annotation;
+    let schema = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Employee").unwrap(),
+        vec![
+            FieldDefinition::new(
+                FieldName::new("name").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            ),
+            FieldDefinition::with_annotations(
+                FieldName::new("salary").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![],
+                vec![FieldAnnotation::FieldAccess {
+                    read: vec!["hr".to_string()],
+                    write: vec!["hr".to_string()],
+                }],
+            ),
+        ],
+        vec![Annotation::Access {
+            read: vec![],
+            write: vec![],
+            delete: vec![],
+            cross_tenant_read: vec![],
+        }],
+    )
+    .unwrap();
+
+    let mut registry = HashMap::new();
+    registry.insert("Employee".to_string(), schema.clone());
+
+    let backend = Arc::new(backend);
+    let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
+    backend
+        .apply_migration(&schema.name, &plan.steps)
+        .await
+        .expect("failed to apply migration");
+    backend
+        .store_schema_metadata(&schema)
+        .await
+        .expect("failed to store metadata");
+
+    // User with "member" role (not "hr")
+    let state = build_test_app_state(TestForgeInit {
+        backend,
+        registry,
+        tenant_config: None,
+        record_access_policy: None,
+        hook_dispatcher: None,
+    })
+    .await;
+    let app = test_app_with_claims_state(state, make_test_claims(&["member"]));
+
+    // Create entity with both fields
+    let entity_body = serde_json::json!({
+        "fields": {
+            "name": "Alice",
+            "salary": "100000"
+        }
+    });
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Employee/entities",
+        Some(entity_body),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // The response should include "name" but NOT "salary" (filtered by read access)
+    assert_eq!(json["fields"]["name"], "Alice");
+    assert!(
+        json["fields"].get("salary").is_none()
+            || json["fields"]["salary"] == serde_json::Value::Null,
+        "salary field should be filtered from response, got: {:?}",
+        json["fields"]
+    );
+
+    // Get the entity back and verify salary is still filtered
+    let entity_id = json["id"].as_str().unwrap();
+    let path = format!("/schemas/Employee/entities/{entity_id}");
+    let (status, get_json) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(get_json["fields"]["name"], "Alice");
+    assert!(
+        get_json["fields"].get("salary").is_none()
+            || get_json["fields"]["salary"] == serde_json::Value::Null,
+        "salary field should be filtered from GET response, got: {:?}",
+        get_json["fields"]
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Issue #10 regression tests: GET → modify → PUT round-trip + PATCH support
+// ---------------------------------------------------------------------------
+
+/// `@hidden` fields are output-shaping only: platform admins may write them,
+/// every API response omits them, and the stored value stays readable from
+/// the backend.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn hidden_field_is_stored_but_never_returned() {
+    use schema_forge_backend::traits::EntityStore;
+    use schema_forge_core::types::{
+        DynamicValue, EntityId, FieldAnnotation, FieldDefinition, FieldName, FieldType,
+        IntegerConstraints, SchemaId, SchemaName, TextConstraints,
+    };
+
+    let backend = Arc::new(
+        SurrealBackend::connect_memory("test", "test")
+            .await
+            .expect("failed to connect to in-memory SurrealDB"),
+    );
+    let schema = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Lead").unwrap(),
+        vec![
+            FieldDefinition::new(
+                FieldName::new("name").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            ),
+            FieldDefinition::with_annotations(
+                FieldName::new("internal_score").unwrap(),
+                FieldType::Integer(IntegerConstraints::unconstrained()),
+                vec![],
+                vec![FieldAnnotation::Hidden],
+            ),
+        ],
+        vec![],
+    )
+    .unwrap();
+    let plan = schema_forge_core::migration::DiffEngine::create_new(&schema);
+    backend
+        .apply_migration(&schema.name, &plan.steps)
+        .await
+        .expect("apply migration");
+    backend
+        .store_schema_metadata(&schema)
+        .await
+        .expect("store metadata");
+    let registry = HashMap::from([("Lead".to_string(), schema)]);
+
+    let state = build_test_app_state(TestForgeInit {
+        backend: backend.clone(),
+        registry,
+        tenant_config: None,
+        record_access_policy: None,
+        hook_dispatcher: None,
+    })
+    .await;
+    let admin = test_app_with_claims_state(state.clone(), make_test_claims(&["platform_admin"]));
+    let member = test_app_with_claims_state(state, make_test_claims(&["member"]));
+
+    // Ordinary callers cannot set a hidden field.
+    let (status, json) = json_request(
+        &member,
+        Method::POST,
+        "/schemas/Lead/entities",
+        Some(serde_json::json!({ "fields": { "name": "Acme", "internal_score": 7 } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "body: {json}");
+
+    // A platform admin can, but the response and change event still omit it.
+    let mut events = open_event_stream(&admin, "Lead").await;
+    let (status, json) = json_request(
+        &admin,
+        Method::POST,
+        "/schemas/Lead/entities",
+        Some(serde_json::json!({ "fields": { "name": "Acme", "internal_score": 42 } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "body: {json}");
+    assert!(
+        json["fields"].get("internal_score").is_none(),
+        "body: {json}"
+    );
+    let id = json["id"].as_str().unwrap().to_string();
+    let (_, payload) = next_sse_event(&mut events).await;
+    assert_eq!(payload["fields"]["name"], "Acme");
+    assert!(
+        payload["fields"].get("internal_score").is_none(),
+        "event: {payload}"
+    );
+
+    for app in [&admin, &member] {
+        let (status, json) = json_request(
+            app,
+            Method::GET,
+            &format!("/schemas/Lead/entities/{id}"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "body: {json}");
+        assert_eq!(json["fields"]["name"], "Acme");
+        assert!(
+            json["fields"].get("internal_score").is_none(),
+            "body: {json}"
+        );
+
+        let (status, json) = json_request(app, Method::GET, "/schemas/Lead/entities", None).await;
+        assert_eq!(status, StatusCode::OK, "body: {json}");
+        assert!(
+            json["entities"][0]["fields"]
+                .get("internal_score")
+                .is_none(),
+            "body: {json}"
+        );
+    }
+
+    // The value is persisted and readable below the API layer.
+    let stored = EntityStore::get(
+        backend.as_ref(),
+        &SchemaName::new("Lead").unwrap(),
+        &EntityId::parse(&id).unwrap(),
+    )
+    .await
+    .expect("backend get");
+    assert_eq!(
+        stored.field("internal_score"),
+        Some(&DynamicValue::Integer(42))
+    );
+}
+
+/// Regression for issue #10: POST → GET → PUT (with the GET response body
+/// echoed back unchanged) must succeed with 200. The pre-fix behavior
+/// returned 502 for two unrelated reasons: GET emitted a `+00:00` datetime
+/// suffix that the client could echo back fine but that downstream null
+/// coercion and full-replacement validation tripped over; and the postgres
+/// binder bound `null` fields as `text`, mismatching typed columns. This
+/// test exercises the round-trip on the in-memory SurrealDB backend (which
+/// shares the field-conversion code path with postgres), guaranteeing the
+/// JSON serialization and required-field checks both accept the round-tripped
+/// payload.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn put_round_trip_after_get_returns_200() {
+    let app = test_app().await;
+
+    // Schema: required name + optional updated_at datetime + optional notes.
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [
+            {"name": "name", "field_type": "Text", "modifiers": ["required"]},
+            {"name": "updated_at", "field_type": "DateTime"},
+            {"name": "notes", "field_type": "Text"}
+        ]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    // POST an entity that exercises a datetime field.
+    let create_body = serde_json::json!({
+        "fields": {
+            "name": "Alice",
+            "updated_at": "2026-04-13T12:00:00Z",
+            "notes": "initial"
+        }
+    });
+    let (create_status, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(create_body),
+    )
+    .await;
+    assert_eq!(create_status, StatusCode::CREATED);
+    let entity_id = created["id"].as_str().unwrap().to_string();
+    let path = format!("/schemas/Contact/entities/{entity_id}");
+
+    // GET the entity — capture its serialized fields verbatim.
+    let (get_status, fetched) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(get_status, StatusCode::OK);
+    let fetched_fields = fetched["fields"].clone();
+
+    // The GET response must serialize datetimes with a `Z` suffix
+    // (issue #10 fix #1) so generic clients consuming the format are happy.
+    let serialized_dt = fetched_fields["updated_at"].as_str().unwrap();
+    assert!(
+        serialized_dt.ends_with('Z'),
+        "expected datetime to end in 'Z', got {serialized_dt}"
+    );
+
+    // PUT the GET response body back unchanged. Pre-fix this returned 502;
+    // post-fix it must return 200 because (a) the round-tripped datetime
+    // re-parses cleanly and (b) the full payload satisfies the required-
+    // field check.
+    let put_body = serde_json::json!({ "fields": fetched_fields });
+    let (put_status, put_json) = json_request(&app, Method::PUT, &path, Some(put_body)).await;
+    assert_eq!(
+        put_status,
+        StatusCode::OK,
+        "round-trip PUT should succeed, got {put_status} body={put_json}"
+    );
+    assert_eq!(put_json["fields"]["name"], "Alice");
+}
+
+/// Regression for issue #10: PATCH must merge a partial payload onto the
+/// existing entity, preserving fields that are not mentioned in the
+/// request body — including required ones.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn patch_entity_merges_partial_fields() {
+    let app = test_app().await;
+
+    // Schema with two required fields plus one optional field. PATCH must
+    // be able to update only `notes` without re-supplying `name` or `email`.
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [
+            {"name": "name", "field_type": "Text", "modifiers": ["required"]},
+            {"name": "email", "field_type": "Text", "modifiers": ["required"]},
+            {"name": "notes", "field_type": "Text"}
+        ]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    let create_body = serde_json::json!({
+        "fields": {
+            "name": "Alice",
+            "email": "alice@example.com",
+            "notes": "initial"
+        }
+    });
+    let (_, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(create_body),
+    )
+    .await;
+    let entity_id = created["id"].as_str().unwrap().to_string();
+    let path = format!("/schemas/Contact/entities/{entity_id}");
+
+    // Patch only the notes field.
+    let patch_body = serde_json::json!({ "fields": { "notes": "patched" } });
+    let (patch_status, patched) = json_request(&app, Method::PATCH, &path, Some(patch_body)).await;
+    assert_eq!(
+        patch_status,
+        StatusCode::OK,
+        "PATCH should succeed, got {patch_status} body={patched}"
+    );
+
+    // The unmodified required fields must still be present and unchanged.
+    assert_eq!(patched["fields"]["name"], "Alice");
+    assert_eq!(patched["fields"]["email"], "alice@example.com");
+    assert_eq!(patched["fields"]["notes"], "patched");
+
+    // Confirm via GET that the merge was actually persisted.
+    let (_, fetched) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(fetched["fields"]["name"], "Alice");
+    assert_eq!(fetched["fields"]["email"], "alice@example.com");
+    assert_eq!(fetched["fields"]["notes"], "patched");
+
+    // Clearing a required field leaves the merged entity invalid.
+    let patch_body = serde_json::json!({ "fields": { "email": null } });
+    let (patch_status, body) = json_request(&app, Method::PATCH, &path, Some(patch_body)).await;
+    assert_eq!(
+        patch_status,
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "unexpected body: {body}"
+    );
+    let (_, fetched) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(fetched["fields"]["email"], "alice@example.com");
+}
+
+/// Regression for issue #12 and the partial-PATCH structural fix.
+///
+/// When PATCH only touches one field, the backend must receive an entity
+/// containing *only* that delta — not the full merged row. This keeps the
+/// backend's UPDATE narrow and structurally prevents the "null column gets
+/// rebound with the wrong type" class of bugs (#12 was one instance of it
+/// involving relation-many columns stored as text[]).
+///
+/// The observable surface of "narrow UPDATE" is that an unrelated field
+/// that was never included in the patch body is still present and
+/// unchanged after the round-trip. This test covers that semantic on a
+/// schema that mixes scalar, array, and nullable fields.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn patch_entity_narrow_update_preserves_untouched_nullable_fields() {
+    let app = test_app().await;
+
+    // Schema with a required scalar, a required scalar we will patch, and
+    // two optional typed fields that stay null through the whole lifecycle.
+    // DateTime is the salient case: its null binding used to fall through
+    // to text before #10 landed typed-null binding.
+    let schema_body = serde_json::json!({
+        "name": "Task",
+        "fields": [
+            {"name": "name", "field_type": "Text", "modifiers": ["required"]},
+            {"name": "duration_days", "field_type": "Float", "modifiers": ["required"]},
+            {"name": "early_start", "field_type": "DateTime"},
+            {"name": "total_slack", "field_type": "Float"}
+        ]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    // Create without setting `early_start` or `total_slack` so they stay null.
+    let create_body = serde_json::json!({
+        "fields": {
+            "name": "Discovery",
+            "duration_days": 5.0
+        }
+    });
+    let (_, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Task/entities",
+        Some(create_body),
+    )
+    .await;
+    let entity_id = created["id"].as_str().unwrap().to_string();
+    let path = format!("/schemas/Task/entities/{entity_id}");
+
+    // Patch only `duration_days`. Prior to the partial-PATCH fix, the
+    // merged entity (including both null `early_start` and null
+    // `total_slack`) would reach the backend and the null rebind would
+    // fail on any backend whose null-binding path had a weak spot. With
+    // the delta fix, the backend only ever sees `duration_days` in the
+    // UPDATE.
+    let patch_body = serde_json::json!({ "fields": { "duration_days": 7.5 } });
+    let (patch_status, patched) = json_request(&app, Method::PATCH, &path, Some(patch_body)).await;
+    assert_eq!(
+        patch_status,
+        StatusCode::OK,
+        "PATCH should succeed even when unrelated columns are null, got {patch_status} body={patched}"
+    );
+
+    // Follow-up GET confirms the patched field landed and the untouched
+    // nullable fields are still null.
+    let (_, fetched) = json_request(&app, Method::GET, &path, None).await;
+    assert_eq!(fetched["fields"]["name"], "Discovery");
+    assert_eq!(fetched["fields"]["duration_days"], 7.5);
+    assert!(
+        fetched["fields"]["early_start"].is_null(),
+        "early_start must still be null after PATCH, got {}",
+        fetched["fields"]["early_start"]
+    );
+    assert!(
+        fetched["fields"]["total_slack"].is_null(),
+        "total_slack must still be null after PATCH, got {}",
+        fetched["fields"]["total_slack"]
+    );
+}
+
+/// A PATCH that re-sends fields identical to the current values must be
+/// treated as a no-op: the backend does not need to be hit, and the
+/// response is still the existing entity.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn patch_entity_noop_when_body_matches_existing_values() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [
+            {"name": "name", "field_type": "Text", "modifiers": ["required"]},
+            {"name": "email", "field_type": "Text", "modifiers": ["required"]}
+        ]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    let create_body = serde_json::json!({
+        "fields": { "name": "Alice", "email": "alice@example.com" }
+    });
+    let (_, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(create_body),
+    )
+    .await;
+    let entity_id = created["id"].as_str().unwrap().to_string();
+    let path = format!("/schemas/Contact/entities/{entity_id}");
+
+    // Patch with the exact same values the row already has.
+    let patch_body = serde_json::json!({
+        "fields": { "name": "Alice", "email": "alice@example.com" }
+    });
+    let (status, body) = json_request(&app, Method::PATCH, &path, Some(patch_body)).await;
+    assert_eq!(status, StatusCode::OK, "no-op PATCH should succeed");
+    assert_eq!(body["fields"]["name"], "Alice");
+    assert_eq!(body["fields"]["email"], "alice@example.com");
+}
+
+/// Regression for issue #10: PUT with a missing required field must return
+/// 422 and the error body must direct callers to PATCH for partial updates.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn put_missing_required_field_suggests_patch() {
+    let app = test_app().await;
+
+    let schema_body = serde_json::json!({
+        "name": "Contact",
+        "fields": [
+            {"name": "name", "field_type": "Text", "modifiers": ["required"]},
+            {"name": "email", "field_type": "Text", "modifiers": ["required"]}
+        ]
+    });
+    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+
+    let create_body = serde_json::json!({
+        "fields": { "name": "Alice", "email": "alice@example.com" }
+    });
+    let (_, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(create_body),
+    )
+    .await;
+    let entity_id = created["id"].as_str().unwrap().to_string();
+    let path = format!("/schemas/Contact/entities/{entity_id}");
+
+    // PUT with only the `name` field — `email` is missing.
+    let put_body = serde_json::json!({ "fields": { "name": "Alice Updated" } });
+    let (status, body) = json_request(&app, Method::PUT, &path, Some(put_body)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // The error message must include the actionable PATCH hint.
+    let message = body["message"].as_str().unwrap_or("");
+    assert!(
+        message.contains("PATCH"),
+        "error message should mention PATCH for partial updates, got: {message}"
+    );
+    assert!(
+        message.contains("email"),
+        "error message should name the missing field, got: {message}"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Derived inverse relation tests (issue #34)
+// ---------------------------------------------------------------------------
+
+/// Builds a (paired) pair of schemas: `Opportunity` with a parent collection
+/// `documents: -> Document[]` and `Document` with `opportunity: -> Opportunity`.
+/// Applies migrations and stores metadata so the backend has live tables.
+async fn setup_paired_schemas() -> (AppState<SchemaForgeConfig>, Router) {
+    use schema_forge_core::types::{
+        Cardinality, FieldDefinition, FieldModifier, FieldName, FieldType, SchemaDefinition,
+        SchemaId, SchemaName, TextConstraints,
+    };
+
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+    let backend = Arc::new(backend);
+
+    let mut opportunity = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Opportunity").unwrap(),
+        vec![
+            FieldDefinition::with_modifiers(
+                FieldName::new("title").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![FieldModifier::Required],
+            ),
+            FieldDefinition::new(
+                FieldName::new("documents").unwrap(),
+                FieldType::Relation {
+                    target: SchemaName::new("Document").unwrap(),
+                    cardinality: Cardinality::Many,
+                },
+            ),
+        ],
+        vec![],
+    )
+    .unwrap();
+
+    let mut document = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Document").unwrap(),
+        vec![
+            FieldDefinition::with_modifiers(
+                FieldName::new("title").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![FieldModifier::Required],
+            ),
+            FieldDefinition::new(
+                FieldName::new("opportunity").unwrap(),
+                FieldType::Relation {
+                    target: SchemaName::new("Opportunity").unwrap(),
+                    cardinality: Cardinality::One,
+                },
+            ),
+        ],
+        vec![],
+    )
+    .unwrap();
+
+    // Run the inverse pairing pass to mark Opportunity.documents as derived.
+    let mut batch = vec![opportunity.clone(), document.clone()];
+    schema_forge_core::inverse_relations::pair_inverse_relations(&mut batch).unwrap();
+    opportunity = batch[0].clone();
+    document = batch[1].clone();
+
+    assert!(
+        opportunity.field("documents").unwrap().is_derived(),
+        "pairing should have marked Opportunity.documents as derived"
+    );
+
+    // Apply migrations & store metadata for both schemas. The migration
+    // plan for Opportunity should NOT create a documents column.
+    for schema in &[&opportunity, &document] {
+        let plan = schema_forge_core::migration::DiffEngine::create_new(schema);
+        backend
+            .apply_migration(&schema.name, &plan.steps)
+            .await
+            .expect("apply migration");
+        backend
+            .store_schema_metadata(schema)
+            .await
+            .expect("store metadata");
+    }
+
+    let mut registry = HashMap::new();
+    registry.insert("Opportunity".to_string(), opportunity);
+    registry.insert("Document".to_string(), document);
+
+    let state = build_test_app_state(TestForgeInit {
+        backend,
+        registry,
+        tenant_config: None,
+        record_access_policy: None,
+        hook_dispatcher: None,
+    })
+    .await;
+    let app = test_app_with_claims_state(state.clone(), make_test_claims(&["platform_admin"]));
+    (state, app)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn derived_collection_populated_on_single_get() {
+    let (_state, app) = setup_paired_schemas().await;
+
+    // Create an opportunity.
+    let (status, opp) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Opportunity/entities",
+        Some(serde_json::json!({ "fields": { "title": "Deal A" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let opp_id = opp["id"].as_str().unwrap().to_string();
+
+    // Create three documents referencing the opportunity.
+    let mut doc_ids = Vec::new();
+    for title in &["Doc 1", "Doc 2", "Doc 3"] {
+        let (status, doc) = json_request(
+            &app,
+            Method::POST,
+            "/schemas/Document/entities",
+            Some(serde_json::json!({
+                "fields": { "title": title, "opportunity": opp_id }
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        doc_ids.push(doc["id"].as_str().unwrap().to_string());
+    }
+
+    // GET the opportunity and verify the derived collection is populated.
+    let (status, body) = json_request(
+        &app,
+        Method::GET,
+        &format!("/schemas/Opportunity/entities/{opp_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let documents = body["fields"]["documents"]
+        .as_array()
+        .expect("documents should be an array, not null");
+    let returned: Vec<String> = documents
+        .iter()
+        .map(|v| v.as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(returned.len(), 3, "expected three child document IDs");
+    for id in &doc_ids {
+        assert!(
+            returned.contains(id),
+            "missing child id {id} in {returned:?}"
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn derived_collection_populated_on_list() {
+    let (_state, app) = setup_paired_schemas().await;
+
+    // Two parents, two children each.
+    let mut parent_ids = Vec::new();
+    for title in &["Deal A", "Deal B"] {
+        let (_status, opp) = json_request(
+            &app,
+            Method::POST,
+            "/schemas/Opportunity/entities",
+            Some(serde_json::json!({ "fields": { "title": title } })),
+        )
+        .await;
+        parent_ids.push(opp["id"].as_str().unwrap().to_string());
+    }
+    for (i, pid) in parent_ids.iter().enumerate() {
+        for n in 0..2 {
+            json_request(
+                &app,
+                Method::POST,
+                "/schemas/Document/entities",
+                Some(serde_json::json!({
+                    "fields": { "title": format!("p{i}-doc{n}"), "opportunity": pid }
+                })),
+            )
+            .await;
+        }
+    }
+
+    let (status, body) =
+        json_request(&app, Method::GET, "/schemas/Opportunity/entities", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let entities = body["entities"].as_array().unwrap();
+    assert_eq!(entities.len(), 2);
+    for entity in entities {
+        let docs = entity["fields"]["documents"].as_array().unwrap();
+        assert_eq!(docs.len(), 2, "each parent should see its two children");
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn write_to_derived_field_is_rejected() {
+    let (_state, app) = setup_paired_schemas().await;
+
+    // Attempt to create an Opportunity with a value in the derived field.
+    let (status, body) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Opportunity/entities",
+        Some(serde_json::json!({
+            "fields": {
+                "title": "Deal A",
+                "documents": ["entity_01foo"]
+            }
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let message = body["message"]
+        .as_str()
+        .or_else(|| body["error"].as_str())
+        .unwrap_or("");
+    assert!(
+        message.to_lowercase().contains("derived")
+            || body.to_string().to_lowercase().contains("derived"),
+        "error should mention 'derived': {body}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn parent_with_no_children_gets_empty_array_not_null() {
+    let (_state, app) = setup_paired_schemas().await;
+
+    let (_status, opp) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Opportunity/entities",
+        Some(serde_json::json!({ "fields": { "title": "Lonely Deal" } })),
+    )
+    .await;
+    let opp_id = opp["id"].as_str().unwrap().to_string();
+
+    let (status, body) = json_request(
+        &app,
+        Method::GET,
+        &format!("/schemas/Opportunity/entities/{opp_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let documents = &body["fields"]["documents"];
+    assert!(
+        documents.is_array(),
+        "documents should always be an array, got {documents}"
+    );
+    assert_eq!(documents.as_array().unwrap().len(), 0);
+}
+
+// ---------------------------------------------------------------------------
+// API key scope tests
+// ---------------------------------------------------------------------------
+
+/// Send a JSON request carrying an `X-API-Key` header.
+async fn keyed_request(
+    app: &Router,
+    method: Method,
+    path: &str,
+    key: &str,
+    body: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let body = match body {
+        Some(v) => Body::from(serde_json::to_vec(&v).unwrap()),
+        None => Body::empty(),
+    };
+    let request = Request::builder()
+        .method(method)
+        .uri(path)
+        .header("content-type", "application/json")
+        .header(schema_forge_acton::api_key::API_KEY_HEADER, key)
+        .body(body)
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body_bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let json = serde_json::from_slice(&body_bytes).unwrap_or(serde_json::Value::Null);
+    (status, json)
+}
+
+/// Build a state with `Contact` and `Invoice` schemas (one entity each) and
+/// a router that authenticates through a `Contact:read` API key.
+async fn api_key_test_app() -> Router {
+    use schema_forge_acton::api_key::{api_key_middleware, ApiKeyScope, ScopeVerb};
+    use schema_forge_acton::{ApiKeyProvider, AuthContext, StaticApiKeyProvider};
+
+    let state = test_app_state().await;
+    let admin = test_app_with_claims_state(state.clone(), make_test_claims(&["platform_admin"]));
+    for name in ["Contact", "Invoice"] {
+        let schema = serde_json::json!({
+            "name": name,
+            "fields": [{"name": "name", "field_type": "Text"}]
+        });
+        let (status, _) = json_request(&admin, Method::POST, "/schemas", Some(schema)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let (status, _) = json_request(
+            &admin,
+            Method::POST,
+            &format!("/schemas/{name}/entities"),
+            Some(serde_json::json!({"fields": {"name": "seed"}})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    // The key acts as a platform admin so that only its scopes, not
+    // `@access`, can be responsible for any denial.
+    let provider: Arc<dyn ApiKeyProvider> = Arc::new(StaticApiKeyProvider::new().with_key(
+        "sk_contacts_ro",
+        AuthContext::new("partner", vec!["platform_admin".into()])
+            .with_scope(ApiKeyScope::new("Contact", ScopeVerb::Read)),
+    ));
+    forge_routes()
+        .layer(axum::middleware::from_fn_with_state(
+            provider,
+            api_key_middleware,
+        ))
+        .with_state(state)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn api_key_scoped_to_contact_read_can_list_contacts() {
+    let app = api_key_test_app().await;
+    let (status, json) = keyed_request(
         &app,
-        Method::POST,
-        "/schemas/Task/entities",
-        Some(create_body),
+        Method::GET,
+        "/schemas/Contact/entities",
+        "sk_contacts_ro",
+        None,
     )
     .await;
-    let entity_id = created["id"].as_str().unwrap().to_string();
-    let path = format!("/schemas/Task/entities/{entity_id}");
-
-    // Patch only `duration_days`. Prior to the partial-PATCH fix, the
-    // merged entity (including both null `early_start` and null
-    // `total_slack`) would reach the backend and the null rebind would
-    // fail on any backend whose null-binding path had a weak spot. With
-    // the delta fix, the backend only ever sees `duration_days` in the
-    // UPDATE.
-    let patch_body = serde_json::json!({ "fields": { "duration_days": 7.5 } });
-    let (patch_status, patched) = json_request(&app, Method::PATCH, &path, Some(patch_body)).await;
-    assert_eq!(
-        patch_status,
-        StatusCode::OK,
-        "PATCH should succeed even when unrelated columns are null, got {patch_status} body={patched}"
-    );
-
-    // Follow-up GET confirms the patched field landed and the untouched
-    // nullable fields are still null.
-    let (_, fetched) = json_request(&app, Method::GET, &path, None).await;
-    assert_eq!(fetched["fields"]["name"], "Discovery");
-    assert_eq!(fetched["fields"]["duration_days"], 7.5);
-    assert!(
-        fetched["fields"]["early_start"].is_null(),
-        "early_start must still be null after PATCH, got {}",
-        fetched["fields"]["early_start"]
-    );
-    assert!(
-        fetched["fields"]["total_slack"].is_null(),
-        "total_slack must still be null after PATCH, got {}",
-        fetched["fields"]["total_slack"]
-    );
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["entities"].as_array().unwrap().len(), 1);
 }
 
-/// A PATCH that re-sends fields identical to the current values must be
-/// treated as a no-op: the backend does not need to be hit, and the
-/// response is still the existing entity.
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn patch_entity_noop_when_body_matches_existing_values() {
-    let app = test_app().await;
-
-    let schema_body = serde_json::json!({
-        "name": "Contact",
-        "fields": [
-            {"name": "name", "field_type": "Text", "modifiers": ["required"]},
-            {"name": "email", "field_type": "Text", "modifiers": ["required"]}
-        ]
-    });
-    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
-
-    let create_body = serde_json::json!({
-        "fields": { "name": "Alice", "email": "alice@example.com" }
-    });
-    let (_, created) = json_request(
+async fn api_key_scoped_to_contact_read_cannot_write_contacts() {
+    let app = api_key_test_app().await;
+    let (status, json) = keyed_request(
         &app,
         Method::POST,
         "/schemas/Contact/entities",
-        Some(create_body),
+        "sk_contacts_ro",
+        Some(serde_json::json!({"fields": {"name": "Bob"}})),
     )
     .await;
-    let entity_id = created["id"].as_str().unwrap().to_string();
-    let path = format!("/schemas/Contact/entities/{entity_id}");
-
-    // Patch with the exact same values the row already has.
-    let patch_body = serde_json::json!({
-        "fields": { "name": "Alice", "email": "alice@example.com" }
-    });
-    let (status, body) = json_request(&app, Method::PATCH, &path, Some(patch_body)).await;
-    assert_eq!(status, StatusCode::OK, "no-op PATCH should succeed");
-    assert_eq!(body["fields"]["name"], "Alice");
-    assert_eq!(body["fields"]["email"], "alice@example.com");
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(json["error"], "forbidden");
 }
 
-/// Regression for issue #10: PUT with a missing required field must return
-/// 422 and the error body must direct callers to PATCH for partial updates.
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn put_missing_required_field_suggests_patch() {
-    let app = test_app().await;
-
-    let schema_body = serde_json::json!({
-        "name": "Contact",
-        "fields": [
-            {"name": "name", "field_type": "Text", "modifiers": ["required"]},
-            {"name": "email", "field_type": "Text", "modifiers": ["required"]}
-        ]
-    });
-    json_request(&app, Method::POST, "/schemas", Some(schema_body)).await;
+async fn api_key_scoped_to_contact_read_cannot_touch_invoices() {
+    let app = api_key_test_app().await;
+    let (status, _) = keyed_request(
+        &app,
+        Method::GET,
+        "/schemas/Invoice/entities",
+        "sk_contacts_ro",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
 
-    let create_body = serde_json::json!({
-        "fields": { "name": "Alice", "email": "alice@example.com" }
-    });
-    let (_, created) = json_request(
+    let (status, _) = keyed_request(
         &app,
         Method::POST,
-        "/schemas/Contact/entities",
-        Some(create_body),
+        "/schemas/Invoice/entities",
+        "sk_contacts_ro",
+        Some(serde_json::json!({"fields": {"name": "INV-1"}})),
     )
     .await;
-    let entity_id = created["id"].as_str().unwrap().to_string();
-    let path = format!("/schemas/Contact/entities/{entity_id}");
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
 
-    // PUT with only the `name` field — `email` is missing.
-    let put_body = serde_json::json!({ "fields": { "name": "Alice Updated" } });
-    let (status, body) = json_request(&app, Method::PUT, &path, Some(put_body)).await;
-    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn api_key_scoped_to_contact_read_cannot_manage_schemas() {
+    let app = api_key_test_app().await;
+    let (status, json) = keyed_request(
+        &app,
+        Method::PUT,
+        "/schemas/Contact",
+        "sk_contacts_ro",
+        Some(serde_json::json!({
+            "name": "Contact",
+            "fields": [
+                {"name": "name", "field_type": "Text"},
+                {"name": "email", "field_type": "Text"}
+            ]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(json["error"], "forbidden");
 
-    // The error message must include the actionable PATCH hint.
-    let message = body["message"].as_str().unwrap_or("");
-    assert!(
-        message.contains("PATCH"),
-        "error message should mention PATCH for partial updates, got: {message}"
-    );
-    assert!(
-        message.contains("email"),
-        "error message should name the missing field, got: {message}"
-    );
+    let (status, _) = keyed_request(
+        &app,
+        Method::DELETE,
+        "/schemas/Contact",
+        "sk_contacts_ro",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // The schema is untouched.
+    let (status, json) = keyed_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact",
+        "sk_contacts_ro",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["fields"].as_array().unwrap().len(), 1);
 }
 
-// ---------------------------------------------------------------------------
-// Derived inverse relation tests (issue #34)
-// ---------------------------------------------------------------------------
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn unknown_api_key_returns_401() {
+    let app = api_key_test_app().await;
+    let (status, json) = keyed_request(
+        &app,
+        Method::GET,
+        "/schemas/Contact/entities",
+        "sk_unknown",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(json["error"], "unauthorized");
+}
 
-/// Builds a (paired) pair of schemas: `Opportunity` with a parent collection
-/// `documents: -> Document[]` and `Document` with `opportunity: -> Opportunity`.
-/// Applies migrations and stores metadata so the backend has live tables.
-async fn setup_paired_schemas() -> (AppState<SchemaForgeConfig>, Router) {
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn noop_provider_user_owns_their_records() {
+    use schema_forge_acton::api_key::api_key_middleware;
+    use schema_forge_acton::{ApiKeyProvider, NoopAuthProvider};
     use schema_forge_core::types::{
-        Cardinality, FieldDefinition, FieldModifier, FieldName, FieldType, SchemaDefinition,
-        SchemaId, SchemaName, TextConstraints,
+        Annotation, FieldAnnotation, FieldDefinition, FieldName, FieldType, SchemaId, SchemaName,
+        TextConstraints,
     };
 
-    let backend = SurrealBackend::connect_memory("test", "test")
-        .await
-        .expect("failed to connect to in-memory SurrealDB");
-    let backend = Arc::new(backend);
-
-    let mut opportunity = SchemaDefinition::new(
+    let backend = Arc::new(
+        SurrealBackend::connect_memory("test", "test")
+            .await
+            .expect("failed to connect to in-memory SurrealDB"),
+    );
+    let note = SchemaDefinition::new(
         SchemaId::new(),
-        SchemaName::new("Opportunity").unwrap(),
+        SchemaName::new("Note").unwrap(),
         vec![
-            FieldDefinition::with_modifiers(
-                FieldName::new("title").unwrap(),
-                FieldType::Text(TextConstraints::unconstrained()),
-                vec![FieldModifier::Required],
-            ),
             FieldDefinition::new(
-                FieldName::new("documents").unwrap(),
-                FieldType::Relation {
-                    target: SchemaName::new("Document").unwrap(),
-                    cardinality: Cardinality::Many,
-                },
-            ),
-        ],
-        vec![],
-    )
-    .unwrap();
-
-    let mut document = SchemaDefinition::new(
-        SchemaId::new(),
-        SchemaName::new("Document").unwrap(),
-        vec![
-            FieldDefinition::with_modifiers(
-                FieldName::new("title").unwrap(),
+                FieldName::new("content").unwrap(),
                 FieldType::Text(TextConstraints::unconstrained()),
-                vec![FieldModifier::Required],
             ),
-            FieldDefinition::new(
-                FieldName::new("opportunity").unwrap(),
-                FieldType::Relation {
-                    target: SchemaName::new("Opportunity").unwrap(),
-                    cardinality: Cardinality::One,
-                },
+            FieldDefinition::with_annotations(
+                FieldName::new("owner_id").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![],
+                vec![FieldAnnotation::Owner],
             ),
         ],
-        vec![],
+        vec![Annotation::Access {
+            read: vec![],
+            write: vec![],
+            delete: vec![],
+            cross_tenant_read: vec![],
+        }],
     )
     .unwrap();
-
-    // Run the inverse pairing pass to mark Opportunity.documents as derived.
-    let mut batch = vec![opportunity.clone(), document.clone()];
-    schema_forge_core::inverse_relations::pair_inverse_relations(&mut batch).unwrap();
-    opportunity = batch[0].clone();
-    document = batch[1].clone();
-
-    assert!(
-        opportunity.field("documents").unwrap().is_derived(),
-        "pairing should have marked Opportunity.documents as derived"
-    );
-
-    // Apply migrations & store metadata for both schemas. The migration
-    // plan for Opportunity should NOT create a documents column.
-    for schema in &[&opportunity, &document] {
-        let plan = schema_forge_core::migration::DiffEngine::create_new(schema);
-        backend
-            .apply_migration(&schema.name, &plan.steps)
-            .await
-            .expect("apply migration");
-        backend
-            .store_schema_metadata(schema)
-            .await
-            .expect("store metadata");
-    }
-
-    let mut registry = HashMap::new();
-    registry.insert("Opportunity".to_string(), opportunity);
-    registry.insert("Document".to_string(), document);
-
+    let plan = schema_forge_core::migration::DiffEngine::create_new(&note);
+    backend
+        .apply_migration(&note.name, &plan.steps)
+        .await
+        .expect("apply migration");
+    backend
+        .store_schema_metadata(&note)
+        .await
+        .expect("store metadata");
+    let mut registry = HashMap::new();
+    registry.insert("Note".to_string(), note);
     let state = build_test_app_state(TestForgeInit {
         backend,
         registry,
@@ -1409,162 +4218,373 @@ async fn setup_paired_schemas() -> (AppState<SchemaForgeConfig>, Router) {
         hook_dispatcher: None,
     })
     .await;
-    let app = test_app_with_claims_state(state.clone(), make_test_claims(&["platform_admin"]));
-    (state, app)
+    let app_as = |user: &str| {
+        let provider: Arc<dyn ApiKeyProvider> = Arc::new(NoopAuthProvider::with_roles_and_user(
+            vec!["member".into()],
+            user,
+        ));
+        forge_routes()
+            .layer(axum::middleware::from_fn_with_state(
+                provider,
+                api_key_middleware,
+            ))
+            .with_state(state.clone())
+    };
+    let alice = app_as("alice");
+
+    let (status, json) = keyed_request(
+        &alice,
+        Method::POST,
+        "/schemas/Note/entities",
+        "dev",
+        Some(serde_json::json!({"fields": {"content": "mine", "owner_id": "alice"}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "body: {json}");
+    let path = format!("/schemas/Note/entities/{}", json["id"].as_str().unwrap());
+
+    // The noop principal is the user itself, so `@owner` matches.
+    let body = serde_json::json!({"fields": {"content": "still mine"}});
+    let (status, json) =
+        keyed_request(&alice, Method::PATCH, &path, "dev", Some(body.clone())).await;
+    assert_eq!(status, StatusCode::OK, "body: {json}");
+
+    let (status, _) = keyed_request(&app_as("bob"), Method::PATCH, &path, "dev", Some(body)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+// ---------------------------------------------------------------------------
+// Audit sink tests
+// ---------------------------------------------------------------------------
+
+/// Audit sink that captures every event for later assertions. Clones
+/// share the captured events.
+#[derive(Clone, Default)]
+struct CapturingAuditSink {
+    events: Arc<tokio::sync::Mutex<Vec<schema_forge_acton::AuditEvent>>>,
+}
+
+#[async_trait::async_trait]
+impl schema_forge_acton::AuditSink for CapturingAuditSink {
+    async fn record(&self, event: schema_forge_acton::AuditEvent) {
+        self.events.lock().await.push(event);
+    }
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn derived_collection_populated_on_single_get() {
-    let (_state, app) = setup_paired_schemas().await;
+async fn update_emits_audit_event_with_only_changed_fields() {
+    use schema_forge_acton::audit::AuditAction;
 
-    // Create an opportunity.
-    let (status, opp) = json_request(
+    let sink = Arc::new(CapturingAuditSink::default());
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+    let state = build_test_app_state_with_audit_sink(
+        TestForgeInit {
+            backend: Arc::new(backend),
+            registry: HashMap::new(),
+            tenant_config: None,
+            record_access_policy: None,
+            hook_dispatcher: None,
+        },
+        Some(sink.clone() as Arc<dyn schema_forge_acton::AuditSink>),
+    )
+    .await;
+    let app = test_app_with_claims_state(state, make_test_claims(&["platform_admin"]));
+
+    let schema = serde_json::json!({
+        "name": "Contact",
+        "fields": [
+            {"name": "name", "field_type": "Text"},
+            {"name": "email", "field_type": "Text"}
+        ]
+    });
+    let (status, _) = json_request(&app, Method::POST, "/schemas", Some(schema)).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, created) = json_request(
         &app,
         Method::POST,
-        "/schemas/Opportunity/entities",
-        Some(serde_json::json!({ "fields": { "title": "Deal A" } })),
+        "/schemas/Contact/entities",
+        Some(serde_json::json!({"fields": {"name": "Alice", "email": "alice@example.com"}})),
     )
     .await;
     assert_eq!(status, StatusCode::CREATED);
-    let opp_id = opp["id"].as_str().unwrap().to_string();
-
-    // Create three documents referencing the opportunity.
-    let mut doc_ids = Vec::new();
-    for title in &["Doc 1", "Doc 2", "Doc 3"] {
-        let (status, doc) = json_request(
-            &app,
-            Method::POST,
-            "/schemas/Document/entities",
-            Some(serde_json::json!({
-                "fields": { "title": title, "opportunity": opp_id }
-            })),
-        )
-        .await;
-        assert_eq!(status, StatusCode::CREATED);
-        doc_ids.push(doc["id"].as_str().unwrap().to_string());
-    }
+    let id = created["id"].as_str().unwrap().to_string();
 
-    // GET the opportunity and verify the derived collection is populated.
-    let (status, body) = json_request(
+    let (status, _) = json_request(
         &app,
-        Method::GET,
-        &format!("/schemas/Opportunity/entities/{opp_id}"),
-        None,
+        Method::PUT,
+        &format!("/schemas/Contact/entities/{id}"),
+        Some(serde_json::json!({"fields": {"name": "Alicia", "email": "alice@example.com"}})),
     )
     .await;
     assert_eq!(status, StatusCode::OK);
 
-    let documents = body["fields"]["documents"]
-        .as_array()
-        .expect("documents should be an array, not null");
-    let returned: Vec<String> = documents
-        .iter()
-        .map(|v| v.as_str().unwrap().to_string())
-        .collect();
-    assert_eq!(returned.len(), 3, "expected three child document IDs");
-    for id in &doc_ids {
-        assert!(
-            returned.contains(id),
-            "missing child id {id} in {returned:?}"
-        );
-    }
+    let events = sink.events.lock().await;
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].action, AuditAction::Create);
+    assert_eq!(events[0].changed_fields, vec!["name", "email"]);
+
+    let update = &events[1];
+    assert_eq!(update.action, AuditAction::Update);
+    assert_eq!(update.actor.as_deref(), Some("user:test-user"));
+    assert_eq!(update.schema, "Contact");
+    assert_eq!(update.entity_id, id);
+    assert_eq!(update.changed_fields, vec!["name"]);
 }
 
+#[cfg(feature = "graphql")]
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn derived_collection_populated_on_list() {
-    let (_state, app) = setup_paired_schemas().await;
+async fn graphql_mutations_emit_audit_events() {
+    use schema_forge_acton::audit::AuditAction;
+    use schema_forge_core::types::{
+        FieldDefinition, FieldName, FieldType, SchemaId, SchemaName, TextConstraints,
+    };
 
-    // Two parents, two children each.
-    let mut parent_ids = Vec::new();
-    for title in &["Deal A", "Deal B"] {
-        let (_status, opp) = json_request(
-            &app,
-            Method::POST,
-            "/schemas/Opportunity/entities",
-            Some(serde_json::json!({ "fields": { "title": title } })),
+    let text = |name: &str| {
+        FieldDefinition::new(
+            FieldName::new(name).unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
         )
-        .await;
-        parent_ids.push(opp["id"].as_str().unwrap().to_string());
-    }
-    for (i, pid) in parent_ids.iter().enumerate() {
-        for n in 0..2 {
-            json_request(
-                &app,
-                Method::POST,
-                "/schemas/Document/entities",
-                Some(serde_json::json!({
-                    "fields": { "title": format!("p{i}-doc{n}"), "opportunity": pid }
-                })),
-            )
-            .await;
-        }
-    }
+    };
+    let contact = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Contact").unwrap(),
+        vec![text("name"), text("email")],
+        vec![],
+    )
+    .unwrap();
+    let sink = CapturingAuditSink::default();
+    let builder = schema_forge_acton::SchemaForgeExtension::builder().with_audit_sink(sink.clone());
+    let app = graphql_app_with(builder, &[contact]).await;
 
-    let (status, body) =
-        json_request(&app, Method::GET, "/schemas/Opportunity/entities", None).await;
-    assert_eq!(status, StatusCode::OK);
-    let entities = body["entities"].as_array().unwrap();
-    assert_eq!(entities.len(), 2);
-    for entity in entities {
-        let docs = entity["fields"]["documents"].as_array().unwrap();
-        assert_eq!(docs.len(), 2, "each parent should see its two children");
-    }
+    let data = graphql_data(
+        &app,
+        r#"mutation { createContact(input: { name: "Alice", email: "a@example.com" }) { id } }"#,
+    )
+    .await;
+    let id = data["createContact"]["id"].as_str().unwrap().to_string();
+    graphql_data(
+        &app,
+        &format!(
+            r#"mutation {{ updateContact(id: "{id}", input: {{ name: "Alicia" }}) {{ id }} }}"#
+        ),
+    )
+    .await;
+    graphql_data(
+        &app,
+        &format!(r#"mutation {{ deleteContact(id: "{id}") }}"#),
+    )
+    .await;
+
+    let events = sink.events.lock().await;
+    let summary: Vec<_> = events
+        .iter()
+        .map(|e| (e.action, e.changed_fields.clone()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (AuditAction::Create, vec!["name".into(), "email".into()]),
+            (AuditAction::Update, vec!["name".into()]),
+            (AuditAction::Delete, vec![]),
+        ]
+    );
+    assert!(events
+        .iter()
+        .all(|e| e.entity_id == id && e.schema == "Contact"));
+    assert_eq!(events[0].actor.as_deref(), Some("user:test-user"));
 }
 
+#[cfg(feature = "graphql")]
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn write_to_derived_field_is_rejected() {
-    let (_state, app) = setup_paired_schemas().await;
+async fn graphql_mutations_publish_change_events() {
+    use schema_forge_acton::events::{ChangeFeed, ChangeOperation};
+    use schema_forge_core::types::{
+        FieldDefinition, FieldName, FieldType, SchemaId, SchemaName, TextConstraints,
+    };
 
-    // Attempt to create an Opportunity with a value in the derived field.
-    let (status, body) = json_request(
+    let contact = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Contact").unwrap(),
+        vec![FieldDefinition::new(
+            FieldName::new("name").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )],
+        vec![],
+    )
+    .unwrap();
+    let feed = ChangeFeed::default();
+    let mut changes = feed.subscribe();
+    let builder = schema_forge_acton::SchemaForgeExtension::builder().with_change_feed(feed);
+    let app = graphql_app_with(builder, &[contact]).await;
+
+    let data = graphql_data(
         &app,
-        Method::POST,
-        "/schemas/Opportunity/entities",
-        Some(serde_json::json!({
-            "fields": {
-                "title": "Deal A",
-                "documents": ["entity_01foo"]
-            }
-        })),
+        r#"mutation { createContact(input: { name: "Alice" }) { id } }"#,
     )
     .await;
-    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
-    let message = body["message"]
-        .as_str()
-        .or_else(|| body["error"].as_str())
-        .unwrap_or("");
-    assert!(
-        message.to_lowercase().contains("derived")
-            || body.to_string().to_lowercase().contains("derived"),
-        "error should mention 'derived': {body}"
+    let id = data["createContact"]["id"].as_str().unwrap().to_string();
+    graphql_data(
+        &app,
+        &format!(r#"mutation {{ deleteContact(id: "{id}") }}"#),
+    )
+    .await;
+
+    let created = changes.try_recv().expect("no create event");
+    assert_eq!(created.operation, ChangeOperation::Created);
+    assert_eq!(created.schema, "Contact");
+    assert_eq!(created.entity_id, id);
+    let name = created.entity.as_ref().and_then(|e| e.fields.get("name"));
+    assert_eq!(
+        name,
+        Some(&schema_forge_core::types::DynamicValue::Text(
+            "Alice".into()
+        ))
     );
+
+    let deleted = changes.try_recv().expect("no delete event");
+    assert_eq!(deleted.operation, ChangeOperation::Deleted);
+    assert_eq!(deleted.entity_id, id);
+    assert!(deleted.entity.is_some(), "subscribers get the last state");
 }
 
+#[cfg(feature = "graphql")]
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn parent_with_no_children_gets_empty_array_not_null() {
-    let (_state, app) = setup_paired_schemas().await;
+async fn graphql_treats_soft_deleted_entities_as_missing() {
+    use schema_forge_core::types::{
+        Annotation, FieldDefinition, FieldName, FieldType, SchemaId, SchemaName, TextConstraints,
+    };
 
-    let (_status, opp) = json_request(
+    let contact = SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Contact").unwrap(),
+        vec![FieldDefinition::new(
+            FieldName::new("name").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )],
+        vec![Annotation::SoftDelete],
+    )
+    .unwrap();
+    let app = graphql_app(&[contact]).await;
+
+    let data = graphql_data(
         &app,
-        Method::POST,
-        "/schemas/Opportunity/entities",
-        Some(serde_json::json!({ "fields": { "title": "Lonely Deal" } })),
+        r#"mutation { createContact(input: { name: "Alice" }) { id } }"#,
     )
     .await;
-    let opp_id = opp["id"].as_str().unwrap().to_string();
-
-    let (status, body) = json_request(
+    let id = data["createContact"]["id"].as_str().unwrap().to_string();
+    graphql_data(
         &app,
-        Method::GET,
-        &format!("/schemas/Opportunity/entities/{opp_id}"),
-        None,
+        &format!(r#"mutation {{ deleteContact(id: "{id}") }}"#),
     )
     .await;
-    assert_eq!(status, StatusCode::OK);
-    let documents = &body["fields"]["documents"];
-    assert!(
-        documents.is_array(),
-        "documents should always be an array, got {documents}"
+
+    for query in [
+        format!(r#"{{ contact(id: "{id}") {{ name }} }}"#),
+        format!(
+            r#"mutation {{ updateContact(id: "{id}", input: {{ name: "Mallory" }}) {{ name }} }}"#
+        ),
+    ] {
+        let (status, json) = json_request(
+            &app,
+            Method::POST,
+            "/forge/graphql",
+            Some(serde_json::json!({ "query": query })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            json["errors"][0]["extensions"]["code"], "NOT_FOUND",
+            "unexpected body: {json}"
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Entity change stream tests
+// ---------------------------------------------------------------------------
+
+/// Open `GET /schemas/{schema}/events` and return the streaming body. The
+/// subscription is registered before the response head is returned, so
+/// writes issued after this returns are guaranteed to be streamed.
+async fn open_event_stream(app: &Router, schema: &str) -> Body {
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/schemas/{schema}/events"))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok()),
+        Some("text/event-stream")
     );
-    assert_eq!(documents.as_array().unwrap().len(), 0);
+    response.into_body()
+}
+
+/// Read the next SSE message from an event stream body, returning its
+/// `event:` name and parsed `data:` payload.
+async fn next_sse_event(body: &mut Body) -> (String, serde_json::Value) {
+    let mut received = String::new();
+    while !received.contains("\n\n") {
+        let frame = tokio::time::timeout(Duration::from_secs(5), body.frame())
+            .await
+            .expect("no event received")
+            .expect("stream ended")
+            .unwrap();
+        if let Some(data) = frame.data_ref() {
+            received.push_str(std::str::from_utf8(data).unwrap());
+        }
+    }
+    let line = |prefix: &str| {
+        received
+            .lines()
+            .find_map(|l| l.strip_prefix(prefix))
+            .map(str::to_string)
+    };
+    let event = line("event: ").unwrap_or_else(|| panic!("no event name in: {received}"));
+    let data = line("data: ").unwrap_or_else(|| panic!("no data line in: {received}"));
+    (event, serde_json::from_str(&data).unwrap())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn event_stream_receives_created_entity() {
+    let app = test_app().await;
+
+    let schema = serde_json::json!({
+        "name": "Contact",
+        "fields": [{"name": "name", "field_type": "Text"}]
+    });
+    let (status, _) = json_request(&app, Method::POST, "/schemas", Some(schema)).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let mut body = open_event_stream(&app, "Contact").await;
+
+    let (status, created) = json_request(
+        &app,
+        Method::POST,
+        "/schemas/Contact/entities",
+        Some(serde_json::json!({"fields": {"name": "Alice"}})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (event, payload) = next_sse_event(&mut body).await;
+    assert_eq!(event, "created");
+    assert_eq!(payload["operation"], "created");
+    assert_eq!(payload["schema"], "Contact");
+    assert_eq!(payload["id"], created["id"]);
+    assert_eq!(payload["fields"]["name"], "Alice");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn event_stream_requires_known_schema() {
+    let app = test_app().await;
+    let (status, _) = json_request(&app, Method::GET, "/schemas/Missing/events", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
 }
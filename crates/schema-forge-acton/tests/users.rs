@@ -158,6 +158,8 @@ async fn users_router(seeded: SeededBackend, claims: Claims) -> Router {
             hook_dispatcher: None,
             storage_registry: schema_forge_acton::storage::StorageRegistry::default(),
             policy_store: None,
+            audit_sink: None,
+            entity_hooks: Default::default(),
             reply: ReplyChannel::new(tx),
         })
         .await;
@@ -614,3 +616,52 @@ async fn put_user_can_disable_and_re_enable_active_flag() {
     let read_back = auth_store.get_user("alice").await.unwrap().unwrap();
     assert!(read_back.active);
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn scoped_api_key_cannot_manage_users_without_admin_scope() {
+    use schema_forge_acton::api_key::{ApiKeyScope, ScopeVerb};
+    use schema_forge_acton::AuthContext;
+
+    let seeded = seed_backend("users_scoped_key").await;
+    seeded
+        .auth_store
+        .create_user("alice", "alicepass", &["sales".to_string()], "Alice")
+        .await
+        .unwrap();
+    let auth_store = seeded.auth_store.clone();
+
+    // The key acts as a platform admin, so only its scopes can deny.
+    let key = AuthContext::new("partner", vec!["platform_admin".into()])
+        .with_scope(ApiKeyScope::new("Contact", ScopeVerb::Read))
+        .to_claims();
+    let app = users_router(seeded, key).await;
+
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/users",
+        Some(serde_json::json!({
+            "username": "mallory",
+            "password": "mallorypass",
+            "roles": ["platform_admin"],
+            "display_name": "Mallory"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "body: {json}");
+    assert!(auth_store.get_user("mallory").await.unwrap().is_none());
+
+    let (status, json) = json_request(
+        &app,
+        Method::POST,
+        "/users/alice/password",
+        Some(serde_json::json!({ "password": "takenover" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "body: {json}");
+    let ok = auth_store
+        .validate_credentials("alice", "alicepass")
+        .await
+        .unwrap();
+    assert!(ok.is_some(), "password must be unchanged");
+}
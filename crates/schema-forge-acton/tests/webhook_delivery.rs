@@ -0,0 +1,264 @@
+//! Integration test for outbound webhook delivery.
+//!
+//! Stands up a loopback HTTP server as the subscriber, creates an entity
+//! through the forge router, and checks the delivered request carries a
+//! valid HMAC-SHA256 signature over the exact body bytes.
+//!
+//! Lives in its own test binary because the webhook dispatcher is a
+//! process-wide singleton initialised from the first config it sees.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use acton_service::config::Config;
+use acton_service::middleware::Claims;
+use acton_service::prelude::ActorHandleInterface;
+use acton_service::state::AppState;
+use axum::body::{Body, Bytes};
+use axum::http::{HeaderMap, Method, Request, StatusCode};
+use axum::Router;
+use hmac::{Hmac, KeyInit, Mac};
+use http_body_util::BodyExt;
+use schema_forge_acton::config::SchemaForgeConfig;
+use schema_forge_acton::messages::{
+    ApplyMigration, InitForge, InsertSchema, ReplyChannel, StoreSchemaMetadata,
+};
+use schema_forge_acton::routes::forge_routes;
+use schema_forge_acton::webhook::WebhookConfig;
+use schema_forge_acton::ForgeActor;
+use schema_forge_core::migration::DiffEngine;
+use schema_forge_core::types::{
+    Annotation, FieldDefinition, FieldName, FieldType, SchemaDefinition, SchemaId, SchemaName,
+    TextConstraints,
+};
+use schema_forge_surrealdb::SurrealBackend;
+use sha2::Sha256;
+use tokio::sync::{mpsc, oneshot};
+use tower::ServiceExt;
+
+const SIGNING_SECRET: &str = "whsec-test";
+
+// ---------------------------------------------------------------------------
+// Test fixtures
+// ---------------------------------------------------------------------------
+
+fn admin_claims() -> Claims {
+    Claims {
+        sub: "user:test-admin".to_string(),
+        roles: vec!["platform_admin".to_string()],
+        perms: vec![],
+        exp: 9_999_999_999,
+        iat: None,
+        jti: None,
+        iss: None,
+        aud: None,
+        email: None,
+        username: None,
+        custom: HashMap::new(),
+    }
+}
+
+/// Start a subscriber on an ephemeral loopback port. Every request's
+/// headers and raw body are forwarded on the returned channel.
+async fn spawn_subscriber() -> (String, mpsc::UnboundedReceiver<(HeaderMap, Bytes)>) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let app = Router::new().route(
+        "/hook",
+        axum::routing::post(move |headers: HeaderMap, body: Bytes| {
+            let tx = tx.clone();
+            async move {
+                let _ = tx.send((headers, body));
+                StatusCode::NO_CONTENT
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    (format!("http://{addr}/hook"), rx)
+}
+
+/// `Contact` schema with an inline `@webhook` subscription pointing at `url`.
+fn contact_schema_with_webhook(url: &str) -> SchemaDefinition {
+    SchemaDefinition::new(
+        SchemaId::new(),
+        SchemaName::new("Contact").unwrap(),
+        vec![FieldDefinition::new(
+            FieldName::new("name").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        )],
+        vec![Annotation::Webhook {
+            events: vec![],
+            url: Some(url.to_string()),
+            secret: None,
+        }],
+    )
+    .unwrap()
+}
+
+/// Build an app state with webhooks enabled and the `Contact` schema
+/// registered in both the backend and the in-memory registry.
+async fn setup(schema: SchemaDefinition) -> AppState<SchemaForgeConfig> {
+    use acton_service::service_builder::ServiceBuilder;
+
+    let backend = SurrealBackend::connect_memory("test", "test")
+        .await
+        .expect("failed to connect to in-memory SurrealDB");
+
+    let mut custom = SchemaForgeConfig::default();
+    custom.schema_forge.webhooks = WebhookConfig {
+        enabled: true,
+        default_retry_count: 0,
+        signing_secret: Some(SIGNING_SECRET.to_string()),
+        allowed_url_schemes: vec!["http".to_string()],
+        ..Default::default()
+    };
+    let config = Config {
+        custom,
+        ..Config::default()
+    };
+
+    let service = ServiceBuilder::new()
+        .with_config(config)
+        .with_actor::<ForgeActor>()
+        .build();
+
+    let forge = service
+        .state()
+        .actor::<ForgeActor>()
+        .expect("ForgeActor not registered");
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(InitForge {
+            registry: HashMap::new(),
+            backend: Arc::new(backend),
+            tenant_config: None,
+            record_access_policy: None,
+            hook_dispatcher: None,
+            storage_registry: schema_forge_acton::storage::StorageRegistry::default(),
+            policy_store: None,
+            audit_sink: None,
+            entity_hooks: Default::default(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    tokio::time::timeout(Duration::from_secs(5), rx)
+        .await
+        .expect("InitForge timeout")
+        .expect("InitForge channel dropped");
+
+    let plan = DiffEngine::create_new(&schema);
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(ApplyMigration {
+            schema_name: schema.name.clone(),
+            steps: plan.steps,
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    tokio::time::timeout(Duration::from_secs(5), rx)
+        .await
+        .expect("ApplyMigration timeout")
+        .expect("ApplyMigration channel dropped")
+        .expect("ApplyMigration failed");
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(StoreSchemaMetadata {
+            definition: schema.clone(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    tokio::time::timeout(Duration::from_secs(5), rx)
+        .await
+        .expect("StoreSchemaMetadata timeout")
+        .expect("StoreSchemaMetadata channel dropped")
+        .expect("StoreSchemaMetadata failed");
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(InsertSchema {
+            name: schema.name.as_str().to_string(),
+            definition: schema,
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    tokio::time::timeout(Duration::from_secs(5), rx)
+        .await
+        .expect("InsertSchema timeout")
+        .expect("InsertSchema channel dropped")
+        .expect("InsertSchema policy recompile failed");
+
+    service.state().clone()
+}
+
+/// Wrap the forge router with an admin-claims middleware layer.
+fn test_router(state: AppState<SchemaForgeConfig>) -> Router {
+    let claims = admin_claims();
+    forge_routes()
+        .layer(axum::middleware::from_fn(
+            move |mut req: axum::extract::Request, next: axum::middleware::Next| {
+                let claims = claims.clone();
+                async move {
+                    req.extensions_mut().insert(claims);
+                    next.run(req).await
+                }
+            },
+        ))
+        .with_state(state)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn create_fires_signed_webhook() {
+    let (url, mut deliveries) = spawn_subscriber().await;
+    let router = test_router(setup(contact_schema_with_webhook(&url)).await);
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/schemas/Contact/entities")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            serde_json::to_vec(&serde_json::json!({ "fields": { "name": "Alice" } })).unwrap(),
+        ))
+        .unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let created: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+    let (headers, body) = tokio::time::timeout(Duration::from_secs(5), deliveries.recv())
+        .await
+        .expect("webhook was not delivered")
+        .expect("subscriber channel closed");
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(SIGNING_SECRET.as_bytes()).unwrap();
+    mac.update(&body);
+    let expected = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+    assert_eq!(
+        headers
+            .get("x-schemaforge-signature")
+            .and_then(|v| v.to_str().ok()),
+        Some(expected.as_str())
+    );
+    assert_eq!(
+        headers
+            .get("x-schemaforge-event")
+            .and_then(|v| v.to_str().ok()),
+        Some("entity.created")
+    );
+
+    let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(payload["event"], "entity.created");
+    assert_eq!(payload["schema"], "Contact");
+    assert_eq!(payload["entity"]["id"], created["id"]);
+    assert_eq!(payload["entity"]["fields"]["name"], "Alice");
+    assert!(payload["timestamp"].is_string());
+}
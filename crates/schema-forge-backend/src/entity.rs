@@ -1,6 +1,13 @@
 use std::collections::BTreeMap;
 
-use schema_forge_core::types::{DynamicValue, EntityId, SchemaName};
+use schema_forge_core::query::{Cursor, Query};
+use schema_forge_core::types::{DynamicValue, EntityId, FieldType, SchemaDefinition, SchemaName};
+
+use crate::error::BackendError;
+
+/// Storage column holding an entity's version. Never part of
+/// [`Entity::fields`].
+pub const VERSION_FIELD: &str = "_version";
 
 /// A runtime entity: a record in a schema-defined table.
 ///
@@ -8,12 +15,16 @@ use schema_forge_core::types::{DynamicValue, EntityId, SchemaName};
 /// which simplifies testing and serialization.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Entity {
-    /// The unique entity identifier (a TypeID whose prefix is derived from the schema name).
+    /// The unique entity identifier (a TypeID whose prefix is the schema's
+    /// `@id_prefix`, or else is derived from the schema name).
     pub id: EntityId,
     /// The schema this entity belongs to.
     pub schema: SchemaName,
     /// Field name to value mapping.
     pub fields: BTreeMap<String, DynamicValue>,
+    /// Stored version, bumped by every update. Zero for entities that have
+    /// not been persisted yet or were written before versioning existed.
+    pub version: u64,
 }
 
 impl Entity {
@@ -24,6 +35,18 @@ impl Entity {
             id: EntityId::new(schema.as_str()),
             schema,
             fields,
+            version: 0,
+        }
+    }
+
+    /// Creates a new entity of `schema`, minting its id with the schema's
+    /// `@id_prefix` when one is declared.
+    pub fn for_schema(schema: &SchemaDefinition, fields: BTreeMap<String, DynamicValue>) -> Self {
+        Self {
+            id: schema.new_entity_id(),
+            schema: schema.name.clone(),
+            fields,
+            version: 0,
         }
     }
 
@@ -33,7 +56,32 @@ impl Entity {
         schema: SchemaName,
         fields: BTreeMap<String, DynamicValue>,
     ) -> Self {
-        Self { id, schema, fields }
+        Self {
+            id,
+            schema,
+            fields,
+            version: 0,
+        }
+    }
+
+    /// Sets the stored version (used when loading from storage).
+    pub fn with_version(mut self, version: u64) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Returns `self` when `expected` is `None` or equals the stored
+    /// version, otherwise `BackendError::Conflict`.
+    pub fn expect_version(self, expected: Option<u64>) -> Result<Self, BackendError> {
+        match expected {
+            Some(expected) if expected != self.version => Err(BackendError::Conflict {
+                schema: self.schema.as_str().to_string(),
+                entity_id: self.id.as_str().to_string(),
+                expected,
+                actual: self.version,
+            }),
+            _ => Ok(self),
+        }
     }
 
     /// Returns the value of a field by name, if present.
@@ -41,6 +89,22 @@ impl Entity {
         self.fields.get(name)
     }
 
+    /// The [`Cursor`] positioned at this entity under `query`'s sort keys.
+    ///
+    /// Sort fields missing from `fields` record `Null`, so the entity must
+    /// have been read with every sort field projected.
+    pub fn cursor(&self, query: &Query) -> Cursor {
+        let values = query
+            .sort
+            .iter()
+            .map(|(path, _)| match path.root() {
+                "id" => DynamicValue::Text(self.id.as_str().to_string()),
+                field => self.field(field).cloned().unwrap_or(DynamicValue::Null),
+            })
+            .collect();
+        Cursor::new(values, self.id.as_str())
+    }
+
     /// Returns the number of fields.
     pub fn field_count(&self) -> usize {
         self.fields.len()
@@ -54,7 +118,7 @@ impl Entity {
     /// field can never escape the storage boundary. Internal consumers
     /// (e.g., `EntityAuthStore`) read the raw entity directly from the
     /// backend and must not pass through this filter.
-    pub fn strip_hidden(&mut self, schema: &schema_forge_core::types::SchemaDefinition) {
+    pub fn strip_hidden(&mut self, schema: &SchemaDefinition) {
         self.fields
             .retain(|name, _| match schema.field(name) {
                 Some(field) => !field.is_hidden(),
@@ -65,6 +129,38 @@ impl Entity {
                 None => true,
             });
     }
+
+    /// Evaluate every `@computed` field of `schema` and store the result.
+    ///
+    /// Fields are evaluated in declaration order, so a computed field may
+    /// read one declared above it. A field whose expression fails to
+    /// evaluate (a type mismatch, overflow, division by zero) is set to
+    /// null; the failures are returned as `(field, reason)` pairs for the
+    /// caller to log.
+    pub fn apply_computed(&mut self, schema: &SchemaDefinition) -> Vec<(String, String)> {
+        let mut failures = Vec::new();
+        for field in &schema.fields {
+            let Some(expr) = field.computed_expr() else {
+                continue;
+            };
+            let value = match expr.evaluate(&self.fields, &field.field_type) {
+                Ok(value) => value,
+                Err(reason) => {
+                    failures.push((field.name.as_str().to_string(), reason));
+                    DynamicValue::Null
+                }
+            };
+            self.fields.insert(field.name.as_str().to_string(), value);
+        }
+        failures
+    }
+
+    /// Drop every `@computed` field, so a value a client echoed back is
+    /// never persisted.
+    pub fn strip_computed(&mut self, schema: &SchemaDefinition) {
+        self.fields
+            .retain(|name, _| !schema.field(name).is_some_and(|f| f.is_computed()));
+    }
 }
 
 impl std::fmt::Display for Entity {
@@ -73,6 +169,72 @@ impl std::fmt::Display for Entity {
     }
 }
 
+/// Check field values against the constraints their schema declares:
+/// integer and float bounds, text and rich text length, text patterns, enum
+/// variants, and array element counts, recursing into arrays and
+/// composites.
+///
+/// Every write path (REST, GraphQL, batch) calls this before handing
+/// fields to the backend. The first violation is returned as
+/// `BackendError::ValidationFailed`, naming the field by dotted path
+/// (`address.zip`, `scores[2]`). Nulls, unknown fields, and values of
+/// the wrong type are left to the backend.
+pub fn validate_fields(
+    schema: &SchemaDefinition,
+    fields: &BTreeMap<String, DynamicValue>,
+) -> Result<(), BackendError> {
+    for (name, value) in fields {
+        if let Some(def) = schema.field(name) {
+            check_value(name, &def.field_type, value)?;
+        }
+    }
+    Ok(())
+}
+
+fn check_value(
+    path: &str,
+    field_type: &FieldType,
+    value: &DynamicValue,
+) -> Result<(), BackendError> {
+    let checked = match (field_type, value) {
+        (FieldType::Text(c), DynamicValue::Text(s)) => {
+            c.check_len(s).and_then(|()| c.check_pattern(s))
+        }
+        (FieldType::RichText(c), DynamicValue::Text(s)) => c.check_plaintext_len(s),
+        (FieldType::Integer(c), DynamicValue::Integer(n)) => c.check(*n),
+        (FieldType::Float(c), DynamicValue::Float(f)) => c.check(*f),
+        (FieldType::Float(c), DynamicValue::Integer(n)) => c.check(*n as f64),
+        (FieldType::Enum(variants), DynamicValue::Enum(s) | DynamicValue::Text(s)) => {
+            variants.check(s)
+        }
+        (FieldType::Array(inner, c), DynamicValue::Array(items)) => {
+            c.check_len(items.len())
+                .map_err(|reason| validation_failed(path, reason))?;
+            for (i, item) in items.iter().enumerate() {
+                check_value(&format!("{path}[{i}]"), inner, item)?;
+            }
+            Ok(())
+        }
+        (FieldType::Composite(sub_fields), DynamicValue::Composite(map)) => {
+            for (key, sub_value) in map {
+                if let Some(def) = sub_fields.iter().find(|f| f.name.as_str() == key) {
+                    check_value(&format!("{path}.{key}"), &def.field_type, sub_value)?;
+                }
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    };
+    checked.map_err(|reason| validation_failed(path, reason))
+}
+
+fn validation_failed(path: &str, reason: String) -> BackendError {
+    BackendError::ValidationFailed {
+        field: path.to_string(),
+        reason,
+    }
+}
+
 /// The result of a query execution: a list of entities with optional total count.
 ///
 /// When `total_count` is `Some`, it represents the total number of matching entities
@@ -83,6 +245,9 @@ pub struct QueryResult {
     pub entities: Vec<Entity>,
     /// The total count of matching entities before pagination, if available.
     pub total_count: Option<usize>,
+    /// Encoded [`Cursor`] for the page after this one; `None` when the page
+    /// was not full or the query cannot be paginated by cursor.
+    pub next_cursor: Option<String>,
 }
 
 impl QueryResult {
@@ -91,9 +256,29 @@ impl QueryResult {
         Self {
             entities,
             total_count,
+            next_cursor: None,
         }
     }
 
+    /// Sets `next_cursor` from the last entity when `query` is limited and
+    /// this page is full.
+    ///
+    /// `query` is the query as submitted (before [`Query::resolve_cursor`]);
+    /// the cursor records the last entity's value for each of its sort keys.
+    pub fn with_next_cursor(mut self, query: &Query) -> Self {
+        let full_page = query
+            .limit
+            .is_some_and(|n| n > 0 && self.entities.len() == n);
+        if !full_page || query.sort.iter().any(|(path, _)| !path.is_simple()) {
+            return self;
+        }
+        let Some(last) = self.entities.last() else {
+            return self;
+        };
+        self.next_cursor = Some(last.cursor(query).encode());
+        self
+    }
+
     /// Returns true if no entities were returned.
     pub fn is_empty(&self) -> bool {
         self.entities.is_empty()
@@ -128,6 +313,30 @@ mod tests {
         assert_eq!(entity.field_count(), 2);
     }
 
+    #[test]
+    fn entity_for_schema_uses_declared_id_prefix() {
+        use schema_forge_core::types::{
+            Annotation, FieldDefinition, FieldName, FieldType, SchemaId, TextConstraints,
+        };
+
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Invoice").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("number").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            )],
+            vec![Annotation::IdPrefix {
+                prefix: "inv".into(),
+            }],
+        )
+        .unwrap();
+        let entity = Entity::for_schema(&schema, make_fields());
+        assert_eq!(entity.id.prefix(), "inv");
+        assert_eq!(entity.schema.as_str(), "Invoice");
+        assert!(schema.parse_entity_id(entity.id.as_str()).is_ok());
+    }
+
     #[test]
     fn entity_with_id_preserves_id() {
         let id = EntityId::new("contact");
@@ -172,4 +381,251 @@ mod tests {
         assert_eq!(result.len(), 2);
         assert_eq!(result.total_count, Some(10));
     }
+
+    #[test]
+    fn next_cursor_set_on_full_page() {
+        use schema_forge_core::query::{FieldPath, SortOrder};
+        use schema_forge_core::types::SchemaId;
+
+        let entities = vec![
+            Entity::new(make_schema_name(), make_fields()),
+            Entity::new(make_schema_name(), make_fields()),
+        ];
+        let last_id = entities[1].id.as_str().to_string();
+        let query = Query::new(SchemaId::new())
+            .with_sort(FieldPath::single("age"), SortOrder::Descending)
+            .with_limit(2);
+        let result = QueryResult::new(entities, None).with_next_cursor(&query);
+        let cursor = Cursor::decode(result.next_cursor.as_deref().unwrap()).unwrap();
+        assert_eq!(cursor.values, vec![DynamicValue::Integer(30)]);
+        assert_eq!(cursor.id, last_id);
+    }
+
+    fn deal_schema() -> SchemaDefinition {
+        use schema_forge_core::types::{
+            ComputedExpr, FieldAnnotation, FieldDefinition, FieldName, FieldType,
+            IntegerConstraints, SchemaId,
+        };
+
+        let computed = |name: &str, field_type: FieldType, expr: &str| {
+            FieldDefinition::with_annotations(
+                FieldName::new(name).unwrap(),
+                field_type,
+                Vec::new(),
+                vec![FieldAnnotation::Computed {
+                    expr: ComputedExpr::new(expr).unwrap(),
+                }],
+            )
+        };
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Deal").unwrap(),
+            vec![
+                FieldDefinition::new(
+                    FieldName::new("price").unwrap(),
+                    FieldType::Integer(IntegerConstraints::unconstrained()),
+                ),
+                FieldDefinition::new(
+                    FieldName::new("cost").unwrap(),
+                    FieldType::Integer(IntegerConstraints::unconstrained()),
+                ),
+                computed(
+                    "margin",
+                    FieldType::Integer(IntegerConstraints::unconstrained()),
+                    "price - cost",
+                ),
+                computed("ratio", FieldType::Decimal { scale: 2 }, "margin / cost"),
+            ],
+            Vec::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn apply_computed_evaluates_in_declaration_order() {
+        use schema_forge_core::types::parse_decimal;
+
+        let schema = deal_schema();
+        let mut fields = BTreeMap::new();
+        fields.insert("price".to_string(), DynamicValue::Integer(150));
+        fields.insert("cost".to_string(), DynamicValue::Integer(100));
+        let mut entity = Entity::new(schema.name.clone(), fields);
+
+        assert!(entity.apply_computed(&schema).is_empty());
+        assert_eq!(entity.field("margin"), Some(&DynamicValue::Integer(50)));
+        assert_eq!(
+            entity.field("ratio"),
+            Some(&DynamicValue::Decimal(parse_decimal("0.50", 2).unwrap()))
+        );
+    }
+
+    #[test]
+    fn apply_computed_nulls_failed_fields() {
+        let schema = deal_schema();
+        let mut fields = BTreeMap::new();
+        fields.insert("price".to_string(), DynamicValue::Integer(10));
+        fields.insert("cost".to_string(), DynamicValue::Integer(0));
+        let mut entity = Entity::new(schema.name.clone(), fields);
+
+        let failures = entity.apply_computed(&schema);
+        assert_eq!(
+            failures,
+            vec![("ratio".to_string(), "division by zero".to_string())]
+        );
+        assert_eq!(entity.field("margin"), Some(&DynamicValue::Integer(10)));
+        assert_eq!(entity.field("ratio"), Some(&DynamicValue::Null));
+    }
+
+    #[test]
+    fn strip_computed_drops_only_computed_fields() {
+        let schema = deal_schema();
+        let mut fields = BTreeMap::new();
+        fields.insert("price".to_string(), DynamicValue::Integer(10));
+        fields.insert("margin".to_string(), DynamicValue::Integer(99));
+        let mut entity = Entity::new(schema.name.clone(), fields);
+
+        entity.strip_computed(&schema);
+        assert_eq!(entity.field_count(), 1);
+        assert_eq!(entity.field("price"), Some(&DynamicValue::Integer(10)));
+    }
+
+    fn survey_schema() -> SchemaDefinition {
+        use schema_forge_core::types::{
+            ArrayConstraints, EnumVariants, FieldDefinition, FieldName, FloatConstraints,
+            IntegerConstraints, SchemaId, TextConstraints,
+        };
+
+        let field = |name: &str, field_type: FieldType| {
+            FieldDefinition::new(FieldName::new(name).unwrap(), field_type)
+        };
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Survey").unwrap(),
+            vec![
+                field(
+                    "score",
+                    FieldType::Integer(IntegerConstraints::with_range(0, 10).unwrap()),
+                ),
+                field(
+                    "ratio",
+                    FieldType::Float(FloatConstraints::with_bounds(Some("0"), Some("1")).unwrap()),
+                ),
+                field(
+                    "title",
+                    FieldType::Text(TextConstraints::with_max_length(5)),
+                ),
+                field(
+                    "status",
+                    FieldType::Enum(
+                        EnumVariants::new(vec!["Open".into(), "Closed".into()]).unwrap(),
+                    ),
+                ),
+                field(
+                    "tags",
+                    FieldType::Array(
+                        Box::new(FieldType::Text(TextConstraints::with_max_length(3))),
+                        ArrayConstraints::with_bounds(None, Some(2)).unwrap(),
+                    ),
+                ),
+                field(
+                    "address",
+                    FieldType::Composite(vec![field(
+                        "zip",
+                        FieldType::Text(TextConstraints::with_max_length(5)),
+                    )]),
+                ),
+            ],
+            Vec::new(),
+        )
+        .unwrap()
+    }
+
+    fn validate(field: &str, value: DynamicValue) -> Result<(), BackendError> {
+        let mut fields = BTreeMap::new();
+        fields.insert(field.to_string(), value);
+        validate_fields(&survey_schema(), &fields)
+    }
+
+    fn failed(field: &str, reason: &str) -> Result<(), BackendError> {
+        Err(BackendError::ValidationFailed {
+            field: field.to_string(),
+            reason: reason.to_string(),
+        })
+    }
+
+    #[test]
+    fn validate_fields_accepts_values_within_constraints() {
+        let mut fields = BTreeMap::new();
+        fields.insert("score".to_string(), DynamicValue::Integer(10));
+        fields.insert("ratio".to_string(), DynamicValue::Float(0.5));
+        fields.insert("title".to_string(), DynamicValue::Text("Hello".into()));
+        fields.insert("status".to_string(), DynamicValue::Enum("Open".into()));
+        fields.insert("tags".to_string(), DynamicValue::Null);
+        fields.insert("unknown".to_string(), DynamicValue::Integer(-1));
+        assert_eq!(validate_fields(&survey_schema(), &fields), Ok(()));
+    }
+
+    #[test]
+    fn validate_fields_names_field_and_bound() {
+        assert_eq!(
+            validate("score", DynamicValue::Integer(11)),
+            failed("score", "expected at most 10, got 11")
+        );
+        assert_eq!(
+            validate("score", DynamicValue::Integer(-1)),
+            failed("score", "expected at least 0, got -1")
+        );
+        assert_eq!(
+            validate("ratio", DynamicValue::Float(1.5)),
+            failed("ratio", "expected at most 1, got 1.5")
+        );
+        assert_eq!(
+            validate("ratio", DynamicValue::Integer(-2)),
+            failed("ratio", "expected at least 0, got -2")
+        );
+        assert_eq!(
+            validate("title", DynamicValue::Text("Greetings".into())),
+            failed("title", "expected at most 5 characters, got 9")
+        );
+        assert_eq!(
+            validate("status", DynamicValue::Enum("Pending".into())),
+            failed("status", "'Pending' is not one of [Open, Closed]")
+        );
+    }
+
+    #[test]
+    fn validate_fields_recurses_into_arrays_and_composites() {
+        let tags = |tags: &[&str]| {
+            DynamicValue::Array(
+                tags.iter()
+                    .map(|t| DynamicValue::Text(t.to_string()))
+                    .collect(),
+            )
+        };
+        assert_eq!(
+            validate("tags", tags(&["a", "b", "c"])),
+            failed("tags", "expected at most 2 elements, got 3")
+        );
+        assert_eq!(
+            validate("tags", tags(&["a", "long"])),
+            failed("tags[1]", "expected at most 3 characters, got 4")
+        );
+
+        let mut address = BTreeMap::new();
+        address.insert("zip".to_string(), DynamicValue::Text("123456".into()));
+        assert_eq!(
+            validate("address", DynamicValue::Composite(address)),
+            failed("address.zip", "expected at most 5 characters, got 6")
+        );
+    }
+
+    #[test]
+    fn next_cursor_absent_on_partial_page() {
+        use schema_forge_core::types::SchemaId;
+
+        let entities = vec![Entity::new(make_schema_name(), make_fields())];
+        let query = Query::new(SchemaId::new()).with_limit(2);
+        let result = QueryResult::new(entities, None).with_next_cursor(&query);
+        assert_eq!(result.next_cursor, None);
+    }
 }
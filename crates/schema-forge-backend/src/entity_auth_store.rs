@@ -167,7 +167,7 @@ where
     ) -> std::pin::Pin<
         Box<dyn std::future::Future<Output = Result<Entity, BackendError>> + Send + 'a>,
     > {
-        Box::pin(EntityStore::update(self, entity))
+        Box::pin(EntityStore::update(self, entity, None))
     }
     fn delete<'a>(
         &'a self,
@@ -580,6 +580,18 @@ mod tests {
             }
         }
 
+        fn create_many(
+            &self,
+            entities: &[Entity],
+        ) -> impl std::future::Future<Output = Result<Vec<Entity>, BackendError>> + Send {
+            let entities = entities.to_vec();
+            async move {
+                let mut rows = self.rows.lock().unwrap();
+                rows.extend(entities.iter().cloned());
+                Ok(entities)
+            }
+        }
+
         fn get(
             &self,
             schema: &SchemaName,
@@ -602,6 +614,7 @@ mod tests {
         fn update(
             &self,
             entity: &Entity,
+            _expected_version: Option<u64>,
         ) -> impl std::future::Future<Output = Result<Entity, BackendError>> + Send {
             let entity = entity.clone();
             async move {
@@ -618,6 +631,29 @@ mod tests {
             }
         }
 
+        fn patch(
+            &self,
+            schema: &SchemaName,
+            id: &EntityId,
+            changes: BTreeMap<String, DynamicValue>,
+            _expected_version: Option<u64>,
+        ) -> impl std::future::Future<Output = Result<Entity, BackendError>> + Send {
+            let schema = schema.clone();
+            let id = id.clone();
+            async move {
+                let mut rows = self.rows.lock().unwrap();
+                if let Some(slot) = rows.iter_mut().find(|e| e.id == id) {
+                    slot.fields.extend(changes);
+                    Ok(slot.clone())
+                } else {
+                    Err(BackendError::EntityNotFound {
+                        schema: schema.as_str().to_string(),
+                        entity_id: id.as_str().to_string(),
+                    })
+                }
+            }
+        }
+
         fn delete(
             &self,
             _schema: &SchemaName,
@@ -631,6 +667,14 @@ mod tests {
             }
         }
 
+        fn restore(
+            &self,
+            schema: &SchemaName,
+            id: &EntityId,
+        ) -> impl std::future::Future<Output = Result<Entity, BackendError>> + Send {
+            self.get(schema, id)
+        }
+
         fn query(
             &self,
             query: &Query,
@@ -686,6 +730,15 @@ mod tests {
         ) -> Result<Vec<schema_forge_core::query::AggregateResult>, BackendError> {
             Ok(Vec::new())
         }
+
+        async fn next_sequence_value(
+            &self,
+            _schema: &SchemaName,
+            _field: &FieldName,
+            start: i64,
+        ) -> Result<i64, BackendError> {
+            Ok(start + 1)
+        }
     }
 
     fn user_schema() -> SchemaDefinition {
@@ -708,7 +761,7 @@ mod tests {
                 ),
                 FieldDefinition::new(
                     FieldName::new(ROLES_FIELD).unwrap(),
-                    FieldType::Array(Box::new(FieldType::Text(TextConstraints::unconstrained()))),
+                    FieldType::array(FieldType::Text(TextConstraints::unconstrained())),
                 ),
                 FieldDefinition::with_annotations(
                     FieldName::new(ROLE_RANK_FIELD).unwrap(),
@@ -29,6 +29,18 @@ pub enum BackendError {
     ConnectionError { message: String },
     /// Query execution error.
     QueryError { message: String },
+    /// One entity of a batch write failed; the whole batch was rolled back.
+    BatchItemFailed { index: usize, reason: String },
+    /// A versioned write expected a different stored version.
+    Conflict {
+        schema: String,
+        entity_id: String,
+        expected: u64,
+        actual: u64,
+    },
+    /// A write would give two entities the same value for a `unique` field,
+    /// or the same values for the fields of a unique `@index`.
+    UniqueConflict { schema: String, fields: Vec<String> },
     /// Internal or unexpected error.
     Internal { message: String },
 }
@@ -70,6 +82,28 @@ impl fmt::Display for BackendError {
             Self::QueryError { message } => {
                 write!(f, "query execution error: {message}")
             }
+            Self::BatchItemFailed { index, reason } => {
+                write!(f, "batch entity {index} failed: {reason}")
+            }
+            Self::Conflict {
+                schema,
+                entity_id,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "entity '{entity_id}' in schema '{schema}' is at version {actual}, expected {expected}"
+                )
+            }
+            Self::UniqueConflict { schema, fields } => match fields.as_slice() {
+                [field] => write!(f, "{field} must be unique in schema '{schema}'"),
+                _ => write!(
+                    f,
+                    "another entity in schema '{schema}' already has these values for ({})",
+                    fields.join(", ")
+                ),
+            },
             Self::Internal { message } => {
                 write!(f, "internal backend error: {message}")
             }
@@ -94,6 +128,38 @@ mod tests {
         assert!(msg.contains("Contact"));
     }
 
+    #[test]
+    fn unique_conflict_display() {
+        let err = BackendError::UniqueConflict {
+            schema: "Deal".into(),
+            fields: vec!["agency".into(), "stage".into()],
+        };
+        assert_eq!(
+            err.to_string(),
+            "another entity in schema 'Deal' already has these values for (agency, stage)"
+        );
+
+        let err = BackendError::UniqueConflict {
+            schema: "Contact".into(),
+            fields: vec!["email".into()],
+        };
+        assert_eq!(err.to_string(), "email must be unique in schema 'Contact'");
+    }
+
+    #[test]
+    fn conflict_display() {
+        let err = BackendError::Conflict {
+            schema: "Contact".into(),
+            entity_id: "entity_abc123".into(),
+            expected: 2,
+            actual: 3,
+        };
+        assert_eq!(
+            err.to_string(),
+            "entity 'entity_abc123' in schema 'Contact' is at version 3, expected 2"
+        );
+    }
+
     #[test]
     fn schema_not_found_display() {
         let err = BackendError::SchemaNotFound {
@@ -169,6 +235,15 @@ mod tests {
         assert!(err.to_string().contains("syntax error"));
     }
 
+    #[test]
+    fn batch_item_failed_display() {
+        let err = BackendError::BatchItemFailed {
+            index: 3,
+            reason: "duplicate id".into(),
+        };
+        assert_eq!(err.to_string(), "batch entity 3 failed: duplicate id");
+    }
+
     #[test]
     fn internal_error_display() {
         let err = BackendError::Internal {
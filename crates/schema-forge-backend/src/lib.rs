@@ -2,12 +2,13 @@ pub mod auth;
 pub mod entity;
 pub mod entity_auth_store;
 pub mod error;
+pub mod stream;
 pub mod tenant;
 pub mod traits;
 pub mod user_store;
 
 pub use auth::{RecordAccessPolicy, PLATFORM_ADMIN_ROLE};
-pub use entity::{Entity, QueryResult};
+pub use entity::{validate_fields, Entity, QueryResult};
 pub use entity_auth_store::{compute_role_rank, DynEntityStore, EntityAuthStore};
 pub use error::BackendError;
 pub use tenant::TenantRef;
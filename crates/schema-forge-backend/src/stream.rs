@@ -0,0 +1,70 @@
+//! Batched streaming over [`EntityStore::query`].
+//!
+//! Large exports must not materialize a whole table. [`paged_query_stream`]
+//! walks a query one keyset page at a time, so at most one batch of
+//! entities is held in memory. Every batch is an independent query: there
+//! is no server-side cursor to leak, and dropping the stream simply drops
+//! the in-flight page request.
+
+use futures::stream::{self, Stream, TryStreamExt};
+use schema_forge_core::query::{Cursor, Query};
+
+use crate::entity::Entity;
+use crate::error::BackendError;
+use crate::traits::EntityStore;
+
+/// Number of entities fetched per page by [`EntityStore::query_stream`].
+pub const STREAM_BATCH_SIZE: usize = 500;
+
+/// Stream every entity matching `query`, fetching `batch_size` entities
+/// per round-trip with cursor pagination.
+///
+/// The query's `limit`, if any, caps the total number of entities yielded;
+/// its `offset` and `after` position the first page. `include_total` is
+/// ignored. A page is only requested once the previous one has been
+/// consumed, so a slow consumer applies back-pressure to the backend.
+///
+/// Sorting on a dotted relation path cannot be paginated by cursor and
+/// yields a single `BackendError::QueryError`.
+pub fn paged_query_stream<'a, S>(
+    store: &'a S,
+    query: &Query,
+    batch_size: usize,
+) -> impl Stream<Item = Result<Entity, BackendError>> + Send + 'a
+where
+    S: EntityStore + ?Sized,
+{
+    let batch_size = batch_size.max(1);
+    let start = Some((query.clone().with_total_count(false), query.limit));
+    stream::try_unfold(start, move |state| async move {
+        let Some((mut page, remaining)) = state else {
+            return Ok(None);
+        };
+        if let Some((path, _)) = page.sort.iter().find(|(path, _)| !path.is_simple()) {
+            return Err(BackendError::QueryError {
+                message: format!("cannot stream a query sorted on '{path}'"),
+            });
+        }
+        let limit = remaining.map_or(batch_size, |n| n.min(batch_size));
+        if limit == 0 {
+            return Ok(None);
+        }
+        page.limit = Some(limit);
+
+        let result = store.query(&page).await?;
+        let remaining = remaining.map(|n| n.saturating_sub(result.entities.len()));
+        let next = match result.next_cursor.as_deref() {
+            Some(raw) => {
+                let cursor = Cursor::decode(raw).map_err(|e| BackendError::QueryError {
+                    message: e.to_string(),
+                })?;
+                page.offset = None;
+                Some((page.with_after(cursor), remaining))
+            }
+            None => None,
+        };
+        Ok(Some((result.entities, next)))
+    })
+    .map_ok(|entities| stream::iter(entities.into_iter().map(Ok)))
+    .try_flatten()
+}
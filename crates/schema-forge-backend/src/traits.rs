@@ -1,8 +1,10 @@
+use std::collections::BTreeMap;
 use std::future::Future;
 
+use futures::{Stream, TryStreamExt};
 use schema_forge_core::migration::MigrationStep;
 use schema_forge_core::query::{AggregateQuery, AggregateResult, Query};
-use schema_forge_core::types::{EntityId, SchemaDefinition, SchemaName};
+use schema_forge_core::types::{DynamicValue, EntityId, FieldName, SchemaDefinition, SchemaName};
 
 use crate::entity::{Entity, QueryResult};
 use crate::error::BackendError;
@@ -62,6 +64,16 @@ pub trait EntityStore: Send + Sync {
     /// Returns the created entity (which may have backend-generated fields).
     fn create(&self, entity: &Entity) -> impl Future<Output = Result<Entity, BackendError>> + Send;
 
+    /// Create several entities atomically.
+    ///
+    /// Either every entity is written or none is. On failure, returns
+    /// `BackendError::BatchItemFailed` naming the index of the offending
+    /// entity in `entities`. Returns the created entities in input order.
+    fn create_many(
+        &self,
+        entities: &[Entity],
+    ) -> impl Future<Output = Result<Vec<Entity>, BackendError>> + Send;
+
     /// Retrieve an entity by schema name and entity ID.
     ///
     /// Returns `BackendError::EntityNotFound` if the entity does not exist.
@@ -74,19 +86,53 @@ pub trait EntityStore: Send + Sync {
     /// Update an existing entity.
     ///
     /// The entity's `id` and `schema` determine which record to update.
-    /// All fields in `entity.fields` replace the existing fields.
-    /// Returns the updated entity.
-    fn update(&self, entity: &Entity) -> impl Future<Output = Result<Entity, BackendError>> + Send;
+    /// All fields in `entity.fields` replace the existing fields, and the
+    /// stored version is bumped. When `expected_version` is given and does
+    /// not match the stored version, nothing is written and
+    /// `BackendError::Conflict` is returned. Returns the updated entity.
+    fn update(
+        &self,
+        entity: &Entity,
+        expected_version: Option<u64>,
+    ) -> impl Future<Output = Result<Entity, BackendError>> + Send;
+
+    /// Merge `changes` into an existing entity, leaving other fields as
+    /// stored.
+    ///
+    /// Returns `BackendError::EntityNotFound` if the entity does not exist,
+    /// and `BackendError::Conflict` as for [`update`](Self::update).
+    /// Returns the full entity after the merge.
+    fn patch(
+        &self,
+        schema: &SchemaName,
+        id: &EntityId,
+        changes: BTreeMap<String, DynamicValue>,
+        expected_version: Option<u64>,
+    ) -> impl Future<Output = Result<Entity, BackendError>> + Send;
 
     /// Delete an entity by schema name and entity ID.
     ///
-    /// Returns `BackendError::EntityNotFound` if the entity does not exist.
+    /// For a `@soft_delete` schema the row is kept and stamped with
+    /// `_deleted_at` instead; queries skip it from then on.
+    /// Returns `BackendError::EntityNotFound` if the entity does not exist
+    /// or is already soft-deleted.
     fn delete(
         &self,
         schema: &SchemaName,
         id: &EntityId,
     ) -> impl Future<Output = Result<(), BackendError>> + Send;
 
+    /// Clear the `_deleted_at` stamp of a soft-deleted entity.
+    ///
+    /// Restoring an entity that is not deleted leaves it unchanged.
+    /// Returns `BackendError::EntityNotFound` if the entity does not exist.
+    /// Returns the restored entity.
+    fn restore(
+        &self,
+        schema: &SchemaName,
+        id: &EntityId,
+    ) -> impl Future<Output = Result<Entity, BackendError>> + Send;
+
     /// Execute a query and return matching entities.
     ///
     /// The query's `schema` field determines the table, and its
@@ -97,6 +143,56 @@ pub trait EntityStore: Send + Sync {
         query: &Query,
     ) -> impl Future<Output = Result<QueryResult, BackendError>> + Send;
 
+    /// Stream every entity matching a query without materializing the
+    /// whole result set.
+    ///
+    /// Defaults to cursor-paginated batches of
+    /// [`STREAM_BATCH_SIZE`](crate::stream::STREAM_BATCH_SIZE) over
+    /// [`query`](Self::query); see
+    /// [`paged_query_stream`](crate::stream::paged_query_stream). Dropping
+    /// the stream cancels any page request in flight.
+    fn query_stream<'a>(
+        &'a self,
+        query: &Query,
+    ) -> impl Stream<Item = Result<Entity, BackendError>> + Send + 'a {
+        crate::stream::paged_query_stream(self, query, crate::stream::STREAM_BATCH_SIZE)
+    }
+
+    /// Delete every entity matching a query and return how many were
+    /// deleted.
+    ///
+    /// Limit, offset, sort, and cursor are ignored: every match is deleted.
+    /// For a `@soft_delete` schema the matches are stamped as by
+    /// [`delete`](Self::delete). Defaults to collecting the matching ids
+    /// with [`query_stream`](Self::query_stream) and deleting them one at a
+    /// time; entities that disappear in between are not counted.
+    fn delete_by_query(
+        &self,
+        query: &Query,
+    ) -> impl Future<Output = Result<usize, BackendError>> + Send {
+        async move {
+            let mut matches = query.clone();
+            matches.limit = None;
+            matches.offset = None;
+            matches.after = None;
+            matches.sort.clear();
+            let targets: Vec<(SchemaName, EntityId)> = self
+                .query_stream(&matches)
+                .map_ok(|entity| (entity.schema, entity.id))
+                .try_collect()
+                .await?;
+            let mut deleted = 0;
+            for (schema, id) in &targets {
+                match self.delete(schema, id).await {
+                    Ok(()) => deleted += 1,
+                    Err(BackendError::EntityNotFound { .. }) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(deleted)
+        }
+    }
+
     /// Count entities matching a query (ignoring limit/offset).
     ///
     /// Returns the total number of entities that match the query's schema
@@ -105,11 +201,29 @@ pub trait EntityStore: Send + Sync {
 
     /// Compute aggregate values over entities matching a query.
     ///
-    /// Returns one `AggregateResult` per operation in the query's `ops` list.
+    /// Returns one `AggregateResult` per operation in the query's `ops` list,
+    /// in order, for each group; ungrouped queries have a single group.
+    /// Numeric operations on non-numeric fields fail with
+    /// `BackendError::ValidationFailed`.
     fn aggregate(
         &self,
         query: &AggregateQuery,
     ) -> impl Future<Output = Result<Vec<AggregateResult>, BackendError>> + Send;
+
+    /// Atomically advance the `default(sequence(...))` counter for a field
+    /// and return the new value.
+    ///
+    /// The counter is created on first use with value `start`, so the first
+    /// call returns `start + 1`. Concurrent callers must never observe the
+    /// same value, and each call advances the counter by exactly one. A
+    /// value is consumed even if the write it was drawn for later fails,
+    /// so stored numbers are unique but not guaranteed contiguous.
+    fn next_sequence_value(
+        &self,
+        schema: &SchemaName,
+        field: &FieldName,
+        start: i64,
+    ) -> impl Future<Output = Result<i64, BackendError>> + Send;
 }
 
 #[cfg(test)]
@@ -78,12 +78,21 @@ pub enum Commands {
     /// Parse and validate .schema files
     Parse(ParseArgs),
 
+    /// Check .schema files for semantic errors without a backend
+    Validate(ValidateArgs),
+
     /// Apply .schema files to a running backend
     Apply(ApplyArgs),
 
     /// Plan and execute schema migrations
     Migrate(MigrateArgs),
 
+    /// Compare local .schema files with the schemas stored in a backend
+    Diff(DiffArgs),
+
+    /// Create fixture entities from a JSON file
+    Seed(SeedArgs),
+
     /// Start acton-service with SchemaForge extension
     Serve(ServeArgs),
 
@@ -435,6 +444,19 @@ pub struct ParseArgs {
     /// Show detailed token-level parse information
     #[arg(short = 'd', long = "debug")]
     pub debug: bool,
+
+    /// Validate only: check relation targets across all files, print
+    /// nothing on success and one `file:line:col: message` line per error
+    #[arg(long = "check", conflicts_with = "print_ast")]
+    pub check: bool,
+}
+
+/// Arguments for `schema-forge validate`.
+#[derive(Args)]
+pub struct ValidateArgs {
+    /// Schema files or directories to validate (default: ./schemas/)
+    #[arg(default_value = "schemas/")]
+    pub paths: Vec<PathBuf>,
 }
 
 /// Arguments for `schema-forge apply`.
@@ -448,13 +470,33 @@ pub struct ApplyArgs {
     #[arg(short = 'n', long = "dry-run")]
     pub dry_run: bool,
 
-    /// Force apply even for destructive changes
+    /// Force apply even for destructive changes or a non-increasing @version
     #[arg(short = 'f', long = "force")]
     pub force: bool,
 
     /// Auto-generate Cedar policies for new schemas
     #[arg(long = "with-policies")]
     pub with_policies: bool,
+
+    /// Apply schemas one at a time instead of running independent schemas
+    /// concurrently
+    #[arg(long = "sequential")]
+    pub sequential: bool,
+}
+
+/// Arguments for `schema-forge diff`.
+#[derive(Args)]
+pub struct DiffArgs {
+    /// Schema files or directories to compare (default: ./schemas/)
+    #[arg(default_value = "schemas/")]
+    pub paths: Vec<PathBuf>,
+}
+
+/// Arguments for `schema-forge seed`.
+#[derive(Args)]
+pub struct SeedArgs {
+    /// JSON file mapping schema names to arrays of field objects
+    pub file: PathBuf,
 }
 
 /// Arguments for `schema-forge migrate`.
@@ -475,6 +517,28 @@ pub struct MigrateArgs {
     /// Show only a specific schema's migration
     #[arg(short = 's', long = "schema")]
     pub schema: Option<String>,
+
+    /// Write the migration plan to `--out` for later review instead of
+    /// printing it
+    #[arg(long = "plan-only", requires = "out", conflicts_with = "execute")]
+    pub plan_only: bool,
+
+    /// Plan file written by `--plan-only`
+    #[arg(long = "out", requires = "plan_only")]
+    pub out: Option<PathBuf>,
+
+    /// Apply a plan file written by `--plan-only`, refusing if any stored
+    /// schema changed since the plan was generated
+    #[arg(
+        long = "from-plan",
+        conflicts_with_all = ["execute", "plan_only", "schema"]
+    )]
+    pub from_plan: Option<PathBuf>,
+
+    /// JSON file of per-field value transforms that replace the inferred
+    /// ones for type changes, keyed by schema and then field name
+    #[arg(long = "transforms", conflicts_with = "from_plan")]
+    pub transforms: Option<PathBuf>,
 }
 
 /// Arguments for `schema-forge serve`.
@@ -492,7 +556,7 @@ pub struct ServeArgs {
     #[arg(long = "schemas", default_value = "schemas/")]
     pub schema_dir: PathBuf,
 
-    /// Watch schema files for changes and hot-reload
+    /// Watch schema files and hot-reload non-destructive changes
     #[arg(short = 'w', long = "watch")]
     pub watch: bool,
 
@@ -529,6 +593,19 @@ pub struct ServeArgs {
 pub enum ExportCommands {
     /// Export OpenAPI specification
     Openapi(ExportOpenapiArgs),
+
+    /// Export every entity of a schema as newline-delimited JSON
+    Entities(ExportEntitiesArgs),
+
+    /// Export a schema as a JSON Schema document for client-side validation
+    JsonSchema(ExportJsonSchemaArgs),
+
+    /// Export TypeScript interfaces for every schema
+    Typescript(ExportTypescriptArgs),
+
+    /// Export the GraphQL schema as SDL
+    #[cfg(feature = "graphql")]
+    GraphqlSdl(ExportGraphqlSdlArgs),
 }
 
 /// Arguments for `schema-forge export openapi`.
@@ -551,12 +628,72 @@ pub struct ExportOpenapiArgs {
     pub spec_version: String,
 }
 
+/// Arguments for `schema-forge export entities`.
+#[derive(Args)]
+pub struct ExportEntitiesArgs {
+    /// Schema whose entities to export
+    pub schema: String,
+
+    /// Output file (default: stdout)
+    #[arg(short = 'o', long = "output")]
+    pub output: Option<PathBuf>,
+
+    /// Include soft-deleted entities of a `@soft_delete` schema
+    #[arg(long = "include-deleted")]
+    pub include_deleted: bool,
+}
+
+/// Arguments for `schema-forge export json-schema`.
+#[derive(Args)]
+pub struct ExportJsonSchemaArgs {
+    /// Schema to export
+    pub schema: String,
+
+    /// Schema files to search (default: ./schemas/)
+    #[arg(default_value = "schemas/")]
+    pub paths: Vec<PathBuf>,
+
+    /// Output file (default: stdout)
+    #[arg(short = 'o', long = "output")]
+    pub output: Option<PathBuf>,
+}
+
+/// Arguments for `schema-forge export typescript`.
+#[derive(Args)]
+pub struct ExportTypescriptArgs {
+    /// Output file (default: stdout)
+    #[arg(short = 'o', long = "output")]
+    pub output: Option<PathBuf>,
+
+    /// Schema files to include (default: ./schemas/)
+    #[arg(default_value = "schemas/")]
+    pub paths: Vec<PathBuf>,
+}
+
+/// Arguments for `schema-forge export graphql-sdl`.
+#[cfg(feature = "graphql")]
+#[derive(Args)]
+pub struct ExportGraphqlSdlArgs {
+    /// Output file (default: stdout)
+    #[arg(short = 'o', long = "output")]
+    pub output: Option<PathBuf>,
+
+    /// Schema files to include (default: ./schemas/)
+    #[arg(default_value = "schemas/")]
+    pub paths: Vec<PathBuf>,
+}
+
 /// Arguments for `schema-forge inspect`.
 #[derive(Args)]
 pub struct InspectArgs {
     /// Show a specific schema (omit for all)
     pub schema: Option<String>,
 
+    /// Show a specific schema's fields, types, and modifiers (same as the
+    /// positional argument)
+    #[arg(short = 's', long = "schema", conflicts_with = "schema")]
+    pub schema_flag: Option<String>,
+
     /// Show detailed field information
     #[arg(short = 'd', long = "detail")]
     pub detail: bool,
@@ -564,6 +701,11 @@ pub struct InspectArgs {
     /// Include entity count per schema (requires backend query)
     #[arg(long = "counts")]
     pub counts: bool,
+
+    /// Show entity counts and per-field null/populated histograms
+    /// (scans every entity; enum fields also show variant distribution)
+    #[arg(long = "stats")]
+    pub stats: bool,
 }
 
 /// Policy subcommands.
@@ -693,6 +835,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_parse_command_check() {
+        let cli = Cli::try_parse_from(["schemaforge", "parse", "--check", "schemas/"]).unwrap();
+        if let Commands::Parse(args) = cli.command {
+            assert!(args.check);
+            assert!(!args.print_ast);
+        } else {
+            panic!("expected Parse command");
+        }
+        assert!(Cli::try_parse_from(["schemaforge", "parse", "--check", "--print"]).is_err());
+    }
+
+    #[test]
+    fn parse_validate_command() {
+        let cli = Cli::try_parse_from(["schemaforge", "validate"]).unwrap();
+        if let Commands::Validate(args) = cli.command {
+            assert_eq!(args.paths, vec![PathBuf::from("schemas/")]);
+        } else {
+            panic!("expected Validate command");
+        }
+    }
+
+    #[test]
+    fn parse_diff_command() {
+        let cli = Cli::try_parse_from([
+            "schemaforge",
+            "diff",
+            "schemas/",
+            "--db-url",
+            "postgres://localhost/app",
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.global.db_url.as_deref(),
+            Some("postgres://localhost/app")
+        );
+        if let Commands::Diff(args) = cli.command {
+            assert_eq!(args.paths, vec![PathBuf::from("schemas/")]);
+        } else {
+            panic!("expected Diff command");
+        }
+    }
+
+    #[test]
+    fn parse_seed_command() {
+        let cli = Cli::try_parse_from(["schemaforge", "seed", "fixtures.json"]).unwrap();
+        if let Commands::Seed(args) = cli.command {
+            assert_eq!(args.file, PathBuf::from("fixtures.json"));
+        } else {
+            panic!("expected Seed command");
+        }
+        assert!(Cli::try_parse_from(["schemaforge", "seed"]).is_err());
+    }
+
     #[test]
     fn parse_apply_command_dry_run() {
         let cli = Cli::try_parse_from(["schemaforge", "apply", "--dry-run"]).unwrap();
@@ -728,6 +924,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_inspect_schema_flag() {
+        let cli = Cli::try_parse_from(["schemaforge", "inspect", "--schema", "Contact"]).unwrap();
+        if let Commands::Inspect(args) = cli.command {
+            assert_eq!(args.schema, None);
+            assert_eq!(args.schema_flag, Some("Contact".to_string()));
+        } else {
+            panic!("expected Inspect command");
+        }
+        assert!(
+            Cli::try_parse_from(["schemaforge", "inspect", "Contact", "--schema", "Company"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_inspect_stats() {
+        let cli = Cli::try_parse_from(["schemaforge", "inspect", "--stats"]).unwrap();
+        if let Commands::Inspect(args) = cli.command {
+            assert_eq!(args.schema, None);
+            assert!(args.stats);
+        } else {
+            panic!("expected Inspect command");
+        }
+    }
+
     #[test]
     fn parse_export_openapi() {
         let cli =
@@ -742,6 +964,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_export_entities() {
+        let cli = Cli::try_parse_from([
+            "schemaforge",
+            "export",
+            "entities",
+            "Contact",
+            "-o",
+            "contacts.ndjson",
+        ])
+        .unwrap();
+        if let Commands::Export {
+            command: ExportCommands::Entities(args),
+        } = cli.command
+        {
+            assert_eq!(args.schema, "Contact");
+            assert_eq!(args.output, Some(PathBuf::from("contacts.ndjson")));
+            assert!(!args.include_deleted);
+        } else {
+            panic!("expected Export Entities command");
+        }
+    }
+
+    #[test]
+    fn parse_export_json_schema() {
+        let cli = Cli::try_parse_from([
+            "schemaforge",
+            "export",
+            "json-schema",
+            "Contact",
+            "src/schemas/",
+        ])
+        .unwrap();
+        if let Commands::Export {
+            command: ExportCommands::JsonSchema(args),
+        } = cli.command
+        {
+            assert_eq!(args.schema, "Contact");
+            assert_eq!(args.paths, vec![PathBuf::from("src/schemas/")]);
+            assert_eq!(args.output, None);
+        } else {
+            panic!("expected Export JsonSchema command");
+        }
+    }
+
+    #[test]
+    fn parse_export_typescript() {
+        let cli = Cli::try_parse_from([
+            "schemaforge",
+            "export",
+            "typescript",
+            "schemas/",
+            "-o",
+            "src/types.ts",
+        ])
+        .unwrap();
+        if let Commands::Export {
+            command: ExportCommands::Typescript(args),
+        } = cli.command
+        {
+            assert_eq!(args.paths, vec![PathBuf::from("schemas/")]);
+            assert_eq!(args.output, Some(PathBuf::from("src/types.ts")));
+        } else {
+            panic!("expected Export Typescript command");
+        }
+    }
+
+    #[cfg(feature = "graphql")]
+    #[test]
+    fn parse_export_graphql_sdl() {
+        let cli = Cli::try_parse_from([
+            "schemaforge",
+            "export",
+            "graphql-sdl",
+            "-o",
+            "schema.graphql",
+        ])
+        .unwrap();
+        if let Commands::Export {
+            command: ExportCommands::GraphqlSdl(args),
+        } = cli.command
+        {
+            assert_eq!(args.output, Some(PathBuf::from("schema.graphql")));
+            assert_eq!(args.paths, vec![PathBuf::from("schemas/")]);
+        } else {
+            panic!("expected Export GraphqlSdl command");
+        }
+    }
+
     #[test]
     fn parse_policies_list() {
         let cli = Cli::try_parse_from(["schemaforge", "policies", "list", "Contact"]).unwrap();
@@ -1,5 +1,13 @@
+use std::sync::Arc;
+
 use console::Term;
-use schema_forge_core::migration::{DiffEngine, MigrationSafety};
+use schema_forge_acton::DynForgeBackend;
+use schema_forge_core::migration::{
+    dependency_layers, order_plans, DiffEngine, MigrationPlan, MigrationSafety,
+};
+use schema_forge_core::types::SchemaDefinition;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 
 use crate::cli::{ApplyArgs, GlobalOpts};
 use crate::commands::parse::parse_all_schemas;
@@ -7,6 +15,9 @@ use crate::config::{load_svc_config, resolve_db_params};
 use crate::error::CliError;
 use crate::output::{OutputContext, OutputMode};
 
+/// Upper bound on schemas migrated at once within a dependency layer.
+const MAX_CONCURRENT_APPLIES: usize = 8;
+
 /// Run the `apply` command: parse schemas and apply to backend.
 pub async fn run(
     args: ApplyArgs,
@@ -24,14 +35,14 @@ pub async fn run(
 
     let mut total_steps = 0usize;
     let mut applied_schemas = 0usize;
+    let mut pending: Vec<(usize, MigrationPlan)> = Vec::new();
 
-    for schema in &schemas {
+    for (index, schema) in schemas.iter().enumerate() {
         let existing = backend.load_schema_metadata(&schema.name).await?;
 
-        let plan = if let Some(old) = existing {
-            DiffEngine::diff(&old, schema)
-        } else {
-            DiffEngine::create_new(schema)
+        let plan = match &existing {
+            Some(old) => DiffEngine::diff(old, schema),
+            None => DiffEngine::create_new(schema),
         };
 
         if plan.is_empty() {
@@ -39,6 +50,10 @@ pub async fn run(
             continue;
         }
 
+        if let Some(old) = &existing {
+            check_version(old, schema, args.force)?;
+        }
+
         // Safety check for destructive operations
         if plan.has_destructive_steps() && !args.force && !args.dry_run {
             let is_tty = Term::stderr().is_term();
@@ -101,13 +116,17 @@ pub async fn run(
             }
         }
 
-        if !args.dry_run {
-            backend.apply_migration(&schema.name, &plan.steps).await?;
-            backend.store_schema_metadata(schema).await?;
-        }
-
         total_steps += plan.steps.len();
         applied_schemas += 1;
+        pending.push((index, plan));
+    }
+
+    // Refuse a batch whose new relations loop, dry run or not, before any
+    // schema is touched.
+    let pending = order_pending(&schemas, pending)?;
+
+    if !args.dry_run {
+        execute_plans(&backend, &schemas, pending, args.sequential).await?;
     }
 
     // Generate policies if requested
@@ -125,7 +144,9 @@ pub async fn run(
     // Summary
     match output.mode {
         OutputMode::Human => {
-            if args.dry_run {
+            if applied_schemas == 0 {
+                output.success("No changes.");
+            } else if args.dry_run {
                 output.success(&format!(
                     "Dry run: {applied_schemas} schemas would be applied ({total_steps} migration steps)."
                 ));
@@ -151,6 +172,107 @@ pub async fn run(
     Ok(())
 }
 
+/// Sort `pending` plans (indexed into `schemas`) with [`order_plans`] so
+/// relation targets come first, failing on a relation cycle.
+fn order_pending(
+    schemas: &[SchemaDefinition],
+    pending: Vec<(usize, MigrationPlan)>,
+) -> Result<Vec<(usize, MigrationPlan)>, CliError> {
+    let plans = order_plans(pending.into_iter().map(|(_, plan)| plan).collect())?;
+    Ok(plans
+        .into_iter()
+        .filter_map(|plan| {
+            let index = schemas.iter().position(|s| s.name == plan.schema_name)?;
+            Some((index, plan))
+        })
+        .collect())
+}
+
+/// Apply the confirmed `plans` (indexed into `schemas`) to the backend.
+///
+/// Schemas are grouped into relation dependency layers; each layer runs
+/// concurrently (bounded by [`MAX_CONCURRENT_APPLIES`]) and completes before
+/// the next begins, so a relation target always lands before the schemas
+/// pointing at it. With `sequential`, plans run one at a time in dependency
+/// order. Every schema writes only its own table and metadata record.
+async fn execute_plans(
+    backend: &Arc<dyn DynForgeBackend>,
+    schemas: &[SchemaDefinition],
+    plans: Vec<(usize, MigrationPlan)>,
+    sequential: bool,
+) -> Result<(), CliError> {
+    let mut plans: Vec<Option<MigrationPlan>> = {
+        let mut by_index = vec![None; schemas.len()];
+        for (index, plan) in plans {
+            by_index[index] = Some(plan);
+        }
+        by_index
+    };
+    let limit = if sequential {
+        1
+    } else {
+        MAX_CONCURRENT_APPLIES
+    };
+    let semaphore = Arc::new(Semaphore::new(limit));
+
+    for layer in dependency_layers(schemas) {
+        let mut tasks = JoinSet::new();
+        for index in layer {
+            let Some(plan) = plans[index].take() else {
+                continue;
+            };
+            let backend = Arc::clone(backend);
+            let schema = schemas[index].clone();
+            let semaphore = Arc::clone(&semaphore);
+            tasks.spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .map_err(|e| CliError::Other(e.to_string()))?;
+                backend.apply_migration(&schema.name, &plan.steps).await?;
+                backend.store_schema_metadata(&schema).await?;
+                Ok::<(), CliError>(())
+            });
+            if sequential {
+                // Await each plan before spawning the next.
+                if let Some(joined) = tasks.join_next().await {
+                    joined.map_err(|e| CliError::Other(e.to_string()))??;
+                }
+            }
+        }
+
+        // Let the rest of the layer finish before reporting a failure so no
+        // write is left running in the background.
+        let mut first_error = None;
+        while let Some(joined) = tasks.join_next().await {
+            let result = joined.map_err(|e| CliError::Other(e.to_string()))?;
+            if let Err(e) = result {
+                first_error.get_or_insert(e);
+            }
+        }
+        if let Some(e) = first_error {
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// Reject applying `incoming` over `stored` when its `@version` does not
+/// advance, unless `force` is set. An out-of-date local file would otherwise
+/// silently undo newer changes.
+fn check_version(
+    stored: &SchemaDefinition,
+    incoming: &SchemaDefinition,
+    force: bool,
+) -> Result<(), CliError> {
+    if force {
+        return Ok(());
+    }
+    incoming
+        .check_version_advances(stored)
+        .map_err(CliError::VersionNotIncreased)
+}
+
 fn format_safety_tag(safety: MigrationSafety) -> String {
     match safety {
         MigrationSafety::Safe => "  [safe]".to_string(),
@@ -159,3 +281,293 @@ fn format_safety_tag(safety: MigrationSafety) -> String {
         _ => String::new(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schema_forge_core::types::{
+        Annotation, FieldDefinition, FieldName, FieldType, SchemaId, SchemaName, SchemaVersion,
+        TextConstraints,
+    };
+
+    fn contact(version: u32) -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("name").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            )],
+            vec![Annotation::Version {
+                version: SchemaVersion::new(version).unwrap(),
+            }],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn newer_version_over_older_is_applied() {
+        assert!(check_version(&contact(1), &contact(2), false).is_ok());
+    }
+
+    #[test]
+    fn older_version_over_newer_fails_without_force() {
+        let err = check_version(&contact(2), &contact(1), false).unwrap_err();
+        assert!(matches!(err, CliError::VersionNotIncreased(_)));
+        assert!(err.to_string().contains("--force"));
+    }
+
+    #[test]
+    fn older_version_over_newer_applies_with_force() {
+        assert!(check_version(&contact(2), &contact(1), true).is_ok());
+    }
+
+    #[test]
+    fn order_pending_rejects_relation_cycle() {
+        use schema_forge_core::types::Cardinality;
+
+        let relates_to = |name: &str, target: &str| {
+            SchemaDefinition::new(
+                SchemaId::new(),
+                SchemaName::new(name).unwrap(),
+                vec![FieldDefinition::new(
+                    FieldName::new("peer").unwrap(),
+                    FieldType::Relation {
+                        target: SchemaName::new(target).unwrap(),
+                        cardinality: Cardinality::One,
+                    },
+                )],
+                vec![],
+            )
+            .unwrap()
+        };
+        let schemas = vec![
+            relates_to("Contact", "Company"),
+            relates_to("Company", "Contact"),
+        ];
+        let pending = schemas
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (i, DiffEngine::create_new(s)))
+            .collect();
+        let err = order_pending(&schemas, pending).unwrap_err();
+        assert_eq!(err.exit_code(), crate::error::ExitCode::MigrationError);
+        assert!(err.to_string().contains("Contact -> Company -> Contact"));
+    }
+
+    /// Delegates to an in-memory SurrealDB backend and records the order in
+    /// which schema metadata is stored.
+    #[cfg(feature = "surrealdb")]
+    struct RecordingBackend {
+        inner: schema_forge_surrealdb::SurrealBackend,
+        stored: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[cfg(feature = "surrealdb")]
+    mod recording_backend {
+        use std::collections::BTreeMap;
+
+        use schema_forge_backend::entity::{Entity, QueryResult};
+        use schema_forge_backend::error::BackendError;
+        use schema_forge_backend::traits::{EntityStore, SchemaBackend};
+        use schema_forge_core::migration::MigrationStep;
+        use schema_forge_core::query::{AggregateQuery, AggregateResult, Query};
+        use schema_forge_core::types::{
+            DynamicValue, EntityId, FieldName, SchemaDefinition, SchemaName,
+        };
+
+        use super::RecordingBackend;
+
+        impl SchemaBackend for RecordingBackend {
+            async fn apply_migration(
+                &self,
+                schema_name: &SchemaName,
+                steps: &[MigrationStep],
+            ) -> Result<(), BackendError> {
+                self.inner.apply_migration(schema_name, steps).await
+            }
+
+            async fn store_schema_metadata(
+                &self,
+                definition: &SchemaDefinition,
+            ) -> Result<(), BackendError> {
+                self.inner.store_schema_metadata(definition).await?;
+                self.stored
+                    .lock()
+                    .unwrap()
+                    .push(definition.name.as_str().to_string());
+                Ok(())
+            }
+
+            async fn load_schema_metadata(
+                &self,
+                name: &SchemaName,
+            ) -> Result<Option<SchemaDefinition>, BackendError> {
+                self.inner.load_schema_metadata(name).await
+            }
+
+            async fn list_schema_metadata(&self) -> Result<Vec<SchemaDefinition>, BackendError> {
+                self.inner.list_schema_metadata().await
+            }
+        }
+
+        impl EntityStore for RecordingBackend {
+            async fn create(&self, entity: &Entity) -> Result<Entity, BackendError> {
+                self.inner.create(entity).await
+            }
+
+            async fn create_many(&self, entities: &[Entity]) -> Result<Vec<Entity>, BackendError> {
+                self.inner.create_many(entities).await
+            }
+
+            async fn get(
+                &self,
+                schema: &SchemaName,
+                id: &EntityId,
+            ) -> Result<Entity, BackendError> {
+                self.inner.get(schema, id).await
+            }
+
+            async fn update(
+                &self,
+                entity: &Entity,
+                expected_version: Option<u64>,
+            ) -> Result<Entity, BackendError> {
+                self.inner.update(entity, expected_version).await
+            }
+
+            async fn patch(
+                &self,
+                schema: &SchemaName,
+                id: &EntityId,
+                changes: BTreeMap<String, DynamicValue>,
+                expected_version: Option<u64>,
+            ) -> Result<Entity, BackendError> {
+                self.inner
+                    .patch(schema, id, changes, expected_version)
+                    .await
+            }
+
+            async fn delete(&self, schema: &SchemaName, id: &EntityId) -> Result<(), BackendError> {
+                self.inner.delete(schema, id).await
+            }
+
+            async fn restore(
+                &self,
+                schema: &SchemaName,
+                id: &EntityId,
+            ) -> Result<Entity, BackendError> {
+                self.inner.restore(schema, id).await
+            }
+
+            async fn query(&self, query: &Query) -> Result<QueryResult, BackendError> {
+                self.inner.query(query).await
+            }
+
+            async fn count(&self, query: &Query) -> Result<usize, BackendError> {
+                self.inner.count(query).await
+            }
+
+            async fn aggregate(
+                &self,
+                query: &AggregateQuery,
+            ) -> Result<Vec<AggregateResult>, BackendError> {
+                self.inner.aggregate(query).await
+            }
+
+            async fn next_sequence_value(
+                &self,
+                schema: &SchemaName,
+                field: &FieldName,
+                start: i64,
+            ) -> Result<i64, BackendError> {
+                self.inner.next_sequence_value(schema, field, start).await
+            }
+        }
+    }
+
+    #[cfg(feature = "surrealdb")]
+    async fn apply_batch(sequential: bool) -> Vec<String> {
+        use schema_forge_core::types::Cardinality;
+
+        let plain = |name: &str| {
+            SchemaDefinition::new(
+                SchemaId::new(),
+                SchemaName::new(name).unwrap(),
+                vec![FieldDefinition::new(
+                    FieldName::new("name").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                )],
+                vec![],
+            )
+            .unwrap()
+        };
+        // The dependent schema comes first so input order alone would
+        // create it before its target.
+        let mut schemas = vec![SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("company").unwrap(),
+                FieldType::Relation {
+                    target: SchemaName::new("Company").unwrap(),
+                    cardinality: Cardinality::One,
+                },
+            )],
+            vec![],
+        )
+        .unwrap()];
+        schemas.extend(["Company", "Tag", "Region", "Product", "Invoice"].map(plain));
+
+        let backend = Arc::new(RecordingBackend {
+            inner: schema_forge_surrealdb::SurrealBackend::connect_memory("test", "apply")
+                .await
+                .unwrap(),
+            stored: std::sync::Mutex::new(Vec::new()),
+        });
+        let plans = schemas
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (i, DiffEngine::create_new(s)))
+            .collect();
+        let dyn_backend: Arc<dyn DynForgeBackend> = backend.clone();
+        execute_plans(&dyn_backend, &schemas, plans, sequential)
+            .await
+            .unwrap();
+
+        let mut listed: Vec<String> = dyn_backend
+            .list_schema_metadata()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|s| s.name.as_str().to_string())
+            .collect();
+        listed.sort();
+        assert_eq!(
+            listed,
+            ["Company", "Contact", "Invoice", "Product", "Region", "Tag"]
+        );
+
+        let stored = backend.stored.lock().unwrap();
+        stored.clone()
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn parallel_apply_registers_all_and_orders_dependents() {
+        let stored = apply_batch(false).await;
+        let position = |name: &str| stored.iter().position(|s| s == name).unwrap();
+        assert!(position("Company") < position("Contact"));
+        assert_eq!(stored.last().map(String::as_str), Some("Contact"));
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[tokio::test]
+    async fn sequential_apply_follows_dependency_order() {
+        let stored = apply_batch(true).await;
+        assert_eq!(
+            stored,
+            ["Company", "Tag", "Region", "Product", "Invoice", "Contact"]
+        );
+    }
+}
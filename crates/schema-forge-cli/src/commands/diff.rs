@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+
+use schema_forge_core::migration::{DiffEngine, MigrationPlan, MigrationSafety, MigrationStep};
+use schema_forge_core::types::SchemaDefinition;
+
+use crate::cli::{DiffArgs, GlobalOpts};
+use crate::commands::migrate::{plan_json, print_plan, safety_label};
+use crate::commands::parse::parse_all_schemas;
+use crate::config::{load_svc_config, resolve_db_params};
+use crate::error::CliError;
+use crate::output::{OutputContext, OutputMode};
+
+/// Run the `diff` command: compare local schemas with the stored registry.
+///
+/// Prints one plan per changed schema and an overall verdict. Nothing is
+/// written to the backend. Fails with `CliError::DestructiveChanges` when
+/// any step is destructive, so CI can gate on the exit code.
+pub async fn run(
+    args: DiffArgs,
+    global: &GlobalOpts,
+    output: &OutputContext,
+) -> Result<(), CliError> {
+    let schemas = parse_all_schemas(&args.paths)?;
+
+    let svc_config = load_svc_config(global)?;
+    let db_params = resolve_db_params(&svc_config)?;
+
+    let backend = super::connect_backend(&db_params, output).await?;
+    let stored = backend.list_schema_metadata().await?;
+
+    let plans = diff_registry(&schemas, &stored);
+    let total_steps: usize = plans.iter().map(MigrationPlan::len).sum();
+    let destructive_steps = plans
+        .iter()
+        .flat_map(|plan| &plan.steps)
+        .filter(|step| step.safety() == MigrationSafety::Destructive)
+        .count();
+    let verdict = overall_safety(&plans);
+
+    match output.mode {
+        OutputMode::Human => {
+            for plan in &plans {
+                print_plan(plan, output);
+            }
+            match verdict {
+                None => output.success("Local schemas match the backend."),
+                Some(safety) => println!(
+                    "Total: {total_steps} steps across {} schemas, {}.",
+                    plans.len(),
+                    safety_label(safety, output)
+                ),
+            }
+        }
+        OutputMode::Json => {
+            let json = serde_json::json!({
+                "plans": plans.iter().map(plan_json).collect::<Vec<_>>(),
+                "total_steps": total_steps,
+                "schemas_affected": plans.len(),
+                "safety": verdict.map(|s| s.to_string()),
+            });
+            output.print_json(&json);
+        }
+        OutputMode::Plain => {
+            for plan in &plans {
+                for step in &plan.steps {
+                    println!("{}\t{}\t{}", plan.schema_name.as_str(), step, step.safety());
+                }
+            }
+        }
+    }
+
+    if destructive_steps > 0 {
+        return Err(CliError::DestructiveChanges {
+            steps: destructive_steps,
+        });
+    }
+    Ok(())
+}
+
+/// Plan every local schema against its stored version, and drop every
+/// stored schema no local file defines. Built-in system schemas are never
+/// dropped. Schemas without changes yield no plan.
+fn diff_registry(local: &[SchemaDefinition], stored: &[SchemaDefinition]) -> Vec<MigrationPlan> {
+    let mut plans: Vec<MigrationPlan> = local
+        .iter()
+        .map(
+            |schema| match stored.iter().find(|s| s.name == schema.name) {
+                Some(old) => DiffEngine::diff(old, schema),
+                None => DiffEngine::create_new(schema),
+            },
+        )
+        .collect();
+
+    let local_names: HashSet<&str> = local.iter().map(|s| s.name.as_str()).collect();
+    let system_names: HashSet<String> = schema_forge_core::system_schemas::all_system_schemas()
+        .into_iter()
+        .filter_map(|dsl| schema_forge_dsl::parse(dsl).ok())
+        .flatten()
+        .map(|s| s.name.as_str().to_string())
+        .collect();
+    plans.extend(
+        stored
+            .iter()
+            .filter(|s| {
+                !local_names.contains(s.name.as_str()) && !system_names.contains(s.name.as_str())
+            })
+            .map(|s| {
+                MigrationPlan::new(
+                    s.id.clone(),
+                    s.name.clone(),
+                    vec![MigrationStep::DropSchema {
+                        name: s.name.clone(),
+                    }],
+                )
+            }),
+    );
+
+    plans.retain(|plan| !plan.is_empty());
+    plans
+}
+
+/// The least safe level across all plans, or `None` if nothing changes.
+fn overall_safety(plans: &[MigrationPlan]) -> Option<MigrationSafety> {
+    if plans.is_empty() {
+        return None;
+    }
+    let levels: Vec<MigrationSafety> = plans.iter().map(MigrationPlan::overall_safety).collect();
+    Some(if levels.contains(&MigrationSafety::Destructive) {
+        MigrationSafety::Destructive
+    } else if levels.contains(&MigrationSafety::RequiresConfirmation) {
+        MigrationSafety::RequiresConfirmation
+    } else {
+        MigrationSafety::Safe
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schemas(source: &str) -> Vec<SchemaDefinition> {
+        schema_forge_dsl::parse(source).unwrap()
+    }
+
+    #[test]
+    fn unchanged_schemas_yield_no_plans() {
+        let local = schemas("schema Contact { name: text }");
+        assert!(diff_registry(&local, &local).is_empty());
+        assert_eq!(overall_safety(&[]), None);
+    }
+
+    #[test]
+    fn new_and_removed_schemas() {
+        let local = schemas("schema Contact { name: text }");
+        let stored = schemas("schema Lead { name: text }\nschema User { email: text }");
+        let plans = diff_registry(&local, &stored);
+        assert_eq!(plans.len(), 2);
+        assert!(matches!(
+            plans[0].steps[0],
+            MigrationStep::CreateSchema { .. }
+        ));
+        assert_eq!(plans[1].schema_name.as_str(), "Lead");
+        assert!(matches!(
+            plans[1].steps[0],
+            MigrationStep::DropSchema { .. }
+        ));
+        assert_eq!(overall_safety(&plans), Some(MigrationSafety::Destructive));
+    }
+
+    #[test]
+    fn added_field_is_safe() {
+        let stored = schemas("schema Contact { name: text }");
+        let local = schemas("schema Contact { name: text email: text }");
+        let plans = diff_registry(&local, &stored);
+        assert_eq!(plans.len(), 1);
+        assert_eq!(overall_safety(&plans), Some(MigrationSafety::Safe));
+    }
+}
@@ -1,5 +1,18 @@
-use crate::cli::{ExportCommands, ExportOpenapiArgs, GlobalOpts};
+use std::io::Write;
+use std::path::PathBuf;
+
+use futures::StreamExt;
+use heck::ToPascalCase;
+use schema_forge_acton::conversions::entity_to_response;
+use schema_forge_core::query::Query;
+use schema_forge_core::types::{Cardinality, FieldDefinition, FieldType, SchemaDefinition};
+
+use crate::cli::{
+    ExportCommands, ExportEntitiesArgs, ExportJsonSchemaArgs, ExportOpenapiArgs,
+    ExportTypescriptArgs, GlobalOpts,
+};
 use crate::commands::parse::parse_all_schemas;
+use crate::config::{load_svc_config, resolve_db_params};
 use crate::error::CliError;
 use crate::output::OutputContext;
 
@@ -11,118 +24,260 @@ pub async fn run(
 ) -> Result<(), CliError> {
     match command {
         ExportCommands::Openapi(args) => run_openapi(args, global, output).await,
+        ExportCommands::Entities(args) => run_entities(args, global, output).await,
+        ExportCommands::JsonSchema(args) => run_json_schema(args, output),
+        ExportCommands::Typescript(args) => run_typescript(args, output),
+        #[cfg(feature = "graphql")]
+        ExportCommands::GraphqlSdl(args) => run_graphql_sdl(args, output),
     }
 }
 
-async fn run_openapi(
-    args: ExportOpenapiArgs,
-    _global: &GlobalOpts,
+/// Write the GraphQL SDL the server would build from the schema files.
+///
+/// Uses the same builder as `POST /forge/graphql`, so the output includes
+/// every query, connection, and mutation field a client can call.
+#[cfg(feature = "graphql")]
+fn run_graphql_sdl(
+    args: crate::cli::ExportGraphqlSdlArgs,
     output: &OutputContext,
 ) -> Result<(), CliError> {
     let schemas = parse_all_schemas(&args.paths)?;
+    let sdl = schema_forge_acton::graphql::schema_builder::build_graphql_schema(&schemas)
+        .map_err(CliError::Other)?
+        .sdl();
 
-    // Build a basic OpenAPI spec from schema definitions
-    let mut paths = serde_json::Map::new();
-    let mut components_schemas = serde_json::Map::new();
+    if let Some(output_path) = &args.output {
+        std::fs::write(output_path, &sdl).map_err(|e| CliError::Io {
+            path: output_path.clone(),
+            source: e,
+        })?;
+        output.success(&format!("Wrote GraphQL SDL to {}", output_path.display()));
+    } else {
+        print!("{sdl}");
+    }
+    Ok(())
+}
 
-    for schema in &schemas {
-        let name = schema.name.as_str();
-        let lower = name.to_ascii_lowercase();
+/// Write the JSON Schema document for one schema from the schema files.
+fn run_json_schema(args: ExportJsonSchemaArgs, output: &OutputContext) -> Result<(), CliError> {
+    let schema = parse_all_schemas(&args.paths)?
+        .into_iter()
+        .find(|s| s.name.as_str() == args.schema)
+        .ok_or_else(|| CliError::SchemaNotFound {
+            name: args.schema.clone(),
+        })?;
+    let json_schema = schema_forge_core::json_schema::to_json_schema(&schema);
 
-        // Build schema component
-        let mut properties = serde_json::Map::new();
-        let mut required_fields = Vec::new();
+    if let Some(output_path) = &args.output {
+        let json_str = serde_json::to_string_pretty(&json_schema)
+            .map_err(|e| CliError::Other(format!("failed to serialize JSON Schema: {e}")))?;
+        std::fs::write(output_path, json_str).map_err(|e| CliError::Io {
+            path: output_path.clone(),
+            source: e,
+        })?;
+        output.success(&format!(
+            "Wrote {} JSON Schema to {}",
+            schema.name.as_str(),
+            output_path.display()
+        ));
+    } else {
+        output.print_json(&json_schema);
+    }
+    Ok(())
+}
 
-        for field in &schema.fields {
-            let field_name = field.name.as_str();
-            let field_schema = serde_json::json!({
-                "type": openapi_type_for(&field.field_type),
-            });
-            properties.insert(field_name.to_string(), field_schema);
+/// Write one TypeScript interface per schema from the schema files.
+fn run_typescript(args: ExportTypescriptArgs, output: &OutputContext) -> Result<(), CliError> {
+    let schemas = parse_all_schemas(&args.paths)?;
+    let ts = render_typescript(&schemas);
 
-            if field.is_required() {
-                required_fields.push(serde_json::Value::String(field_name.to_string()));
-            }
-        }
+    if let Some(output_path) = &args.output {
+        std::fs::write(output_path, &ts).map_err(|e| CliError::Io {
+            path: output_path.clone(),
+            source: e,
+        })?;
+        output.success(&format!(
+            "Wrote {} TypeScript interfaces to {}",
+            schemas.len(),
+            output_path.display()
+        ));
+    } else {
+        print!("{ts}");
+    }
+    Ok(())
+}
 
-        let mut component = serde_json::json!({
-            "type": "object",
-            "properties": properties,
-        });
-        if !required_fields.is_empty() {
-            component["required"] = serde_json::Value::Array(required_fields);
-        }
-        components_schemas.insert(name.to_string(), component);
-
-        // Build path entries
-        let collection_path = format!("{}/schemas/{lower}/entities", args.base_path);
-        let item_path = format!("{}/schemas/{lower}/entities/{{id}}", args.base_path);
-
-        paths.insert(
-            collection_path,
-            serde_json::json!({
-                "get": {
-                    "summary": format!("List {name} entities"),
-                    "responses": {
-                        "200": {
-                            "description": format!("List of {name} entities"),
-                        }
-                    }
-                },
-                "post": {
-                    "summary": format!("Create a {name} entity"),
-                    "responses": {
-                        "201": {
-                            "description": format!("Created {name} entity"),
-                        }
-                    }
-                }
-            }),
-        );
+/// Render the entity `fields` of every schema as TypeScript interfaces.
+///
+/// Schemas are sorted by name and fields keep declaration order, so the
+/// output only changes when the schemas do. Composite fields become nested
+/// interfaces named after the parent and field (`Contact.address` ->
+/// `ContactAddress`). `@hidden` fields are left out since responses never
+/// carry them.
+fn render_typescript(schemas: &[SchemaDefinition]) -> String {
+    let mut sorted: Vec<&SchemaDefinition> = schemas.iter().collect();
+    sorted.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
 
-        paths.insert(
-            item_path,
-            serde_json::json!({
-                "get": {
-                    "summary": format!("Get a {name} entity by ID"),
-                    "responses": {
-                        "200": {
-                            "description": format!("{name} entity"),
-                        }
-                    }
-                },
-                "put": {
-                    "summary": format!("Update a {name} entity"),
-                    "responses": {
-                        "200": {
-                            "description": format!("Updated {name} entity"),
-                        }
-                    }
-                },
-                "delete": {
-                    "summary": format!("Delete a {name} entity"),
-                    "responses": {
-                        "204": {
-                            "description": "Entity deleted",
-                        }
-                    }
-                }
-            }),
+    let mut out = String::from("// Generated by `schema-forge export typescript`. Do not edit.\n");
+    for schema in sorted {
+        out.push('\n');
+        write_interface(
+            &mut out,
+            schema.name.as_str(),
+            schema.doc.as_deref(),
+            &schema.fields,
         );
     }
+    out
+}
+
+fn write_interface(out: &mut String, name: &str, doc: Option<&str>, fields: &[FieldDefinition]) {
+    let mut nested = Vec::new();
+    write_ts_doc(out, doc, "");
+    out.push_str(&format!("export interface {name} {{\n"));
+    for field in fields.iter().filter(|f| !f.is_hidden()) {
+        let field_name = field.name.as_str();
+        let composite_name = format!("{name}{}", field_name.to_pascal_case());
+        let ts_type = ts_type_for(&field.field_type, &composite_name, &mut nested);
+        let readonly = if !field.is_stored() { "readonly " } else { "" };
+        let optional = if field.is_required() { "" } else { "?" };
+        write_ts_doc(out, field.doc.as_deref(), "  ");
+        out.push_str(&format!("  {readonly}{field_name}{optional}: {ts_type};\n"));
+    }
+    out.push_str("}\n");
+
+    for (name, fields) in nested {
+        out.push('\n');
+        write_interface(out, &name, None, fields);
+    }
+}
+
+fn write_ts_doc(out: &mut String, doc: Option<&str>, indent: &str) {
+    let Some(doc) = doc else {
+        return;
+    };
+    let lines: Vec<&str> = doc.lines().collect();
+    if let [line] = lines.as_slice() {
+        out.push_str(&format!("{indent}/** {line} */\n"));
+        return;
+    }
+    out.push_str(&format!("{indent}/**\n"));
+    for line in lines {
+        out.push_str(format!("{indent} * {line}").trim_end());
+        out.push('\n');
+    }
+    out.push_str(&format!("{indent} */\n"));
+}
 
-    let openapi_spec = serde_json::json!({
-        "openapi": args.spec_version,
-        "info": {
-            "title": "SchemaForge API",
-            "version": "0.1.0",
-            "description": "Auto-generated API from SchemaForge schema definitions",
+/// TypeScript type of a field's JSON value. A composite pushes its fields
+/// onto `nested` to be rendered as the interface `composite_name`.
+fn ts_type_for<'a>(
+    field_type: &'a FieldType,
+    composite_name: &str,
+    nested: &mut Vec<(String, &'a [FieldDefinition])>,
+) -> String {
+    match field_type {
+        FieldType::Text(_)
+        | FieldType::RichText(_)
+        | FieldType::DateTime
+        | FieldType::Duration
+        | FieldType::Uuid
+        | FieldType::Decimal { .. } => "string".to_string(),
+        FieldType::Integer(_) | FieldType::Float(_) => "number".to_string(),
+        FieldType::Boolean => "boolean".to_string(),
+        FieldType::Enum(variants) => variants
+            .iter()
+            .map(|v| serde_json::Value::String(v.clone()).to_string())
+            .collect::<Vec<_>>()
+            .join(" | "),
+        FieldType::Relation {
+            cardinality: Cardinality::One,
+            ..
+        } => "string".to_string(),
+        FieldType::Relation {
+            cardinality: Cardinality::Many,
+            ..
+        } => "string[]".to_string(),
+        FieldType::Array(element, _) => match ts_type_for(element, composite_name, nested) {
+            union if matches!(**element, FieldType::Enum(_)) => format!("({union})[]"),
+            element => format!("{element}[]"),
         },
-        "paths": paths,
-        "components": {
-            "schemas": components_schemas,
+        FieldType::Composite(fields) => {
+            nested.push((composite_name.to_string(), fields));
+            composite_name.to_string()
         }
-    });
+        _ => "unknown".to_string(),
+    }
+}
+
+/// Stream a schema's entities to NDJSON, one entity per line.
+///
+/// Entities are written as they arrive from the backend's batched query
+/// stream, so memory stays bounded by one batch regardless of table size.
+async fn run_entities(
+    args: ExportEntitiesArgs,
+    global: &GlobalOpts,
+    output: &OutputContext,
+) -> Result<(), CliError> {
+    let svc_config = load_svc_config(global)?;
+    let db_params = resolve_db_params(&svc_config)?;
+    let backend = super::connect_backend(&db_params, output).await?;
+
+    let schema = backend
+        .list_schema_metadata()
+        .await?
+        .into_iter()
+        .find(|s| s.name.as_str() == args.schema)
+        .ok_or_else(|| CliError::SchemaNotFound {
+            name: args.schema.clone(),
+        })?;
+
+    let io_path = args
+        .output
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("<stdout>"));
+    let io_err = |source: std::io::Error| CliError::Io {
+        path: io_path.clone(),
+        source,
+    };
+    let mut writer: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(std::io::BufWriter::new(
+            std::fs::File::create(path).map_err(io_err)?,
+        )),
+        None => Box::new(std::io::BufWriter::new(std::io::stdout().lock())),
+    };
+
+    let query = Query::new(schema.id.clone()).with_deleted(args.include_deleted);
+    let mut entities = backend.query_stream(&query);
+    let mut written = 0usize;
+    while let Some(entity) = entities.next().await {
+        let line = serde_json::to_string(&entity_to_response(&entity?, &schema))
+            .map_err(|e| CliError::Other(format!("failed to serialize entity: {e}")))?;
+        writeln!(writer, "{line}").map_err(io_err)?;
+        written += 1;
+    }
+    writer.flush().map_err(io_err)?;
+
+    if let Some(path) = &args.output {
+        output.success(&format!(
+            "Wrote {written} {} entities to {}",
+            schema.name.as_str(),
+            path.display()
+        ));
+    }
+    Ok(())
+}
+
+/// Write the OpenAPI document `GET /forge/openapi.json` would serve for the
+/// schema files.
+async fn run_openapi(
+    args: ExportOpenapiArgs,
+    _global: &GlobalOpts,
+    output: &OutputContext,
+) -> Result<(), CliError> {
+    let schemas = parse_all_schemas(&args.paths)?;
+    let mut openapi_spec = schema_forge_acton::openapi::build_openapi(&schemas, &args.base_path);
+    openapi_spec["openapi"] = serde_json::Value::String(args.spec_version);
 
     if let Some(output_path) = &args.output {
         let json_str = serde_json::to_string_pretty(&openapi_spec)
@@ -139,17 +294,75 @@ async fn run_openapi(
     Ok(())
 }
 
-fn openapi_type_for(field_type: &schema_forge_core::types::FieldType) -> &'static str {
-    use schema_forge_core::types::FieldType;
-    match field_type {
-        FieldType::Text(_) => "string",
-        FieldType::Integer(_) => "integer",
-        FieldType::Float(_) => "number",
-        FieldType::Boolean => "boolean",
-        FieldType::Enum(_) => "string",
-        FieldType::Array(_) => "array",
-        FieldType::Composite(_) => "object",
-        FieldType::Relation { .. } => "string",
-        _ => "string",
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typescript_interfaces_are_sorted_and_nested() {
+        let schemas = schema_forge_dsl::parse(
+            r#"
+schema Contact {
+    /// Display name
+    name: text required
+    status: enum("Lead", "Customer") required
+    score: float
+    company: -> Company
+    tags: text[]
+    levels: enum("Low", "High")[]
+    address: composite {
+        street: text required
+        geo: composite {
+            lat: float
+        }
+    }
+    meta: json
+    password: text @hidden
+}
+
+schema Company {
+    name: text required
+    employees: -> Contact[]
+}
+"#,
+        )
+        .unwrap();
+
+        let ts = render_typescript(&schemas);
+        assert_eq!(
+            ts,
+            r#"// Generated by `schema-forge export typescript`. Do not edit.
+
+export interface Company {
+  name: string;
+  employees?: string[];
+}
+
+export interface Contact {
+  /** Display name */
+  name: string;
+  status: "Lead" | "Customer";
+  score?: number;
+  company?: string;
+  tags?: string[];
+  levels?: ("Low" | "High")[];
+  address?: ContactAddress;
+  meta?: unknown;
+}
+
+export interface ContactAddress {
+  street: string;
+  geo?: ContactAddressGeo;
+}
+
+export interface ContactAddressGeo {
+  lat?: number;
+}
+"#
+        );
+
+        let mut reversed = schemas.clone();
+        reversed.reverse();
+        assert_eq!(render_typescript(&reversed), ts);
     }
 }
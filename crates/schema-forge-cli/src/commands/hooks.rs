@@ -629,11 +629,15 @@ fn field_type_to_proto(
     schema_name: &str,
 ) -> Result<(&'static str, bool), CliError> {
     match ft {
-        FieldType::Text(_) | FieldType::RichText => Ok(("string", false)),
+        FieldType::Text(_) | FieldType::RichText(_) => Ok(("string", false)),
         FieldType::Integer(_) => Ok(("int64", false)),
         FieldType::Float(_) => Ok(("double", false)),
         FieldType::Boolean => Ok(("bool", false)),
         FieldType::DateTime => Ok(("string", false)),
+        FieldType::Duration => Ok(("string", false)),
+        FieldType::Uuid => Ok(("string", false)),
+        // Exact digits as text; a proto double would reintroduce float error.
+        FieldType::Decimal { .. } => Ok(("string", false)),
         FieldType::Enum(_) => Ok(("string", false)),
         FieldType::Json => Ok(("string", false)),
         // Composites are projected as JSON-stringified `optional string` on the
@@ -649,7 +653,7 @@ fn field_type_to_proto(
         FieldType::Relation { cardinality, .. } => {
             Ok(("string", matches!(cardinality, Cardinality::Many)))
         }
-        FieldType::Array(inner) => {
+        FieldType::Array(inner, _) => {
             // One level only: recurse into the inner type and forbid nesting.
             let (inner_type, inner_repeated) = scalar_inner(inner, field_name, schema_name)?;
             if inner_repeated {
@@ -1148,7 +1152,7 @@ mod tests {
             "Task",
             vec![field(
                 "tags",
-                FieldType::Array(Box::new(FieldType::Text(TextConstraints::unconstrained()))),
+                FieldType::array(FieldType::Text(TextConstraints::unconstrained())),
             )],
         );
         let fields = scalar_proto_fields(&s).unwrap();
@@ -1164,9 +1168,7 @@ mod tests {
             "Task",
             vec![field(
                 "scores",
-                FieldType::Array(Box::new(FieldType::Integer(
-                    IntegerConstraints::unconstrained(),
-                ))),
+                FieldType::array(FieldType::Integer(IntegerConstraints::unconstrained())),
             )],
         );
         let fields = scalar_proto_fields(&s).unwrap();
@@ -1180,9 +1182,9 @@ mod tests {
             "Task",
             vec![field(
                 "labels",
-                FieldType::Array(Box::new(FieldType::Enum(
+                FieldType::array(FieldType::Enum(
                     EnumVariants::new(vec!["a".into(), "b".into()]).unwrap(),
-                ))),
+                )),
             )],
         );
         let fields = scalar_proto_fields(&s).unwrap();
@@ -1247,7 +1249,7 @@ mod tests {
             "Thing",
             vec![field(
                 "rows",
-                FieldType::Array(Box::new(FieldType::Composite(vec![inner]))),
+                FieldType::array(FieldType::Composite(vec![inner])),
             )],
         );
         let fields = scalar_proto_fields(&s).unwrap();
@@ -1261,9 +1263,9 @@ mod tests {
             "Bad",
             vec![field(
                 "matrix",
-                FieldType::Array(Box::new(FieldType::Array(Box::new(FieldType::Text(
+                FieldType::array(FieldType::array(FieldType::Text(
                     TextConstraints::unconstrained(),
-                ))))),
+                ))),
             )],
         );
         let err = scalar_proto_fields(&s).unwrap_err();
@@ -1,4 +1,10 @@
-use schema_forge_core::types::SchemaDefinition;
+use std::collections::BTreeMap;
+
+use schema_forge_acton::DynForgeBackend;
+use schema_forge_backend::entity::Entity;
+use schema_forge_core::query::Query;
+use schema_forge_core::types::{DynamicValue, FieldType, SchemaDefinition};
+use serde::Serialize;
 
 use crate::cli::{GlobalOpts, InspectArgs};
 use crate::config::{load_svc_config, resolve_db_params};
@@ -7,10 +13,14 @@ use crate::output::{OutputContext, OutputMode};
 
 /// Run the `inspect` command: show registered schemas and their details.
 pub async fn run(
-    args: InspectArgs,
+    mut args: InspectArgs,
     global: &GlobalOpts,
     output: &OutputContext,
 ) -> Result<(), CliError> {
+    if args.schema.is_none() {
+        args.schema = args.schema_flag.take();
+    }
+
     let svc_config = load_svc_config(global)?;
     let db_params = resolve_db_params(&svc_config)?;
 
@@ -18,109 +28,197 @@ pub async fn run(
 
     let all_schemas = backend.list_schema_metadata().await?;
 
+    if args.stats {
+        let targets: Vec<&SchemaDefinition> = match args.schema {
+            Some(ref name) => vec![all_schemas
+                .iter()
+                .find(|s| s.name.as_str() == name)
+                .ok_or_else(|| CliError::SchemaNotFound { name: name.clone() })?],
+            None => all_schemas.iter().collect(),
+        };
+        let mut stats = Vec::with_capacity(targets.len());
+        for schema in targets {
+            stats.push(collect_schema_stats(backend.as_ref(), schema).await?);
+        }
+        render_schema_stats(&stats, output);
+        return Ok(());
+    }
+
     if let Some(ref name) = args.schema {
         // Show specific schema
         let schema = all_schemas
             .iter()
             .find(|s| s.name.as_str() == name)
             .ok_or_else(|| CliError::SchemaNotFound { name: name.clone() })?;
+        let entities = if args.counts {
+            Some(backend.count(&Query::new(schema.id.clone())).await?)
+        } else {
+            None
+        };
 
-        render_schema_detail(schema, output);
+        render_schema_detail(schema, entities, output);
     } else {
         // Show all schemas
-        render_schema_list(&all_schemas, output);
+        let counts = if args.counts {
+            let mut counts = Vec::with_capacity(all_schemas.len());
+            for schema in &all_schemas {
+                counts.push(backend.count(&Query::new(schema.id.clone())).await?);
+            }
+            Some(counts)
+        } else {
+            None
+        };
+        render_schema_list(&all_schemas, counts.as_deref(), output);
     }
 
     Ok(())
 }
 
-fn render_schema_list(schemas: &[SchemaDefinition], output: &OutputContext) {
+/// One row of the `inspect` schema listing.
+#[derive(Debug, Clone, PartialEq)]
+struct SchemaSummary<'a> {
+    name: &'a str,
+    version: u32,
+    fields: usize,
+    relations: usize,
+    indexed: usize,
+    /// Annotation kinds other than `@version`, in declaration order.
+    annotations: Vec<&'static str>,
+    entities: Option<usize>,
+}
+
+fn summarize_schema(schema: &SchemaDefinition, entities: Option<usize>) -> SchemaSummary<'_> {
+    let mut annotations: Vec<&'static str> = Vec::new();
+    for kind in schema.annotations.iter().map(|a| a.kind()) {
+        if kind != "version" && !annotations.contains(&kind) {
+            annotations.push(kind);
+        }
+    }
+    SchemaSummary {
+        name: schema.name.as_str(),
+        version: schema_version(schema),
+        fields: schema.fields.len(),
+        relations: schema
+            .fields
+            .iter()
+            .filter(|f| matches!(f.field_type, FieldType::Relation { .. }))
+            .count(),
+        indexed: schema.fields.iter().filter(|f| f.is_indexed()).count(),
+        annotations,
+        entities,
+    }
+}
+
+/// The declared `@version`, or 1 for schemas that never declared one.
+fn schema_version(schema: &SchemaDefinition) -> u32 {
+    schema.version().map_or(1, |v| v.get())
+}
+
+fn render_schema_list(
+    schemas: &[SchemaDefinition],
+    counts: Option<&[usize]>,
+    output: &OutputContext,
+) {
+    let summaries: Vec<SchemaSummary<'_>> = schemas
+        .iter()
+        .enumerate()
+        .map(|(i, schema)| summarize_schema(schema, counts.map(|c| c[i])))
+        .collect();
     match output.mode {
         OutputMode::Human => {
             if schemas.is_empty() {
                 output.status("No schemas registered.");
                 return;
             }
-            println!(
-                "{:<20} {:<8} {:<8} {:<10} {:<8}",
-                "Schema", "Version", "Fields", "Relations", "Indexed"
-            );
-            println!(
-                "{:<20} {:<8} {:<8} {:<10} {:<8}",
-                "------", "-------", "------", "---------", "-------"
-            );
-            for schema in schemas {
-                let relations = schema
-                    .fields
-                    .iter()
-                    .filter(|f| {
-                        matches!(
-                            f.field_type,
-                            schema_forge_core::types::FieldType::Relation { .. }
-                        )
-                    })
-                    .count();
-                let indexed = schema.fields.iter().filter(|f| f.is_indexed()).count();
-                println!(
-                    "{:<20} {:<8} {:<8} {:<10} {:<8}",
-                    schema.name.as_str(),
-                    1, // version placeholder
-                    schema.fields.len(),
-                    relations,
-                    indexed
-                );
+            let mut headers = vec![
+                "Schema",
+                "Version",
+                "Fields",
+                "Relations",
+                "Indexed",
+                "Annotations",
+            ];
+            if counts.is_some() {
+                headers.push("Entities");
             }
+            let rows: Vec<Vec<String>> = summaries
+                .iter()
+                .map(|s| {
+                    let mut row = vec![
+                        s.name.to_string(),
+                        s.version.to_string(),
+                        s.fields.to_string(),
+                        s.relations.to_string(),
+                        s.indexed.to_string(),
+                        if s.annotations.is_empty() {
+                            "-".to_string()
+                        } else {
+                            s.annotations.join(", ")
+                        },
+                    ];
+                    if let Some(entities) = s.entities {
+                        row.push(entities.to_string());
+                    }
+                    row
+                })
+                .collect();
+            print!("{}", format_table(&headers, &rows));
         }
         OutputMode::Json => {
-            let json_schemas: Vec<serde_json::Value> = schemas.iter().map(schema_to_json).collect();
-            let json = serde_json::json!({ "schemas": json_schemas });
-            output.print_json(&json);
+            output.print_json(&schema_list_json(schemas, counts));
         }
         OutputMode::Plain => {
-            for schema in schemas {
-                let relations = schema
-                    .fields
-                    .iter()
-                    .filter(|f| {
-                        matches!(
-                            f.field_type,
-                            schema_forge_core::types::FieldType::Relation { .. }
-                        )
-                    })
-                    .count();
-                let indexed = schema.fields.iter().filter(|f| f.is_indexed()).count();
-                println!(
-                    "{}\t1\t{}\t{}\t{}",
-                    schema.name.as_str(),
-                    schema.fields.len(),
-                    relations,
-                    indexed
+            for s in &summaries {
+                let mut line = format!(
+                    "{}\t{}\t{}\t{}\t{}\t{}",
+                    s.name,
+                    s.version,
+                    s.fields,
+                    s.relations,
+                    s.indexed,
+                    s.annotations.join(",")
                 );
+                if let Some(entities) = s.entities {
+                    line.push_str(&format!("\t{entities}"));
+                }
+                println!("{line}");
             }
         }
     }
 }
 
-fn render_schema_detail(schema: &SchemaDefinition, output: &OutputContext) {
+fn render_schema_detail(
+    schema: &SchemaDefinition,
+    entities: Option<usize>,
+    output: &OutputContext,
+) {
     match output.mode {
         OutputMode::Human => {
-            println!("Schema: {} (version 1)", schema.name.as_str());
+            println!(
+                "Schema: {} (version {})",
+                schema.name.as_str(),
+                schema_version(schema)
+            );
+            if let Some(entities) = entities {
+                println!("Entities: {entities}");
+            }
             println!();
             println!("Fields:");
-            for field in &schema.fields {
-                let modifiers: Vec<&str> =
-                    field.modifiers.iter().map(|m| modifier_label(m)).collect();
-                let mod_str = if modifiers.is_empty() {
-                    String::new()
-                } else {
-                    format!("  {}", modifiers.join(" "))
-                };
-                println!(
-                    "  {:<16} {:<24}{}",
-                    field.name.as_str(),
-                    field.field_type.to_string(),
-                    mod_str
-                );
+            let rows: Vec<Vec<String>> = schema
+                .fields
+                .iter()
+                .map(|field| {
+                    let modifiers: Vec<&str> =
+                        field.modifiers.iter().map(|m| modifier_label(m)).collect();
+                    vec![
+                        field.name.as_str().to_string(),
+                        field.field_type.to_string(),
+                        modifiers.join(" "),
+                    ]
+                })
+                .collect();
+            for line in format_table(&["Field", "Type", "Modifiers"], &rows).lines() {
+                println!("  {line}");
             }
             if schema.annotations.is_empty() {
                 println!();
@@ -134,7 +232,7 @@ fn render_schema_detail(schema: &SchemaDefinition, output: &OutputContext) {
             }
         }
         OutputMode::Json => {
-            let json = schema_to_json(schema);
+            let json = schema_to_json(schema, entities);
             output.print_json(&json);
         }
         OutputMode::Plain => {
@@ -152,17 +250,209 @@ fn render_schema_detail(schema: &SchemaDefinition, output: &OutputContext) {
     }
 }
 
+/// Lay out `rows` under `headers` as left-aligned columns sized to their
+/// widest cell, with a dashed rule below the header.
+fn format_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: Vec<String>| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect();
+        format!("{}\n", padded.join("  ").trim_end())
+    };
+
+    let mut out = line(headers.iter().map(|h| h.to_string()).collect());
+    out.push_str(&line(
+        headers
+            .iter()
+            .map(|h| "-".repeat(h.chars().count()))
+            .collect(),
+    ));
+    for row in rows {
+        out.push_str(&line(row.clone()));
+    }
+    out
+}
+
+// ---------------------------------------------------------------------------
+// --stats
+// ---------------------------------------------------------------------------
+
+/// Entities fetched per page while scanning a schema for `--stats`.
+const STATS_PAGE_SIZE: usize = 500;
+
+/// Entity count and per-field population for one schema.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SchemaStats {
+    pub schema: String,
+    pub entities: usize,
+    pub fields: Vec<FieldStats>,
+}
+
+/// Null vs populated counts for one field. `variants` is filled for enum
+/// fields only, keyed by variant name, and lists every declared variant
+/// (including those with a zero count).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldStats {
+    pub field: String,
+    pub populated: usize,
+    pub null: usize,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub variants: BTreeMap<String, usize>,
+}
+
+/// Count the schema's entities and scan them page by page to tally field
+/// population. Derived fields are skipped: they are computed at read time
+/// and never stored.
+async fn collect_schema_stats(
+    backend: &dyn DynForgeBackend,
+    schema: &SchemaDefinition,
+) -> Result<SchemaStats, CliError> {
+    let mut stats = empty_stats(schema, backend.count(&Query::new(schema.id.clone())).await?);
+
+    let mut offset = 0;
+    loop {
+        let page = Query::new(schema.id.clone())
+            .with_limit(STATS_PAGE_SIZE)
+            .with_offset(offset);
+        let result = backend.query(&page).await?;
+        let fetched = result.entities.len();
+        tally_entities(schema, &mut stats, &result.entities);
+        if fetched < STATS_PAGE_SIZE {
+            break;
+        }
+        offset += fetched;
+    }
+
+    Ok(stats)
+}
+
+fn empty_stats(schema: &SchemaDefinition, entities: usize) -> SchemaStats {
+    let fields = schema
+        .fields
+        .iter()
+        .filter(|f| f.is_stored())
+        .map(|f| FieldStats {
+            field: f.name.as_str().to_string(),
+            populated: 0,
+            null: 0,
+            variants: match &f.field_type {
+                FieldType::Enum(variants) => variants.iter().map(|v| (v.clone(), 0)).collect(),
+                _ => BTreeMap::new(),
+            },
+        })
+        .collect();
+    SchemaStats {
+        schema: schema.name.as_str().to_string(),
+        entities,
+        fields,
+    }
+}
+
+/// Add one page of entities to the running tallies. A missing key counts
+/// as null, matching how the backends surface unset fields.
+fn tally_entities(schema: &SchemaDefinition, stats: &mut SchemaStats, entities: &[Entity]) {
+    let is_enum: Vec<bool> = schema
+        .fields
+        .iter()
+        .filter(|f| f.is_stored())
+        .map(|f| matches!(f.field_type, FieldType::Enum(_)))
+        .collect();
+    for entity in entities {
+        for (field, is_enum) in stats.fields.iter_mut().zip(&is_enum) {
+            match entity.fields.get(&field.field) {
+                None | Some(DynamicValue::Null) => field.null += 1,
+                Some(value) => {
+                    field.populated += 1;
+                    if *is_enum {
+                        if let DynamicValue::Enum(v) | DynamicValue::Text(v) = value {
+                            *field.variants.entry(v.clone()).or_insert(0) += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_schema_stats(stats: &[SchemaStats], output: &OutputContext) {
+    match output.mode {
+        OutputMode::Human => {
+            if stats.is_empty() {
+                output.status("No schemas registered.");
+                return;
+            }
+            for (i, schema) in stats.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                println!("Schema: {} ({} entities)", schema.schema, schema.entities);
+                println!("  {:<20} {:>10} {:>10}", "Field", "Populated", "Null");
+                println!("  {:<20} {:>10} {:>10}", "-----", "---------", "----");
+                for field in &schema.fields {
+                    println!(
+                        "  {:<20} {:>10} {:>10}",
+                        field.field, field.populated, field.null
+                    );
+                    for (variant, count) in &field.variants {
+                        println!("    {variant:<18} {count:>10}");
+                    }
+                }
+            }
+        }
+        OutputMode::Json => {
+            let json = serde_json::json!({ "stats": stats });
+            output.print_json(&json);
+        }
+        OutputMode::Plain => {
+            for schema in stats {
+                for field in &schema.fields {
+                    println!(
+                        "{}\t{}\t{}\t{}\t{}",
+                        schema.schema, schema.entities, field.field, field.populated, field.null
+                    );
+                    for (variant, count) in &field.variants {
+                        println!(
+                            "{}\t{}\t{}={}\t{}",
+                            schema.schema, schema.entities, field.field, variant, count
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Map a field modifier to a human-readable label.
 fn modifier_label(m: &schema_forge_core::types::FieldModifier) -> &'static str {
     match m {
         schema_forge_core::types::FieldModifier::Required => "required",
         schema_forge_core::types::FieldModifier::Indexed => "indexed",
+        schema_forge_core::types::FieldModifier::Unique => "unique",
         schema_forge_core::types::FieldModifier::Default { .. } => "default",
         _ => "unknown",
     }
 }
 
-fn schema_to_json(schema: &SchemaDefinition) -> serde_json::Value {
+fn schema_list_json(schemas: &[SchemaDefinition], counts: Option<&[usize]>) -> serde_json::Value {
+    let json_schemas: Vec<serde_json::Value> = schemas
+        .iter()
+        .enumerate()
+        .map(|(i, schema)| schema_to_json(schema, counts.map(|c| c[i])))
+        .collect();
+    serde_json::json!({ "schemas": json_schemas })
+}
+
+fn schema_to_json(schema: &SchemaDefinition, entities: Option<usize>) -> serde_json::Value {
+    let summary = summarize_schema(schema, entities);
     let fields: Vec<serde_json::Value> = schema
         .fields
         .iter()
@@ -176,9 +466,194 @@ fn schema_to_json(schema: &SchemaDefinition) -> serde_json::Value {
         })
         .collect();
 
-    serde_json::json!({
-        "name": schema.name.as_str(),
-        "version": 1,
+    let mut json = serde_json::json!({
+        "name": summary.name,
+        "version": summary.version,
+        "field_count": summary.fields,
+        "relations": summary.relations,
+        "indexed": summary.indexed,
+        "annotations": summary.annotations,
         "fields": fields,
-    })
+    });
+    if let Some(entities) = summary.entities {
+        json["entities"] = serde_json::json!(entities);
+    }
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use schema_forge_core::types::{
+        Annotation, EnumVariants, FieldDefinition, FieldName, SchemaId, SchemaName, SchemaVersion,
+        TextConstraints,
+    };
+
+    fn ticket_schema() -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Ticket").unwrap(),
+            vec![
+                FieldDefinition::new(
+                    FieldName::new("title").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                ),
+                FieldDefinition::new(
+                    FieldName::new("status").unwrap(),
+                    FieldType::Enum(
+                        EnumVariants::new(vec!["Open".into(), "Closed".into(), "Stale".into()])
+                            .unwrap(),
+                    ),
+                ),
+            ],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    fn ticket(title: Option<&str>, status: Option<&str>) -> Entity {
+        let mut fields = BTreeMap::new();
+        if let Some(t) = title {
+            fields.insert("title".to_string(), DynamicValue::Text(t.into()));
+        }
+        if let Some(s) = status {
+            fields.insert("status".to_string(), DynamicValue::Enum(s.into()));
+        }
+        Entity::new(SchemaName::new("Ticket").unwrap(), fields)
+    }
+
+    #[test]
+    fn schema_list_json_reports_schemas_and_field_counts() {
+        let ticket = ticket_schema();
+        let note = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Note").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("body").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            )],
+            vec![
+                Annotation::Version {
+                    version: SchemaVersion::new(3).unwrap(),
+                },
+                Annotation::Display {
+                    field: FieldName::new("body").unwrap(),
+                },
+            ],
+        )
+        .unwrap();
+
+        let json = schema_list_json(&[ticket, note], Some(&[4, 0]));
+        let schemas = json["schemas"].as_array().unwrap();
+        assert_eq!(schemas.len(), 2);
+        assert_eq!(schemas[0]["name"], "Ticket");
+        assert_eq!(schemas[0]["field_count"], 2);
+        assert_eq!(schemas[0]["version"], 1);
+        assert_eq!(schemas[0]["entities"], 4);
+        assert_eq!(schemas[0]["annotations"], serde_json::json!([]));
+        assert_eq!(schemas[1]["name"], "Note");
+        assert_eq!(schemas[1]["field_count"], 1);
+        assert_eq!(schemas[1]["version"], 3);
+        assert_eq!(schemas[1]["annotations"], serde_json::json!(["display"]));
+        assert_eq!(schemas[1]["fields"][0]["name"], "body");
+
+        let uncounted = schema_list_json(&[ticket_schema()], None);
+        assert!(uncounted["schemas"][0].get("entities").is_none());
+    }
+
+    #[test]
+    fn format_table_aligns_columns() {
+        let table = format_table(
+            &["Schema", "Fields"],
+            &[
+                vec!["Ticket".into(), "2".into()],
+                vec!["SupportCase".into(), "10".into()],
+            ],
+        );
+        assert_eq!(
+            table,
+            "Schema       Fields\n\
+             ------       ------\n\
+             Ticket       2\n\
+             SupportCase  10\n"
+        );
+    }
+
+    #[test]
+    fn tally_counts_nulls_and_variants() {
+        let schema = ticket_schema();
+        let mut stats = empty_stats(&schema, 3);
+        tally_entities(
+            &schema,
+            &mut stats,
+            &[
+                ticket(Some("a"), Some("Open")),
+                ticket(None, Some("Open")),
+                ticket(Some("c"), None),
+            ],
+        );
+
+        assert_eq!(stats.fields[0].field, "title");
+        assert_eq!((stats.fields[0].populated, stats.fields[0].null), (2, 1));
+        assert!(stats.fields[0].variants.is_empty());
+
+        let status = &stats.fields[1];
+        assert_eq!((status.populated, status.null), (2, 1));
+        assert_eq!(
+            status.variants,
+            BTreeMap::from([
+                ("Closed".to_string(), 0),
+                ("Open".to_string(), 2),
+                ("Stale".to_string(), 0),
+            ])
+        );
+    }
+
+    #[test]
+    fn stats_json_omits_variants_for_non_enum_fields() {
+        let schema = ticket_schema();
+        let stats = empty_stats(&schema, 0);
+        let json = serde_json::to_value(&stats).unwrap();
+        assert!(json["fields"][0].get("variants").is_none());
+        assert_eq!(json["fields"][1]["variants"]["Open"], 0);
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[tokio::test]
+    async fn collect_stats_against_in_memory_backend() {
+        use schema_forge_core::migration::DiffEngine;
+
+        let backend: std::sync::Arc<dyn DynForgeBackend> = std::sync::Arc::new(
+            schema_forge_surrealdb::SurrealBackend::connect_memory("test", "stats")
+                .await
+                .unwrap(),
+        );
+        let schema = ticket_schema();
+        backend
+            .apply_migration(&schema.name, &DiffEngine::create_new(&schema).steps)
+            .await
+            .unwrap();
+
+        let fixtures = [
+            (Some("a"), Some("Open")),
+            (Some("b"), Some("Open")),
+            (Some("c"), Some("Closed")),
+            (None, Some("Open")),
+            (Some("e"), None),
+        ];
+        for (title, status) in fixtures {
+            backend.create(&ticket(title, status)).await.unwrap();
+        }
+
+        let stats = collect_schema_stats(backend.as_ref(), &schema)
+            .await
+            .unwrap();
+        assert_eq!(stats.entities, 5);
+        assert_eq!((stats.fields[0].populated, stats.fields[0].null), (4, 1));
+        let status = &stats.fields[1];
+        assert_eq!((status.populated, status.null), (4, 1));
+        assert_eq!(status.variants["Open"], 3);
+        assert_eq!(status.variants["Closed"], 1);
+        assert_eq!(status.variants["Stale"], 0);
+    }
 }
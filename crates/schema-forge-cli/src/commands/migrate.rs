@@ -1,5 +1,12 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
 use console::Term;
-use schema_forge_core::migration::DiffEngine;
+use schema_forge_acton::DynForgeBackend;
+use schema_forge_core::migration::{DiffEngine, MigrationPlan, MigrationSafety, ValueTransform};
+use schema_forge_core::types::{FieldName, SchemaDefinition};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use crate::cli::{GlobalOpts, MigrateArgs};
 use crate::commands::parse::parse_all_schemas;
@@ -7,13 +14,57 @@ use crate::config::{load_svc_config, resolve_db_params};
 use crate::error::CliError;
 use crate::output::{OutputContext, OutputMode};
 
+/// Format version of files written by `migrate --plan-only`.
+const PLAN_FILE_VERSION: u32 = 1;
+
+/// Value transforms from `--transforms`, keyed by schema and field name:
+///
+/// ```json
+/// { "Deal": { "status": { "transform": "MapText", "mapping": { "open": "Open" }, "default": "Won" } } }
+/// ```
+type TransformOverrides = BTreeMap<String, BTreeMap<FieldName, ValueTransform>>;
+
+/// A reviewed migration plan persisted by `migrate --plan-only` and applied
+/// later by `migrate --from-plan`.
+#[derive(Debug, Serialize, Deserialize)]
+struct PlanFile {
+    version: u32,
+    migrations: Vec<PlannedMigration>,
+}
+
+/// One schema's migration plan together with the stored schema it was
+/// computed against.
+#[derive(Debug, Serialize, Deserialize)]
+struct PlannedMigration {
+    /// Hash of the stored schema the plan was diffed against, or `None` if
+    /// the schema did not exist yet.
+    base_hash: Option<String>,
+    /// The definition the plan migrates to.
+    schema: SchemaDefinition,
+    plan: MigrationPlan,
+}
+
 /// Run the `migrate` command: plan and optionally execute schema migrations.
 pub async fn run(
     args: MigrateArgs,
     global: &GlobalOpts,
     output: &OutputContext,
 ) -> Result<(), CliError> {
+    if let Some(path) = &args.from_plan {
+        return run_from_plan(path, args.force, global, output).await;
+    }
+
     let schemas = parse_all_schemas(&args.paths)?;
+    let overrides = match &args.transforms {
+        Some(path) => read_transform_file(path)?,
+        None => TransformOverrides::new(),
+    };
+    if let Some(name) = overrides
+        .keys()
+        .find(|name| !schemas.iter().any(|s| s.name.as_str() == name.as_str()))
+    {
+        return Err(CliError::SchemaNotFound { name: name.clone() });
+    }
 
     let svc_config = load_svc_config(global)?;
     let db_params = resolve_db_params(&svc_config)?;
@@ -32,23 +83,45 @@ pub async fn run(
             }
         }
 
-        let existing = backend.load_schema_metadata(&schema.name).await?;
-        let plan = if let Some(old) = existing {
-            DiffEngine::diff(&old, schema)
-        } else {
-            DiffEngine::create_new(schema)
-        };
+        let mut planned = plan_schema(backend.as_ref(), schema).await?;
+        if let Some(fields) = overrides.get(schema.name.as_str()) {
+            apply_transform_overrides(&mut planned.plan, fields)?;
+        }
 
-        if plan.is_empty() {
+        if planned.plan.is_empty() {
             if output.mode == OutputMode::Human {
                 output.status(&format!("{} (no changes)", schema.name.as_str()));
             }
         } else {
-            total_steps += plan.steps.len();
+            total_steps += planned.plan.steps.len();
             schemas_affected += 1;
         }
 
-        plans.push((schema, plan));
+        plans.push(planned);
+    }
+
+    if args.plan_only {
+        let path = args
+            .out
+            .as_deref()
+            .ok_or_else(|| CliError::Other("--plan-only requires --out".into()))?;
+        let file = PlanFile {
+            version: PLAN_FILE_VERSION,
+            migrations: plans.into_iter().filter(|m| !m.plan.is_empty()).collect(),
+        };
+        write_plan_file(path, &file)?;
+        match output.mode {
+            OutputMode::Json => output.print_json(&serde_json::json!({
+                "out": path.display().to_string(),
+                "total_steps": total_steps,
+                "schemas_affected": schemas_affected,
+            })),
+            _ => output.success(&format!(
+                "Wrote plan with {total_steps} steps across {schemas_affected} schemas to {}.",
+                path.display()
+            )),
+        }
+        return Ok(());
     }
 
     // Render plan
@@ -56,20 +129,10 @@ pub async fn run(
         OutputMode::Human => {
             println!("Migration plan for {} schemas:", plans.len());
             println!();
-            for (schema, plan) in &plans {
-                if plan.is_empty() {
-                    continue;
-                }
-                println!(
-                    "{} ({} steps, {})",
-                    schema.name.as_str(),
-                    plan.steps.len(),
-                    plan.overall_safety()
-                );
-                for (i, step) in plan.steps.iter().enumerate() {
-                    println!("  {}. {} [{}]", i + 1, step, step.safety());
+            for PlannedMigration { plan, .. } in &plans {
+                if !plan.is_empty() {
+                    print_plan(plan, output);
                 }
-                println!();
             }
             println!("Total: {total_steps} steps across {schemas_affected} schemas.");
             if !args.execute {
@@ -79,24 +142,8 @@ pub async fn run(
         OutputMode::Json => {
             let json_plans: Vec<serde_json::Value> = plans
                 .iter()
-                .filter(|(_, p)| !p.is_empty())
-                .map(|(schema, plan)| {
-                    let steps: Vec<serde_json::Value> = plan
-                        .steps
-                        .iter()
-                        .map(|s| {
-                            serde_json::json!({
-                                "description": s.to_string(),
-                                "safety": s.safety().to_string(),
-                            })
-                        })
-                        .collect();
-                    serde_json::json!({
-                        "schema": schema.name.as_str(),
-                        "safety": plan.overall_safety().to_string(),
-                        "steps": steps,
-                    })
-                })
+                .filter(|m| !m.plan.is_empty())
+                .map(|PlannedMigration { plan, .. }| plan_json(plan))
                 .collect();
             let json = serde_json::json!({
                 "plans": json_plans,
@@ -106,7 +153,7 @@ pub async fn run(
             output.print_json(&json);
         }
         OutputMode::Plain => {
-            for (schema, plan) in &plans {
+            for PlannedMigration { schema, plan, .. } in &plans {
                 if plan.is_empty() {
                     continue;
                 }
@@ -119,35 +166,11 @@ pub async fn run(
 
     // Execute if requested
     if args.execute {
-        for (schema, plan) in &plans {
-            if plan.is_empty() {
+        for planned in &plans {
+            if planned.plan.is_empty() || !confirm_destructive(planned, args.force, output)? {
                 continue;
             }
-
-            // Safety check for destructive changes
-            if plan.has_destructive_steps() && !args.force {
-                let is_tty = Term::stderr().is_term();
-                if !is_tty {
-                    return Err(CliError::RequiresForce);
-                }
-
-                let confirm = dialoguer::Confirm::new()
-                    .with_prompt(format!(
-                        "Apply destructive migration to {}?",
-                        schema.name.as_str()
-                    ))
-                    .default(false)
-                    .interact()
-                    .map_err(|_| CliError::Cancelled)?;
-
-                if !confirm {
-                    output.status(&format!("  Skipped {}", schema.name.as_str()));
-                    continue;
-                }
-            }
-
-            backend.apply_migration(&schema.name, &plan.steps).await?;
-            backend.store_schema_metadata(schema).await?;
+            execute_planned(backend.as_ref(), planned).await?;
         }
 
         output.success(&format!(
@@ -157,3 +180,395 @@ pub async fn run(
 
     Ok(())
 }
+
+/// Print one schema's plan as a header line plus numbered steps, each
+/// tagged with its safety level.
+pub(crate) fn print_plan(plan: &MigrationPlan, output: &OutputContext) {
+    println!(
+        "{} ({} steps, {})",
+        plan.schema_name.as_str(),
+        plan.steps.len(),
+        safety_label(plan.overall_safety(), output)
+    );
+    for (i, step) in plan.steps.iter().enumerate() {
+        println!(
+            "  {}. {} [{}]",
+            i + 1,
+            step,
+            safety_label(step.safety(), output)
+        );
+    }
+    println!();
+}
+
+/// JSON form of one schema's plan for `--format json` output.
+pub(crate) fn plan_json(plan: &MigrationPlan) -> serde_json::Value {
+    let steps: Vec<serde_json::Value> = plan
+        .steps
+        .iter()
+        .map(|s| {
+            serde_json::json!({
+                "description": s.to_string(),
+                "safety": s.safety().to_string(),
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "schema": plan.schema_name.as_str(),
+        "safety": plan.overall_safety().to_string(),
+        "steps": steps,
+    })
+}
+
+/// A safety level, colored green, yellow, or red when color is enabled.
+pub(crate) fn safety_label(safety: MigrationSafety, output: &OutputContext) -> String {
+    if !output.use_color {
+        return safety.to_string();
+    }
+    let style = match safety {
+        MigrationSafety::Safe => console::Style::new().green(),
+        MigrationSafety::RequiresConfirmation => console::Style::new().yellow(),
+        _ => console::Style::new().red().bold(),
+    };
+    style.apply_to(safety).to_string()
+}
+
+/// Apply a plan file written by `--plan-only`.
+///
+/// Every entry is checked for drift before any step runs, so a stale plan
+/// is rejected as a whole rather than half-applied.
+async fn run_from_plan(
+    path: &Path,
+    force: bool,
+    global: &GlobalOpts,
+    output: &OutputContext,
+) -> Result<(), CliError> {
+    let file = read_plan_file(path)?;
+
+    let svc_config = load_svc_config(global)?;
+    let db_params = resolve_db_params(&svc_config)?;
+
+    let backend = super::connect_backend(&db_params, output).await?;
+
+    verify_plan_file(backend.as_ref(), &file).await?;
+
+    let mut total_steps = 0usize;
+    let mut schemas_affected = 0usize;
+    for planned in &file.migrations {
+        if !confirm_destructive(planned, force, output)? {
+            continue;
+        }
+        execute_planned(backend.as_ref(), planned).await?;
+        total_steps += planned.plan.steps.len();
+        schemas_affected += 1;
+    }
+
+    output.success(&format!(
+        "Executed {total_steps} migration steps across {schemas_affected} schemas from {}.",
+        path.display()
+    ));
+    Ok(())
+}
+
+/// Diff `schema` against its stored version (or plan it as new).
+async fn plan_schema(
+    backend: &dyn DynForgeBackend,
+    schema: &SchemaDefinition,
+) -> Result<PlannedMigration, CliError> {
+    let existing = backend.load_schema_metadata(&schema.name).await?;
+    let plan = match &existing {
+        Some(old) => DiffEngine::diff(old, schema),
+        None => DiffEngine::create_new(schema),
+    };
+    Ok(PlannedMigration {
+        base_hash: existing.as_ref().map(schema_hash),
+        schema: schema.clone(),
+        plan,
+    })
+}
+
+/// Replace the inferred transforms of `plan`'s type changes with `fields`.
+fn apply_transform_overrides(
+    plan: &mut MigrationPlan,
+    fields: &BTreeMap<FieldName, ValueTransform>,
+) -> Result<(), CliError> {
+    for (field, transform) in fields {
+        plan.override_transform(field, transform.clone())?;
+    }
+    Ok(())
+}
+
+/// Reject `file` if any stored schema no longer matches the base its plan
+/// was generated against.
+async fn verify_plan_file(backend: &dyn DynForgeBackend, file: &PlanFile) -> Result<(), CliError> {
+    for planned in &file.migrations {
+        let current = backend.load_schema_metadata(&planned.schema.name).await?;
+        if current.as_ref().map(schema_hash) != planned.base_hash {
+            return Err(CliError::PlanDrift {
+                schema: planned.schema.name.as_str().to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Ask before running destructive steps; returns `false` if the user
+/// declined. Non-interactive sessions require `force`.
+fn confirm_destructive(
+    planned: &PlannedMigration,
+    force: bool,
+    output: &OutputContext,
+) -> Result<bool, CliError> {
+    if !planned.plan.has_destructive_steps() || force {
+        return Ok(true);
+    }
+    if !Term::stderr().is_term() {
+        return Err(CliError::RequiresForce);
+    }
+
+    let name = planned.schema.name.as_str();
+    let confirm = dialoguer::Confirm::new()
+        .with_prompt(format!("Apply destructive migration to {name}?"))
+        .default(false)
+        .interact()
+        .map_err(|_| CliError::Cancelled)?;
+    if !confirm {
+        output.status(&format!("  Skipped {name}"));
+    }
+    Ok(confirm)
+}
+
+async fn execute_planned(
+    backend: &dyn DynForgeBackend,
+    planned: &PlannedMigration,
+) -> Result<(), CliError> {
+    backend
+        .apply_migration(&planned.schema.name, &planned.plan.steps)
+        .await?;
+    backend.store_schema_metadata(&planned.schema).await?;
+    Ok(())
+}
+
+/// SHA-256 of a schema definition's JSON serialization.
+fn schema_hash(schema: &SchemaDefinition) -> String {
+    let json = serde_json::to_vec(schema).expect("schema definitions always serialize");
+    hex::encode(Sha256::digest(&json))
+}
+
+fn write_plan_file(path: &Path, file: &PlanFile) -> Result<(), CliError> {
+    let json = serde_json::to_string_pretty(file)
+        .map_err(|e| CliError::Other(format!("failed to serialize plan: {e}")))?;
+    std::fs::write(path, json).map_err(|e| CliError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+fn read_plan_file(path: &Path) -> Result<PlanFile, CliError> {
+    let json = std::fs::read_to_string(path).map_err(|e| CliError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    let file: PlanFile = serde_json::from_str(&json)
+        .map_err(|e| CliError::Other(format!("invalid plan file {}: {e}", path.display())))?;
+    if file.version != PLAN_FILE_VERSION {
+        return Err(CliError::Other(format!(
+            "unsupported plan file version {} in {} (expected {PLAN_FILE_VERSION})",
+            file.version,
+            path.display()
+        )));
+    }
+    Ok(file)
+}
+
+fn read_transform_file(path: &Path) -> Result<TransformOverrides, CliError> {
+    let json = std::fs::read_to_string(path).map_err(|e| CliError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    serde_json::from_str(&json)
+        .map_err(|e| CliError::Other(format!("invalid transform file {}: {e}", path.display())))
+}
+
+#[cfg(all(test, feature = "surrealdb"))]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use schema_forge_core::types::{
+        FieldDefinition, FieldName, FieldType, SchemaId, SchemaName, TextConstraints,
+    };
+
+    fn contact(fields: &[&str]) -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            fields
+                .iter()
+                .map(|f| {
+                    FieldDefinition::new(
+                        FieldName::new(*f).unwrap(),
+                        FieldType::Text(TextConstraints::unconstrained()),
+                    )
+                })
+                .collect(),
+            vec![],
+        )
+        .unwrap()
+    }
+
+    async fn memory_backend() -> Arc<dyn DynForgeBackend> {
+        Arc::new(
+            schema_forge_surrealdb::SurrealBackend::connect_memory("test", "migrate")
+                .await
+                .unwrap(),
+        )
+    }
+
+    async fn write_plan(
+        backend: &dyn DynForgeBackend,
+        schema: &SchemaDefinition,
+        dir: &Path,
+    ) -> std::path::PathBuf {
+        let planned = plan_schema(backend, schema).await.unwrap();
+        let path = dir.join("plan.json");
+        write_plan_file(
+            &path,
+            &PlanFile {
+                version: PLAN_FILE_VERSION,
+                migrations: vec![planned],
+            },
+        )
+        .unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn plan_file_generate_then_apply() {
+        let backend = memory_backend().await;
+        let dir = tempfile::tempdir().unwrap();
+        let schema = contact(&["name"]);
+        execute_planned(
+            backend.as_ref(),
+            &plan_schema(backend.as_ref(), &schema).await.unwrap(),
+        )
+        .await
+        .unwrap();
+
+        let updated = contact(&["name", "email"]);
+        let path = write_plan(backend.as_ref(), &updated, dir.path()).await;
+
+        let file = read_plan_file(&path).unwrap();
+        assert_eq!(file.migrations.len(), 1);
+        assert_eq!(file.migrations[0].plan.steps.len(), 1);
+        verify_plan_file(backend.as_ref(), &file).await.unwrap();
+        execute_planned(backend.as_ref(), &file.migrations[0])
+            .await
+            .unwrap();
+
+        let stored = backend
+            .load_schema_metadata(&updated.name)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored, updated);
+        let replanned = plan_schema(backend.as_ref(), &updated).await.unwrap();
+        assert!(replanned.plan.is_empty());
+    }
+
+    #[tokio::test]
+    async fn plan_file_rejected_after_base_drift() {
+        let backend = memory_backend().await;
+        let dir = tempfile::tempdir().unwrap();
+        let schema = contact(&["name"]);
+        execute_planned(
+            backend.as_ref(),
+            &plan_schema(backend.as_ref(), &schema).await.unwrap(),
+        )
+        .await
+        .unwrap();
+
+        let path = write_plan(backend.as_ref(), &contact(&["name", "email"]), dir.path()).await;
+
+        // Someone else changes the stored schema before the plan is applied.
+        let other = contact(&["name", "phone"]);
+        execute_planned(
+            backend.as_ref(),
+            &plan_schema(backend.as_ref(), &other).await.unwrap(),
+        )
+        .await
+        .unwrap();
+
+        let file = read_plan_file(&path).unwrap();
+        let err = verify_plan_file(backend.as_ref(), &file).await.unwrap_err();
+        assert!(matches!(err, CliError::PlanDrift { schema } if schema == "Contact"));
+    }
+
+    #[tokio::test]
+    async fn plan_for_new_schema_rejected_once_it_exists() {
+        let backend = memory_backend().await;
+        let dir = tempfile::tempdir().unwrap();
+        let schema = contact(&["name"]);
+        let path = write_plan(backend.as_ref(), &schema, dir.path()).await;
+        assert!(read_plan_file(&path).unwrap().migrations[0]
+            .base_hash
+            .is_none());
+
+        execute_planned(
+            backend.as_ref(),
+            &plan_schema(backend.as_ref(), &schema).await.unwrap(),
+        )
+        .await
+        .unwrap();
+
+        let file = read_plan_file(&path).unwrap();
+        assert!(matches!(
+            verify_plan_file(backend.as_ref(), &file).await,
+            Err(CliError::PlanDrift { .. })
+        ));
+    }
+
+    #[test]
+    fn transform_file_overrides_inferred_transform() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("transforms.json");
+        std::fs::write(
+            &path,
+            r#"{"Deal": {"status": {"transform": "MapText", "mapping": {"open": "Open"}}}}"#,
+        )
+        .unwrap();
+        let overrides = read_transform_file(&path).unwrap();
+
+        let old = &schema_forge_dsl::parse("schema Deal { status: text }").unwrap()[0];
+        let new =
+            &schema_forge_dsl::parse("schema Deal { status: enum(\"Open\", \"Won\") }").unwrap()[0];
+        let mut plan = DiffEngine::diff(old, new);
+        apply_transform_overrides(&mut plan, &overrides["Deal"]).unwrap();
+        assert_eq!(
+            plan.steps[0].to_string(),
+            "CHANGE TYPE of 'status' from Text to Enum[Open, Won] via map_text([open -> Open], unmapped -> fail)"
+        );
+    }
+
+    #[test]
+    fn read_transform_file_rejects_invalid_field_names() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("transforms.json");
+        std::fs::write(
+            &path,
+            r#"{"Deal": {"Status": {"transform": "ParseNumber"}}}"#,
+        )
+        .unwrap();
+        let err = read_transform_file(&path).unwrap_err();
+        assert!(err.to_string().contains("invalid transform file"));
+    }
+
+    #[test]
+    fn read_plan_file_rejects_unknown_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("plan.json");
+        std::fs::write(&path, r#"{"version": 99, "migrations": []}"#).unwrap();
+        let err = read_plan_file(&path).unwrap_err();
+        assert!(err.to_string().contains("unsupported plan file version 99"));
+    }
+}
@@ -2,6 +2,7 @@ pub mod apply;
 pub mod bootstrap_admin;
 pub mod codegen;
 pub mod completions;
+pub mod diff;
 pub mod export;
 pub mod hooks;
 pub mod init;
@@ -9,9 +10,12 @@ pub mod inspect;
 pub mod migrate;
 pub mod parse;
 pub mod policies;
+pub mod seed;
 pub mod serve;
 pub mod site;
 pub mod token;
+pub mod validate;
+pub mod watch;
 
 use std::sync::Arc;
 
@@ -1,7 +1,8 @@
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 use crate::cli::{GlobalOpts, ParseArgs};
-use crate::diagnostic::render_diagnostics;
+use crate::diagnostic::{render_concise, render_diagnostics};
 use crate::error::CliError;
 use crate::output::{OutputContext, OutputMode};
 
@@ -13,6 +14,10 @@ pub async fn run(
 ) -> Result<(), CliError> {
     let files = discover_schema_files(&args.paths)?;
 
+    if args.check {
+        return run_check(&files);
+    }
+
     let mut total_schemas = 0usize;
     let mut total_errors = 0usize;
     let mut all_file_results: Vec<serde_json::Value> = Vec::new();
@@ -119,11 +124,72 @@ pub async fn run(
     }
 }
 
+/// Run `parse --check`: parse every file, then check relation targets
+/// across the whole batch (system schemas count as defined).
+///
+/// Prints nothing on success. On failure prints one `file:line:col: message`
+/// line per error to stderr and returns a parse error for the exit code.
+fn run_check(files: &[PathBuf]) -> Result<(), CliError> {
+    let mut sources = Vec::with_capacity(files.len());
+    for file in files {
+        let source_text = std::fs::read_to_string(file).map_err(|e| CliError::Io {
+            path: file.clone(),
+            source: e,
+        })?;
+        sources.push((file.display().to_string(), source_text));
+    }
+
+    let mut defined: HashSet<String> = schema_forge_core::system_schemas::all_system_schemas()
+        .into_iter()
+        .filter_map(|dsl| schema_forge_dsl::parse(dsl).ok())
+        .flatten()
+        .map(|s| s.name.as_str().to_string())
+        .collect();
+    let mut lines = Vec::new();
+    for (filename, source_text) in &sources {
+        match schema_forge_dsl::parse(source_text) {
+            Ok(schemas) => defined.extend(schemas.iter().map(|s| s.name.as_str().to_string())),
+            Err(errors) => lines.extend(
+                errors
+                    .iter()
+                    .map(|e| render_concise(e, source_text, filename)),
+            ),
+        }
+    }
+
+    // A file that failed to parse may be the one defining a target, so
+    // relation targets are only judged once every file parsed.
+    if lines.is_empty() {
+        for (filename, source_text) in &sources {
+            let errors = schema_forge_dsl::check_relation_targets(source_text, |name| {
+                defined.contains(name)
+            });
+            lines.extend(
+                errors
+                    .iter()
+                    .map(|e| render_concise(e, source_text, filename)),
+            );
+        }
+    }
+
+    if lines.is_empty() {
+        return Ok(());
+    }
+    for line in &lines {
+        eprintln!("{line}");
+    }
+    Err(CliError::Parse {
+        errors: vec![], // individual errors already rendered
+        source_text: String::new(),
+        file: PathBuf::from("(multiple)"),
+    })
+}
+
 /// Discover .schema files from a list of paths.
 ///
 /// Paths can be files (used directly) or directories (searched recursively
 /// for files matching `**/*.schema`).
-fn discover_schema_files(paths: &[PathBuf]) -> Result<Vec<PathBuf>, CliError> {
+pub(crate) fn discover_schema_files(paths: &[PathBuf]) -> Result<Vec<PathBuf>, CliError> {
     let mut files = Vec::new();
 
     for path in paths {
@@ -0,0 +1,357 @@
+//! `schemaforge seed` — load fixture entities from a JSON file.
+//!
+//! The file maps schema names to arrays of field objects:
+//!
+//! ```json
+//! {
+//!   "Agency": [{ "name": "NASA" }],
+//!   "Opportunity": [{ "title": "Lunar lander", "agency": { "name": "NASA" } }]
+//! }
+//! ```
+//!
+//! A relation given as an object is a natural key: it resolves to the id of
+//! the single stored entity of the target schema whose fields match. Each
+//! schema's records are validated together and written in one atomic batch;
+//! schemas are seeded after the schemas their relations point at.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use schema_forge_acton::actor::assign_sequence_defaults;
+use schema_forge_acton::routes::entities::{
+    json_to_entity_fields, json_to_entity_fields_with_mode, ConversionMode,
+};
+use schema_forge_acton::DynForgeBackend;
+use schema_forge_backend::entity::Entity;
+use schema_forge_core::query::{FieldPath, Filter, Query};
+use schema_forge_core::types::{FieldType, SchemaDefinition};
+use serde_json::{Map, Value};
+
+use crate::cli::{GlobalOpts, SeedArgs};
+use crate::config::{load_svc_config, resolve_db_params};
+use crate::error::CliError;
+use crate::output::{OutputContext, OutputMode};
+
+/// Fixture records keyed by schema name.
+type Fixtures = BTreeMap<String, Vec<Map<String, Value>>>;
+
+/// Run the `seed` command: validate and bulk-create fixture entities.
+pub async fn run(
+    args: SeedArgs,
+    global: &GlobalOpts,
+    output: &OutputContext,
+) -> Result<(), CliError> {
+    let fixtures = read_fixtures(&args.file)?;
+
+    let svc_config = load_svc_config(global)?;
+    let db_params = resolve_db_params(&svc_config)?;
+
+    let backend = super::connect_backend(&db_params, output).await?;
+    let registry: HashMap<String, SchemaDefinition> = backend
+        .list_schema_metadata()
+        .await?
+        .into_iter()
+        .map(|s| (s.name.as_str().to_string(), s))
+        .collect();
+    if let Some(name) = fixtures.keys().find(|name| !registry.contains_key(*name)) {
+        return Err(CliError::SchemaNotFound { name: name.clone() });
+    }
+
+    let mut created = Vec::new();
+    for name in seed_order(&fixtures, &registry) {
+        match seed_schema(backend.as_ref(), &registry, name, &fixtures[name]).await {
+            Ok(count) => {
+                output.status(&format!("  {name} .... {count} created"));
+                created.push((name, count));
+            }
+            Err(errors) => {
+                for error in &errors {
+                    eprintln!("{error}");
+                }
+                report(&created, output);
+                return Err(CliError::Other(format!(
+                    "{} fixture errors in {name}; none of its records were written",
+                    errors.len()
+                )));
+            }
+        }
+    }
+
+    report(&created, output);
+    Ok(())
+}
+
+fn report(created: &[(&str, usize)], output: &OutputContext) {
+    let total: usize = created.iter().map(|(_, count)| count).sum();
+    match output.mode {
+        OutputMode::Json => {
+            let counts: Map<String, Value> = created
+                .iter()
+                .map(|(name, count)| (name.to_string(), Value::from(*count)))
+                .collect();
+            output.print_json(&serde_json::json!({ "created": counts, "total": total }));
+        }
+        OutputMode::Plain => {
+            for (name, count) in created {
+                println!("{name}\t{count}");
+            }
+        }
+        OutputMode::Human => output.success(&format!(
+            "Seeded {total} entities across {} schemas.",
+            created.len()
+        )),
+    }
+}
+
+fn read_fixtures(path: &Path) -> Result<Fixtures, CliError> {
+    let json = std::fs::read_to_string(path).map_err(|e| CliError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    serde_json::from_str(&json)
+        .map_err(|e| CliError::Other(format!("invalid fixture file {}: {e}", path.display())))
+}
+
+/// Order fixture schemas so each comes after the schemas its relations
+/// target. Schemas in a relation cycle keep file order; natural keys into
+/// them must then already exist in the backend.
+fn seed_order<'a>(
+    fixtures: &'a Fixtures,
+    registry: &HashMap<String, SchemaDefinition>,
+) -> Vec<&'a str> {
+    let mut pending: Vec<&str> = fixtures.keys().map(String::as_str).collect();
+    let mut order = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let ready = pending.iter().position(|name| {
+            registry[*name].fields.iter().all(|f| match &f.field_type {
+                FieldType::Relation { target, .. } => {
+                    target.as_str() == *name || !pending.contains(&target.as_str())
+                }
+                _ => true,
+            })
+        });
+        order.push(pending.remove(ready.unwrap_or(0)));
+    }
+    order
+}
+
+/// Validate every record of one schema, then create them in one batch.
+///
+/// Returns the number of created entities, or one `Schema[index]: ...`
+/// message per problem. Nothing is written if any record fails.
+async fn seed_schema(
+    backend: &dyn DynForgeBackend,
+    registry: &HashMap<String, SchemaDefinition>,
+    name: &str,
+    records: &[Map<String, Value>],
+) -> Result<usize, Vec<String>> {
+    let schema = &registry[name];
+    let mut entities = Vec::with_capacity(records.len());
+    let mut errors = Vec::new();
+    for (index, record) in records.iter().enumerate() {
+        match build_entity(backend, registry, schema, record).await {
+            Ok(entity) => entities.push(entity),
+            Err(errs) => errors.extend(errs.into_iter().map(|e| format!("{name}[{index}]: {e}"))),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    for entity in &mut entities {
+        assign_sequence_defaults(backend, Some(schema), entity)
+            .await
+            .map_err(|e| vec![format!("{name}: {e}")])?;
+    }
+    let created = backend
+        .create_many(&entities)
+        .await
+        .map_err(|e| vec![format!("{name}: {e}")])?;
+    Ok(created.len())
+}
+
+/// Resolve natural-key relations in `record`, then convert it against
+/// `schema` as a create would.
+async fn build_entity(
+    backend: &dyn DynForgeBackend,
+    registry: &HashMap<String, SchemaDefinition>,
+    schema: &SchemaDefinition,
+    record: &Map<String, Value>,
+) -> Result<Entity, Vec<String>> {
+    let mut record = record.clone();
+    let mut errors = Vec::new();
+    for field in &schema.fields {
+        let FieldType::Relation { target, .. } = &field.field_type else {
+            continue;
+        };
+        let (Some(value), Some(target)) = (
+            record.get_mut(field.name.as_str()),
+            registry.get(target.as_str()),
+        ) else {
+            continue;
+        };
+        let keys: Vec<&mut Value> = match value {
+            Value::Array(items) => items.iter_mut().collect(),
+            other => vec![other],
+        };
+        for key in keys {
+            let Value::Object(fields) = &*key else {
+                continue;
+            };
+            match resolve_natural_key(backend, target, fields).await {
+                Ok(id) => *key = Value::String(id),
+                Err(e) => errors.push(format!("field '{}': {e}", field.name.as_str())),
+            }
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let fields = json_to_entity_fields(schema, &record)?;
+    Ok(Entity::for_schema(schema, fields))
+}
+
+/// Find the id of the one `target` entity whose fields equal `key`.
+async fn resolve_natural_key(
+    backend: &dyn DynForgeBackend,
+    target: &SchemaDefinition,
+    key: &Map<String, Value>,
+) -> Result<String, String> {
+    let target_name = target.name.as_str();
+    if key.is_empty() {
+        return Err(format!("empty natural key for {target_name}"));
+    }
+    if let Some(unknown) = key.keys().find(|k| target.field(k).is_none()) {
+        return Err(format!("{target_name} has no field '{unknown}'"));
+    }
+    let values = json_to_entity_fields_with_mode(target, key, ConversionMode::Merge)
+        .map_err(|errs| errs.join("; "))?;
+    let filter = Filter::and(
+        values
+            .into_iter()
+            .map(|(field, value)| Filter::eq(FieldPath::single(field), value))
+            .collect(),
+    );
+    let query = Query::new(target.id.clone())
+        .with_filter(filter)
+        .with_limit(2)
+        .without_total_count();
+    let result = backend.query(&query).await.map_err(|e| e.to_string())?;
+    match result.entities.as_slice() {
+        [entity] => Ok(entity.id.as_str().to_string()),
+        [] => Err(format!(
+            "no {target_name} matches {}",
+            Value::Object(key.clone())
+        )),
+        _ => Err(format!(
+            "{} matches more than one {target_name}",
+            Value::Object(key.clone())
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(source: &str) -> HashMap<String, SchemaDefinition> {
+        schema_forge_dsl::parse(source)
+            .unwrap()
+            .into_iter()
+            .map(|s| (s.name.as_str().to_string(), s))
+            .collect()
+    }
+
+    #[test]
+    fn relation_targets_are_seeded_first() {
+        let registry = registry(
+            "schema Opportunity { title: text agency: -> Agency }\n\
+             schema Agency { name: text parent: -> Agency }\n\
+             schema Note { body: text }",
+        );
+        let fixtures: Fixtures = ["Opportunity", "Agency", "Note"]
+            .into_iter()
+            .map(|name| (name.to_string(), Vec::new()))
+            .collect();
+        assert_eq!(
+            seed_order(&fixtures, &registry),
+            ["Agency", "Note", "Opportunity"]
+        );
+    }
+
+    #[test]
+    fn read_fixtures_rejects_non_array_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fixtures.json");
+        std::fs::write(&path, r#"{"Agency": {"name": "NASA"}}"#).unwrap();
+        let err = read_fixtures(&path).unwrap_err();
+        assert!(err.to_string().contains("invalid fixture file"));
+    }
+
+    #[cfg(feature = "surrealdb")]
+    #[tokio::test]
+    async fn natural_keys_resolve_or_report_the_record() {
+        use std::sync::Arc;
+
+        let backend: Arc<dyn DynForgeBackend> = Arc::new(
+            schema_forge_surrealdb::SurrealBackend::connect_memory("test", "seed")
+                .await
+                .unwrap(),
+        );
+        let registry = registry(
+            "schema Agency { name: text region: text }\n\
+             schema Opportunity { title: text required agency: -> Agency }",
+        );
+        for name in ["Agency", "Opportunity"] {
+            let schema = &registry[name];
+            backend
+                .apply_migration(
+                    &schema.name,
+                    &schema_forge_core::migration::DiffEngine::create_new(schema).steps,
+                )
+                .await
+                .unwrap();
+            backend.store_schema_metadata(schema).await.unwrap();
+        }
+
+        let records =
+            |json: Value| -> Vec<Map<String, Value>> { serde_json::from_value(json).unwrap() };
+        let agencies = records(serde_json::json!([
+            { "name": "NASA", "region": "US" },
+            { "name": "ESA", "region": "EU" },
+            { "name": "ESA", "region": "EU" }
+        ]));
+        assert_eq!(
+            seed_schema(backend.as_ref(), &registry, "Agency", &agencies)
+                .await
+                .unwrap(),
+            3
+        );
+
+        let good = records(serde_json::json!([
+            { "title": "Lunar lander", "agency": { "name": "NASA" } }
+        ]));
+        assert_eq!(
+            seed_schema(backend.as_ref(), &registry, "Opportunity", &good)
+                .await
+                .unwrap(),
+            1
+        );
+
+        let bad = records(serde_json::json!([
+            { "title": "Probe", "agency": { "name": "NASA" } },
+            { "title": "Rover", "agency": { "name": "ESA" } },
+            { "title": "Telescope", "agency": { "name": "JAXA" } },
+            { "agency": { "name": "NASA" } }
+        ]));
+        let errors = seed_schema(backend.as_ref(), &registry, "Opportunity", &bad)
+            .await
+            .unwrap_err();
+        assert_eq!(errors.len(), 3, "{errors:?}");
+        assert!(errors[0].starts_with("Opportunity[1]: field 'agency'"));
+        assert!(errors[0].contains("more than one Agency"));
+        assert!(errors[1].starts_with("Opportunity[2]: field 'agency': no Agency matches"));
+        assert!(errors[2].starts_with("Opportunity[3]: required field 'title'"));
+    }
+}
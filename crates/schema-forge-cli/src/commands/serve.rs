@@ -79,6 +79,10 @@ pub async fn run(
                 "failed to load role ranks from {}: {e}",
                 args.role_ranks.display()
             ),
+        })?
+        .with_hierarchy(svc_config.custom.schema_forge.authz.role_hierarchy.clone())
+        .map_err(|e| CliError::Server {
+            message: format!("invalid [schema_forge.authz.role_hierarchy]: {e}"),
         })?;
 
     // 4b. Resolve operator-defined PASETO custom-claim → Cedar principal
@@ -141,6 +145,22 @@ pub async fn run(
         }
     }
 
+    // Install the configured audit sink. The persistent sink writes to the
+    // opt-in AuditLog system schema, which must be seeded (and visible to
+    // the policy recompile below) before any request is served.
+    let audit_config = svc_config.custom.schema_forge.audit.clone();
+    if audit_config.sink == schema_forge_acton::audit::AuditSinkKind::AuditLog {
+        schema_forge_acton::system::seed_audit_log_schema_into_map(
+            &mut registry,
+            backend_arc.as_ref(),
+        )
+        .await
+        .map_err(|e| CliError::Server {
+            message: format!("failed to seed AuditLog schema: {e}"),
+        })?;
+    }
+    let audit_sink = audit_config.build_sink(backend_arc.clone());
+
     // Rebuild tenant config after applying parsed schemas
     let all_schemas: Vec<_> = registry.values().cloned().collect();
     let tenant_config = schema_forge_backend::tenant::TenantConfig::from_schemas(&all_schemas)
@@ -192,6 +212,8 @@ pub async fn run(
         storage_registry: init_data.storage_registry,
         policy_store: init_data.policy_store,
         principal_claims: resolved_principal_claims.clone(),
+        audit_sink,
+        entity_hooks: init_data.entity_hooks,
     };
 
     // Build the canonical AuthStore from the User entity table. This
@@ -201,12 +223,7 @@ pub async fn run(
     // `_forge_users` table is no longer touched.
     let auth_store = build_entity_auth_store(&init_data, entity_store.clone())?;
 
-    // 6. Warn about --watch
-    if args.watch {
-        output.warn("--watch is not yet implemented; schemas will not auto-reload.");
-    }
-
-    // 7. Bootstrap the initial admin user, if requested. The
+    // 6. Bootstrap the initial admin user, if requested. The
     //    SchemaForgeExtension builder is the legacy seam for this — it
     //    no longer mounts any routes; the JSON forge router is mounted
     //    directly by `build_versioned_routes()` below.
@@ -275,7 +292,7 @@ pub async fn run(
     // auto-created on first boot when missing so `serve` is self-bootstrapping.
     let paseto_generator = build_paseto_generator(&svc_config, output)?;
 
-    // 8. Build versioned routes via acton-service for the JSON forge API.
+    // 7. Build versioned routes via acton-service for the JSON forge API.
     //    Build a `MetaInfo` snapshot from the resolved DB params + the
     //    login token TTL so `GET /api/v1/forge/meta` can surface honest
     //    runtime posture to unauthenticated callers (the login screen).
@@ -300,6 +317,7 @@ pub async fn run(
     output.status("    GET  /api/v1/forge/schemas/:name");
     output.status("    PUT  /api/v1/forge/schemas/:name");
     output.status("    DEL  /api/v1/forge/schemas/:name");
+    output.status("    GET  /api/v1/forge/openapi.json");
     output.status("    POST /api/v1/forge/schemas/:schema/entities");
     output.status("    GET  /api/v1/forge/schemas/:schema/entities");
     output.status("    GET  /api/v1/forge/schemas/:schema/entities/:id");
@@ -356,6 +374,8 @@ pub async fn run(
             hook_dispatcher,
             storage_registry: init_data.storage_registry,
             policy_store: init_data.policy_store,
+            audit_sink: init_data.audit_sink,
+            entity_hooks: init_data.entity_hooks,
             reply: ReplyChannel::new(tx),
         })
         .await;
@@ -370,6 +390,17 @@ pub async fn run(
             message: "ForgeActor initialization failed (channel dropped)".to_string(),
         })?;
 
+    if args.watch {
+        output.status(&format!(
+            "  Watching {} for schema changes.",
+            args.schema_dir.display()
+        ));
+        tokio::spawn(super::watch::watch_schemas(
+            args.schema_dir.clone(),
+            forge_handle.clone(),
+        ));
+    }
+
     service.serve().await.map_err(|e| CliError::Server {
         message: format!("server error: {e}"),
     })?;
@@ -692,7 +723,7 @@ mod tests {
                 ),
                 FieldDefinition::new(
                     FieldName::new("roles").unwrap(),
-                    FieldType::Array(Box::new(FieldType::Text(TextConstraints::unconstrained()))),
+                    FieldType::array(FieldType::Text(TextConstraints::unconstrained())),
                 ),
                 FieldDefinition::with_annotations(
                     FieldName::new("role_rank").unwrap(),
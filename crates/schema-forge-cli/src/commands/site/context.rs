@@ -9,7 +9,7 @@ use std::collections::BTreeMap;
 use heck::{ToKebabCase, ToLowerCamelCase, ToPascalCase, ToSnakeCase, ToTitleCase};
 use serde::Serialize;
 
-use schema_forge_core::types::{FieldDefinition, SchemaDefinition};
+use schema_forge_core::types::{FieldDefinition, SchemaDefinition, WidgetType};
 
 use crate::error::CliError;
 use crate::output::OutputContext;
@@ -154,6 +154,68 @@ pub struct EntityView {
     /// components when the entity actually has a file field, otherwise
     /// `noUnusedLocals` rejects the generated file.
     pub has_file_field: bool,
+    /// `true` iff any top-level field has `rich_widget` set. The detail
+    /// template only imports `widgetDisplay` when some row renders through
+    /// it, otherwise `noUnusedLocals` rejects the generated file.
+    pub has_rich_widget: bool,
+    /// `true` if the schema carries `@deprecated`. Templates render a
+    /// "deprecated" badge next to the entity title.
+    pub deprecated: bool,
+    /// The `@deprecated("...")` reason, if one was given.
+    pub deprecation_reason: Option<String>,
+    /// Fields named by `@list_columns(...)`, in declared order. Empty when
+    /// the schema has no such annotation. Non-empty lists override each
+    /// field's `list_placement` and become the list query's `fields`
+    /// projection.
+    pub list_columns: Vec<String>,
+    /// Page sizes for the list view, from the schema's `@list(...)`.
+    pub pagination: PaginationView,
+}
+
+/// Rows per page offered by list views whose schema declares no `@list(...)`.
+const PAGE_SIZE_OPTIONS: [u32; 4] = [25, 50, 100, 200];
+
+/// Initial rows per page of a list view whose schema declares no
+/// `@list(page_size: N)`.
+const DEFAULT_PAGE_SIZE: u32 = 50;
+
+/// The effective page sizes of a schema's list view.
+///
+/// The server applies the same `@list(page_size, max_page_size)` rules to
+/// every list request, so these only keep the page-size picker honest: it
+/// starts at `page_size` and never offers more than `max_page_size` rows.
+#[derive(Debug, Clone, Serialize)]
+pub struct PaginationView {
+    /// Rows per page when the view first loads.
+    pub page_size: u32,
+    /// The server-side cap on rows per page, if the schema declares one.
+    pub max_page_size: Option<u32>,
+    /// Options for the rows-per-page picker, ascending. Always contains
+    /// `page_size`, and `max_page_size` when one is declared.
+    pub page_sizes: Vec<u32>,
+}
+
+impl PaginationView {
+    fn from_schema(def: &SchemaDefinition) -> Self {
+        let max_page_size = def.max_page_size();
+        let page_size = def
+            .page_size()
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .min(max_page_size.unwrap_or(u32::MAX));
+        let mut page_sizes: Vec<u32> = PAGE_SIZE_OPTIONS
+            .into_iter()
+            .chain([page_size])
+            .chain(max_page_size)
+            .filter(|n| max_page_size.is_none_or(|max| *n <= max))
+            .collect();
+        page_sizes.sort_unstable();
+        page_sizes.dedup();
+        Self {
+            page_size,
+            max_page_size,
+            page_sizes,
+        }
+    }
 }
 
 impl EntityView {
@@ -185,7 +247,9 @@ impl EntityView {
         });
         let has_json_field = fields.iter().any(|f| f.kind == "json");
         let has_file_field = fields.iter().any(|f| f.kind == "file");
+        let has_rich_widget = fields.iter().any(|f| f.rich_widget);
         let display_field = def.display_field().map(|s| s.to_string());
+        let deprecation = def.deprecation();
 
         // `@display("field")` auto-promotes to `primary` when no explicit
         // `@list(primary)` exists anywhere on the schema. Validation in the
@@ -200,6 +264,37 @@ impl EntityView {
             }
         }
 
+        // A schema-level `@enum_colors(...)` fills in the variants a field's
+        // own `@enum_colors` leaves out.
+        if let Some(defaults) = def.enum_colors() {
+            for f in fields.iter_mut().filter(|f| f.kind == "enum") {
+                for (variant, color) in defaults {
+                    if f.enum_variants.contains(variant) {
+                        f.enum_colors
+                            .entry(variant.clone())
+                            .or_insert_with(|| color.as_str().to_string());
+                    }
+                }
+            }
+        }
+
+        // `@list_columns(...)` replaces the per-field heuristic: declared
+        // fields render (a promoted `primary` keeps its styling) and every
+        // other field is hidden.
+        let list_columns: Vec<String> = def
+            .list_columns()
+            .map(|cols| cols.iter().map(|c| c.as_str().to_string()).collect())
+            .unwrap_or_default();
+        if !list_columns.is_empty() {
+            for f in &mut fields {
+                if !list_columns.contains(&f.leaf) {
+                    f.list_placement = "hidden".to_string();
+                } else if f.list_placement != "primary" {
+                    f.list_placement = "column".to_string();
+                }
+            }
+        }
+
         let pascal = name.to_pascal_case();
         Ok(Self {
             pascal_plural: pluralize(&pascal),
@@ -214,6 +309,11 @@ impl EntityView {
             has_relation_link,
             has_json_field,
             has_file_field,
+            has_rich_widget,
+            deprecated: deprecation.is_some(),
+            deprecation_reason: deprecation.flatten().map(str::to_string),
+            list_columns,
+            pagination: PaginationView::from_schema(def),
         })
     }
 }
@@ -261,6 +361,10 @@ pub struct FieldView {
     pub widget: Option<String>,
     /// `@format("...")` hint as the canonical snake_case token, if present.
     pub format: Option<String>,
+    /// `locale: "..."` option of the `@format(...)` hint, if given.
+    pub format_locale: Option<String>,
+    /// `currency: "..."` option of the `@format(...)` hint, if given.
+    pub format_currency: Option<String>,
     /// For `kind == "array"`: the scalar kind of each element
     /// (`"text"`, `"integer"`, `"float"`, `"boolean"`, `"enum"`).
     pub item_kind: Option<String>,
@@ -271,12 +375,15 @@ pub struct FieldView {
     pub sub_fields: Vec<FieldView>,
     /// For `kind == "enum"`: map from variant name to its `@enum_colors`
     /// color token (one of `neutral|gray|red|amber|green|blue|purple|violet|teal|rose`).
-    /// Empty when the field carries no `@enum_colors` annotation; variants
-    /// without an explicit entry render with the default neutral badge.
+    /// Field-level entries win over the schema-level `@enum_colors`
+    /// defaults; variants named by neither render with the default neutral
+    /// badge.
     pub enum_colors: BTreeMap<String, String>,
     /// Resolved list-view placement for this field. Always one of
-    /// `"primary"`, `"column"`, or `"hidden"`, computed from the field's
-    /// `@list(...)` annotation with these fallbacks:
+    /// `"primary"`, `"column"`, or `"hidden"`. A schema-level
+    /// `@list_columns(...)` decides outright (listed fields show, the rest
+    /// are hidden); otherwise it is computed from the field's `@list(...)`
+    /// annotation with these fallbacks:
     ///
     /// 1. Explicit `@list(hint)` wins.
     /// 2. If the schema's `@display("field")` points at this field and no
@@ -292,11 +399,27 @@ pub struct FieldView {
     /// the detail view. Reads already flow through the standard relation
     /// envelope, populated by the backend's inverse-collection pass.
     pub derived: bool,
+    /// `true` if this field carries `@computed(expr: ...)`. Like derived
+    /// fields it is read-only: the server evaluates it on every read, so
+    /// forms skip it and the detail view renders it as plain text.
+    pub computed: bool,
+    /// `true` when the detail view renders this field through
+    /// `widgetDisplay`: `@widget("progress")` on a numeric field (a
+    /// `<progress>` bar) or `@widget("url")` on a text field (an external
+    /// link) without an overriding `@format(...)`. Anything else renders
+    /// through `formatFieldValue`.
+    pub rich_widget: bool,
     /// For `kind == "file"`: metadata the template needs to render the
     /// upload widget (accept attribute, max-size guard, proxied vs. presigned
     /// behavior). `None` for non-file fields.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file_meta: Option<FileMetaView>,
+    /// `true` if the field carries `@deprecated`. Templates render a
+    /// "deprecated" badge next to the field label.
+    pub deprecated: bool,
+    /// The `@deprecated("...")` reason, if one was given. Rendered as the
+    /// badge's tooltip.
+    pub deprecation_reason: Option<String>,
 }
 
 /// File-field metadata projected to the site template layer.
@@ -357,6 +480,8 @@ pub fn make_field_view(
         kind: kind.to_string(),
         widget: field.widget_type_hint().map(|w| w.as_str().to_string()),
         format: field.format_type_hint().map(|fmt| fmt.as_str().to_string()),
+        format_locale: field.format_locale().map(str::to_string),
+        format_currency: field.format_currency().map(str::to_string),
         item_kind: None,
         item_enum_variants: Vec::new(),
         sub_fields: Vec::new(),
@@ -377,6 +502,17 @@ pub fn make_field_view(
             None => default_list_placement(kind).to_string(),
         },
         derived: field.is_derived(),
+        computed: field.is_computed(),
+        // An explicit `@format(...)` wins over the widget, as it does in
+        // `formatFieldValue`.
+        rich_widget: field.format_type_hint().is_none()
+            && matches!(
+                (field.widget_type_hint(), kind),
+                (Some(WidgetType::Progress), "integer" | "float" | "decimal")
+                    | (Some(WidgetType::Url), "text")
+            ),
+        deprecated: field.deprecation().is_some(),
+        deprecation_reason: field.deprecation().flatten().map(str::to_string),
     }
 }
 
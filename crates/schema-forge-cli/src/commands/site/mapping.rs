@@ -1,7 +1,7 @@
 //! Pure-function mapping from [`FieldDefinition`] to [`FieldView`].
 //!
-//! Supported: Text, RichText, Integer, Float, Boolean, DateTime, Enum,
-//! Json, Relation(One|Many), Array(scalar|enum), and Composite (recursive,
+//! Supported: Text, RichText, Integer, Float, Decimal, Boolean, DateTime, Duration,
+//! Uuid, Enum, Json, Relation(One|Many), Array(scalar|enum), and Composite (recursive,
 //! flattened into dot-path sub-fields). Array-of-array and
 //! array-of-composite fall back to a JSON textarea: the field is projected
 //! as `kind = "json"` with a best-effort TS type, and the edit form
@@ -9,7 +9,7 @@
 
 use std::collections::BTreeMap;
 
-use schema_forge_core::types::{Cardinality, FieldDefinition, FieldType};
+use schema_forge_core::types::{Cardinality, FieldDefinition, FieldType, UUID_PATTERN};
 
 use super::context::{make_field_view, FieldView, FileMetaView, SchemaMeta};
 
@@ -94,6 +94,30 @@ fn field_to_view_with_prefix(
                 Vec::new(),
             ))
         }
+        FieldType::Decimal { scale } => {
+            // Decimals travel as strings; cap the fraction at the field's
+            // scale so the form rejects what the server would.
+            let fraction = if *scale == 0 {
+                String::new()
+            } else {
+                format!(r"(\.\d{{1,{scale}}})?")
+            };
+            let mut zod = format!(
+                r#"z.string().regex(/^-?\d+{fraction}$/, "Expected a number with at most {scale} decimal places")"#
+            );
+            if !required {
+                zod.push_str(".nullish()");
+            }
+            Ok(make_field_view(
+                field,
+                "string".to_string(),
+                zod,
+                "decimal",
+                false,
+                None,
+                Vec::new(),
+            ))
+        }
         FieldType::Boolean => {
             let mut zod = "z.boolean()".to_string();
             if !required {
@@ -129,6 +153,41 @@ fn field_to_view_with_prefix(
                 Vec::new(),
             ))
         }
+        FieldType::Duration => {
+            // Loose shape check for ISO-8601 (`P3DT4H`) or shorthand
+            // (`2h 30m`) durations; the server rejects anything it cannot
+            // parse.
+            let mut zod = r#"z.string().regex(/^(P(\d+[YMWD])*(T(\d+[HMS])+)?|\d+[wdhmsWDHMS](\s*\d+[wdhmsWDHMS])*)$/, "Expected a duration such as 2h 30m or P3DT4H")"#.to_string();
+            if !required {
+                zod.push_str(".nullish()");
+            }
+            Ok(make_field_view(
+                field,
+                "string".to_string(),
+                zod,
+                "duration",
+                false,
+                None,
+                Vec::new(),
+            ))
+        }
+        FieldType::Uuid => {
+            let mut zod = format!(
+                r#"z.string().regex(/^{UUID_PATTERN}$/, "Expected a UUID such as 67e55044-10b1-426f-9247-bb680e5fe0c8")"#
+            );
+            if !required {
+                zod.push_str(".nullish()");
+            }
+            Ok(make_field_view(
+                field,
+                "string".to_string(),
+                zod,
+                "uuid",
+                false,
+                None,
+                Vec::new(),
+            ))
+        }
         FieldType::Enum(v) => {
             let variants: Vec<String> = v.as_slice().iter().map(|s| s.to_string()).collect();
             let ts_type = variants
@@ -190,7 +249,7 @@ fn field_to_view_with_prefix(
             );
             Ok(with_relation_metadata(base, target.as_str(), catalog))
         }
-        FieldType::Array(inner) => {
+        FieldType::Array(inner, _) => {
             let zod = if required {
                 "z.string().min(1, \"Required\")".to_string()
             } else {
@@ -231,7 +290,7 @@ fn field_to_view_with_prefix(
                 }
             }
         }
-        FieldType::RichText => {
+        FieldType::RichText(_) => {
             let zod = if required {
                 "z.string().min(1, \"Required\")".to_string()
             } else {
@@ -414,7 +473,12 @@ fn with_relation_metadata(
 /// `unknown` since the JSON wire shape is opaque to the generator.
 fn ts_type_for_field_type(ft: &FieldType) -> String {
     match ft {
-        FieldType::Text(_) | FieldType::RichText | FieldType::DateTime => "string".to_string(),
+        FieldType::Text(_)
+        | FieldType::RichText(_)
+        | FieldType::DateTime
+        | FieldType::Duration
+        | FieldType::Uuid
+        | FieldType::Decimal { .. } => "string".to_string(),
         FieldType::Integer(_) | FieldType::Float(_) => "number".to_string(),
         FieldType::Boolean => "boolean".to_string(),
         FieldType::Enum(v) => {
@@ -424,7 +488,7 @@ fn ts_type_for_field_type(ft: &FieldType) -> String {
         }
         FieldType::Json => "unknown".to_string(),
         FieldType::Relation { .. } => "string".to_string(),
-        FieldType::Array(inner) => format!("{}[]", ts_type_for_field_type(inner)),
+        FieldType::Array(inner, _) => format!("{}[]", ts_type_for_field_type(inner)),
         FieldType::Composite(sub_defs) => {
             let mut parts = Vec::with_capacity(sub_defs.len());
             for sub in sub_defs {
@@ -464,14 +528,16 @@ fn describe_array_element(
             Ok(("enum", format!("({ts_type})"), variants))
         }
         FieldType::DateTime => Ok(("datetime", "string".to_string(), Vec::new())),
-        FieldType::Array(_) => Err("nested arrays are not supported in v0 site generator".into()),
+        FieldType::Array(_, _) => {
+            Err("nested arrays are not supported in v0 site generator".into())
+        }
         FieldType::Composite(_) => {
             Err("arrays of composites are not supported in v0 site generator".into())
         }
         FieldType::Relation { .. } => {
             Err("arrays of relations are not supported here — use `-> T[]` instead".into())
         }
-        FieldType::RichText => {
+        FieldType::RichText(_) => {
             Err("arrays of rich text are not supported in v0 site generator".into())
         }
         FieldType::Json => Err("arrays of json are not supported in v0 site generator".into()),
@@ -577,6 +643,36 @@ mod tests {
         assert_eq!(v.zod, "z.string().min(1, \"Required\")");
     }
 
+    #[test]
+    fn duration_optional() {
+        let v = project(&field("planned_duration", FieldType::Duration, false)).unwrap();
+        assert_eq!(v.kind, "duration");
+        assert_eq!(v.ts_type, "string");
+        assert!(v.zod.starts_with("z.string().regex("));
+        assert!(v.zod.ends_with(".nullish()"));
+    }
+
+    #[test]
+    fn uuid_uses_hyphenated_pattern() {
+        let v = project(&field("external_id", FieldType::Uuid, true)).unwrap();
+        assert_eq!(v.kind, "uuid");
+        assert_eq!(v.ts_type, "string");
+        assert!(v.zod.contains(&format!("/^{UUID_PATTERN}$/")), "{}", v.zod);
+        assert!(!v.zod.ends_with(".nullish()"));
+    }
+
+    #[test]
+    fn decimal_regex_follows_scale() {
+        let v = project(&field("total", FieldType::Decimal { scale: 2 }, true)).unwrap();
+        assert_eq!(v.kind, "decimal");
+        assert_eq!(v.ts_type, "string");
+        assert!(v.zod.contains(r"/^-?\d+(\.\d{1,2})?$/"), "{}", v.zod);
+
+        let v = project(&field("units", FieldType::Decimal { scale: 0 }, false)).unwrap();
+        assert!(v.zod.contains(r"/^-?\d+$/"), "{}", v.zod);
+        assert!(v.zod.ends_with(".nullish()"));
+    }
+
     #[test]
     fn widget_and_format_hints_propagate() {
         use schema_forge_core::types::{FieldAnnotation, FormatType, WidgetType};
@@ -591,12 +687,41 @@ mod tests {
                 },
                 FieldAnnotation::Format {
                     format_type: FormatType::Currency,
+                    locale: None,
+                    currency: None,
                 },
             ],
         );
         let v = project(&fd).unwrap();
         assert_eq!(v.widget.as_deref(), Some("progress"));
         assert_eq!(v.format.as_deref(), Some("currency"));
+        // The explicit format wins, so the detail view stays plain text.
+        assert!(!v.rich_widget);
+    }
+
+    #[test]
+    fn rich_widget_requires_a_matching_kind() {
+        use schema_forge_core::types::{FieldAnnotation, WidgetType};
+
+        let with_widget = |name: &str, field_type: FieldType, widget_type: WidgetType| {
+            FieldDefinition::with_annotations(
+                FieldName::new(name).unwrap(),
+                field_type,
+                vec![],
+                vec![FieldAnnotation::Widget { widget_type }],
+            )
+        };
+        let integer = || FieldType::Integer(IntegerConstraints::unconstrained());
+        let text = || FieldType::Text(TextConstraints::unconstrained());
+
+        let progress = project(&with_widget("done", integer(), WidgetType::Progress)).unwrap();
+        assert!(progress.rich_widget);
+        let url = project(&with_widget("site", text(), WidgetType::Url)).unwrap();
+        assert!(url.rich_widget);
+        let rating = project(&with_widget("stars", integer(), WidgetType::Rating)).unwrap();
+        assert!(!rating.rich_widget);
+        let mismatched = project(&with_widget("note", text(), WidgetType::Progress)).unwrap();
+        assert!(!mismatched.rich_widget);
     }
 
     #[test]
@@ -657,7 +782,7 @@ mod tests {
     fn array_of_text_optional() {
         let v = project(&field(
             "tags",
-            FieldType::Array(Box::new(FieldType::Text(TextConstraints::unconstrained()))),
+            FieldType::array(FieldType::Text(TextConstraints::unconstrained())),
             false,
         ))
         .unwrap();
@@ -671,9 +796,7 @@ mod tests {
     fn array_of_integer_required() {
         let v = project(&field(
             "scores",
-            FieldType::Array(Box::new(FieldType::Integer(
-                IntegerConstraints::unconstrained(),
-            ))),
+            FieldType::array(FieldType::Integer(IntegerConstraints::unconstrained())),
             true,
         ))
         .unwrap();
@@ -687,9 +810,9 @@ mod tests {
     fn array_of_enum_carries_variants() {
         let v = project(&field(
             "labels",
-            FieldType::Array(Box::new(FieldType::Enum(
+            FieldType::array(FieldType::Enum(
                 EnumVariants::new(vec!["bug".into(), "feature".into()]).unwrap(),
-            ))),
+            )),
             false,
         ))
         .unwrap();
@@ -706,7 +829,7 @@ mod tests {
     fn array_of_array_falls_back_to_json_textarea() {
         let v = project(&field(
             "matrix",
-            FieldType::Array(Box::new(FieldType::Array(Box::new(FieldType::Boolean)))),
+            FieldType::array(FieldType::array(FieldType::Boolean)),
             false,
         ))
         .unwrap();
@@ -728,7 +851,7 @@ mod tests {
         );
         let v = project(&field(
             "rows",
-            FieldType::Array(Box::new(FieldType::Composite(vec![inner, extra]))),
+            FieldType::array(FieldType::Composite(vec![inner, extra])),
             true,
         ))
         .unwrap();
@@ -749,7 +872,7 @@ mod tests {
 
     #[test]
     fn rich_text_yields_rich_text_kind() {
-        let v = project(&field("body", FieldType::RichText, false)).unwrap();
+        let v = project(&field("body", FieldType::rich_text(), false)).unwrap();
         assert_eq!(v.kind, "rich_text");
         assert_eq!(v.ts_type, "string");
         assert_eq!(v.zod, "z.string().nullish()");
@@ -794,7 +917,7 @@ mod tests {
             FieldName::new("address").unwrap(),
             FieldType::Composite(vec![FieldDefinition::new(
                 FieldName::new("matrix").unwrap(),
-                FieldType::Array(Box::new(FieldType::Array(Box::new(FieldType::Boolean)))),
+                FieldType::array(FieldType::array(FieldType::Boolean)),
             )]),
         );
         let v = project(&fd).unwrap();
@@ -834,6 +957,23 @@ mod tests {
         assert!(!v.derived);
     }
 
+    #[test]
+    fn computed_field_is_flagged() {
+        use schema_forge_core::types::{ComputedExpr, FieldAnnotation};
+
+        let mut fd = field(
+            "margin",
+            FieldType::Integer(IntegerConstraints::unconstrained()),
+            false,
+        );
+        fd.annotations.push(FieldAnnotation::Computed {
+            expr: ComputedExpr::new("price - cost").unwrap(),
+        });
+        let v = project(&fd).unwrap();
+        assert!(v.computed);
+        assert!(!v.derived);
+    }
+
     #[test]
     fn relation_one_picks_up_target_metadata_from_catalog() {
         let mut catalog = BTreeMap::new();
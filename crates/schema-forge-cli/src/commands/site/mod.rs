@@ -581,6 +581,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn schema_level_enum_colors_fill_in_unset_variants() {
+        use super::context::{EntityView, SchemaMeta};
+
+        let mut schema = opportunity_schema_with_enum_colors();
+        // `stage` only colors `won` itself.
+        schema.fields[1].annotations = vec![FieldAnnotation::EnumColors {
+            colors: BTreeMap::from([("won".to_string(), EnumColor::Green)]),
+        }];
+        schema.annotations.push(Annotation::EnumColors {
+            colors: BTreeMap::from([
+                ("won".to_string(), EnumColor::Blue),
+                ("lost".to_string(), EnumColor::Red),
+            ]),
+        });
+        let mut catalog = BTreeMap::new();
+        catalog.insert("Opportunity".to_string(), SchemaMeta::from_schema(&schema));
+        let output = crate::output::OutputContext {
+            mode: crate::output::OutputMode::Plain,
+            verbose: 0,
+            quiet: true,
+            use_color: false,
+        };
+        let entity = EntityView::from_schema(&schema, &catalog, &output).unwrap();
+
+        let stage = entity.fields.iter().find(|f| f.leaf == "stage").unwrap();
+        assert_eq!(
+            stage.enum_colors,
+            BTreeMap::from([
+                ("lost".to_string(), "red".to_string()),
+                ("won".to_string(), "green".to_string()),
+            ])
+        );
+    }
+
     fn schema_with_list_hints() -> SchemaDefinition {
         // Fields carry a mix of explicit hints and default behavior so we
         // can assert the full partition + auto-hide policy in one fixture.
@@ -607,7 +642,7 @@ mod tests {
                 // Rich text auto-hides by default.
                 FieldDefinition::new(
                     FieldName::new("description").unwrap(),
-                    FieldType::RichText,
+                    FieldType::rich_text(),
                 ),
                 // Unannotated integer -> column.
                 FieldDefinition::new(
@@ -709,6 +744,100 @@ mod tests {
         assert!(sortable_block.contains("\"pwin\""));
         assert!(!sortable_block.contains("\"description\""));
         assert!(!sortable_block.contains("\"internal_flag\""));
+        // No @list_columns: the list query fetches every field.
+        assert!(rendered.contains("LIST_FIELDS: readonly string[] | undefined = undefined"));
+    }
+
+    #[test]
+    fn list_columns_annotation_restricts_list_view_model() {
+        use super::context::{EntityView, PageContext, SchemaMeta};
+        use super::render::SiteRenderer;
+
+        let mut schema = schema_with_list_hints();
+        schema.annotations.push(Annotation::ListColumns {
+            fields: vec![
+                FieldName::new("title").unwrap(),
+                FieldName::new("description").unwrap(),
+            ],
+        });
+        let mut catalog = BTreeMap::new();
+        catalog.insert("Opportunity".to_string(), SchemaMeta::from_schema(&schema));
+        let output = crate::output::OutputContext {
+            mode: crate::output::OutputMode::Plain,
+            verbose: 0,
+            quiet: true,
+            use_color: false,
+        };
+        let entity = EntityView::from_schema(&schema, &catalog, &output).unwrap();
+
+        assert_eq!(entity.list_columns, vec!["title", "description"]);
+        let visible: Vec<(&str, &str)> = entity
+            .fields
+            .iter()
+            .filter(|f| f.list_placement != "hidden")
+            .map(|f| (f.leaf.as_str(), f.list_placement.as_str()))
+            .collect();
+        // The @display field keeps its primary styling; rich text that would
+        // auto-hide is shown because it is declared; everything else is hidden.
+        assert_eq!(
+            visible,
+            vec![("title", "primary"), ("description", "column")]
+        );
+
+        let page_ctx = PageContext {
+            project_name: "demo".to_string(),
+            entity,
+        };
+        let renderer = SiteRenderer::new(None).unwrap();
+        let rendered = renderer
+            .render("src/app/pages/list.generated.tsx", &page_ctx)
+            .expect("list.generated template must render");
+        assert!(rendered.contains("accessorKey: \"description\""));
+        assert!(!rendered.contains("accessorKey: \"stage\""));
+        assert!(!rendered.contains("accessorKey: \"pwin\""));
+        assert!(
+            rendered.contains("LIST_FIELDS: readonly string[] | undefined = [\n  \"title\",\n  \"description\",\n]"),
+            "list query must project the declared columns:\n{rendered}"
+        );
+    }
+
+    #[test]
+    fn pagination_annotation_sets_page_size_options() {
+        use super::context::{EntityView, PageContext, SchemaMeta};
+        use super::render::SiteRenderer;
+
+        let mut schema = schema_with_list_hints();
+        schema.annotations.push(Annotation::Pagination {
+            page_size: Some(20),
+            max_page_size: Some(75),
+        });
+        let mut catalog = BTreeMap::new();
+        catalog.insert("Opportunity".to_string(), SchemaMeta::from_schema(&schema));
+        let output = crate::output::OutputContext {
+            mode: crate::output::OutputMode::Plain,
+            verbose: 0,
+            quiet: true,
+            use_color: false,
+        };
+        let entity = EntityView::from_schema(&schema, &catalog, &output).unwrap();
+
+        assert_eq!(entity.pagination.page_size, 20);
+        assert_eq!(entity.pagination.max_page_size, Some(75));
+        assert_eq!(entity.pagination.page_sizes, vec![20, 25, 50, 75]);
+
+        let page_ctx = PageContext {
+            project_name: "demo".to_string(),
+            entity,
+        };
+        let renderer = SiteRenderer::new(None).unwrap();
+        let rendered = renderer
+            .render("src/app/pages/list.generated.tsx", &page_ctx)
+            .expect("list.generated template must render");
+        assert!(rendered.contains("PAGE_SIZE = 20"), "{rendered}");
+        assert!(
+            rendered.contains("PAGE_SIZES: readonly number[] = [20, 25, 50, 75]"),
+            "{rendered}"
+        );
     }
 
     /// Schema with a single optional `file` field used to drive the codegen
@@ -755,6 +884,65 @@ mod tests {
         EntityView::from_schema(&schema, &catalog, &output).unwrap()
     }
 
+    #[test]
+    fn views_expose_deprecation() {
+        use super::context::{EntityView, PageContext, SchemaMeta};
+        use super::render::SiteRenderer;
+
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Customer").unwrap(),
+            vec![
+                FieldDefinition::with_annotations(
+                    FieldName::new("fax").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                    vec![],
+                    vec![FieldAnnotation::Deprecated {
+                        reason: Some("use email".into()),
+                    }],
+                ),
+                FieldDefinition::new(
+                    FieldName::new("email").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                ),
+            ],
+            vec![Annotation::Deprecated { reason: None }],
+        )
+        .unwrap();
+        let mut catalog = BTreeMap::new();
+        catalog.insert("Customer".to_string(), SchemaMeta::from_schema(&schema));
+        let output = crate::output::OutputContext {
+            mode: crate::output::OutputMode::Plain,
+            verbose: 0,
+            quiet: true,
+            use_color: false,
+        };
+        let entity = EntityView::from_schema(&schema, &catalog, &output).unwrap();
+
+        assert!(entity.deprecated);
+        assert_eq!(entity.deprecation_reason, None);
+        let fax = entity.fields.iter().find(|f| f.leaf == "fax").unwrap();
+        assert!(fax.deprecated);
+        assert_eq!(fax.deprecation_reason.as_deref(), Some("use email"));
+        let email = entity.fields.iter().find(|f| f.leaf == "email").unwrap();
+        assert!(!email.deprecated);
+
+        let page_ctx = PageContext {
+            project_name: "demo".to_string(),
+            entity,
+        };
+        let rendered = SiteRenderer::new(None)
+            .unwrap()
+            .render("src/app/pages/edit.generated.tsx", &page_ctx)
+            .expect("edit.generated must render");
+        assert!(
+            rendered.contains(
+                r#"<span className="chip chip-warn" title="use email">deprecated</span>"#
+            ),
+            "deprecated field must render a badge carrying its reason"
+        );
+    }
+
     #[test]
     fn edit_template_emits_file_upload_for_file_field() {
         use super::context::PageContext;
@@ -832,6 +1020,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn detail_template_renders_progress_and_url_widgets() {
+        use super::context::{EntityView, PageContext, SchemaMeta};
+        use super::render::SiteRenderer;
+        use schema_forge_core::types::WidgetType;
+
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Project").unwrap(),
+            vec![
+                FieldDefinition::with_annotations(
+                    FieldName::new("completion").unwrap(),
+                    FieldType::Integer(IntegerConstraints::with_range(0, 100).unwrap()),
+                    vec![],
+                    vec![FieldAnnotation::Widget {
+                        widget_type: WidgetType::Progress,
+                    }],
+                ),
+                FieldDefinition::with_annotations(
+                    FieldName::new("homepage").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                    vec![],
+                    vec![FieldAnnotation::Widget {
+                        widget_type: WidgetType::Url,
+                    }],
+                ),
+            ],
+            vec![],
+        )
+        .unwrap();
+        let mut catalog = BTreeMap::new();
+        catalog.insert("Project".to_string(), SchemaMeta::from_schema(&schema));
+        let output = crate::output::OutputContext {
+            mode: crate::output::OutputMode::Plain,
+            verbose: 0,
+            quiet: true,
+            use_color: false,
+        };
+        let entity = EntityView::from_schema(&schema, &catalog, &output).unwrap();
+        assert!(entity.has_rich_widget);
+
+        let page_ctx = PageContext {
+            project_name: "demo".to_string(),
+            entity,
+        };
+        let renderer = SiteRenderer::new(None).unwrap();
+        let rendered = renderer
+            .render("src/app/pages/detail.generated.tsx", &page_ctx)
+            .expect("detail.generated must render");
+
+        assert!(rendered.contains("import { formatFieldValue, widgetDisplay }"));
+        assert!(rendered.contains("widgetDisplay(data.completion, hints)"));
+        assert!(rendered.contains("<progress max={100} value={display.percent} />"));
+        assert!(rendered.contains("widgetDisplay(data.homepage, hints)"));
+        assert!(rendered.contains("href={display.href}"));
+    }
+
     #[test]
     fn detail_template_emits_attachment_download_for_file_field() {
         use super::context::PageContext;
@@ -530,7 +530,9 @@ export {
 
 /// A generic relation picker: renders a `<select>` whose options are
 /// fetched from the backend for a given schema target and display field.
-/// Value is the selected entity id (or `undefined`).
+/// Value is the selected entity id (or `undefined`). When `onCreateNew` is
+/// passed, a trailing "Create new" option hands control to the caller
+/// instead of changing the value.
 pub const RELATION_SELECT: &str = r#"// Generated by schema-forge — edit freely.
 import { forwardRef } from "react"
 import { useQuery } from "@tanstack/react-query"
@@ -545,8 +547,14 @@ export type RelationSelectProps = {
   name?: string
   placeholder?: string
   disabled?: boolean
+  /** Offer a "Create new <target>" option that calls this instead of
+   *  `onChange`. The caller opens its create form and selects the result. */
+  onCreateNew?: () => void
 }
 
+/** Sentinel option value for the "Create new" entry. Never a real id. */
+export const CREATE_NEW_OPTION = "__create_new__"
+
 type RelationRow = { id: string; [key: string]: unknown }
 
 /// Pick the best display label for a fetched relation row:
@@ -566,7 +574,17 @@ function labelFor(row: RelationRow, displayField?: string): string {
 
 export const RelationSelect = forwardRef<HTMLSelectElement, RelationSelectProps>(
   function RelationSelect(
-    { target, displayField, value, onChange, onBlur, name, placeholder, disabled },
+    {
+      target,
+      displayField,
+      value,
+      onChange,
+      onBlur,
+      name,
+      placeholder,
+      disabled,
+      onCreateNew,
+    },
     ref,
   ) {
     const { data, isLoading, error } = useQuery<RelationRow[]>({
@@ -580,7 +598,13 @@ export const RelationSelect = forwardRef<HTMLSelectElement, RelationSelectProps>
         ref={ref}
         name={name}
         value={value ?? ""}
-        onChange={(e) => onChange(e.target.value)}
+        onChange={(e) => {
+          if (onCreateNew && e.target.value === CREATE_NEW_OPTION) {
+            onCreateNew()
+            return
+          }
+          onChange(e.target.value)
+        }}
         onBlur={onBlur}
         disabled={disabled || isLoading || Boolean(error)}
         className="h-9 w-full rounded-md border border-input bg-transparent px-3 text-sm"
@@ -596,6 +620,9 @@ export const RelationSelect = forwardRef<HTMLSelectElement, RelationSelectProps>
             {labelFor(row, displayField)}
           </option>
         ))}
+        {onCreateNew ? (
+          <option value={CREATE_NEW_OPTION}>+ Create new {target}…</option>
+        ) : null}
       </select>
     )
   },
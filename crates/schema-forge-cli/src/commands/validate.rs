@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crate::cli::ValidateArgs;
+use crate::commands::parse::discover_schema_files;
+use crate::diagnostic::render_concise;
+use crate::error::CliError;
+use crate::output::{OutputContext, OutputMode};
+
+/// Run the `validate` command: check .schema files without a backend.
+///
+/// Beyond syntax, this checks that relation targets exist across the whole
+/// batch (system schemas count as defined), that `@display` and
+/// `@dashboard(group_by:)` name suitable fields, and that `default(...)`
+/// values fit their field types. Each error is printed to stderr as one
+/// `file:line:col: message` line, followed by a summary.
+pub fn run(args: ValidateArgs, output: &OutputContext) -> Result<(), CliError> {
+    let files = discover_schema_files(&args.paths)?;
+
+    let mut sources = Vec::with_capacity(files.len());
+    for file in &files {
+        let source_text = std::fs::read_to_string(file).map_err(|e| CliError::Io {
+            path: file.clone(),
+            source: e,
+        })?;
+        sources.push((file.display().to_string(), source_text));
+    }
+
+    let mut defined: HashSet<String> = schema_forge_core::system_schemas::all_system_schemas()
+        .into_iter()
+        .filter_map(|dsl| schema_forge_dsl::parse(dsl).ok())
+        .flatten()
+        .map(|s| s.name.as_str().to_string())
+        .collect();
+    let mut total_schemas = 0usize;
+    let mut lines = Vec::new();
+    for (filename, source_text) in &sources {
+        match schema_forge_dsl::parse(source_text) {
+            Ok(schemas) => {
+                total_schemas += schemas.len();
+                defined.extend(schemas.iter().map(|s| s.name.as_str().to_string()));
+            }
+            Err(errors) => lines.extend(
+                errors
+                    .iter()
+                    .map(|e| render_concise(e, source_text, filename)),
+            ),
+        }
+    }
+
+    // As in `parse --check`, relation targets are only judged once every
+    // file parsed, since a broken file may be the one defining a target.
+    if lines.is_empty() {
+        for (filename, source_text) in &sources {
+            let mut errors = schema_forge_dsl::check_relation_targets(source_text, |name| {
+                defined.contains(name)
+            });
+            errors.extend(schema_forge_dsl::check_semantics(source_text));
+            lines.extend(
+                errors
+                    .iter()
+                    .map(|e| render_concise(e, source_text, filename)),
+            );
+        }
+    }
+
+    for line in &lines {
+        eprintln!("{line}");
+    }
+
+    match output.mode {
+        OutputMode::Human => {
+            let summary = format!(
+                "{total_schemas} schemas in {} files, {} errors",
+                files.len(),
+                lines.len()
+            );
+            if lines.is_empty() {
+                output.success(&summary);
+            } else {
+                output.warn(&summary);
+            }
+        }
+        OutputMode::Json => {
+            output.print_json(&serde_json::json!({
+                "files": files.len(),
+                "schemas": total_schemas,
+                "errors": lines,
+            }));
+        }
+        OutputMode::Plain => {
+            println!("{}\t{total_schemas}\t{}", files.len(), lines.len());
+        }
+    }
+
+    if lines.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::Parse {
+            errors: vec![], // individual errors already rendered
+            source_text: String::new(),
+            file: PathBuf::from("(multiple)"),
+        })
+    }
+}
@@ -0,0 +1,224 @@
+//! Schema hot-reload behind `serve --watch`.
+//!
+//! The schema directory is polled for added, removed, or modified `.schema`
+//! files. Once a burst of saves has settled, the whole directory is
+//! re-parsed and every changed schema is diffed against the running
+//! registry. Safe and confirmable plans are applied through the
+//! `ForgeActor` the same way `PUT /schemas/{name}` applies them; plans with
+//! destructive steps are logged and skipped so an edit can never drop data
+//! behind the developer's back.
+//!
+//! The `serve` router does not mount GraphQL, so there is no GraphQL schema
+//! to rebuild here; `SchemaForgeExtension::apply_schemas` covers that for
+//! embedders.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use acton_service::prelude::{ActorHandle, ActorHandleInterface};
+use schema_forge_acton::messages::{ApplyMigration, GetSchema, InsertSchema, StoreSchemaMetadata};
+use schema_forge_acton::ReplyChannel;
+use schema_forge_core::migration::{DiffEngine, MigrationSafety, MigrationStep};
+use schema_forge_core::types::SchemaDefinition;
+use tokio::sync::oneshot;
+
+use crate::commands::parse::parse_all_schemas;
+use crate::diagnostic::render_concise;
+use crate::error::CliError;
+
+/// How often the schema directory is scanned for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long the directory must stay unchanged before a reload runs, so an
+/// editor's save-rename-save sequence triggers a single reload.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Timeout for each `ForgeActor` round-trip.
+const ACTOR_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Watch `schema_dir` until the process exits, reloading changed schemas
+/// into the running `ForgeActor`.
+pub async fn watch_schemas(schema_dir: PathBuf, forge: ActorHandle) {
+    let mut seen = snapshot(&schema_dir);
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        let mut current = snapshot(&schema_dir);
+        if current == seen {
+            continue;
+        }
+        loop {
+            tokio::time::sleep(DEBOUNCE).await;
+            let next = snapshot(&schema_dir);
+            if next == current {
+                break;
+            }
+            current = next;
+        }
+        seen = current;
+        reload(&schema_dir, &forge).await;
+    }
+}
+
+/// Modification time of every `.schema` file under `dir`.
+fn snapshot(dir: &Path) -> BTreeMap<PathBuf, SystemTime> {
+    let pattern = format!("{}/**/*.schema", dir.display());
+    glob::glob(&pattern)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|path| {
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
+}
+
+/// Re-parse `schema_dir` and apply every non-destructive change.
+///
+/// A directory that fails to parse is reported and left unapplied; the
+/// server keeps running on the last good schemas.
+async fn reload(schema_dir: &Path, forge: &ActorHandle) {
+    let schemas = match parse_all_schemas(&[schema_dir.to_path_buf()]) {
+        Ok(schemas) => schemas,
+        Err(CliError::Parse {
+            errors,
+            source_text,
+            file,
+        }) => {
+            let filename = file.display().to_string();
+            for error in &errors {
+                tracing::warn!(
+                    "schema reload skipped: {}",
+                    render_concise(error, &source_text, &filename)
+                );
+            }
+            return;
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "schema reload skipped");
+            return;
+        }
+    };
+
+    for mut schema in schemas {
+        let name = schema.name.as_str().to_string();
+        let (tx, rx) = oneshot::channel();
+        forge
+            .send(GetSchema {
+                name: name.clone(),
+                reply: ReplyChannel::new(tx),
+            })
+            .await;
+        let existing = match ask_forge(rx).await {
+            Ok(existing) => existing,
+            Err(e) => {
+                tracing::warn!(schema = %name, error = %e, "schema reload failed");
+                continue;
+            }
+        };
+        let plan = match &existing {
+            Some(old) => {
+                schema.id = old.id.clone();
+                if *old == schema {
+                    continue;
+                }
+                DiffEngine::diff(old, &schema)
+            }
+            None => DiffEngine::create_new(&schema),
+        };
+        if plan.has_destructive_steps() {
+            for step in &plan.steps {
+                if step.safety() == MigrationSafety::Destructive {
+                    tracing::warn!(schema = %name, step = %step, "destructive change not applied");
+                }
+            }
+            tracing::warn!(
+                schema = %name,
+                "schema reload skipped; apply destructive changes with `schema-forge migrate --execute`"
+            );
+            continue;
+        }
+        let steps = plan.steps.len();
+        match apply(forge, schema, plan.steps).await {
+            Ok(()) => tracing::info!(schema = %name, steps, "schema reloaded"),
+            Err(e) => tracing::warn!(schema = %name, error = %e, "schema reload failed"),
+        }
+    }
+}
+
+/// Migrate, store, and register `schema`, as the schema update route does.
+async fn apply(
+    forge: &ActorHandle,
+    schema: SchemaDefinition,
+    steps: Vec<MigrationStep>,
+) -> Result<(), String> {
+    if !steps.is_empty() {
+        let (tx, rx) = oneshot::channel();
+        forge
+            .send(ApplyMigration {
+                schema_name: schema.name.clone(),
+                steps,
+                reply: ReplyChannel::new(tx),
+            })
+            .await;
+        ask_forge(rx).await?.map_err(|e| e.to_string())?;
+    }
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(StoreSchemaMetadata {
+            definition: schema.clone(),
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    ask_forge(rx).await?.map_err(|e| e.to_string())?;
+
+    let (tx, rx) = oneshot::channel();
+    forge
+        .send(InsertSchema {
+            name: schema.name.as_str().to_string(),
+            definition: schema,
+            reply: ReplyChannel::new(tx),
+        })
+        .await;
+    ask_forge(rx)
+        .await?
+        .map_err(|e| format!("Cedar policy recompile failed: {e}"))
+}
+
+async fn ask_forge<T>(rx: oneshot::Receiver<T>) -> Result<T, String> {
+    tokio::time::timeout(ACTOR_TIMEOUT, rx)
+        .await
+        .map_err(|_| "forge actor timeout".to_string())?
+        .map_err(|_| "forge actor unavailable".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_tracks_schema_files_only() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(snapshot(dir.path()).is_empty());
+
+        std::fs::write(dir.path().join("a.schema"), "schema A { name: text }").unwrap();
+        std::fs::write(dir.path().join("notes.md"), "ignored").unwrap();
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        std::fs::write(
+            dir.path().join("nested/b.schema"),
+            "schema B { name: text }",
+        )
+        .unwrap();
+
+        let files: Vec<_> = snapshot(dir.path()).into_keys().collect();
+        assert_eq!(
+            files,
+            [
+                dir.path().join("a.schema"),
+                dir.path().join("nested/b.schema")
+            ]
+        );
+    }
+}
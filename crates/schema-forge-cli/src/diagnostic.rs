@@ -1,5 +1,5 @@
 use miette::{Diagnostic, NamedSource, SourceSpan};
-use schema_forge_dsl::DslError;
+use schema_forge_dsl::{DslError, Span};
 
 /// A diagnostic wrapping a `DslError` for rich miette rendering.
 ///
@@ -41,6 +41,39 @@ pub fn dsl_error_to_diagnostic(error: &DslError, source: &str, filename: &str) -
             suggestion: Some("Check for typos or unsupported characters.".to_string()),
         },
 
+        DslError::UnexpectedCharacter { found, hint, span } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: format!(
+                "unexpected character '{found}' (U+{:04X})",
+                u32::from(*found)
+            ),
+            label: "not valid here".to_string(),
+            suggestion: Some(match hint {
+                Some(hint) => capitalize_first(hint),
+                None => "Check for typos or unsupported characters.".to_string(),
+            }),
+        },
+
+        DslError::NestedBlockComment { span } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: "nested block comment".to_string(),
+            label: "comment opened again here".to_string(),
+            suggestion: Some(
+                "Block comments do not nest; close the outer comment first or use `//` line comments."
+                    .to_string(),
+            ),
+        },
+
+        DslError::UnterminatedBlockComment { span } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: "unterminated block comment".to_string(),
+            label: "comment starts here".to_string(),
+            suggestion: Some("Close the comment with `*/`.".to_string()),
+        },
+
         DslError::UnexpectedToken {
             expected,
             found,
@@ -123,6 +156,14 @@ pub fn dsl_error_to_diagnostic(error: &DslError, source: &str, filename: &str) -
             suggestion: None,
         },
 
+        DslError::InvalidDecimalScale { text, span } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: format!("invalid decimal scale '{text}'"),
+            label: "expected an integer from 0 to 28".to_string(),
+            suggestion: Some("Use a scale such as decimal(scale: 2).".to_string()),
+        },
+
         DslError::CoreSchemaError { source, span } => SchemaDiagnostic {
             src: named_src,
             span: (span.start, span.end.saturating_sub(span.start)).into(),
@@ -150,6 +191,18 @@ pub fn dsl_error_to_diagnostic(error: &DslError, source: &str, filename: &str) -
             suggestion: Some("Remove the duplicate variant.".to_string()),
         },
 
+        DslError::InvalidPattern {
+            pattern,
+            message,
+            span,
+        } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: format!("invalid pattern \"{pattern}\""),
+            label: "not a valid regular expression".to_string(),
+            suggestion: Some(capitalize_first(message)),
+        },
+
         DslError::InvalidIntegerRange { min, max, span } => SchemaDiagnostic {
             src: named_src,
             span: (span.start, span.end.saturating_sub(span.start)).into(),
@@ -158,6 +211,115 @@ pub fn dsl_error_to_diagnostic(error: &DslError, source: &str, filename: &str) -
             suggestion: Some(format!("Swap the values: integer(min: {max}, max: {min})")),
         },
 
+        DslError::AnnotationWithoutSchema { span } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: "annotation is not followed by a schema".to_string(),
+            label: "not attached to any schema".to_string(),
+            suggestion: Some(
+                "Move the annotation above the `schema` keyword of the schema it belongs to."
+                    .to_string(),
+            ),
+        },
+
+        DslError::MissingSchemaKeyword { name, span } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: format!("missing `schema` keyword before '{name}'"),
+            label: "expected `schema` here".to_string(),
+            suggestion: Some(format!("Write `schema {name} {{ ... }}`.")),
+        },
+
+        DslError::FieldOutsideSchema { name, span } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: format!("field '{name}' is outside any schema body"),
+            label: "field declared at top level".to_string(),
+            suggestion: Some(
+                "Move the field inside a schema's braces; check for a `}` that closes the schema too early."
+                    .to_string(),
+            ),
+        },
+
+        DslError::StrayTopLevelToken { found, span } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: format!("unexpected {found} between schema definitions"),
+            label: "expected `schema` or an annotation".to_string(),
+            suggestion: Some("Remove the stray token.".to_string()),
+        },
+
+        DslError::UnknownRelationTarget {
+            field,
+            target,
+            span,
+        } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: format!("field '{field}' relates to undefined schema '{target}'"),
+            label: "no schema with this name".to_string(),
+            suggestion: Some(format!(
+                "Define `schema {target}` in one of the parsed files, or fix the name."
+            )),
+        },
+
+        DslError::UnknownDisplayField {
+            schema,
+            field,
+            span,
+        } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: format!("@display field '{field}' is not a field of schema '{schema}'"),
+            label: "unknown field".to_string(),
+            suggestion: Some(format!(
+                "Name one of the fields declared in `schema {schema}`."
+            )),
+        },
+
+        DslError::InvalidDashboardGroupBy {
+            schema,
+            field,
+            span,
+        } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: format!(
+                "@dashboard group_by '{field}' is not an enum or single relation field of schema '{schema}'"
+            ),
+            label: "not an enum or single relation field".to_string(),
+            suggestion: Some(
+                "Dashboards group by an `enum` field or a `-> Target` relation.".to_string(),
+            ),
+        },
+
+        DslError::DefaultTypeMismatch {
+            field,
+            value,
+            field_type,
+            span,
+        } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: format!("default {value} does not fit field '{field}' of type {field_type}"),
+            label: "mismatched default".to_string(),
+            suggestion: None,
+        },
+
+        DslError::UnknownComputedReference {
+            field,
+            reference,
+            span,
+        } => SchemaDiagnostic {
+            src: named_src,
+            span: (span.start, span.end.saturating_sub(span.start)).into(),
+            message: format!("@computed expression on field '{field}' reads unknown field '{reference}'"),
+            label: "unknown field".to_string(),
+            suggestion: Some(
+                "Read stored fields, or computed fields declared above this one.".to_string(),
+            ),
+        },
+
         // Catch future non_exhaustive variants
         _ => SchemaDiagnostic {
             src: named_src,
@@ -169,6 +331,15 @@ pub fn dsl_error_to_diagnostic(error: &DslError, source: &str, filename: &str) -
     }
 }
 
+/// Render one error as a single `file:line:col: message` line, the compact
+/// form `parse --check` prints for CI logs.
+pub fn render_concise(error: &DslError, source: &str, filename: &str) -> String {
+    let diagnostic = dsl_error_to_diagnostic(error, source, filename);
+    let offset = diagnostic.span.offset();
+    let (line, col) = Span::new(offset, offset).line_col(source);
+    format!("{filename}:{line}:{col}: {}", diagnostic.message)
+}
+
 /// Render all parse errors for a file using miette.
 ///
 /// Returns a vector of `miette::Report` that can be printed to stderr.
@@ -186,6 +357,16 @@ pub fn render_diagnostics(
         .collect()
 }
 
+/// Uppercase the first character so a lowercase lexer hint reads as a
+/// sentence in the `help:` line.
+fn capitalize_first(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 /// Simple PascalCase converter: capitalizes first letter of each word boundary.
 fn to_pascal_case(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
@@ -218,7 +399,6 @@ fn to_snake_case(s: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use schema_forge_dsl::Span;
 
     #[test]
     fn invalid_token_diagnostic() {
@@ -230,6 +410,23 @@ mod tests {
         assert!(diag.suggestion.is_some());
     }
 
+    #[test]
+    fn unexpected_character_diagnostic_carries_hint() {
+        let source = "name: text default(\u{201C}x\")";
+        let start = source.find('\u{201C}').unwrap();
+        let err = DslError::UnexpectedCharacter {
+            found: '\u{201C}',
+            hint: Some("did you mean a straight quote '\"'?".into()),
+            span: Span::new(start, start + 3),
+        };
+        let diag = dsl_error_to_diagnostic(&err, source, "test.schema");
+        assert!(diag.message.contains("U+201C"));
+        assert_eq!(
+            diag.suggestion.as_deref(),
+            Some("Did you mean a straight quote '\"'?")
+        );
+    }
+
     #[test]
     fn unexpected_token_diagnostic() {
         let err = DslError::UnexpectedToken {
@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 
 use schema_forge_backend::BackendError;
+use schema_forge_core::error::SchemaError;
+use schema_forge_core::migration::MigrationError;
 use schema_forge_dsl::DslError;
 
 /// Exit codes for the CLI process.
@@ -72,6 +74,24 @@ pub enum CliError {
     #[error("destructive changes require --force in non-interactive mode")]
     RequiresForce,
 
+    /// An applied schema's `@version` does not advance the stored one.
+    #[error("{0} (use --force to apply anyway)")]
+    VersionNotIncreased(SchemaError),
+
+    /// A migration plan could not be built or ordered.
+    #[error("migration error: {0}")]
+    Migration(#[from] MigrationError),
+
+    /// A stored schema changed after a `--plan-only` plan was generated.
+    #[error(
+        "schema '{schema}' changed since the plan was generated; regenerate it with `migrate --plan-only`"
+    )]
+    PlanDrift { schema: String },
+
+    /// `diff` found destructive steps between the local and stored schemas.
+    #[error("{steps} destructive migration steps pending")]
+    DestructiveChanges { steps: usize },
+
     /// HTTP server errors.
     #[error("server error: {message}")]
     Server { message: String },
@@ -87,7 +107,10 @@ impl CliError {
         match self {
             Self::Parse { .. } => ExitCode::ParseError,
             Self::Backend(BackendError::ConnectionError { .. }) => ExitCode::ConnectionError,
-            Self::Backend(BackendError::MigrationFailed { .. }) => ExitCode::MigrationError,
+            Self::Backend(BackendError::MigrationFailed { .. })
+            | Self::Migration(_)
+            | Self::PlanDrift { .. }
+            | Self::DestructiveChanges { .. } => ExitCode::MigrationError,
             Self::Backend(_) => ExitCode::GeneralError,
             Self::Config { .. } | Self::NoSchemaFiles { .. } => ExitCode::InvalidArguments,
             Self::Server { .. } => ExitCode::ServerError,
@@ -96,6 +119,7 @@ impl CliError {
             | Self::SchemaNotFound { .. }
             | Self::DirectoryExists { .. }
             | Self::RequiresForce
+            | Self::VersionNotIncreased(_)
             | Self::Other(_) => ExitCode::GeneralError,
         }
     }
@@ -173,6 +197,15 @@ mod tests {
         assert_eq!(err.exit_code(), ExitCode::MigrationError);
     }
 
+    #[test]
+    fn plan_drift_exit_code() {
+        let err = CliError::PlanDrift {
+            schema: "Contact".into(),
+        };
+        assert_eq!(err.exit_code(), ExitCode::MigrationError);
+        assert!(err.to_string().contains("Contact"));
+    }
+
     #[test]
     fn config_error_exit_code() {
         let err = CliError::Config {
@@ -17,8 +17,11 @@ async fn main() {
     let result = match cli.command {
         cli::Commands::Init(args) => commands::init::run(args, &cli.global, &output).await,
         cli::Commands::Parse(args) => commands::parse::run(args, &cli.global, &output).await,
+        cli::Commands::Validate(args) => commands::validate::run(args, &output),
         cli::Commands::Apply(args) => commands::apply::run(args, &cli.global, &output).await,
         cli::Commands::Migrate(args) => commands::migrate::run(args, &cli.global, &output).await,
+        cli::Commands::Diff(args) => commands::diff::run(args, &cli.global, &output).await,
+        cli::Commands::Seed(args) => commands::seed::run(args, &cli.global, &output).await,
         cli::Commands::Serve(args) => commands::serve::run(args, &cli.global, &output).await,
         cli::Commands::Export { command } => {
             commands::export::run(command, &cli.global, &output).await
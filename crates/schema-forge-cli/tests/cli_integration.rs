@@ -405,6 +405,50 @@ fn parse_directory_with_schema_files() {
         .success();
 }
 
+#[test]
+fn parse_check_clean_directory_is_silent() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("person.schema"),
+        "schema Person {\n    name: text required\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("project.schema"),
+        "schema Project {\n    owner: -> Person\n    creator: -> User\n}\n",
+    )
+    .unwrap();
+
+    schema_forge()
+        .args(["parse", "--check", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn parse_check_reports_dangling_relation() {
+    let dir = TempDir::new().unwrap();
+    let project = dir.path().join("project.schema");
+    fs::write(
+        &project,
+        "schema Project {\n    title: text\n    owner: -> Persn\n}\n",
+    )
+    .unwrap();
+
+    schema_forge()
+        .args(["parse", "--check", dir.path().to_str().unwrap()])
+        .assert()
+        .failure()
+        .code(3)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains(format!(
+            "{}:3:15: field 'Project.owner' relates to undefined schema 'Persn'",
+            project.display()
+        )));
+}
+
 #[test]
 fn parse_empty_directory_fails() {
     let dir = TempDir::new().unwrap();
@@ -0,0 +1,55 @@
+import { test, expect, type Page } from "@playwright/test"
+
+// `@format("relative")` checks for the generated formatters module. The
+// module is imported through the Vite dev server, so it runs with the
+// browser's own Intl data; no login or backend state is needed.
+
+const NOW = "2026-03-15T12:00:00Z"
+const MINUTE = 60_000
+const HOUR = 60 * MINUTE
+const DAY = 24 * HOUR
+
+// Renders `formatRelative(NOW + offset, NOW)` for every offset, in order.
+async function relative(page: Page, offsets: number[]) {
+  await page.goto("/")
+  return page.evaluate(
+    async ({ now, offsets }) => {
+      const { formatRelative } = await import("/src/generated/formatters.ts")
+      const base = new Date(now)
+      return offsets.map((ms) =>
+        formatRelative(new Date(base.getTime() + ms).toISOString(), base),
+      )
+    },
+    { now: NOW, offsets },
+  )
+}
+
+test("relative times read the same either side of now", async ({ page }) => {
+  const offsets = [30_000, 5 * MINUTE, 3 * HOUR, 2 * DAY]
+  const past = await relative(page, offsets.map((ms) => -ms))
+  const future = await relative(page, offsets)
+  expect(past).toEqual(["30 seconds ago", "5 minutes ago", "3 hours ago", "2 days ago"])
+  expect(future).toEqual(["in 30 seconds", "in 5 minutes", "in 3 hours", "in 2 days"])
+})
+
+test("relative times a week or more away render as dates", async ({ page }) => {
+  const [before, after] = await relative(page, [-8 * DAY, 8 * DAY])
+  expect(before).toBe("Mar 07, 2026")
+  expect(after).toBe("Mar 23, 2026")
+})
+
+test("relative accepts Date values and rejects unparseable ones", async ({ page }) => {
+  await page.goto("/")
+  const [fromDate, invalid, empty] = await page.evaluate(async (now) => {
+    const { formatRelative } = await import("/src/generated/formatters.ts")
+    const base = new Date(now)
+    return [
+      formatRelative(new Date(base.getTime() - 3 * 3_600_000), base),
+      formatRelative("not a date", base),
+      formatRelative("", base),
+    ]
+  }, NOW)
+  expect(fromDate).toBe("3 hours ago")
+  expect(invalid).toBe("—")
+  expect(empty).toBe("—")
+})
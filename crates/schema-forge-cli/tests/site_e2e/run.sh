@@ -3,7 +3,7 @@
 #
 # Boots a fresh schemaforge backend against an in-memory SurrealDB, generates
 # a React site from tests/site_e2e/demo.schema, starts the Vite dev server,
-# and runs the Playwright spec at tests/site_e2e/playwright/tests/smoke.spec.ts.
+# and runs the Playwright specs under tests/site_e2e/playwright/tests/.
 # Tears down both child processes on exit.
 #
 # Intended to run both locally and from .github/workflows/site-e2e.yml. Keep
@@ -633,3 +633,72 @@ fn missing_schema_name_errors_clearly() {
     let err = String::from_utf8_lossy(&output.get_output().stderr).to_string();
     assert!(err.contains("not found"), "stderr: {err}");
 }
+
+/// The admin relation picker offers "Create new <Target>": the vendored
+/// select exposes the hook, the admin renderer opens the target's create
+/// form in a modal, and the post-create helper hands the new id back to
+/// the picker for selection.
+#[test]
+fn admin_relation_picker_supports_inline_create() {
+    let tmp = TempDir::new().unwrap();
+    let schema_dir = tmp.path().join("schemas");
+    let out_dir = tmp.path().join("site");
+    write_schemas(&schema_dir, V0_EMPLOYEE);
+
+    run_generate(&schema_dir, &out_dir, "Employee", &[])
+        .assert()
+        .success();
+
+    let select = fs::read_to_string(out_dir.join("src/components/ui/relation-select.tsx")).unwrap();
+    assert!(select.contains("onCreateNew?: () => void"));
+    assert!(select.contains("CREATE_NEW_OPTION"));
+    assert!(select.contains("+ Create new {target}"));
+
+    let api = fs::read_to_string(out_dir.join("src/admin/api-client.ts")).unwrap();
+    let helper = slice_between(
+        &api,
+        "export async function createEntityForPicker(",
+        "export async function updateEntity(",
+    )
+    .expect("createEntityForPicker must be generated");
+    assert!(helper.contains("createEntity(schema, body)"));
+    assert!(helper.contains("id: row.id"));
+
+    let renderer = fs::read_to_string(out_dir.join("src/admin/field-renderer.tsx")).unwrap();
+    assert!(renderer.contains("<RelationPicker"));
+    assert!(renderer.contains("export function InlineCreateDialog"));
+    assert!(renderer.contains("permissions?.create"));
+    assert!(renderer.contains("queryKey: [\"relation-options\", target]"));
+    assert!(renderer.contains("onCreated(created.id)"));
+    assert!(renderer.contains("onChange(id)"));
+
+    // The edit page shares the form helpers instead of keeping its own copies.
+    let edit = fs::read_to_string(out_dir.join("src/admin/entity-edit.tsx")).unwrap();
+    assert!(edit.contains("defaultValuesFor,"));
+    assert!(!edit.contains("function isBlank"));
+}
+
+/// The admin edit form sends the loaded version as `If-Match` and turns a
+/// 412 into a friendly message instead of a raw API error.
+#[test]
+fn admin_edit_form_guards_against_concurrent_edits() {
+    let tmp = TempDir::new().unwrap();
+    let schema_dir = tmp.path().join("schemas");
+    let out_dir = tmp.path().join("site");
+    write_schemas(&schema_dir, V0_EMPLOYEE);
+
+    run_generate(&schema_dir, &out_dir, "Employee", &[])
+        .assert()
+        .success();
+
+    let api = fs::read_to_string(out_dir.join("src/admin/api-client.ts")).unwrap();
+    assert!(api.contains("flat.__version = env.version"));
+    assert!(api.contains("headers[\"If-Match\"] = `\"${version}\"`"));
+    assert!(api.contains("export function isConflictError("));
+
+    let edit = fs::read_to_string(out_dir.join("src/admin/entity-edit.tsx")).unwrap();
+    assert!(edit.contains("updateEntity(schema!, id!, body, version)"));
+    assert!(edit.contains("<input type=\"hidden\" name=\"__version\""));
+    assert!(edit.contains("isConflictError(err)"));
+    assert!(edit.contains("Someone else changed this record"));
+}
@@ -22,8 +22,20 @@ pub enum SchemaError {
     DuplicateEnumVariant(String),
     /// Integer constraint min > max.
     InvalidIntegerRange { min: i64, max: i64 },
+    /// Float constraint min > max.
+    InvalidFloatRange { min: String, max: String },
+    /// Array constraint min_items > max_items.
+    InvalidArrayLength { min: u32, max: u32 },
     /// Float string could not be parsed.
     InvalidFloatString(String),
+    /// Duration string is not a valid ISO-8601 duration.
+    InvalidDuration(String),
+    /// Decimal string is not a plain decimal number.
+    InvalidDecimal(String),
+    /// Decimal value has more significant fractional digits than the field's scale.
+    DecimalScaleExceeded { value: String, scale: u8 },
+    /// UUID string is not in the hyphenated 8-4-4-4-12 form.
+    InvalidUuid(String),
     /// Duplicate field name in a schema or composite.
     DuplicateFieldName(String),
     /// Duplicate annotation kind.
@@ -32,6 +44,24 @@ pub enum SchemaError {
     EmptyFields,
     /// MIME pattern string is empty or malformed.
     InvalidMimePattern(String),
+    /// `@ref_label` template is malformed.
+    InvalidRefLabel { template: String, reason: String },
+    /// `@computed` expression does not parse.
+    InvalidComputedExpr { expr: String, reason: String },
+    /// `@id_prefix` value is not a valid TypeID prefix.
+    InvalidIdPrefix { prefix: String, reason: String },
+    /// `@list(page_size: ..., max_page_size: ...)` sizes are zero or out of
+    /// order.
+    InvalidPageSize { reason: String },
+    /// An `@index(fields: [...])` annotation names no fields, repeats one,
+    /// names a field the schema does not store, or duplicates another index.
+    InvalidIndex { reason: String },
+    /// An incoming schema's `@version` is not greater than the stored one.
+    VersionNotIncreased {
+        schema: String,
+        incoming: u32,
+        stored: u32,
+    },
 }
 
 impl fmt::Display for SchemaError {
@@ -72,9 +102,37 @@ impl fmt::Display for SchemaError {
             Self::InvalidIntegerRange { min, max } => {
                 write!(f, "invalid integer range: min ({min}) > max ({max})")
             }
+            Self::InvalidFloatRange { min, max } => {
+                write!(f, "invalid float range: min ({min}) > max ({max})")
+            }
+            Self::InvalidArrayLength { min, max } => {
+                write!(f, "invalid array length: min ({min}) > max ({max})")
+            }
             Self::InvalidFloatString(s) => {
                 write!(f, "invalid float string '{s}': must be a valid f64")
             }
+            Self::InvalidDuration(s) => {
+                write!(
+                    f,
+                    "invalid duration '{s}': must be an ISO-8601 duration such as P3DT4H \
+                     or shorthand such as 2h 30m"
+                )
+            }
+            Self::InvalidDecimal(s) => {
+                write!(f, "invalid decimal '{s}': must be a number such as 19.99")
+            }
+            Self::DecimalScaleExceeded { value, scale } => {
+                write!(
+                    f,
+                    "decimal '{value}' has more than {scale} fractional digit(s)"
+                )
+            }
+            Self::InvalidUuid(s) => {
+                write!(
+                    f,
+                    "invalid UUID '{s}': must be hyphenated hex such as 67e55044-10b1-426f-9247-bb680e5fe0c8"
+                )
+            }
             Self::DuplicateFieldName(n) => write!(f, "duplicate field name '{n}'"),
             Self::DuplicateAnnotation(a) => write!(f, "duplicate annotation '{a}'"),
             Self::EmptyFields => write!(f, "schema must have at least one field"),
@@ -84,6 +142,28 @@ impl fmt::Display for SchemaError {
                     "invalid MIME pattern '{s}': must be 'type/subtype' or 'type/*'"
                 )
             }
+            Self::InvalidRefLabel { template, reason } => {
+                write!(f, "invalid @ref_label template \"{template}\": {reason}")
+            }
+            Self::InvalidComputedExpr { expr, reason } => {
+                write!(f, "invalid @computed expression \"{expr}\": {reason}")
+            }
+            Self::InvalidIdPrefix { prefix, reason } => {
+                write!(f, "invalid @id_prefix \"{prefix}\": {reason}")
+            }
+            Self::InvalidPageSize { reason } => write!(f, "invalid @list page size: {reason}"),
+            Self::InvalidIndex { reason } => write!(f, "invalid @index: {reason}"),
+            Self::VersionNotIncreased {
+                schema,
+                incoming,
+                stored,
+            } => {
+                write!(
+                    f,
+                    "schema '{schema}' @version({incoming}) does not advance the stored \
+                     @version({stored}); bump the version or the change would overwrite newer metadata"
+                )
+            }
         }
     }
 }
@@ -125,6 +205,17 @@ mod tests {
                 SchemaError::InvalidIntegerRange { min: 10, max: 5 },
                 "invalid integer range: min (10) > max (5)",
             ),
+            (
+                SchemaError::InvalidFloatRange {
+                    min: "2.5".into(),
+                    max: "1".into(),
+                },
+                "invalid float range: min (2.5) > max (1)",
+            ),
+            (
+                SchemaError::InvalidArrayLength { min: 3, max: 1 },
+                "invalid array length: min (3) > max (1)",
+            ),
             (
                 SchemaError::InvalidFloatString("abc".into()),
                 "invalid float string 'abc'",
@@ -137,6 +228,63 @@ mod tests {
                 SchemaError::DuplicateAnnotation("version".into()),
                 "duplicate annotation 'version'",
             ),
+            (
+                SchemaError::InvalidRefLabel {
+                    template: "{name".into(),
+                    reason: "unclosed '{'".into(),
+                },
+                "invalid @ref_label template \"{name\": unclosed '{'",
+            ),
+            (
+                SchemaError::InvalidComputedExpr {
+                    expr: "price -".into(),
+                    reason: "unexpected end of expression".into(),
+                },
+                "invalid @computed expression \"price -\": unexpected end of expression",
+            ),
+            (
+                SchemaError::InvalidIdPrefix {
+                    prefix: "Inv".into(),
+                    reason: "must contain only lowercase letters and '_'".into(),
+                },
+                "invalid @id_prefix \"Inv\": must contain only lowercase letters and '_'",
+            ),
+            (
+                SchemaError::InvalidPageSize {
+                    reason: "page_size 500 exceeds max_page_size 200".into(),
+                },
+                "invalid @list page size: page_size 500 exceeds max_page_size 200",
+            ),
+            (
+                SchemaError::InvalidIndex {
+                    reason: "field 'stage' is listed twice".into(),
+                },
+                "invalid @index: field 'stage' is listed twice",
+            ),
+            (
+                SchemaError::VersionNotIncreased {
+                    schema: "Contact".into(),
+                    incoming: 1,
+                    stored: 2,
+                },
+                "schema 'Contact' @version(1) does not advance the stored @version(2)",
+            ),
+            (
+                SchemaError::InvalidDuration("3 days".into()),
+                "invalid duration '3 days'",
+            ),
+            (
+                SchemaError::InvalidDecimal("12,50".into()),
+                "invalid decimal '12,50'",
+            ),
+            (
+                SchemaError::DecimalScaleExceeded {
+                    value: "1.005".into(),
+                    scale: 2,
+                },
+                "decimal '1.005' has more than 2 fractional digit(s)",
+            ),
+            (SchemaError::InvalidUuid("abc".into()), "invalid UUID 'abc'"),
             (
                 SchemaError::EmptyFields,
                 "schema must have at least one field",
@@ -0,0 +1,335 @@
+//! JSON Schema export of schema definitions.
+//!
+//! The generated document describes the JSON body the entity routes accept
+//! for a schema, so a browser form can validate the same rules the backend
+//! enforces: required fields, text length and pattern, integer bounds,
+//! enum variants, and array sizes.
+
+use serde_json::{json, Map, Value};
+
+use crate::types::{Cardinality, DefaultValue, FieldDefinition, FieldType, SchemaDefinition};
+
+/// The JSON Schema dialect of every exported document.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Build a JSON Schema (draft 2020-12) describing entities of `def`.
+///
+/// Fields with the `required` modifier populate the top-level `required`
+/// list; derived inverse-relation fields and `@computed` fields are marked
+/// `readOnly` and never required, since writes to them are rejected.
+pub fn to_json_schema(def: &SchemaDefinition) -> Value {
+    let mut schema = Map::new();
+    schema.insert("$schema".into(), json!(JSON_SCHEMA_DIALECT));
+    schema.insert("title".into(), json!(def.name.as_str()));
+    if let Some(doc) = &def.doc {
+        schema.insert("description".into(), json!(doc));
+    }
+    if let Value::Object(object) = object_schema(&def.fields) {
+        schema.extend(object);
+    }
+    Value::Object(schema)
+}
+
+fn object_schema(fields: &[FieldDefinition]) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for field in fields {
+        properties.insert(field.name.as_str().to_string(), field_schema(field));
+        if field.is_required() && field.is_stored() {
+            required.push(json!(field.name.as_str()));
+        }
+    }
+
+    let mut schema = json!({ "type": "object", "properties": properties });
+    if !required.is_empty() {
+        schema["required"] = Value::Array(required);
+    }
+    schema
+}
+
+fn field_schema(field: &FieldDefinition) -> Value {
+    let mut schema = type_schema(&field.field_type);
+    if let Some(doc) = &field.doc {
+        schema["description"] = json!(doc);
+    }
+    if let Some(default) = field.default_value().and_then(default_json) {
+        schema["default"] = default;
+    }
+    if !field.is_stored() {
+        schema["readOnly"] = Value::Bool(true);
+    }
+    if field.deprecation().is_some() {
+        schema["deprecated"] = Value::Bool(true);
+    }
+    schema
+}
+
+fn type_schema(field_type: &FieldType) -> Value {
+    match field_type {
+        FieldType::Text(c) => {
+            let mut schema = json!({ "type": "string" });
+            if let Some(max) = c.max_length {
+                schema["maxLength"] = json!(max);
+            }
+            if let Some(pattern) = &c.pattern {
+                schema["pattern"] = json!(pattern);
+            }
+            schema
+        }
+        FieldType::RichText(_) => json!({ "type": "string", "contentMediaType": "text/html" }),
+        FieldType::Integer(c) => {
+            let mut schema = json!({ "type": "integer" });
+            if let Some(min) = c.min {
+                schema["minimum"] = json!(min);
+            }
+            if let Some(max) = c.max {
+                schema["maximum"] = json!(max);
+            }
+            schema
+        }
+        FieldType::Float(c) => {
+            let mut schema = json!({ "type": "number" });
+            if let Some(min) = c.min_value() {
+                schema["minimum"] = json!(min);
+            }
+            if let Some(max) = c.max_value() {
+                schema["maximum"] = json!(max);
+            }
+            schema
+        }
+        // Decimals are accepted as numbers or as exact decimal strings.
+        FieldType::Decimal { .. } => json!({ "type": ["string", "number"] }),
+        FieldType::Boolean => json!({ "type": "boolean" }),
+        FieldType::DateTime => json!({ "type": "string", "format": "date-time" }),
+        FieldType::Duration => json!({ "type": "string", "format": "duration" }),
+        FieldType::Uuid => json!({ "type": "string", "format": "uuid" }),
+        FieldType::Enum(variants) => json!({ "type": "string", "enum": variants.as_slice() }),
+        FieldType::Json => json!({}),
+        FieldType::Relation {
+            cardinality: Cardinality::One,
+            ..
+        } => json!({ "type": "string" }),
+        FieldType::Relation {
+            cardinality: Cardinality::Many,
+            ..
+        } => json!({ "type": "array", "items": { "type": "string" } }),
+        FieldType::Array(element, c) => {
+            let mut schema = json!({ "type": "array", "items": type_schema(element) });
+            if let Some(min) = c.min_items {
+                schema["minItems"] = json!(min);
+            }
+            if let Some(max) = c.max_items {
+                schema["maxItems"] = json!(max);
+            }
+            schema
+        }
+        FieldType::Composite(fields) => object_schema(fields),
+        FieldType::File(_) => json!({ "type": "object" }),
+    }
+}
+
+/// A default a client can prefill. Sequences are assigned by the backend
+/// on create and have no literal value.
+fn default_json(value: &DefaultValue) -> Option<Value> {
+    match value {
+        DefaultValue::String(s) => Some(json!(s)),
+        DefaultValue::Integer(i) => Some(json!(i)),
+        DefaultValue::Float(s) => s.parse::<f64>().ok().map(|f| json!(f)),
+        DefaultValue::Boolean(b) => Some(json!(b)),
+        DefaultValue::Sequence { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        ArrayConstraints, EnumVariants, FieldModifier, FieldName, FloatConstraints,
+        IntegerConstraints, SchemaId, SchemaName, TextConstraints,
+    };
+
+    fn field(name: &str, field_type: FieldType, required: bool) -> FieldDefinition {
+        let modifiers = if required {
+            vec![FieldModifier::Required]
+        } else {
+            Vec::new()
+        };
+        FieldDefinition::with_modifiers(FieldName::new(name).unwrap(), field_type, modifiers)
+    }
+
+    fn schema(fields: Vec<FieldDefinition>) -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            fields,
+            Vec::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn maps_field_types_and_constraints() {
+        let def = schema(vec![
+            field(
+                "name",
+                FieldType::Text(TextConstraints::with_max_length(80)),
+                true,
+            ),
+            field(
+                "age",
+                FieldType::Integer(IntegerConstraints::with_range(0, 150).unwrap()),
+                false,
+            ),
+            field(
+                "rating",
+                FieldType::Float(FloatConstraints::with_bounds(Some("0"), Some("4.5")).unwrap()),
+                false,
+            ),
+            field(
+                "status",
+                FieldType::Enum(
+                    EnumVariants::new(vec!["Active".into(), "Archived".into()]).unwrap(),
+                ),
+                true,
+            ),
+            field("vip", FieldType::Boolean, false),
+            field("met_at", FieldType::DateTime, false),
+            field(
+                "company",
+                FieldType::Relation {
+                    target: SchemaName::new("Company").unwrap(),
+                    cardinality: Cardinality::One,
+                },
+                false,
+            ),
+            field(
+                "tags",
+                FieldType::Array(
+                    Box::new(FieldType::Text(TextConstraints::unconstrained())),
+                    ArrayConstraints::with_bounds(Some(1), Some(5)).unwrap(),
+                ),
+                false,
+            ),
+        ]);
+
+        let schema = to_json_schema(&def);
+        assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT);
+        assert_eq!(schema["title"], "Contact");
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["required"], json!(["name", "status"]));
+
+        let props = &schema["properties"];
+        assert_eq!(props["name"], json!({ "type": "string", "maxLength": 80 }));
+        assert_eq!(
+            props["age"],
+            json!({ "type": "integer", "minimum": 0, "maximum": 150 })
+        );
+        assert_eq!(
+            props["rating"],
+            json!({ "type": "number", "minimum": 0.0, "maximum": 4.5 })
+        );
+        assert_eq!(
+            props["status"],
+            json!({ "type": "string", "enum": ["Active", "Archived"] })
+        );
+        assert_eq!(props["vip"], json!({ "type": "boolean" }));
+        assert_eq!(
+            props["met_at"],
+            json!({ "type": "string", "format": "date-time" })
+        );
+        assert_eq!(props["company"], json!({ "type": "string" }));
+        assert_eq!(
+            props["tags"],
+            json!({ "type": "array", "items": { "type": "string" }, "minItems": 1, "maxItems": 5 })
+        );
+    }
+
+    #[test]
+    fn composite_fields_nest_their_own_required_list() {
+        let def = schema(vec![field(
+            "address",
+            FieldType::Composite(vec![
+                field(
+                    "street",
+                    FieldType::Text(TextConstraints::unconstrained()),
+                    true,
+                ),
+                field(
+                    "zip",
+                    FieldType::Text(TextConstraints::unconstrained()),
+                    false,
+                ),
+            ]),
+            false,
+        )]);
+
+        let schema = to_json_schema(&def);
+        assert!(schema.get("required").is_none());
+        assert_eq!(
+            schema["properties"]["address"],
+            json!({
+                "type": "object",
+                "properties": {
+                    "street": { "type": "string" },
+                    "zip": { "type": "string" },
+                },
+                "required": ["street"],
+            })
+        );
+    }
+
+    #[test]
+    fn defaults_are_prefilled_except_sequences() {
+        let def = schema(vec![
+            FieldDefinition::with_modifiers(
+                FieldName::new("score").unwrap(),
+                FieldType::Float(FloatConstraints::unconstrained()),
+                vec![FieldModifier::Default {
+                    value: DefaultValue::float("0.5").unwrap(),
+                }],
+            ),
+            FieldDefinition::with_modifiers(
+                FieldName::new("number").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![FieldModifier::Default {
+                    value: DefaultValue::Sequence {
+                        prefix: "C-".into(),
+                        start: 0,
+                    },
+                }],
+            ),
+        ]);
+
+        let props = &to_json_schema(&def)["properties"];
+        assert_eq!(props["score"], json!({ "type": "number", "default": 0.5 }));
+        assert_eq!(props["number"], json!({ "type": "string" }));
+    }
+
+    #[test]
+    fn computed_fields_are_read_only() {
+        use crate::types::{ComputedExpr, FieldAnnotation};
+
+        let def = schema(vec![
+            field(
+                "price",
+                FieldType::Integer(IntegerConstraints::unconstrained()),
+                true,
+            ),
+            FieldDefinition::with_annotations(
+                FieldName::new("margin").unwrap(),
+                FieldType::Integer(IntegerConstraints::unconstrained()),
+                vec![FieldModifier::Required],
+                vec![FieldAnnotation::Computed {
+                    expr: ComputedExpr::new("price * 2").unwrap(),
+                }],
+            ),
+        ]);
+
+        let schema = to_json_schema(&def);
+        assert_eq!(schema["required"], json!(["price"]));
+        assert_eq!(
+            schema["properties"]["margin"],
+            json!({ "type": "integer", "readOnly": true })
+        );
+    }
+}
@@ -1,5 +1,6 @@
 pub mod error;
 pub mod inverse_relations;
+pub mod json_schema;
 pub mod migration;
 pub mod query;
 pub mod system_schemas;
@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::str::FromStr;
 
@@ -7,8 +8,8 @@ use mti::prelude::{MagicTypeId, MagicTypeIdExt, V7};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::types::{
-    Annotation, Cardinality, DefaultValue, DynamicValue, FieldDefinition, FieldModifier, FieldName,
-    FieldType, SchemaId, SchemaName,
+    Annotation, Cardinality, DefaultValue, DynamicValue, EnumVariants, FieldDefinition,
+    FieldModifier, FieldName, FieldType, SchemaId, SchemaName, TextConstraints,
 };
 
 // ---------------------------------------------------------------------------
@@ -112,8 +113,29 @@ pub enum ValueTransform {
     IntegerToFloat,
     /// Convert float to integer (truncation).
     FloatToInteger,
+    /// Convert float to an exact decimal, rounded to `scale` fractional digits.
+    FloatToDecimal { scale: u8 },
     /// Convert any scalar to its text representation.
     ToString,
+    /// Parse text as a UUID. Confirmation is required because a malformed
+    /// stored value fails the migration.
+    ParseUuid,
+    /// Cut text values longer than `max_length` characters down to it.
+    /// Confirmation is required because the cut characters are lost.
+    Truncate { max_length: u32 },
+    /// Parse text as an integer, float, or decimal. Blank values become
+    /// null; any other unparseable value fails the migration.
+    ParseNumber,
+    /// Rewrite text values found in `mapping`. Other values are kept when
+    /// the new type accepts them (any text, or a variant of the new enum);
+    /// the rest become `default`, or fail the migration when no default is
+    /// given.
+    MapText {
+        #[serde(default)]
+        mapping: BTreeMap<String, String>,
+        #[serde(default)]
+        default: Option<String>,
+    },
     /// Set all existing values to a specific default.
     SetDefault { value: DefaultValue },
     /// Set all existing values to null.
@@ -126,7 +148,23 @@ impl fmt::Display for ValueTransform {
             Self::Identity => write!(f, "identity"),
             Self::IntegerToFloat => write!(f, "integer_to_float"),
             Self::FloatToInteger => write!(f, "float_to_integer"),
+            Self::FloatToDecimal { scale } => write!(f, "float_to_decimal(scale: {scale})"),
             Self::ToString => write!(f, "to_string"),
+            Self::ParseUuid => write!(f, "parse_uuid"),
+            Self::ParseNumber => write!(f, "parse_number"),
+            Self::Truncate { max_length } => write!(f, "truncate(max: {max_length})"),
+            Self::MapText { mapping, default } => {
+                let pairs: Vec<String> = mapping
+                    .iter()
+                    .map(|(from, to)| format!("{from} -> {to}"))
+                    .collect();
+                write!(
+                    f,
+                    "map_text([{}], unmapped -> {})",
+                    pairs.join(", "),
+                    default.as_deref().unwrap_or("fail")
+                )
+            }
             Self::SetDefault { value } => write!(f, "set_default({value})"),
             Self::SetNull => write!(f, "set_null"),
         }
@@ -165,10 +203,47 @@ pub enum MigrationStep {
         new_type: FieldType,
         transform: ValueTransform,
     },
+    /// Add a variant to an enum field. `variants` is the field's full
+    /// variant list once this step has run.
+    AddEnumVariant {
+        field: FieldName,
+        variant: String,
+        variants: EnumVariants,
+    },
+    /// Remove a variant from an enum field. Stored values naming it are
+    /// rewritten to `fallback`, or to null when no fallback is given.
+    RemoveEnumVariant {
+        field: FieldName,
+        variant: String,
+        fallback: Option<String>,
+        variants: EnumVariants,
+    },
+    /// Rename a variant of an enum field, rewriting stored values.
+    RenameEnumVariant {
+        field: FieldName,
+        old_variant: String,
+        new_variant: String,
+        variants: EnumVariants,
+    },
     /// Add an index on a field.
     AddIndex { field: FieldName },
     /// Remove an index from a field.
     RemoveIndex { field: FieldName },
+    /// Add a unique index on a field that gained the `unique` modifier.
+    AddUnique { field: FieldName },
+    /// Remove the unique index of a field that lost the `unique` modifier.
+    RemoveUnique { field: FieldName },
+    /// Add an `@index` over one or more fields, optionally unique.
+    AddCompositeIndex {
+        fields: Vec<FieldName>,
+        unique: bool,
+    },
+    /// Remove an `@index` over one or more fields. `unique` is kept so the
+    /// step can be inverted.
+    RemoveCompositeIndex {
+        fields: Vec<FieldName>,
+        unique: bool,
+    },
     /// Add a relation field.
     AddRelation {
         name: FieldName,
@@ -186,13 +261,21 @@ pub enum MigrationStep {
     AddRequired { field: FieldName },
     /// Remove a required modifier from an existing field.
     RemoveRequired { field: FieldName },
-    /// Set or change a default value for a field.
+    /// Set or change a default value for a field. `previous` is the default
+    /// being replaced, kept so the step can be inverted.
     SetDefault {
         field: FieldName,
         value: DefaultValue,
+        #[serde(default)]
+        previous: Option<DefaultValue>,
+    },
+    /// Remove a default value from a field. `previous` is the removed
+    /// default, kept so the step can be inverted.
+    RemoveDefault {
+        field: FieldName,
+        #[serde(default)]
+        previous: Option<DefaultValue>,
     },
-    /// Remove a default value from a field.
-    RemoveDefault { field: FieldName },
     /// Declare a new `@hook(event)` annotation on this schema. Hook
     /// changes are metadata-only — no on-disk migration is required;
     /// the operator must regenerate any hook service scaffold so the
@@ -212,6 +295,13 @@ pub enum MigrationStep {
         old_intent: String,
         new_intent: String,
     },
+    /// Define the `_deleted_at` marker for a schema that gained
+    /// `@soft_delete`. Existing rows stay live.
+    EnableSoftDelete,
+    /// Drop the `_deleted_at` marker for a schema that lost `@soft_delete`.
+    /// Rows already soft-deleted are purged, since without the marker they
+    /// would reappear.
+    DisableSoftDelete,
 }
 
 impl MigrationStep {
@@ -222,24 +312,133 @@ impl MigrationStep {
             | Self::AddField { .. }
             | Self::AddIndex { .. }
             | Self::AddRelation { .. }
+            | Self::AddEnumVariant { .. }
             | Self::RemoveIndex { .. }
+            | Self::RemoveUnique { .. }
+            | Self::AddCompositeIndex { unique: false, .. }
+            | Self::RemoveCompositeIndex { .. }
             | Self::RemoveRequired { .. }
             | Self::SetDefault { .. }
             | Self::RemoveDefault { .. }
             | Self::AddHook { .. }
             | Self::RemoveHook { .. }
-            | Self::ChangeHookIntent { .. } => MigrationSafety::Safe,
+            | Self::ChangeHookIntent { .. }
+            | Self::EnableSoftDelete => MigrationSafety::Safe,
+
+            // A longer or unchanged text limit keeps every stored value.
+            Self::ChangeType {
+                old_type: FieldType::Text(_),
+                new_type: FieldType::Text(_),
+                transform: ValueTransform::Identity,
+                ..
+            } => MigrationSafety::Safe,
+
+            // Existing rows may already repeat the indexed values.
+            Self::AddCompositeIndex { unique: true, .. } => MigrationSafety::RequiresConfirmation,
 
             Self::RenameField { .. }
             | Self::ChangeType { .. }
+            | Self::RenameEnumVariant { .. }
             | Self::BackfillRequired { .. }
-            | Self::AddRequired { .. } => MigrationSafety::RequiresConfirmation,
+            | Self::AddRequired { .. }
+            | Self::AddUnique { .. } => MigrationSafety::RequiresConfirmation,
+
+            Self::DropSchema { .. }
+            | Self::RemoveField { .. }
+            | Self::RemoveRelation { .. }
+            | Self::RemoveEnumVariant { .. }
+            | Self::DisableSoftDelete => MigrationSafety::Destructive,
+        }
+    }
+
+    /// The fields this step touches, in the order they appear in the step.
+    ///
+    /// `CreateSchema` touches every field it creates; `RenameField` touches
+    /// both the old and the new name. Schema-wide steps (`DropSchema`) and
+    /// hook steps touch no individual field.
+    pub fn affected_fields(&self) -> Vec<&FieldName> {
+        match self {
+            Self::CreateSchema { fields, .. } => fields.iter().map(|f| &f.name).collect(),
+            Self::AddField { field } => vec![&field.name],
+            Self::RenameField { old_name, new_name } => vec![old_name, new_name],
+            Self::RemoveField { name }
+            | Self::ChangeType { name, .. }
+            | Self::AddRelation { name, .. }
+            | Self::RemoveRelation { name } => vec![name],
+            Self::AddEnumVariant { field, .. }
+            | Self::RemoveEnumVariant { field, .. }
+            | Self::RenameEnumVariant { field, .. }
+            | Self::AddIndex { field }
+            | Self::RemoveIndex { field }
+            | Self::AddUnique { field }
+            | Self::RemoveUnique { field }
+            | Self::BackfillRequired { field, .. }
+            | Self::AddRequired { field }
+            | Self::RemoveRequired { field }
+            | Self::SetDefault { field, .. }
+            | Self::RemoveDefault { field, .. } => vec![field],
+            Self::AddCompositeIndex { fields, .. } | Self::RemoveCompositeIndex { fields, .. } => {
+                fields.iter().collect()
+            }
+            Self::DropSchema { .. }
+            | Self::AddHook { .. }
+            | Self::RemoveHook { .. }
+            | Self::ChangeHookIntent { .. }
+            | Self::EnableSoftDelete
+            | Self::DisableSoftDelete => Vec::new(),
+        }
+    }
 
-            Self::DropSchema { .. } | Self::RemoveField { .. } | Self::RemoveRelation { .. } => {
-                MigrationSafety::Destructive
+    /// Whether this step touches the field called `name`.
+    pub fn affects_field(&self, name: &FieldName) -> bool {
+        self.affected_fields().contains(&name)
+    }
+
+    /// Which aspect of its fields this step changes, for conflict detection.
+    fn field_aspect(&self) -> FieldAspect {
+        match self {
+            Self::CreateSchema { .. }
+            | Self::DropSchema { .. }
+            | Self::AddField { .. }
+            | Self::RemoveField { .. }
+            | Self::RenameField { .. }
+            | Self::AddRelation { .. }
+            | Self::RemoveRelation { .. }
+            | Self::EnableSoftDelete
+            | Self::DisableSoftDelete => FieldAspect::Definition,
+            Self::ChangeType { .. }
+            | Self::AddEnumVariant { .. }
+            | Self::RemoveEnumVariant { .. }
+            | Self::RenameEnumVariant { .. } => FieldAspect::Type,
+            Self::AddIndex { .. }
+            | Self::RemoveIndex { .. }
+            | Self::AddUnique { .. }
+            | Self::RemoveUnique { .. }
+            | Self::AddCompositeIndex { .. }
+            | Self::RemoveCompositeIndex { .. } => FieldAspect::Index,
+            Self::BackfillRequired { .. }
+            | Self::AddRequired { .. }
+            | Self::RemoveRequired { .. } => FieldAspect::Required,
+            Self::SetDefault { .. } | Self::RemoveDefault { .. } => FieldAspect::Default,
+            Self::AddHook { .. } | Self::RemoveHook { .. } | Self::ChangeHookIntent { .. } => {
+                FieldAspect::Hook
             }
         }
     }
+
+    /// Whether this step changes the backend's stored structure: tables,
+    /// columns, types, indexes, constraints, or defaults.
+    ///
+    /// False for data-only backfills and metadata-only hook changes.
+    pub fn affects_schema_structure(&self) -> bool {
+        !matches!(
+            self,
+            Self::BackfillRequired { .. }
+                | Self::AddHook { .. }
+                | Self::RemoveHook { .. }
+                | Self::ChangeHookIntent { .. }
+        )
+    }
 }
 
 impl fmt::Display for MigrationStep {
@@ -265,10 +464,71 @@ impl fmt::Display for MigrationStep {
                 write!(
                     f,
                     "CHANGE TYPE of '{name}' from {old_type} to {new_type} via {transform}"
+                )?;
+                if let ValueTransform::Truncate { max_length } = transform {
+                    match old_type {
+                        FieldType::Text(TextConstraints {
+                            max_length: Some(old),
+                            ..
+                        }) => write!(
+                            f,
+                            " (may truncate {} characters)",
+                            old.saturating_sub(*max_length)
+                        )?,
+                        _ => write!(
+                            f,
+                            " (may truncate values longer than {max_length} characters)"
+                        )?,
+                    }
+                }
+                Ok(())
+            }
+            Self::AddEnumVariant { field, variant, .. } => {
+                write!(f, "ADD VARIANT '{variant}' to '{field}'")
+            }
+            Self::RemoveEnumVariant {
+                field,
+                variant,
+                fallback,
+                ..
+            } => {
+                write!(
+                    f,
+                    "REMOVE VARIANT '{variant}' from '{field}' (remap to {})",
+                    fallback.as_deref().unwrap_or("null")
+                )
+            }
+            Self::RenameEnumVariant {
+                field,
+                old_variant,
+                new_variant,
+                ..
+            } => {
+                write!(
+                    f,
+                    "RENAME VARIANT '{old_variant}' to '{new_variant}' on '{field}'"
                 )
             }
             Self::AddIndex { field } => write!(f, "ADD INDEX on '{field}'"),
             Self::RemoveIndex { field } => write!(f, "REMOVE INDEX on '{field}'"),
+            Self::AddUnique { field } => write!(f, "ADD UNIQUE on '{field}'"),
+            Self::RemoveUnique { field } => write!(f, "REMOVE UNIQUE on '{field}'"),
+            Self::AddCompositeIndex { fields, unique } => {
+                write!(
+                    f,
+                    "ADD {}INDEX on ({})",
+                    unique_prefix(*unique),
+                    join_fields(fields)
+                )
+            }
+            Self::RemoveCompositeIndex { fields, unique } => {
+                write!(
+                    f,
+                    "REMOVE {}INDEX on ({})",
+                    unique_prefix(*unique),
+                    join_fields(fields)
+                )
+            }
             Self::AddRelation {
                 name,
                 target,
@@ -285,10 +545,10 @@ impl fmt::Display for MigrationStep {
             }
             Self::AddRequired { field } => write!(f, "ADD REQUIRED on '{field}'"),
             Self::RemoveRequired { field } => write!(f, "REMOVE REQUIRED on '{field}'"),
-            Self::SetDefault { field, value } => {
+            Self::SetDefault { field, value, .. } => {
                 write!(f, "SET DEFAULT on '{field}' to {value}")
             }
-            Self::RemoveDefault { field } => write!(f, "REMOVE DEFAULT on '{field}'"),
+            Self::RemoveDefault { field, .. } => write!(f, "REMOVE DEFAULT on '{field}'"),
             Self::AddHook { event, intent } => {
                 write!(f, "ADD HOOK {} \"{intent}\"", event.as_str())
             }
@@ -304,10 +564,30 @@ impl fmt::Display for MigrationStep {
                     event.as_str()
                 )
             }
+            Self::EnableSoftDelete => write!(f, "ENABLE SOFT DELETE"),
+            Self::DisableSoftDelete => write!(f, "DISABLE SOFT DELETE (purges deleted rows)"),
         }
     }
 }
 
+/// `"UNIQUE "` for a unique index, empty otherwise.
+fn unique_prefix(unique: bool) -> &'static str {
+    if unique {
+        "UNIQUE "
+    } else {
+        ""
+    }
+}
+
+/// Comma-separated field names of a composite index.
+fn join_fields(fields: &[FieldName]) -> String {
+    fields
+        .iter()
+        .map(FieldName::as_str)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 // ---------------------------------------------------------------------------
 // MigrationPlan
 // ---------------------------------------------------------------------------
@@ -377,6 +657,100 @@ impl MigrationPlan {
             .iter()
             .any(|s| s.safety() == MigrationSafety::Destructive)
     }
+
+    /// Compose consecutive plans for one schema into a single plan with the
+    /// same end result, skipping the intermediate states.
+    ///
+    /// A field added and later removed disappears. Renames, type changes,
+    /// and default changes to a field added within the sequence fold into
+    /// its `AddField` or `CreateSchema` definition. Chained renames and
+    /// consecutive default changes collapse to the last one, and vanish when
+    /// they restore the starting state. Dropping the schema discards every
+    /// earlier step. Other steps are kept in order.
+    ///
+    /// The result takes the last plan's schema id and name. Fails on an
+    /// empty slice or on plans for different schemas.
+    pub fn squash(plans: &[MigrationPlan]) -> Result<MigrationPlan, MigrationError> {
+        let last = plans.last().ok_or(MigrationError::EmptyMigrationPlan)?;
+        if let Some(other) = plans.iter().find(|p| p.schema_name != last.schema_name) {
+            return Err(MigrationError::MixedSchemas {
+                first: other.schema_name.as_str().to_string(),
+                second: last.schema_name.as_str().to_string(),
+            });
+        }
+        let mut steps = Vec::new();
+        for step in plans.iter().flat_map(|p| &p.steps) {
+            squash_step(&mut steps, step.clone());
+        }
+        Ok(MigrationPlan::new(
+            last.schema_id.clone(),
+            last.schema_name.clone(),
+            steps,
+        ))
+    }
+
+    /// Replace the inferred transform of `field`'s `ChangeType` step.
+    ///
+    /// Fails when the plan does not change the field's type, or when the
+    /// transform cannot convert between the step's old and new types.
+    pub fn override_transform(
+        &mut self,
+        field: &FieldName,
+        transform: ValueTransform,
+    ) -> Result<(), MigrationError> {
+        let invalid = |reason: String| MigrationError::InvalidTransform {
+            field_name: field.as_str().to_string(),
+            reason,
+        };
+        let step = self.steps.iter_mut().find_map(|step| match step {
+            MigrationStep::ChangeType {
+                name,
+                old_type,
+                new_type,
+                transform,
+            } if name == field => Some((&*old_type, &*new_type, transform)),
+            _ => None,
+        });
+        let Some((old_type, new_type, current)) = step else {
+            return Err(invalid("the plan does not change its type".into()));
+        };
+        let is_text = |t: &FieldType| matches!(t, FieldType::Text(_) | FieldType::Enum(_));
+        match &transform {
+            ValueTransform::ParseNumber => {
+                if !matches!(old_type, FieldType::Text(_))
+                    || !matches!(
+                        new_type,
+                        FieldType::Integer(_) | FieldType::Float(_) | FieldType::Decimal { .. }
+                    )
+                {
+                    return Err(invalid(format!(
+                        "{transform} converts text to a number, not {old_type} to {new_type}"
+                    )));
+                }
+            }
+            ValueTransform::MapText { mapping, default } => {
+                if !is_text(old_type) || !is_text(new_type) {
+                    return Err(invalid(format!(
+                        "map_text converts between text and enum fields, not {old_type} to {new_type}"
+                    )));
+                }
+                if let FieldType::Enum(variants) = new_type {
+                    let unknown = mapping
+                        .values()
+                        .chain(default)
+                        .find(|v| !variants.as_slice().contains(*v));
+                    if let Some(value) = unknown {
+                        return Err(invalid(format!(
+                            "'{value}' is not a variant of the new enum"
+                        )));
+                    }
+                }
+            }
+            _ => {}
+        }
+        *current = transform;
+        Ok(())
+    }
 }
 
 impl fmt::Display for MigrationPlan {
@@ -395,6 +769,53 @@ impl fmt::Display for MigrationPlan {
     }
 }
 
+// ---------------------------------------------------------------------------
+// MigrationConflict
+// ---------------------------------------------------------------------------
+
+/// The part of a field a step changes. Two different steps on the same field
+/// are compatible only when they change different aspects and neither one
+/// (re)defines the field itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldAspect {
+    Definition,
+    Type,
+    Index,
+    Required,
+    Default,
+    Hook,
+}
+
+/// A pair of steps from two plans, authored against the same base schema,
+/// that cannot both be applied.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MigrationConflict {
+    /// The field both steps touch, or `None` when one step drops the schema.
+    pub field: Option<FieldName>,
+    /// Index of the first plan in the slice passed to `detect_conflicts`.
+    pub first_plan: usize,
+    /// The conflicting step from the first plan.
+    pub first_step: MigrationStep,
+    /// Index of the second plan in the slice passed to `detect_conflicts`.
+    pub second_plan: usize,
+    /// The conflicting step from the second plan.
+    pub second_step: MigrationStep,
+}
+
+impl fmt::Display for MigrationConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "field '{field}'")?,
+            None => write!(f, "schema")?,
+        }
+        write!(
+            f,
+            ": plan {} does {}, plan {} does {}",
+            self.first_plan, self.first_step, self.second_plan, self.second_step
+        )
+    }
+}
+
 // ---------------------------------------------------------------------------
 // DiffEngine
 // ---------------------------------------------------------------------------
@@ -425,15 +846,90 @@ impl DiffEngine {
         new: &crate::types::SchemaDefinition,
         renames: &[(FieldName, FieldName)],
     ) -> MigrationPlan {
-        let mut steps = Vec::new();
+        Self::diff_with_variant_renames(old, new, renames, &[])
+    }
+
+    /// Compare two schema definitions with field and enum variant rename
+    /// hints.
+    ///
+    /// Variant rename hints are `(field, old_variant, new_variant)` triples,
+    /// keyed by the field's name in `new`. A hint applies when `old_variant`
+    /// exists only in the old enum and `new_variant` only in the new one;
+    /// it then yields a `RenameEnumVariant` step instead of a
+    /// `RemoveEnumVariant` + `AddEnumVariant` pair.
+    pub fn diff_with_variant_renames(
+        old: &crate::types::SchemaDefinition,
+        new: &crate::types::SchemaDefinition,
+        renames: &[(FieldName, FieldName)],
+        variant_renames: &[(FieldName, String, String)],
+    ) -> MigrationPlan {
+        let (mut steps, added_indexes) = Self::diff_indexes(old, new, renames);
 
-        Self::diff_fields_with_renames(old, new, renames, &mut steps);
+        Self::diff_fields_with_renames(old, new, renames, variant_renames, &mut steps);
         Self::diff_modifiers_with_renames(old, new, renames, &mut steps);
+        steps.extend(added_indexes);
         Self::diff_hooks(old, new, &mut steps);
+        match (old.is_soft_delete(), new.is_soft_delete()) {
+            (false, true) => steps.push(MigrationStep::EnableSoftDelete),
+            (true, false) => steps.push(MigrationStep::DisableSoftDelete),
+            _ => {}
+        }
 
         MigrationPlan::new(new.id.clone(), new.name.clone(), steps)
     }
 
+    /// Compare the `@index(...)` annotations between two schema versions.
+    ///
+    /// Returns the `RemoveCompositeIndex` steps and the `AddCompositeIndex`
+    /// steps apart: removals run before field changes, since a removed
+    /// field may still be indexed, and additions after, since a new index
+    /// may cover a new field. Old field names are mapped through `renames`
+    /// before comparing, so renaming an indexed field keeps its index.
+    fn diff_indexes(
+        old: &crate::types::SchemaDefinition,
+        new: &crate::types::SchemaDefinition,
+        renames: &[(FieldName, FieldName)],
+    ) -> (Vec<MigrationStep>, Vec<MigrationStep>) {
+        let renamed = |name: &FieldName| {
+            renames
+                .iter()
+                .find(|(from, _)| from == name)
+                .map_or_else(|| name.clone(), |(_, to)| to.clone())
+        };
+        let old_keys: Vec<(Vec<FieldName>, bool)> = old
+            .indexes()
+            .into_iter()
+            .map(|(fields, unique)| (fields.iter().map(renamed).collect(), unique))
+            .collect();
+        let new_keys: Vec<(Vec<FieldName>, bool)> = new
+            .indexes()
+            .into_iter()
+            .map(|(fields, unique)| (fields.to_vec(), unique))
+            .collect();
+
+        let removed = old
+            .indexes()
+            .into_iter()
+            .zip(&old_keys)
+            .filter(|(_, key)| !new_keys.contains(key))
+            .map(
+                |((fields, unique), _)| MigrationStep::RemoveCompositeIndex {
+                    fields: fields.to_vec(),
+                    unique,
+                },
+            )
+            .collect();
+        let added = new_keys
+            .iter()
+            .filter(|key| !old_keys.contains(key))
+            .map(|(fields, unique)| MigrationStep::AddCompositeIndex {
+                fields: fields.clone(),
+                unique: *unique,
+            })
+            .collect();
+        (removed, added)
+    }
+
     /// Compare the `@hook(...)` annotations between two schema versions
     /// and emit `AddHook` / `RemoveHook` / `ChangeHookIntent` steps. Hook
     /// changes are metadata-only — no on-disk migration is needed — but
@@ -482,23 +978,229 @@ impl DiffEngine {
 
     /// Create a migration plan for a brand new schema (no old version).
     ///
-    /// Derived fields (paired inverse collections) are excluded from the
-    /// emitted `CreateSchema` step — they have no physical column.
+    /// Derived fields (paired inverse collections) and `@computed` fields
+    /// are excluded from the emitted `CreateSchema` step — they have no
+    /// physical column. A `@soft_delete` schema also gets an
+    /// `EnableSoftDelete` step, and each `@index` an `AddCompositeIndex`
+    /// step.
     #[instrument(skip(schema), fields(schema = %schema.name.as_str()))]
     pub fn create_new(schema: &crate::types::SchemaDefinition) -> MigrationPlan {
         let fields: Vec<FieldDefinition> = schema
             .fields
             .iter()
-            .filter(|f| !f.is_derived())
+            .filter(|f| f.is_stored())
             .cloned()
             .collect();
-        let steps = vec![MigrationStep::CreateSchema {
+        let mut steps = vec![MigrationStep::CreateSchema {
             name: schema.name.clone(),
             fields,
         }];
+        if schema.is_soft_delete() {
+            steps.push(MigrationStep::EnableSoftDelete);
+        }
+        steps.extend(schema.indexes().into_iter().map(|(fields, unique)| {
+            MigrationStep::AddCompositeIndex {
+                fields: fields.to_vec(),
+                unique,
+            }
+        }));
         MigrationPlan::new(schema.id.clone(), schema.name.clone(), steps)
     }
 
+    /// Build the plan that undoes `plan`: each step inverted, in reverse
+    /// order.
+    ///
+    /// This is a pure function: no I/O, no side effects. Steps whose data or
+    /// definition is gone once applied (`DropSchema`, `RemoveField`,
+    /// `RemoveEnumVariant`, backfills, ...) fail with
+    /// [`MigrationError::Irreversible`]; type changes whose transform loses
+    /// information fail with [`MigrationError::UnsupportedTypeConversion`].
+    #[instrument(skip(plan), fields(schema = %plan.schema_name.as_str()))]
+    pub fn invert(plan: &MigrationPlan) -> Result<MigrationPlan, MigrationError> {
+        let steps = plan
+            .steps
+            .iter()
+            .rev()
+            .map(Self::invert_step)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(MigrationPlan::new(
+            plan.schema_id.clone(),
+            plan.schema_name.clone(),
+            steps,
+        ))
+    }
+
+    fn invert_step(step: &MigrationStep) -> Result<MigrationStep, MigrationError> {
+        let irreversible = || MigrationError::Irreversible {
+            step_description: step.to_string(),
+        };
+        let inverse = match step {
+            MigrationStep::CreateSchema { name, .. } => {
+                MigrationStep::DropSchema { name: name.clone() }
+            }
+            MigrationStep::AddField { field } => MigrationStep::RemoveField {
+                name: field.name.clone(),
+            },
+            MigrationStep::RenameField { old_name, new_name } => MigrationStep::RenameField {
+                old_name: new_name.clone(),
+                new_name: old_name.clone(),
+            },
+            MigrationStep::ChangeType {
+                name,
+                old_type,
+                new_type,
+                transform,
+            } => {
+                let transform = match transform {
+                    // Undoing a text limit change may shorten the limit.
+                    ValueTransform::Identity
+                        if matches!(
+                            (old_type, new_type),
+                            (FieldType::Text(_), FieldType::Text(_))
+                        ) =>
+                    {
+                        Self::infer_transform(new_type, old_type)
+                    }
+                    ValueTransform::Identity => ValueTransform::Identity,
+                    // Values that started as integers convert back exactly.
+                    ValueTransform::IntegerToFloat => ValueTransform::FloatToInteger,
+                    // Parsed UUIDs and numbers print back to their
+                    // canonical text.
+                    ValueTransform::ParseUuid | ValueTransform::ParseNumber => {
+                        ValueTransform::ToString
+                    }
+                    // Nothing was rewritten, so the values are still text.
+                    ValueTransform::MapText {
+                        mapping,
+                        default: None,
+                    } if mapping.is_empty() => ValueTransform::ToString,
+                    _ => {
+                        return Err(MigrationError::UnsupportedTypeConversion {
+                            field_name: name.as_str().to_string(),
+                            from_type: new_type.to_string(),
+                            to_type: old_type.to_string(),
+                        })
+                    }
+                };
+                MigrationStep::ChangeType {
+                    name: name.clone(),
+                    old_type: new_type.clone(),
+                    new_type: old_type.clone(),
+                    transform,
+                }
+            }
+            MigrationStep::AddEnumVariant {
+                field,
+                variant,
+                variants,
+            } => {
+                let remaining = variants.iter().filter(|v| *v != variant).cloned().collect();
+                MigrationStep::RemoveEnumVariant {
+                    field: field.clone(),
+                    variant: variant.clone(),
+                    fallback: None,
+                    variants: EnumVariants::new(remaining).map_err(|_| irreversible())?,
+                }
+            }
+            MigrationStep::RenameEnumVariant {
+                field,
+                old_variant,
+                new_variant,
+                variants,
+            } => {
+                let restored = variants
+                    .iter()
+                    .map(|v| if v == new_variant { old_variant } else { v })
+                    .cloned()
+                    .collect();
+                MigrationStep::RenameEnumVariant {
+                    field: field.clone(),
+                    old_variant: new_variant.clone(),
+                    new_variant: old_variant.clone(),
+                    variants: EnumVariants::new(restored).map_err(|_| irreversible())?,
+                }
+            }
+            MigrationStep::AddIndex { field } => MigrationStep::RemoveIndex {
+                field: field.clone(),
+            },
+            MigrationStep::RemoveIndex { field } => MigrationStep::AddIndex {
+                field: field.clone(),
+            },
+            MigrationStep::AddUnique { field } => MigrationStep::RemoveUnique {
+                field: field.clone(),
+            },
+            MigrationStep::RemoveUnique { field } => MigrationStep::AddUnique {
+                field: field.clone(),
+            },
+            MigrationStep::AddCompositeIndex { fields, unique } => {
+                MigrationStep::RemoveCompositeIndex {
+                    fields: fields.clone(),
+                    unique: *unique,
+                }
+            }
+            MigrationStep::RemoveCompositeIndex { fields, unique } => {
+                MigrationStep::AddCompositeIndex {
+                    fields: fields.clone(),
+                    unique: *unique,
+                }
+            }
+            MigrationStep::AddRelation { name, .. } => {
+                MigrationStep::RemoveRelation { name: name.clone() }
+            }
+            MigrationStep::AddRequired { field } => MigrationStep::RemoveRequired {
+                field: field.clone(),
+            },
+            MigrationStep::RemoveRequired { field } => MigrationStep::AddRequired {
+                field: field.clone(),
+            },
+            MigrationStep::SetDefault {
+                field,
+                value,
+                previous: Some(previous),
+            } => MigrationStep::SetDefault {
+                field: field.clone(),
+                value: previous.clone(),
+                previous: Some(value.clone()),
+            },
+            MigrationStep::SetDefault {
+                field,
+                value,
+                previous: None,
+            } => MigrationStep::RemoveDefault {
+                field: field.clone(),
+                previous: Some(value.clone()),
+            },
+            MigrationStep::RemoveDefault {
+                field,
+                previous: Some(previous),
+            } => MigrationStep::SetDefault {
+                field: field.clone(),
+                value: previous.clone(),
+                previous: None,
+            },
+            MigrationStep::AddHook { event, .. } => MigrationStep::RemoveHook { event: *event },
+            MigrationStep::EnableSoftDelete => MigrationStep::DisableSoftDelete,
+            MigrationStep::ChangeHookIntent {
+                event,
+                old_intent,
+                new_intent,
+            } => MigrationStep::ChangeHookIntent {
+                event: *event,
+                old_intent: new_intent.clone(),
+                new_intent: old_intent.clone(),
+            },
+            MigrationStep::DropSchema { .. }
+            | MigrationStep::RemoveField { .. }
+            | MigrationStep::RemoveRelation { .. }
+            | MigrationStep::RemoveEnumVariant { .. }
+            | MigrationStep::BackfillRequired { .. }
+            | MigrationStep::RemoveDefault { previous: None, .. }
+            | MigrationStep::RemoveHook { .. }
+            | MigrationStep::DisableSoftDelete => return Err(irreversible()),
+        };
+        Ok(inverse)
+    }
+
     /// Returns true if the schema has the `@system` annotation.
     pub fn is_system_schema(schema: &crate::types::SchemaDefinition) -> bool {
         schema
@@ -527,10 +1229,79 @@ impl DiffEngine {
         Ok(())
     }
 
+    /// Find steps in `plans` that change the same part of `base` in
+    /// incompatible ways, e.g. two plans both adding `status` with
+    /// different types.
+    ///
+    /// Every pair of plans targeting `base` is compared step by step, using
+    /// [`MigrationStep::affected_fields`]. Identical steps never conflict,
+    /// so plans that converge on the same change merge cleanly. Steps on a
+    /// shared field conflict when they change the same aspect of it (type,
+    /// index, required, default) or when either adds, removes, or renames
+    /// it. Dropping the schema conflicts with any other step. Plans for
+    /// other schemas are ignored.
+    pub fn detect_conflicts(
+        base: &crate::types::SchemaDefinition,
+        plans: &[MigrationPlan],
+    ) -> Vec<MigrationConflict> {
+        let relevant: Vec<(usize, &MigrationPlan)> = plans
+            .iter()
+            .enumerate()
+            .filter(|(_, plan)| plan.schema_name == base.name)
+            .collect();
+
+        let mut conflicts = Vec::new();
+        for (pos, &(first_plan, first)) in relevant.iter().enumerate() {
+            for &(second_plan, second) in &relevant[pos + 1..] {
+                for first_step in &first.steps {
+                    for second_step in &second.steps {
+                        if let Some(field) = Self::step_conflict(first_step, second_step) {
+                            conflicts.push(MigrationConflict {
+                                field,
+                                first_plan,
+                                first_step: first_step.clone(),
+                                second_plan,
+                                second_step: second_step.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// `Some(field)` when `a` and `b` conflict; the inner `None` marks a
+    /// schema-wide conflict caused by `DropSchema`.
+    fn step_conflict(a: &MigrationStep, b: &MigrationStep) -> Option<Option<FieldName>> {
+        if a == b {
+            return None;
+        }
+        if matches!(a, MigrationStep::DropSchema { .. })
+            || matches!(b, MigrationStep::DropSchema { .. })
+        {
+            return Some(None);
+        }
+        let shared = a
+            .affected_fields()
+            .into_iter()
+            .find(|field| b.affects_field(field))?;
+        let (aspect_a, aspect_b) = (a.field_aspect(), b.field_aspect());
+        let clash = aspect_a == aspect_b
+            || aspect_a == FieldAspect::Definition
+            || aspect_b == FieldAspect::Definition;
+        if clash {
+            Some(Some(shared.clone()))
+        } else {
+            None
+        }
+    }
+
     fn diff_fields_with_renames(
         old: &crate::types::SchemaDefinition,
         new: &crate::types::SchemaDefinition,
         renames: &[(FieldName, FieldName)],
+        variant_renames: &[(FieldName, String, String)],
         steps: &mut Vec<MigrationStep>,
     ) {
         use std::collections::HashMap;
@@ -548,6 +1319,15 @@ impl DiffEngine {
         // Emit RenameField for valid rename pairs
         for (old_name, new_name) in renames {
             if let Some(old_field) = old.field(old_name.as_str()) {
+                let new_field = new.field(new_name.as_str());
+                if !old_field.is_stored() || new_field.is_some_and(|f| !f.is_stored()) {
+                    // Only stored fields have a column to rename.
+                    Self::emit_remove_field(old_field, steps);
+                    if let Some(new_field) = new_field {
+                        Self::emit_add_field(new_field, steps);
+                    }
+                    continue;
+                }
                 steps.push(MigrationStep::RenameField {
                     old_name: old_name.clone(),
                     new_name: new_name.clone(),
@@ -560,6 +1340,7 @@ impl DiffEngine {
                             new_name.clone(),
                             old_field,
                             new_field,
+                            variant_renames,
                             steps,
                         );
                     }
@@ -593,8 +1374,13 @@ impl DiffEngine {
                 continue; // already handled above
             }
             if let Some(old_field) = old.field(new_field.name.as_str()) {
-                if old_field.field_type != new_field.field_type {
-                    Self::emit_change_type(old_field, new_field, steps);
+                // A field that gains or loses `@computed` gains or loses its
+                // column rather than changing type.
+                if old_field.is_stored() != new_field.is_stored() {
+                    Self::emit_remove_field(old_field, steps);
+                    Self::emit_add_field(new_field, steps);
+                } else if new_field.is_stored() && old_field.field_type != new_field.field_type {
+                    Self::emit_change_type(old_field, new_field, variant_renames, steps);
                 }
             }
         }
@@ -614,10 +1400,10 @@ impl DiffEngine {
             .collect();
 
         for new_field in &new.fields {
-            // Derived inverse collections have no physical column, so
-            // none of the modifier changes (required/indexed/default)
-            // apply at the DDL layer.
-            if new_field.is_derived() {
+            // Derived inverse collections and computed fields have no
+            // physical column, so none of the modifier changes
+            // (required/indexed/default) apply at the DDL layer.
+            if !new_field.is_stored() {
                 continue;
             }
             // Find the corresponding old field: either by same name, or via rename
@@ -649,6 +1435,8 @@ impl DiffEngine {
         let new_required = new_field.is_required();
         let old_indexed = old_field.is_indexed();
         let new_indexed = new_field.is_indexed();
+        let old_unique = old_field.is_unique();
+        let new_unique = new_field.is_unique();
         let old_default = Self::extract_default(&old_field.modifiers);
         let new_default = Self::extract_default(&new_field.modifiers);
 
@@ -674,23 +1462,37 @@ impl DiffEngine {
             });
         }
 
+        // Unique changes
+        if !old_unique && new_unique {
+            steps.push(MigrationStep::AddUnique {
+                field: new_field.name.clone(),
+            });
+        } else if old_unique && !new_unique {
+            steps.push(MigrationStep::RemoveUnique {
+                field: new_field.name.clone(),
+            });
+        }
+
         // Default value changes
         match (old_default, new_default) {
             (None, Some(val)) => {
                 steps.push(MigrationStep::SetDefault {
                     field: new_field.name.clone(),
                     value: val.clone(),
+                    previous: None,
                 });
             }
-            (Some(_), None) => {
+            (Some(old_val), None) => {
                 steps.push(MigrationStep::RemoveDefault {
                     field: new_field.name.clone(),
+                    previous: Some(old_val.clone()),
                 });
             }
             (Some(old_val), Some(new_val)) if old_val != new_val => {
                 steps.push(MigrationStep::SetDefault {
                     field: new_field.name.clone(),
                     value: new_val.clone(),
+                    previous: Some(old_val.clone()),
                 });
             }
             _ => {}
@@ -708,9 +1510,10 @@ impl DiffEngine {
     }
 
     fn emit_remove_field(field: &FieldDefinition, steps: &mut Vec<MigrationStep>) {
-        // Derived inverse collections never had a physical column, so
-        // removing them from the schema is a no-op at the DDL layer.
-        if field.is_derived() {
+        // Derived inverse collections and computed fields never had a
+        // physical column, so removing them from the schema is a no-op at
+        // the DDL layer.
+        if !field.is_stored() {
             return;
         }
         if matches!(field.field_type, FieldType::Relation { .. }) {
@@ -725,9 +1528,9 @@ impl DiffEngine {
     }
 
     fn emit_add_field(field: &FieldDefinition, steps: &mut Vec<MigrationStep>) {
-        // Derived inverse collections are resolved at read time — no
-        // column is created, so we emit no migration step.
-        if field.is_derived() {
+        // Derived inverse collections and computed fields are resolved at
+        // read time — no column is created, so we emit no migration step.
+        if !field.is_stored() {
             return;
         }
         if let FieldType::Relation {
@@ -750,33 +1553,552 @@ impl DiffEngine {
     fn emit_change_type(
         old_field: &FieldDefinition,
         new_field: &FieldDefinition,
+        variant_renames: &[(FieldName, String, String)],
         steps: &mut Vec<MigrationStep>,
     ) {
-        Self::emit_change_type_with_name(new_field.name.clone(), old_field, new_field, steps);
+        Self::emit_change_type_with_name(
+            new_field.name.clone(),
+            old_field,
+            new_field,
+            variant_renames,
+            steps,
+        );
     }
 
     fn emit_change_type_with_name(
         name: FieldName,
         old_field: &FieldDefinition,
         new_field: &FieldDefinition,
+        variant_renames: &[(FieldName, String, String)],
         steps: &mut Vec<MigrationStep>,
     ) {
-        let transform = Self::infer_transform(&old_field.field_type, &new_field.field_type);
+        if let (FieldType::Enum(old_variants), FieldType::Enum(new_variants)) =
+            (&old_field.field_type, &new_field.field_type)
+        {
+            Self::emit_enum_variant_steps(
+                &name,
+                old_variants,
+                new_variants,
+                new_field,
+                variant_renames,
+                steps,
+            );
+            return;
+        }
         steps.push(MigrationStep::ChangeType {
             name,
             old_type: old_field.field_type.clone(),
             new_type: new_field.field_type.clone(),
-            transform,
+            transform: Self::infer_transform(&old_field.field_type, &new_field.field_type),
         });
     }
 
+    /// Per-variant steps for an enum whose variant set changed.
+    ///
+    /// Renames come first, then additions, then removals, so every
+    /// intermediate variant list is non-empty and already holds the variant
+    /// a removal remaps to. Removed values fall back to the field's new
+    /// default when it names a surviving variant, else to null. A reorder
+    /// alone emits nothing.
+    fn emit_enum_variant_steps(
+        name: &FieldName,
+        old: &EnumVariants,
+        new: &EnumVariants,
+        new_field: &FieldDefinition,
+        variant_renames: &[(FieldName, String, String)],
+        steps: &mut Vec<MigrationStep>,
+    ) {
+        let in_old = |v: &str| old.iter().any(|o| o == v);
+        let in_new = |v: &str| new.iter().any(|n| n == v);
+        // The variant list after each step: surviving variants in their new
+        // order, then those still awaiting removal in their old order.
+        let snapshot = |current: &[String]| {
+            let ordered = new
+                .iter()
+                .filter(|v| current.contains(v))
+                .chain(current.iter().filter(|v| !in_new(v)))
+                .cloned()
+                .collect();
+            EnumVariants::new(ordered).expect("intermediate variant lists are non-empty")
+        };
+        let mut current = old.as_slice().to_vec();
+
+        for (field, from, to) in variant_renames {
+            let applies = field == name
+                && in_old(from)
+                && !in_new(from)
+                && in_new(to)
+                && !in_old(to)
+                && !current.contains(to);
+            if !applies {
+                continue;
+            }
+            if let Some(pos) = current.iter().position(|v| v == from) {
+                current[pos] = to.clone();
+                steps.push(MigrationStep::RenameEnumVariant {
+                    field: name.clone(),
+                    old_variant: from.clone(),
+                    new_variant: to.clone(),
+                    variants: snapshot(&current),
+                });
+            }
+        }
+
+        for variant in new.iter() {
+            if !current.contains(variant) {
+                current.push(variant.clone());
+                steps.push(MigrationStep::AddEnumVariant {
+                    field: name.clone(),
+                    variant: variant.clone(),
+                    variants: snapshot(&current),
+                });
+            }
+        }
+
+        let fallback = match Self::extract_default(&new_field.modifiers) {
+            Some(DefaultValue::String(v)) if in_new(v) => Some(v.clone()),
+            _ => None,
+        };
+        for variant in old.iter() {
+            if current.contains(variant) && !in_new(variant) {
+                current.retain(|v| v != variant);
+                steps.push(MigrationStep::RemoveEnumVariant {
+                    field: name.clone(),
+                    variant: variant.clone(),
+                    fallback: fallback.clone(),
+                    variants: snapshot(&current),
+                });
+            }
+        }
+    }
+
     fn infer_transform(old: &FieldType, new: &FieldType) -> ValueTransform {
         match (old, new) {
             (FieldType::Integer(_), FieldType::Float(_)) => ValueTransform::IntegerToFloat,
             (FieldType::Float(_), FieldType::Integer(_)) => ValueTransform::FloatToInteger,
-            (_, FieldType::Text(_)) => ValueTransform::ToString,
-            _ => ValueTransform::SetNull,
-        }
+            (FieldType::Float(_), FieldType::Decimal { scale }) => {
+                ValueTransform::FloatToDecimal { scale: *scale }
+            }
+            (FieldType::Text(_), FieldType::Uuid) => ValueTransform::ParseUuid,
+            // Only a shorter limit touches stored values; a pattern change
+            // is checked on the next write.
+            (FieldType::Text(old), FieldType::Text(new)) => match new.max_length {
+                Some(max) if old.max_length.is_none_or(|old| old > max) => {
+                    ValueTransform::Truncate { max_length: max }
+                }
+                _ => ValueTransform::Identity,
+            },
+            (
+                FieldType::Text(_),
+                FieldType::Integer(_) | FieldType::Float(_) | FieldType::Decimal { .. },
+            ) => ValueTransform::ParseNumber,
+            // Values that already name a variant are kept; any other value
+            // fails the migration unless an override maps it.
+            (FieldType::Text(_), FieldType::Enum(_)) => ValueTransform::MapText {
+                mapping: BTreeMap::new(),
+                default: None,
+            },
+            // A scale change keeps the amounts; the backend rescales them.
+            (FieldType::Decimal { .. }, FieldType::Decimal { .. }) => ValueTransform::Identity,
+            // Only the element-count bounds moved; stored arrays keep their
+            // shape and are re-validated on the next write.
+            (FieldType::Array(old_el, _), FieldType::Array(new_el, _)) if old_el == new_el => {
+                ValueTransform::Identity
+            }
+            (_, FieldType::Text(_)) => ValueTransform::ToString,
+            _ => ValueTransform::SetNull,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plan squashing
+// ---------------------------------------------------------------------------
+
+/// Append `step` to `steps`, composing it with an earlier step where the
+/// two collapse.
+fn squash_step(steps: &mut Vec<MigrationStep>, step: MigrationStep) {
+    let folded = match &step {
+        MigrationStep::DropSchema { .. } => {
+            let created_here = steps.iter().find_map(|s| match s {
+                MigrationStep::CreateSchema { .. } => Some(true),
+                MigrationStep::DropSchema { .. } => Some(false),
+                _ => None,
+            }) == Some(true);
+            steps.clear();
+            created_here
+        }
+        MigrationStep::RenameField { old_name, new_name } => fold_rename(steps, old_name, new_name),
+        MigrationStep::RemoveField { name } | MigrationStep::RemoveRelation { name } => {
+            fold_remove(steps, name, &step)
+        }
+        MigrationStep::ChangeType { name, new_type, .. } => {
+            fold_type(steps, name, new_type.clone())
+        }
+        MigrationStep::AddEnumVariant {
+            field, variants, ..
+        }
+        | MigrationStep::RemoveEnumVariant {
+            field, variants, ..
+        }
+        | MigrationStep::RenameEnumVariant {
+            field, variants, ..
+        } => fold_type(steps, field, FieldType::Enum(variants.clone())),
+        MigrationStep::SetDefault { field, value, .. } => fold_default(steps, field, Some(value)),
+        MigrationStep::RemoveDefault { field, .. } => fold_default(steps, field, None),
+        _ => false,
+    };
+    if !folded {
+        steps.push(step);
+    }
+}
+
+/// Index of the last step that adds, removes, or renames the field called
+/// `name`. Later steps only change that field's type, index, required
+/// flag, or default.
+fn last_definition(steps: &[MigrationStep], name: &FieldName) -> Option<usize> {
+    steps
+        .iter()
+        .rposition(|s| s.field_aspect() == FieldAspect::Definition && s.affects_field(name))
+}
+
+/// The definition of `name` in an `AddField` or `CreateSchema` step.
+fn defined_field_mut<'a>(
+    step: &'a mut MigrationStep,
+    name: &FieldName,
+) -> Option<&'a mut FieldDefinition> {
+    match step {
+        MigrationStep::AddField { field } if field.name == *name => Some(field),
+        MigrationStep::CreateSchema { fields, .. } => fields.iter_mut().find(|f| f.name == *name),
+        _ => None,
+    }
+}
+
+/// Fold a rename of `old` into the step that introduced `old`: its
+/// definition, or an earlier rename into `old`.
+fn fold_rename(steps: &mut Vec<MigrationStep>, old: &FieldName, new: &FieldName) -> bool {
+    let Some(i) = last_definition(steps, old) else {
+        return false;
+    };
+    if steps[i + 1..].iter().any(|s| s.affects_field(new)) {
+        return false;
+    }
+    match &mut steps[i] {
+        MigrationStep::AddField { field } => field.name = new.clone(),
+        MigrationStep::AddRelation { name, .. } => *name = new.clone(),
+        MigrationStep::CreateSchema { fields, .. } => {
+            for field in fields.iter_mut().filter(|f| f.name == *old) {
+                field.name = new.clone();
+            }
+        }
+        MigrationStep::RenameField { new_name, .. } if new_name == old => *new_name = new.clone(),
+        _ => return false,
+    }
+    for step in &mut steps[i + 1..] {
+        rename_field_refs(step, old, new);
+    }
+    // Renamed back to where it started.
+    if matches!(&steps[i], MigrationStep::RenameField { old_name, new_name } if old_name == new_name)
+    {
+        steps.remove(i);
+    }
+    true
+}
+
+/// Fold the removal of `name` into the step that introduced it, dropping
+/// every step on the field in between. A field renamed within the sequence
+/// is removed under its original name instead.
+fn fold_remove(steps: &mut Vec<MigrationStep>, name: &FieldName, removal: &MigrationStep) -> bool {
+    let Some(i) = last_definition(steps, name) else {
+        return false;
+    };
+    let renamed_from = match &steps[i] {
+        MigrationStep::AddField { .. }
+        | MigrationStep::AddRelation { .. }
+        | MigrationStep::CreateSchema { .. } => None,
+        MigrationStep::RenameField { old_name, new_name } if new_name == name => {
+            Some(old_name.clone())
+        }
+        _ => return false,
+    };
+    let later: Vec<MigrationStep> = steps
+        .drain(i + 1..)
+        .filter(|s| !s.affects_field(name))
+        .collect();
+    if let MigrationStep::CreateSchema { fields, .. } = &mut steps[i] {
+        fields.retain(|f| f.name != *name);
+    } else {
+        steps.remove(i);
+    }
+    steps.extend(later);
+    if let Some(original) = renamed_from {
+        let mut removal = removal.clone();
+        rename_field_refs(&mut removal, name, &original);
+        squash_step(steps, removal);
+    }
+    true
+}
+
+/// Fold a type change of `name` into the step that added the field.
+fn fold_type(steps: &mut [MigrationStep], name: &FieldName, new_type: FieldType) -> bool {
+    let Some(i) = last_definition(steps, name) else {
+        return false;
+    };
+    match defined_field_mut(&mut steps[i], name) {
+        Some(field) => {
+            field.field_type = new_type;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Fold a default change of `field` into the step that added the field,
+/// or into the previous default change since the field got its name.
+/// `value` is the new default, `None` when it is removed.
+fn fold_default(
+    steps: &mut Vec<MigrationStep>,
+    field: &FieldName,
+    value: Option<&DefaultValue>,
+) -> bool {
+    let start = match last_definition(steps, field) {
+        Some(i) => {
+            if let Some(def) = defined_field_mut(&mut steps[i], field) {
+                def.modifiers
+                    .retain(|m| !matches!(m, FieldModifier::Default { .. }));
+                if let Some(value) = value {
+                    def.modifiers.push(FieldModifier::Default {
+                        value: value.clone(),
+                    });
+                }
+                return true;
+            }
+            i + 1
+        }
+        None => 0,
+    };
+    let earlier = steps[start..].iter().rposition(|s| {
+        matches!(
+            s,
+            MigrationStep::SetDefault { field: f, .. } | MigrationStep::RemoveDefault { field: f, .. }
+                if f == field
+        )
+    });
+    let Some(j) = earlier.map(|j| j + start) else {
+        return false;
+    };
+    let original = match steps.remove(j) {
+        MigrationStep::SetDefault { previous, .. }
+        | MigrationStep::RemoveDefault { previous, .. } => previous,
+        _ => None,
+    };
+    let combined = match value {
+        Some(value) if original.as_ref() != Some(value) => Some(MigrationStep::SetDefault {
+            field: field.clone(),
+            value: value.clone(),
+            previous: original,
+        }),
+        None if original.is_some() => Some(MigrationStep::RemoveDefault {
+            field: field.clone(),
+            previous: original,
+        }),
+        // The default is back where the sequence started.
+        _ => None,
+    };
+    steps.extend(combined);
+    true
+}
+
+/// Point every reference to the field `from` in `step` at `to`.
+fn rename_field_refs(step: &mut MigrationStep, from: &FieldName, to: &FieldName) {
+    let rename = |name: &mut FieldName| {
+        if name == from {
+            *name = to.clone();
+        }
+    };
+    match step {
+        MigrationStep::CreateSchema { fields, .. } => {
+            fields.iter_mut().for_each(|f| rename(&mut f.name));
+        }
+        MigrationStep::AddField { field } => rename(&mut field.name),
+        MigrationStep::RenameField { old_name, new_name } => {
+            rename(old_name);
+            rename(new_name);
+        }
+        MigrationStep::RemoveField { name }
+        | MigrationStep::ChangeType { name, .. }
+        | MigrationStep::AddRelation { name, .. }
+        | MigrationStep::RemoveRelation { name } => rename(name),
+        MigrationStep::AddEnumVariant { field, .. }
+        | MigrationStep::RemoveEnumVariant { field, .. }
+        | MigrationStep::RenameEnumVariant { field, .. }
+        | MigrationStep::AddIndex { field }
+        | MigrationStep::RemoveIndex { field }
+        | MigrationStep::AddUnique { field }
+        | MigrationStep::RemoveUnique { field }
+        | MigrationStep::BackfillRequired { field, .. }
+        | MigrationStep::AddRequired { field }
+        | MigrationStep::RemoveRequired { field }
+        | MigrationStep::SetDefault { field, .. }
+        | MigrationStep::RemoveDefault { field, .. } => rename(field),
+        MigrationStep::AddCompositeIndex { fields, .. }
+        | MigrationStep::RemoveCompositeIndex { fields, .. } => fields.iter_mut().for_each(rename),
+        MigrationStep::DropSchema { .. }
+        | MigrationStep::AddHook { .. }
+        | MigrationStep::RemoveHook { .. }
+        | MigrationStep::ChangeHookIntent { .. }
+        | MigrationStep::EnableSoftDelete
+        | MigrationStep::DisableSoftDelete => {}
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Dependency ordering
+// ---------------------------------------------------------------------------
+
+/// Groups `schemas` into dependency layers by their relation edges.
+///
+/// Returns indices into `schemas`. A schema only relates to schemas in
+/// earlier layers (or to itself, or to schemas outside the batch), so the
+/// schemas within one layer can be applied concurrently. Derived inverse
+/// relations are ignored since they have no physical column. Schemas caught
+/// in a relation cycle, and anything depending on them, come last as
+/// single-schema layers in input order.
+pub fn dependency_layers(schemas: &[crate::types::SchemaDefinition]) -> Vec<Vec<usize>> {
+    let index: HashMap<&str, usize> = schemas
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.name.as_str(), i))
+        .collect();
+    let deps: Vec<Vec<usize>> = schemas
+        .iter()
+        .enumerate()
+        .map(|(i, schema)| {
+            let mut targets = Vec::new();
+            for field in schema.fields.iter().filter(|f| !f.is_derived()) {
+                collect_relation_targets(&field.field_type, &mut targets);
+            }
+            let mut deps: Vec<usize> = targets
+                .into_iter()
+                .filter_map(|t| index.get(t.as_str()).copied())
+                .filter(|&j| j != i)
+                .collect();
+            deps.sort_unstable();
+            deps.dedup();
+            deps
+        })
+        .collect();
+
+    let mut placed = vec![false; schemas.len()];
+    let mut layers = Vec::new();
+    loop {
+        let layer: Vec<usize> = (0..schemas.len())
+            .filter(|&i| !placed[i] && deps[i].iter().all(|&d| placed[d]))
+            .collect();
+        if layer.is_empty() {
+            break;
+        }
+        for &i in &layer {
+            placed[i] = true;
+        }
+        layers.push(layer);
+    }
+    layers.extend((0..schemas.len()).filter(|&i| !placed[i]).map(|i| vec![i]));
+    layers
+}
+
+/// Orders `plans` so every plan runs after the plans creating the schemas
+/// it relates to.
+///
+/// Edges come from the relations a plan introduces: the fields of a
+/// `CreateSchema`, added fields, and `AddRelation` steps. Relations to
+/// schemas outside the batch and self-relations are ignored. Plans without
+/// a dependency between them keep their input order. Fails with
+/// [`MigrationError::CyclicDependency`] naming the cycle when plans relate
+/// to each other in a loop.
+pub fn order_plans(plans: Vec<MigrationPlan>) -> Result<Vec<MigrationPlan>, MigrationError> {
+    let index: HashMap<&str, usize> = plans
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (p.schema_name.as_str(), i))
+        .collect();
+    let deps: Vec<Vec<usize>> = plans
+        .iter()
+        .enumerate()
+        .map(|(i, plan)| {
+            let mut targets = Vec::new();
+            for step in &plan.steps {
+                match step {
+                    MigrationStep::CreateSchema { fields, .. } => {
+                        for field in fields.iter().filter(|f| !f.is_derived()) {
+                            collect_relation_targets(&field.field_type, &mut targets);
+                        }
+                    }
+                    MigrationStep::AddField { field } if !field.is_derived() => {
+                        collect_relation_targets(&field.field_type, &mut targets);
+                    }
+                    MigrationStep::AddRelation { target, .. } => targets.push(target),
+                    _ => {}
+                }
+            }
+            let mut deps: Vec<usize> = targets
+                .into_iter()
+                .filter_map(|t| index.get(t.as_str()).copied())
+                .filter(|&j| j != i)
+                .collect();
+            deps.sort_unstable();
+            deps.dedup();
+            deps
+        })
+        .collect();
+
+    let mut placed = vec![false; plans.len()];
+    let mut order = Vec::with_capacity(plans.len());
+    while let Some(next) =
+        (0..plans.len()).find(|&i| !placed[i] && deps[i].iter().all(|&d| placed[d]))
+    {
+        placed[next] = true;
+        order.push(next);
+    }
+
+    if let Some(start) = (0..plans.len()).find(|&i| !placed[i]) {
+        // Every unplaced plan waits on another unplaced one, so following
+        // those edges from any of them must revisit a plan.
+        let mut path = vec![start];
+        let mut current = start;
+        let cycle_start = loop {
+            current = deps[current]
+                .iter()
+                .copied()
+                .find(|&d| !placed[d])
+                .expect("an unplaced plan has an unplaced dependency");
+            if let Some(pos) = path.iter().position(|&p| p == current) {
+                break pos;
+            }
+            path.push(current);
+        };
+        let mut cycle: Vec<String> = path[cycle_start..]
+            .iter()
+            .map(|&i| plans[i].schema_name.as_str().to_string())
+            .collect();
+        cycle.push(cycle[0].clone());
+        return Err(MigrationError::CyclicDependency { cycle });
+    }
+
+    let mut slots: Vec<Option<MigrationPlan>> = plans.into_iter().map(Some).collect();
+    Ok(order.into_iter().filter_map(|i| slots[i].take()).collect())
+}
+
+fn collect_relation_targets<'a>(field_type: &'a FieldType, out: &mut Vec<&'a SchemaName>) {
+    match field_type {
+        FieldType::Relation { target, .. } => out.push(target),
+        FieldType::Array(inner, _) => collect_relation_targets(inner, out),
+        FieldType::Composite(fields) => {
+            for field in fields {
+                collect_relation_targets(&field.field_type, out);
+            }
+        }
+        _ => {}
     }
 }
 
@@ -804,6 +2126,16 @@ pub enum MigrationError {
     EmptyMigrationPlan,
     /// Cannot drop a system schema.
     SystemSchemaProtected { name: String },
+    /// A step cannot be undone because the data or definition it removed
+    /// is not recorded in the step.
+    Irreversible { step_description: String },
+    /// Schemas in a batch relate to each other in a loop, so none of them
+    /// can be created first. `cycle` starts and ends with the same schema.
+    CyclicDependency { cycle: Vec<String> },
+    /// A transform override does not fit the field's type change.
+    InvalidTransform { field_name: String, reason: String },
+    /// Plans for different schemas cannot be squashed together.
+    MixedSchemas { first: String, second: String },
 }
 
 impl fmt::Display for MigrationError {
@@ -843,6 +2175,21 @@ impl fmt::Display for MigrationError {
                     "cannot drop system schema '{name}': system schemas are protected from destructive operations"
                 )
             }
+            Self::Irreversible { step_description } => {
+                write!(f, "migration step cannot be reversed: {step_description}")
+            }
+            Self::CyclicDependency { cycle } => {
+                write!(f, "cyclic relation dependency: {}", cycle.join(" -> "))
+            }
+            Self::InvalidTransform { field_name, reason } => {
+                write!(f, "invalid transform for field '{field_name}': {reason}")
+            }
+            Self::MixedSchemas { first, second } => {
+                write!(
+                    f,
+                    "cannot squash plans for different schemas: '{first}' and '{second}'"
+                )
+            }
         }
     }
 }
@@ -937,8 +2284,34 @@ mod tests {
             ValueTransform::FloatToInteger.to_string(),
             "float_to_integer"
         );
+        assert_eq!(
+            ValueTransform::FloatToDecimal { scale: 2 }.to_string(),
+            "float_to_decimal(scale: 2)"
+        );
         assert_eq!(ValueTransform::ToString.to_string(), "to_string");
+        assert_eq!(ValueTransform::ParseUuid.to_string(), "parse_uuid");
+        assert_eq!(ValueTransform::ParseNumber.to_string(), "parse_number");
+        assert_eq!(
+            ValueTransform::Truncate { max_length: 50 }.to_string(),
+            "truncate(max: 50)"
+        );
         assert_eq!(ValueTransform::SetNull.to_string(), "set_null");
+        assert_eq!(
+            ValueTransform::MapText {
+                mapping: BTreeMap::from([("active".into(), "Active".into())]),
+                default: Some("Archived".into()),
+            }
+            .to_string(),
+            "map_text([active -> Active], unmapped -> Archived)"
+        );
+        assert_eq!(
+            ValueTransform::MapText {
+                mapping: BTreeMap::new(),
+                default: None,
+            }
+            .to_string(),
+            "map_text([], unmapped -> fail)"
+        );
         assert_eq!(
             ValueTransform::SetDefault {
                 value: DefaultValue::Integer(0)
@@ -954,11 +2327,19 @@ mod tests {
             ValueTransform::Identity,
             ValueTransform::IntegerToFloat,
             ValueTransform::FloatToInteger,
+            ValueTransform::FloatToDecimal { scale: 2 },
             ValueTransform::ToString,
+            ValueTransform::ParseUuid,
+            ValueTransform::ParseNumber,
+            ValueTransform::Truncate { max_length: 50 },
             ValueTransform::SetNull,
             ValueTransform::SetDefault {
                 value: DefaultValue::Boolean(true),
             },
+            ValueTransform::MapText {
+                mapping: BTreeMap::from([("active".into(), "Active".into())]),
+                default: None,
+            },
         ];
         for t in transforms {
             let json = serde_json::to_string(&t).unwrap();
@@ -967,6 +2348,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn value_transform_map_text_fields_default_when_omitted() {
+        let t: ValueTransform = serde_json::from_str(r#"{"transform":"MapText"}"#).unwrap();
+        assert_eq!(
+            t,
+            ValueTransform::MapText {
+                mapping: BTreeMap::new(),
+                default: None,
+            }
+        );
+    }
+
     // -- MigrationStep tests --
 
     #[test]
@@ -991,9 +2384,11 @@ mod tests {
             MigrationStep::SetDefault {
                 field: FieldName::new("status").unwrap(),
                 value: DefaultValue::String("active".into()),
+                previous: None,
             },
             MigrationStep::RemoveDefault {
                 field: FieldName::new("status").unwrap(),
+                previous: None,
             },
         ];
 
@@ -1050,6 +2445,180 @@ mod tests {
         }
     }
 
+    #[test]
+    fn step_affected_fields() {
+        let name = |s: &str| FieldName::new(s).unwrap();
+        let cases = vec![
+            (
+                MigrationStep::CreateSchema {
+                    name: SchemaName::new("Test").unwrap(),
+                    fields: vec![make_field("name"), make_field("email")],
+                },
+                vec!["name", "email"],
+            ),
+            (
+                MigrationStep::DropSchema {
+                    name: SchemaName::new("Old").unwrap(),
+                },
+                vec![],
+            ),
+            (
+                MigrationStep::AddField {
+                    field: make_field("email"),
+                },
+                vec!["email"],
+            ),
+            (
+                MigrationStep::RemoveField {
+                    name: name("old_field"),
+                },
+                vec!["old_field"],
+            ),
+            (
+                MigrationStep::RenameField {
+                    old_name: name("name"),
+                    new_name: name("full_name"),
+                },
+                vec!["name", "full_name"],
+            ),
+            (
+                MigrationStep::ChangeType {
+                    name: name("score"),
+                    old_type: FieldType::Integer(IntegerConstraints::unconstrained()),
+                    new_type: FieldType::Float(FloatConstraints::unconstrained()),
+                    transform: ValueTransform::IntegerToFloat,
+                },
+                vec!["score"],
+            ),
+            (
+                MigrationStep::AddIndex {
+                    field: name("email"),
+                },
+                vec!["email"],
+            ),
+            (
+                MigrationStep::RemoveIndex {
+                    field: name("email"),
+                },
+                vec!["email"],
+            ),
+            (
+                MigrationStep::AddRelation {
+                    name: name("company"),
+                    target: SchemaName::new("Company").unwrap(),
+                    cardinality: Cardinality::One,
+                },
+                vec!["company"],
+            ),
+            (
+                MigrationStep::RemoveRelation {
+                    name: name("company"),
+                },
+                vec!["company"],
+            ),
+            (
+                MigrationStep::BackfillRequired {
+                    field: name("status"),
+                    default_value: DynamicValue::Text("active".into()),
+                },
+                vec!["status"],
+            ),
+            (
+                MigrationStep::AddRequired {
+                    field: name("email"),
+                },
+                vec!["email"],
+            ),
+            (
+                MigrationStep::RemoveRequired {
+                    field: name("email"),
+                },
+                vec!["email"],
+            ),
+            (
+                MigrationStep::SetDefault {
+                    field: name("status"),
+                    value: DefaultValue::String("active".into()),
+                    previous: None,
+                },
+                vec!["status"],
+            ),
+            (
+                MigrationStep::RemoveDefault {
+                    field: name("status"),
+                    previous: None,
+                },
+                vec!["status"],
+            ),
+            (
+                MigrationStep::AddHook {
+                    event: crate::types::HookEvent::AfterChange,
+                    intent: "notify".into(),
+                },
+                vec![],
+            ),
+            (
+                MigrationStep::RemoveHook {
+                    event: crate::types::HookEvent::AfterChange,
+                },
+                vec![],
+            ),
+            (
+                MigrationStep::ChangeHookIntent {
+                    event: crate::types::HookEvent::AfterChange,
+                    old_intent: "notify".into(),
+                    new_intent: "notify twice".into(),
+                },
+                vec![],
+            ),
+        ];
+
+        for (step, expected) in &cases {
+            let actual: Vec<&str> = step.affected_fields().iter().map(|f| f.as_str()).collect();
+            assert_eq!(&actual, expected, "affected fields of {step}");
+            for field in expected {
+                assert!(
+                    step.affects_field(&name(*field)),
+                    "{step} should affect {field}"
+                );
+            }
+            assert!(!step.affects_field(&name("unrelated")));
+        }
+    }
+
+    #[test]
+    fn step_affects_schema_structure() {
+        let structural = [
+            MigrationStep::AddField {
+                field: make_field("email"),
+            },
+            MigrationStep::AddIndex {
+                field: FieldName::new("email").unwrap(),
+            },
+            MigrationStep::SetDefault {
+                field: FieldName::new("status").unwrap(),
+                value: DefaultValue::String("active".into()),
+                previous: None,
+            },
+        ];
+        for step in &structural {
+            assert!(step.affects_schema_structure(), "{step}");
+        }
+
+        let non_structural = [
+            MigrationStep::BackfillRequired {
+                field: FieldName::new("status").unwrap(),
+                default_value: DynamicValue::Text("active".into()),
+            },
+            MigrationStep::RemoveHook {
+                event: crate::types::HookEvent::AfterChange,
+            },
+        ];
+        for step in &non_structural {
+            assert!(!step.affects_schema_structure(), "{step}");
+        }
+    }
+
     #[test]
     fn step_display() {
         let step = MigrationStep::AddField {
@@ -1067,6 +2636,17 @@ mod tests {
             new_name: FieldName::new("full_name").unwrap(),
         };
         assert_eq!(step.to_string(), "RENAME field 'name' to 'full_name'");
+
+        let step = MigrationStep::RemoveEnumVariant {
+            field: FieldName::new("status").unwrap(),
+            variant: "Stale".into(),
+            fallback: Some("Open".into()),
+            variants: EnumVariants::new(vec!["Open".into()]).unwrap(),
+        };
+        assert_eq!(
+            step.to_string(),
+            "REMOVE VARIANT 'Stale' from 'status' (remap to Open)"
+        );
     }
 
     #[test]
@@ -1085,6 +2665,12 @@ mod tests {
             MigrationStep::AddIndex {
                 field: FieldName::new("email").unwrap(),
             },
+            MigrationStep::RenameEnumVariant {
+                field: FieldName::new("status").unwrap(),
+                old_variant: "Done".into(),
+                new_variant: "Closed".into(),
+                variants: EnumVariants::new(vec!["Open".into(), "Closed".into()]).unwrap(),
+            },
         ];
         for step in steps {
             let json = serde_json::to_string(&step).unwrap();
@@ -1206,42 +2792,458 @@ mod tests {
         );
     }
 
+    fn status_field(variants: &[&str], modifiers: Vec<FieldModifier>) -> FieldDefinition {
+        FieldDefinition::with_modifiers(
+            FieldName::new("status").unwrap(),
+            FieldType::Enum(
+                EnumVariants::new(variants.iter().map(|v| v.to_string()).collect()).unwrap(),
+            ),
+            modifiers,
+        )
+    }
+
     #[test]
-    fn diff_detects_type_change() {
+    fn diff_enum_variant_removal_remaps_to_default() {
         let old = make_schema(
-            "Stats",
-            vec![FieldDefinition::new(
-                FieldName::new("score").unwrap(),
-                FieldType::Integer(IntegerConstraints::unconstrained()),
-            )],
+            "Ticket",
+            vec![status_field(&["Open", "Stale", "Closed"], vec![])],
         );
         let new = make_schema(
-            "Stats",
-            vec![FieldDefinition::new(
-                FieldName::new("score").unwrap(),
-                FieldType::Float(FloatConstraints::unconstrained()),
+            "Ticket",
+            vec![status_field(
+                &["Open", "Closed"],
+                vec![FieldModifier::Default {
+                    value: DefaultValue::String("Closed".into()),
+                }],
             )],
         );
         let plan = DiffEngine::diff(&old, &new);
-        assert_eq!(plan.len(), 1);
+        let remove = plan
+            .steps
+            .iter()
+            .find(|s| matches!(s, MigrationStep::RemoveEnumVariant { .. }))
+            .expect("enum change emits a RemoveEnumVariant step");
+        assert_eq!(remove.safety(), MigrationSafety::Destructive);
         assert!(matches!(
-            &plan.steps[0],
-            MigrationStep::ChangeType { name, transform: ValueTransform::IntegerToFloat, .. }
-            if name.as_str() == "score"
+            remove,
+            MigrationStep::RemoveEnumVariant { variant, fallback, variants, .. }
+                if variant == "Stale"
+                    && fallback.as_deref() == Some("Closed")
+                    && variants.as_slice() == ["Open", "Closed"]
         ));
+        assert!(!plan
+            .steps
+            .iter()
+            .any(|s| matches!(s, MigrationStep::ChangeType { .. })));
     }
 
     #[test]
-    fn diff_detects_modifier_changes() {
-        let old = make_schema(
-            "Contact",
-            vec![FieldDefinition::new(
-                FieldName::new("email").unwrap(),
-                FieldType::Text(TextConstraints::unconstrained()),
-            )],
-        );
-        let new = make_schema(
-            "Contact",
+    fn diff_enum_variant_removal_without_default_remaps_to_null() {
+        let old = make_schema("Ticket", vec![status_field(&["Open", "Stale"], vec![])]);
+        let new = make_schema("Ticket", vec![status_field(&["Open"], vec![])]);
+        let plan = DiffEngine::diff(&old, &new);
+        assert!(matches!(
+            &plan.steps[..],
+            [MigrationStep::RemoveEnumVariant { fallback: None, .. }]
+        ));
+    }
+
+    #[test]
+    fn diff_enum_variant_addition_keeps_values() {
+        let old = make_schema("Ticket", vec![status_field(&["Open"], vec![])]);
+        let new = make_schema("Ticket", vec![status_field(&["Open", "Stale"], vec![])]);
+        let plan = DiffEngine::diff(&old, &new);
+        assert!(plan.is_safe());
+        assert!(matches!(
+            &plan.steps[..],
+            [MigrationStep::AddEnumVariant { variant, variants, .. }]
+                if variant == "Stale" && variants.as_slice() == ["Open", "Stale"]
+        ));
+    }
+
+    #[test]
+    fn diff_enum_variant_reorder_emits_nothing() {
+        let old = make_schema("Ticket", vec![status_field(&["Open", "Closed"], vec![])]);
+        let new = make_schema("Ticket", vec![status_field(&["Closed", "Open"], vec![])]);
+        assert!(DiffEngine::diff(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn diff_enum_variant_rename_with_hint() {
+        let old = make_schema(
+            "Ticket",
+            vec![status_field(&["Open", "Done", "Stale"], vec![])],
+        );
+        let new = make_schema(
+            "Ticket",
+            vec![status_field(&["Open", "Closed", "Blocked"], vec![])],
+        );
+        let hints = [(
+            FieldName::new("status").unwrap(),
+            "Done".to_string(),
+            "Closed".to_string(),
+        )];
+        let plan = DiffEngine::diff_with_variant_renames(&old, &new, &[], &hints);
+        let shown: Vec<String> = plan.steps.iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            shown,
+            [
+                "RENAME VARIANT 'Done' to 'Closed' on 'status'",
+                "ADD VARIANT 'Blocked' to 'status'",
+                "REMOVE VARIANT 'Stale' from 'status' (remap to null)",
+            ]
+        );
+        assert_eq!(
+            plan.steps[0].safety(),
+            MigrationSafety::RequiresConfirmation
+        );
+        assert!(matches!(
+            &plan.steps[0],
+            MigrationStep::RenameEnumVariant { variants, .. }
+                if variants.as_slice() == ["Open", "Closed", "Stale"]
+        ));
+        assert!(matches!(
+            &plan.steps[2],
+            MigrationStep::RemoveEnumVariant { variants, .. }
+                if variants.as_slice() == ["Open", "Closed", "Blocked"]
+        ));
+    }
+
+    #[test]
+    fn diff_enum_variant_rename_without_hint_removes_and_adds() {
+        let old = make_schema("Ticket", vec![status_field(&["Open", "Done"], vec![])]);
+        let new = make_schema("Ticket", vec![status_field(&["Open", "Closed"], vec![])]);
+        let plan = DiffEngine::diff(&old, &new);
+        assert!(matches!(
+            &plan.steps[..],
+            [
+                MigrationStep::AddEnumVariant { .. },
+                MigrationStep::RemoveEnumVariant { .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn diff_enum_variant_hint_for_other_field_is_ignored() {
+        let old = make_schema("Ticket", vec![status_field(&["Open", "Done"], vec![])]);
+        let new = make_schema("Ticket", vec![status_field(&["Open", "Closed"], vec![])]);
+        let hints = [(
+            FieldName::new("priority").unwrap(),
+            "Done".to_string(),
+            "Closed".to_string(),
+        )];
+        let plan = DiffEngine::diff_with_variant_renames(&old, &new, &[], &hints);
+        assert!(!plan
+            .steps
+            .iter()
+            .any(|s| matches!(s, MigrationStep::RenameEnumVariant { .. })));
+    }
+
+    #[test]
+    fn diff_detects_type_change() {
+        let old = make_schema(
+            "Stats",
+            vec![FieldDefinition::new(
+                FieldName::new("score").unwrap(),
+                FieldType::Integer(IntegerConstraints::unconstrained()),
+            )],
+        );
+        let new = make_schema(
+            "Stats",
+            vec![FieldDefinition::new(
+                FieldName::new("score").unwrap(),
+                FieldType::Float(FloatConstraints::unconstrained()),
+            )],
+        );
+        let plan = DiffEngine::diff(&old, &new);
+        assert_eq!(plan.len(), 1);
+        assert!(matches!(
+            &plan.steps[0],
+            MigrationStep::ChangeType { name, transform: ValueTransform::IntegerToFloat, .. }
+            if name.as_str() == "score"
+        ));
+    }
+
+    #[test]
+    fn diff_float_to_decimal_needs_confirmation() {
+        let old = make_schema(
+            "Invoice",
+            vec![FieldDefinition::new(
+                FieldName::new("amount").unwrap(),
+                FieldType::Float(FloatConstraints::unconstrained()),
+            )],
+        );
+        let new = make_schema(
+            "Invoice",
+            vec![FieldDefinition::new(
+                FieldName::new("amount").unwrap(),
+                FieldType::Decimal { scale: 2 },
+            )],
+        );
+        let plan = DiffEngine::diff(&old, &new);
+        assert_eq!(plan.len(), 1);
+        assert!(matches!(
+            &plan.steps[0],
+            MigrationStep::ChangeType {
+                transform: ValueTransform::FloatToDecimal { scale: 2 },
+                ..
+            }
+        ));
+        assert_eq!(
+            plan.steps[0].safety(),
+            MigrationSafety::RequiresConfirmation
+        );
+    }
+
+    #[test]
+    fn diff_text_to_uuid_needs_confirmation_and_inverts() {
+        let old = make_schema("Account", vec![make_field("external_id")]);
+        let new = make_schema(
+            "Account",
+            vec![FieldDefinition::new(
+                FieldName::new("external_id").unwrap(),
+                FieldType::Uuid,
+            )],
+        );
+        let plan = DiffEngine::diff(&old, &new);
+        assert_eq!(plan.len(), 1);
+        assert!(matches!(
+            &plan.steps[0],
+            MigrationStep::ChangeType {
+                transform: ValueTransform::ParseUuid,
+                ..
+            }
+        ));
+        assert_eq!(
+            plan.steps[0].safety(),
+            MigrationSafety::RequiresConfirmation
+        );
+        assert!(matches!(
+            &DiffEngine::invert(&plan).unwrap().steps[0],
+            MigrationStep::ChangeType {
+                new_type: FieldType::Text(_),
+                transform: ValueTransform::ToString,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn diff_text_to_number_parses_and_inverts() {
+        let old = make_schema("Deal", vec![make_field("amount")]);
+        let new = make_schema(
+            "Deal",
+            vec![FieldDefinition::new(
+                FieldName::new("amount").unwrap(),
+                FieldType::Integer(IntegerConstraints::unconstrained()),
+            )],
+        );
+        let plan = DiffEngine::diff(&old, &new);
+        assert!(matches!(
+            &plan.steps[0],
+            MigrationStep::ChangeType {
+                transform: ValueTransform::ParseNumber,
+                ..
+            }
+        ));
+        assert!(matches!(
+            &DiffEngine::invert(&plan).unwrap().steps[0],
+            MigrationStep::ChangeType {
+                transform: ValueTransform::ToString,
+                ..
+            }
+        ));
+    }
+
+    fn text_limit_plan(old: Option<u32>, new: Option<u32>) -> MigrationPlan {
+        let field = |max: Option<u32>| {
+            FieldDefinition::new(
+                FieldName::new("bio").unwrap(),
+                FieldType::Text(TextConstraints {
+                    max_length: max,
+                    pattern: None,
+                }),
+            )
+        };
+        DiffEngine::diff(
+            &make_schema("Contact", vec![field(old)]),
+            &make_schema("Contact", vec![field(new)]),
+        )
+    }
+
+    #[test]
+    fn diff_text_limit_decrease_truncates_with_confirmation() {
+        let plan = text_limit_plan(Some(255), Some(50));
+        assert_eq!(plan.len(), 1);
+        assert!(matches!(
+            &plan.steps[0],
+            MigrationStep::ChangeType {
+                transform: ValueTransform::Truncate { max_length: 50 },
+                ..
+            }
+        ));
+        assert_eq!(
+            plan.steps[0].safety(),
+            MigrationSafety::RequiresConfirmation
+        );
+        assert_eq!(
+            plan.steps[0].to_string(),
+            "CHANGE TYPE of 'bio' from Text to Text via truncate(max: 50) (may truncate 205 characters)"
+        );
+
+        let plan = text_limit_plan(None, Some(50));
+        assert!(plan.steps[0]
+            .to_string()
+            .ends_with("(may truncate values longer than 50 characters)"));
+        // The cut characters are gone, so the change cannot be undone.
+        assert!(DiffEngine::invert(&plan).is_err());
+    }
+
+    #[test]
+    fn diff_text_limit_increase_is_safe_and_inverts_to_truncate() {
+        for (old, new) in [(Some(50), Some(255)), (Some(50), None)] {
+            let plan = text_limit_plan(old, new);
+            assert!(matches!(
+                &plan.steps[0],
+                MigrationStep::ChangeType {
+                    transform: ValueTransform::Identity,
+                    ..
+                }
+            ));
+            assert!(plan.is_safe());
+            assert!(matches!(
+                &DiffEngine::invert(&plan).unwrap().steps[0],
+                MigrationStep::ChangeType {
+                    transform: ValueTransform::Truncate { max_length: 50 },
+                    ..
+                }
+            ));
+        }
+    }
+
+    fn text_to_status_plan() -> MigrationPlan {
+        let old = make_schema("Deal", vec![make_field("status")]);
+        let new = make_schema(
+            "Deal",
+            vec![FieldDefinition::new(
+                FieldName::new("status").unwrap(),
+                FieldType::Enum(EnumVariants::new(vec!["Open".into(), "Won".into()]).unwrap()),
+            )],
+        );
+        DiffEngine::diff(&old, &new)
+    }
+
+    #[test]
+    fn diff_text_to_enum_keeps_matching_values() {
+        let plan = text_to_status_plan();
+        assert_eq!(
+            plan.steps[0],
+            MigrationStep::ChangeType {
+                name: FieldName::new("status").unwrap(),
+                old_type: FieldType::Text(TextConstraints::unconstrained()),
+                new_type: FieldType::Enum(
+                    EnumVariants::new(vec!["Open".into(), "Won".into()]).unwrap()
+                ),
+                transform: ValueTransform::MapText {
+                    mapping: BTreeMap::new(),
+                    default: None,
+                },
+            }
+        );
+        assert!(DiffEngine::invert(&plan).is_ok());
+    }
+
+    #[test]
+    fn override_transform_replaces_inferred_transform() {
+        let mut plan = text_to_status_plan();
+        let transform = ValueTransform::MapText {
+            mapping: BTreeMap::from([("open".into(), "Open".into())]),
+            default: Some("Won".into()),
+        };
+        plan.override_transform(&FieldName::new("status").unwrap(), transform.clone())
+            .unwrap();
+        assert!(matches!(
+            &plan.steps[0],
+            MigrationStep::ChangeType { transform: t, .. } if *t == transform
+        ));
+        // A mapping loses the original values, so it cannot be undone.
+        assert!(DiffEngine::invert(&plan).is_err());
+    }
+
+    #[test]
+    fn override_transform_rejects_mismatches() {
+        let status = FieldName::new("status").unwrap();
+        let mut plan = text_to_status_plan();
+
+        let err = plan
+            .override_transform(
+                &status,
+                ValueTransform::MapText {
+                    mapping: BTreeMap::from([("open".into(), "Pending".into())]),
+                    default: None,
+                },
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid transform for field 'status': 'Pending' is not a variant of the new enum"
+        );
+
+        let err = plan
+            .override_transform(&status, ValueTransform::ParseNumber)
+            .unwrap_err();
+        assert!(matches!(err, MigrationError::InvalidTransform { .. }));
+
+        let err = plan
+            .override_transform(&FieldName::new("title").unwrap(), ValueTransform::SetNull)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid transform for field 'title': the plan does not change its type"
+        );
+    }
+
+    #[test]
+    fn diff_array_bounds_change_keeps_values() {
+        let text = || FieldType::Text(TextConstraints::unconstrained());
+        let old = make_schema(
+            "Post",
+            vec![FieldDefinition::new(
+                FieldName::new("tags").unwrap(),
+                FieldType::array(text()),
+            )],
+        );
+        let new = make_schema(
+            "Post",
+            vec![FieldDefinition::new(
+                FieldName::new("tags").unwrap(),
+                FieldType::Array(
+                    Box::new(text()),
+                    crate::types::ArrayConstraints::with_bounds(Some(1), None).unwrap(),
+                ),
+            )],
+        );
+        let plan = DiffEngine::diff(&old, &new);
+        assert!(matches!(
+            plan.steps.as_slice(),
+            [MigrationStep::ChangeType {
+                transform: ValueTransform::Identity,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn diff_detects_modifier_changes() {
+        let old = make_schema(
+            "Contact",
+            vec![FieldDefinition::new(
+                FieldName::new("email").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            )],
+        );
+        let new = make_schema(
+            "Contact",
             vec![FieldDefinition::with_modifiers(
                 FieldName::new("email").unwrap(),
                 FieldType::Text(TextConstraints::unconstrained()),
@@ -1259,6 +3261,43 @@ mod tests {
             .any(|s| matches!(s, MigrationStep::AddIndex { field } if field.as_str() == "email")));
     }
 
+    #[test]
+    fn diff_unique_modifier_needs_confirmation_and_inverts() {
+        let plain = make_schema("Contact", vec![make_field("email")]);
+        let unique = make_schema(
+            "Contact",
+            vec![FieldDefinition::with_modifiers(
+                FieldName::new("email").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![FieldModifier::Unique],
+            )],
+        );
+        let email = FieldName::new("email").unwrap();
+
+        let add = DiffEngine::diff(&plain, &unique);
+        assert_eq!(
+            add.steps,
+            vec![MigrationStep::AddUnique {
+                field: email.clone()
+            }]
+        );
+        assert_eq!(add.steps[0].to_string(), "ADD UNIQUE on 'email'");
+        assert_eq!(add.overall_safety(), MigrationSafety::RequiresConfirmation);
+        assert_eq!(
+            DiffEngine::invert(&add).unwrap().steps,
+            vec![MigrationStep::RemoveUnique {
+                field: email.clone()
+            }]
+        );
+
+        let remove = DiffEngine::diff(&unique, &plain);
+        assert_eq!(
+            remove.steps,
+            vec![MigrationStep::RemoveUnique { field: email }]
+        );
+        assert_eq!(remove.overall_safety(), MigrationSafety::Safe);
+    }
+
     #[test]
     fn diff_detects_added_relation() {
         let old = make_schema("Contact", vec![make_field("name")]);
@@ -1334,7 +3373,7 @@ mod tests {
         assert_eq!(plan.len(), 1);
         assert!(matches!(
             &plan.steps[0],
-            MigrationStep::SetDefault { field, value: DefaultValue::String(s) }
+            MigrationStep::SetDefault { field, value: DefaultValue::String(s), .. }
             if field.as_str() == "status" && s == "pending"
         ));
     }
@@ -1362,7 +3401,7 @@ mod tests {
         assert_eq!(plan.len(), 1);
         assert!(matches!(
             &plan.steps[0],
-            MigrationStep::RemoveDefault { field } if field.as_str() == "status"
+            MigrationStep::RemoveDefault { field, .. } if field.as_str() == "status"
         ));
     }
 
@@ -1372,11 +3411,129 @@ mod tests {
         let plan = DiffEngine::create_new(&schema);
         assert_eq!(plan.len(), 1);
         assert!(matches!(
-            &plan.steps[0],
-            MigrationStep::CreateSchema { name, fields }
-            if name.as_str() == "Contact" && fields.len() == 2
+            &plan.steps[0],
+            MigrationStep::CreateSchema { name, fields }
+            if name.as_str() == "Contact" && fields.len() == 2
+        ));
+        assert!(plan.is_safe());
+    }
+
+    // -- Inversion tests --
+
+    fn default_status(value: &str) -> FieldDefinition {
+        FieldDefinition::with_modifiers(
+            FieldName::new("status").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+            vec![FieldModifier::Default {
+                value: DefaultValue::String(value.into()),
+            }],
+        )
+    }
+
+    #[test]
+    fn invert_undoes_steps_in_reverse_order() {
+        let old = make_schema("Contact", vec![make_field("name"), default_status("open")]);
+        let new = make_schema(
+            "Contact",
+            vec![
+                make_field("full_name"),
+                default_status("new"),
+                make_field("email"),
+            ],
+        );
+        let renames = [(
+            FieldName::new("name").unwrap(),
+            FieldName::new("full_name").unwrap(),
+        )];
+        let plan = DiffEngine::diff_with_renames(&old, &new, &renames);
+        let inverse = DiffEngine::invert(&plan).unwrap();
+
+        let shown: Vec<String> = inverse.steps.iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            shown,
+            [
+                "SET DEFAULT on 'status' to \"open\"",
+                "REMOVE field 'email'",
+                "RENAME field 'full_name' to 'name'",
+            ]
+        );
+        assert_eq!(inverse.schema_name, plan.schema_name);
+        assert_ne!(inverse.id, plan.id);
+    }
+
+    #[test]
+    fn invert_twice_restores_reversible_steps() {
+        let status = FieldName::new("status").unwrap();
+        let plan = MigrationPlan::new(
+            SchemaId::new(),
+            SchemaName::new("Ticket").unwrap(),
+            vec![
+                MigrationStep::SetDefault {
+                    field: status.clone(),
+                    value: DefaultValue::String("open".into()),
+                    previous: None,
+                },
+                MigrationStep::AddIndex {
+                    field: status.clone(),
+                },
+                MigrationStep::ChangeType {
+                    name: FieldName::new("score").unwrap(),
+                    old_type: FieldType::Integer(IntegerConstraints::unconstrained()),
+                    new_type: FieldType::Float(FloatConstraints::unconstrained()),
+                    transform: ValueTransform::IntegerToFloat,
+                },
+                MigrationStep::RenameEnumVariant {
+                    field: FieldName::new("stage").unwrap(),
+                    old_variant: "Done".into(),
+                    new_variant: "Closed".into(),
+                    variants: EnumVariants::new(vec!["Open".into(), "Closed".into()]).unwrap(),
+                },
+            ],
+        );
+        let inverse = DiffEngine::invert(&plan).unwrap();
+        assert!(matches!(
+            &inverse.steps[0],
+            MigrationStep::RenameEnumVariant { variants, .. }
+                if variants.as_slice() == ["Open", "Done"]
+        ));
+        assert!(matches!(
+            &inverse.steps[3],
+            MigrationStep::RemoveDefault { previous: Some(DefaultValue::String(v)), .. }
+                if v == "open"
+        ));
+        assert_eq!(DiffEngine::invert(&inverse).unwrap().steps, plan.steps);
+    }
+
+    #[test]
+    fn invert_rejects_irreversible_steps() {
+        let plan = DiffEngine::diff(
+            &make_schema("Contact", vec![make_field("name"), make_field("fax")]),
+            &make_schema("Contact", vec![make_field("name")]),
+        );
+        assert_eq!(
+            DiffEngine::invert(&plan).unwrap_err(),
+            MigrationError::Irreversible {
+                step_description: "REMOVE field 'fax'".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn invert_rejects_lossy_type_change() {
+        let plan = DiffEngine::diff(
+            &make_schema("Stats", vec![make_field("score")]),
+            &make_schema(
+                "Stats",
+                vec![FieldDefinition::new(
+                    FieldName::new("score").unwrap(),
+                    FieldType::Boolean,
+                )],
+            ),
+        );
+        assert!(matches!(
+            DiffEngine::invert(&plan),
+            Err(MigrationError::UnsupportedTypeConversion { field_name, .. }) if field_name == "score"
         ));
-        assert!(plan.is_safe());
     }
 
     #[test]
@@ -1514,6 +3671,322 @@ mod tests {
             .any(|s| matches!(s, MigrationStep::RenameField { .. })));
     }
 
+    // -- Squash tests --
+
+    fn contact_plan(steps: Vec<MigrationStep>) -> MigrationPlan {
+        MigrationPlan::new(SchemaId::new(), SchemaName::new("Contact").unwrap(), steps)
+    }
+
+    fn field_name(name: &str) -> FieldName {
+        FieldName::new(name).unwrap()
+    }
+
+    #[test]
+    fn squash_add_then_remove_cancels() {
+        let plans = [
+            contact_plan(vec![
+                MigrationStep::AddField {
+                    field: make_field("nick"),
+                },
+                MigrationStep::AddIndex {
+                    field: field_name("nick"),
+                },
+            ]),
+            contact_plan(vec![MigrationStep::RemoveField {
+                name: field_name("nick"),
+            }]),
+        ];
+        assert!(MigrationPlan::squash(&plans).unwrap().is_empty());
+    }
+
+    #[test]
+    fn squash_matches_direct_diff_for_add_rename_and_retype() {
+        let v1 = make_schema("Contact", vec![make_field("name")]);
+        let v2 = make_schema("Contact", vec![make_field("name"), make_field("nick")]);
+        let v3 = make_schema("Contact", vec![make_field("name"), make_field("nickname")]);
+        let v4 = make_schema(
+            "Contact",
+            vec![
+                make_field("name"),
+                FieldDefinition::with_modifiers(
+                    field_name("nickname"),
+                    FieldType::Text(TextConstraints::with_max_length(40)),
+                    vec![FieldModifier::Default {
+                        value: DefaultValue::String("friend".into()),
+                    }],
+                ),
+            ],
+        );
+        let plans = [
+            DiffEngine::diff(&v1, &v2),
+            DiffEngine::diff_with_renames(
+                &v2,
+                &v3,
+                &[(field_name("nick"), field_name("nickname"))],
+            ),
+            DiffEngine::diff(&v3, &v4),
+        ];
+        let squashed = MigrationPlan::squash(&plans).unwrap();
+        assert_eq!(squashed.steps, DiffEngine::diff(&v1, &v4).steps);
+        assert_eq!(squashed.schema_id, v4.id);
+    }
+
+    #[test]
+    fn squash_collapses_defaults_to_the_last() {
+        let set = |value: &str, previous: Option<&str>| MigrationStep::SetDefault {
+            field: field_name("stage"),
+            value: DefaultValue::String(value.into()),
+            previous: previous.map(|p| DefaultValue::String(p.into())),
+        };
+        let mut plans = vec![
+            contact_plan(vec![set("lead", None)]),
+            contact_plan(vec![set("prospect", Some("lead"))]),
+            contact_plan(vec![set("customer", Some("prospect"))]),
+        ];
+        assert_eq!(
+            MigrationPlan::squash(&plans).unwrap().steps,
+            vec![set("customer", None)]
+        );
+
+        // Removing the default again restores the start.
+        plans.push(contact_plan(vec![MigrationStep::RemoveDefault {
+            field: field_name("stage"),
+            previous: Some(DefaultValue::String("customer".into())),
+        }]));
+        assert!(MigrationPlan::squash(&plans).unwrap().is_empty());
+    }
+
+    #[test]
+    fn squash_chains_renames_and_drops_round_trips() {
+        let rename = |from: &str, to: &str| MigrationStep::RenameField {
+            old_name: field_name(from),
+            new_name: field_name(to),
+        };
+        let mut plans = vec![
+            contact_plan(vec![rename("phone", "mobile")]),
+            contact_plan(vec![MigrationStep::AddIndex {
+                field: field_name("mobile"),
+            }]),
+            contact_plan(vec![rename("mobile", "cell")]),
+        ];
+        assert_eq!(
+            MigrationPlan::squash(&plans).unwrap().steps,
+            vec![
+                rename("phone", "cell"),
+                MigrationStep::AddIndex {
+                    field: field_name("cell"),
+                },
+            ]
+        );
+
+        plans.push(contact_plan(vec![rename("cell", "phone")]));
+        assert_eq!(
+            MigrationPlan::squash(&plans).unwrap().steps,
+            vec![MigrationStep::AddIndex {
+                field: field_name("phone"),
+            }]
+        );
+
+        // A renamed field that is then removed is removed under its
+        // original name.
+        plans[3] = contact_plan(vec![MigrationStep::RemoveField {
+            name: field_name("cell"),
+        }]);
+        assert_eq!(
+            MigrationPlan::squash(&plans).unwrap().steps,
+            vec![MigrationStep::RemoveField {
+                name: field_name("phone"),
+            }]
+        );
+    }
+
+    #[test]
+    fn squash_drop_discards_earlier_steps() {
+        let create = MigrationStep::CreateSchema {
+            name: SchemaName::new("Contact").unwrap(),
+            fields: vec![make_field("name")],
+        };
+        let add = MigrationStep::AddField {
+            field: make_field("email"),
+        };
+        let drop = MigrationStep::DropSchema {
+            name: SchemaName::new("Contact").unwrap(),
+        };
+
+        let plans = [
+            contact_plan(vec![add.clone()]),
+            contact_plan(vec![drop.clone()]),
+        ];
+        assert_eq!(
+            MigrationPlan::squash(&plans).unwrap().steps,
+            vec![drop.clone()]
+        );
+
+        let plans = [
+            contact_plan(vec![create]),
+            contact_plan(vec![add]),
+            contact_plan(vec![drop]),
+        ];
+        assert!(MigrationPlan::squash(&plans).unwrap().is_empty());
+    }
+
+    #[test]
+    fn squash_rejects_empty_and_mixed_input() {
+        assert_eq!(
+            MigrationPlan::squash(&[]).unwrap_err(),
+            MigrationError::EmptyMigrationPlan
+        );
+        let other = MigrationPlan::new(
+            SchemaId::new(),
+            SchemaName::new("Company").unwrap(),
+            Vec::new(),
+        );
+        let err = MigrationPlan::squash(&[other, contact_plan(Vec::new())]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot squash plans for different schemas: 'Company' and 'Contact'"
+        );
+    }
+
+    // -- Dependency ordering tests --
+
+    fn make_relation(name: &str, target: &str) -> FieldDefinition {
+        FieldDefinition::new(
+            FieldName::new(name).unwrap(),
+            FieldType::Relation {
+                target: SchemaName::new(target).unwrap(),
+                cardinality: Cardinality::One,
+            },
+        )
+    }
+
+    #[test]
+    fn dependency_layers_put_targets_first() {
+        let schemas = vec![
+            make_schema("Contact", vec![make_relation("company", "Company")]),
+            make_schema("Company", vec![make_field("name")]),
+            make_schema("Tag", vec![make_field("name")]),
+            make_schema(
+                "Deal",
+                vec![
+                    make_relation("contact", "Contact"),
+                    FieldDefinition::new(
+                        FieldName::new("partners").unwrap(),
+                        FieldType::array(FieldType::Relation {
+                            target: SchemaName::new("Company").unwrap(),
+                            cardinality: Cardinality::One,
+                        }),
+                    ),
+                ],
+            ),
+        ];
+        assert_eq!(
+            dependency_layers(&schemas),
+            vec![vec![1, 2], vec![0], vec![3]]
+        );
+    }
+
+    #[test]
+    fn dependency_layers_ignore_self_and_external_relations() {
+        let schemas = vec![
+            make_schema(
+                "Employee",
+                vec![
+                    make_relation("manager", "Employee"),
+                    make_relation("owner", "User"),
+                ],
+            ),
+            make_schema("Team", vec![make_field("name")]),
+        ];
+        assert_eq!(dependency_layers(&schemas), vec![vec![0, 1]]);
+    }
+
+    #[test]
+    fn dependency_layers_put_cycles_last() {
+        let schemas = vec![
+            make_schema("A", vec![make_relation("b", "B")]),
+            make_schema("B", vec![make_relation("a", "A")]),
+            make_schema("C", vec![make_field("name")]),
+        ];
+        assert_eq!(dependency_layers(&schemas), vec![vec![2], vec![0], vec![1]]);
+    }
+
+    fn plan_names(plans: &[MigrationPlan]) -> Vec<&str> {
+        plans.iter().map(|p| p.schema_name.as_str()).collect()
+    }
+
+    #[test]
+    fn order_plans_puts_targets_first() {
+        let plans = vec![
+            DiffEngine::create_new(&make_schema(
+                "Contact",
+                vec![make_relation("company", "Company")],
+            )),
+            DiffEngine::create_new(&make_schema("Tag", vec![make_field("name")])),
+            DiffEngine::create_new(&make_schema(
+                "Company",
+                vec![make_relation("region", "Region")],
+            )),
+            DiffEngine::create_new(&make_schema("Region", vec![make_field("name")])),
+        ];
+        let ordered = order_plans(plans).unwrap();
+        assert_eq!(
+            plan_names(&ordered),
+            ["Tag", "Region", "Company", "Contact"]
+        );
+    }
+
+    #[test]
+    fn order_plans_follows_added_relations() {
+        let old = make_schema("Contact", vec![make_field("name")]);
+        let new = make_schema(
+            "Contact",
+            vec![make_field("name"), make_relation("company", "Company")],
+        );
+        let plans = vec![
+            DiffEngine::diff(&old, &new),
+            DiffEngine::create_new(&make_schema("Company", vec![make_field("name")])),
+        ];
+        assert_eq!(
+            plan_names(&order_plans(plans).unwrap()),
+            ["Company", "Contact"]
+        );
+    }
+
+    #[test]
+    fn order_plans_tolerates_self_and_external_relations() {
+        let plans = vec![
+            DiffEngine::create_new(&make_schema(
+                "Employee",
+                vec![
+                    make_relation("manager", "Employee"),
+                    make_relation("office", "Office"),
+                ],
+            )),
+            DiffEngine::create_new(&make_schema("Tag", vec![make_field("name")])),
+        ];
+        assert_eq!(
+            plan_names(&order_plans(plans).unwrap()),
+            ["Employee", "Tag"]
+        );
+    }
+
+    #[test]
+    fn order_plans_reports_cycle() {
+        let plans = vec![
+            DiffEngine::create_new(&make_schema("Tag", vec![make_field("name")])),
+            DiffEngine::create_new(&make_schema("A", vec![make_relation("b", "B")])),
+            DiffEngine::create_new(&make_schema("B", vec![make_relation("c", "C")])),
+            DiffEngine::create_new(&make_schema("C", vec![make_relation("a", "A")])),
+        ];
+        assert_eq!(
+            order_plans(plans).unwrap_err(),
+            MigrationError::CyclicDependency {
+                cycle: vec!["A".into(), "B".into(), "C".into(), "A".into()],
+            }
+        );
+    }
+
     // -- MigrationError tests --
 
     #[test]
@@ -1547,6 +4020,18 @@ mod tests {
                 MigrationError::EmptyMigrationPlan,
                 "migration plan has no steps to apply",
             ),
+            (
+                MigrationError::Irreversible {
+                    step_description: "DROP schema 'Contact'".into(),
+                },
+                "migration step cannot be reversed: DROP schema 'Contact'",
+            ),
+            (
+                MigrationError::CyclicDependency {
+                    cycle: vec!["Contact".into(), "Company".into(), "Contact".into()],
+                },
+                "cyclic relation dependency: Contact -> Company -> Contact",
+            ),
         ];
         for (error, expected) in cases {
             assert_eq!(error.to_string(), expected);
@@ -1678,6 +4163,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn computed_fields_have_no_column() {
+        use crate::types::{ComputedExpr, FieldAnnotation};
+
+        let computed = |name: &str| {
+            FieldDefinition::with_annotations(
+                FieldName::new(name).unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                Vec::new(),
+                vec![FieldAnnotation::Computed {
+                    expr: ComputedExpr::new("upper(title)").unwrap(),
+                }],
+            )
+        };
+        let v1 = make_schema("Opportunity", vec![make_field("title")]);
+        let v2 = make_schema("Opportunity", vec![make_field("title"), computed("label")]);
+        match &DiffEngine::create_new(&v2).steps[..] {
+            [MigrationStep::CreateSchema { fields, .. }] => assert_eq!(fields.len(), 1),
+            other => panic!("expected CreateSchema step, got {other:?}"),
+        }
+        assert!(DiffEngine::diff(&v1, &v2).steps.is_empty());
+        assert!(DiffEngine::diff(&v2, &v1).steps.is_empty());
+
+        // A stored field that becomes computed loses its column, and back.
+        let stored = make_schema(
+            "Opportunity",
+            vec![make_field("title"), make_field("label")],
+        );
+        assert_eq!(
+            DiffEngine::diff(&stored, &v2).steps,
+            vec![MigrationStep::RemoveField {
+                name: FieldName::new("label").unwrap(),
+            }]
+        );
+        assert_eq!(
+            DiffEngine::diff(&v2, &stored).steps,
+            vec![MigrationStep::AddField {
+                field: make_field("label"),
+            }]
+        );
+    }
+
     #[test]
     fn validate_system_schema_protection_allows_drop_on_non_system() {
         let schema = make_schema("Contact", vec![make_field("name")]);
@@ -1703,6 +4230,129 @@ mod tests {
         assert!(msg.contains("protected"));
     }
 
+    // -- Conflict detection tests --
+
+    fn typed_field(name: &str, field_type: FieldType) -> FieldDefinition {
+        FieldDefinition::new(FieldName::new(name).unwrap(), field_type)
+    }
+
+    #[test]
+    fn detect_conflicts_flags_incompatible_changes_to_same_field() {
+        let base = make_schema("Ticket", vec![make_field("title")]);
+        let plan_a = DiffEngine::diff(
+            &base,
+            &make_schema("Ticket", vec![make_field("title"), make_field("status")]),
+        );
+        let plan_b = DiffEngine::diff(
+            &base,
+            &make_schema(
+                "Ticket",
+                vec![
+                    make_field("title"),
+                    typed_field(
+                        "status",
+                        FieldType::Integer(IntegerConstraints::unconstrained()),
+                    ),
+                ],
+            ),
+        );
+
+        let conflicts = DiffEngine::detect_conflicts(&base, &[plan_a, plan_b]);
+        assert_eq!(conflicts.len(), 1);
+        let conflict = &conflicts[0];
+        assert_eq!(conflict.field, Some(FieldName::new("status").unwrap()));
+        assert_eq!((conflict.first_plan, conflict.second_plan), (0, 1));
+        assert!(matches!(
+            conflict.first_step,
+            MigrationStep::AddField { .. }
+        ));
+        assert!(conflict
+            .to_string()
+            .starts_with("field 'status': plan 0 does ADD field"));
+    }
+
+    #[test]
+    fn detect_conflicts_ignores_changes_to_different_fields() {
+        let base = make_schema("Ticket", vec![make_field("title")]);
+        let plan_a = DiffEngine::diff(
+            &base,
+            &make_schema("Ticket", vec![make_field("title"), make_field("status")]),
+        );
+        let plan_b = DiffEngine::diff(
+            &base,
+            &make_schema("Ticket", vec![make_field("title"), make_field("priority")]),
+        );
+        assert!(DiffEngine::detect_conflicts(&base, &[plan_a, plan_b]).is_empty());
+    }
+
+    #[test]
+    fn detect_conflicts_accepts_identical_changes() {
+        let base = make_schema("Ticket", vec![make_field("title")]);
+        let target = make_schema("Ticket", vec![make_field("title"), make_field("status")]);
+        let plans = [
+            DiffEngine::diff(&base, &target),
+            DiffEngine::diff(&base, &target),
+        ];
+        assert!(DiffEngine::detect_conflicts(&base, &plans).is_empty());
+    }
+
+    #[test]
+    fn detect_conflicts_compares_aspects_of_a_shared_field() {
+        let base = make_schema("Ticket", vec![make_field("status")]);
+        let status = FieldName::new("status").unwrap();
+        let plan = |steps| MigrationPlan::new(base.id.clone(), base.name.clone(), steps);
+        let index = plan(vec![MigrationStep::AddIndex {
+            field: status.clone(),
+        }]);
+        let default_open = plan(vec![MigrationStep::SetDefault {
+            field: status.clone(),
+            value: DefaultValue::String("open".into()),
+            previous: None,
+        }]);
+        let default_new = plan(vec![MigrationStep::SetDefault {
+            field: status.clone(),
+            value: DefaultValue::String("new".into()),
+            previous: None,
+        }]);
+        let dropped = plan(vec![MigrationStep::DropSchema {
+            name: base.name.clone(),
+        }]);
+
+        assert!(
+            DiffEngine::detect_conflicts(&base, &[index.clone(), default_open.clone()]).is_empty()
+        );
+        assert_eq!(
+            DiffEngine::detect_conflicts(&base, &[default_open, default_new]).len(),
+            1
+        );
+        let schema_wide = DiffEngine::detect_conflicts(&base, &[index, dropped]);
+        assert_eq!(schema_wide.len(), 1);
+        assert_eq!(schema_wide[0].field, None);
+    }
+
+    #[test]
+    fn detect_conflicts_ignores_plans_for_other_schemas() {
+        let base = make_schema("Ticket", vec![make_field("title")]);
+        let other = make_schema("Invoice", vec![make_field("title")]);
+        let plans = [
+            DiffEngine::diff(
+                &base,
+                &make_schema("Ticket", vec![make_field("title"), make_field("status")]),
+            ),
+            DiffEngine::diff(
+                &other,
+                &make_schema(
+                    "Invoice",
+                    vec![
+                        make_field("title"),
+                        typed_field("status", FieldType::Boolean),
+                    ],
+                ),
+            ),
+        ];
+        assert!(DiffEngine::detect_conflicts(&base, &plans).is_empty());
+    }
+
     // -- Test helpers --
 
     fn make_field(name: &str) -> FieldDefinition {
@@ -1790,4 +4440,156 @@ mod tests {
         };
         assert_eq!(step.safety(), MigrationSafety::Safe);
     }
+
+    // -----------------------------------------------------------------
+    // Soft-delete diff tests
+    // -----------------------------------------------------------------
+
+    fn soft_delete_schema(name: &str) -> SchemaDefinition {
+        let mut schema = make_schema(name, vec![make_field("name")]);
+        schema.annotations.push(Annotation::SoftDelete);
+        schema
+    }
+
+    #[test]
+    fn create_new_enables_soft_delete() {
+        let plan = DiffEngine::create_new(&soft_delete_schema("Contact"));
+        assert!(matches!(
+            plan.steps.as_slice(),
+            [
+                MigrationStep::CreateSchema { .. },
+                MigrationStep::EnableSoftDelete
+            ]
+        ));
+    }
+
+    #[test]
+    fn diff_toggles_soft_delete() {
+        let plain = make_schema("Contact", vec![make_field("name")]);
+        let soft = soft_delete_schema("Contact");
+
+        let enable = DiffEngine::diff(&plain, &soft);
+        assert_eq!(enable.steps, vec![MigrationStep::EnableSoftDelete]);
+        assert_eq!(enable.steps[0].safety(), MigrationSafety::Safe);
+
+        let disable = DiffEngine::diff(&soft, &plain);
+        assert_eq!(disable.steps, vec![MigrationStep::DisableSoftDelete]);
+        assert_eq!(disable.steps[0].safety(), MigrationSafety::Destructive);
+        assert!(DiffEngine::invert(&disable).is_err());
+        assert_eq!(
+            DiffEngine::invert(&enable).unwrap().steps,
+            vec![MigrationStep::DisableSoftDelete]
+        );
+
+        assert!(DiffEngine::diff(&soft, &soft_delete_schema("Contact"))
+            .steps
+            .is_empty());
+    }
+
+    // -----------------------------------------------------------------
+    // Composite index diff tests
+    // -----------------------------------------------------------------
+
+    fn index(names: &[&str], unique: bool) -> Annotation {
+        Annotation::Index {
+            fields: names.iter().map(|n| FieldName::new(*n).unwrap()).collect(),
+            unique,
+        }
+    }
+
+    fn indexed_schema(fields: &[&str], indexes: Vec<Annotation>) -> SchemaDefinition {
+        let mut schema = make_schema("Deal", fields.iter().map(|n| make_field(n)).collect());
+        schema.annotations.extend(indexes);
+        schema
+    }
+
+    fn names(fields: &[&str]) -> Vec<FieldName> {
+        fields.iter().map(|n| FieldName::new(*n).unwrap()).collect()
+    }
+
+    #[test]
+    fn create_new_adds_composite_indexes() {
+        let schema = indexed_schema(
+            &["agency", "stage"],
+            vec![index(&["agency", "stage"], true)],
+        );
+        let plan = DiffEngine::create_new(&schema);
+        assert_eq!(
+            plan.steps[1..],
+            [MigrationStep::AddCompositeIndex {
+                fields: names(&["agency", "stage"]),
+                unique: true,
+            }]
+        );
+        assert_eq!(
+            plan.steps[1].to_string(),
+            "ADD UNIQUE INDEX on (agency, stage)"
+        );
+        assert_eq!(
+            plan.steps[1].safety(),
+            MigrationSafety::RequiresConfirmation
+        );
+    }
+
+    #[test]
+    fn diff_orders_index_removals_before_field_changes() {
+        let old = indexed_schema(
+            &["agency", "stage"],
+            vec![index(&["agency", "stage"], false)],
+        );
+        let new = indexed_schema(&["stage", "owner"], vec![index(&["stage", "owner"], true)]);
+        let plan = DiffEngine::diff(&old, &new);
+        assert_eq!(
+            plan.steps.first(),
+            Some(&MigrationStep::RemoveCompositeIndex {
+                fields: names(&["agency", "stage"]),
+                unique: false,
+            })
+        );
+        assert_eq!(
+            plan.steps.last(),
+            Some(&MigrationStep::AddCompositeIndex {
+                fields: names(&["stage", "owner"]),
+                unique: true,
+            })
+        );
+
+        let inverted = DiffEngine::invert(&MigrationPlan::new(
+            plan.schema_id.clone(),
+            plan.schema_name.clone(),
+            vec![plan.steps[0].clone()],
+        ))
+        .unwrap();
+        assert_eq!(
+            inverted.steps,
+            vec![MigrationStep::AddCompositeIndex {
+                fields: names(&["agency", "stage"]),
+                unique: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_keeps_index_across_field_rename() {
+        let old = indexed_schema(
+            &["agency", "stage"],
+            vec![index(&["agency", "stage"], true)],
+        );
+        let new = indexed_schema(
+            &["agency", "phase"],
+            vec![index(&["agency", "phase"], true)],
+        );
+        let plan = DiffEngine::diff_with_renames(
+            &old,
+            &new,
+            &[(
+                FieldName::new("stage").unwrap(),
+                FieldName::new("phase").unwrap(),
+            )],
+        );
+        assert!(!plan.steps.iter().any(|s| matches!(
+            s,
+            MigrationStep::AddCompositeIndex { .. } | MigrationStep::RemoveCompositeIndex { .. }
+        )));
+    }
 }
@@ -1,8 +1,9 @@
+use std::collections::BTreeMap;
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
-use crate::types::{DynamicValue, FieldType, SchemaDefinition, SchemaId};
+use crate::types::{Cardinality, DynamicValue, FieldName, FieldType, SchemaDefinition, SchemaId};
 
 // ---------------------------------------------------------------------------
 // FieldPath
@@ -319,6 +320,15 @@ pub struct Query {
     /// the per-list DB round-trips.
     #[serde(default = "default_true", skip_serializing_if = "is_true")]
     pub include_total: bool,
+    /// Keyset pagination: return only rows that sort strictly after this
+    /// cursor. Backends apply it through [`Query::resolve_cursor`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<Cursor>,
+    /// Whether soft-deleted rows of a `@soft_delete` schema are returned.
+    /// Defaults to `false`: backends exclude them while translating the
+    /// query (see [`Query::hides_deleted`]).
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub include_deleted: bool,
 }
 
 fn default_true() -> bool {
@@ -329,6 +339,10 @@ fn is_true(b: &bool) -> bool {
     *b
 }
 
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
 impl Query {
     /// Create a new query for a given schema with no filter, sort, or pagination.
     pub fn new(schema: SchemaId) -> Self {
@@ -340,6 +354,8 @@ impl Query {
             offset: None,
             projection: None,
             include_total: true,
+            after: None,
+            include_deleted: false,
         }
     }
 
@@ -392,6 +408,61 @@ impl Query {
         self
     }
 
+    /// Resume after the given cursor (keyset pagination).
+    pub fn with_after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Set whether soft-deleted rows are returned.
+    pub fn with_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = include_deleted;
+        self
+    }
+
+    /// Whether translating this query against `schema` must exclude
+    /// soft-deleted rows: the schema is `@soft_delete` and the query did
+    /// not opt in with [`with_deleted`](Self::with_deleted).
+    pub fn hides_deleted(&self, schema: Option<&SchemaDefinition>) -> bool {
+        !self.include_deleted && schema.is_some_and(SchemaDefinition::is_soft_delete)
+    }
+
+    /// Rewrite the query into the form a backend executes for cursor
+    /// pagination.
+    ///
+    /// `id` is appended as a final ascending sort key so the order is total.
+    /// When `after` is set, it becomes a keyset filter ANDed with the
+    /// existing filter and any offset is dropped. When the query is limited
+    /// and projected, the sort keys are added to the projection so the
+    /// backend can build the next cursor from the last row.
+    pub fn resolve_cursor(&self) -> Result<Query, QueryError> {
+        let mut resolved = self.clone();
+        if self.after.is_none() && self.limit.is_none() {
+            return Ok(resolved);
+        }
+        if !resolved.sort.iter().any(|(path, _)| path.root() == "id") {
+            resolved
+                .sort
+                .push((FieldPath::single("id"), SortOrder::Ascending));
+        }
+        if let Some(projection) = &mut resolved.projection {
+            for (path, _) in &self.sort {
+                if !projection.iter().any(|f| f == path.root()) {
+                    projection.push(path.root().to_string());
+                }
+            }
+        }
+        if let Some(cursor) = resolved.after.take() {
+            let keyset = cursor.keyset_filter(&self.sort)?;
+            resolved.filter = Some(match resolved.filter.take() {
+                Some(existing) => Filter::and(vec![existing, keyset]),
+                None => keyset,
+            });
+            resolved.offset = None;
+        }
+        Ok(resolved)
+    }
+
     /// Validate the query structure.
     pub fn validate(&self) -> Result<(), QueryError> {
         if let (Some(limit), Some(offset)) = (self.limit, self.offset) {
@@ -445,6 +516,101 @@ impl fmt::Display for Query {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Cursor
+// ---------------------------------------------------------------------------
+
+/// Position of the last row seen by a keyset-paginated query: its value for
+/// each sort key, in sort order, plus its entity id as the tiebreaker.
+///
+/// Clients receive cursors as opaque strings via [`Cursor::encode`] and hand
+/// them back unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cursor {
+    /// The row's value for each sort key.
+    pub values: Vec<DynamicValue>,
+    /// The row's entity id.
+    pub id: String,
+}
+
+impl Cursor {
+    /// Create a cursor from sort-key values and an entity id.
+    pub fn new(values: Vec<DynamicValue>, id: impl Into<String>) -> Self {
+        Self {
+            values,
+            id: id.into(),
+        }
+    }
+
+    /// Encode the cursor as an opaque, URL-safe string.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor serialization is infallible");
+        json.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    /// Decode a string produced by [`Cursor::encode`].
+    pub fn decode(s: &str) -> Result<Self, QueryError> {
+        let invalid = |reason: &str| QueryError::InvalidCursor {
+            reason: reason.to_string(),
+        };
+        if s.len() % 2 != 0 || !s.is_ascii() {
+            return Err(invalid("malformed cursor"));
+        }
+        let bytes = (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| invalid("malformed cursor"))?;
+        serde_json::from_slice(&bytes).map_err(|_| invalid("malformed cursor"))
+    }
+
+    /// Build the filter selecting rows that sort strictly after this cursor
+    /// under `sort`, followed by `id` ascending unless `sort` already has it.
+    ///
+    /// Expands the row comparison `(k1, .., kn, id) > (v1, .., vn, id0)`
+    /// into `k1 > v1 OR (k1 = v1 AND k2 > v2) OR ...`, flipping `>` to `<`
+    /// for descending keys.
+    fn keyset_filter(&self, sort: &[(FieldPath, SortOrder)]) -> Result<Filter, QueryError> {
+        if self.values.len() != sort.len() {
+            return Err(QueryError::InvalidCursor {
+                reason: "cursor does not match the query's sort keys".to_string(),
+            });
+        }
+        if let Some((path, _)) = sort.iter().find(|(path, _)| !path.is_simple()) {
+            return Err(QueryError::InvalidCursor {
+                reason: format!("cannot paginate by cursor when sorting on '{path}'"),
+            });
+        }
+        let id_key = (FieldPath::single("id"), SortOrder::Ascending);
+        let id_value = DynamicValue::Text(self.id.clone());
+        let mut keys: Vec<(&(FieldPath, SortOrder), &DynamicValue)> =
+            sort.iter().zip(&self.values).collect();
+        if !sort.iter().any(|(path, _)| path.root() == "id") {
+            keys.push((&id_key, &id_value));
+        }
+
+        let branches = (0..keys.len())
+            .map(|i| {
+                let mut conds: Vec<Filter> = keys[..i]
+                    .iter()
+                    .map(|((path, _), value)| Filter::eq(path.clone(), (*value).clone()))
+                    .collect();
+                let ((path, order), value) = keys[i];
+                conds.push(match order {
+                    SortOrder::Ascending => Filter::gt(path.clone(), value.clone()),
+                    SortOrder::Descending => Filter::lt(path.clone(), value.clone()),
+                });
+                if conds.len() == 1 {
+                    conds.remove(0)
+                } else {
+                    Filter::and(conds)
+                }
+            })
+            .collect::<Vec<_>>();
+        Ok(Filter::or(branches))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // AggregateOp
 // ---------------------------------------------------------------------------
@@ -459,6 +625,41 @@ pub enum AggregateOp {
     Sum { field: FieldPath },
     /// Average a numeric field.
     Avg { field: FieldPath },
+    /// Smallest value of a numeric field.
+    Min { field: FieldPath },
+    /// Largest value of a numeric field.
+    Max { field: FieldPath },
+}
+
+impl AggregateOp {
+    /// Parse the display form: `count`, or `sum:field`, `avg:field`,
+    /// `min:field`, `max:field` (dotted paths allowed).
+    pub fn parse(s: &str) -> Result<Self, QueryError> {
+        if s == "count" {
+            return Ok(Self::Count);
+        }
+        let unknown = || QueryError::UnknownAggregate { op: s.to_string() };
+        let (name, field) = s.split_once(':').ok_or_else(unknown)?;
+        let field = FieldPath::parse(field)?;
+        match name {
+            "sum" => Ok(Self::Sum { field }),
+            "avg" => Ok(Self::Avg { field }),
+            "min" => Ok(Self::Min { field }),
+            "max" => Ok(Self::Max { field }),
+            _ => Err(unknown()),
+        }
+    }
+
+    /// The field this operation reads, or `None` for `Count`.
+    pub fn field(&self) -> Option<&FieldPath> {
+        match self {
+            Self::Count => None,
+            Self::Sum { field }
+            | Self::Avg { field }
+            | Self::Min { field }
+            | Self::Max { field } => Some(field),
+        }
+    }
 }
 
 impl fmt::Display for AggregateOp {
@@ -467,6 +668,8 @@ impl fmt::Display for AggregateOp {
             Self::Count => write!(f, "count"),
             Self::Sum { field } => write!(f, "sum:{field}"),
             Self::Avg { field } => write!(f, "avg:{field}"),
+            Self::Min { field } => write!(f, "min:{field}"),
+            Self::Max { field } => write!(f, "max:{field}"),
         }
     }
 }
@@ -485,6 +688,14 @@ pub struct AggregateQuery {
     pub filter: Option<Filter>,
     /// The aggregate operations to compute.
     pub ops: Vec<AggregateOp>,
+    /// Fields to group by. Empty means a single group over all matching
+    /// entities.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub group_by: Vec<FieldName>,
+    /// Whether soft-deleted rows are aggregated; see
+    /// [`Query::include_deleted`].
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub include_deleted: bool,
 }
 
 impl AggregateQuery {
@@ -494,6 +705,8 @@ impl AggregateQuery {
             schema,
             filter: None,
             ops: Vec::new(),
+            group_by: Vec::new(),
+            include_deleted: false,
         }
     }
 
@@ -514,6 +727,24 @@ impl AggregateQuery {
         self.ops.extend(ops);
         self
     }
+
+    /// Add a group-by field.
+    pub fn with_group_by(mut self, field: FieldName) -> Self {
+        self.group_by.push(field);
+        self
+    }
+
+    /// Set whether soft-deleted rows are aggregated.
+    pub fn with_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = include_deleted;
+        self
+    }
+
+    /// Whether translating this query against `schema` must exclude
+    /// soft-deleted rows; see [`Query::hides_deleted`].
+    pub fn hides_deleted(&self, schema: Option<&SchemaDefinition>) -> bool {
+        !self.include_deleted && schema.is_some_and(SchemaDefinition::is_soft_delete)
+    }
 }
 
 impl fmt::Display for AggregateQuery {
@@ -529,6 +760,10 @@ impl fmt::Display for AggregateQuery {
         if let Some(filter) = &self.filter {
             write!(f, " WHERE {filter}")?;
         }
+        if !self.group_by.is_empty() {
+            let fields: Vec<&str> = self.group_by.iter().map(|g| g.as_str()).collect();
+            write!(f, " GROUP BY {}", fields.join(", "))?;
+        }
         Ok(())
     }
 }
@@ -538,12 +773,35 @@ impl fmt::Display for AggregateQuery {
 // ---------------------------------------------------------------------------
 
 /// The result of a single aggregate operation.
+///
+/// Grouped queries produce one result per operation per group; `group`
+/// holds that group's value for each `group_by` field.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AggregateResult {
     /// The operation that produced this result.
     pub op: AggregateOp,
     /// The computed value.
     pub value: f64,
+    /// The group this result belongs to; empty for ungrouped queries.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub group: BTreeMap<String, DynamicValue>,
+}
+
+impl AggregateResult {
+    /// Create an ungrouped result.
+    pub fn new(op: AggregateOp, value: f64) -> Self {
+        Self {
+            op,
+            value,
+            group: BTreeMap::new(),
+        }
+    }
+
+    /// Set the group this result belongs to.
+    pub fn with_group(mut self, group: BTreeMap<String, DynamicValue>) -> Self {
+        self.group = group;
+        self
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -570,6 +828,12 @@ pub enum QueryError {
     },
     /// The In filter has an empty values list.
     EmptyInValues { field: String },
+    /// The pagination cursor could not be decoded or does not fit the query.
+    InvalidCursor { reason: String },
+    /// The aggregate operation is not recognized.
+    UnknownAggregate { op: String },
+    /// The field is `@computed` on read and has no stored value to query.
+    ComputedField { field: String },
 }
 
 impl fmt::Display for QueryError {
@@ -598,6 +862,15 @@ impl fmt::Display for QueryError {
             Self::EmptyInValues { field } => {
                 write!(f, "IN filter for field '{field}' has no values")
             }
+            Self::InvalidCursor { reason } => write!(f, "invalid cursor: {reason}"),
+            Self::UnknownAggregate { op } => write!(
+                f,
+                "unknown aggregate '{op}': expected count, sum:<field>, avg:<field>, min:<field>, or max:<field>"
+            ),
+            Self::ComputedField { field } => write!(
+                f,
+                "field '{field}' is computed on read and cannot be filtered, sorted, or aggregated"
+            ),
         }
     }
 }
@@ -608,6 +881,10 @@ impl std::error::Error for QueryError {}
 // Filter validation
 // ---------------------------------------------------------------------------
 
+/// Maximum number of segments in a filter path that traverses a relation:
+/// the relation field itself plus one field on its target schema.
+pub const MAX_RELATION_PATH_DEPTH: usize = 2;
+
 /// Validate that a filter only references fields that exist in the schema.
 ///
 /// Recursively walks the `Filter` tree. For each leaf filter, checks that the
@@ -615,7 +892,114 @@ impl std::error::Error for QueryError {}
 /// errors found rather than stopping at the first.
 pub fn validate_filter(filter: &Filter, schema: &SchemaDefinition) -> Result<(), Vec<QueryError>> {
     let mut errors = Vec::new();
-    collect_filter_errors(filter, schema, &mut errors);
+    collect_filter_errors(filter, schema, None, &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Like [`validate_filter`], but also checks dotted paths that traverse a
+/// relation (e.g. `organization.region`) against the relation's target.
+///
+/// `targets` holds the definitions of the schemas `schema`'s relations
+/// point at. A path rooted at a relation field must be exactly
+/// [`MAX_RELATION_PATH_DEPTH`] segments long, the relation must be to-one,
+/// and the second segment must name a field of the target schema; the
+/// filter value is type-checked against that field. Dotted paths rooted at
+/// non-relation fields keep the root-only check.
+pub fn validate_filter_with_targets(
+    filter: &Filter,
+    schema: &SchemaDefinition,
+    targets: &[SchemaDefinition],
+) -> Result<(), Vec<QueryError>> {
+    let mut errors = Vec::new();
+    collect_filter_errors(filter, schema, Some(targets), &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate that every sort key names a stored field of the schema.
+///
+/// The root segment of each path must be a schema field or the implicit
+/// `id`, and must not be `@computed`. Returns all errors found rather than
+/// stopping at the first.
+pub fn validate_sort(
+    sort: &[(FieldPath, SortOrder)],
+    schema: &SchemaDefinition,
+) -> Result<(), Vec<QueryError>> {
+    let errors: Vec<QueryError> = sort
+        .iter()
+        .filter(|(path, _)| path.root() != "id")
+        .filter_map(|(path, _)| match schema.field(path.root()) {
+            None => Some(QueryError::UnknownField {
+                field: path.root().to_string(),
+                schema: schema.name.as_str().to_string(),
+            }),
+            Some(field) if field.is_computed() => Some(QueryError::ComputedField {
+                field: path.root().to_string(),
+            }),
+            Some(_) => None,
+        })
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validate an aggregate query's operations and group-by fields.
+///
+/// Every field must exist in the schema, and the fields of `sum`, `avg`,
+/// `min`, and `max` must be numeric (Integer, Float, or Decimal). Returns
+/// all errors found rather than stopping at the first.
+pub fn validate_aggregate(
+    query: &AggregateQuery,
+    schema: &SchemaDefinition,
+) -> Result<(), Vec<QueryError>> {
+    let unknown = |field: &str| QueryError::UnknownField {
+        field: field.to_string(),
+        schema: schema.name.as_str().to_string(),
+    };
+    let mut errors = Vec::new();
+    for op in &query.ops {
+        let Some(path) = op.field() else {
+            continue;
+        };
+        match schema.field(path.root()) {
+            None => errors.push(unknown(path.root())),
+            Some(field) if field.is_computed() => errors.push(QueryError::ComputedField {
+                field: path.root().to_string(),
+            }),
+            Some(field) if path.is_simple() => {
+                if !matches!(
+                    field.field_type,
+                    FieldType::Integer(_) | FieldType::Float(_) | FieldType::Decimal { .. }
+                ) {
+                    errors.push(QueryError::TypeMismatch {
+                        field: path.as_dotted(),
+                        expected: "numeric".to_string(),
+                        actual: field_type_name(&field.field_type),
+                    });
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    for field in &query.group_by {
+        match schema.field(field.as_str()) {
+            None => errors.push(unknown(field.as_str())),
+            Some(def) if def.is_computed() => errors.push(QueryError::ComputedField {
+                field: field.as_str().to_string(),
+            }),
+            Some(_) => {}
+        }
+    }
     if errors.is_empty() {
         Ok(())
     } else {
@@ -623,7 +1007,12 @@ pub fn validate_filter(filter: &Filter, schema: &SchemaDefinition) -> Result<(),
     }
 }
 
-fn collect_filter_errors(filter: &Filter, schema: &SchemaDefinition, errors: &mut Vec<QueryError>) {
+fn collect_filter_errors(
+    filter: &Filter,
+    schema: &SchemaDefinition,
+    targets: Option<&[SchemaDefinition]>,
+    errors: &mut Vec<QueryError>,
+) {
     match filter {
         Filter::Eq { path, value }
         | Filter::Ne { path, value }
@@ -631,79 +1020,138 @@ fn collect_filter_errors(filter: &Filter, schema: &SchemaDefinition, errors: &mu
         | Filter::Gte { path, value }
         | Filter::Lt { path, value }
         | Filter::Lte { path, value } => {
-            check_field_exists(path, schema, errors);
-            if let Some(field_def) = schema.field(path.root()) {
-                if path.is_simple() {
-                    check_type_compat(path.root(), &field_def.field_type, value, errors);
-                }
+            if let Some(field_type) = resolve_path_type(path, schema, targets, errors) {
+                check_type_compat(&path.as_dotted(), field_type, value, errors);
             }
         }
         Filter::Contains { path, .. } | Filter::StartsWith { path, .. } => {
-            check_field_exists(path, schema, errors);
-            if let Some(field_def) = schema.field(path.root()) {
-                if path.is_simple() && !is_text_like(&field_def.field_type) {
+            if let Some(field_type) = resolve_path_type(path, schema, targets, errors) {
+                if !is_text_like(field_type) {
                     errors.push(QueryError::TypeMismatch {
-                        field: path.root().to_string(),
+                        field: path.as_dotted(),
                         expected: "Text".to_string(),
-                        actual: field_type_name(&field_def.field_type),
+                        actual: field_type_name(field_type),
                     });
                 }
             }
         }
         Filter::In { path, values } => {
-            check_field_exists(path, schema, errors);
+            let field_type = resolve_path_type(path, schema, targets, errors);
             if values.is_empty() {
                 errors.push(QueryError::EmptyInValues {
                     field: path.as_dotted(),
                 });
             }
-            if let Some(field_def) = schema.field(path.root()) {
-                if path.is_simple() {
-                    for v in values {
-                        check_type_compat(path.root(), &field_def.field_type, v, errors);
-                    }
+            if let Some(field_type) = field_type {
+                for v in values {
+                    check_type_compat(&path.as_dotted(), field_type, v, errors);
                 }
             }
         }
         Filter::And { filters } | Filter::Or { filters } => {
             for f in filters {
-                collect_filter_errors(f, schema, errors);
+                collect_filter_errors(f, schema, targets, errors);
             }
         }
         Filter::Not { filter } => {
-            collect_filter_errors(filter, schema, errors);
+            collect_filter_errors(filter, schema, targets, errors);
         }
     }
 }
 
-fn check_field_exists(path: &FieldPath, schema: &SchemaDefinition, errors: &mut Vec<QueryError>) {
-    if schema.field(path.root()).is_none() {
+/// Resolve the type of the field `path` points at, pushing any path errors.
+///
+/// Returns `None` when the path is invalid or when its leaf type is not
+/// checked: dotted paths without `targets`, or rooted at a non-relation.
+fn resolve_path_type<'a>(
+    path: &FieldPath,
+    schema: &'a SchemaDefinition,
+    targets: Option<&'a [SchemaDefinition]>,
+    errors: &mut Vec<QueryError>,
+) -> Option<&'a FieldType> {
+    let Some(root) = schema.field(path.root()) else {
         errors.push(QueryError::UnknownField {
             field: path.root().to_string(),
             schema: schema.name.as_str().to_string(),
         });
+        return None;
+    };
+    if root.is_computed() {
+        errors.push(QueryError::ComputedField {
+            field: path.root().to_string(),
+        });
+        return None;
+    }
+    if path.is_simple() {
+        return Some(&root.field_type);
+    }
+    let (
+        Some(targets),
+        FieldType::Relation {
+            target,
+            cardinality,
+        },
+    ) = (targets, &root.field_type)
+    else {
+        return None;
+    };
+    let invalid = |reason: String| QueryError::InvalidFieldPath {
+        path: path.as_dotted(),
+        reason,
+    };
+    if *cardinality != Cardinality::One {
+        errors.push(invalid(format!(
+            "'{}' is a to-many relation; only to-one relations can be traversed",
+            path.root()
+        )));
+        return None;
+    }
+    if path.depth() > MAX_RELATION_PATH_DEPTH {
+        errors.push(invalid(
+            "relation filters may traverse only one level".to_string(),
+        ));
+        return None;
+    }
+    let Some(target_def) = targets.iter().find(|t| t.name == *target) else {
+        errors.push(invalid(format!(
+            "relation target '{target}' is not defined"
+        )));
+        return None;
+    };
+    match target_def.field(path.leaf()) {
+        Some(leaf) => Some(&leaf.field_type),
+        None => {
+            errors.push(QueryError::UnknownField {
+                field: path.leaf().to_string(),
+                schema: target.as_str().to_string(),
+            });
+            None
+        }
     }
 }
 
 fn is_text_like(ft: &FieldType) -> bool {
     matches!(
         ft,
-        FieldType::Text(_) | FieldType::RichText | FieldType::Enum(_)
+        FieldType::Text(_) | FieldType::RichText(_) | FieldType::Enum(_)
     )
 }
 
 fn field_type_name(ft: &FieldType) -> String {
     match ft {
         FieldType::Text(_) => "Text",
-        FieldType::RichText => "RichText",
+        FieldType::RichText(_) => "RichText",
         FieldType::Integer(_) => "Integer",
         FieldType::Float(_) => "Float",
+        FieldType::Decimal { .. } => "Decimal",
         FieldType::Boolean => "Boolean",
         FieldType::DateTime => "DateTime",
+        FieldType::Duration => "Duration",
+        FieldType::Uuid => "Uuid",
         FieldType::Enum(_) => "Enum",
         FieldType::Json => "Json",
         FieldType::Relation { .. } => "Relation",
-        FieldType::Array(_) => "Array",
+        FieldType::Array(_, _) => "Array",
         FieldType::Composite(_) => "Composite",
         FieldType::File(_) => "File",
     }
@@ -720,11 +1168,17 @@ fn check_type_compat(
         return; // null is always compatible
     }
     let compatible = match field_type {
-        FieldType::Text(_) | FieldType::RichText => matches!(value, DynamicValue::Text(_)),
+        FieldType::Text(_) | FieldType::RichText(_) => matches!(value, DynamicValue::Text(_)),
         FieldType::Integer(_) => matches!(value, DynamicValue::Integer(_)),
         FieldType::Float(_) => matches!(value, DynamicValue::Float(_) | DynamicValue::Integer(_)),
+        FieldType::Decimal { .. } => matches!(
+            value,
+            DynamicValue::Decimal(_) | DynamicValue::Integer(_) | DynamicValue::Float(_)
+        ),
         FieldType::Boolean => matches!(value, DynamicValue::Boolean(_)),
         FieldType::DateTime => matches!(value, DynamicValue::DateTime(_)),
+        FieldType::Duration => matches!(value, DynamicValue::Duration(_)),
+        FieldType::Uuid => matches!(value, DynamicValue::Uuid(_)),
         FieldType::Enum(_) => matches!(value, DynamicValue::Enum(_) | DynamicValue::Text(_)),
         _ => true, // Json, Relation, Array, Composite — accept anything
     };
@@ -743,8 +1197,11 @@ fn dynamic_value_type_name(value: &DynamicValue) -> String {
         DynamicValue::Text(_) => "Text",
         DynamicValue::Integer(_) => "Integer",
         DynamicValue::Float(_) => "Float",
+        DynamicValue::Decimal(_) => "Decimal",
         DynamicValue::Boolean(_) => "Boolean",
         DynamicValue::DateTime(_) => "DateTime",
+        DynamicValue::Duration(_) => "Duration",
+        DynamicValue::Uuid(_) => "Uuid",
         DynamicValue::Enum(_) => "Enum",
         DynamicValue::Json(_) => "Json",
         DynamicValue::Array(_) => "Array",
@@ -1099,6 +1556,7 @@ mod tests {
         assert!(!json.contains("sort"));
         assert!(!json.contains("limit"));
         assert!(!json.contains("offset"));
+        assert!(!json.contains("include_deleted"));
     }
 
     // -- QueryError tests --
@@ -1178,6 +1636,23 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn hides_deleted_only_for_soft_delete_schemas() {
+        let plain = test_schema();
+        let mut soft = test_schema();
+        soft.annotations.push(crate::types::Annotation::SoftDelete);
+
+        let q = Query::new(soft.id.clone());
+        assert!(q.hides_deleted(Some(&soft)));
+        assert!(!q.hides_deleted(Some(&plain)));
+        assert!(!q.hides_deleted(None));
+        assert!(!q.with_deleted(true).hides_deleted(Some(&soft)));
+
+        let agg = AggregateQuery::new(soft.id.clone()).with_op(AggregateOp::Count);
+        assert!(agg.hides_deleted(Some(&soft)));
+        assert!(!agg.with_deleted(true).hides_deleted(Some(&soft)));
+    }
+
     #[test]
     fn validate_filter_known_field_passes() {
         let schema = test_schema();
@@ -1199,6 +1674,104 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_sort_known_fields_pass() {
+        let schema = test_schema();
+        let sort = vec![
+            (FieldPath::single("name"), SortOrder::Ascending),
+            (FieldPath::single("age"), SortOrder::Descending),
+            (FieldPath::single("id"), SortOrder::Ascending),
+        ];
+        assert!(validate_sort(&sort, &schema).is_ok());
+    }
+
+    #[test]
+    fn validate_sort_unknown_field_fails() {
+        let schema = test_schema();
+        let sort = vec![
+            (FieldPath::single("name"), SortOrder::Ascending),
+            (FieldPath::single("missing"), SortOrder::Descending),
+        ];
+        let errs = validate_sort(&sort, &schema).unwrap_err();
+        assert_eq!(errs.len(), 1);
+        assert!(matches!(&errs[0], QueryError::UnknownField { field, .. } if field == "missing"));
+    }
+
+    #[test]
+    fn cursor_encode_decode_roundtrip() {
+        let cursor = Cursor::new(
+            vec![DynamicValue::Text("Jane".into()), DynamicValue::Integer(30)],
+            "contact_abc",
+        );
+        let encoded = cursor.encode();
+        assert!(encoded.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(Cursor::decode(&encoded).unwrap(), cursor);
+    }
+
+    #[test]
+    fn cursor_decode_rejects_garbage() {
+        for bad in ["", "zz", "abc", "7b7d"] {
+            assert!(
+                matches!(Cursor::decode(bad), Err(QueryError::InvalidCursor { .. })),
+                "{bad:?} should not decode"
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_cursor_builds_keyset_filter() {
+        let q = Query::new(SchemaId::new())
+            .with_filter(Filter::eq(
+                FieldPath::single("active"),
+                DynamicValue::Boolean(true),
+            ))
+            .with_sort(FieldPath::single("name"), SortOrder::Ascending)
+            .with_sort(FieldPath::single("age"), SortOrder::Descending)
+            .with_limit(10)
+            .with_offset(20)
+            .with_after(Cursor::new(
+                vec![DynamicValue::Text("Jane".into()), DynamicValue::Integer(30)],
+                "contact_abc",
+            ));
+        let resolved = q.resolve_cursor().unwrap();
+        assert_eq!(resolved.after, None);
+        assert_eq!(resolved.offset, None);
+        assert_eq!(
+            resolved.to_string(),
+            format!(
+                "SELECT * FROM {} WHERE (active = true AND (name > \"Jane\" \
+                 OR (name = \"Jane\" AND age < 30) \
+                 OR (name = \"Jane\" AND age = 30 AND id > \"contact_abc\"))) \
+                 ORDER BY name ASC, age DESC, id ASC LIMIT 10",
+                q.schema
+            )
+        );
+    }
+
+    #[test]
+    fn resolve_cursor_rejects_mismatched_sort() {
+        let q = Query::new(SchemaId::new())
+            .with_sort(FieldPath::single("name"), SortOrder::Ascending)
+            .with_after(Cursor::new(vec![], "contact_abc"));
+        assert!(matches!(
+            q.resolve_cursor(),
+            Err(QueryError::InvalidCursor { .. })
+        ));
+    }
+
+    #[test]
+    fn resolve_cursor_adds_sort_keys_to_projection() {
+        let q = Query::new(SchemaId::new())
+            .with_sort(FieldPath::single("age"), SortOrder::Ascending)
+            .with_projection(vec!["name".into()])
+            .with_limit(5);
+        let resolved = q.resolve_cursor().unwrap();
+        assert_eq!(
+            resolved.projection,
+            Some(vec!["name".to_string(), "age".to_string()])
+        );
+    }
+
     #[test]
     fn validate_filter_type_mismatch() {
         let schema = test_schema();
@@ -1304,6 +1877,115 @@ mod tests {
         assert!(validate_filter(&f, &schema).is_ok());
     }
 
+    fn organization_schema() -> SchemaDefinition {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Organization").unwrap(),
+            vec![
+                FieldDefinition::new(
+                    FieldName::new("region").unwrap(),
+                    FieldType::Text(TextConstraints::unconstrained()),
+                ),
+                FieldDefinition::new(
+                    FieldName::new("headcount").unwrap(),
+                    FieldType::Integer(IntegerConstraints::unconstrained()),
+                ),
+            ],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    fn project_schema() -> SchemaDefinition {
+        let relation = |name: &str, cardinality| {
+            FieldDefinition::new(
+                FieldName::new(name).unwrap(),
+                FieldType::Relation {
+                    target: SchemaName::new("Organization").unwrap(),
+                    cardinality,
+                },
+            )
+        };
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Project").unwrap(),
+            vec![
+                relation("organization", Cardinality::One),
+                relation("partners", Cardinality::Many),
+            ],
+            vec![],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn validate_filter_with_targets_accepts_target_field() {
+        let f = Filter::eq(
+            FieldPath::parse("organization.region").unwrap(),
+            DynamicValue::Text("EU".into()),
+        );
+        assert!(
+            validate_filter_with_targets(&f, &project_schema(), &[organization_schema()]).is_ok()
+        );
+    }
+
+    #[test]
+    fn validate_filter_with_targets_rejects_unknown_target_field() {
+        let f = Filter::eq(
+            FieldPath::parse("organization.country").unwrap(),
+            DynamicValue::Text("DE".into()),
+        );
+        let errs = validate_filter_with_targets(&f, &project_schema(), &[organization_schema()])
+            .unwrap_err();
+        assert!(matches!(
+            &errs[0],
+            QueryError::UnknownField { field, schema }
+                if field == "country" && schema == "Organization"
+        ));
+    }
+
+    #[test]
+    fn validate_filter_with_targets_type_checks_target_field() {
+        let f = Filter::gt(
+            FieldPath::parse("organization.headcount").unwrap(),
+            DynamicValue::Text("many".into()),
+        );
+        let errs = validate_filter_with_targets(&f, &project_schema(), &[organization_schema()])
+            .unwrap_err();
+        assert!(matches!(
+            &errs[0],
+            QueryError::TypeMismatch { field, .. } if field == "organization.headcount"
+        ));
+    }
+
+    #[test]
+    fn validate_filter_with_targets_limits_depth_and_cardinality() {
+        let targets = [organization_schema()];
+        let deep = Filter::eq(
+            FieldPath::parse("organization.region.code").unwrap(),
+            DynamicValue::Text("EU".into()),
+        );
+        let errs = validate_filter_with_targets(&deep, &project_schema(), &targets).unwrap_err();
+        assert!(matches!(&errs[0], QueryError::InvalidFieldPath { .. }));
+
+        let many = Filter::eq(
+            FieldPath::parse("partners.region").unwrap(),
+            DynamicValue::Text("EU".into()),
+        );
+        let errs = validate_filter_with_targets(&many, &project_schema(), &targets).unwrap_err();
+        assert!(matches!(&errs[0], QueryError::InvalidFieldPath { .. }));
+    }
+
+    #[test]
+    fn validate_filter_with_targets_requires_target_definition() {
+        let f = Filter::eq(
+            FieldPath::parse("organization.region").unwrap(),
+            DynamicValue::Text("EU".into()),
+        );
+        let errs = validate_filter_with_targets(&f, &project_schema(), &[]).unwrap_err();
+        assert!(matches!(&errs[0], QueryError::InvalidFieldPath { .. }));
+    }
+
     // -- AggregateOp tests --
 
     #[test]
@@ -1323,6 +2005,53 @@ mod tests {
             .to_string(),
             "avg:line_items.amount"
         );
+        assert_eq!(
+            AggregateOp::Min {
+                field: FieldPath::single("value")
+            }
+            .to_string(),
+            "min:value"
+        );
+        assert_eq!(
+            AggregateOp::Max {
+                field: FieldPath::single("value")
+            }
+            .to_string(),
+            "max:value"
+        );
+    }
+
+    #[test]
+    fn aggregate_op_parse_roundtrips_display() {
+        let ops = vec![
+            AggregateOp::Count,
+            AggregateOp::Sum {
+                field: FieldPath::single("value"),
+            },
+            AggregateOp::Avg {
+                field: FieldPath::parse("line_items.amount").unwrap(),
+            },
+            AggregateOp::Min {
+                field: FieldPath::single("value"),
+            },
+            AggregateOp::Max {
+                field: FieldPath::single("value"),
+            },
+        ];
+        for op in ops {
+            assert_eq!(AggregateOp::parse(&op.to_string()).unwrap(), op);
+        }
+    }
+
+    #[test]
+    fn aggregate_op_parse_rejects_unknown() {
+        for bad in ["median:value", "sum", "total"] {
+            assert!(matches!(
+                AggregateOp::parse(bad),
+                Err(QueryError::UnknownAggregate { .. })
+            ));
+        }
+        assert!(AggregateOp::parse("sum:").is_err());
     }
 
     #[test]
@@ -1396,6 +2125,101 @@ mod tests {
         let q = AggregateQuery::new(SchemaId::new()).with_op(AggregateOp::Count);
         let json = serde_json::to_string(&q).unwrap();
         assert!(!json.contains("filter"));
+        assert!(!json.contains("group_by"));
+    }
+
+    #[test]
+    fn aggregate_query_group_by_display() {
+        let q = AggregateQuery::new(SchemaId::new())
+            .with_op(AggregateOp::Count)
+            .with_group_by(FieldName::new("stage").unwrap());
+        assert!(q.to_string().ends_with(" GROUP BY stage"));
+    }
+
+    #[test]
+    fn validate_aggregate_numeric_fields_pass() {
+        let schema = test_schema();
+        let q = AggregateQuery::new(schema.id.clone())
+            .with_ops(vec![
+                AggregateOp::Count,
+                AggregateOp::Sum {
+                    field: FieldPath::single("age"),
+                },
+                AggregateOp::Min {
+                    field: FieldPath::single("age"),
+                },
+                AggregateOp::Max {
+                    field: FieldPath::single("age"),
+                },
+            ])
+            .with_group_by(FieldName::new("active").unwrap());
+        assert!(validate_aggregate(&q, &schema).is_ok());
+    }
+
+    #[test]
+    fn validate_aggregate_rejects_non_numeric_field() {
+        let schema = test_schema();
+        let q = AggregateQuery::new(schema.id.clone()).with_op(AggregateOp::Avg {
+            field: FieldPath::single("name"),
+        });
+        let errs = validate_aggregate(&q, &schema).unwrap_err();
+        assert_eq!(errs.len(), 1);
+        assert!(matches!(
+            &errs[0],
+            QueryError::TypeMismatch { field, actual, .. } if field == "name" && actual == "Text"
+        ));
+    }
+
+    #[test]
+    fn validate_aggregate_rejects_unknown_fields() {
+        let schema = test_schema();
+        let q = AggregateQuery::new(schema.id.clone())
+            .with_op(AggregateOp::Sum {
+                field: FieldPath::single("missing"),
+            })
+            .with_group_by(FieldName::new("stage").unwrap());
+        let errs = validate_aggregate(&q, &schema).unwrap_err();
+        assert_eq!(errs.len(), 2);
+        assert!(errs
+            .iter()
+            .all(|e| matches!(e, QueryError::UnknownField { .. })));
+    }
+
+    #[test]
+    fn computed_fields_cannot_be_queried() {
+        use crate::types::{ComputedExpr, FieldAnnotation};
+
+        let mut schema = test_schema();
+        schema.fields.push(FieldDefinition::with_annotations(
+            FieldName::new("next_age").unwrap(),
+            FieldType::Integer(IntegerConstraints::unconstrained()),
+            Vec::new(),
+            vec![FieldAnnotation::Computed {
+                expr: ComputedExpr::new("age + 1").unwrap(),
+            }],
+        ));
+        let computed = |e: &QueryError| matches!(e, QueryError::ComputedField { field } if field == "next_age");
+
+        let f = Filter::gt(FieldPath::single("next_age"), DynamicValue::Integer(30));
+        assert!(validate_filter(&f, &schema)
+            .unwrap_err()
+            .iter()
+            .all(computed));
+
+        let sort = vec![(FieldPath::single("next_age"), SortOrder::Ascending)];
+        assert!(validate_sort(&sort, &schema)
+            .unwrap_err()
+            .iter()
+            .all(computed));
+
+        let q = AggregateQuery::new(schema.id.clone())
+            .with_op(AggregateOp::Sum {
+                field: FieldPath::single("next_age"),
+            })
+            .with_group_by(FieldName::new("next_age").unwrap());
+        let errs = validate_aggregate(&q, &schema).unwrap_err();
+        assert_eq!(errs.len(), 2);
+        assert!(errs.iter().all(computed));
     }
 
     // -- AggregateResult tests --
@@ -1407,12 +2231,25 @@ mod tests {
                 field: FieldPath::single("value"),
             },
             value: 1234.56,
+            group: BTreeMap::new(),
         };
         let json = serde_json::to_string(&r).unwrap();
         let back: AggregateResult = serde_json::from_str(&json).unwrap();
         assert_eq!(r, back);
     }
 
+    #[test]
+    fn aggregate_result_grouped_serde_roundtrip() {
+        let r = AggregateResult::new(AggregateOp::Count, 3.0).with_group(BTreeMap::from([(
+            "stage".to_string(),
+            DynamicValue::Enum("Won".into()),
+        )]));
+        let json = serde_json::to_string(&r).unwrap();
+        assert!(json.contains("\"group\""));
+        let back: AggregateResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(r, back);
+    }
+
     #[test]
     fn validate_filter_float_field_accepts_integer() {
         // Float fields should accept Integer values (widening)
@@ -12,6 +12,8 @@
 //!   inside that tenant.
 //! - [`WEBHOOK_SUBSCRIPTION_SCHEMA`] — subscriber registrations consumed by
 //!   the webhook dispatcher.
+//! - [`AUDIT_LOG_SCHEMA`] — opt-in write audit trail; seeded only when the
+//!   persistent audit sink is installed.
 
 /// DSL text for the system User schema.
 ///
@@ -65,6 +67,23 @@ schema WebhookSubscription {
 }
 "#;
 
+/// DSL text for the opt-in AuditLog system schema.
+///
+/// One row per successful entity write. `changed_fields` holds the names of
+/// the fields whose values differ between the previous and new entity.
+/// Not included in [`all_system_schemas`].
+pub const AUDIT_LOG_SCHEMA: &str = r#"
+@system
+schema AuditLog {
+    actor:          text(max: 255)
+    action:         text(max: 32) required indexed
+    target_schema:  text(max: 128) required indexed
+    entity_id:      text(max: 255) required indexed
+    timestamp:      datetime required indexed
+    changed_fields: text[]
+}
+"#;
+
 /// Returns all system schema DSL texts in dependency order.
 ///
 /// `User` has no dependencies. `TenantMembership` depends on `User`.
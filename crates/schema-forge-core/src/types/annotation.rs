@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+use super::field_annotation::EnumColor;
 use super::field_name::FieldName;
+use super::ref_label::RefLabelTemplate;
 use super::schema_name::SchemaName;
 use super::schema_version::SchemaVersion;
 
@@ -22,6 +26,23 @@ pub enum Annotation {
     Version { version: SchemaVersion },
     /// `@display("field_name")` -- which field to use as display name.
     Display { field: FieldName },
+    /// `@ref_label("{name} ({code})")` -- how an entity of this schema is
+    /// rendered when shown as a relation value. Falls back to `@display`.
+    RefLabel { template: RefLabelTemplate },
+    /// `@list_columns(title, stage, value)` -- the only fields shown (and
+    /// fetched) by list views. Absent means the default column heuristic.
+    ListColumns { fields: Vec<FieldName> },
+    /// `@list(page_size: 50, max_page_size: 200)` -- the default page size
+    /// of list requests that send no `limit`, and the cap every `limit` is
+    /// clamped to. Absent means no default and no cap.
+    Pagination {
+        page_size: Option<u32>,
+        max_page_size: Option<u32>,
+    },
+    /// `@enum_colors(won: "green", lost: "red")` -- schema-wide badge colors
+    /// keyed by enum variant. They apply to every enum field that has the
+    /// variant; a field's own `@enum_colors` wins for the variants it names.
+    EnumColors { colors: BTreeMap<String, EnumColor> },
     /// `@system` -- marks a schema as system-internal (not user-editable).
     System,
     /// `@access(...)` -- role-based access control on the schema.
@@ -59,6 +80,29 @@ pub enum Annotation {
         /// hook implementation.
         intent: String,
     },
+    /// `@deprecated` or `@deprecated("reason")` -- the schema still works but
+    /// clients should migrate away from it.
+    Deprecated { reason: Option<String> },
+    /// `@id_prefix("inv")` -- TypeID prefix for this schema's entity ids.
+    /// Absent means the prefix is derived from the schema name.
+    IdPrefix { prefix: String },
+    /// `@empty_as_null` -- every text field of the schema stores an empty
+    /// string submitted on input as null. See
+    /// [`FieldAnnotation::EmptyAsNull`](crate::types::FieldAnnotation::EmptyAsNull)
+    /// for the per-field form.
+    EmptyAsNull,
+    /// `@soft_delete` -- deleting an entity stamps `_deleted_at` instead of
+    /// removing the row. Queries skip stamped rows unless they ask for them.
+    SoftDelete,
+    /// `@index(fields: ["agency", "stage"], unique: true)` -- a secondary
+    /// index over one or more fields. `unique` rejects writes that would
+    /// give two entities the same values for all of the fields. Unlike other
+    /// annotations, a schema may declare several.
+    Index {
+        fields: Vec<FieldName>,
+        #[serde(default)]
+        unique: bool,
+    },
 }
 
 /// Lifecycle events that a `@hook` annotation can target.
@@ -236,6 +280,44 @@ impl std::fmt::Display for Annotation {
             Self::Hook { event, intent } => {
                 write!(f, "@hook({}) \"\"\"{}\"\"\"", event.as_str(), intent)
             }
+            Self::RefLabel { template } => write!(f, "@ref_label(\"{template}\")"),
+            Self::ListColumns { fields } => {
+                let names: Vec<&str> = fields.iter().map(FieldName::as_str).collect();
+                write!(f, "@list_columns({})", names.join(", "))
+            }
+            Self::Pagination {
+                page_size,
+                max_page_size,
+            } => {
+                let params: Vec<String> =
+                    [("page_size", page_size), ("max_page_size", max_page_size)]
+                        .into_iter()
+                        .filter_map(|(key, value)| value.map(|v| format!("{key}: {v}")))
+                        .collect();
+                write!(f, "@list({})", params.join(", "))
+            }
+            Self::EnumColors { colors } => {
+                let parts: Vec<String> = colors
+                    .iter()
+                    .map(|(k, v)| format!("{k}: \"{v}\""))
+                    .collect();
+                write!(f, "@enum_colors({})", parts.join(", "))
+            }
+            Self::Deprecated { reason: None } => write!(f, "@deprecated"),
+            Self::Deprecated {
+                reason: Some(reason),
+            } => write!(f, "@deprecated(\"{reason}\")"),
+            Self::IdPrefix { prefix } => write!(f, "@id_prefix(\"{prefix}\")"),
+            Self::EmptyAsNull => write!(f, "@empty_as_null"),
+            Self::SoftDelete => write!(f, "@soft_delete"),
+            Self::Index { fields, unique } => {
+                let names: Vec<String> = fields.iter().map(|n| format!("\"{n}\"")).collect();
+                write!(f, "@index(fields: [{}]", names.join(", "))?;
+                if *unique {
+                    write!(f, ", unique: true")?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -255,6 +337,10 @@ impl Annotation {
         match self {
             Self::Version { .. } => "version",
             Self::Display { .. } => "display",
+            Self::RefLabel { .. } => "ref_label",
+            Self::ListColumns { .. } => "list_columns",
+            Self::Pagination { .. } => "list",
+            Self::EnumColors { .. } => "enum_colors",
             Self::System => "system",
             Self::Access { .. } => "access",
             Self::Tenant(_) => "tenant",
@@ -272,6 +358,11 @@ impl Annotation {
                 HookEvent::AfterUpload => "hook:after_upload",
                 HookEvent::OnScanComplete => "hook:on_scan_complete",
             },
+            Self::Deprecated { .. } => "deprecated",
+            Self::IdPrefix { .. } => "id_prefix",
+            Self::EmptyAsNull => "empty_as_null",
+            Self::SoftDelete => "soft_delete",
+            Self::Index { .. } => "index",
         }
     }
 }
@@ -354,6 +445,117 @@ mod tests {
         assert_eq!(a.kind(), "tenant");
     }
 
+    #[test]
+    fn display_ref_label() {
+        let a = Annotation::RefLabel {
+            template: RefLabelTemplate::new("{name} ({code})").unwrap(),
+        };
+        assert_eq!(a.to_string(), "@ref_label(\"{name} ({code})\")");
+        assert_eq!(a.kind(), "ref_label");
+        let json = serde_json::to_string(&a).unwrap();
+        let back: Annotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, back);
+    }
+
+    #[test]
+    fn display_enum_colors() {
+        let a = Annotation::EnumColors {
+            colors: BTreeMap::from([
+                ("won".to_string(), EnumColor::Green),
+                ("lost".to_string(), EnumColor::Red),
+            ]),
+        };
+        assert_eq!(a.to_string(), "@enum_colors(lost: \"red\", won: \"green\")");
+        assert_eq!(a.kind(), "enum_colors");
+        let json = serde_json::to_string(&a).unwrap();
+        let back: Annotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, back);
+    }
+
+    #[test]
+    fn display_list_columns() {
+        let a = Annotation::ListColumns {
+            fields: vec![
+                FieldName::new("title").unwrap(),
+                FieldName::new("stage").unwrap(),
+            ],
+        };
+        assert_eq!(a.to_string(), "@list_columns(title, stage)");
+        assert_eq!(a.kind(), "list_columns");
+        let json = serde_json::to_string(&a).unwrap();
+        let back: Annotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, back);
+    }
+
+    #[test]
+    fn display_pagination() {
+        let both = Annotation::Pagination {
+            page_size: Some(50),
+            max_page_size: Some(200),
+        };
+        assert_eq!(both.to_string(), "@list(page_size: 50, max_page_size: 200)");
+        assert_eq!(both.kind(), "list");
+        let json = serde_json::to_string(&both).unwrap();
+        let back: Annotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(both, back);
+
+        let cap = Annotation::Pagination {
+            page_size: None,
+            max_page_size: Some(100),
+        };
+        assert_eq!(cap.to_string(), "@list(max_page_size: 100)");
+    }
+
+    #[test]
+    fn display_deprecated() {
+        let bare = Annotation::Deprecated { reason: None };
+        assert_eq!(bare.to_string(), "@deprecated");
+        assert_eq!(bare.kind(), "deprecated");
+
+        let with_reason = Annotation::Deprecated {
+            reason: Some("replaced by Account".into()),
+        };
+        assert_eq!(
+            with_reason.to_string(),
+            "@deprecated(\"replaced by Account\")"
+        );
+        let json = serde_json::to_string(&with_reason).unwrap();
+        let back: Annotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(with_reason, back);
+    }
+
+    #[test]
+    fn display_id_prefix() {
+        let a = Annotation::IdPrefix {
+            prefix: "inv".into(),
+        };
+        assert_eq!(a.to_string(), "@id_prefix(\"inv\")");
+        assert_eq!(a.kind(), "id_prefix");
+        let json = serde_json::to_string(&a).unwrap();
+        let back: Annotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, back);
+    }
+
+    #[test]
+    fn display_empty_as_null() {
+        let a = Annotation::EmptyAsNull;
+        assert_eq!(a.to_string(), "@empty_as_null");
+        assert_eq!(a.kind(), "empty_as_null");
+        let json = serde_json::to_string(&a).unwrap();
+        let back: Annotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, back);
+    }
+
+    #[test]
+    fn display_soft_delete() {
+        let a = Annotation::SoftDelete;
+        assert_eq!(a.to_string(), "@soft_delete");
+        assert_eq!(a.kind(), "soft_delete");
+        let json = serde_json::to_string(&a).unwrap();
+        let back: Annotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, back);
+    }
+
     #[test]
     fn serde_roundtrip_system() {
         let a = Annotation::System;
@@ -539,4 +741,42 @@ mod tests {
         let back: Annotation = serde_json::from_str(&json).unwrap();
         assert_eq!(a, back);
     }
+
+    #[test]
+    fn display_index() {
+        let a = Annotation::Index {
+            fields: vec![
+                FieldName::new("agency").unwrap(),
+                FieldName::new("stage").unwrap(),
+            ],
+            unique: true,
+        };
+        assert_eq!(
+            a.to_string(),
+            "@index(fields: [\"agency\", \"stage\"], unique: true)"
+        );
+        assert_eq!(a.kind(), "index");
+
+        let a = Annotation::Index {
+            fields: vec![FieldName::new("stage").unwrap()],
+            unique: false,
+        };
+        assert_eq!(a.to_string(), "@index(fields: [\"stage\"])");
+    }
+
+    #[test]
+    fn serde_index_unique_defaults_to_false() {
+        let a: Annotation =
+            serde_json::from_str(r#"{"annotation":"Index","fields":["stage"]}"#).unwrap();
+        assert_eq!(
+            a,
+            Annotation::Index {
+                fields: vec![FieldName::new("stage").unwrap()],
+                unique: false,
+            }
+        );
+        let json = serde_json::to_string(&a).unwrap();
+        let back: Annotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, back);
+    }
 }
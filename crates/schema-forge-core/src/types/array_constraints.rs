@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::SchemaError;
+
+/// Optional element-count constraints for `FieldType::Array`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct ArrayConstraints {
+    /// Minimum number of elements, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_items: Option<u32>,
+    /// Maximum number of elements, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<u32>,
+}
+
+impl ArrayConstraints {
+    /// Creates an unconstrained array (any number of elements).
+    pub fn unconstrained() -> Self {
+        Self {
+            min_items: None,
+            max_items: None,
+        }
+    }
+
+    /// Creates array bounds, validating min <= max when both are present.
+    pub fn with_bounds(
+        min_items: Option<u32>,
+        max_items: Option<u32>,
+    ) -> Result<Self, SchemaError> {
+        let c = Self {
+            min_items,
+            max_items,
+        };
+        c.validate()?;
+        Ok(c)
+    }
+
+    /// Returns true when neither bound is set.
+    pub fn is_unconstrained(&self) -> bool {
+        self.min_items.is_none() && self.max_items.is_none()
+    }
+
+    /// Validates that min <= max if both are present.
+    pub fn validate(&self) -> Result<(), SchemaError> {
+        if let (Some(min), Some(max)) = (self.min_items, self.max_items) {
+            if min > max {
+                return Err(SchemaError::InvalidArrayLength { min, max });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks an element count against the bounds, describing the first
+    /// violated bound.
+    pub fn check_len(&self, len: usize) -> Result<(), String> {
+        if let Some(min) = self.min_items {
+            if len < min as usize {
+                return Err(format!("expected at least {min} elements, got {len}"));
+            }
+        }
+        if let Some(max) = self.max_items {
+            if len > max as usize {
+                return Err(format!("expected at most {max} elements, got {len}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unconstrained() {
+        let c = ArrayConstraints::unconstrained();
+        assert!(c.is_unconstrained());
+        assert_eq!(c, ArrayConstraints::default());
+    }
+
+    #[test]
+    fn valid_bounds() {
+        let c = ArrayConstraints::with_bounds(Some(1), Some(10)).unwrap();
+        assert_eq!(c.min_items, Some(1));
+        assert_eq!(c.max_items, Some(10));
+        assert!(!c.is_unconstrained());
+    }
+
+    #[test]
+    fn invalid_bounds() {
+        assert_eq!(
+            ArrayConstraints::with_bounds(Some(5), Some(2)),
+            Err(SchemaError::InvalidArrayLength { min: 5, max: 2 })
+        );
+    }
+
+    #[test]
+    fn check_len_enforces_bounds() {
+        let c = ArrayConstraints::with_bounds(Some(1), Some(3)).unwrap();
+        assert_eq!(
+            c.check_len(0),
+            Err("expected at least 1 elements, got 0".to_string())
+        );
+        assert!(c.check_len(1).is_ok());
+        assert!(c.check_len(3).is_ok());
+        assert_eq!(
+            c.check_len(4),
+            Err("expected at most 3 elements, got 4".to_string())
+        );
+    }
+
+    #[test]
+    fn serde_skips_none() {
+        let json = serde_json::to_string(&ArrayConstraints::unconstrained()).unwrap();
+        assert_eq!(json, "{}");
+    }
+}
@@ -0,0 +1,1091 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::RoundingStrategy;
+use serde::{Deserialize, Serialize};
+
+use crate::error::SchemaError;
+
+use super::decimal::{Decimal, MAX_DECIMAL_SCALE};
+use super::dynamic_value::DynamicValue;
+use super::field_name::FieldName;
+use super::field_type::FieldType;
+
+/// A validated `@computed` expression such as `"price - cost"`.
+///
+/// A computed field has no stored value: its expression is evaluated over
+/// the entity's stored fields every time the entity is read. Expressions
+/// are built from numbers, `'quoted'` strings, `true`, `false`, `null`,
+/// field references (`address.city` reaches into a composite), the
+/// operators `+ - * / %` with parentheses, and the functions `concat`,
+/// `coalesce`, `upper`, `lower`, `trim`, `length`, `abs`, `round`,
+/// `floor`, `ceil`, `min`, and `max`.
+///
+/// Arithmetic with a null operand yields null, and `+` with a text operand
+/// concatenates. Numbers with a fractional part and integer division are
+/// exact decimals unless a float is involved.
+///
+/// The expression is parsed once, at construction; equality, hashing, and
+/// serialization go by the source text alone.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ComputedExpr {
+    source: String,
+    ast: Arc<Expr>,
+}
+
+impl ComputedExpr {
+    /// Creates a new expression, returning an error if it does not parse.
+    pub fn new(expr: impl Into<String>) -> Result<Self, SchemaError> {
+        let source = expr.into();
+        let ast = Arc::new(parse(&source)?);
+        Ok(Self { source, ast })
+    }
+
+    /// Returns the expression source.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Top-level field names the expression reads, in order of first use.
+    pub fn fields(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        collect_fields(&self.ast, &mut out);
+        out
+    }
+
+    /// Evaluates the expression over `fields` and converts the result to
+    /// `field_type`.
+    ///
+    /// Fails with a reason when an operand has the wrong type, arithmetic
+    /// overflows or divides by zero, or the result does not fit the field.
+    pub fn evaluate(
+        &self,
+        fields: &BTreeMap<String, DynamicValue>,
+        field_type: &FieldType,
+    ) -> Result<DynamicValue, String> {
+        coerce(eval(&self.ast, fields)?, field_type)
+    }
+}
+
+impl fmt::Debug for ComputedExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ComputedExpr").field(&self.source).finish()
+    }
+}
+
+impl PartialEq for ComputedExpr {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for ComputedExpr {}
+
+impl Hash for ComputedExpr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.source.hash(state);
+    }
+}
+
+impl fmt::Display for ComputedExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl From<ComputedExpr> for String {
+    fn from(e: ComputedExpr) -> String {
+        e.source
+    }
+}
+
+impl TryFrom<String> for ComputedExpr {
+    type Error = SchemaError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Parsing
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Literal(DynamicValue),
+    Field(Vec<String>),
+    Neg(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Call(Function, Vec<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl fmt::Display for BinaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::Rem => "%",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Function {
+    Concat,
+    Coalesce,
+    Upper,
+    Lower,
+    Trim,
+    Length,
+    Abs,
+    Round,
+    Floor,
+    Ceil,
+    Min,
+    Max,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "concat" => Self::Concat,
+            "coalesce" => Self::Coalesce,
+            "upper" => Self::Upper,
+            "lower" => Self::Lower,
+            "trim" => Self::Trim,
+            "length" => Self::Length,
+            "abs" => Self::Abs,
+            "round" => Self::Round,
+            "floor" => Self::Floor,
+            "ceil" => Self::Ceil,
+            "min" => Self::Min,
+            "max" => Self::Max,
+            _ => return None,
+        })
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Concat => "concat",
+            Self::Coalesce => "coalesce",
+            Self::Upper => "upper",
+            Self::Lower => "lower",
+            Self::Trim => "trim",
+            Self::Length => "length",
+            Self::Abs => "abs",
+            Self::Round => "round",
+            Self::Floor => "floor",
+            Self::Ceil => "ceil",
+            Self::Min => "min",
+            Self::Max => "max",
+        }
+    }
+
+    /// Minimum and maximum argument counts; `None` means any number.
+    fn arity(self) -> (usize, Option<usize>) {
+        match self {
+            Self::Concat | Self::Coalesce | Self::Min | Self::Max => (1, None),
+            Self::Round => (1, Some(2)),
+            _ => (1, Some(1)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(String),
+    Str(String),
+    Ident(String),
+    Punct(char),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "number {n}"),
+            Self::Str(s) => write!(f, "string '{s}'"),
+            Self::Ident(name) => write!(f, "'{name}'"),
+            Self::Punct(c) => write!(f, "'{c}'"),
+        }
+    }
+}
+
+fn parse(source: &str) -> Result<Expr, SchemaError> {
+    let invalid = |reason: String| SchemaError::InvalidComputedExpr {
+        expr: source.to_string(),
+        reason,
+    };
+    let tokens = tokenize(source).map_err(invalid)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.expr().map_err(invalid)?;
+    if let Some(tok) = parser.peek() {
+        return Err(invalid(format!("unexpected {tok}")));
+    }
+    Ok(expr)
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '0'..='9' => {
+                let mut number = String::new();
+                while let Some(&d) = chars.peek().filter(|d| d.is_ascii_digit() || **d == '.') {
+                    number.push(d);
+                    chars.next();
+                }
+                tokens.push(Token::Number(number));
+            }
+            '\'' | '"' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some(q) if q == c => break,
+                        Some(ch) => text.push(ch),
+                        None => return Err("unterminated string".into()),
+                    }
+                }
+                tokens.push(Token::Str(text));
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let mut ident = String::new();
+                while let Some(&d) = chars
+                    .peek()
+                    .filter(|d| d.is_ascii_alphanumeric() || **d == '_')
+                {
+                    ident.push(d);
+                    chars.next();
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            '+' | '-' | '*' | '/' | '%' | '(' | ')' | ',' | '.' => {
+                chars.next();
+                tokens.push(Token::Punct(c));
+            }
+            other => return Err(format!("unexpected character '{other}'")),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive-descent parser over:
+///
+/// ```text
+/// expr  = term (("+" | "-") term)*
+/// term  = unary (("*" | "/" | "%") unary)*
+/// unary = "-" unary | atom
+/// atom  = NUMBER | STRING | "true" | "false" | "null"
+///       | IDENT "(" [expr ("," expr)*] ")"
+///       | IDENT ("." IDENT)*
+///       | "(" expr ")"
+/// ```
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let tok = self.tokens.get(self.pos).cloned();
+        if tok.is_some() {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(&Token::Punct(c)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.eat(c) {
+            return Ok(());
+        }
+        Err(match self.peek() {
+            Some(tok) => format!("expected '{c}', found {tok}"),
+            None => format!("expected '{c}'"),
+        })
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut left = self.term()?;
+        loop {
+            let op = if self.eat('+') {
+                BinaryOp::Add
+            } else if self.eat('-') {
+                BinaryOp::Sub
+            } else {
+                return Ok(left);
+            };
+            left = Expr::Binary(op, Box::new(left), Box::new(self.term()?));
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, String> {
+        let mut left = self.unary()?;
+        loop {
+            let op = if self.eat('*') {
+                BinaryOp::Mul
+            } else if self.eat('/') {
+                BinaryOp::Div
+            } else if self.eat('%') {
+                BinaryOp::Rem
+            } else {
+                return Ok(left);
+            };
+            left = Expr::Binary(op, Box::new(left), Box::new(self.unary()?));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        if self.eat('-') {
+            return Ok(Expr::Neg(Box::new(self.unary()?)));
+        }
+        self.atom()
+    }
+
+    fn atom(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Number(n)) => number_literal(&n),
+            Some(Token::Str(s)) => Ok(Expr::Literal(DynamicValue::Text(s))),
+            Some(Token::Punct('(')) => {
+                let inner = self.expr()?;
+                self.expect(')')?;
+                Ok(inner)
+            }
+            Some(Token::Ident(name)) => match name.as_str() {
+                "true" => Ok(Expr::Literal(DynamicValue::Boolean(true))),
+                "false" => Ok(Expr::Literal(DynamicValue::Boolean(false))),
+                "null" => Ok(Expr::Literal(DynamicValue::Null)),
+                _ if self.eat('(') => self.call(&name),
+                _ => self.path(name),
+            },
+            Some(tok) => Err(format!("unexpected {tok}")),
+            None => Err("unexpected end of expression".into()),
+        }
+    }
+
+    fn call(&mut self, name: &str) -> Result<Expr, String> {
+        let function =
+            Function::from_name(name).ok_or_else(|| format!("unknown function '{name}'"))?;
+        let mut args = Vec::new();
+        if !self.eat(')') {
+            loop {
+                args.push(self.expr()?);
+                if self.eat(')') {
+                    break;
+                }
+                self.expect(',')?;
+            }
+        }
+        let (min, max) = function.arity();
+        if args.len() < min || max.is_some_and(|max| args.len() > max) {
+            let expected = match max {
+                Some(max) if max == min => format!("{min} argument"),
+                Some(max) => format!("{min} or {max} arguments"),
+                None => format!("at least {min} argument"),
+            };
+            return Err(format!("{name}() takes {expected}"));
+        }
+        Ok(Expr::Call(function, args))
+    }
+
+    fn path(&mut self, first: String) -> Result<Expr, String> {
+        let mut path = vec![first];
+        while self.eat('.') {
+            match self.next() {
+                Some(Token::Ident(segment)) => path.push(segment),
+                _ => return Err("expected a field name after '.'".into()),
+            }
+        }
+        for segment in &path {
+            if FieldName::new(segment).is_err() {
+                return Err(format!("'{segment}' is not a valid field name"));
+            }
+        }
+        Ok(Expr::Field(path))
+    }
+}
+
+fn number_literal(text: &str) -> Result<Expr, String> {
+    let value = if text.contains('.') {
+        text.parse::<Decimal>().ok().map(DynamicValue::Decimal)
+    } else {
+        text.parse::<i64>().ok().map(DynamicValue::Integer)
+    };
+    value
+        .map(Expr::Literal)
+        .ok_or_else(|| format!("invalid number {text}"))
+}
+
+fn collect_fields(expr: &Expr, out: &mut Vec<String>) {
+    match expr {
+        Expr::Literal(_) => {}
+        Expr::Field(path) => {
+            if !out.contains(&path[0]) {
+                out.push(path[0].clone());
+            }
+        }
+        Expr::Neg(inner) => collect_fields(inner, out),
+        Expr::Binary(_, left, right) => {
+            collect_fields(left, out);
+            collect_fields(right, out);
+        }
+        Expr::Call(_, args) => {
+            for arg in args {
+                collect_fields(arg, out);
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Evaluation
+// ---------------------------------------------------------------------------
+
+/// A numeric operand. Operations widen integers to decimals and anything
+/// to floats only as far as the other operand requires.
+#[derive(Debug, Clone, Copy)]
+enum Number {
+    Integer(i64),
+    Decimal(Decimal),
+    Float(f64),
+}
+
+impl Number {
+    fn of(value: &DynamicValue) -> Option<Self> {
+        match value {
+            DynamicValue::Integer(i) => Some(Self::Integer(*i)),
+            DynamicValue::Decimal(d) => Some(Self::Decimal(*d)),
+            DynamicValue::Float(f) => Some(Self::Float(*f)),
+            _ => None,
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        match self {
+            Self::Integer(i) => i as f64,
+            Self::Decimal(d) => d.to_f64().unwrap_or(f64::NAN),
+            Self::Float(f) => f,
+        }
+    }
+
+    fn to_decimal(self) -> Result<Decimal, String> {
+        match self {
+            Self::Integer(i) => Ok(Decimal::from(i)),
+            Self::Decimal(d) => Ok(d),
+            Self::Float(f) => Decimal::try_from(f).map_err(|_| format!("{f} is out of range")),
+        }
+    }
+
+    fn into_value(self) -> DynamicValue {
+        match self {
+            Self::Integer(i) => DynamicValue::Integer(i),
+            Self::Decimal(d) => DynamicValue::Decimal(d),
+            Self::Float(f) => DynamicValue::Float(f),
+        }
+    }
+}
+
+fn eval(expr: &Expr, fields: &BTreeMap<String, DynamicValue>) -> Result<DynamicValue, String> {
+    match expr {
+        Expr::Literal(value) => Ok(value.clone()),
+        Expr::Field(path) => Ok(lookup(fields, path)),
+        Expr::Neg(inner) => negate(eval(inner, fields)?),
+        Expr::Binary(op, left, right) => binary(*op, eval(left, fields)?, eval(right, fields)?),
+        Expr::Call(function, args) => {
+            let args = args
+                .iter()
+                .map(|arg| eval(arg, fields))
+                .collect::<Result<Vec<_>, _>>()?;
+            call(*function, args)
+        }
+    }
+}
+
+fn lookup(fields: &BTreeMap<String, DynamicValue>, path: &[String]) -> DynamicValue {
+    let mut value = fields.get(&path[0]);
+    for segment in &path[1..] {
+        value = match value {
+            Some(DynamicValue::Composite(map)) => map.get(segment),
+            _ => None,
+        };
+    }
+    value.cloned().unwrap_or(DynamicValue::Null)
+}
+
+fn negate(value: DynamicValue) -> Result<DynamicValue, String> {
+    match value {
+        DynamicValue::Null => Ok(DynamicValue::Null),
+        DynamicValue::Integer(i) => i
+            .checked_neg()
+            .map(DynamicValue::Integer)
+            .ok_or_else(overflow),
+        DynamicValue::Decimal(d) => Ok(DynamicValue::Decimal(-d)),
+        DynamicValue::Float(f) => Ok(DynamicValue::Float(-f)),
+        other => Err(format!("cannot negate {}", type_name(&other))),
+    }
+}
+
+fn binary(op: BinaryOp, left: DynamicValue, right: DynamicValue) -> Result<DynamicValue, String> {
+    if matches!(left, DynamicValue::Null) || matches!(right, DynamicValue::Null) {
+        return Ok(DynamicValue::Null);
+    }
+    let is_text = |v: &DynamicValue| matches!(v, DynamicValue::Text(_) | DynamicValue::Enum(_));
+    if op == BinaryOp::Add && (is_text(&left) || is_text(&right)) {
+        if let (Some(l), Some(r)) = (text(&left), text(&right)) {
+            return Ok(DynamicValue::Text(l + &r));
+        }
+    }
+    match (Number::of(&left), Number::of(&right)) {
+        (Some(l), Some(r)) => arithmetic(op, l, r),
+        _ => Err(format!(
+            "cannot apply '{op}' to {} and {}",
+            type_name(&left),
+            type_name(&right)
+        )),
+    }
+}
+
+fn arithmetic(op: BinaryOp, left: Number, right: Number) -> Result<DynamicValue, String> {
+    match (left, right) {
+        (Number::Integer(a), Number::Integer(b)) if op != BinaryOp::Div => {
+            let result = match op {
+                BinaryOp::Add => a.checked_add(b),
+                BinaryOp::Sub => a.checked_sub(b),
+                BinaryOp::Mul => a.checked_mul(b),
+                _ if b == 0 => return Err(division_by_zero()),
+                _ => a.checked_rem(b),
+            };
+            result.map(DynamicValue::Integer).ok_or_else(overflow)
+        }
+        (Number::Float(_), _) | (_, Number::Float(_)) => {
+            let (a, b) = (left.to_f64(), right.to_f64());
+            let result = match op {
+                BinaryOp::Add => a + b,
+                BinaryOp::Sub => a - b,
+                BinaryOp::Mul => a * b,
+                _ if b == 0.0 => return Err(division_by_zero()),
+                BinaryOp::Div => a / b,
+                BinaryOp::Rem => a % b,
+            };
+            if result.is_finite() {
+                Ok(DynamicValue::Float(result))
+            } else {
+                Err(overflow())
+            }
+        }
+        _ => {
+            let (a, b) = (left.to_decimal()?, right.to_decimal()?);
+            let result = match op {
+                BinaryOp::Add => a.checked_add(b),
+                BinaryOp::Sub => a.checked_sub(b),
+                BinaryOp::Mul => a.checked_mul(b),
+                _ if b.is_zero() => return Err(division_by_zero()),
+                BinaryOp::Div => a.checked_div(b),
+                BinaryOp::Rem => a.checked_rem(b),
+            };
+            result
+                .map(|d| DynamicValue::Decimal(d.normalize()))
+                .ok_or_else(overflow)
+        }
+    }
+}
+
+fn call(function: Function, mut args: Vec<DynamicValue>) -> Result<DynamicValue, String> {
+    let name = function.name();
+    let expected = |what: &str, value: &DynamicValue| {
+        format!("{name}() expects {what}, got {}", type_name(value))
+    };
+    let first = args.remove(0);
+    if matches!(first, DynamicValue::Null)
+        && !matches!(
+            function,
+            Function::Concat | Function::Coalesce | Function::Min | Function::Max
+        )
+    {
+        return Ok(DynamicValue::Null);
+    }
+    match function {
+        Function::Concat => std::iter::once(first)
+            .chain(args)
+            .map(|value| match value {
+                DynamicValue::Null => Ok(String::new()),
+                value => text(&value).ok_or_else(|| expected("text", &value)),
+            })
+            .collect::<Result<String, _>>()
+            .map(DynamicValue::Text),
+        Function::Coalesce => Ok(std::iter::once(first)
+            .chain(args)
+            .find(|value| !matches!(value, DynamicValue::Null))
+            .unwrap_or(DynamicValue::Null)),
+        Function::Upper | Function::Lower | Function::Trim => {
+            let (DynamicValue::Text(s) | DynamicValue::Enum(s)) = &first else {
+                return Err(expected("text", &first));
+            };
+            Ok(DynamicValue::Text(match function {
+                Function::Upper => s.to_uppercase(),
+                Function::Lower => s.to_lowercase(),
+                _ => s.trim().to_string(),
+            }))
+        }
+        Function::Length => match &first {
+            DynamicValue::Text(s) | DynamicValue::Enum(s) => {
+                Ok(DynamicValue::Integer(s.chars().count() as i64))
+            }
+            DynamicValue::Array(items) => Ok(DynamicValue::Integer(items.len() as i64)),
+            DynamicValue::RefArray(ids) => Ok(DynamicValue::Integer(ids.len() as i64)),
+            other => Err(expected("text or a list", other)),
+        },
+        Function::Abs | Function::Round | Function::Floor | Function::Ceil => {
+            let number = Number::of(&first).ok_or_else(|| expected("a number", &first))?;
+            let digits = match args.first() {
+                None => 0,
+                Some(DynamicValue::Integer(d))
+                    if (0..=i64::from(MAX_DECIMAL_SCALE)).contains(d) =>
+                {
+                    *d as u32
+                }
+                Some(other) => {
+                    return Err(format!(
+                        "{name}() digits must be an integer from 0 to {MAX_DECIMAL_SCALE}, got {}",
+                        type_name(other)
+                    ))
+                }
+            };
+            let result = match (function, number) {
+                (_, Number::Integer(i)) if function != Function::Abs => Number::Integer(i),
+                (Function::Abs, Number::Integer(i)) => {
+                    Number::Integer(i.checked_abs().ok_or_else(overflow)?)
+                }
+                (Function::Abs, Number::Decimal(d)) => Number::Decimal(d.abs()),
+                (Function::Abs, Number::Float(f)) => Number::Float(f.abs()),
+                (Function::Round, Number::Decimal(d)) => Number::Decimal(round_half_up(d, digits)),
+                (Function::Round, Number::Float(f)) => {
+                    let factor = 10f64.powi(digits as i32);
+                    Number::Float((f * factor).round() / factor)
+                }
+                (Function::Floor, Number::Decimal(d)) => Number::Decimal(d.floor()),
+                (Function::Floor, Number::Float(f)) => Number::Float(f.floor()),
+                (_, Number::Decimal(d)) => Number::Decimal(d.ceil()),
+                (_, Number::Float(f)) => Number::Float(f.ceil()),
+            };
+            Ok(result.into_value())
+        }
+        Function::Min | Function::Max => {
+            let mut best: Option<Number> = None;
+            for value in std::iter::once(first).chain(args) {
+                if matches!(value, DynamicValue::Null) {
+                    continue;
+                }
+                let number = Number::of(&value).ok_or_else(|| expected("numbers", &value))?;
+                let replace = match best {
+                    None => true,
+                    Some(current) => {
+                        let ordering = compare(number, current)?;
+                        if function == Function::Min {
+                            ordering == Ordering::Less
+                        } else {
+                            ordering == Ordering::Greater
+                        }
+                    }
+                };
+                if replace {
+                    best = Some(number);
+                }
+            }
+            Ok(best.map_or(DynamicValue::Null, Number::into_value))
+        }
+    }
+}
+
+fn compare(left: Number, right: Number) -> Result<Ordering, String> {
+    match (left, right) {
+        (Number::Integer(a), Number::Integer(b)) => Ok(a.cmp(&b)),
+        (Number::Float(_), _) | (_, Number::Float(_)) => left
+            .to_f64()
+            .partial_cmp(&right.to_f64())
+            .ok_or_else(|| "cannot compare NaN".to_string()),
+        _ => Ok(left.to_decimal()?.cmp(&right.to_decimal()?)),
+    }
+}
+
+/// Converts an evaluated result to the computed field's declared type.
+fn coerce(value: DynamicValue, field_type: &FieldType) -> Result<DynamicValue, String> {
+    let mismatch = |value: &DynamicValue| {
+        format!(
+            "result of type {} does not fit a {field_type} field",
+            type_name(value)
+        )
+    };
+    let number = Number::of(&value);
+    Ok(match (field_type, value) {
+        (_, DynamicValue::Null) => DynamicValue::Null,
+        (FieldType::Integer(_), value) => match number {
+            Some(Number::Integer(i)) => DynamicValue::Integer(i),
+            Some(Number::Decimal(d)) => {
+                DynamicValue::Integer(round_half_up(d, 0).to_i64().ok_or_else(overflow)?)
+            }
+            Some(Number::Float(f))
+                if f.round() >= i64::MIN as f64 && f.round() < i64::MAX as f64 =>
+            {
+                DynamicValue::Integer(f.round() as i64)
+            }
+            Some(Number::Float(_)) => return Err(overflow()),
+            None => return Err(mismatch(&value)),
+        },
+        (FieldType::Float(_), value) => match number {
+            Some(n) => DynamicValue::Float(n.to_f64()),
+            None => return Err(mismatch(&value)),
+        },
+        (FieldType::Decimal { scale }, value) => match number {
+            Some(n) => {
+                let mut d = round_half_up(n.to_decimal()?, u32::from(*scale));
+                d.rescale(u32::from(*scale));
+                DynamicValue::Decimal(d)
+            }
+            None => return Err(mismatch(&value)),
+        },
+        (FieldType::Text(_) | FieldType::RichText(_), value) => {
+            DynamicValue::Text(text(&value).ok_or_else(|| mismatch(&value))?)
+        }
+        (FieldType::Enum(variants), DynamicValue::Text(s) | DynamicValue::Enum(s))
+            if variants.as_slice().contains(&s) =>
+        {
+            DynamicValue::Enum(s)
+        }
+        (FieldType::Boolean, value @ DynamicValue::Boolean(_))
+        | (FieldType::DateTime, value @ DynamicValue::DateTime(_))
+        | (FieldType::Duration, value @ DynamicValue::Duration(_))
+        | (FieldType::Uuid, value @ DynamicValue::Uuid(_)) => value,
+        (_, value) => return Err(mismatch(&value)),
+    })
+}
+
+/// Plain text form of a scalar, as `+` and `concat` join it.
+fn text(value: &DynamicValue) -> Option<String> {
+    match value {
+        DynamicValue::Text(s) | DynamicValue::Enum(s) => Some(s.clone()),
+        DynamicValue::Integer(i) => Some(i.to_string()),
+        DynamicValue::Float(f) => Some(f.to_string()),
+        DynamicValue::Decimal(d) => Some(d.to_string()),
+        DynamicValue::Boolean(b) => Some(b.to_string()),
+        DynamicValue::DateTime(dt) => Some(dt.to_rfc3339()),
+        DynamicValue::Duration(d) => Some(d.to_string()),
+        DynamicValue::Uuid(u) => Some(u.to_string()),
+        DynamicValue::Ref(id) => Some(id.as_str().to_string()),
+        _ => None,
+    }
+}
+
+fn type_name(value: &DynamicValue) -> &'static str {
+    match value {
+        DynamicValue::Null => "null",
+        DynamicValue::Text(_) => "text",
+        DynamicValue::Integer(_) => "integer",
+        DynamicValue::Float(_) => "float",
+        DynamicValue::Decimal(_) => "decimal",
+        DynamicValue::Boolean(_) => "boolean",
+        DynamicValue::DateTime(_) => "datetime",
+        DynamicValue::Duration(_) => "duration",
+        DynamicValue::Uuid(_) => "uuid",
+        DynamicValue::Enum(_) => "enum",
+        DynamicValue::Json(_) => "json",
+        DynamicValue::Array(_) => "array",
+        DynamicValue::Composite(_) => "composite",
+        DynamicValue::Ref(_) => "relation",
+        DynamicValue::RefArray(_) => "relation list",
+    }
+}
+
+/// Rounds half away from zero, as float rounding does.
+fn round_half_up(d: Decimal, digits: u32) -> Decimal {
+    d.round_dp_with_strategy(digits, RoundingStrategy::MidpointAwayFromZero)
+}
+
+fn overflow() -> String {
+    "arithmetic overflow".to_string()
+}
+
+fn division_by_zero() -> String {
+    "division by zero".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EnumVariants, FloatConstraints, IntegerConstraints, TextConstraints};
+
+    fn fields(pairs: &[(&str, DynamicValue)]) -> BTreeMap<String, DynamicValue> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    fn text_type() -> FieldType {
+        FieldType::Text(TextConstraints::unconstrained())
+    }
+
+    fn eval_as(
+        expr: &str,
+        fields: &BTreeMap<String, DynamicValue>,
+        ty: &FieldType,
+    ) -> DynamicValue {
+        ComputedExpr::new(expr)
+            .unwrap()
+            .evaluate(fields, ty)
+            .unwrap()
+    }
+
+    #[test]
+    fn arithmetic_follows_precedence() {
+        let f = fields(&[
+            ("price", DynamicValue::Integer(10)),
+            ("cost", DynamicValue::Integer(4)),
+        ]);
+        let int = FieldType::Integer(IntegerConstraints::unconstrained());
+        assert_eq!(eval_as("price - cost", &f, &int), DynamicValue::Integer(6));
+        assert_eq!(
+            eval_as("price - cost * 2", &f, &int),
+            DynamicValue::Integer(2)
+        );
+        assert_eq!(
+            eval_as("(price - cost) * 2", &f, &int),
+            DynamicValue::Integer(12)
+        );
+        assert_eq!(eval_as("-price % 3", &f, &int), DynamicValue::Integer(-1));
+    }
+
+    #[test]
+    fn division_and_decimals_are_exact() {
+        let f = fields(&[
+            ("price", DynamicValue::Decimal("19.99".parse().unwrap())),
+            ("qty", DynamicValue::Integer(3)),
+        ]);
+        let money = FieldType::Decimal { scale: 2 };
+        assert_eq!(
+            eval_as("price * qty", &f, &money),
+            DynamicValue::Decimal("59.97".parse().unwrap())
+        );
+        assert_eq!(
+            eval_as(
+                "7 / 2",
+                &f,
+                &FieldType::Float(FloatConstraints::unconstrained())
+            ),
+            DynamicValue::Float(3.5)
+        );
+        assert_eq!(
+            eval_as("price * 1.1", &f, &money),
+            DynamicValue::Decimal("21.99".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn text_concatenates_and_nulls_propagate() {
+        let f = fields(&[
+            ("first_name", DynamicValue::Text("Ada".into())),
+            ("last_name", DynamicValue::Text("Lovelace".into())),
+            ("nickname", DynamicValue::Null),
+        ]);
+        assert_eq!(
+            eval_as("first_name + ' ' + last_name", &f, &text_type()),
+            DynamicValue::Text("Ada Lovelace".into())
+        );
+        assert_eq!(
+            eval_as("nickname + ' ' + last_name", &f, &text_type()),
+            DynamicValue::Null
+        );
+        assert_eq!(
+            eval_as("concat(nickname, upper(last_name))", &f, &text_type()),
+            DynamicValue::Text("LOVELACE".into())
+        );
+        assert_eq!(
+            eval_as("coalesce(nickname, first_name)", &f, &text_type()),
+            DynamicValue::Text("Ada".into())
+        );
+        assert_eq!(
+            eval_as("missing_field * 2", &f, &text_type()),
+            DynamicValue::Null
+        );
+    }
+
+    #[test]
+    fn functions_and_composite_paths() {
+        let address =
+            DynamicValue::Composite(fields(&[("city", DynamicValue::Text("Paris".into()))]));
+        let f = fields(&[
+            ("address", address),
+            ("score", DynamicValue::Float(2.4567)),
+            (
+                "tags",
+                DynamicValue::Array(vec![DynamicValue::Text("a".into())]),
+            ),
+        ]);
+        let float = FieldType::Float(FloatConstraints::unconstrained());
+        let int = FieldType::Integer(IntegerConstraints::unconstrained());
+        assert_eq!(
+            eval_as("lower(address.city)", &f, &text_type()),
+            DynamicValue::Text("paris".into())
+        );
+        assert_eq!(
+            eval_as("round(score, 2)", &f, &float),
+            DynamicValue::Float(2.46)
+        );
+        assert_eq!(
+            eval_as("floor(score)", &f, &float),
+            DynamicValue::Float(2.0)
+        );
+        assert_eq!(
+            eval_as("length(tags) + length('abc')", &f, &int),
+            DynamicValue::Integer(4)
+        );
+        assert_eq!(
+            eval_as("max(1, score, null)", &f, &float),
+            DynamicValue::Float(2.4567)
+        );
+        assert_eq!(
+            eval_as("min(abs(-3), 5)", &f, &int),
+            DynamicValue::Integer(3)
+        );
+    }
+
+    #[test]
+    fn result_is_converted_to_the_field_type() {
+        let f = fields(&[("won", DynamicValue::Boolean(true))]);
+        let stage = FieldType::Enum(EnumVariants::new(vec!["Open".into(), "Won".into()]).unwrap());
+        assert_eq!(
+            eval_as("'Won'", &f, &stage),
+            DynamicValue::Enum("Won".into())
+        );
+        assert_eq!(
+            eval_as(
+                "2.5",
+                &f,
+                &FieldType::Integer(IntegerConstraints::unconstrained())
+            ),
+            DynamicValue::Integer(3)
+        );
+        let expr = ComputedExpr::new("'Lost'").unwrap();
+        assert!(expr.evaluate(&f, &stage).is_err());
+        assert!(ComputedExpr::new("won")
+            .unwrap()
+            .evaluate(&f, &FieldType::Float(FloatConstraints::unconstrained()))
+            .is_err());
+    }
+
+    #[test]
+    fn evaluation_errors_carry_a_reason() {
+        let f = fields(&[("n", DynamicValue::Integer(i64::MAX))]);
+        let int = FieldType::Integer(IntegerConstraints::unconstrained());
+        let err = |expr: &str| {
+            ComputedExpr::new(expr)
+                .unwrap()
+                .evaluate(&f, &int)
+                .unwrap_err()
+        };
+        assert_eq!(err("n + 1"), "arithmetic overflow");
+        assert_eq!(err("n / 0"), "division by zero");
+        assert_eq!(err("n - 'x'"), "cannot apply '-' to integer and text");
+        assert_eq!(err("upper(n)"), "upper() expects text, got integer");
+    }
+
+    #[test]
+    fn fields_lists_top_level_references_once() {
+        let expr = ComputedExpr::new("concat(first, ' ', address.city, first)").unwrap();
+        assert_eq!(expr.fields(), vec!["first", "address"]);
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        for (bad, reason) in [
+            ("price -", "unexpected end of expression"),
+            ("(price", "expected ')'"),
+            ("price cost", "unexpected 'cost'"),
+            ("'open", "unterminated string"),
+            ("price # 2", "unexpected character '#'"),
+            ("sum(price)", "unknown function 'sum'"),
+            ("upper(a, b)", "upper() takes 1 argument"),
+            ("round()", "round() takes 1 or 2 arguments"),
+            ("Price", "'Price' is not a valid field name"),
+            ("1.2.3", "invalid number 1.2.3"),
+        ] {
+            assert_eq!(
+                ComputedExpr::new(bad),
+                Err(SchemaError::InvalidComputedExpr {
+                    expr: bad.to_string(),
+                    reason: reason.to_string(),
+                }),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn serde_roundtrips_as_a_string() {
+        let expr = ComputedExpr::new("price - cost").unwrap();
+        let json = serde_json::to_string(&expr).unwrap();
+        assert_eq!(json, "\"price - cost\"");
+        assert_eq!(serde_json::from_str::<ComputedExpr>(&json).unwrap(), expr);
+        assert!(serde_json::from_str::<ComputedExpr>("\"price -\"").is_err());
+    }
+
+    #[test]
+    fn parsed_once_and_compared_by_source() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let expr = ComputedExpr::new("price - cost").unwrap();
+        let copy = expr.clone();
+        assert!(Arc::ptr_eq(&expr.ast, &copy.ast));
+
+        let hash = |e: &ComputedExpr| {
+            let mut h = DefaultHasher::new();
+            e.hash(&mut h);
+            h.finish()
+        };
+        let reparsed: ComputedExpr = serde_json::from_str("\"price - cost\"").unwrap();
+        assert_eq!(reparsed, expr);
+        assert_eq!(hash(&reparsed), hash(&expr));
+        assert_ne!(ComputedExpr::new("price + cost").unwrap(), expr);
+        assert_eq!(format!("{expr:?}"), "ComputedExpr(\"price - cost\")");
+
+        let row = fields(&[
+            ("price", DynamicValue::Integer(10)),
+            ("cost", DynamicValue::Integer(4)),
+        ]);
+        let ty = FieldType::Integer(IntegerConstraints::unconstrained());
+        assert_eq!(reparsed.evaluate(&row, &ty), Ok(DynamicValue::Integer(6)));
+    }
+}
@@ -0,0 +1,84 @@
+use std::str::FromStr;
+
+pub use rust_decimal::Decimal;
+
+use crate::error::SchemaError;
+
+/// The largest scale a [`Decimal`] can carry.
+pub const MAX_DECIMAL_SCALE: u8 = 28;
+
+/// Parses a plain decimal string (`-12.50`) for a field of the given scale.
+///
+/// Trailing zeros past the scale are accepted (`12.500` fits scale 2), but
+/// significant digits past it are rejected rather than rounded, so a stored
+/// amount never differs from what the client sent. The result carries
+/// exactly `scale` fractional digits.
+pub fn parse_decimal(s: &str, scale: u8) -> Result<Decimal, SchemaError> {
+    let invalid = || SchemaError::InvalidDecimal(s.to_string());
+    let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+    let (whole, frac) = digits.split_once('.').unwrap_or((digits, ""));
+    // `Decimal::from_str` also takes `1_000`; keep the wire format strict.
+    if (whole.is_empty() && frac.is_empty())
+        || !whole
+            .bytes()
+            .chain(frac.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let mut value = Decimal::from_str(s).map_err(|_| invalid())?;
+    if value.normalize().scale() > u32::from(scale) {
+        return Err(SchemaError::DecimalScaleExceeded {
+            value: s.to_string(),
+            scale,
+        });
+    }
+    value.rescale(u32::from(scale));
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pads_to_scale() {
+        assert_eq!(parse_decimal("12.5", 2).unwrap().to_string(), "12.50");
+        assert_eq!(parse_decimal("7", 2).unwrap().to_string(), "7.00");
+        assert_eq!(parse_decimal("-0.10", 2).unwrap().to_string(), "-0.10");
+        assert_eq!(parse_decimal("3", 0).unwrap().to_string(), "3");
+    }
+
+    #[test]
+    fn parse_accepts_trailing_zeros_past_scale() {
+        assert_eq!(parse_decimal("12.500", 2).unwrap().to_string(), "12.50");
+    }
+
+    #[test]
+    fn parse_rejects_digits_past_scale() {
+        assert_eq!(
+            parse_decimal("1.005", 2).unwrap_err(),
+            SchemaError::DecimalScaleExceeded {
+                value: "1.005".into(),
+                scale: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed() {
+        for s in ["", "-", ".", "1e3", "1_000", "12,50", "abc", "1.2.3", " 1"] {
+            assert_eq!(
+                parse_decimal(s, 2).unwrap_err(),
+                SchemaError::InvalidDecimal(s.into()),
+                "{s:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_is_exact() {
+        let sum = parse_decimal("0.1", 2).unwrap() + parse_decimal("0.2", 2).unwrap();
+        assert_eq!(sum, parse_decimal("0.3", 2).unwrap());
+    }
+}
@@ -14,6 +14,15 @@ pub enum DefaultValue {
     /// Float stored as a string representation (e.g. `"3.14"`).
     Float(String),
     Boolean(bool),
+    /// Per-schema counter resolved atomically by the backend on create,
+    /// e.g. `sequence("INV-", 1000)` yields `INV-1001`, `INV-1002`, ...
+    ///
+    /// `start` is the counter's initial value; the first entity gets
+    /// `start + 1`.
+    Sequence {
+        prefix: String,
+        start: i64,
+    },
 }
 
 impl DefaultValue {
@@ -25,6 +34,21 @@ impl DefaultValue {
         Ok(Self::Float(s))
     }
 
+    /// Returns `true` for defaults resolved by the backend at create time
+    /// rather than stored as a literal column default.
+    pub fn is_sequence(&self) -> bool {
+        matches!(self, Self::Sequence { .. })
+    }
+
+    /// For `Sequence` variants, formats the counter value `n` as the stored
+    /// text (`prefix` followed by `n`). Returns `None` for other variants.
+    pub fn format_sequence(&self, n: i64) -> Option<String> {
+        match self {
+            Self::Sequence { prefix, .. } => Some(format!("{prefix}{n}")),
+            _ => None,
+        }
+    }
+
     /// For `Float` variants, parses and returns the f64 value.
     /// Returns `None` for other variants.
     pub fn as_f64(&self) -> Option<f64> {
@@ -42,6 +66,7 @@ impl fmt::Display for DefaultValue {
             Self::Integer(i) => write!(f, "{i}"),
             Self::Float(s) => write!(f, "{s}"),
             Self::Boolean(b) => write!(f, "{b}"),
+            Self::Sequence { prefix, start } => write!(f, "sequence(\"{prefix}\", {start})"),
         }
     }
 }
@@ -70,6 +95,26 @@ mod tests {
         );
         assert_eq!(DefaultValue::Integer(42).to_string(), "42");
         assert_eq!(DefaultValue::Boolean(true).to_string(), "true");
+        assert_eq!(
+            DefaultValue::Sequence {
+                prefix: "INV-".into(),
+                start: 1000
+            }
+            .to_string(),
+            "sequence(\"INV-\", 1000)"
+        );
+    }
+
+    #[test]
+    fn sequence_helpers() {
+        let seq = DefaultValue::Sequence {
+            prefix: "INV-".into(),
+            start: 1000,
+        };
+        assert!(seq.is_sequence());
+        assert_eq!(seq.format_sequence(1001).as_deref(), Some("INV-1001"));
+        assert!(!DefaultValue::Integer(1).is_sequence());
+        assert_eq!(DefaultValue::Integer(1).format_sequence(1), None);
     }
 
     #[test]
@@ -84,6 +129,10 @@ mod tests {
             DefaultValue::Integer(42),
             DefaultValue::float("3.14").unwrap(),
             DefaultValue::Boolean(false),
+            DefaultValue::Sequence {
+                prefix: "INV-".into(),
+                start: 1000,
+            },
         ];
         for v in values {
             let json = serde_json::to_string(&v).unwrap();
@@ -2,7 +2,10 @@ use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use super::decimal::Decimal;
 use super::entity_id::EntityId;
+use super::iso_duration::IsoDuration;
+use super::uuid_value::Uuid;
 
 /// Runtime value for any field type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -13,8 +16,13 @@ pub enum DynamicValue {
     Text(String),
     Integer(i64),
     Float(f64),
+    /// Serialized as a string so no precision is lost in transit.
+    Decimal(Decimal),
     Boolean(bool),
     DateTime(chrono::DateTime<chrono::Utc>),
+    Duration(IsoDuration),
+    /// Serialized in the canonical hyphenated form.
+    Uuid(Uuid),
     Enum(String),
     Json(serde_json::Value),
     Array(Vec<DynamicValue>),
@@ -30,8 +38,11 @@ impl std::fmt::Display for DynamicValue {
             Self::Text(s) => write!(f, "\"{s}\""),
             Self::Integer(i) => write!(f, "{i}"),
             Self::Float(v) => write!(f, "{v}"),
+            Self::Decimal(d) => write!(f, "{d}"),
             Self::Boolean(b) => write!(f, "{b}"),
             Self::DateTime(dt) => write!(f, "{dt}"),
+            Self::Duration(d) => write!(f, "{d}"),
+            Self::Uuid(u) => write!(f, "{u}"),
             Self::Enum(s) => write!(f, "{s}"),
             Self::Json(v) => write!(f, "{v}"),
             Self::Array(arr) => {
@@ -69,10 +80,235 @@ impl std::fmt::Display for DynamicValue {
     }
 }
 
+impl DynamicValue {
+    /// Compares two values by their canonical form.
+    ///
+    /// Unlike `==`, JSON objects compare regardless of key order, JSON
+    /// numbers compare by value (`1` equals `1.0`), and floats treat `-0.0`
+    /// as `0.0` and every NaN as equal. Use this to decide whether a field
+    /// actually changed.
+    pub fn canonical_eq(&self, other: &Self) -> bool {
+        self.canonical_bytes() == other.canonical_bytes()
+    }
+
+    /// A stable 64-bit hash of the canonical form.
+    ///
+    /// Values that are [`canonical_eq`](Self::canonical_eq) hash equal. The
+    /// hash is FNV-1a over a fixed encoding, so it is the same across
+    /// processes and releases and may be persisted.
+    pub fn canonical_hash(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+        self.canonical_bytes()
+            .iter()
+            .fold(FNV_OFFSET, |hash, byte| {
+                (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
+
+    fn canonical_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write_canonical(&mut out);
+        out
+    }
+
+    /// Appends a type-tagged, length-prefixed encoding of `self`.
+    fn write_canonical(&self, out: &mut Vec<u8>) {
+        match self {
+            Self::Null => out.push(0),
+            Self::Text(s) => write_tagged_str(out, 1, s),
+            Self::Integer(i) => {
+                out.push(2);
+                out.extend_from_slice(&i.to_be_bytes());
+            }
+            Self::Float(f) => {
+                out.push(3);
+                out.extend_from_slice(&canonical_float_bits(*f).to_be_bytes());
+            }
+            Self::Boolean(b) => out.extend_from_slice(&[4, u8::from(*b)]),
+            Self::DateTime(dt) => {
+                out.push(5);
+                out.extend_from_slice(&dt.timestamp().to_be_bytes());
+                out.extend_from_slice(&dt.timestamp_subsec_nanos().to_be_bytes());
+            }
+            Self::Duration(d) => write_tagged_str(out, 13, &d.to_string()),
+            // Normalized so `1.5` and `1.50` fingerprint alike, matching `==`.
+            Self::Decimal(d) => write_tagged_str(out, 14, &d.normalize().to_string()),
+            Self::Uuid(u) => {
+                out.push(15);
+                out.extend_from_slice(u.as_bytes());
+            }
+            Self::Enum(s) => write_tagged_str(out, 6, s),
+            Self::Json(v) => {
+                out.push(7);
+                write_canonical_json(out, v);
+            }
+            Self::Array(items) => {
+                write_len(out, 8, items.len());
+                for item in items {
+                    item.write_canonical(out);
+                }
+            }
+            Self::Composite(map) => {
+                write_len(out, 9, map.len());
+                for (key, value) in map {
+                    write_tagged_str(out, 1, key);
+                    value.write_canonical(out);
+                }
+            }
+            Self::Ref(id) => write_tagged_str(out, 10, id.as_str()),
+            Self::RefArray(ids) => {
+                write_len(out, 11, ids.len());
+                for id in ids {
+                    write_tagged_str(out, 10, id.as_str());
+                }
+            }
+        }
+    }
+}
+
+fn write_len(out: &mut Vec<u8>, tag: u8, len: usize) {
+    out.push(tag);
+    out.extend_from_slice(&(len as u64).to_be_bytes());
+}
+
+fn write_tagged_str(out: &mut Vec<u8>, tag: u8, s: &str) {
+    write_len(out, tag, s.len());
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn canonical_float_bits(f: f64) -> u64 {
+    if f.is_nan() {
+        f64::NAN.to_bits()
+    } else if f == 0.0 {
+        0.0f64.to_bits()
+    } else {
+        f.to_bits()
+    }
+}
+
+/// JSON objects are written with sorted keys; integral numbers are written
+/// as integers so `1` and `1.0` encode the same.
+fn write_canonical_json(out: &mut Vec<u8>, value: &serde_json::Value) {
+    use serde_json::Value;
+    match value {
+        Value::Null => out.push(0),
+        Value::Bool(b) => out.extend_from_slice(&[4, u8::from(*b)]),
+        Value::Number(n) => {
+            let as_int = n.as_i64().or_else(|| {
+                n.as_f64()
+                    .filter(|f| f.fract() == 0.0 && *f >= i64::MIN as f64 && *f < i64::MAX as f64)
+                    .map(|f| f as i64)
+            });
+            match (as_int, n.as_u64()) {
+                (Some(i), _) => {
+                    out.push(2);
+                    out.extend_from_slice(&i.to_be_bytes());
+                }
+                (None, Some(u)) => {
+                    out.push(12);
+                    out.extend_from_slice(&u.to_be_bytes());
+                }
+                (None, None) => {
+                    out.push(3);
+                    let f = n.as_f64().unwrap_or(f64::NAN);
+                    out.extend_from_slice(&canonical_float_bits(f).to_be_bytes());
+                }
+            }
+        }
+        Value::String(s) => write_tagged_str(out, 1, s),
+        Value::Array(items) => {
+            write_len(out, 8, items.len());
+            for item in items {
+                write_canonical_json(out, item);
+            }
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            write_len(out, 9, entries.len());
+            for (key, value) in entries {
+                write_tagged_str(out, 1, key);
+                write_canonical_json(out, value);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn json(s: &str) -> DynamicValue {
+        DynamicValue::Json(serde_json::from_str(s).unwrap())
+    }
+
+    fn composite(entries: &[(&str, DynamicValue)]) -> DynamicValue {
+        let mut map = BTreeMap::new();
+        for (k, v) in entries {
+            map.insert(k.to_string(), v.clone());
+        }
+        DynamicValue::Composite(map)
+    }
+
+    #[test]
+    fn canonical_eq_ignores_key_order() {
+        let a = composite(&[
+            ("street", DynamicValue::Text("Main".into())),
+            ("meta", json(r#"{"a": 1, "b": {"x": true, "y": [1, 2]}}"#)),
+        ]);
+        let b = composite(&[
+            ("meta", json(r#"{"b": {"y": [1, 2], "x": true}, "a": 1}"#)),
+            ("street", DynamicValue::Text("Main".into())),
+        ]);
+        assert!(a.canonical_eq(&b));
+        assert_eq!(a.canonical_hash(), b.canonical_hash());
+    }
+
+    #[test]
+    fn canonical_eq_normalizes_numbers() {
+        assert!(json("1").canonical_eq(&json("1.0")));
+        assert_eq!(json("1").canonical_hash(), json("1.0").canonical_hash());
+        assert!(DynamicValue::Float(0.0).canonical_eq(&DynamicValue::Float(-0.0)));
+        assert!(DynamicValue::Float(f64::NAN).canonical_eq(&DynamicValue::Float(f64::NAN)));
+    }
+
+    #[test]
+    fn canonical_eq_distinguishes_different_values() {
+        let pairs = [
+            (json(r#"{"a": 1}"#), json(r#"{"a": 2}"#)),
+            (json("[1, 2]"), json("[2, 1]")),
+            (
+                composite(&[("a", DynamicValue::Integer(1))]),
+                composite(&[("b", DynamicValue::Integer(1))]),
+            ),
+            (
+                DynamicValue::Text("x".into()),
+                DynamicValue::Enum("x".into()),
+            ),
+            (DynamicValue::Integer(1), DynamicValue::Float(1.0)),
+            (DynamicValue::Null, DynamicValue::Text(String::new())),
+            (
+                DynamicValue::Array(vec![DynamicValue::Text("ab".into())]),
+                DynamicValue::Array(vec![
+                    DynamicValue::Text("a".into()),
+                    DynamicValue::Text("b".into()),
+                ]),
+            ),
+        ];
+        for (a, b) in pairs {
+            assert!(!a.canonical_eq(&b), "{a} vs {b}");
+            assert_ne!(a.canonical_hash(), b.canonical_hash(), "{a} vs {b}");
+        }
+    }
+
+    #[test]
+    fn canonical_hash_is_stable() {
+        // Pinned so a change to the encoding is caught before persisted
+        // hashes silently stop matching.
+        assert_eq!(DynamicValue::Null.canonical_hash(), 0xaf63_bd4c_8601_b7df);
+    }
+
     #[test]
     fn display_null() {
         assert_eq!(DynamicValue::Null.to_string(), "null");
@@ -160,4 +396,42 @@ mod tests {
         let back: DynamicValue = serde_json::from_str(&json).unwrap();
         assert_eq!(v, back);
     }
+    #[test]
+    fn serde_roundtrip_duration() {
+        let v = DynamicValue::Duration(IsoDuration::parse("P3DT4H").unwrap());
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"{"type":"Duration","value":"P3DT4H"}"#);
+        let back: DynamicValue = serde_json::from_str(&json).unwrap();
+        assert_eq!(v, back);
+    }
+
+    #[test]
+    fn serde_roundtrip_uuid_hyphenated() {
+        let u = crate::types::parse_uuid("67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap();
+        let v = DynamicValue::Uuid(u);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"Uuid","value":"67e55044-10b1-426f-9247-bb680e5fe0c8"}"#
+        );
+        let back: DynamicValue = serde_json::from_str(&json).unwrap();
+        assert_eq!(v, back);
+    }
+
+    #[test]
+    fn serde_roundtrip_decimal_as_string() {
+        let v = DynamicValue::Decimal(crate::types::parse_decimal("19.99", 2).unwrap());
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"{"type":"Decimal","value":"19.99"}"#);
+        let back: DynamicValue = serde_json::from_str(&json).unwrap();
+        assert_eq!(v, back);
+    }
+
+    #[test]
+    fn canonical_eq_decimal_ignores_trailing_zeros() {
+        let a = DynamicValue::Decimal(crate::types::parse_decimal("1.5", 1).unwrap());
+        let b = DynamicValue::Decimal(crate::types::parse_decimal("1.5", 2).unwrap());
+        assert!(a.canonical_eq(&b));
+        assert_eq!(a.canonical_hash(), b.canonical_hash());
+    }
 }
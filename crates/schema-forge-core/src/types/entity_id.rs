@@ -4,6 +4,11 @@ use std::str::FromStr;
 use mti::prelude::{MagicTypeId, MagicTypeIdExt, V7};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+use crate::error::SchemaError;
+
+/// Longest prefix a TypeID allows.
+const MAX_PREFIX_LEN: usize = 63;
+
 /// A TypeID-based identifier whose prefix encodes the entity type
 /// (e.g. `project_01k…`, `opportunity_01k…`, `user_01k…`).
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -28,6 +33,46 @@ impl EntityId {
         Ok(Self(id))
     }
 
+    /// Parses an `EntityId` and checks that its prefix is exactly `expected`.
+    ///
+    /// Used for schemas that declare `@id_prefix`, where an id minted for a
+    /// different schema must not be accepted.
+    pub fn parse_with_prefix(s: &str, expected: &str) -> Result<Self, String> {
+        let id = Self::parse(s)?;
+        if id.prefix() != expected {
+            return Err(format!(
+                "expected id prefix '{expected}', got '{}'",
+                id.prefix()
+            ));
+        }
+        Ok(id)
+    }
+
+    /// Checks that `prefix` is usable verbatim as a TypeID prefix: 1-63
+    /// lowercase ASCII letters or `_`, not starting or ending with `_`.
+    ///
+    /// Unlike [`EntityId::new`], which silently sanitizes, this rejects
+    /// anything that would not survive unchanged.
+    pub fn validate_prefix(prefix: &str) -> Result<(), SchemaError> {
+        let invalid = |reason: &str| SchemaError::InvalidIdPrefix {
+            prefix: prefix.to_string(),
+            reason: reason.to_string(),
+        };
+        if prefix.is_empty() {
+            return Err(invalid("must not be empty"));
+        }
+        if prefix.len() > MAX_PREFIX_LEN {
+            return Err(invalid("must be at most 63 characters"));
+        }
+        if !prefix.bytes().all(|b| b.is_ascii_lowercase() || b == b'_') {
+            return Err(invalid("must contain only lowercase letters and '_'"));
+        }
+        if prefix.starts_with('_') || prefix.ends_with('_') {
+            return Err(invalid("must not start or end with '_'"));
+        }
+        Ok(())
+    }
+
     /// Returns the TypeID prefix (e.g. `"project"`, `"opportunity"`).
     pub fn prefix(&self) -> &str {
         self.0.prefix().as_str()
@@ -96,6 +141,32 @@ mod tests {
         assert_eq!(parsed, schema_prefixed);
     }
 
+    #[test]
+    fn parse_with_prefix_accepts_matching_prefix() {
+        let id = EntityId::new("inv");
+        let parsed = EntityId::parse_with_prefix(id.as_str(), "inv").unwrap();
+        assert_eq!(parsed, id);
+    }
+
+    #[test]
+    fn parse_with_prefix_rejects_mismatch() {
+        let id = EntityId::new("invoice");
+        let err = EntityId::parse_with_prefix(id.as_str(), "inv").unwrap_err();
+        assert_eq!(err, "expected id prefix 'inv', got 'invoice'");
+    }
+
+    #[test]
+    fn validate_prefix_rules() {
+        assert!(EntityId::validate_prefix("inv").is_ok());
+        assert!(EntityId::validate_prefix("line_item").is_ok());
+        assert!(EntityId::validate_prefix("").is_err());
+        assert!(EntityId::validate_prefix("Inv").is_err());
+        assert!(EntityId::validate_prefix("inv2").is_err());
+        assert!(EntityId::validate_prefix("_inv").is_err());
+        assert!(EntityId::validate_prefix("inv_").is_err());
+        assert!(EntityId::validate_prefix(&"a".repeat(64)).is_err());
+    }
+
     #[test]
     fn parse_rejects_malformed() {
         assert!(EntityId::parse("not-a-typeid").is_err());
@@ -49,6 +49,15 @@ impl EnumVariants {
     pub fn iter(&self) -> std::slice::Iter<'_, String> {
         self.0.iter()
     }
+
+    /// Checks that `value` is one of the variants.
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        if self.0.iter().any(|v| v == value) {
+            Ok(())
+        } else {
+            Err(format!("'{value}' is not one of {self}"))
+        }
+    }
 }
 
 impl fmt::Display for EnumVariants {
@@ -112,6 +121,13 @@ mod tests {
         assert_eq!(v.to_string(), "[A, B]");
     }
 
+    #[test]
+    fn check_rejects_undeclared_variant() {
+        let v = EnumVariants::new(vec!["A".into(), "B".into()]).unwrap();
+        assert!(v.check("A").is_ok());
+        assert_eq!(v.check("C").unwrap_err(), "'C' is not one of [A, B]");
+    }
+
     #[test]
     fn serde_roundtrip() {
         let v = EnumVariants::new(vec!["Active".into(), "Pending".into()]).unwrap();
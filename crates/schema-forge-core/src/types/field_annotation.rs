@@ -4,6 +4,8 @@ use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use super::computed_expr::ComputedExpr;
+
 /// Closed vocabulary of UI widget hints accepted by `@widget("...")`.
 ///
 /// The JSON / DSL representation is the `snake_case` form of each variant,
@@ -270,6 +272,35 @@ const VALID_FORMAT_TYPES: &[&str] = &[
     "currency", "percent", "date", "datetime", "relative", "bytes", "duration",
 ];
 
+/// Locale used by number formatting when `@format(...)` names none.
+pub const DEFAULT_FORMAT_LOCALE: &str = "en-US";
+
+/// Currency used by `@format("currency")` when the annotation names none.
+pub const DEFAULT_CURRENCY: &str = "USD";
+
+/// Returns `true` if `tag` is a `language[-REGION]` locale tag such as
+/// `en`, `de-DE`, or `es-419`: a 2–3 letter lowercase language subtag,
+/// optionally followed by a 2-letter uppercase or 3-digit region.
+pub fn is_valid_locale_tag(tag: &str) -> bool {
+    let (language, region) = match tag.split_once('-') {
+        Some((language, region)) => (language, Some(region)),
+        None => (tag, None),
+    };
+    let language_ok =
+        (2..=3).contains(&language.len()) && language.bytes().all(|b| b.is_ascii_lowercase());
+    let region_ok = region.is_none_or(|r| {
+        (r.len() == 2 && r.bytes().all(|b| b.is_ascii_uppercase()))
+            || (r.len() == 3 && r.bytes().all(|b| b.is_ascii_digit()))
+    });
+    language_ok && region_ok
+}
+
+/// Returns `true` if `code` is shaped like an ISO 4217 currency code
+/// (three uppercase ASCII letters, e.g. `EUR`).
+pub fn is_valid_currency_code(code: &str) -> bool {
+    code.len() == 3 && code.bytes().all(|b| b.is_ascii_uppercase())
+}
+
 /// Closed vocabulary of semantic color tokens accepted by `@enum_colors(...)`.
 ///
 /// Each variant maps to a stable CSS token emitted by the site generator and
@@ -423,6 +454,7 @@ impl ListHint {
             Self::Primary => "primary",
             Self::Column => "column",
             Self::Hidden => "hidden",
+            Self::Deprecated { .. } => "deprecated",
         }
     }
 }
@@ -498,7 +530,18 @@ pub enum FieldAnnotation {
     /// `@kanban_column` -- marks this field as the grouping column for kanban views.
     KanbanColumn,
     /// `@format("format_type")` -- display format hint for field values.
-    Format { format_type: FormatType },
+    /// `currency` and `percent` accept a `locale: "de-DE"` option that picks
+    /// the digit separators (and the currency symbol placement), and
+    /// `currency` accepts an ISO 4217 `currency: "EUR"` code. Absent
+    /// options fall back to [`DEFAULT_FORMAT_LOCALE`] and
+    /// [`DEFAULT_CURRENCY`].
+    Format {
+        format_type: FormatType,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        locale: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        currency: Option<String>,
+    },
     /// `@enum_colors(variant: "color", ...)` -- semantic color tokens for
     /// specific enum variants. Keyed by the variant name; the generator is
     /// responsible for ensuring every key names a valid variant of the
@@ -512,8 +555,9 @@ pub enum FieldAnnotation {
     /// as the headline cell rendered with display styling.
     List { hint: ListHint },
     /// `@hidden` -- marks the field as out-of-band: it must not appear in
-    /// any API response (REST, GraphQL, list, query, get) and must not be
-    /// accepted in any client-supplied request body. Consumers that legitimately
+    /// any API response (REST, GraphQL, list, query, get) and is accepted in
+    /// a client-supplied request body only from a `platform_admin`, so
+    /// server-side values stay writable by operators. Consumers that legitimately
     /// need the value (e.g. an `AuthStore` reading a `password_hash`) bypass
     /// the API layer and read the entity directly from the backend.
     ///
@@ -522,6 +566,22 @@ pub enum FieldAnnotation {
     /// the routing-layer strip, this makes the field invisible to every
     /// authenticated principal short of the storage layer itself.
     Hidden,
+    /// `@deprecated` or `@deprecated("reason")` -- the field still works but
+    /// clients should stop relying on it. Surfaced in the generated admin UI,
+    /// the OpenAPI/JSON-Schema exports, and the GraphQL schema.
+    Deprecated { reason: Option<String> },
+    /// `@empty_as_null` -- an empty string submitted for this text field is
+    /// stored as null, so "blank" and "absent" filter and validate the same
+    /// way. A required field therefore rejects `""`.
+    EmptyAsNull,
+    /// `@immutable` -- write-once: the value may be set on create (or on
+    /// the first update that gives it one) but never changed afterwards.
+    /// Re-sending the stored value is allowed.
+    Immutable,
+    /// `@computed(expr: "price - cost")` -- the field has no stored value;
+    /// the expression is evaluated over the entity's other fields on every
+    /// read. Writes to it are rejected and forms leave it out.
+    Computed { expr: ComputedExpr },
 }
 
 impl FieldAnnotation {
@@ -536,6 +596,10 @@ impl FieldAnnotation {
             Self::EnumColors { .. } => "enum_colors",
             Self::List { .. } => "list",
             Self::Hidden => "hidden",
+            Self::Deprecated { .. } => "deprecated",
+            Self::EmptyAsNull => "empty_as_null",
+            Self::Immutable => "immutable",
+            Self::Computed { .. } => "computed",
         }
     }
 }
@@ -554,7 +618,20 @@ impl fmt::Display for FieldAnnotation {
             Self::Owner => write!(f, "@owner"),
             Self::Widget { widget_type } => write!(f, "@widget(\"{widget_type}\")"),
             Self::KanbanColumn => write!(f, "@kanban_column"),
-            Self::Format { format_type } => write!(f, "@format(\"{format_type}\")"),
+            Self::Format {
+                format_type,
+                locale,
+                currency,
+            } => {
+                write!(f, "@format(\"{format_type}\"")?;
+                if let Some(currency) = currency {
+                    write!(f, ", currency: \"{currency}\"")?;
+                }
+                if let Some(locale) = locale {
+                    write!(f, ", locale: \"{locale}\"")?;
+                }
+                write!(f, ")")
+            }
             Self::EnumColors { colors } => {
                 let parts: Vec<String> = colors
                     .iter()
@@ -564,6 +641,13 @@ impl fmt::Display for FieldAnnotation {
             }
             Self::List { hint } => write!(f, "@list({hint})"),
             Self::Hidden => write!(f, "@hidden"),
+            Self::Deprecated { reason: None } => write!(f, "@deprecated"),
+            Self::Deprecated {
+                reason: Some(reason),
+            } => write!(f, "@deprecated(\"{reason}\")"),
+            Self::EmptyAsNull => write!(f, "@empty_as_null"),
+            Self::Immutable => write!(f, "@immutable"),
+            Self::Computed { expr } => write!(f, "@computed(expr: \"{expr}\")"),
         }
     }
 }
@@ -730,6 +814,57 @@ mod tests {
         assert_eq!(a.to_string(), "@widget(\"status_badge\")");
     }
 
+    #[test]
+    fn display_deprecated() {
+        let bare = FieldAnnotation::Deprecated { reason: None };
+        assert_eq!(bare.to_string(), "@deprecated");
+        assert_eq!(bare.kind(), "deprecated");
+
+        let with_reason = FieldAnnotation::Deprecated {
+            reason: Some("use email_address".into()),
+        };
+        assert_eq!(
+            with_reason.to_string(),
+            "@deprecated(\"use email_address\")"
+        );
+        let json = serde_json::to_string(&with_reason).unwrap();
+        let back: FieldAnnotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(with_reason, back);
+    }
+
+    #[test]
+    fn display_empty_as_null() {
+        let a = FieldAnnotation::EmptyAsNull;
+        assert_eq!(a.to_string(), "@empty_as_null");
+        assert_eq!(a.kind(), "empty_as_null");
+        let json = serde_json::to_string(&a).unwrap();
+        let back: FieldAnnotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, back);
+    }
+
+    #[test]
+    fn display_immutable() {
+        let a = FieldAnnotation::Immutable;
+        assert_eq!(a.to_string(), "@immutable");
+        assert_eq!(a.kind(), "immutable");
+        let json = serde_json::to_string(&a).unwrap();
+        let back: FieldAnnotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, back);
+    }
+
+    #[test]
+    fn display_computed() {
+        let a = FieldAnnotation::Computed {
+            expr: ComputedExpr::new("price - cost").unwrap(),
+        };
+        assert_eq!(a.to_string(), "@computed(expr: \"price - cost\")");
+        assert_eq!(a.kind(), "computed");
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, r#"{"annotation":"Computed","expr":"price - cost"}"#);
+        let back: FieldAnnotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, back);
+    }
+
     #[test]
     fn display_kanban_column() {
         let a = FieldAnnotation::KanbanColumn;
@@ -845,15 +980,53 @@ mod tests {
     fn display_format() {
         let a = FieldAnnotation::Format {
             format_type: FormatType::Currency,
+            locale: None,
+            currency: None,
         };
         assert_eq!(a.to_string(), "@format(\"currency\")");
     }
 
+    #[test]
+    fn display_format_with_locale_options() {
+        let a = FieldAnnotation::Format {
+            format_type: FormatType::Currency,
+            locale: Some("de-DE".into()),
+            currency: Some("EUR".into()),
+        };
+        assert_eq!(
+            a.to_string(),
+            "@format(\"currency\", currency: \"EUR\", locale: \"de-DE\")"
+        );
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(
+            json,
+            r#"{"annotation":"Format","format_type":"currency","locale":"de-DE","currency":"EUR"}"#
+        );
+        let back: FieldAnnotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, back);
+    }
+
+    #[test]
+    fn locale_and_currency_shapes() {
+        for ok in ["en", "de-DE", "fil-PH", "es-419"] {
+            assert!(is_valid_locale_tag(ok), "{ok}");
+        }
+        for bad in ["", "e", "EN", "de_DE", "de-de", "de-DEU", "de-", "english"] {
+            assert!(!is_valid_locale_tag(bad), "{bad}");
+        }
+        assert!(is_valid_currency_code("EUR"));
+        assert!(!is_valid_currency_code("eur"));
+        assert!(!is_valid_currency_code("€"));
+        assert!(!is_valid_currency_code("EURO"));
+    }
+
     #[test]
     fn kind_format() {
         assert_eq!(
             FieldAnnotation::Format {
                 format_type: FormatType::Percent,
+                locale: None,
+                currency: None,
             }
             .kind(),
             "format"
@@ -864,6 +1037,8 @@ mod tests {
     fn serde_roundtrip_format() {
         let a = FieldAnnotation::Format {
             format_type: FormatType::Currency,
+            locale: None,
+            currency: None,
         };
         let json = serde_json::to_string(&a).unwrap();
         let back: FieldAnnotation = serde_json::from_str(&json).unwrap();
@@ -878,6 +1053,8 @@ mod tests {
             back,
             FieldAnnotation::Format {
                 format_type: FormatType::Percent,
+                locale: None,
+                currency: None,
             }
         );
     }
@@ -2,6 +2,8 @@ use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use super::computed_expr::ComputedExpr;
+use super::default_value::DefaultValue;
 use super::field_annotation::{EnumColor, FieldAnnotation, FormatType, ListHint, WidgetType};
 use super::field_modifier::FieldModifier;
 use super::field_name::FieldName;
@@ -23,6 +25,10 @@ pub struct FieldDefinition {
     /// them are rejected by the API layer.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub derived_from: Option<FieldName>,
+    /// Text of the `///` doc comment written above the field, lines joined
+    /// with `\n` and without the `///` markers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
 }
 
 impl FieldDefinition {
@@ -34,6 +40,7 @@ impl FieldDefinition {
             modifiers: Vec::new(),
             annotations: Vec::new(),
             derived_from: None,
+            doc: None,
         }
     }
 
@@ -49,6 +56,7 @@ impl FieldDefinition {
             modifiers,
             annotations: Vec::new(),
             derived_from: None,
+            doc: None,
         }
     }
 
@@ -65,6 +73,7 @@ impl FieldDefinition {
             modifiers,
             annotations,
             derived_from: None,
+            doc: None,
         }
     }
 
@@ -74,6 +83,12 @@ impl FieldDefinition {
         self.derived_from.is_some()
     }
 
+    /// Returns true if this field is backed by a physical column: neither a
+    /// derived inverse collection nor a `@computed` field.
+    pub fn is_stored(&self) -> bool {
+        !self.is_derived() && !self.is_computed()
+    }
+
     /// Returns true if this field has the `Required` modifier.
     pub fn is_required(&self) -> bool {
         self.modifiers
@@ -88,6 +103,21 @@ impl FieldDefinition {
             .any(|m| matches!(m, FieldModifier::Indexed))
     }
 
+    /// Returns true if this field has the `Unique` modifier.
+    pub fn is_unique(&self) -> bool {
+        self.modifiers
+            .iter()
+            .any(|m| matches!(m, FieldModifier::Unique))
+    }
+
+    /// Returns the value of the `Default` modifier, if present.
+    pub fn default_value(&self) -> Option<&DefaultValue> {
+        self.modifiers.iter().find_map(|m| match m {
+            FieldModifier::Default { value } => Some(value),
+            _ => None,
+        })
+    }
+
     /// Returns true if this field has the `Owner` annotation.
     pub fn has_owner(&self) -> bool {
         self.annotations
@@ -107,7 +137,25 @@ impl FieldDefinition {
     /// Returns the typed format hint if this field has a `@format` annotation.
     pub fn format_type_hint(&self) -> Option<FormatType> {
         self.annotations.iter().find_map(|a| match a {
-            FieldAnnotation::Format { format_type } => Some(*format_type),
+            FieldAnnotation::Format { format_type, .. } => Some(*format_type),
+            _ => None,
+        })
+    }
+
+    /// Returns the `locale: "..."` option of the `@format` annotation, if
+    /// one was given.
+    pub fn format_locale(&self) -> Option<&str> {
+        self.annotations.iter().find_map(|a| match a {
+            FieldAnnotation::Format { locale, .. } => locale.as_deref(),
+            _ => None,
+        })
+    }
+
+    /// Returns the `currency: "..."` option of the `@format` annotation, if
+    /// one was given.
+    pub fn format_currency(&self) -> Option<&str> {
+        self.annotations.iter().find_map(|a| match a {
+            FieldAnnotation::Format { currency, .. } => currency.as_deref(),
             _ => None,
         })
     }
@@ -131,6 +179,46 @@ impl FieldDefinition {
             .any(|a| matches!(a, FieldAnnotation::Hidden))
     }
 
+    /// Returns `true` when the field carries the `@empty_as_null` annotation.
+    ///
+    /// Use [`SchemaDefinition::empty_as_null`](crate::types::SchemaDefinition::empty_as_null)
+    /// to also honor the schema-level form.
+    pub fn is_empty_as_null(&self) -> bool {
+        self.annotations
+            .iter()
+            .any(|a| matches!(a, FieldAnnotation::EmptyAsNull))
+    }
+
+    /// Returns `true` when the field carries the `@immutable` annotation.
+    pub fn is_immutable(&self) -> bool {
+        self.annotations
+            .iter()
+            .any(|a| matches!(a, FieldAnnotation::Immutable))
+    }
+
+    /// Returns the `@computed` expression, if the field carries one.
+    pub fn computed_expr(&self) -> Option<&ComputedExpr> {
+        self.annotations.iter().find_map(|a| match a {
+            FieldAnnotation::Computed { expr } => Some(expr),
+            _ => None,
+        })
+    }
+
+    /// Returns `true` when the field carries the `@computed` annotation.
+    pub fn is_computed(&self) -> bool {
+        self.computed_expr().is_some()
+    }
+
+    /// Returns the `@deprecated` annotation's state: `None` when the field is
+    /// not deprecated, `Some(reason)` when it is (the reason itself is
+    /// optional).
+    pub fn deprecation(&self) -> Option<Option<&str>> {
+        self.annotations.iter().find_map(|a| match a {
+            FieldAnnotation::Deprecated { reason } => Some(reason.as_deref()),
+            _ => None,
+        })
+    }
+
     /// Returns the widget hint string if this field has a `@widget` annotation.
     ///
     /// Prefer [`FieldDefinition::widget_type_hint`] for new code that needs
@@ -188,7 +276,6 @@ impl std::fmt::Display for FieldDefinition {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::default_value::DefaultValue;
     use crate::types::integer_constraints::IntegerConstraints;
     use crate::types::text_constraints::TextConstraints;
 
@@ -201,6 +288,7 @@ mod tests {
         assert_eq!(fd.name.as_str(), "email");
         assert!(!fd.is_required());
         assert!(!fd.is_indexed());
+        assert!(!fd.is_unique());
         assert!(fd.modifiers.is_empty());
     }
 
@@ -239,6 +327,24 @@ mod tests {
         assert_eq!(fd, back);
     }
 
+    #[test]
+    fn default_value_some_and_none() {
+        let fd = FieldDefinition::with_modifiers(
+            FieldName::new("active").unwrap(),
+            FieldType::Boolean,
+            vec![
+                FieldModifier::Required,
+                FieldModifier::Default {
+                    value: DefaultValue::Boolean(true),
+                },
+            ],
+        );
+        assert_eq!(fd.default_value(), Some(&DefaultValue::Boolean(true)));
+
+        let plain = FieldDefinition::new(FieldName::new("x").unwrap(), FieldType::Boolean);
+        assert_eq!(plain.default_value(), None);
+    }
+
     #[test]
     fn serde_skips_empty_modifiers() {
         let fd = FieldDefinition::new(FieldName::new("x").unwrap(), FieldType::Boolean);
@@ -337,10 +443,14 @@ mod tests {
             vec![],
             vec![FieldAnnotation::Format {
                 format_type: FormatType::Currency,
+                locale: Some("de-DE".into()),
+                currency: None,
             }],
         );
         assert_eq!(fd.format_hint(), Some("currency"));
         assert_eq!(fd.format_type_hint(), Some(FormatType::Currency));
+        assert_eq!(fd.format_locale(), Some("de-DE"));
+        assert_eq!(fd.format_currency(), None);
     }
 
     #[test]
@@ -359,6 +469,17 @@ mod tests {
         assert!(!json.contains("annotations"));
     }
 
+    #[test]
+    fn serde_keeps_doc_and_skips_it_when_absent() {
+        let mut fd = FieldDefinition::new(FieldName::new("x").unwrap(), FieldType::Boolean);
+        assert!(!serde_json::to_string(&fd).unwrap().contains("doc"));
+
+        fd.doc = Some("first\nsecond".to_string());
+        let json = serde_json::to_string(&fd).unwrap();
+        let back: FieldDefinition = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.doc.as_deref(), Some("first\nsecond"));
+    }
+
     #[test]
     fn widget_hint_some() {
         let fd = FieldDefinition::with_annotations(
@@ -1,11 +1,13 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+use super::array_constraints::ArrayConstraints;
 use super::cardinality::Cardinality;
 use super::enum_variants::EnumVariants;
 use super::field_definition::FieldDefinition;
 use super::file_constraints::FileConstraints;
 use super::float_constraints::FloatConstraints;
 use super::integer_constraints::IntegerConstraints;
+use super::rich_text_constraints::RichTextConstraints;
 use super::schema_name::SchemaName;
 use super::text_constraints::TextConstraints;
 
@@ -15,38 +17,109 @@ use super::text_constraints::TextConstraints;
 #[non_exhaustive]
 pub enum FieldType {
     Text(TextConstraints),
-    RichText,
+    /// Plaintext-length and sanitization settings. A stored `RichText`
+    /// without `data` loads as unconstrained.
+    RichText(RichTextConstraints),
     Integer(IntegerConstraints),
     Float(FloatConstraints),
+    /// An exact fixed-point number stored with `scale` fractional digits,
+    /// for amounts such as money that must not drift like a float.
+    Decimal {
+        scale: u8,
+    },
     Boolean,
     DateTime,
+    /// An ISO-8601 duration such as `P3DT4H`.
+    Duration,
+    /// An RFC 4122 UUID, such as an id issued by an external system.
+    Uuid,
     Enum(EnumVariants),
     Json,
     Relation {
         target: SchemaName,
         cardinality: Cardinality,
     },
-    Array(Box<FieldType>),
+    /// Element type plus element-count bounds. Unconstrained arrays keep
+    /// the original wire shape (the bare element type as `data`).
+    #[serde(
+        serialize_with = "serialize_array",
+        deserialize_with = "deserialize_array"
+    )]
+    Array(Box<FieldType>, ArrayConstraints),
     Composite(Vec<FieldDefinition>),
     File(FileConstraints),
 }
 
+impl FieldType {
+    /// Creates an unconstrained array of `element`.
+    pub fn array(element: FieldType) -> Self {
+        Self::Array(Box::new(element), ArrayConstraints::unconstrained())
+    }
+
+    /// Creates unconstrained rich text.
+    pub fn rich_text() -> Self {
+        Self::RichText(RichTextConstraints::unconstrained())
+    }
+}
+
+/// Wire shape of a constrained array's `data`.
+#[derive(Serialize, Deserialize)]
+struct ConstrainedArray<E> {
+    element: E,
+    constraints: ArrayConstraints,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ArrayRepr {
+    Constrained(ConstrainedArray<Box<FieldType>>),
+    Bare(Box<FieldType>),
+}
+
+fn serialize_array<S: Serializer, E: Serialize>(
+    element: &E,
+    constraints: &ArrayConstraints,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if constraints.is_unconstrained() {
+        element.serialize(serializer)
+    } else {
+        ConstrainedArray {
+            element,
+            constraints: constraints.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+fn deserialize_array<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<(Box<FieldType>, ArrayConstraints), D::Error> {
+    Ok(match ArrayRepr::deserialize(deserializer)? {
+        ArrayRepr::Constrained(c) => (c.element, c.constraints),
+        ArrayRepr::Bare(element) => (element, ArrayConstraints::unconstrained()),
+    })
+}
+
 impl std::fmt::Display for FieldType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Text(_) => write!(f, "Text"),
-            Self::RichText => write!(f, "RichText"),
+            Self::RichText(_) => write!(f, "RichText"),
             Self::Integer(_) => write!(f, "Integer"),
             Self::Float(_) => write!(f, "Float"),
+            Self::Decimal { .. } => write!(f, "Decimal"),
             Self::Boolean => write!(f, "Boolean"),
             Self::DateTime => write!(f, "DateTime"),
+            Self::Duration => write!(f, "Duration"),
+            Self::Uuid => write!(f, "Uuid"),
             Self::Enum(v) => write!(f, "Enum{v}"),
             Self::Json => write!(f, "Json"),
             Self::Relation {
                 target,
                 cardinality,
             } => write!(f, "Relation({target}, {cardinality})"),
-            Self::Array(inner) => write!(f, "Array<{inner}>"),
+            Self::Array(inner, _) => write!(f, "Array<{inner}>"),
             Self::Composite(fields) => write!(f, "Composite({} fields)", fields.len()),
             Self::File(_) => write!(f, "File"),
         }
@@ -61,7 +134,9 @@ mod tests {
     fn display_simple_types() {
         assert_eq!(FieldType::Boolean.to_string(), "Boolean");
         assert_eq!(FieldType::DateTime.to_string(), "DateTime");
-        assert_eq!(FieldType::RichText.to_string(), "RichText");
+        assert_eq!(FieldType::Duration.to_string(), "Duration");
+        assert_eq!(FieldType::Uuid.to_string(), "Uuid");
+        assert_eq!(FieldType::rich_text().to_string(), "RichText");
         assert_eq!(FieldType::Json.to_string(), "Json");
     }
 
@@ -71,6 +146,23 @@ mod tests {
         assert_eq!(t.to_string(), "Text");
     }
 
+    #[test]
+    fn display_decimal() {
+        assert_eq!(FieldType::Decimal { scale: 2 }.to_string(), "Decimal");
+    }
+
+    #[test]
+    fn serde_roundtrip_decimal() {
+        let ft = FieldType::Decimal { scale: 2 };
+        let json = serde_json::to_value(&ft).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "Decimal", "data": {"scale": 2}})
+        );
+        let back: FieldType = serde_json::from_value(json).unwrap();
+        assert_eq!(ft, back);
+    }
+
     #[test]
     fn display_relation() {
         let t = FieldType::Relation {
@@ -82,7 +174,7 @@ mod tests {
 
     #[test]
     fn display_array() {
-        let t = FieldType::Array(Box::new(FieldType::Boolean));
+        let t = FieldType::array(FieldType::Boolean);
         assert_eq!(t.to_string(), "Array<Boolean>");
     }
 
@@ -91,7 +183,9 @@ mod tests {
         for ft in [
             FieldType::Boolean,
             FieldType::DateTime,
-            FieldType::RichText,
+            FieldType::Duration,
+            FieldType::Uuid,
+            FieldType::rich_text(),
             FieldType::Json,
         ] {
             let json = serde_json::to_string(&ft).unwrap();
@@ -108,6 +202,27 @@ mod tests {
         assert_eq!(ft, back);
     }
 
+    #[test]
+    fn serde_roundtrip_constrained_rich_text() {
+        let ft = FieldType::RichText(RichTextConstraints {
+            max_plaintext_length: Some(2000),
+            sanitize: true,
+            allowed_tags: Some(vec!["p".into(), "strong".into()]),
+        });
+        let json = serde_json::to_string(&ft).unwrap();
+        let back: FieldType = serde_json::from_str(&json).unwrap();
+        assert_eq!(ft, back);
+    }
+
+    #[test]
+    fn serde_rich_text_without_data_is_unconstrained() {
+        let back: FieldType =
+            serde_json::from_value(serde_json::json!({"type": "RichText"})).unwrap();
+        assert_eq!(back, FieldType::rich_text());
+        let back: FieldType = serde_json::from_str(r#"{"type":"RichText","data":null}"#).unwrap();
+        assert_eq!(back, FieldType::rich_text());
+    }
+
     #[test]
     fn serde_roundtrip_relation() {
         let ft = FieldType::Relation {
@@ -121,14 +236,35 @@ mod tests {
 
     #[test]
     fn serde_roundtrip_array() {
-        let ft = FieldType::Array(Box::new(FieldType::Integer(
-            IntegerConstraints::unconstrained(),
-        )));
+        let ft = FieldType::array(FieldType::Integer(IntegerConstraints::unconstrained()));
         let json = serde_json::to_string(&ft).unwrap();
         let back: FieldType = serde_json::from_str(&json).unwrap();
         assert_eq!(ft, back);
     }
 
+    #[test]
+    fn serde_unconstrained_array_keeps_bare_element_shape() {
+        let ft = FieldType::array(FieldType::Boolean);
+        let json = serde_json::to_value(&ft).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "Array", "data": {"type": "Boolean"}})
+        );
+    }
+
+    #[test]
+    fn serde_roundtrip_constrained_array() {
+        let ft = FieldType::Array(
+            Box::new(FieldType::Text(TextConstraints::unconstrained())),
+            ArrayConstraints::with_bounds(Some(1), Some(10)).unwrap(),
+        );
+        let json = serde_json::to_value(&ft).unwrap();
+        assert_eq!(json["data"]["constraints"]["min_items"], 1);
+        assert_eq!(json["data"]["constraints"]["max_items"], 10);
+        let back: FieldType = serde_json::from_value(json).unwrap();
+        assert_eq!(ft, back);
+    }
+
     #[test]
     fn serde_roundtrip_enum() {
         let ft = FieldType::Enum(EnumVariants::new(vec!["A".into(), "B".into()]).unwrap());
@@ -1,24 +1,98 @@
 use serde::{Deserialize, Serialize};
 
-/// Optional constraints for `FieldType::Float`.
+use crate::error::SchemaError;
+
+/// Optional constraints for `FieldType::Float`. Bounds are stored as
+/// strings, like float defaults, to preserve `Eq`/`Hash` and round-trip
+/// fidelity.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub struct FloatConstraints {
     /// Decimal precision (number of decimal places), if any.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub precision: Option<u32>,
+    /// Inclusive lower bound (e.g. `"0.5"`), if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<String>,
+    /// Inclusive upper bound, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<String>,
 }
 
 impl FloatConstraints {
     /// Creates unconstrained float.
     pub fn unconstrained() -> Self {
-        Self { precision: None }
+        Self {
+            precision: None,
+            min: None,
+            max: None,
+        }
     }
 
     /// Creates float with a precision constraint.
     pub fn with_precision(precision: u32) -> Self {
         Self {
             precision: Some(precision),
+            ..Self::unconstrained()
+        }
+    }
+
+    /// Creates float with optional bounds, validating that each parses as
+    /// a finite f64 and that min <= max.
+    pub fn with_bounds(
+        min: Option<impl Into<String>>,
+        max: Option<impl Into<String>>,
+    ) -> Result<Self, SchemaError> {
+        let c = Self {
+            min: min.map(Into::into),
+            max: max.map(Into::into),
+            ..Self::unconstrained()
+        };
+        c.validate()?;
+        Ok(c)
+    }
+
+    /// Returns the lower bound as an f64.
+    pub fn min_value(&self) -> Option<f64> {
+        self.min.as_deref().and_then(|s| s.parse().ok())
+    }
+
+    /// Returns the upper bound as an f64.
+    pub fn max_value(&self) -> Option<f64> {
+        self.max.as_deref().and_then(|s| s.parse().ok())
+    }
+
+    /// Validates that both bounds are finite numbers and min <= max.
+    pub fn validate(&self) -> Result<(), SchemaError> {
+        for bound in [&self.min, &self.max].into_iter().flatten() {
+            if !bound.parse::<f64>().is_ok_and(f64::is_finite) {
+                return Err(SchemaError::InvalidFloatString(bound.clone()));
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_value(), self.max_value()) {
+            if min > max {
+                return Err(SchemaError::InvalidFloatRange {
+                    min: self.min.clone().unwrap_or_default(),
+                    max: self.max.clone().unwrap_or_default(),
+                });
+            }
         }
+        Ok(())
+    }
+
+    /// Checks a value against the bounds, describing the first violated
+    /// bound.
+    pub fn check(&self, value: f64) -> Result<(), String> {
+        if let (Some(min), Some(bound)) = (self.min_value(), &self.min) {
+            if value < min {
+                return Err(format!("expected at least {bound}, got {value}"));
+            }
+        }
+        if let (Some(max), Some(bound)) = (self.max_value(), &self.max) {
+            if value > max {
+                return Err(format!("expected at most {bound}, got {value}"));
+            }
+        }
+        Ok(())
     }
 }
 
@@ -30,6 +104,8 @@ mod tests {
     fn unconstrained() {
         let c = FloatConstraints::unconstrained();
         assert_eq!(c.precision, None);
+        assert_eq!(c.min, None);
+        assert_eq!(c.max, None);
     }
 
     #[test]
@@ -38,6 +114,35 @@ mod tests {
         assert_eq!(c.precision, Some(2));
     }
 
+    #[test]
+    fn with_bounds_validates() {
+        let c = FloatConstraints::with_bounds(Some("0.5"), Some("99.5")).unwrap();
+        assert_eq!(c.min_value(), Some(0.5));
+        assert_eq!(c.max_value(), Some(99.5));
+        assert_eq!(
+            FloatConstraints::with_bounds(Some("2.5"), Some("1")),
+            Err(SchemaError::InvalidFloatRange {
+                min: "2.5".into(),
+                max: "1".into(),
+            })
+        );
+        assert!(FloatConstraints::with_bounds(Some("abc"), None::<&str>).is_err());
+        assert!(FloatConstraints::with_bounds(None::<&str>, Some("NaN")).is_err());
+    }
+
+    #[test]
+    fn check_enforces_bounds() {
+        let c = FloatConstraints::with_bounds(Some("0"), Some("1.5")).unwrap();
+        assert!(c.check(0.0).is_ok());
+        assert!(c.check(1.5).is_ok());
+        assert_eq!(
+            c.check(-0.25).unwrap_err(),
+            "expected at least 0, got -0.25"
+        );
+        assert_eq!(c.check(1.75).unwrap_err(), "expected at most 1.5, got 1.75");
+        assert!(FloatConstraints::unconstrained().check(f64::MAX).is_ok());
+    }
+
     #[test]
     fn serde_roundtrip() {
         let c = FloatConstraints::with_precision(4);
@@ -45,4 +150,12 @@ mod tests {
         let back: FloatConstraints = serde_json::from_str(&json).unwrap();
         assert_eq!(c, back);
     }
+
+    #[test]
+    fn serde_without_bounds_keeps_wire_shape() {
+        let json = serde_json::to_string(&FloatConstraints::with_precision(2)).unwrap();
+        assert_eq!(json, r#"{"precision":2}"#);
+        let c: FloatConstraints = serde_json::from_str(r#"{"min":"0.5"}"#).unwrap();
+        assert_eq!(c.min_value(), Some(0.5));
+    }
 }
@@ -56,6 +56,22 @@ impl IntegerConstraints {
         }
         Ok(())
     }
+
+    /// Checks a value against the bounds, describing the first violated
+    /// bound.
+    pub fn check(&self, value: i64) -> Result<(), String> {
+        if let Some(min) = self.min {
+            if value < min {
+                return Err(format!("expected at least {min}, got {value}"));
+            }
+        }
+        if let Some(max) = self.max {
+            if value > max {
+                return Err(format!("expected at most {max}, got {value}"));
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -88,6 +104,16 @@ mod tests {
         assert!(IntegerConstraints::with_range(10, 5).is_err());
     }
 
+    #[test]
+    fn check_enforces_bounds() {
+        let c = IntegerConstraints::with_range(0, 100).unwrap();
+        assert!(c.check(0).is_ok());
+        assert!(c.check(100).is_ok());
+        assert_eq!(c.check(-1).unwrap_err(), "expected at least 0, got -1");
+        assert_eq!(c.check(101).unwrap_err(), "expected at most 100, got 101");
+        assert!(IntegerConstraints::unconstrained().check(i64::MIN).is_ok());
+    }
+
     #[test]
     fn serde_roundtrip() {
         let c = IntegerConstraints::with_range(0, 100).unwrap();
@@ -0,0 +1,357 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::SchemaError;
+
+/// A validated ISO-8601 duration such as `P3DT4H` or `P1Y2M`.
+///
+/// Components are whole numbers; fractional values (`PT1.5S`) are rejected.
+/// The components are kept as written rather than normalized, so `PT90M`
+/// stays ninety minutes instead of becoming `PT1H30M`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct IsoDuration {
+    pub years: u32,
+    pub months: u32,
+    pub weeks: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+}
+
+/// Designators in the order ISO-8601 requires them. `M` appears in both
+/// the date part (months) and the time part (minutes).
+const DATE_DESIGNATORS: [char; 4] = ['Y', 'M', 'W', 'D'];
+const TIME_DESIGNATORS: [char; 3] = ['H', 'M', 'S'];
+
+/// Units of the shorthand form (`1d 4h`) in the order they must appear.
+const SHORTHAND_UNITS: [char; 5] = ['w', 'd', 'h', 'm', 's'];
+
+impl IsoDuration {
+    /// Parses an ISO-8601 duration string.
+    pub fn parse(s: &str) -> Result<Self, SchemaError> {
+        let invalid = || SchemaError::InvalidDuration(s.to_string());
+        let rest = s.strip_prefix('P').ok_or_else(invalid)?;
+        let (date, time) = match rest.split_once('T') {
+            Some((_, "")) => return Err(invalid()),
+            Some((date, time)) => (date, Some(time)),
+            None => (rest, None),
+        };
+
+        let date = parse_components(date, &DATE_DESIGNATORS).ok_or_else(invalid)?;
+        let time = match time {
+            Some(time) => parse_components(time, &TIME_DESIGNATORS).ok_or_else(invalid)?,
+            None => [None; 3],
+        };
+        if date.iter().chain(time.iter()).all(Option::is_none) {
+            return Err(invalid());
+        }
+        let [years, months, weeks, days] = date.map(Option::unwrap_or_default);
+        let [hours, minutes, seconds] = time.map(Option::unwrap_or_default);
+        Ok(Self {
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+        })
+    }
+
+    /// Parses user input: an ISO-8601 duration, or the shorthand people type
+    /// into forms such as `2h 30m`, `1d 4h`, or `90m`.
+    ///
+    /// Shorthand units are `w`, `d`, `h`, `m` (minutes), and `s`, each at
+    /// most once and largest first; spaces between parts are optional.
+    /// Components are kept as written, so `90m` becomes `PT90M`.
+    pub fn parse_input(s: &str) -> Result<Self, SchemaError> {
+        let invalid = || SchemaError::InvalidDuration(s.to_string());
+        if s.starts_with('P') {
+            return Self::parse(s);
+        }
+        let compact: String = s.split_whitespace().collect();
+        if compact.is_empty() || s.trim() != s {
+            return Err(invalid());
+        }
+        let [weeks, days, hours, minutes, seconds] =
+            parse_components(&compact.to_ascii_lowercase(), &SHORTHAND_UNITS)
+                .filter(|values| values.iter().any(Option::is_some))
+                .ok_or_else(invalid)?
+                .map(Option::unwrap_or_default);
+        Ok(Self {
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            ..Self::default()
+        })
+    }
+
+    /// Builds a duration from a number of seconds, split into days, hours,
+    /// minutes, and seconds. Returns `None` past `u32::MAX` days.
+    pub fn from_seconds(total: u64) -> Option<Self> {
+        Some(Self {
+            days: u32::try_from(total / 86_400).ok()?,
+            hours: (total % 86_400 / 3_600) as u32,
+            minutes: (total % 3_600 / 60) as u32,
+            seconds: (total % 60) as u32,
+            ..Self::default()
+        })
+    }
+
+    /// Renders the duration in the shorthand form, e.g. `2h 30m`. Months
+    /// are `mo` so they cannot be read as minutes. A zero duration renders
+    /// as `0s`.
+    pub fn compact(&self) -> String {
+        let parts: Vec<String> = [
+            (self.years, "y"),
+            (self.months, "mo"),
+            (self.weeks, "w"),
+            (self.days, "d"),
+            (self.hours, "h"),
+            (self.minutes, "m"),
+            (self.seconds, "s"),
+        ]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, unit)| format!("{n}{unit}"))
+        .collect();
+        if parts.is_empty() {
+            "0s".to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+
+    /// Renders the duration for people, e.g. `3 days 4 hours`. A zero
+    /// duration renders as `0 seconds`.
+    pub fn humanize(&self) -> String {
+        let parts: Vec<String> = [
+            (self.years, "year"),
+            (self.months, "month"),
+            (self.weeks, "week"),
+            (self.days, "day"),
+            (self.hours, "hour"),
+            (self.minutes, "minute"),
+            (self.seconds, "second"),
+        ]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, unit)| {
+            if n == 1 {
+                format!("1 {unit}")
+            } else {
+                format!("{n} {unit}s")
+            }
+        })
+        .collect();
+        if parts.is_empty() {
+            "0 seconds".to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+
+    /// Returns true when every component is zero.
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Parses `<digits><designator>` pairs from `part`, requiring the
+/// designators to appear at most once and in `order`. Returns the value for
+/// each designator in `order`, or `None` if `part` is malformed.
+fn parse_components<const N: usize>(part: &str, order: &[char; N]) -> Option<[Option<u32>; N]> {
+    let mut values = [None; N];
+    let mut next = 0;
+    let mut digits = String::new();
+    for c in part.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        if digits.is_empty() {
+            return None;
+        }
+        let pos = order[next..].iter().position(|&d| d == c)? + next;
+        values[pos] = Some(digits.parse().ok()?);
+        digits.clear();
+        next = pos + 1;
+    }
+    if !digits.is_empty() {
+        return None;
+    }
+    Some(values)
+}
+
+impl fmt::Display for IsoDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "PT0S");
+        }
+        write!(f, "P")?;
+        for (n, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if n > 0 {
+                write!(f, "{n}{designator}")?;
+            }
+        }
+        if self.hours > 0 || self.minutes > 0 || self.seconds > 0 {
+            write!(f, "T")?;
+            for (n, designator) in [(self.hours, 'H'), (self.minutes, 'M'), (self.seconds, 'S')] {
+                if n > 0 {
+                    write!(f, "{n}{designator}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for IsoDuration {
+    type Err = SchemaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<IsoDuration> for String {
+    fn from(d: IsoDuration) -> String {
+        d.to_string()
+    }
+}
+
+impl TryFrom<String> for IsoDuration {
+    type Error = SchemaError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid() {
+        let d = IsoDuration::parse("P3DT4H").unwrap();
+        assert_eq!(d.days, 3);
+        assert_eq!(d.hours, 4);
+        assert_eq!(d.to_string(), "P3DT4H");
+
+        let d: IsoDuration = "P1Y2M3W4DT5H6M7S".parse().unwrap();
+        assert_eq!(
+            (d.years, d.months, d.weeks, d.days, d.hours, d.minutes, d.seconds),
+            (1, 2, 3, 4, 5, 6, 7)
+        );
+        assert_eq!(d.to_string(), "P1Y2M3W4DT5H6M7S");
+        assert_eq!(IsoDuration::parse("PT90M").unwrap().minutes, 90);
+        assert!(IsoDuration::parse("PT0S").unwrap().is_zero());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in [
+            "", "P", "PT", "3D", "P3", "PD", "P3H", "PT3D", "P4D3Y", "P3D3D", "PT1.5S", "p3d",
+            "P3DT", "P-3D", " P3D",
+        ] {
+            assert_eq!(
+                IsoDuration::parse(s),
+                Err(SchemaError::InvalidDuration(s.to_string())),
+                "expected invalid: {s:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_input_accepts_shorthand_and_iso() {
+        let d = IsoDuration::parse_input("2h 30m").unwrap();
+        assert_eq!((d.hours, d.minutes), (2, 30));
+        assert_eq!(d.to_string(), "PT2H30M");
+        assert_eq!(
+            IsoDuration::parse_input("1d4h").unwrap().to_string(),
+            "P1DT4H"
+        );
+        assert_eq!(
+            IsoDuration::parse_input("90m").unwrap().to_string(),
+            "PT90M"
+        );
+        assert_eq!(
+            IsoDuration::parse_input("1W 2D").unwrap().to_string(),
+            "P1W2D"
+        );
+        assert_eq!(
+            IsoDuration::parse_input("P1Y2M").unwrap().to_string(),
+            "P1Y2M"
+        );
+    }
+
+    #[test]
+    fn parse_input_rejects_unparseable() {
+        for s in [
+            "", " ", "2h30", "30m 2h", "1h 1h", "1.5h", "3 days", "-1h", "h", " 2h", "1y",
+        ] {
+            assert_eq!(
+                IsoDuration::parse_input(s),
+                Err(SchemaError::InvalidDuration(s.to_string())),
+                "expected invalid: {s:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn from_seconds_splits_units() {
+        let d = IsoDuration::from_seconds(9_000).unwrap();
+        assert_eq!(d.to_string(), "PT2H30M");
+        assert_eq!(
+            IsoDuration::from_seconds(90_061).unwrap().compact(),
+            "1d 1h 1m 1s"
+        );
+        assert!(IsoDuration::from_seconds(0).unwrap().is_zero());
+    }
+
+    #[test]
+    fn compact() {
+        assert_eq!(IsoDuration::parse("PT2H30M").unwrap().compact(), "2h 30m");
+        assert_eq!(
+            IsoDuration::parse("P1Y2M3D").unwrap().compact(),
+            "1y 2mo 3d"
+        );
+        assert_eq!(IsoDuration::parse("PT0S").unwrap().compact(), "0s");
+    }
+
+    #[test]
+    fn humanize() {
+        assert_eq!(
+            IsoDuration::parse("P3DT4H").unwrap().humanize(),
+            "3 days 4 hours"
+        );
+        assert_eq!(
+            IsoDuration::parse("P1DT1M").unwrap().humanize(),
+            "1 day 1 minute"
+        );
+        assert_eq!(IsoDuration::parse("P2W").unwrap().humanize(), "2 weeks");
+        assert_eq!(IsoDuration::parse("PT0S").unwrap().humanize(), "0 seconds");
+    }
+
+    #[test]
+    fn serde_as_string() {
+        let d = IsoDuration::parse("P3DT4H").unwrap();
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(json, "\"P3DT4H\"");
+        let back: IsoDuration = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, d);
+        assert!(serde_json::from_str::<IsoDuration>("\"3 days\"").is_err());
+    }
+}
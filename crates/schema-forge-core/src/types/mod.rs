@@ -1,6 +1,9 @@
 mod annotation;
+mod array_constraints;
 mod cardinality;
 pub mod cedar_reserved;
+mod computed_expr;
+mod decimal;
 mod default_value;
 mod dynamic_value;
 mod entity_id;
@@ -13,24 +16,31 @@ mod field_type;
 mod file_constraints;
 mod float_constraints;
 mod integer_constraints;
+mod iso_duration;
+mod ref_label;
+mod rich_text_constraints;
 mod schema_definition;
 mod schema_id;
 mod schema_name;
 mod schema_version;
 mod text_constraints;
+mod uuid_value;
 
 pub use annotation::Annotation;
 pub use annotation::HookEvent;
 pub use annotation::TenantKind;
+pub use array_constraints::ArrayConstraints;
 pub use cardinality::Cardinality;
+pub use computed_expr::ComputedExpr;
+pub use decimal::{parse_decimal, Decimal, MAX_DECIMAL_SCALE};
 pub use default_value::DefaultValue;
 pub use dynamic_value::DynamicValue;
 pub use entity_id::EntityId;
 pub use enum_variants::EnumVariants;
 pub use field_annotation::{
-    sanitize_schema_metadata_json, EnumColor, FieldAnnotation, FormatType, ListHint,
-    UnknownEnumColor, UnknownFormatType, UnknownListHint, UnknownWidgetType, WidgetRepair,
-    WidgetType,
+    is_valid_currency_code, is_valid_locale_tag, sanitize_schema_metadata_json, EnumColor,
+    FieldAnnotation, FormatType, ListHint, UnknownEnumColor, UnknownFormatType, UnknownListHint,
+    UnknownWidgetType, WidgetRepair, WidgetType, DEFAULT_CURRENCY, DEFAULT_FORMAT_LOCALE,
 };
 pub use field_definition::FieldDefinition;
 pub use field_modifier::FieldModifier;
@@ -39,8 +49,12 @@ pub use field_type::FieldType;
 pub use file_constraints::{FileAccess, FileAttachment, FileConstraints, FileStatus, MimePattern};
 pub use float_constraints::FloatConstraints;
 pub use integer_constraints::IntegerConstraints;
-pub use schema_definition::SchemaDefinition;
+pub use iso_duration::IsoDuration;
+pub use ref_label::RefLabelTemplate;
+pub use rich_text_constraints::{plaintext_len, RichTextConstraints};
+pub use schema_definition::{SchemaDefinition, DELETED_AT_FIELD};
 pub use schema_id::SchemaId;
 pub use schema_name::SchemaName;
 pub use schema_version::SchemaVersion;
 pub use text_constraints::TextConstraints;
+pub use uuid_value::{parse_uuid, Uuid, UUID_PATTERN};
@@ -0,0 +1,210 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::SchemaError;
+
+use super::field_name::FieldName;
+
+/// A validated `@ref_label` template such as `"{name} ({code})"`.
+///
+/// Controls how an entity is rendered when it appears as a relation value
+/// on another schema. `{field}` placeholders are replaced by the referenced
+/// entity's field values, `{id}` by its entity id, and `{{` / `}}` produce
+/// literal braces.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct RefLabelTemplate(String);
+
+/// One parsed piece of a template.
+#[derive(Debug, PartialEq, Eq)]
+enum Segment<'a> {
+    Literal(String),
+    Placeholder(&'a str),
+}
+
+impl RefLabelTemplate {
+    /// Creates a new template, returning an error if a placeholder is
+    /// unbalanced, empty, or not a valid field name.
+    pub fn new(template: impl Into<String>) -> Result<Self, SchemaError> {
+        let template = template.into();
+        segments(&template)?;
+        Ok(Self(template))
+    }
+
+    /// Returns the raw template string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Field names referenced by the template, in order of first use.
+    ///
+    /// `{id}` is not included since it is not a stored field.
+    pub fn fields(&self) -> Vec<&str> {
+        let mut out: Vec<&str> = Vec::new();
+        for segment in self.parsed() {
+            if let Segment::Placeholder(name) = segment {
+                if name != "id" && !out.contains(&name) {
+                    out.push(name);
+                }
+            }
+        }
+        out
+    }
+
+    /// Renders the template for one entity.
+    ///
+    /// `lookup` returns the display string for a field; fields it cannot
+    /// resolve render as the empty string.
+    pub fn render(&self, id: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+        let mut out = String::new();
+        for segment in self.parsed() {
+            match segment {
+                Segment::Literal(text) => out.push_str(&text),
+                Segment::Placeholder("id") => out.push_str(id),
+                Segment::Placeholder(name) => {
+                    if let Some(value) = lookup(name) {
+                        out.push_str(&value);
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    fn parsed(&self) -> Vec<Segment<'_>> {
+        segments(&self.0).expect("template validated at construction")
+    }
+}
+
+fn segments(template: &str) -> Result<Vec<Segment<'_>>, SchemaError> {
+    let invalid = |reason: &str| SchemaError::InvalidRefLabel {
+        template: template.to_string(),
+        reason: reason.to_string(),
+    };
+
+    let mut out = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|(_, n)| *n) == Some('{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek().map(|(_, n)| *n) == Some('}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let start = i + 1;
+                let end = loop {
+                    match chars.next() {
+                        Some((j, '}')) => break j,
+                        Some((_, '{')) => return Err(invalid("nested '{' in placeholder")),
+                        Some(_) => {}
+                        None => return Err(invalid("unclosed '{'")),
+                    }
+                };
+                let name = template[start..end].trim();
+                if name.is_empty() {
+                    return Err(invalid("empty placeholder"));
+                }
+                if name != "id" && FieldName::new(name).is_err() {
+                    return Err(invalid(&format!("'{name}' is not a valid field name")));
+                }
+                if !literal.is_empty() {
+                    out.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                out.push(Segment::Placeholder(name));
+            }
+            '}' => return Err(invalid("unmatched '}' (use '}}' for a literal brace)")),
+            other => literal.push(other),
+        }
+    }
+    if !literal.is_empty() {
+        out.push(Segment::Literal(literal));
+    }
+    Ok(out)
+}
+
+impl fmt::Display for RefLabelTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<RefLabelTemplate> for String {
+    fn from(t: RefLabelTemplate) -> String {
+        t.0
+    }
+}
+
+impl TryFrom<String> for RefLabelTemplate {
+    type Error = SchemaError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "name" => Some("Acme Corp".into()),
+            "code" => Some("C123".into()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn renders_fields_and_id() {
+        let t = RefLabelTemplate::new("{name} ({code}) #{id}").unwrap();
+        assert_eq!(t.render("company_1", lookup), "Acme Corp (C123) #company_1");
+    }
+
+    #[test]
+    fn missing_fields_render_empty() {
+        let t = RefLabelTemplate::new("{name}/{missing}").unwrap();
+        assert_eq!(t.render("x", lookup), "Acme Corp/");
+    }
+
+    #[test]
+    fn escaped_braces_are_literal() {
+        let t = RefLabelTemplate::new("{{{name}}}").unwrap();
+        assert_eq!(t.render("x", lookup), "{Acme Corp}");
+        assert_eq!(t.fields(), vec!["name"]);
+    }
+
+    #[test]
+    fn fields_are_deduplicated_and_exclude_id() {
+        let t = RefLabelTemplate::new("{code} {id} {name} {code}").unwrap();
+        assert_eq!(t.fields(), vec!["code", "name"]);
+    }
+
+    #[test]
+    fn rejects_malformed_templates() {
+        for bad in ["{name", "name}", "{}", "{ }", "{Name}", "{a{b}}"] {
+            assert!(
+                matches!(
+                    RefLabelTemplate::new(bad),
+                    Err(SchemaError::InvalidRefLabel { .. })
+                ),
+                "expected {bad:?} to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let t = RefLabelTemplate::new("{name} ({code})").unwrap();
+        let json = serde_json::to_string(&t).unwrap();
+        assert_eq!(json, "\"{name} ({code})\"");
+        let back: RefLabelTemplate = serde_json::from_str(&json).unwrap();
+        assert_eq!(t, back);
+        assert!(serde_json::from_str::<RefLabelTemplate>("\"{name\"").is_err());
+    }
+}
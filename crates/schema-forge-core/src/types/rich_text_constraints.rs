@@ -0,0 +1,165 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Optional constraints for `FieldType::RichText`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize)]
+pub struct RichTextConstraints {
+    /// Maximum length of the text content with markup removed, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_plaintext_length: Option<u32>,
+    /// Sanitize submitted HTML on write, storing only allowlisted markup.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub sanitize: bool,
+    /// Tags kept by sanitization. `None` keeps the sanitizer's default
+    /// allowlist. Ignored unless `sanitize` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_tags: Option<Vec<String>>,
+}
+
+/// Wire shape of [`RichTextConstraints`]; every key is optional.
+#[derive(Deserialize)]
+struct RichTextConstraintsRepr {
+    max_plaintext_length: Option<u32>,
+    #[serde(default)]
+    sanitize: bool,
+    allowed_tags: Option<Vec<String>>,
+}
+
+/// Accepts `null`, and inside `FieldType` a missing `data`, as
+/// unconstrained, so rich text fields stored before constraints existed
+/// still load.
+impl<'de> Deserialize<'de> for RichTextConstraints {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(
+            Option::<RichTextConstraintsRepr>::deserialize(deserializer)?
+                .map(|r| Self {
+                    max_plaintext_length: r.max_plaintext_length,
+                    sanitize: r.sanitize,
+                    allowed_tags: r.allowed_tags,
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+
+impl RichTextConstraints {
+    /// Creates unconstrained rich text (no sanitization, no length limit).
+    pub fn unconstrained() -> Self {
+        Self::default()
+    }
+
+    /// Creates rich text with a maximum plaintext length.
+    pub fn with_max_plaintext_length(max: u32) -> Self {
+        Self {
+            max_plaintext_length: Some(max),
+            ..Self::default()
+        }
+    }
+
+    /// Returns true when no constraint is set.
+    pub fn is_unconstrained(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Checks the plaintext length of `html` against `max_plaintext_length`.
+    pub fn check_plaintext_len(&self, html: &str) -> Result<(), String> {
+        if let Some(max) = self.max_plaintext_length {
+            let len = plaintext_len(html);
+            if len > max as usize {
+                return Err(format!(
+                    "expected at most {max} characters of text, got {len}"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Counts the characters of `html` that render as text: markup inside
+/// `<...>` is skipped and a character reference such as `&amp;` counts
+/// as one character.
+pub fn plaintext_len(html: &str) -> usize {
+    let mut len = 0;
+    let mut chars = html.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '<' => {
+                for c in chars.by_ref() {
+                    if c == '>' {
+                        break;
+                    }
+                }
+            }
+            '&' => {
+                let rest = chars.as_str();
+                let reference = rest.find(';').filter(|&end| {
+                    end > 0
+                        && end <= 32
+                        && rest[..end]
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '#')
+                });
+                if let Some(end) = reference {
+                    chars = rest[end + 1..].chars();
+                }
+                len += 1;
+            }
+            _ => len += 1,
+        }
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unconstrained() {
+        let c = RichTextConstraints::unconstrained();
+        assert!(c.is_unconstrained());
+        assert!(c.check_plaintext_len(&"x".repeat(10_000)).is_ok());
+    }
+
+    #[test]
+    fn plaintext_len_skips_markup_and_folds_references() {
+        assert_eq!(plaintext_len("<p>Hello <b>world</b></p>"), 11);
+        assert_eq!(plaintext_len("Fish &amp; chips"), 12);
+        assert_eq!(plaintext_len("&#169; 2026"), 6);
+        assert_eq!(plaintext_len("a & b"), 5);
+        assert_eq!(plaintext_len(""), 0);
+    }
+
+    #[test]
+    fn check_plaintext_len_ignores_markup() {
+        let c = RichTextConstraints::with_max_plaintext_length(5);
+        assert!(c.check_plaintext_len("<p><em>Hello</em></p>").is_ok());
+        assert_eq!(
+            c.check_plaintext_len("<p>Hello!</p>"),
+            Err("expected at most 5 characters of text, got 6".to_string())
+        );
+    }
+
+    #[test]
+    fn serde_skips_unset() {
+        let json = serde_json::to_string(&RichTextConstraints::unconstrained()).unwrap();
+        assert_eq!(json, "{}");
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let c = RichTextConstraints {
+            max_plaintext_length: Some(500),
+            sanitize: true,
+            allowed_tags: Some(vec!["p".into(), "a".into()]),
+        };
+        let json = serde_json::to_string(&c).unwrap();
+        let back: RichTextConstraints = serde_json::from_str(&json).unwrap();
+        assert_eq!(c, back);
+    }
+
+    #[test]
+    fn serde_null_is_unconstrained() {
+        let c: RichTextConstraints = serde_json::from_str("null").unwrap();
+        assert!(c.is_unconstrained());
+    }
+}
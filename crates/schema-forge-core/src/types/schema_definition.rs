@@ -1,13 +1,23 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
 use crate::error::SchemaError;
 
 use super::annotation::{Annotation, HookEvent};
+use super::entity_id::EntityId;
+use super::field_annotation::EnumColor;
 use super::field_definition::FieldDefinition;
+use super::field_name::FieldName;
+use super::field_type::FieldType;
+use super::ref_label::RefLabelTemplate;
 use super::schema_id::SchemaId;
 use super::schema_name::SchemaName;
+use super::schema_version::SchemaVersion;
+
+/// The storage column that marks an entity of a `@soft_delete` schema as
+/// deleted. Unset (null) on live entities.
+pub const DELETED_AT_FIELD: &str = "_deleted_at";
 
 /// A complete schema definition: id, name, fields, and annotations.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -17,13 +27,20 @@ pub struct SchemaDefinition {
     pub fields: Vec<FieldDefinition>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub annotations: Vec<Annotation>,
+    /// Text of the `///` doc comment written above the schema, lines joined
+    /// with `\n` and without the `///` markers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
 }
 
 impl SchemaDefinition {
     /// Creates a new `SchemaDefinition`, validating:
     /// - fields is non-empty
     /// - no duplicate field names
-    /// - no duplicate annotation kinds
+    /// - no duplicate annotation kinds (`@index` may repeat)
+    /// - any `@id_prefix` is a valid TypeID prefix
+    /// - every `@index` names distinct stored fields, and no two indexes
+    ///   cover the same fields
     pub fn new(
         id: SchemaId,
         name: SchemaName,
@@ -43,16 +60,28 @@ impl SchemaDefinition {
 
         let mut ann_kinds = HashSet::with_capacity(annotations.len());
         for a in &annotations {
-            if !ann_kinds.insert(a.kind()) {
+            if !matches!(a, Annotation::Index { .. }) && !ann_kinds.insert(a.kind()) {
                 return Err(SchemaError::DuplicateAnnotation(a.kind().to_string()));
             }
+            if let Annotation::IdPrefix { prefix } = a {
+                EntityId::validate_prefix(prefix)?;
+            }
+            if let Annotation::Pagination {
+                page_size,
+                max_page_size,
+            } = a
+            {
+                validate_page_sizes(*page_size, *max_page_size)?;
+            }
         }
+        validate_indexes(&fields, &annotations)?;
 
         Ok(Self {
             id,
             name,
             fields,
             annotations,
+            doc: None,
         })
     }
 
@@ -61,6 +90,32 @@ impl SchemaDefinition {
         self.fields.iter().find(|f| f.name.as_str() == name)
     }
 
+    /// Returns the `@version` annotation's value, if declared.
+    pub fn version(&self) -> Option<SchemaVersion> {
+        self.annotations.iter().find_map(|a| match a {
+            Annotation::Version { version } => Some(*version),
+            _ => None,
+        })
+    }
+
+    /// Checks that applying this definition over `stored` moves its
+    /// `@version` forward.
+    ///
+    /// Only enforced when both sides declare `@version`; the incoming version
+    /// must then be strictly greater than the stored one.
+    pub fn check_version_advances(&self, stored: &SchemaDefinition) -> Result<(), SchemaError> {
+        match (self.version(), stored.version()) {
+            (Some(incoming), Some(current)) if incoming <= current => {
+                Err(SchemaError::VersionNotIncreased {
+                    schema: self.name.as_str().to_string(),
+                    incoming: incoming.get(),
+                    stored: current.get(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Returns the `@access` annotation if present.
     pub fn access_annotation(&self) -> Option<&Annotation> {
         self.annotations
@@ -80,6 +135,13 @@ impl SchemaDefinition {
             .any(|a| matches!(a, Annotation::System))
     }
 
+    /// Returns true if this schema has the `@soft_delete` annotation.
+    pub fn is_soft_delete(&self) -> bool {
+        self.annotations
+            .iter()
+            .any(|a| matches!(a, Annotation::SoftDelete))
+    }
+
     /// Returns the name of the field nominated by `@display("...")`, if any.
     ///
     /// This is the field clients should render in a UI to represent a row
@@ -91,6 +153,131 @@ impl SchemaDefinition {
         })
     }
 
+    /// Returns the `@ref_label` template, if any.
+    pub fn ref_label(&self) -> Option<&RefLabelTemplate> {
+        self.annotations.iter().find_map(|a| match a {
+            Annotation::RefLabel { template } => Some(template),
+            _ => None,
+        })
+    }
+
+    /// Returns the `@list_columns(...)` fields, if any, in declared order.
+    pub fn list_columns(&self) -> Option<&[FieldName]> {
+        self.annotations.iter().find_map(|a| match a {
+            Annotation::ListColumns { fields } => Some(fields.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Returns the schema-wide `@enum_colors(...)` map, if declared.
+    pub fn enum_colors(&self) -> Option<&BTreeMap<String, EnumColor>> {
+        self.annotations.iter().find_map(|a| match a {
+            Annotation::EnumColors { colors } => Some(colors),
+            _ => None,
+        })
+    }
+
+    /// Returns the `(fields, unique)` pair of every `@index` annotation, in
+    /// declaration order.
+    pub fn indexes(&self) -> Vec<(&[FieldName], bool)> {
+        self.annotations
+            .iter()
+            .filter_map(|a| match a {
+                Annotation::Index { fields, unique } => Some((fields.as_slice(), *unique)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the `@list(page_size: N)` default page size, if declared.
+    pub fn page_size(&self) -> Option<u32> {
+        self.annotations.iter().find_map(|a| match a {
+            Annotation::Pagination { page_size, .. } => *page_size,
+            _ => None,
+        })
+    }
+
+    /// Returns the `@list(max_page_size: N)` page size cap, if declared.
+    pub fn max_page_size(&self) -> Option<u32> {
+        self.annotations.iter().find_map(|a| match a {
+            Annotation::Pagination { max_page_size, .. } => *max_page_size,
+            _ => None,
+        })
+    }
+
+    /// The limit a list request runs with, given the `limit` it asked for.
+    ///
+    /// A request without a limit takes the declared `page_size`; any limit
+    /// is clamped to `max_page_size`, so a capped schema never returns an
+    /// unbounded page. `None` means the request is unlimited.
+    pub fn effective_limit(&self, requested: Option<usize>) -> Option<usize> {
+        let limit = requested.or(self.page_size().map(|n| n as usize));
+        match (limit, self.max_page_size().map(|n| n as usize)) {
+            (Some(limit), Some(max)) => Some(limit.min(max)),
+            (None, max) => max,
+            (limit, None) => limit,
+        }
+    }
+
+    /// Returns the `@id_prefix("...")` value, if declared.
+    pub fn declared_id_prefix(&self) -> Option<&str> {
+        self.annotations.iter().find_map(|a| match a {
+            Annotation::IdPrefix { prefix } => Some(prefix.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns the prefix new entity ids of this schema are minted with:
+    /// the declared `@id_prefix`, or else the schema name (which
+    /// [`EntityId::new`] lowercases and sanitizes).
+    pub fn id_prefix(&self) -> &str {
+        self.declared_id_prefix().unwrap_or(self.name.as_str())
+    }
+
+    /// Generates a fresh entity id for this schema.
+    pub fn new_entity_id(&self) -> EntityId {
+        EntityId::new(self.id_prefix())
+    }
+
+    /// Parses an entity id addressed to this schema.
+    ///
+    /// When the schema declares `@id_prefix`, ids must carry that prefix or
+    /// the schema-name prefix rows were minted with before the annotation
+    /// was added; any other prefix is rejected. Without it any well-formed
+    /// TypeID is accepted, since ids minted under earlier prefixing rules
+    /// are still in storage.
+    pub fn parse_entity_id(&self, s: &str) -> Result<EntityId, String> {
+        match self.declared_id_prefix() {
+            Some(prefix) => EntityId::parse_with_prefix(s, prefix).or_else(|err| {
+                let minted_before = EntityId::new(self.name.as_str());
+                EntityId::parse_with_prefix(s, minted_before.prefix()).map_err(|_| err)
+            }),
+            None => EntityId::parse(s),
+        }
+    }
+
+    /// Returns the `@deprecated` annotation's state: `None` when the schema
+    /// is not deprecated, `Some(reason)` when it is (the reason itself is
+    /// optional).
+    pub fn deprecation(&self) -> Option<Option<&str>> {
+        self.annotations.iter().find_map(|a| match a {
+            Annotation::Deprecated { reason } => Some(reason.as_deref()),
+            _ => None,
+        })
+    }
+
+    /// Returns `true` when an empty string submitted for `field` should be
+    /// stored as null: the field is `text`/`richtext` and either it or the
+    /// schema carries `@empty_as_null`.
+    pub fn empty_as_null(&self, field: &FieldDefinition) -> bool {
+        matches!(field.field_type, FieldType::Text(_) | FieldType::RichText(_))
+            && (field.is_empty_as_null()
+                || self
+                    .annotations
+                    .iter()
+                    .any(|a| matches!(a, Annotation::EmptyAsNull)))
+    }
+
     /// Returns the `@webhook` annotation if present.
     pub fn webhook_annotation(&self) -> Option<&Annotation> {
         self.annotations
@@ -145,6 +332,63 @@ impl SchemaDefinition {
     }
 }
 
+/// Checks the sizes of an `@list(...)` annotation: at least one is given,
+/// neither is zero, and the default fits under the cap.
+fn validate_page_sizes(
+    page_size: Option<u32>,
+    max_page_size: Option<u32>,
+) -> Result<(), SchemaError> {
+    let invalid = |reason: String| Err(SchemaError::InvalidPageSize { reason });
+    match (page_size, max_page_size) {
+        (None, None) => invalid("declare page_size, max_page_size, or both".into()),
+        (Some(0), _) => invalid("page_size must be at least 1".into()),
+        (_, Some(0)) => invalid("max_page_size must be at least 1".into()),
+        (Some(size), Some(max)) if size > max => {
+            invalid(format!("page_size {size} exceeds max_page_size {max}"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks the `@index` annotations: each names at least one field, no field
+/// twice, only fields the schema stores, and no two cover the same fields.
+fn validate_indexes(
+    fields: &[FieldDefinition],
+    annotations: &[Annotation],
+) -> Result<(), SchemaError> {
+    let invalid = |reason: String| Err(SchemaError::InvalidIndex { reason });
+    let mut seen: HashSet<&[FieldName]> = HashSet::new();
+    for a in annotations {
+        let Annotation::Index {
+            fields: indexed, ..
+        } = a
+        else {
+            continue;
+        };
+        if indexed.is_empty() {
+            return invalid("declare at least one field".into());
+        }
+        let mut names = HashSet::with_capacity(indexed.len());
+        for name in indexed {
+            if !names.insert(name.as_str()) {
+                return invalid(format!("field '{name}' is listed twice"));
+            }
+            match fields.iter().find(|f| f.name == *name) {
+                None => return invalid(format!("unknown field '{name}'")),
+                Some(f) if !f.is_stored() => {
+                    return invalid(format!("field '{name}' is not stored"));
+                }
+                Some(_) => {}
+            }
+        }
+        if !seen.insert(indexed.as_slice()) {
+            let names: Vec<&str> = indexed.iter().map(FieldName::as_str).collect();
+            return invalid(format!("more than one index covers ({})", names.join(", ")));
+        }
+    }
+    Ok(())
+}
+
 impl std::fmt::Display for SchemaDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for a in &self.annotations {
@@ -162,9 +406,7 @@ impl std::fmt::Display for SchemaDefinition {
 mod tests {
     use super::*;
     use crate::types::field_modifier::FieldModifier;
-    use crate::types::field_name::FieldName;
     use crate::types::field_type::FieldType;
-    use crate::types::schema_version::SchemaVersion;
     use crate::types::text_constraints::TextConstraints;
 
     fn make_field(name: &str) -> FieldDefinition {
@@ -231,6 +473,171 @@ mod tests {
         assert!(matches!(result, Err(SchemaError::DuplicateAnnotation(_))));
     }
 
+    fn invoice(annotations: Vec<Annotation>) -> Result<SchemaDefinition, SchemaError> {
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Invoice").unwrap(),
+            vec![make_field("number")],
+            annotations,
+        )
+    }
+
+    #[test]
+    fn id_prefix_defaults_to_schema_name() {
+        let sd = invoice(vec![]).unwrap();
+        assert_eq!(sd.declared_id_prefix(), None);
+        assert_eq!(sd.new_entity_id().prefix(), "invoice");
+    }
+
+    #[test]
+    fn custom_id_prefix_generates_and_validates_ids() {
+        let sd = invoice(vec![Annotation::IdPrefix {
+            prefix: "inv".into(),
+        }])
+        .unwrap();
+        assert_eq!(sd.id_prefix(), "inv");
+
+        let id = sd.new_entity_id();
+        assert!(id.as_str().starts_with("inv_"), "got: {id}");
+        assert_eq!(sd.parse_entity_id(id.as_str()).unwrap(), id);
+
+        let foreign = EntityId::new("contact");
+        let err = sd.parse_entity_id(foreign.as_str()).unwrap_err();
+        assert_eq!(err, "expected id prefix 'inv', got 'contact'");
+    }
+
+    #[test]
+    fn custom_id_prefix_still_accepts_ids_minted_before_it() {
+        let before = invoice(vec![]).unwrap().new_entity_id();
+        let sd = invoice(vec![Annotation::IdPrefix {
+            prefix: "inv".into(),
+        }])
+        .unwrap();
+        assert_eq!(sd.parse_entity_id(before.as_str()).unwrap(), before);
+    }
+
+    #[test]
+    fn invalid_id_prefix_rejected() {
+        let result = invoice(vec![Annotation::IdPrefix {
+            prefix: "Inv-1".into(),
+        }]);
+        assert!(matches!(result, Err(SchemaError::InvalidIdPrefix { .. })));
+    }
+
+    #[test]
+    fn empty_as_null_honors_field_and_schema_annotations() {
+        use crate::types::field_annotation::FieldAnnotation;
+
+        let opted_in = FieldDefinition::with_annotations(
+            FieldName::new("nickname").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+            vec![],
+            vec![FieldAnnotation::EmptyAsNull],
+        );
+        let active = FieldDefinition::new(FieldName::new("active").unwrap(), FieldType::Boolean);
+        let fields = vec![make_field("name"), opted_in, active];
+
+        let plain = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            fields.clone(),
+            vec![],
+        )
+        .unwrap();
+        assert!(!plain.empty_as_null(plain.field("name").unwrap()));
+        assert!(plain.empty_as_null(plain.field("nickname").unwrap()));
+
+        let schema_wide = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            fields,
+            vec![Annotation::EmptyAsNull],
+        )
+        .unwrap();
+        assert!(schema_wide.empty_as_null(schema_wide.field("name").unwrap()));
+        assert!(!schema_wide.empty_as_null(schema_wide.field("active").unwrap()));
+    }
+
+    #[test]
+    fn is_soft_delete_follows_annotation() {
+        let fields = vec![make_field("name")];
+        let plain = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            fields.clone(),
+            vec![],
+        )
+        .unwrap();
+        assert!(!plain.is_soft_delete());
+
+        let soft = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            fields,
+            vec![Annotation::SoftDelete],
+        )
+        .unwrap();
+        assert!(soft.is_soft_delete());
+    }
+
+    fn versioned(version: Option<u32>) -> SchemaDefinition {
+        let annotations = version
+            .map(|v| {
+                vec![Annotation::Version {
+                    version: SchemaVersion::new(v).unwrap(),
+                }]
+            })
+            .unwrap_or_default();
+        SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![make_field("name")],
+            annotations,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn version_accessor() {
+        assert_eq!(versioned(Some(3)).version().map(|v| v.get()), Some(3));
+        assert_eq!(versioned(None).version(), None);
+    }
+
+    #[test]
+    fn higher_version_advances() {
+        assert!(versioned(Some(2))
+            .check_version_advances(&versioned(Some(1)))
+            .is_ok());
+    }
+
+    #[test]
+    fn lower_or_equal_version_rejected() {
+        let stored = versioned(Some(2));
+        for incoming in [1, 2] {
+            let err = versioned(Some(incoming))
+                .check_version_advances(&stored)
+                .unwrap_err();
+            assert_eq!(
+                err,
+                SchemaError::VersionNotIncreased {
+                    schema: "Contact".into(),
+                    incoming,
+                    stored: 2,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn unversioned_side_skips_check() {
+        assert!(versioned(None)
+            .check_version_advances(&versioned(Some(2)))
+            .is_ok());
+        assert!(versioned(Some(1))
+            .check_version_advances(&versioned(None))
+            .is_ok());
+    }
+
     #[test]
     fn display() {
         let sd = SchemaDefinition::new(
@@ -252,6 +659,142 @@ mod tests {
         assert!(s.contains("name: Text @required"));
     }
 
+    #[test]
+    fn list_columns_accessor() {
+        let sd = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Deal").unwrap(),
+            vec![
+                make_field("title"),
+                make_field("stage"),
+                make_field("notes"),
+            ],
+            vec![Annotation::ListColumns {
+                fields: vec![
+                    FieldName::new("stage").unwrap(),
+                    FieldName::new("title").unwrap(),
+                ],
+            }],
+        )
+        .unwrap();
+        let columns: Vec<&str> = sd
+            .list_columns()
+            .unwrap()
+            .iter()
+            .map(FieldName::as_str)
+            .collect();
+        assert_eq!(columns, vec!["stage", "title"]);
+    }
+
+    #[test]
+    fn indexes_may_repeat_and_are_validated() {
+        let index = |names: &[&str], unique| Annotation::Index {
+            fields: names.iter().map(|n| FieldName::new(*n).unwrap()).collect(),
+            unique,
+        };
+        let schema = |annotations| {
+            SchemaDefinition::new(
+                SchemaId::new(),
+                SchemaName::new("Deal").unwrap(),
+                vec![make_field("agency"), make_field("stage")],
+                annotations,
+            )
+        };
+
+        let sd = schema(vec![
+            index(&["agency", "stage"], true),
+            index(&["stage"], false),
+        ])
+        .unwrap();
+        let indexes: Vec<(Vec<&str>, bool)> = sd
+            .indexes()
+            .into_iter()
+            .map(|(fields, unique)| (fields.iter().map(FieldName::as_str).collect(), unique))
+            .collect();
+        assert_eq!(
+            indexes,
+            vec![(vec!["agency", "stage"], true), (vec!["stage"], false)]
+        );
+
+        for bad in [
+            vec![index(&[], false)],
+            vec![index(&["stage", "stage"], false)],
+            vec![index(&["missing"], false)],
+            vec![index(&["stage"], false), index(&["stage"], true)],
+        ] {
+            assert!(matches!(schema(bad), Err(SchemaError::InvalidIndex { .. })));
+        }
+    }
+
+    #[test]
+    fn effective_limit_defaults_and_clamps() {
+        let schema = |page_size, max_page_size| {
+            SchemaDefinition::new(
+                SchemaId::new(),
+                SchemaName::new("Deal").unwrap(),
+                vec![make_field("title")],
+                vec![Annotation::Pagination {
+                    page_size,
+                    max_page_size,
+                }],
+            )
+        };
+
+        let sized = schema(Some(50), Some(200)).unwrap();
+        assert_eq!(sized.page_size(), Some(50));
+        assert_eq!(sized.max_page_size(), Some(200));
+        assert_eq!(sized.effective_limit(None), Some(50));
+        assert_eq!(sized.effective_limit(Some(10)), Some(10));
+        assert_eq!(sized.effective_limit(Some(1_000_000)), Some(200));
+
+        let capped = schema(None, Some(100)).unwrap();
+        assert_eq!(capped.effective_limit(None), Some(100));
+
+        let plain = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Note").unwrap(),
+            vec![make_field("title")],
+            vec![],
+        )
+        .unwrap();
+        assert_eq!(plain.effective_limit(None), None);
+        assert_eq!(plain.effective_limit(Some(500)), Some(500));
+
+        for (page_size, max_page_size) in [(None, None), (Some(0), None), (Some(300), Some(200))] {
+            assert!(matches!(
+                schema(page_size, max_page_size),
+                Err(SchemaError::InvalidPageSize { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn ref_label_accessor() {
+        let field = FieldDefinition::new(
+            FieldName::new("name").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+        );
+        let plain = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Company").unwrap(),
+            vec![field.clone()],
+            vec![],
+        )
+        .unwrap();
+        assert!(plain.ref_label().is_none());
+
+        let labelled = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Company").unwrap(),
+            vec![field],
+            vec![Annotation::RefLabel {
+                template: RefLabelTemplate::new("{name} (#{id})").unwrap(),
+            }],
+        )
+        .unwrap();
+        assert_eq!(labelled.ref_label().unwrap().as_str(), "{name} (#{id})");
+    }
+
     #[test]
     fn serde_roundtrip() {
         let sd = SchemaDefinition::new(
@@ -6,18 +6,67 @@ pub struct TextConstraints {
     /// Maximum character length, if any.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_length: Option<u32>,
+    /// Regular expression every value must match, if any. Unanchored:
+    /// write `^...$` to match the whole value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
 }
 
 impl TextConstraints {
     /// Creates unconstrained text (no max length).
     pub fn unconstrained() -> Self {
-        Self { max_length: None }
+        Self {
+            max_length: None,
+            pattern: None,
+        }
     }
 
     /// Creates text with a max length.
     pub fn with_max_length(max: u32) -> Self {
         Self {
             max_length: Some(max),
+            pattern: None,
+        }
+    }
+
+    /// Returns these constraints with `pattern` added.
+    pub fn and_pattern(self, pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: Some(pattern.into()),
+            ..self
+        }
+    }
+
+    /// Checks that `pattern` is a valid regular expression.
+    pub fn check_pattern_syntax(pattern: &str) -> Result<(), String> {
+        regex::Regex::new(pattern)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Checks the character count of `value` against `max_length`.
+    pub fn check_len(&self, value: &str) -> Result<(), String> {
+        if let Some(max) = self.max_length {
+            let len = value.chars().count();
+            if len > max as usize {
+                return Err(format!("expected at most {max} characters, got {len}"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks `value` against the pattern, if one is set.
+    pub fn check_pattern(&self, value: &str) -> Result<(), String> {
+        let Some(pattern) = &self.pattern else {
+            return Ok(());
+        };
+        let re = regex::Regex::new(pattern).map_err(|e| format!("invalid pattern: {e}"))?;
+        if re.is_match(value) {
+            Ok(())
+        } else {
+            Err(format!(
+                "value '{value}' does not match pattern '{pattern}'"
+            ))
         }
     }
 }
@@ -51,6 +100,41 @@ mod tests {
         assert_eq!(c, back);
     }
 
+    #[test]
+    fn check_pattern_matches_value() {
+        let c = TextConstraints::with_max_length(20).and_pattern(r"^[A-Z]{3}-\d{4}$");
+        assert!(c.check_pattern("ABC-1234").is_ok());
+        let err = c.check_pattern("abc-1234").unwrap_err();
+        assert!(err.contains("does not match pattern"), "got: {err}");
+        assert!(TextConstraints::unconstrained().check_pattern("x").is_ok());
+    }
+
+    #[test]
+    fn check_len_counts_characters() {
+        let c = TextConstraints::with_max_length(3);
+        assert!(c.check_len("héé").is_ok());
+        assert_eq!(
+            c.check_len("abcd").unwrap_err(),
+            "expected at most 3 characters, got 4"
+        );
+        assert!(TextConstraints::unconstrained().check_len("abcd").is_ok());
+    }
+
+    #[test]
+    fn check_pattern_syntax_rejects_bad_regex() {
+        assert!(TextConstraints::check_pattern_syntax(r"^\d+$").is_ok());
+        assert!(TextConstraints::check_pattern_syntax("[a-").is_err());
+    }
+
+    #[test]
+    fn serde_roundtrip_with_pattern() {
+        let c = TextConstraints::unconstrained().and_pattern("^x");
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(json, r#"{"pattern":"^x"}"#);
+        let back: TextConstraints = serde_json::from_str(&json).unwrap();
+        assert_eq!(c, back);
+    }
+
     #[test]
     fn serde_skips_none() {
         let c = TextConstraints::unconstrained();
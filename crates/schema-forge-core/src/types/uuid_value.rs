@@ -0,0 +1,60 @@
+pub use uuid::Uuid;
+
+use crate::error::SchemaError;
+
+/// Regex for the hyphenated UUID form accepted by [`parse_uuid`], for form
+/// inputs and client-side validation.
+pub const UUID_PATTERN: &str =
+    "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}";
+
+/// Parses a hyphenated UUID such as `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+///
+/// Hex digits may be either case. The simple, braced, and URN forms that
+/// `Uuid::parse_str` also takes are rejected so the wire format stays the
+/// one the forms and filters expect.
+pub fn parse_uuid(s: &str) -> Result<Uuid, SchemaError> {
+    let invalid = || SchemaError::InvalidUuid(s.to_string());
+    if s.len() != 36 {
+        return Err(invalid());
+    }
+    Uuid::try_parse(s).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_hyphenated_either_case() {
+        let lower = parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let upper = parse_uuid("67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap();
+        assert_eq!(lower, upper);
+        assert_eq!(upper.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    }
+
+    #[test]
+    fn parse_rejects_malformed() {
+        for s in [
+            "",
+            "not-a-uuid",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67e55044-10b1-426f-9247-bb680e5fe0cg",
+            "67e55044-10b1-426f-9247-bb680e5fe0c8 ",
+        ] {
+            assert_eq!(
+                parse_uuid(s).unwrap_err(),
+                SchemaError::InvalidUuid(s.into()),
+                "{s:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn pattern_matches_exactly_the_accepted_form() {
+        let re = regex::Regex::new(&format!("^{UUID_PATTERN}$")).unwrap();
+        assert!(re.is_match("67E55044-10b1-426f-9247-bb680e5fe0c8"));
+        assert!(!re.is_match("67e5504410b1426f9247bb680e5fe0c8"));
+    }
+}
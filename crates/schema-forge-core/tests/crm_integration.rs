@@ -42,7 +42,7 @@ fn full_crm_contact_schema_serde_roundtrip() {
     ));
 
     let tags = back.field("tags").expect("tags field");
-    assert!(matches!(&tags.field_type, FieldType::Array(_)));
+    assert!(matches!(&tags.field_type, FieldType::Array(_, _)));
 
     let address = back.field("address").expect("address field");
     assert!(matches!(&address.field_type, FieldType::Composite(_)));
@@ -153,7 +153,7 @@ fn build_crm_contact_schema() -> SchemaDefinition {
             // tags: Array<Text>
             FieldDefinition::new(
                 FieldName::new("tags").unwrap(),
-                FieldType::Array(Box::new(FieldType::Text(TextConstraints::unconstrained()))),
+                FieldType::array(FieldType::Text(TextConstraints::unconstrained())),
             ),
             // address: Composite { street, city, zip }
             FieldDefinition::new(
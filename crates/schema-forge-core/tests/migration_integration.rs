@@ -445,7 +445,7 @@ fn scenario_change_default_value() {
     assert!(plan.is_safe());
     assert!(matches!(
         &plan.steps[0],
-        MigrationStep::SetDefault { field, value: DefaultValue::String(s) }
+        MigrationStep::SetDefault { field, value: DefaultValue::String(s), .. }
         if field.as_str() == "priority" && s == "Medium"
     ));
 }
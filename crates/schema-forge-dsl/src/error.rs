@@ -16,6 +16,36 @@ impl Span {
     pub fn new(start: usize, end: usize) -> Self {
         Self { start, end }
     }
+
+    /// 1-based `(line, column)` of the span start within `source`.
+    ///
+    /// CRLF and lone CR both count as one line break, a leading UTF-8 BOM
+    /// is not a column, and columns count characters rather than bytes.
+    pub fn line_col(&self, source: &str) -> (usize, usize) {
+        let end = self.start.min(source.len());
+        let prefix = source.get(..end).unwrap_or(source);
+        let prefix = prefix.strip_prefix('\u{FEFF}').unwrap_or(prefix);
+        let mut line = 1;
+        let mut col = 1;
+        let mut chars = prefix.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\r' => {
+                    if chars.peek() == Some(&'\n') {
+                        chars.next();
+                    }
+                    line += 1;
+                    col = 1;
+                }
+                '\n' => {
+                    line += 1;
+                    col = 1;
+                }
+                _ => col += 1,
+            }
+        }
+        (line, col)
+    }
 }
 
 impl fmt::Display for Span {
@@ -31,6 +61,23 @@ pub enum DslError {
     /// The lexer encountered a token that does not match any rule.
     InvalidToken { span: Span },
 
+    /// The lexer encountered a single character that cannot start any
+    /// token. `hint` names the ASCII character the author most likely
+    /// meant when `found` is a known lookalike (smart quotes, non-breaking
+    /// spaces, dashes pasted from a word processor).
+    UnexpectedCharacter {
+        found: char,
+        hint: Option<String>,
+        span: Span,
+    },
+
+    /// A `/*` appeared inside a block comment. Block comments do not nest;
+    /// `span` covers the inner `/*`.
+    NestedBlockComment { span: Span },
+
+    /// A block comment has no closing `*/`. `span` covers its `/*`.
+    UnterminatedBlockComment { span: Span },
+
     /// The parser encountered an unexpected token.
     UnexpectedToken {
         expected: String,
@@ -62,6 +109,9 @@ pub enum DslError {
     /// A float literal could not be parsed.
     InvalidFloatLiteral { text: String, span: Span },
 
+    /// A `decimal(scale: N)` scale is not an integer from 0 to 28.
+    InvalidDecimalScale { text: String, span: Span },
+
     /// An error propagated from schema-forge-core validation.
     CoreSchemaError { source: SchemaError, span: Span },
 
@@ -71,6 +121,14 @@ pub enum DslError {
     /// Duplicate enum variant found.
     DuplicateEnumVariant { variant: String, span: Span },
 
+    /// A `text(pattern: "...")` value is not a valid regular expression.
+    /// `span` covers the pattern string.
+    InvalidPattern {
+        pattern: String,
+        message: String,
+        span: Span,
+    },
+
     /// Integer constraint min > max.
     InvalidIntegerRange { min: i64, max: i64, span: Span },
 
@@ -96,6 +154,10 @@ pub enum DslError {
     /// `@enum_colors(...)` was applied to a field whose type is not `enum`.
     EnumColorsOnNonEnum { span: Span },
 
+    /// `@empty_as_null` was applied to a field whose type is not `text` or
+    /// `richtext`.
+    EmptyAsNullOnNonText { span: Span },
+
     /// `@enum_colors()` had no variant entries.
     EmptyEnumColors { span: Span },
 
@@ -133,11 +195,75 @@ pub enum DslError {
         span: Span,
     },
 
+    /// `@list_columns(...)` named a field the schema does not declare.
+    UnknownListColumn { field: String, span: Span },
+
+    /// `@list_columns(...)` listed the same field more than once.
+    DuplicateListColumn { field: String, span: Span },
+
     /// A `file(...)` parameter value was invalid or a required parameter was missing.
     InvalidFileParam { message: String, span: Span },
 
+    /// A `richtext(...)` parameter value was invalid or unknown.
+    InvalidRichTextParam { message: String, span: Span },
+
     /// A `max_size` literal in `file(...)` could not be parsed.
     InvalidSizeLiteral { text: String, span: Span },
+
+    /// One or more annotations were not followed by a `schema` declaration,
+    /// e.g. annotations written after a schema's closing brace.
+    AnnotationWithoutSchema { span: Span },
+
+    /// A schema body appeared without the `schema` keyword, e.g.
+    /// `Contact { ... }`.
+    MissingSchemaKeyword { name: String, span: Span },
+
+    /// A field declaration appeared at top level, outside any schema body.
+    FieldOutsideSchema { name: String, span: Span },
+
+    /// A token that cannot start a schema definition appeared at top level.
+    StrayTopLevelToken { found: String, span: Span },
+
+    /// A relation points at a schema that no file in the batch defines.
+    /// `field` is `Schema.field`.
+    UnknownRelationTarget {
+        field: String,
+        target: String,
+        span: Span,
+    },
+
+    /// `@display("...")` names a field the schema does not declare.
+    UnknownDisplayField {
+        schema: String,
+        field: String,
+        span: Span,
+    },
+
+    /// `@dashboard(group_by: "...")` names a field that is missing or is
+    /// neither an `enum` nor a to-one relation.
+    InvalidDashboardGroupBy {
+        schema: String,
+        field: String,
+        span: Span,
+    },
+
+    /// A `default(...)` literal cannot be stored in its field's type.
+    /// `field` is `Schema.field`; `span` covers the literal.
+    DefaultTypeMismatch {
+        field: String,
+        value: String,
+        field_type: String,
+        span: Span,
+    },
+
+    /// A `@computed` expression reads a field that is neither stored nor
+    /// computed above it. `field` is `Schema.field`; `span` covers the
+    /// expression.
+    UnknownComputedReference {
+        field: String,
+        reference: String,
+        span: Span,
+    },
 }
 
 impl fmt::Display for DslError {
@@ -146,6 +272,26 @@ impl fmt::Display for DslError {
             Self::InvalidToken { span } => {
                 write!(f, "invalid token at {span}")
             }
+            Self::UnexpectedCharacter { found, hint, span } => {
+                write!(
+                    f,
+                    "unexpected character '{found}' (U+{:04X}) at {span}",
+                    u32::from(*found)
+                )?;
+                if let Some(hint) = hint {
+                    write!(f, " — {hint}")?;
+                }
+                Ok(())
+            }
+            Self::NestedBlockComment { span } => {
+                write!(
+                    f,
+                    "nested block comment at {span}: block comments cannot contain '/*'"
+                )
+            }
+            Self::UnterminatedBlockComment { span } => {
+                write!(f, "block comment at {span} is never closed with '*/'")
+            }
             Self::UnexpectedToken {
                 expected,
                 found,
@@ -195,6 +341,12 @@ impl fmt::Display for DslError {
                     "invalid float literal '{text}' at {span}: expected a valid number"
                 )
             }
+            Self::InvalidDecimalScale { text, span } => {
+                write!(
+                    f,
+                    "invalid decimal scale '{text}' at {span}: expected an integer from 0 to 28"
+                )
+            }
             Self::CoreSchemaError { source, span } => {
                 write!(f, "schema validation error at {span}: {source}")
             }
@@ -207,6 +359,13 @@ impl fmt::Display for DslError {
             Self::DuplicateEnumVariant { variant, span } => {
                 write!(f, "duplicate enum variant '{variant}' at {span}")
             }
+            Self::InvalidPattern {
+                pattern,
+                message,
+                span,
+            } => {
+                write!(f, "invalid pattern \"{pattern}\" at {span}: {message}")
+            }
             Self::InvalidIntegerRange { min, max, span } => {
                 write!(
                     f,
@@ -236,6 +395,12 @@ impl fmt::Display for DslError {
                     "@enum_colors at {span} can only be applied to an enum field"
                 )
             }
+            Self::EmptyAsNullOnNonText { span } => {
+                write!(
+                    f,
+                    "@empty_as_null at {span} can only be applied to a text or richtext field"
+                )
+            }
             Self::EmptyEnumColors { span } => {
                 write!(
                     f,
@@ -283,15 +448,105 @@ impl fmt::Display for DslError {
                     "@list(primary) at {span} on field '{second_field}' conflicts with earlier @list(primary) on field '{first_field}'; at most one primary per schema"
                 )
             }
+            Self::UnknownListColumn { field, span } => {
+                write!(
+                    f,
+                    "@list_columns at {span} names unknown field '{field}'; list only fields declared in the schema"
+                )
+            }
+            Self::DuplicateListColumn { field, span } => {
+                write!(
+                    f,
+                    "@list_columns at {span} lists field '{field}' more than once"
+                )
+            }
             Self::InvalidFileParam { message, span } => {
                 write!(f, "invalid file parameter at {span}: {message}")
             }
+            Self::InvalidRichTextParam { message, span } => {
+                write!(f, "invalid richtext parameter at {span}: {message}")
+            }
             Self::InvalidSizeLiteral { text, span } => {
                 write!(
                     f,
                     "invalid size literal '{text}' at {span}: expected integer bytes or \"<N>(B|KB|MB|GB|TB)\""
                 )
             }
+            Self::AnnotationWithoutSchema { span } => {
+                write!(
+                    f,
+                    "annotation at {span} is not followed by a schema; schema annotations go before the 'schema' keyword, not after the closing '}}'"
+                )
+            }
+            Self::MissingSchemaKeyword { name, span } => {
+                write!(
+                    f,
+                    "missing 'schema' keyword before '{name}' at {span}; write 'schema {name} {{ ... }}'"
+                )
+            }
+            Self::FieldOutsideSchema { name, span } => {
+                write!(
+                    f,
+                    "field '{name}' at {span} is outside any schema body; move it inside the braces (is there an extra '}}' above it?)"
+                )
+            }
+            Self::StrayTopLevelToken { found, span } => {
+                write!(
+                    f,
+                    "unexpected {found} at {span} between schema definitions: expected 'schema' or an annotation"
+                )
+            }
+            Self::UnknownRelationTarget {
+                field,
+                target,
+                span,
+            } => {
+                write!(
+                    f,
+                    "field '{field}' at {span} relates to undefined schema '{target}'"
+                )
+            }
+            Self::UnknownDisplayField {
+                schema,
+                field,
+                span,
+            } => {
+                write!(
+                    f,
+                    "@display field '{field}' at {span} is not a field of schema '{schema}'"
+                )
+            }
+            Self::InvalidDashboardGroupBy {
+                schema,
+                field,
+                span,
+            } => {
+                write!(
+                    f,
+                    "@dashboard group_by '{field}' at {span} is not an enum or single relation field of schema '{schema}'"
+                )
+            }
+            Self::DefaultTypeMismatch {
+                field,
+                value,
+                field_type,
+                span,
+            } => {
+                write!(
+                    f,
+                    "default {value} at {span} does not fit field '{field}' of type {field_type}"
+                )
+            }
+            Self::UnknownComputedReference {
+                field,
+                reference,
+                span,
+            } => {
+                write!(
+                    f,
+                    "@computed expression at {span} on field '{field}' reads '{reference}', which is not a stored field or a computed field declared above it"
+                )
+            }
         }
     }
 }
@@ -311,6 +566,25 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn span_line_col_counts_line_endings_once() {
+        let lf = "schema S {\n  name: text\n}";
+        let crlf = "schema S {\r\n  name: text\r\n}";
+        let cr = "schema S {\r  name: text\r}";
+        for source in [lf, crlf, cr] {
+            let start = source.find("name").unwrap();
+            assert_eq!(Span::new(start, start + 4).line_col(source), (2, 3));
+        }
+        assert_eq!(Span::new(0, 6).line_col(lf), (1, 1));
+    }
+
+    #[test]
+    fn span_line_col_ignores_bom() {
+        let source = "\u{FEFF}schema S";
+        let start = source.find('S').unwrap();
+        assert_eq!(Span::new(start, start + 1).line_col(source), (1, 8));
+    }
+
     #[test]
     fn span_display() {
         let span = Span::new(10, 20);
@@ -396,6 +670,75 @@ mod tests {
         assert!(err.source().is_none());
     }
 
+    #[test]
+    fn error_display_structural_errors() {
+        let msg = DslError::AnnotationWithoutSchema {
+            span: Span::new(30, 41),
+        }
+        .to_string();
+        assert!(msg.contains("before the 'schema' keyword"));
+
+        let msg = DslError::MissingSchemaKeyword {
+            name: "Contact".into(),
+            span: Span::new(0, 7),
+        }
+        .to_string();
+        assert!(msg.contains("schema Contact { ... }"));
+
+        let msg = DslError::FieldOutsideSchema {
+            name: "email".into(),
+            span: Span::new(30, 35),
+        }
+        .to_string();
+        assert!(msg.contains("'email'"));
+        assert!(msg.contains("outside any schema body"));
+
+        let msg = DslError::StrayTopLevelToken {
+            found: "'}'".into(),
+            span: Span::new(30, 31),
+        }
+        .to_string();
+        assert!(msg.contains("expected 'schema' or an annotation"));
+    }
+
+    #[test]
+    fn error_display_unexpected_character() {
+        let msg = DslError::UnexpectedCharacter {
+            found: '\u{201C}',
+            hint: Some("did you mean a straight quote '\"'?".into()),
+            span: Span::new(20, 23),
+        }
+        .to_string();
+        assert!(msg.contains("unexpected character '\u{201C}' (U+201C) at 20..23"));
+        assert!(msg.contains("did you mean a straight quote '\"'?"));
+
+        let msg = DslError::UnexpectedCharacter {
+            found: '#',
+            hint: None,
+            span: Span::new(7, 8),
+        }
+        .to_string();
+        assert_eq!(msg, "unexpected character '#' (U+0023) at 7..8");
+    }
+
+    #[test]
+    fn error_display_list_columns() {
+        let msg = DslError::UnknownListColumn {
+            field: "stage".into(),
+            span: Span::new(0, 20),
+        }
+        .to_string();
+        assert!(msg.contains("unknown field 'stage'"));
+        assert!(msg.contains("0..20"));
+
+        let msg = DslError::DuplicateListColumn {
+            field: "title".into(),
+            span: Span::new(0, 20),
+        }
+        .to_string();
+        assert!(msg.contains("'title' more than once"));
+    }
+
     #[test]
     fn error_display_unknown_annotation() {
         let err = DslError::UnknownAnnotation {
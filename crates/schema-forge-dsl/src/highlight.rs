@@ -0,0 +1,219 @@
+//! Token classification for syntax highlighting.
+//!
+//! Editors re-highlight on every keystroke, so the source is usually
+//! incomplete or invalid. Classification therefore works on the raw token
+//! stream with a little local context instead of on a parse.
+
+use crate::error::Span;
+use crate::lexer::tokenize_lossy;
+use crate::token::Token;
+
+/// Highlighting category of a DSL token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TokenKind {
+    /// Structural keywords and modifiers: `schema`, `required`, `indexed`,
+    /// `default`, `true`, `false`.
+    Keyword,
+    /// Field type keywords (`text`, `integer`, ...) and schema names, both
+    /// where a schema is declared and as a relation target.
+    Type,
+    /// The name of a field, at the point it is declared.
+    FieldName,
+    /// An annotation: the `@` and the name that follows it.
+    Annotation,
+    /// A string literal, including triple-quoted strings.
+    String,
+    /// An integer or float literal.
+    Number,
+    /// A `///` doc comment line.
+    DocComment,
+    /// Braces, brackets, parentheses, `:`, `,` and `->`.
+    Punctuation,
+    /// Any other identifier, such as a type parameter name or an enum
+    /// variant.
+    Identifier,
+}
+
+/// Returns every token in `source` with its span and highlighting category.
+///
+/// Never fails: characters the lexer cannot match are skipped, and no parse
+/// is attempted, so the result is useful for half-typed schemas too.
+/// Whitespace and ordinary comments produce no spans; `///` doc comments
+/// do.
+pub fn tokenize_spans(source: &str) -> Vec<(Span, TokenKind)> {
+    let tokens = tokenize_lossy(source);
+    // Parameter lists (`text(max: 255)`, `@access(read: [...])`) also use
+    // `name:`; only a `name:` outside parentheses and brackets declares a
+    // field.
+    let mut nesting = 0usize;
+    let mut spans = Vec::with_capacity(tokens.len());
+
+    for (i, tok) in tokens.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|j| &tokens[j].token);
+        let next = tokens.get(i + 1).map(|t| &t.token);
+
+        let kind = match tok.token {
+            Token::LParen | Token::LBracket => {
+                nesting += 1;
+                TokenKind::Punctuation
+            }
+            Token::RParen | Token::RBracket => {
+                nesting = nesting.saturating_sub(1);
+                TokenKind::Punctuation
+            }
+            // Braces never appear inside a parameter list, so they also
+            // recover from a parenthesis left unclosed while typing.
+            Token::LBrace | Token::RBrace => {
+                nesting = 0;
+                TokenKind::Punctuation
+            }
+            Token::Colon | Token::Comma | Token::Arrow => TokenKind::Punctuation,
+            Token::BlockComment => continue,
+            Token::DocComment => TokenKind::DocComment,
+            Token::At => TokenKind::Annotation,
+            Token::StringLiteral | Token::TripleStringLiteral => TokenKind::String,
+            Token::IntegerLiteral | Token::FloatLiteral => TokenKind::Number,
+            _ if prev == Some(&Token::At) => TokenKind::Annotation,
+            // Keywords double as field names (`text: text`).
+            _ if nesting == 0 && next == Some(&Token::Colon) => TokenKind::FieldName,
+            Token::Ident if matches!(prev, Some(Token::Schema | Token::Arrow)) => TokenKind::Type,
+            Token::Ident => TokenKind::Identifier,
+            Token::Schema
+            | Token::Required
+            | Token::Indexed
+            | Token::Unique
+            | Token::Default
+            | Token::True
+            | Token::False => TokenKind::Keyword,
+            Token::Text
+            | Token::RichText
+            | Token::Integer
+            | Token::Float
+            | Token::Decimal
+            | Token::Boolean
+            | Token::DateTime
+            | Token::Duration
+            | Token::Uuid
+            | Token::Enum
+            | Token::Json
+            | Token::Composite
+            | Token::File => TokenKind::Type,
+        };
+        spans.push((tok.span.clone(), kind));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classified(source: &str) -> Vec<(&str, TokenKind)> {
+        tokenize_spans(source)
+            .into_iter()
+            .map(|(span, kind)| (&source[span.start..span.end], kind))
+            .collect()
+    }
+
+    #[test]
+    fn sample_schema_is_classified() {
+        let source = r#"@display("name")
+schema Contact {
+    // primary label
+    name: text(max: 255) required
+    age: integer default(0)
+    company: -> Company @widget("select")
+    status: enum("a", "b")
+}"#;
+        use TokenKind::*;
+        assert_eq!(
+            classified(source),
+            vec![
+                ("@", Annotation),
+                ("display", Annotation),
+                ("(", Punctuation),
+                ("\"name\"", String),
+                (")", Punctuation),
+                ("schema", Keyword),
+                ("Contact", Type),
+                ("{", Punctuation),
+                ("name", FieldName),
+                (":", Punctuation),
+                ("text", Type),
+                ("(", Punctuation),
+                ("max", Identifier),
+                (":", Punctuation),
+                ("255", Number),
+                (")", Punctuation),
+                ("required", Keyword),
+                ("age", FieldName),
+                (":", Punctuation),
+                ("integer", Type),
+                ("default", Keyword),
+                ("(", Punctuation),
+                ("0", Number),
+                (")", Punctuation),
+                ("company", FieldName),
+                (":", Punctuation),
+                ("->", Punctuation),
+                ("Company", Type),
+                ("@", Annotation),
+                ("widget", Annotation),
+                ("(", Punctuation),
+                ("\"select\"", String),
+                (")", Punctuation),
+                ("status", FieldName),
+                (":", Punctuation),
+                ("enum", Type),
+                ("(", Punctuation),
+                ("\"a\"", String),
+                (",", Punctuation),
+                ("\"b\"", String),
+                (")", Punctuation),
+                ("}", Punctuation),
+            ]
+        );
+    }
+
+    #[test]
+    fn keyword_used_as_field_name_is_a_field_name() {
+        let source = "schema S { text: text }";
+        assert_eq!(
+            classified(source)[3..5],
+            [
+                ("text", TokenKind::FieldName),
+                (":", TokenKind::Punctuation)
+            ]
+        );
+        assert_eq!(classified(source)[5], ("text", TokenKind::Type));
+    }
+
+    #[test]
+    fn invalid_and_incomplete_input_still_yields_spans() {
+        let source = "schema Contact {\n    name: text(max: # \n}\nschema Lead {\n    email: ";
+        let spans = classified(source);
+        assert!(spans.contains(&("email", TokenKind::FieldName)));
+        assert!(spans.contains(&("max", TokenKind::Identifier)));
+        assert!(!spans.iter().any(|(text, _)| *text == "#"));
+    }
+
+    #[test]
+    fn spans_are_offsets_into_source_with_bom() {
+        let source = "\u{FEFF}schema Contact";
+        let spans = tokenize_spans(source);
+        assert_eq!(spans[0], (Span::new(3, 9), TokenKind::Keyword));
+        assert_eq!(spans[1], (Span::new(10, 17), TokenKind::Type));
+    }
+
+    #[test]
+    fn doc_comments_are_classified() {
+        let source = "/// About\nschema S {}";
+        assert_eq!(classified(source)[0], ("/// About", TokenKind::DocComment));
+    }
+
+    #[test]
+    fn comments_produce_no_spans() {
+        assert!(tokenize_spans("// only\n/* comments */").is_empty());
+    }
+}
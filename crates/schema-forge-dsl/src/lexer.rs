@@ -11,32 +11,60 @@ pub struct SpannedToken {
     pub text: String,
 }
 
+/// UTF-8 byte order mark some Windows editors prepend to saved files.
+const BOM: char = '\u{FEFF}';
+
 /// Tokenizes DSL source text into a sequence of spanned tokens.
 ///
-/// Invalid tokens are collected as `DslError::InvalidToken` errors.
-/// If any invalid tokens are found, the entire result is an error.
+/// Invalid tokens are collected as errors. If any invalid tokens are found,
+/// the entire result is an error. A leading UTF-8 BOM is skipped and CR /
+/// CRLF line endings are treated as whitespace; spans stay byte offsets
+/// into `source` as given, BOM included.
 ///
 /// # Errors
 ///
-/// Returns a list of `DslError::InvalidToken` for any bytes the lexer
-/// cannot match to a valid token rule.
+/// Returns `DslError::UnexpectedCharacter` for a single character that
+/// cannot start a token (with a hint when it is a known lookalike of an
+/// ASCII character), `DslError::NestedBlockComment` and
+/// `DslError::UnterminatedBlockComment` for malformed `/* ... */` comments,
+/// and `DslError::InvalidToken` for longer unmatched input.
 pub fn tokenize(source: &str) -> Result<Vec<SpannedToken>, Vec<DslError>> {
     let mut tokens = Vec::new();
     let mut errors = Vec::new();
 
-    let lexer = Token::lexer(source);
+    let offset = if source.starts_with(BOM) {
+        BOM.len_utf8()
+    } else {
+        0
+    };
+    let body = &source[offset..];
+
+    let lexer = Token::lexer(body);
     for (result, range) in lexer.spanned() {
-        let span = Span::new(range.start, range.end);
+        let span = Span::new(range.start + offset, range.end + offset);
         match result {
             Ok(token) => {
                 tokens.push(SpannedToken {
                     token,
                     span,
-                    text: source[range].to_string(),
+                    text: body[range].to_string(),
                 });
             }
             Err(()) => {
-                errors.push(DslError::InvalidToken { span });
+                let text = body.get(range.clone()).unwrap_or_default();
+                if let Some(rest) = text.strip_prefix("/*") {
+                    errors.push(block_comment_error(rest, span));
+                    continue;
+                }
+                let mut chars = text.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(found), None) => errors.push(DslError::UnexpectedCharacter {
+                        found,
+                        hint: lookalike_hint(found).map(str::to_string),
+                        span,
+                    }),
+                    _ => errors.push(DslError::InvalidToken { span }),
+                }
             }
         }
     }
@@ -48,6 +76,78 @@ pub fn tokenize(source: &str) -> Result<Vec<SpannedToken>, Vec<DslError>> {
     }
 }
 
+/// Classify a rejected block comment. `rest` is the comment text after its
+/// opening `/*` and `span` covers the whole comment.
+fn block_comment_error(rest: &str, span: Span) -> DslError {
+    match rest.find("/*") {
+        Some(nested) => {
+            let start = span.start + 2 + nested;
+            DslError::NestedBlockComment {
+                span: Span::new(start, start + 2),
+            }
+        }
+        None => DslError::UnterminatedBlockComment {
+            span: Span::new(span.start, span.start + 2),
+        },
+    }
+}
+
+/// Tokenizes DSL source text, silently dropping anything the lexer cannot
+/// match.
+///
+/// For tooling that has to cope with incomplete or invalid input (syntax
+/// highlighting, editor integrations). Spans follow the same rules as
+/// [`tokenize`].
+pub(crate) fn tokenize_lossy(source: &str) -> Vec<SpannedToken> {
+    let offset = if source.starts_with(BOM) {
+        BOM.len_utf8()
+    } else {
+        0
+    };
+    let body = &source[offset..];
+
+    Token::lexer(body)
+        .spanned()
+        .filter_map(|(result, range)| {
+            let token = result.ok()?;
+            Some(SpannedToken {
+                token,
+                span: Span::new(range.start + offset, range.end + offset),
+                text: body[range].to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Suggest the ASCII character a non-ASCII lookalike most likely stands
+/// for. These usually arrive by pasting from word processors, chat tools,
+/// or rendered documentation.
+fn lookalike_hint(c: char) -> Option<&'static str> {
+    let hint = match c {
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' | '\u{FF02}' => {
+            "did you mean a straight quote '\"'?"
+        }
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => {
+            "strings use straight double quotes: did you mean '\"'?"
+        }
+        '\u{00A0}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}' => {
+            "this is a non-breaking or typographic space; replace it with a regular space"
+        }
+        '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}' => {
+            "this is an invisible zero-width character; delete it"
+        }
+        '\u{2010}'..='\u{2015}' | '\u{2212}' => "did you mean a hyphen-minus '-'?",
+        '\u{2192}' | '\u{27F6}' => "did you mean the relation arrow '->'?",
+        '\u{FF1A}' => "did you mean ':'?",
+        '\u{FF0C}' => "did you mean ','?",
+        '\u{FF08}' => "did you mean '('?",
+        '\u{FF09}' => "did you mean ')'?",
+        '\u{FF20}' => "did you mean '@'?",
+        _ => return None,
+    };
+    Some(hint)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,7 +177,47 @@ mod tests {
         assert!(result.is_err());
         let errors = result.unwrap_err();
         assert_eq!(errors.len(), 1);
-        assert!(matches!(&errors[0], DslError::InvalidToken { .. }));
+        assert_eq!(
+            errors[0],
+            DslError::UnexpectedCharacter {
+                found: '#',
+                hint: None,
+                span: Span::new(7, 8),
+            }
+        );
+    }
+
+    #[test]
+    fn tokenize_curly_quote_suggests_straight_quote() {
+        let source = "schema S { name: text default(\u{201C}x\") }";
+        let errors = tokenize(source).unwrap_err();
+        let start = source.find('\u{201C}').unwrap();
+        assert_eq!(
+            errors[0],
+            DslError::UnexpectedCharacter {
+                found: '\u{201C}',
+                hint: Some("did you mean a straight quote '\"'?".to_string()),
+                span: Span::new(start, start + '\u{201C}'.len_utf8()),
+            }
+        );
+        assert!(errors[0]
+            .to_string()
+            .contains("did you mean a straight quote"));
+    }
+
+    #[test]
+    fn tokenize_non_breaking_space_suggests_regular_space() {
+        let source = "schema S {\u{00A0}name: text }";
+        let errors = tokenize(source).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            DslError::UnexpectedCharacter { found, hint, span } => {
+                assert_eq!(*found, '\u{00A0}');
+                assert!(hint.as_deref().unwrap().contains("regular space"));
+                assert_eq!(*span, Span::new(10, 12));
+            }
+            other => panic!("expected UnexpectedCharacter, got {other:?}"),
+        }
     }
 
     #[test]
@@ -98,6 +238,71 @@ mod tests {
         assert!(tokens.is_empty());
     }
 
+    #[test]
+    fn tokenize_skips_leading_bom_and_keeps_source_offsets() {
+        let source = "\u{FEFF}schema Contact";
+        let tokens = tokenize(source).unwrap();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].token, Token::Schema);
+        assert_eq!(tokens[0].span, Span::new(3, 9));
+        assert_eq!(&source[tokens[1].span.start..tokens[1].span.end], "Contact");
+    }
+
+    #[test]
+    fn tokenize_line_comment_stops_at_lone_cr() {
+        let tokens = tokenize("// note\rschema S").unwrap();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].token, Token::Schema);
+    }
+
+    #[test]
+    fn tokenize_comment_keeps_later_positions_accurate() {
+        let source = "// header\nschema /* a\nb */ Contact {\n  # }";
+        let errors = tokenize(source).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            DslError::UnexpectedCharacter {
+                found: '#', span, ..
+            } => {
+                assert_eq!(span.line_col(source), (4, 3));
+            }
+            other => panic!("expected UnexpectedCharacter, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn tokenize_nested_block_comment_points_at_inner_opener() {
+        let source = "schema /* outer /* inner */ still */ Contact";
+        let errors = tokenize(source).unwrap_err();
+        let inner = source.find("/* inner").unwrap();
+        assert_eq!(
+            errors,
+            vec![DslError::NestedBlockComment {
+                span: Span::new(inner, inner + 2),
+            }]
+        );
+    }
+
+    #[test]
+    fn tokenize_unterminated_block_comment() {
+        let source = "schema Contact { /* TODO";
+        let errors = tokenize(source).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![DslError::UnterminatedBlockComment {
+                span: Span::new(17, 19),
+            }]
+        );
+    }
+
+    #[test]
+    fn tokenize_lossy_skips_invalid_input() {
+        let tokens = tokenize_lossy("schema # Contact {");
+        let kinds: Vec<_> = tokens.iter().map(|t| t.token.clone()).collect();
+        assert_eq!(kinds, vec![Token::Schema, Token::Ident, Token::LBrace]);
+        assert_eq!(tokens[1].span, Span::new(9, 16));
+    }
+
     #[test]
     fn tokenize_string_with_escapes() {
         let tokens = tokenize(r#""hello \"world\"""#).unwrap();
@@ -6,6 +6,7 @@
 //! - A lexer that tokenizes `.schema` source files
 //! - A recursive descent parser that produces `SchemaDefinition` values
 //! - A printer that converts `SchemaDefinition` back to DSL text
+//! - Classified token spans for syntax highlighting, usable on invalid input
 //! - Round-trip fidelity: `parse(print(schema))` produces an equivalent AST
 //!
 //! # Example
@@ -30,11 +31,17 @@
 //! ```
 
 pub mod error;
+pub mod highlight;
 mod lexer;
 pub mod parser;
 pub mod printer;
+pub mod relations;
+pub mod semantics;
 pub mod token;
 
 pub use error::{DslError, Span};
-pub use parser::parse;
-pub use printer::{print, print_all};
+pub use highlight::{tokenize_spans, TokenKind};
+pub use parser::{parse, parse_all_errors};
+pub use printer::{print, print_all, print_all_with, print_with, PrintOptions, SchemaGrouping};
+pub use relations::check_relation_targets;
+pub use semantics::check_semantics;
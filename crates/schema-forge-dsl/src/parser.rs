@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
 use tracing::instrument;
@@ -6,10 +6,12 @@ use tracing::instrument;
 use std::collections::BTreeMap;
 
 use schema_forge_core::types::{
-    Annotation, Cardinality, DefaultValue, EnumColor, EnumVariants, FieldAnnotation,
+    is_valid_currency_code, is_valid_locale_tag, Annotation, ArrayConstraints, Cardinality,
+    ComputedExpr, DefaultValue, EntityId, EnumColor, EnumVariants, FieldAnnotation,
     FieldDefinition, FieldModifier, FieldName, FieldType, FileAccess, FileConstraints,
     FloatConstraints, FormatType, HookEvent, IntegerConstraints, ListHint, MimePattern,
-    SchemaDefinition, SchemaId, SchemaName, SchemaVersion, TenantKind, TextConstraints, WidgetType,
+    RefLabelTemplate, RichTextConstraints, SchemaDefinition, SchemaId, SchemaName, SchemaVersion,
+    TenantKind, TextConstraints, WidgetType, MAX_DECIMAL_SCALE,
 };
 
 use crate::error::{DslError, Span};
@@ -29,11 +31,44 @@ struct MixedParams {
 struct Parser {
     tokens: Vec<SpannedToken>,
     pos: usize,
+    /// `///` doc comment text keyed by the position of the token that
+    /// follows it. Only schema and field starts consult this, so a doc
+    /// comment anywhere else (e.g. before a closing `}`) is dropped.
+    docs: HashMap<usize, String>,
 }
 
 impl Parser {
     fn new(tokens: Vec<SpannedToken>) -> Self {
-        Self { tokens, pos: 0 }
+        let mut kept = Vec::with_capacity(tokens.len());
+        let mut docs = HashMap::new();
+        let mut pending: Option<String> = None;
+        for st in tokens {
+            if st.token == Token::DocComment {
+                let line = doc_comment_line(&st.text);
+                match &mut pending {
+                    Some(doc) => {
+                        doc.push('\n');
+                        doc.push_str(line);
+                    }
+                    None => pending = Some(line.to_string()),
+                }
+            } else {
+                if let Some(doc) = pending.take() {
+                    docs.insert(kept.len(), doc);
+                }
+                kept.push(st);
+            }
+        }
+        Self {
+            tokens: kept,
+            pos: 0,
+            docs,
+        }
+    }
+
+    /// The doc comment directly above the token at the cursor, if any.
+    fn doc_at_cursor(&self) -> Option<String> {
+        self.docs.get(&self.pos).cloned()
     }
 
     // -- Cursor helpers --
@@ -84,6 +119,17 @@ impl Parser {
 
     /// file = schema_def*
     fn parse_file(&mut self) -> Result<Vec<SchemaDefinition>, Vec<DslError>> {
+        let (schemas, errors) = self.parse_file_recovering();
+        if errors.is_empty() {
+            Ok(schemas)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Parse every schema in the file, skipping to the next schema boundary
+    /// after an error. Returns the schemas that parsed alongside the errors.
+    fn parse_file_recovering(&mut self) -> (Vec<SchemaDefinition>, Vec<DslError>) {
         let mut schemas = Vec::new();
         let mut errors = Vec::new();
 
@@ -97,11 +143,7 @@ impl Parser {
             }
         }
 
-        if errors.is_empty() {
-            Ok(schemas)
-        } else {
-            Err(errors)
-        }
+        (schemas, errors)
     }
 
     /// Skip tokens until we find the next `schema` keyword or `@` annotation at top level.
@@ -130,9 +172,15 @@ impl Parser {
 
     /// schema_def = annotation* "schema" IDENT "{" field_def* "}"
     fn parse_schema(&mut self) -> Result<SchemaDefinition, DslError> {
+        let doc = self.doc_at_cursor();
         let schema_start = self.current_span().start;
         let annotations = self.parse_annotations()?;
 
+        if self.peek_token() != Some(&Token::Schema) {
+            let annotations_span = (!annotations.is_empty())
+                .then(|| Span::new(schema_start, self.tokens[self.pos - 1].span.end));
+            return Err(self.structural_error(annotations_span));
+        }
         self.expect(&Token::Schema)?;
 
         let name_tok = self.expect_ident("schema name")?;
@@ -167,10 +215,12 @@ impl Parser {
             }
         }
 
-        // Validate no duplicate annotation kinds
+        // Validate no duplicate annotation kinds (`@index` may repeat)
         let mut seen_kinds = HashSet::new();
         for ann in &annotations {
-            if !seen_kinds.insert(ann.kind().to_string()) {
+            if !matches!(ann, Annotation::Index { .. })
+                && !seen_kinds.insert(ann.kind().to_string())
+            {
                 return Err(DslError::DuplicateAnnotation {
                     kind: ann.kind().to_string(),
                     span: schema_span,
@@ -195,12 +245,88 @@ impl Parser {
             }
         }
 
-        SchemaDefinition::new(SchemaId::new(), schema_name, fields, annotations).map_err(|e| {
-            DslError::CoreSchemaError {
-                source: e,
-                span: schema_span,
+        // Validate `@list_columns(...)` only names declared fields.
+        for ann in &annotations {
+            if let Annotation::ListColumns { fields: columns } = ann {
+                if let Some(unknown) = columns.iter().find(|c| !seen_names.contains(c.as_str())) {
+                    return Err(DslError::UnknownListColumn {
+                        field: unknown.as_str().to_string(),
+                        span: schema_span,
+                    });
+                }
             }
-        })
+        }
+
+        // Validate schema-level `@enum_colors(...)` keys name a variant of
+        // some enum field.
+        for ann in &annotations {
+            if let Annotation::EnumColors { colors } = ann {
+                let mut variants: Vec<String> = Vec::new();
+                for field in &fields {
+                    if let FieldType::Enum(v) = &field.field_type {
+                        for variant in v.as_slice() {
+                            if !variants.contains(variant) {
+                                variants.push(variant.clone());
+                            }
+                        }
+                    }
+                }
+                if let Some(unknown) = colors.keys().find(|k| !variants.contains(k)) {
+                    return Err(DslError::UnknownEnumColorsVariant {
+                        variant: unknown.clone(),
+                        valid: variants,
+                        span: schema_span,
+                    });
+                }
+            }
+        }
+
+        let mut schema = SchemaDefinition::new(SchemaId::new(), schema_name, fields, annotations)
+            .map_err(|e| DslError::CoreSchemaError {
+            source: e,
+            span: schema_span,
+        })?;
+        schema.doc = doc;
+        Ok(schema)
+    }
+
+    /// Diagnose a top-level position that should start a schema but does
+    /// not, naming the most likely structural mistake instead of reporting
+    /// a bare "expected 'schema'".
+    ///
+    /// `annotations_span` covers the leading annotations already consumed,
+    /// if any.
+    fn structural_error(&self, annotations_span: Option<Span>) -> DslError {
+        let Some(st) = self.peek() else {
+            return match annotations_span {
+                Some(span) => DslError::AnnotationWithoutSchema { span },
+                None => DslError::UnexpectedEndOfInput {
+                    expected: Token::Schema.description().to_string(),
+                },
+            };
+        };
+        let is_ident = st.token == Token::Ident || is_contextual_ident(&st.token);
+        let next = self.tokens.get(self.pos + 1).map(|t| &t.token);
+
+        if is_ident && next == Some(&Token::LBrace) {
+            return DslError::MissingSchemaKeyword {
+                name: st.text.clone(),
+                span: st.span.clone(),
+            };
+        }
+        if let Some(span) = annotations_span {
+            return DslError::AnnotationWithoutSchema { span };
+        }
+        if is_ident && next == Some(&Token::Colon) {
+            return DslError::FieldOutsideSchema {
+                name: st.text.clone(),
+                span: st.span.clone(),
+            };
+        }
+        DslError::StrayTopLevelToken {
+            found: format!("{} ('{}')", st.token.description(), st.text),
+            span: st.span.clone(),
+        }
     }
 
     /// annotation* (zero or more leading annotations)
@@ -242,6 +368,69 @@ impl Parser {
                 self.expect(&Token::RParen)?;
                 Annotation::Display { field }
             }
+            "ref_label" => {
+                self.expect(&Token::LParen)?;
+                let value_tok = self.expect_string_literal()?;
+                let template =
+                    RefLabelTemplate::new(unquote_string(&value_tok.text)).map_err(|e| {
+                        DslError::CoreSchemaError {
+                            source: e,
+                            span: value_tok.span.clone(),
+                        }
+                    })?;
+                self.expect(&Token::RParen)?;
+                Annotation::RefLabel { template }
+            }
+            "list_columns" => {
+                self.expect(&Token::LParen)?;
+                let mut fields: Vec<FieldName> = Vec::new();
+                loop {
+                    let field_tok = self.expect_ident("field name")?;
+                    let field = FieldName::new(&field_tok.text).map_err(|_| {
+                        DslError::InvalidFieldName {
+                            name: field_tok.text.clone(),
+                            span: field_tok.span.clone(),
+                        }
+                    })?;
+                    if fields.contains(&field) {
+                        return Err(DslError::DuplicateListColumn {
+                            field: field_tok.text.clone(),
+                            span: field_tok.span.clone(),
+                        });
+                    }
+                    fields.push(field);
+                    if self.peek_token() == Some(&Token::Comma) {
+                        self.advance();
+                    } else {
+                        break;
+                    }
+                }
+                self.expect(&Token::RParen)?;
+                Annotation::ListColumns { fields }
+            }
+            "enum_colors" => Annotation::EnumColors {
+                colors: self.parse_enum_colors_params(None, name_tok.span.clone())?,
+            },
+            "list" => {
+                let paren_span = self.current_span();
+                self.expect(&Token::LParen)?;
+                let params = self.parse_named_params()?;
+                self.expect(&Token::RParen)?;
+                if let Some((key, _)) = params
+                    .iter()
+                    .find(|(k, _)| k != "page_size" && k != "max_page_size")
+                {
+                    return Err(DslError::UnexpectedToken {
+                        expected: "page_size or max_page_size".to_string(),
+                        found: format!("parameter '{key}'"),
+                        span: paren_span,
+                    });
+                }
+                Annotation::Pagination {
+                    page_size: extract_u32_param(&params, "page_size", &paren_span)?,
+                    max_page_size: extract_u32_param(&params, "max_page_size", &paren_span)?,
+                }
+            }
             "access" => {
                 self.expect(&Token::LParen)?;
                 let lists = self.parse_named_string_lists()?;
@@ -347,6 +536,23 @@ impl Parser {
                     }
                 }
             }
+            "deprecated" => Annotation::Deprecated {
+                reason: self.parse_deprecation_reason()?,
+            },
+            "id_prefix" => {
+                self.expect(&Token::LParen)?;
+                let value_tok = self.expect_string_literal()?;
+                let prefix = unquote_string(&value_tok.text);
+                EntityId::validate_prefix(&prefix).map_err(|e| DslError::CoreSchemaError {
+                    source: e,
+                    span: value_tok.span.clone(),
+                })?;
+                self.expect(&Token::RParen)?;
+                Annotation::IdPrefix { prefix }
+            }
+            "empty_as_null" => Annotation::EmptyAsNull,
+            "soft_delete" => Annotation::SoftDelete,
+            "index" => self.parse_index_params()?,
             other => {
                 return Err(DslError::UnknownAnnotation {
                     name: other.to_string(),
@@ -358,6 +564,86 @@ impl Parser {
         Ok(annotation)
     }
 
+    /// Parse `(fields: ["a", "b"], unique: true)` of a schema-level
+    /// `@index`. `fields` is required; `unique` defaults to false. Whether
+    /// the fields exist is checked once the whole schema is parsed.
+    fn parse_index_params(&mut self) -> Result<Annotation, DslError> {
+        let paren_span = self.current_span();
+        self.expect(&Token::LParen)?;
+        let mut fields: Option<Vec<FieldName>> = None;
+        let mut unique = false;
+        loop {
+            let key_tok = self.expect_ident("fields or unique")?;
+            self.expect(&Token::Colon)?;
+            match key_tok.text.as_str() {
+                "fields" => {
+                    let list_span = self.current_span();
+                    let names = self.parse_string_list()?;
+                    fields = Some(
+                        names
+                            .into_iter()
+                            .map(|name| {
+                                FieldName::new(&name).map_err(|_| DslError::InvalidFieldName {
+                                    name,
+                                    span: list_span.clone(),
+                                })
+                            })
+                            .collect::<Result<_, _>>()?,
+                    );
+                }
+                "unique" => {
+                    let tok = self
+                        .advance()
+                        .ok_or_else(|| DslError::UnexpectedEndOfInput {
+                            expected: "true or false".to_string(),
+                        })?;
+                    unique = match tok.token {
+                        Token::True => true,
+                        Token::False => false,
+                        _ => {
+                            return Err(DslError::UnexpectedToken {
+                                expected: "true or false".to_string(),
+                                found: format!("{} ('{}')", tok.token.description(), tok.text),
+                                span: tok.span,
+                            });
+                        }
+                    };
+                }
+                other => {
+                    return Err(DslError::UnexpectedToken {
+                        expected: "fields or unique".to_string(),
+                        found: format!("parameter '{other}'"),
+                        span: key_tok.span,
+                    });
+                }
+            }
+            if self.peek_token() == Some(&Token::Comma) {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        self.expect(&Token::RParen)?;
+        let fields = fields.ok_or_else(|| DslError::UnexpectedToken {
+            expected: "fields: [...]".to_string(),
+            found: "@index without fields".to_string(),
+            span: paren_span,
+        })?;
+        Ok(Annotation::Index { fields, unique })
+    }
+
+    /// Parse the optional `("reason")` suffix shared by the schema- and
+    /// field-level `@deprecated` annotations.
+    fn parse_deprecation_reason(&mut self) -> Result<Option<String>, DslError> {
+        if self.peek_token() != Some(&Token::LParen) {
+            return Ok(None);
+        }
+        self.expect(&Token::LParen)?;
+        let reason_tok = self.expect_string_literal()?;
+        self.expect(&Token::RParen)?;
+        Ok(Some(unquote_string(&reason_tok.text)))
+    }
+
     /// Parse named string lists: `key: ["a", "b"], key2: ["c"]`
     fn parse_named_string_lists(&mut self) -> Result<Vec<(String, Vec<String>)>, DslError> {
         let mut result = Vec::new();
@@ -437,6 +723,7 @@ impl Parser {
 
     /// field_def = IDENT ":" type_expr modifier* field_annotation*
     fn parse_field(&mut self) -> Result<FieldDefinition, DslError> {
+        let doc = self.doc_at_cursor();
         let name_tok = self.expect_ident("field name")?;
         let field_name =
             FieldName::new(&name_tok.text).map_err(|_| DslError::InvalidFieldName {
@@ -450,22 +737,17 @@ impl Parser {
         let modifiers = self.parse_modifiers()?;
         let field_annotations = self.parse_field_annotations(&field_type)?;
 
-        if field_annotations.is_empty() {
+        let mut field = if field_annotations.is_empty() {
             if modifiers.is_empty() {
-                Ok(FieldDefinition::new(field_name, field_type))
+                FieldDefinition::new(field_name, field_type)
             } else {
-                Ok(FieldDefinition::with_modifiers(
-                    field_name, field_type, modifiers,
-                ))
+                FieldDefinition::with_modifiers(field_name, field_type, modifiers)
             }
         } else {
-            Ok(FieldDefinition::with_annotations(
-                field_name,
-                field_type,
-                modifiers,
-                field_annotations,
-            ))
-        }
+            FieldDefinition::with_annotations(field_name, field_type, modifiers, field_annotations)
+        };
+        field.doc = doc;
+        Ok(field)
     }
 
     /// Parse zero or more field-level annotations (e.g., `@owner`, `@field_access(...)`).
@@ -516,6 +798,17 @@ impl Parser {
                 Ok(FieldAnnotation::Widget { widget_type })
             }
             "kanban_column" => Ok(FieldAnnotation::KanbanColumn),
+            "immutable" => Ok(FieldAnnotation::Immutable),
+            "computed" => self.parse_computed_annotation(),
+            "empty_as_null" => match field_type {
+                FieldType::Text(_) | FieldType::RichText(_) => Ok(FieldAnnotation::EmptyAsNull),
+                _ => Err(DslError::EmptyAsNullOnNonText {
+                    span: name_tok.span,
+                }),
+            },
+            "deprecated" => Ok(FieldAnnotation::Deprecated {
+                reason: self.parse_deprecation_reason()?,
+            }),
             "list" => {
                 self.expect(&Token::LParen)?;
                 let hint_tok = self.expect_ident("list hint (primary|column|hidden)")?;
@@ -541,8 +834,62 @@ impl Parser {
                         span: value_tok.span.clone(),
                     }
                 })?;
+                let params = if self.peek_token() == Some(&Token::Comma) {
+                    self.advance();
+                    self.parse_named_params()?
+                } else {
+                    Vec::new()
+                };
                 self.expect(&Token::RParen)?;
-                Ok(FieldAnnotation::Format { format_type })
+                let mut locale = None;
+                let mut currency = None;
+                for (key, value) in params {
+                    match key.as_str() {
+                        "locale"
+                            if matches!(
+                                format_type,
+                                FormatType::Currency | FormatType::Percent
+                            ) =>
+                        {
+                            if !is_valid_locale_tag(&value) {
+                                return Err(DslError::UnexpectedToken {
+                                    expected: "a locale tag such as \"de-DE\"".to_string(),
+                                    found: format!("\"{value}\""),
+                                    span: value_tok.span.clone(),
+                                });
+                            }
+                            locale = Some(value);
+                        }
+                        "currency" if format_type == FormatType::Currency => {
+                            if !is_valid_currency_code(&value) {
+                                return Err(DslError::UnexpectedToken {
+                                    expected: "an ISO 4217 currency code such as \"EUR\""
+                                        .to_string(),
+                                    found: format!("\"{value}\""),
+                                    span: value_tok.span.clone(),
+                                });
+                            }
+                            currency = Some(value);
+                        }
+                        _ => {
+                            let expected = match format_type {
+                                FormatType::Currency => "locale or currency",
+                                FormatType::Percent => "locale",
+                                _ => "no options",
+                            };
+                            return Err(DslError::UnexpectedToken {
+                                expected: expected.to_string(),
+                                found: format!("parameter '{key}'"),
+                                span: value_tok.span.clone(),
+                            });
+                        }
+                    }
+                }
+                Ok(FieldAnnotation::Format {
+                    format_type,
+                    locale,
+                    currency,
+                })
             }
             other => Err(DslError::UnknownAnnotation {
                 name: other.to_string(),
@@ -551,6 +898,31 @@ impl Parser {
         }
     }
 
+    /// Parse `@computed(expr: "...")`. The opening `(` has not been
+    /// consumed. The expression must parse; whether the fields it names
+    /// exist is checked by [`check_semantics`](crate::check_semantics).
+    fn parse_computed_annotation(&mut self) -> Result<FieldAnnotation, DslError> {
+        self.expect(&Token::LParen)?;
+        let key_tok = self.expect_ident("'expr'")?;
+        if key_tok.text != "expr" {
+            return Err(DslError::UnexpectedToken {
+                expected: "'expr'".to_string(),
+                found: format!("{} ('{}')", key_tok.token.description(), key_tok.text),
+                span: key_tok.span,
+            });
+        }
+        self.expect(&Token::Colon)?;
+        let value_tok = self.expect_string_literal()?;
+        let expr = ComputedExpr::new(unquote_string(&value_tok.text)).map_err(|e| {
+            DslError::CoreSchemaError {
+                source: e,
+                span: value_tok.span.clone(),
+            }
+        })?;
+        self.expect(&Token::RParen)?;
+        Ok(FieldAnnotation::Computed { expr })
+    }
+
     /// Parse `@enum_colors(variant: "color", ...)`. The opening `(` has not
     /// been consumed. `field_type` is the already-parsed field type used to
     /// validate that every key names a real enum variant.
@@ -568,6 +940,19 @@ impl Parser {
             }
         };
 
+        let colors = self.parse_enum_colors_params(Some(variants), annotation_name_span)?;
+        Ok(FieldAnnotation::EnumColors { colors })
+    }
+
+    /// Parse the `(variant: "color", ...)` list of an `@enum_colors`
+    /// annotation. The opening `(` has not been consumed. When `variants`
+    /// is given every key must be one of them; the schema-level form passes
+    /// `None` and has its keys checked once the fields are known.
+    fn parse_enum_colors_params(
+        &mut self,
+        variants: Option<&[String]>,
+        annotation_name_span: Span,
+    ) -> Result<BTreeMap<String, EnumColor>, DslError> {
         self.expect(&Token::LParen)?;
         let open_span = self.current_span();
         let params = self.parse_named_params()?;
@@ -583,12 +968,14 @@ impl Parser {
         let mut colors = BTreeMap::new();
         let mut seen = HashSet::new();
         for (variant_name, color_token) in params {
-            if !variants.iter().any(|v| v == &variant_name) {
-                return Err(DslError::UnknownEnumColorsVariant {
-                    variant: variant_name,
-                    valid: variants.to_vec(),
-                    span: annotation_name_span.clone(),
-                });
+            if let Some(variants) = variants {
+                if !variants.iter().any(|v| v == &variant_name) {
+                    return Err(DslError::UnknownEnumColorsVariant {
+                        variant: variant_name,
+                        valid: variants.to_vec(),
+                        span: annotation_name_span.clone(),
+                    });
+                }
             }
             if !seen.insert(variant_name.clone()) {
                 return Err(DslError::DuplicateEnumColorsVariant {
@@ -605,21 +992,22 @@ impl Parser {
             colors.insert(variant_name, color);
         }
 
-        Ok(FieldAnnotation::EnumColors { colors })
+        Ok(colors)
     }
 
-    /// type_expr = relation_type | primitive_type ("[]")? | composite_type
+    /// type_expr = relation_type | array_type | primitive_type ("[]")? | composite_type
     fn parse_type(&mut self) -> Result<FieldType, DslError> {
         match self.peek_token() {
             Some(Token::Arrow) => self.parse_relation_type(),
             Some(Token::Composite) => self.parse_composite_type(),
+            Some(Token::Ident) if self.at_array_type() => self.parse_array_type(),
             _ => {
                 let base_type = self.parse_primitive_type()?;
                 // Check for array suffix []
                 if self.peek_token() == Some(&Token::LBracket) {
                     self.advance(); // consume [
                     self.expect(&Token::RBracket)?;
-                    Ok(FieldType::Array(Box::new(base_type)))
+                    Ok(FieldType::array(base_type))
                 } else {
                     Ok(base_type)
                 }
@@ -627,8 +1015,50 @@ impl Parser {
         }
     }
 
-    /// primitive_type = "text" params? | "richtext" | "integer" params? | "float" params?
-    ///                | "boolean" | "datetime" | "enum" "(" string_list ")" | "json"
+    /// True when the parser sits on `array(`. `array` is not a keyword, so
+    /// fields named `array` keep parsing as plain identifiers.
+    fn at_array_type(&self) -> bool {
+        self.peek().is_some_and(|st| st.text == "array")
+            && self.tokens.get(self.pos + 1).map(|st| &st.token) == Some(&Token::LParen)
+    }
+
+    /// array_type = "array" "(" (primitive_type | composite_type) ("," params)? ")"
+    ///
+    /// `params` accepts `min` and `max` element counts.
+    fn parse_array_type(&mut self) -> Result<FieldType, DslError> {
+        self.advance(); // consume `array`
+        let paren_span = self.current_span();
+        self.expect(&Token::LParen)?;
+
+        let element = if self.peek_token() == Some(&Token::Composite) {
+            self.parse_composite_type()?
+        } else {
+            self.parse_primitive_type()?
+        };
+
+        let params = if self.peek_token() == Some(&Token::Comma) {
+            self.advance();
+            self.parse_named_params()?
+        } else {
+            Vec::new()
+        };
+        self.expect(&Token::RParen)?;
+
+        let min_items = extract_u32_param(&params, "min", &paren_span)?;
+        let max_items = extract_u32_param(&params, "max", &paren_span)?;
+        let constraints = ArrayConstraints::with_bounds(min_items, max_items).map_err(|e| {
+            DslError::CoreSchemaError {
+                source: e,
+                span: paren_span,
+            }
+        })?;
+
+        Ok(FieldType::Array(Box::new(element), constraints))
+    }
+
+    /// primitive_type = "text" params? | "richtext" params? | "integer" params? | "float" params?
+    ///                | "decimal" params? | "boolean" | "datetime" | "duration" | "uuid"
+    ///                | "enum" "(" string_list ")" | "json"
     fn parse_primitive_type(&mut self) -> Result<FieldType, DslError> {
         let tok = self
             .advance()
@@ -641,7 +1071,10 @@ impl Parser {
                 let constraints = self.parse_text_params()?;
                 Ok(FieldType::Text(constraints))
             }
-            Token::RichText => Ok(FieldType::RichText),
+            Token::RichText => {
+                let constraints = self.parse_rich_text_params()?;
+                Ok(FieldType::RichText(constraints))
+            }
             Token::Integer => {
                 let constraints = self.parse_integer_params()?;
                 Ok(FieldType::Integer(constraints))
@@ -650,8 +1083,11 @@ impl Parser {
                 let constraints = self.parse_float_params()?;
                 Ok(FieldType::Float(constraints))
             }
+            Token::Decimal => self.parse_decimal_params(),
             Token::Boolean => Ok(FieldType::Boolean),
             Token::DateTime => Ok(FieldType::DateTime),
+            Token::Duration => Ok(FieldType::Duration),
+            Token::Uuid => Ok(FieldType::Uuid),
             Token::Enum => self.parse_enum_type(),
             Token::Json => Ok(FieldType::Json),
             Token::File => {
@@ -659,7 +1095,7 @@ impl Parser {
                 Ok(FieldType::File(constraints))
             }
             _ => Err(DslError::UnexpectedToken {
-                expected: "type name (text, integer, float, boolean, datetime, enum, richtext, json, file, composite, or ->)"
+                expected: "type name (text, integer, float, decimal, boolean, datetime, duration, uuid, enum, richtext, json, file, composite, or ->)"
                     .to_string(),
                 found: format!("{} ('{}')", tok.token.description(), tok.text),
                 span: tok.span,
@@ -667,12 +1103,13 @@ impl Parser {
         }
     }
 
-    /// Parse optional text params: (max: N)
+    /// Parse optional text params: (max: N, pattern: "regex")
     fn parse_text_params(&mut self) -> Result<TextConstraints, DslError> {
         if self.peek_token() != Some(&Token::LParen) {
             return Ok(TextConstraints::unconstrained());
         }
         self.advance(); // consume (
+        let params_start = self.pos;
         let params = self.parse_named_params()?;
         self.expect(&Token::RParen)?;
 
@@ -689,9 +1126,34 @@ impl Parser {
                 }
             })?;
 
-        Ok(match max_length {
+        let constraints = match max_length {
             Some(max) => TextConstraints::with_max_length(max),
             None => TextConstraints::unconstrained(),
+        };
+
+        match params.into_iter().find(|(k, _)| k == "pattern") {
+            Some((_, pattern)) => {
+                if let Err(message) = TextConstraints::check_pattern_syntax(&pattern) {
+                    let span = self
+                        .param_value_span(params_start, "pattern")
+                        .unwrap_or_else(|| self.current_span());
+                    return Err(DslError::InvalidPattern {
+                        pattern,
+                        message,
+                        span,
+                    });
+                }
+                Ok(constraints.and_pattern(pattern))
+            }
+            None => Ok(constraints),
+        }
+    }
+
+    /// Span of the value token for parameter `key` in the named-parameter
+    /// list that starts at token position `from`.
+    fn param_value_span(&self, from: usize, key: &str) -> Option<Span> {
+        self.tokens[from..self.pos].windows(3).find_map(|w| {
+            (w[0].text == key && w[1].token == Token::Colon).then(|| w[2].span.clone())
         })
     }
 
@@ -731,11 +1193,12 @@ impl Parser {
         }
     }
 
-    /// Parse optional float params: (precision: N)
+    /// Parse optional float params: (precision: N, min: X, max: Y)
     fn parse_float_params(&mut self) -> Result<FloatConstraints, DslError> {
         if self.peek_token() != Some(&Token::LParen) {
             return Ok(FloatConstraints::unconstrained());
         }
+        let paren_span = self.current_span();
         self.advance(); // consume (
         let params = self.parse_named_params()?;
         self.expect(&Token::RParen)?;
@@ -753,12 +1216,154 @@ impl Parser {
                 }
             })?;
 
-        Ok(match precision {
-            Some(p) => FloatConstraints::with_precision(p),
-            None => FloatConstraints::unconstrained(),
+        let bound = |key: &str| {
+            params
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+        };
+        let bounds = FloatConstraints::with_bounds(bound("min"), bound("max")).map_err(|e| {
+            DslError::CoreSchemaError {
+                source: e,
+                span: paren_span,
+            }
+        })?;
+
+        Ok(FloatConstraints {
+            precision,
+            ..bounds
         })
     }
 
+    /// Parse optional decimal params: (scale: N). The scale defaults to 2,
+    /// the common case for money.
+    fn parse_decimal_params(&mut self) -> Result<FieldType, DslError> {
+        const DEFAULT_SCALE: u8 = 2;
+        if self.peek_token() != Some(&Token::LParen) {
+            return Ok(FieldType::Decimal {
+                scale: DEFAULT_SCALE,
+            });
+        }
+        self.advance(); // consume (
+        let params_start = self.pos;
+        let params = self.parse_named_params()?;
+        self.expect(&Token::RParen)?;
+
+        let Some((_, raw)) = params.iter().find(|(k, _)| k == "scale") else {
+            return Ok(FieldType::Decimal {
+                scale: DEFAULT_SCALE,
+            });
+        };
+        match raw.parse::<u8>() {
+            Ok(scale) if scale <= MAX_DECIMAL_SCALE => Ok(FieldType::Decimal { scale }),
+            _ => Err(DslError::InvalidDecimalScale {
+                text: raw.clone(),
+                span: self
+                    .param_value_span(params_start, "scale")
+                    .unwrap_or_else(|| self.current_span()),
+            }),
+        }
+    }
+
+    /// Parse optional richtext params:
+    /// `(max_plaintext: N, sanitize: true|false, allowed_tags: ["p", ...])`.
+    ///
+    /// `allowed_tags` narrows what sanitization keeps, so it requires
+    /// `sanitize: true`.
+    fn parse_rich_text_params(&mut self) -> Result<RichTextConstraints, DslError> {
+        if self.peek_token() != Some(&Token::LParen) {
+            return Ok(RichTextConstraints::unconstrained());
+        }
+        let paren_span = self.current_span();
+        self.advance(); // consume (
+
+        let mut constraints = RichTextConstraints::unconstrained();
+        if self.peek_token() != Some(&Token::RParen) {
+            loop {
+                let key_tok = self.expect_ident("richtext parameter name")?;
+                self.expect(&Token::Colon)?;
+                let key_span = key_tok.span.clone();
+
+                match key_tok.text.as_str() {
+                    "max_plaintext" => {
+                        let tok = self
+                            .advance()
+                            .ok_or_else(|| DslError::UnexpectedEndOfInput {
+                                expected: "integer".to_string(),
+                            })?;
+                        let max = tok.text.parse::<u32>().map_err(|_| {
+                            DslError::InvalidIntegerLiteral {
+                                text: tok.text.clone(),
+                                span: tok.span.clone(),
+                            }
+                        })?;
+                        if max == 0 {
+                            return Err(DslError::InvalidRichTextParam {
+                                message: "max_plaintext must be greater than zero".to_string(),
+                                span: tok.span,
+                            });
+                        }
+                        constraints.max_plaintext_length = Some(max);
+                    }
+                    "sanitize" => {
+                        let tok = self
+                            .advance()
+                            .ok_or_else(|| DslError::UnexpectedEndOfInput {
+                                expected: "true or false".to_string(),
+                            })?;
+                        constraints.sanitize = match tok.token {
+                            Token::True => true,
+                            Token::False => false,
+                            _ => {
+                                return Err(DslError::UnexpectedToken {
+                                    expected: "true or false".to_string(),
+                                    found: format!("{} ('{}')", tok.token.description(), tok.text),
+                                    span: tok.span,
+                                });
+                            }
+                        };
+                    }
+                    "allowed_tags" => {
+                        let tags = self.parse_string_list()?;
+                        if let Some(tag) = tags
+                            .iter()
+                            .find(|t| matches!(t.to_ascii_lowercase().as_str(), "script" | "style"))
+                        {
+                            return Err(DslError::InvalidRichTextParam {
+                                message: format!("allowed_tags must not include \"{tag}\""),
+                                span: key_span,
+                            });
+                        }
+                        constraints.allowed_tags = Some(tags);
+                    }
+                    other => {
+                        return Err(DslError::InvalidRichTextParam {
+                            message: format!(
+                                "unknown richtext parameter \"{other}\" (expected max_plaintext, sanitize, allowed_tags)"
+                            ),
+                            span: key_span,
+                        });
+                    }
+                }
+
+                if self.peek_token() == Some(&Token::Comma) {
+                    self.advance();
+                } else {
+                    break;
+                }
+            }
+        }
+        self.expect(&Token::RParen)?;
+
+        if constraints.allowed_tags.is_some() && !constraints.sanitize {
+            return Err(DslError::InvalidRichTextParam {
+                message: "allowed_tags requires sanitize: true".to_string(),
+                span: paren_span,
+            });
+        }
+        Ok(constraints)
+    }
+
     /// Parse file params: `(bucket: "...", max_size: <int|"<N><SIZE_SUFFIX>">, mime: [...], access: "presigned"|"proxied")`.
     ///
     /// `bucket`, `max_size`, and `mime` are required. `access` defaults to `presigned`.
@@ -1058,6 +1663,13 @@ impl Parser {
                     self.advance();
                     modifiers.push(FieldModifier::Indexed);
                 }
+                // `unique:` starts the next field, not a modifier.
+                Some(Token::Unique)
+                    if self.tokens.get(self.pos + 1).map(|st| &st.token) != Some(&Token::Colon) =>
+                {
+                    self.advance();
+                    modifiers.push(FieldModifier::Unique);
+                }
                 Some(Token::Default) => {
                     self.advance();
                     let default_value = self.parse_default_value()?;
@@ -1073,6 +1685,7 @@ impl Parser {
     }
 
     /// Parse a default value: "default" already consumed, expects "(" literal ")"
+    /// or `"(" sequence "(" string "," integer ")" ")"`
     fn parse_default_value(&mut self) -> Result<DefaultValue, DslError> {
         self.expect(&Token::LParen)?;
 
@@ -1096,9 +1709,21 @@ impl Parser {
             }
             Token::True => DefaultValue::Boolean(true),
             Token::False => DefaultValue::Boolean(false),
+            Token::Ident if tok.text == "sequence" => {
+                self.expect(&Token::LParen)?;
+                let prefix_tok = self.expect_string_literal()?;
+                self.expect(&Token::Comma)?;
+                let start_tok = self.expect_integer_literal()?;
+                self.expect(&Token::RParen)?;
+                DefaultValue::Sequence {
+                    prefix: unquote_string(&prefix_tok.text),
+                    start: parse_i64(&start_tok.text, &start_tok.span)?,
+                }
+            }
             _ => {
                 return Err(DslError::UnexpectedToken {
-                    expected: "default value (string, integer, float, or boolean)".to_string(),
+                    expected: "default value (string, integer, float, boolean, or sequence)"
+                        .to_string(),
                     found: format!("{} ('{}')", tok.token.description(), tok.text),
                     span: tok.span,
                 });
@@ -1181,22 +1806,27 @@ fn is_contextual_ident(token: &Token) -> bool {
         Token::Text
             | Token::Integer
             | Token::Float
+            | Token::Decimal
             | Token::Boolean
             | Token::DateTime
+            | Token::Duration
+            | Token::Uuid
             | Token::Json
             | Token::Default
             | Token::Required
             | Token::Indexed
+            | Token::Unique
             | Token::Schema
     )
 }
 
 /// Strip the surrounding `"""` delimiters from a triple-quoted string literal.
 /// No escape processing — the body is returned verbatim, preserving newlines
-/// and embedded double quotes (up to two in a row).
+/// and embedded double quotes (up to two in a row). CRLF and lone CR line
+/// endings are normalized to LF so the value matches an LF-authored file.
 fn unquote_triple_string(s: &str) -> String {
     debug_assert!(s.starts_with("\"\"\"") && s.ends_with("\"\"\"") && s.len() >= 6);
-    s[3..s.len() - 3].to_string()
+    s[3..s.len() - 3].replace("\r\n", "\n").replace('\r', "\n")
 }
 
 /// Remove surrounding quotes from a string literal and handle escape sequences.
@@ -1299,6 +1929,31 @@ fn extract_i64_param(
         .transpose()
 }
 
+fn extract_u32_param(
+    params: &[(String, String)],
+    key: &str,
+    span: &Span,
+) -> Result<Option<u32>, DslError> {
+    params
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| {
+            v.parse::<u32>()
+                .map_err(|_| DslError::InvalidIntegerLiteral {
+                    text: v.clone(),
+                    span: span.clone(),
+                })
+        })
+        .transpose()
+}
+
+/// Text of one `///` line without the marker and the single space that
+/// conventionally follows it.
+fn doc_comment_line(text: &str) -> &str {
+    let line = text.strip_prefix("///").unwrap_or(text);
+    line.strip_prefix(' ').unwrap_or(line)
+}
+
 /// Parse DSL source text into a list of schema definitions.
 ///
 /// # Errors
@@ -1313,9 +1968,30 @@ pub fn parse(source: &str) -> Result<Vec<SchemaDefinition>, Vec<DslError>> {
     parser.parse_file()
 }
 
+/// Parse DSL source text, reporting every error instead of only failing.
+///
+/// Lexer errors do not stop parsing: unmatched input is dropped and the
+/// remaining tokens are parsed. After a syntax or validation error the
+/// parser skips to the next top-level `schema` or `@` and carries on, so a
+/// file with several broken schemas yields one error per schema. Schemas
+/// that parsed cleanly are returned alongside the errors, in source order.
+/// Errors come lexer-first, then in source order.
+#[instrument(skip(source), fields(source_len = source.len()))]
+pub fn parse_all_errors(source: &str) -> (Vec<SchemaDefinition>, Vec<DslError>) {
+    let (tokens, mut errors) = match crate::lexer::tokenize(source) {
+        Ok(tokens) => (tokens, Vec::new()),
+        Err(lex_errors) => (crate::lexer::tokenize_lossy(source), lex_errors),
+    };
+    let mut parser = Parser::new(tokens);
+    let (schemas, parse_errors) = parser.parse_file_recovering();
+    errors.extend(parse_errors);
+    (schemas, errors)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use schema_forge_core::error::SchemaError;
 
     // -- Helper --
     fn parse_one(source: &str) -> SchemaDefinition {
@@ -1398,6 +2074,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_float_with_bounds() {
+        let schema = parse_one("schema S { ratio: float(precision: 2, min: -1, max: 0.75) }");
+        match &schema.fields[0].field_type {
+            FieldType::Float(c) => {
+                assert_eq!(c.precision, Some(2));
+                assert_eq!(c.min.as_deref(), Some("-1"));
+                assert_eq!(c.max.as_deref(), Some("0.75"));
+            }
+            other => panic!("expected Float, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_float_rejects_inverted_bounds() {
+        let errors = parse("schema S { ratio: float(min: 1.5, max: 0.5) }").unwrap_err();
+        assert!(matches!(
+            &errors[0],
+            DslError::CoreSchemaError {
+                source: SchemaError::InvalidFloatRange { .. },
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn parse_boolean() {
         let schema = parse_one("schema S { active: boolean }");
@@ -1410,10 +2111,87 @@ mod tests {
         assert!(matches!(schema.fields[0].field_type, FieldType::DateTime));
     }
 
+    #[test]
+    fn parse_duration() {
+        let schema = parse_one("schema Task { planned_duration: duration duration: integer }");
+        assert_eq!(schema.fields[0].field_type, FieldType::Duration);
+        // `duration` stays usable as a field name.
+        assert_eq!(schema.fields[1].name.as_str(), "duration");
+    }
+
+    #[test]
+    fn parse_uuid() {
+        let schema = parse_one("schema Account { external_id: uuid uuid: text }");
+        assert_eq!(schema.fields[0].field_type, FieldType::Uuid);
+        // `uuid` stays usable as a field name.
+        assert_eq!(schema.fields[1].name.as_str(), "uuid");
+    }
+
+    #[test]
+    fn parse_decimal() {
+        let schema =
+            parse_one("schema Invoice { total: decimal(scale: 2) rate: decimal(scale: 4) fee: decimal decimal: text }");
+        assert_eq!(schema.fields[0].field_type, FieldType::Decimal { scale: 2 });
+        assert_eq!(schema.fields[1].field_type, FieldType::Decimal { scale: 4 });
+        assert_eq!(schema.fields[2].field_type, FieldType::Decimal { scale: 2 });
+        assert_eq!(schema.fields[3].name.as_str(), "decimal");
+    }
+
+    #[test]
+    fn parse_decimal_rejects_out_of_range_scale() {
+        let source = "schema Invoice { total: decimal(scale: 29) }";
+        let errors = parse(source).unwrap_err();
+        match &errors[0] {
+            DslError::InvalidDecimalScale { text, span } => {
+                assert_eq!(text, "29");
+                assert_eq!(&source[span.start..span.end], "29");
+            }
+            other => panic!("expected InvalidDecimalScale, got {other:?}"),
+        }
+        assert!(matches!(
+            parse("schema Invoice { total: decimal(scale: -1) }").unwrap_err()[0],
+            DslError::InvalidDecimalScale { .. }
+        ));
+    }
+
     #[test]
     fn parse_richtext() {
         let schema = parse_one("schema S { body: richtext }");
-        assert!(matches!(schema.fields[0].field_type, FieldType::RichText));
+        assert!(matches!(
+            schema.fields[0].field_type,
+            FieldType::RichText(_)
+        ));
+    }
+
+    #[test]
+    fn parse_richtext_params() {
+        let schema = parse_one(
+            r#"schema S { body: richtext(max_plaintext: 2000, sanitize: true, allowed_tags: ["p", "a"]) }"#,
+        );
+        assert_eq!(
+            schema.fields[0].field_type,
+            FieldType::RichText(RichTextConstraints {
+                max_plaintext_length: Some(2000),
+                sanitize: true,
+                allowed_tags: Some(vec!["p".into(), "a".into()]),
+            })
+        );
+    }
+
+    #[test]
+    fn error_richtext_invalid_params() {
+        for source in [
+            r#"schema S { body: richtext(allowed_tags: ["p"]) }"#,
+            r#"schema S { body: richtext(sanitize: true, allowed_tags: ["script"]) }"#,
+            "schema S { body: richtext(max_plaintext: 0) }",
+            "schema S { body: richtext(max: 10) }",
+        ] {
+            let errors = parse(source).expect_err(source);
+            assert!(
+                matches!(errors[0], DslError::InvalidRichTextParam { .. }),
+                "{source}: {errors:?}"
+            );
+        }
     }
 
     #[test]
@@ -1478,28 +2256,120 @@ mod tests {
         }
     }
 
+    // -- Line endings and BOM --
+
+    const LF_SOURCE: &str = "@hook(before_change) \"\"\"Check\nthe name\"\"\"\nschema Contact {\n    name: text required // display name\n    tags: text[]\n}\n";
+
+    fn assert_same_schemas(a: &[SchemaDefinition], b: &[SchemaDefinition]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert_eq!(x.name, y.name);
+            assert_eq!(x.fields, y.fields);
+            assert_eq!(x.annotations, y.annotations);
+        }
+    }
+
+    #[test]
+    fn parse_bom_prefixed_source_matches_lf() {
+        let lf = parse(LF_SOURCE).unwrap();
+        let bom = parse(&format!("\u{FEFF}{LF_SOURCE}")).unwrap();
+        assert_same_schemas(&lf, &bom);
+    }
+
+    #[test]
+    fn parse_crlf_and_cr_sources_match_lf() {
+        let lf = parse(LF_SOURCE).unwrap();
+        let crlf = parse(&LF_SOURCE.replace('\n', "\r\n")).unwrap();
+        let cr = parse(&LF_SOURCE.replace('\n', "\r")).unwrap();
+        assert_same_schemas(&lf, &crlf);
+        assert_same_schemas(&lf, &cr);
+        assert!(matches!(
+            &crlf[0].annotations[0],
+            Annotation::Hook { intent, .. } if intent == "Check\nthe name"
+        ));
+    }
+
+    #[test]
+    fn parse_error_spans_point_at_same_place_across_line_endings() {
+        let lf = "schema S {\n    name: text\n    age: nope\n}\n";
+        let variants = [
+            lf.to_string(),
+            lf.replace('\n', "\r\n"),
+            format!("\u{FEFF}{}", lf.replace('\n', "\r\n")),
+        ];
+        for source in &variants {
+            let errors = parse(source).unwrap_err();
+            let span = match &errors[0] {
+                DslError::UnexpectedToken { span, .. } => span.clone(),
+                other => panic!("expected UnexpectedToken, got {other:?}"),
+            };
+            assert_eq!(&source[span.start..span.end], "nope");
+            assert_eq!(span.line_col(source), (3, 10));
+        }
+    }
+
     // -- Arrays --
 
     #[test]
     fn parse_text_array() {
         let schema = parse_one("schema S { tags: text[] }");
         match &schema.fields[0].field_type {
-            FieldType::Array(inner) => {
+            FieldType::Array(inner, _) => {
+                assert!(matches!(inner.as_ref(), FieldType::Text(_)));
+            }
+            other => panic!("expected Array, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_integer_array() {
+        let schema = parse_one("schema S { scores: integer[] }");
+        match &schema.fields[0].field_type {
+            FieldType::Array(inner, _) => {
+                assert!(matches!(inner.as_ref(), FieldType::Integer(_)));
+            }
+            other => panic!("expected Array, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_array_with_length_constraints() {
+        let schema = parse_one("schema S { tags: array(text, min: 1, max: 10) }");
+        match &schema.fields[0].field_type {
+            FieldType::Array(inner, constraints) => {
                 assert!(matches!(inner.as_ref(), FieldType::Text(_)));
+                assert_eq!(constraints.min_items, Some(1));
+                assert_eq!(constraints.max_items, Some(10));
             }
             other => panic!("expected Array, got {other:?}"),
         }
     }
 
     #[test]
-    fn parse_integer_array() {
-        let schema = parse_one("schema S { scores: integer[] }");
-        match &schema.fields[0].field_type {
-            FieldType::Array(inner) => {
-                assert!(matches!(inner.as_ref(), FieldType::Integer(_)));
+    fn parse_array_without_constraints() {
+        let schema = parse_one("schema S { scores: array(integer(min: 0)) }");
+        assert_eq!(
+            schema.fields[0].field_type,
+            FieldType::array(FieldType::Integer(IntegerConstraints::with_min(0)))
+        );
+    }
+
+    #[test]
+    fn parse_array_rejects_inverted_bounds() {
+        let errors = parse("schema S { tags: array(text, min: 5, max: 2) }").unwrap_err();
+        assert!(matches!(
+            &errors[0],
+            DslError::CoreSchemaError {
+                source: SchemaError::InvalidArrayLength { min: 5, max: 2 },
+                ..
             }
-            other => panic!("expected Array, got {other:?}"),
-        }
+        ));
+    }
+
+    #[test]
+    fn field_named_array_still_parses() {
+        let schema = parse_one("schema S { array: text }");
+        assert_eq!(schema.fields[0].name.as_str(), "array");
     }
 
     // -- Composites --
@@ -1541,6 +2411,15 @@ mod tests {
         assert!(schema.fields[0].is_indexed());
     }
 
+    #[test]
+    fn parse_unique() {
+        let schema = parse_one("schema S { email: text required unique unique: boolean }");
+        assert!(schema.fields[0].is_unique());
+        assert!(schema.fields[0].is_required());
+        assert_eq!(schema.fields[1].name.as_str(), "unique");
+        assert!(!schema.fields[1].is_unique());
+    }
+
     #[test]
     fn parse_multiple_modifiers() {
         let schema = parse_one("schema S { email: text required indexed }");
@@ -1584,6 +2463,23 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parse_default_sequence() {
+        let schema = parse_one(r#"schema S { number: text default(sequence("INV-", 1000)) }"#);
+        assert_eq!(
+            schema.fields[0].default_value(),
+            Some(&DefaultValue::Sequence {
+                prefix: "INV-".into(),
+                start: 1000
+            })
+        );
+    }
+
+    #[test]
+    fn parse_default_sequence_requires_start() {
+        assert!(parse(r#"schema S { number: text default(sequence("INV-")) }"#).is_err());
+    }
+
     #[test]
     fn parse_default_boolean_false() {
         let schema = parse_one("schema S { active: boolean default(false) }");
@@ -1618,6 +2514,219 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_ref_label_annotation() {
+        let schema =
+            parse_one(r#"@ref_label("{name} ({code})") schema Company { name: text code: text }"#);
+        match &schema.annotations[0] {
+            Annotation::RefLabel { template } => {
+                assert_eq!(template.as_str(), "{name} ({code})");
+                assert_eq!(template.fields(), vec!["name", "code"]);
+            }
+            other => panic!("expected RefLabel, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_ref_label_rejects_malformed_template() {
+        let result = parse(r#"@ref_label("{name") schema Company { name: text }"#);
+        assert!(matches!(
+            result.unwrap_err().first(),
+            Some(DslError::CoreSchemaError { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_id_prefix_annotation() {
+        let schema = parse_one(r#"@id_prefix("inv") schema Invoice { number: text }"#);
+        assert_eq!(schema.declared_id_prefix(), Some("inv"));
+        assert!(schema.new_entity_id().as_str().starts_with("inv_"));
+    }
+
+    #[test]
+    fn parse_id_prefix_rejects_invalid_prefix() {
+        let result = parse(r#"@id_prefix("Inv1") schema Invoice { number: text }"#);
+        assert!(matches!(
+            result.unwrap_err().first(),
+            Some(DslError::CoreSchemaError {
+                source: SchemaError::InvalidIdPrefix { .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_empty_as_null_annotations() {
+        let schema =
+            parse_one("@empty_as_null schema Contact { name: text nickname: text @empty_as_null }");
+        assert_eq!(schema.annotations, vec![Annotation::EmptyAsNull]);
+        assert!(schema.fields[1].is_empty_as_null());
+        assert!(!schema.fields[0].is_empty_as_null());
+    }
+
+    #[test]
+    fn parse_soft_delete_annotation() {
+        let schema = parse_one("@soft_delete schema Contact { name: text }");
+        assert_eq!(schema.annotations, vec![Annotation::SoftDelete]);
+        assert!(schema.is_soft_delete());
+    }
+
+    #[test]
+    fn parse_index_annotations() {
+        let schema = parse_one(
+            r#"@index(fields: ["agency", "stage"], unique: true)
+               @index(fields: ["stage"])
+               schema Deal { agency: text stage: text }"#,
+        );
+        assert_eq!(
+            schema.annotations,
+            vec![
+                Annotation::Index {
+                    fields: vec![
+                        FieldName::new("agency").unwrap(),
+                        FieldName::new("stage").unwrap(),
+                    ],
+                    unique: true,
+                },
+                Annotation::Index {
+                    fields: vec![FieldName::new("stage").unwrap()],
+                    unique: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn error_index_unknown_field_or_parameter() {
+        let result = parse(r#"@index(fields: ["missing"]) schema Deal { stage: text }"#);
+        assert!(matches!(
+            result.unwrap_err().first(),
+            Some(DslError::CoreSchemaError {
+                source: SchemaError::InvalidIndex { .. },
+                ..
+            })
+        ));
+
+        let result =
+            parse(r#"@index(fields: ["stage"], sparse: true) schema Deal { stage: text }"#);
+        assert!(matches!(
+            result.unwrap_err().first(),
+            Some(DslError::UnexpectedToken { .. })
+        ));
+
+        let result = parse("@index(unique: true) schema Deal { stage: text }");
+        assert!(matches!(
+            result.unwrap_err().first(),
+            Some(DslError::UnexpectedToken { .. })
+        ));
+    }
+
+    #[test]
+    fn error_empty_as_null_on_non_text() {
+        let result = parse("schema S { count: integer @empty_as_null }");
+        let errors = result.expect_err("empty_as_null on non-text must be rejected");
+        assert!(matches!(errors[0], DslError::EmptyAsNullOnNonText { .. }));
+    }
+
+    #[test]
+    fn parse_immutable_annotation() {
+        let schema = parse_one("schema Account { sku: text @immutable name: text }");
+        assert_eq!(
+            schema.fields[0].annotations,
+            vec![FieldAnnotation::Immutable]
+        );
+        assert!(schema.fields[0].is_immutable());
+        assert!(!schema.fields[1].is_immutable());
+    }
+
+    #[test]
+    fn parse_computed_annotation() {
+        let schema = parse_one(
+            r#"schema Deal { price: float cost: float margin: float @computed(expr: "price - cost") }"#,
+        );
+        let margin = &schema.fields[2];
+        assert!(margin.is_computed());
+        assert!(!margin.is_stored());
+        assert_eq!(margin.computed_expr().unwrap().as_str(), "price - cost");
+        assert!(!schema.fields[0].is_computed());
+    }
+
+    #[test]
+    fn error_invalid_computed_expression() {
+        let errors = parse(r#"schema S { a: float b: float @computed(expr: "a +") }"#)
+            .expect_err("a dangling operator must be rejected");
+        assert!(matches!(
+            &errors[0],
+            DslError::CoreSchemaError {
+                source: SchemaError::InvalidComputedExpr { .. },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_list_columns_annotation() {
+        let schema = parse_one(
+            "@list_columns(title, stage, value) schema Deal { title: text stage: text value: integer notes: richtext }",
+        );
+        let columns: Vec<&str> = schema
+            .list_columns()
+            .unwrap()
+            .iter()
+            .map(FieldName::as_str)
+            .collect();
+        assert_eq!(columns, vec!["title", "stage", "value"]);
+    }
+
+    #[test]
+    fn parse_list_columns_rejects_unknown_field() {
+        let errors = parse("@list_columns(title, stage) schema Deal { title: text }").unwrap_err();
+        assert!(matches!(
+            &errors[0],
+            DslError::UnknownListColumn { field, .. } if field == "stage"
+        ));
+    }
+
+    #[test]
+    fn parse_list_columns_rejects_duplicate_field() {
+        let errors = parse("@list_columns(title, title) schema Deal { title: text }").unwrap_err();
+        assert!(matches!(
+            &errors[0],
+            DslError::DuplicateListColumn { field, .. } if field == "title"
+        ));
+    }
+
+    #[test]
+    fn parse_pagination_annotation() {
+        let schema =
+            parse_one("@list(page_size: 50, max_page_size: 200) schema Deal { title: text }");
+        assert_eq!(schema.page_size(), Some(50));
+        assert_eq!(schema.max_page_size(), Some(200));
+
+        let schema = parse_one("@list(max_page_size: 100) schema Deal { title: text }");
+        assert_eq!(schema.page_size(), None);
+        assert_eq!(schema.max_page_size(), Some(100));
+    }
+
+    #[test]
+    fn parse_pagination_rejects_bad_sizes() {
+        let errors = parse("@list(page_size: 300, max_page_size: 200) schema Deal { title: text }")
+            .unwrap_err();
+        assert!(matches!(
+            &errors[0],
+            DslError::CoreSchemaError {
+                source: SchemaError::InvalidPageSize { .. },
+                ..
+            }
+        ));
+
+        let errors = parse("@list(size: 20) schema Deal { title: text }").unwrap_err();
+        assert!(matches!(
+            &errors[0],
+            DslError::UnexpectedToken { found, .. } if found == "parameter 'size'"
+        ));
+    }
+
     #[test]
     fn parse_multiple_annotations() {
         let schema = parse_one(
@@ -1936,6 +3045,48 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn error_annotation_after_closing_brace() {
+        let result = parse("schema S { name: text } @version(2)");
+        let errors = result.unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            DslError::AnnotationWithoutSchema { span } if span.start == 24
+        ));
+    }
+
+    #[test]
+    fn error_missing_schema_keyword() {
+        let result = parse("@version(1) Contact { name: text }");
+        let errors = result.unwrap_err();
+        assert!(matches!(
+            &errors[0],
+            DslError::MissingSchemaKeyword { name, .. } if name == "Contact"
+        ));
+    }
+
+    #[test]
+    fn error_field_outside_schema() {
+        let result = parse("schema S { name: text }\n  email: text\n}");
+        let errors = result.unwrap_err();
+        assert!(matches!(
+            &errors[0],
+            DslError::FieldOutsideSchema { name, .. } if name == "email"
+        ));
+    }
+
+    #[test]
+    fn error_stray_token_between_schemas() {
+        let result = parse(r#"schema A { x: text } "oops" schema B { y: text }"#);
+        let errors = result.unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            DslError::StrayTopLevelToken { found, .. } if found.contains("oops")
+        ));
+    }
+
     #[test]
     fn error_invalid_tenant_arg() {
         let result = parse("@tenant(invalid) schema S { name: text }");
@@ -2015,6 +3166,23 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parse_deprecated_annotations() {
+        let schema = parse_one(
+            r#"@deprecated("replaced by Account") schema S {
+                fax: text @deprecated
+                email: text required @deprecated("use contact_email")
+            }"#,
+        );
+        assert_eq!(schema.deprecation(), Some(Some("replaced by Account")));
+        assert_eq!(schema.fields[0].deprecation(), Some(None));
+        assert_eq!(
+            schema.fields[1].deprecation(),
+            Some(Some("use contact_email"))
+        );
+        assert!(schema.fields[1].is_required());
+    }
+
     #[test]
     fn parse_field_with_widget_and_modifiers() {
         let schema = parse_one(r#"schema S { status: text required @widget("status_badge") }"#);
@@ -2049,7 +3217,7 @@ mod tests {
         let schema = parse_one(r#"schema S { price: float @format("currency") }"#);
         assert_eq!(schema.fields[0].annotations.len(), 1);
         match &schema.fields[0].annotations[0] {
-            FieldAnnotation::Format { format_type } => {
+            FieldAnnotation::Format { format_type, .. } => {
                 assert_eq!(*format_type, FormatType::Currency);
             }
             other => panic!("expected Format, got {other:?}"),
@@ -2060,13 +3228,47 @@ mod tests {
     fn parse_format_percent() {
         let schema = parse_one(r#"schema S { rate: float @format("percent") }"#);
         match &schema.fields[0].annotations[0] {
-            FieldAnnotation::Format { format_type } => {
+            FieldAnnotation::Format { format_type, .. } => {
                 assert_eq!(*format_type, FormatType::Percent);
             }
             other => panic!("expected Format, got {other:?}"),
         }
     }
 
+    #[test]
+    fn parse_format_locale_options() {
+        let schema = parse_one(
+            r#"schema S { price: decimal(scale: 2) @format("currency", currency: "EUR", locale: "de-DE") }"#,
+        );
+        assert_eq!(
+            schema.fields[0].annotations[0],
+            FieldAnnotation::Format {
+                format_type: FormatType::Currency,
+                locale: Some("de-DE".into()),
+                currency: Some("EUR".into()),
+            }
+        );
+
+        let schema = parse_one(r#"schema S { rate: float @format("percent", locale: "fr-FR") }"#);
+        assert_eq!(schema.fields[0].format_locale(), Some("fr-FR"));
+    }
+
+    #[test]
+    fn error_format_locale_options_rejected() {
+        for source in [
+            r#"schema S { price: float @format("currency", locale: "de_DE") }"#,
+            r#"schema S { price: float @format("currency", currency: "€") }"#,
+            r#"schema S { rate: float @format("percent", currency: "EUR") }"#,
+            r#"schema S { at: datetime @format("date", locale: "de-DE") }"#,
+            r#"schema S { price: float @format("currency", symbol: "$") }"#,
+        ] {
+            assert!(
+                matches!(parse(source), Err(errs) if matches!(errs[0], DslError::UnexpectedToken { .. })),
+                "{source} must be rejected"
+            );
+        }
+    }
+
     #[test]
     fn parse_format_annotation_all_canonical_variants() {
         // Every variant of FormatType must parse successfully.
@@ -2074,7 +3276,7 @@ mod tests {
             let source = format!(r#"schema S {{ f: float @format("{}") }}"#, variant.as_str());
             let schema = parse_one(&source);
             match &schema.fields[0].annotations[0] {
-                FieldAnnotation::Format { format_type } => {
+                FieldAnnotation::Format { format_type, .. } => {
                     assert_eq!(
                         *format_type,
                         *variant,
@@ -2195,6 +3397,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_schema_level_enum_colors() {
+        let schema = parse_one(
+            r#"@enum_colors(open: "blue", won: "green")
+schema Deal {
+    stage: enum("open", "won", "lost")
+    review: enum("open", "closed")
+}"#,
+        );
+        assert_eq!(
+            schema.enum_colors(),
+            Some(&BTreeMap::from([
+                ("open".to_string(), EnumColor::Blue),
+                ("won".to_string(), EnumColor::Green),
+            ]))
+        );
+    }
+
+    #[test]
+    fn error_schema_level_enum_colors_unknown_variant() {
+        let errors = parse(
+            r#"@enum_colors(pending: "amber")
+schema Deal { stage: enum("open", "won") }"#,
+        )
+        .unwrap_err();
+        match &errors[0] {
+            DslError::UnknownEnumColorsVariant { variant, valid, .. } => {
+                assert_eq!(variant, "pending");
+                assert_eq!(valid, &vec!["open".to_string(), "won".to_string()]);
+            }
+            other => panic!("expected UnknownEnumColorsVariant, got {other:?}"),
+        }
+
+        let errors = parse(
+            r#"@enum_colors(open: "orange")
+schema Deal { stage: enum("open", "won") }"#,
+        )
+        .unwrap_err();
+        assert!(matches!(errors[0], DslError::UnknownEnumColor { .. }));
+    }
+
     #[test]
     fn parse_enum_colors_all_color_variants() {
         for color in EnumColor::VARIANTS {
@@ -2724,4 +3967,148 @@ schema B { title: text @list(primary) }"#,
                 .any(|m| matches!(m, FieldModifier::Required))
         );
     }
+
+    // -- Doc comments --
+
+    #[test]
+    fn parse_doc_comments_on_schema_and_fields() {
+        let schema = parse_one(
+            "/// People we sell to.
+/// Imported nightly.
+@version(1)
+schema Contact {
+    /// Full legal name.
+    name: text required
+    email: text
+    ///Preferred channel.
+    channel: composite {
+        /// E.g. \"sms\".
+        kind: text
+    }
+}",
+        );
+        assert_eq!(
+            schema.doc.as_deref(),
+            Some("People we sell to.\nImported nightly.")
+        );
+        assert_eq!(schema.fields[0].doc.as_deref(), Some("Full legal name."));
+        assert_eq!(schema.fields[1].doc, None);
+        assert_eq!(schema.fields[2].doc.as_deref(), Some("Preferred channel."));
+        let FieldType::Composite(sub) = &schema.fields[2].field_type else {
+            panic!("expected composite");
+        };
+        assert_eq!(sub[0].doc.as_deref(), Some("E.g. \"sms\"."));
+    }
+
+    #[test]
+    fn parse_doc_comment_before_closing_brace_is_dropped() {
+        let schemas = parse(
+            "schema A {
+    name: text
+    /// dangling
+}
+schema B {
+    title: text
+}",
+        )
+        .unwrap();
+        assert_eq!(schemas[0].fields[0].doc, None);
+        assert_eq!(schemas[1].doc, None);
+        assert_eq!(schemas[1].fields[0].doc, None);
+    }
+
+    #[test]
+    fn parse_empty_doc_line_keeps_paragraph_break() {
+        let schema = parse_one("/// Summary.\n///\n/// Details.\nschema S { x: text }");
+        assert_eq!(schema.doc.as_deref(), Some("Summary.\n\nDetails."));
+    }
+
+    // -- Error collection --
+
+    #[test]
+    fn parse_all_errors_reports_each_broken_schema_and_keeps_good_ones() {
+        let source = "schema A { name: text }
+schema B { name: txet }
+schema C { title: text }
+schema D { count integer }
+schema E { done: boolean }
+";
+        let (schemas, errors) = parse_all_errors(source);
+        let names: Vec<&str> = schemas.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["A", "C", "E"]);
+        assert_eq!(errors.len(), 2, "got: {errors:?}");
+
+        let lines: Vec<usize> = errors
+            .iter()
+            .map(|e| match e {
+                DslError::UnexpectedToken { span, .. } => span.line_col(source).0,
+                other => panic!("expected UnexpectedToken, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(lines, vec![2, 4]);
+    }
+
+    #[test]
+    fn parse_all_errors_continues_past_lexer_errors() {
+        let source = "schema A { name: text # }\nschema B { x: nope }";
+        let (schemas, errors) = parse_all_errors(source);
+        assert_eq!(schemas.len(), 1);
+        assert_eq!(schemas[0].name.as_str(), "A");
+        assert!(matches!(
+            errors[0],
+            DslError::UnexpectedCharacter { found: '#', .. }
+        ));
+        assert!(matches!(errors[1], DslError::UnexpectedToken { .. }));
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn parse_all_errors_on_valid_source_matches_parse() {
+        let source = "schema A { name: text }\nschema B { id: integer }";
+        let (schemas, errors) = parse_all_errors(source);
+        assert!(errors.is_empty());
+        let expected = parse(source).unwrap();
+        assert_eq!(schemas.len(), expected.len());
+        assert_eq!(schemas[1].fields, expected[1].fields);
+    }
+
+    // -- Text patterns --
+
+    #[test]
+    fn parse_text_pattern() {
+        let schema = parse_one(r#"schema S { sku: text(max: 20, pattern: "^[A-Z]{3}-\\d{4}$") }"#);
+        match &schema.fields[0].field_type {
+            FieldType::Text(c) => {
+                assert_eq!(c.max_length, Some(20));
+                assert_eq!(c.pattern.as_deref(), Some(r"^[A-Z]{3}-\d{4}$"));
+            }
+            other => panic!("expected Text, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_text_pattern_without_max() {
+        let schema = parse_one(r#"schema S { code: text(pattern: "^\d+$") }"#);
+        match &schema.fields[0].field_type {
+            FieldType::Text(c) => {
+                assert_eq!(c.max_length, None);
+                assert_eq!(c.pattern.as_deref(), Some(r"^\d+$"));
+            }
+            other => panic!("expected Text, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_rejects_invalid_text_pattern_at_its_span() {
+        let source = r#"schema S { code: text(max: 5, pattern: "[a-") }"#;
+        let errors = parse(source).unwrap_err();
+        let start = source.find(r#""[a-""#).unwrap();
+        match &errors[0] {
+            DslError::InvalidPattern { pattern, span, .. } => {
+                assert_eq!(pattern, "[a-");
+                assert_eq!(*span, Span::new(start, start + 5));
+            }
+            other => panic!("expected InvalidPattern, got {other:?}"),
+        }
+    }
 }
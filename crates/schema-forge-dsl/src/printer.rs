@@ -5,29 +5,113 @@ use schema_forge_core::types::{
 #[cfg(test)]
 use schema_forge_core::types::{FormatType, WidgetType};
 
+/// Layout options for [`print_with`] and [`print_all_with`].
+///
+/// The defaults reproduce [`print`] and [`print_all`]: 4-space
+/// indentation, fields in declaration order, one space after each field
+/// name's colon, and schemas in input order separated by one blank line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// Spaces per nesting level (schema body, composite bodies).
+    pub indent: usize,
+    /// Print fields in alphabetical order instead of declaration order.
+    /// Applies to composite sub-fields as well.
+    pub sort_fields: bool,
+    /// Pad field names so the types within each block start in the same
+    /// column.
+    pub align_field_types: bool,
+    /// Blank lines between consecutive schemas in [`print_all_with`].
+    pub blank_lines_between: usize,
+    /// Order of schemas in [`print_all_with`].
+    pub grouping: SchemaGrouping,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            indent: 4,
+            sort_fields: false,
+            align_field_types: false,
+            blank_lines_between: 1,
+            grouping: SchemaGrouping::InputOrder,
+        }
+    }
+}
+
+/// How [`print_all_with`] orders a schema set.
+///
+/// Every strategy is a stable reorder: schemas within a group keep their
+/// input order.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum SchemaGrouping {
+    /// Print schemas in the order given.
+    #[default]
+    InputOrder,
+    /// Print schemas carrying the schema-level annotation of this kind
+    /// (as returned by `Annotation::kind`, e.g. `"system"`) after all
+    /// others.
+    AnnotatedLast(String),
+}
+
+impl SchemaGrouping {
+    /// Pushes `@system` schemas to the end.
+    pub fn system_last() -> Self {
+        Self::AnnotatedLast("system".to_string())
+    }
+
+    fn order<'a>(&self, schemas: &'a [SchemaDefinition]) -> Vec<&'a SchemaDefinition> {
+        match self {
+            Self::InputOrder => schemas.iter().collect(),
+            Self::AnnotatedLast(kind) => {
+                let (last, first): (Vec<_>, Vec<_>) = schemas
+                    .iter()
+                    .partition(|s| s.annotations.iter().any(|a| a.kind() == kind));
+                first.into_iter().chain(last).collect()
+            }
+        }
+    }
+}
+
 /// Print a single schema definition to DSL text.
 ///
 /// The output is formatted with 4-space indentation and follows the
 /// SchemaDSL grammar specification exactly, enabling round-trip fidelity.
 pub fn print(schema: &SchemaDefinition) -> String {
-    let mut output = String::new();
-    print_schema(schema, &mut output);
-    output
+    print_with(schema, &PrintOptions::default())
 }
 
 /// Print multiple schema definitions to DSL text, separated by blank lines.
 pub fn print_all(schemas: &[SchemaDefinition]) -> String {
+    print_all_with(schemas, &PrintOptions::default())
+}
+
+/// Print a single schema definition using the given layout options.
+///
+/// Every option only affects whitespace and field order, so the output
+/// still parses back to an equivalent schema.
+pub fn print_with(schema: &SchemaDefinition, options: &PrintOptions) -> String {
+    let mut output = String::new();
+    print_schema(schema, &mut output, options);
+    output
+}
+
+/// Print multiple schema definitions using the given layout options,
+/// ordered by `options.grouping` and separated by
+/// `options.blank_lines_between` blank lines.
+pub fn print_all_with(schemas: &[SchemaDefinition], options: &PrintOptions) -> String {
     let mut output = String::new();
-    for (i, schema) in schemas.iter().enumerate() {
+    for (i, schema) in options.grouping.order(schemas).into_iter().enumerate() {
         if i > 0 {
-            output.push('\n');
+            output.push_str(&"\n".repeat(options.blank_lines_between));
         }
-        print_schema(schema, &mut output);
+        print_schema(schema, &mut output, options);
     }
     output
 }
 
-fn print_schema(schema: &SchemaDefinition, output: &mut String) {
+fn print_schema(schema: &SchemaDefinition, output: &mut String, options: &PrintOptions) {
+    print_doc(schema.doc.as_deref(), "", output);
     for annotation in &schema.annotations {
         print_annotation(annotation, output);
         output.push('\n');
@@ -36,14 +120,58 @@ fn print_schema(schema: &SchemaDefinition, output: &mut String) {
     output.push_str("schema ");
     output.push_str(schema.name.as_str());
     output.push_str(" {\n");
+    print_fields(&schema.fields, output, 1, options);
+    output.push_str("}\n");
+}
 
-    for field in &schema.fields {
-        output.push_str("    ");
-        print_field(field, output, 1);
+/// Print one block of fields (a schema body or a composite body), one per
+/// line, each indented to `depth`.
+fn print_fields(
+    fields: &[FieldDefinition],
+    output: &mut String,
+    depth: usize,
+    options: &PrintOptions,
+) {
+    let mut ordered: Vec<&FieldDefinition> = fields.iter().collect();
+    if options.sort_fields {
+        ordered.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+    }
+    let name_width = if options.align_field_types {
+        ordered
+            .iter()
+            .map(|f| f.name.as_str().len())
+            .max()
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    let indent = " ".repeat(options.indent * depth);
+    for field in ordered {
+        print_doc(field.doc.as_deref(), &indent, output);
+        output.push_str(&indent);
+        print_field(field, output, depth, name_width, options);
         output.push('\n');
     }
+}
 
-    output.push_str("}\n");
+/// Quote `s` as a DSL string literal, escaping backslashes and quotes so
+/// the parser reads back exactly `s`.
+fn quote_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Print a doc comment as `///` lines at `indent`, one per line of `doc`.
+fn print_doc(doc: Option<&str>, indent: &str, output: &mut String) {
+    for line in doc.into_iter().flat_map(|d| d.split('\n')) {
+        output.push_str(indent);
+        output.push_str("///");
+        if !line.is_empty() {
+            output.push(' ');
+            output.push_str(line);
+        }
+        output.push('\n');
+    }
 }
 
 fn print_annotation(annotation: &Annotation, output: &mut String) {
@@ -54,6 +182,16 @@ fn print_annotation(annotation: &Annotation, output: &mut String) {
         Annotation::Display { field } => {
             output.push_str(&format!("@display(\"{}\")", field.as_str()));
         }
+        Annotation::RefLabel { template } => {
+            output.push_str(&format!("@ref_label(\"{}\")", template.as_str()));
+        }
+        Annotation::ListColumns { fields } => {
+            let names: Vec<&str> = fields.iter().map(|f| f.as_str()).collect();
+            output.push_str(&format!("@list_columns({})", names.join(", ")));
+        }
+        Annotation::Pagination { .. }
+        | Annotation::EnumColors { .. }
+        | Annotation::Index { .. } => output.push_str(&annotation.to_string()),
         Annotation::System => {
             output.push_str("@system");
         }
@@ -171,6 +309,14 @@ fn print_annotation(annotation: &Annotation, output: &mut String) {
             output.push_str(intent);
             output.push_str("\"\"\"");
         }
+        Annotation::Deprecated { reason } => print_deprecated(reason.as_deref(), output),
+        Annotation::IdPrefix { prefix } => {
+            output.push_str("@id_prefix(\"");
+            output.push_str(prefix);
+            output.push_str("\")");
+        }
+        Annotation::EmptyAsNull => output.push_str("@empty_as_null"),
+        Annotation::SoftDelete => output.push_str("@soft_delete"),
         _ => {
             // Future annotation kinds -- print as @unknown for forward compatibility
             output.push_str("@unknown");
@@ -178,10 +324,20 @@ fn print_annotation(annotation: &Annotation, output: &mut String) {
     }
 }
 
-fn print_field(field: &FieldDefinition, output: &mut String, depth: usize) {
-    output.push_str(field.name.as_str());
-    output.push_str(": ");
-    print_type(&field.field_type, output, depth);
+/// Print `name: type modifiers annotations`. When `name_width` is non-zero
+/// the name is padded to that width so the types of a block line up.
+fn print_field(
+    field: &FieldDefinition,
+    output: &mut String,
+    depth: usize,
+    name_width: usize,
+    options: &PrintOptions,
+) {
+    let name = field.name.as_str();
+    output.push_str(name);
+    output.push(':');
+    output.push_str(&" ".repeat(name_width.saturating_sub(name.len()) + 1));
+    print_type(&field.field_type, output, depth, options);
 
     for modifier in &field.modifiers {
         output.push(' ');
@@ -194,15 +350,32 @@ fn print_field(field: &FieldDefinition, output: &mut String, depth: usize) {
     }
 }
 
-fn print_type(field_type: &FieldType, output: &mut String, depth: usize) {
+fn print_type(field_type: &FieldType, output: &mut String, depth: usize, options: &PrintOptions) {
     match field_type {
         FieldType::Text(constraints) => {
             output.push_str("text");
+            let mut params = Vec::new();
             if let Some(max) = constraints.max_length {
-                output.push_str(&format!("(max: {max})"));
+                params.push(format!("max: {max}"));
+            }
+            if let Some(pattern) = &constraints.pattern {
+                params.push(format!("pattern: {}", quote_string(pattern)));
+            }
+            if !params.is_empty() {
+                output.push('(');
+                output.push_str(&params.join(", "));
+                output.push(')');
+            }
+        }
+        FieldType::RichText(constraints) => {
+            output.push_str("richtext");
+            let params = build_rich_text_params(constraints);
+            if !params.is_empty() {
+                output.push('(');
+                output.push_str(&params.join(", "));
+                output.push(')');
             }
         }
-        FieldType::RichText => output.push_str("richtext"),
         FieldType::Integer(constraints) => {
             output.push_str("integer");
             let params = build_integer_params(constraints);
@@ -214,12 +387,18 @@ fn print_type(field_type: &FieldType, output: &mut String, depth: usize) {
         }
         FieldType::Float(constraints) => {
             output.push_str("float");
-            if let Some(precision) = constraints.precision {
-                output.push_str(&format!("(precision: {precision})"));
+            let params = build_float_params(constraints);
+            if !params.is_empty() {
+                output.push('(');
+                output.push_str(&params.join(", "));
+                output.push(')');
             }
         }
+        FieldType::Decimal { scale } => output.push_str(&format!("decimal(scale: {scale})")),
         FieldType::Boolean => output.push_str("boolean"),
         FieldType::DateTime => output.push_str("datetime"),
+        FieldType::Duration => output.push_str("duration"),
+        FieldType::Uuid => output.push_str("uuid"),
         FieldType::Enum(variants) => {
             output.push_str("enum(");
             for (i, variant) in variants.iter().enumerate() {
@@ -243,19 +422,29 @@ fn print_type(field_type: &FieldType, output: &mut String, depth: usize) {
                 output.push_str("[]");
             }
         }
-        FieldType::Array(inner) => {
-            print_type(inner, output, depth);
+        // `text[]` shorthand when it reparses to the same type; bounds and
+        // composite elements need the `array(...)` form.
+        FieldType::Array(inner, constraints)
+            if constraints.is_unconstrained() && !matches!(**inner, FieldType::Composite(_)) =>
+        {
+            print_type(inner, output, depth, options);
             output.push_str("[]");
         }
+        FieldType::Array(inner, constraints) => {
+            output.push_str("array(");
+            print_type(inner, output, depth, options);
+            if let Some(min) = constraints.min_items {
+                output.push_str(&format!(", min: {min}"));
+            }
+            if let Some(max) = constraints.max_items {
+                output.push_str(&format!(", max: {max}"));
+            }
+            output.push(')');
+        }
         FieldType::Composite(fields) => {
             output.push_str("composite {\n");
-            let indent = "    ".repeat(depth + 1);
-            for field in fields {
-                output.push_str(&indent);
-                print_field(field, output, depth + 1);
-                output.push('\n');
-            }
-            output.push_str(&"    ".repeat(depth));
+            print_fields(fields, output, depth + 1, options);
+            output.push_str(&" ".repeat(options.indent * depth));
             output.push('}');
         }
         FieldType::File(constraints) => {
@@ -293,10 +482,42 @@ fn build_integer_params(constraints: &schema_forge_core::types::IntegerConstrain
     params
 }
 
+fn build_float_params(constraints: &schema_forge_core::types::FloatConstraints) -> Vec<String> {
+    let mut params = Vec::new();
+    if let Some(precision) = constraints.precision {
+        params.push(format!("precision: {precision}"));
+    }
+    if let Some(min) = &constraints.min {
+        params.push(format!("min: {min}"));
+    }
+    if let Some(max) = &constraints.max {
+        params.push(format!("max: {max}"));
+    }
+    params
+}
+
+fn build_rich_text_params(
+    constraints: &schema_forge_core::types::RichTextConstraints,
+) -> Vec<String> {
+    let mut params = Vec::new();
+    if let Some(max) = constraints.max_plaintext_length {
+        params.push(format!("max_plaintext: {max}"));
+    }
+    if constraints.sanitize {
+        params.push("sanitize: true".to_string());
+    }
+    if let Some(tags) = &constraints.allowed_tags {
+        let quoted: Vec<String> = tags.iter().map(|t| format!("\"{t}\"")).collect();
+        params.push(format!("allowed_tags: [{}]", quoted.join(", ")));
+    }
+    params
+}
+
 fn print_modifier(modifier: &FieldModifier, output: &mut String) {
     match modifier {
         FieldModifier::Required => output.push_str("required"),
         FieldModifier::Indexed => output.push_str("indexed"),
+        FieldModifier::Unique => output.push_str("unique"),
         FieldModifier::Default { value } => {
             output.push_str("default(");
             print_default_value(value, output);
@@ -319,6 +540,13 @@ fn print_default_value(value: &DefaultValue, output: &mut String) {
         DefaultValue::Integer(n) => output.push_str(&n.to_string()),
         DefaultValue::Float(s) => output.push_str(s),
         DefaultValue::Boolean(b) => output.push_str(if *b { "true" } else { "false" }),
+        DefaultValue::Sequence { prefix, start } => {
+            output.push_str("sequence(\"");
+            output.push_str(prefix);
+            output.push_str("\", ");
+            output.push_str(&start.to_string());
+            output.push(')');
+        }
     }
 }
 
@@ -345,11 +573,7 @@ fn print_field_annotation(annotation: &FieldAnnotation, output: &mut String) {
             output.push_str("\")");
         }
         FieldAnnotation::KanbanColumn => output.push_str("@kanban_column"),
-        FieldAnnotation::Format { format_type } => {
-            output.push_str("@format(\"");
-            output.push_str(format_type.as_str());
-            output.push_str("\")");
-        }
+        FieldAnnotation::Format { .. } => output.push_str(&annotation.to_string()),
         FieldAnnotation::EnumColors { colors } => {
             output.push_str("@enum_colors(");
             for (i, (variant, color)) in colors.iter().enumerate() {
@@ -369,12 +593,27 @@ fn print_field_annotation(annotation: &FieldAnnotation, output: &mut String) {
             output.push(')');
         }
         FieldAnnotation::Hidden => output.push_str("@hidden"),
+        FieldAnnotation::Deprecated { reason } => print_deprecated(reason.as_deref(), output),
+        FieldAnnotation::EmptyAsNull => output.push_str("@empty_as_null"),
+        FieldAnnotation::Immutable => output.push_str("@immutable"),
+        FieldAnnotation::Computed { expr } => {
+            output.push_str(&format!("@computed(expr: {})", quote_string(expr.as_str())));
+        }
         _ => {
             output.push_str("@unknown_field_annotation");
         }
     }
 }
 
+fn print_deprecated(reason: Option<&str>, output: &mut String) {
+    output.push_str("@deprecated");
+    if let Some(reason) = reason {
+        output.push_str("(\"");
+        output.push_str(reason);
+        output.push_str("\")");
+    }
+}
+
 fn print_named_string_list(name: &str, list: &[String], output: &mut String) {
     output.push_str(name);
     output.push_str(": [");
@@ -550,7 +789,7 @@ mod tests {
             "S",
             vec![make_field(
                 "tags",
-                FieldType::Array(Box::new(FieldType::Text(TextConstraints::unconstrained()))),
+                FieldType::array(FieldType::Text(TextConstraints::unconstrained())),
             )],
             vec![],
         );
@@ -581,6 +820,164 @@ mod tests {
         assert!(output.contains("        city: text required"));
     }
 
+    fn options_schema() -> SchemaDefinition {
+        make_schema(
+            "Contact",
+            vec![
+                make_field_with_mods(
+                    "name",
+                    FieldType::Text(TextConstraints::unconstrained()),
+                    vec![FieldModifier::Required],
+                ),
+                make_field(
+                    "id",
+                    FieldType::Integer(IntegerConstraints::unconstrained()),
+                ),
+                make_field(
+                    "address",
+                    FieldType::Composite(vec![
+                        make_field("street", FieldType::Text(TextConstraints::unconstrained())),
+                        make_field("zip", FieldType::Text(TextConstraints::unconstrained())),
+                    ]),
+                ),
+            ],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn print_with_default_options_matches_print() {
+        let schema = options_schema();
+        assert_eq!(
+            print_with(&schema, &PrintOptions::default()),
+            print(&schema)
+        );
+    }
+
+    #[test]
+    fn print_with_two_space_indent() {
+        let options = PrintOptions {
+            indent: 2,
+            ..Default::default()
+        };
+        let output = print_with(&options_schema(), &options);
+        assert_eq!(
+            output,
+            "schema Contact {\n  name: text required\n  id: integer\n  address: composite {\n    street: text\n    zip: text\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn print_with_aligned_field_types() {
+        let options = PrintOptions {
+            align_field_types: true,
+            ..Default::default()
+        };
+        let output = print_with(&options_schema(), &options);
+        assert_eq!(
+            output,
+            "schema Contact {\n    name:    text required\n    id:      integer\n    address: composite {\n        street: text\n        zip:    text\n    }\n}\n"
+        );
+    }
+
+    #[test]
+    fn print_with_sorted_fields() {
+        let options = PrintOptions {
+            sort_fields: true,
+            ..Default::default()
+        };
+        let output = print_with(&options_schema(), &options);
+        let address = output.find("address:").unwrap();
+        let id = output.find("id:").unwrap();
+        let name = output.find("name:").unwrap();
+        assert!(address < id && id < name, "fields not sorted:\n{output}");
+        assert!(output.find("street:").unwrap() < output.find("zip:").unwrap());
+    }
+
+    #[test]
+    fn print_with_options_round_trips() {
+        let options = PrintOptions {
+            indent: 2,
+            sort_fields: false,
+            align_field_types: true,
+            blank_lines_between: 1,
+            grouping: SchemaGrouping::InputOrder,
+        };
+        let schema = options_schema();
+        let reparsed = crate::parser::parse(&print_with(&schema, &options)).unwrap();
+        assert_eq!(reparsed[0].fields, schema.fields);
+    }
+
+    #[test]
+    fn print_all_with_applies_options_to_every_schema() {
+        let options = PrintOptions {
+            indent: 2,
+            ..Default::default()
+        };
+        let output = print_all_with(&[options_schema(), options_schema()], &options);
+        assert_eq!(output.matches("\n  name: text required\n").count(), 2);
+    }
+
+    #[test]
+    fn print_all_with_blank_lines_between() {
+        let a = make_schema("A", vec![make_field("x", FieldType::Boolean)], vec![]);
+        let b = make_schema("B", vec![make_field("y", FieldType::Boolean)], vec![]);
+        let schemas = [a, b];
+
+        let output = print_all_with(&schemas, &PrintOptions::default());
+        assert_eq!(output, print_all(&schemas));
+        assert!(output.contains("}\n\nschema B"));
+
+        for (blank_lines, separator) in [(0, "}\nschema B"), (2, "}\n\n\nschema B")] {
+            let options = PrintOptions {
+                blank_lines_between: blank_lines,
+                ..Default::default()
+            };
+            let output = print_all_with(&schemas, &options);
+            assert!(output.contains(separator), "{blank_lines}: {output:?}");
+            assert!(!output.ends_with("\n\n"));
+            let reparsed = crate::parser::parse(&output).unwrap();
+            assert_eq!(reparsed.len(), 2);
+        }
+    }
+
+    #[test]
+    fn print_all_with_system_schemas_last() {
+        let schemas = [
+            make_schema(
+                "User",
+                vec![make_field("x", FieldType::Boolean)],
+                vec![Annotation::System],
+            ),
+            make_schema("Contact", vec![make_field("x", FieldType::Boolean)], vec![]),
+            make_schema(
+                "Role",
+                vec![make_field("x", FieldType::Boolean)],
+                vec![Annotation::System],
+            ),
+            make_schema("Deal", vec![make_field("x", FieldType::Boolean)], vec![]),
+        ];
+        let options = PrintOptions {
+            grouping: SchemaGrouping::system_last(),
+            ..Default::default()
+        };
+        let output = print_all_with(&schemas, &options);
+        let order: Vec<usize> = [
+            "schema Contact",
+            "schema Deal",
+            "schema User",
+            "schema Role",
+        ]
+        .iter()
+        .map(|s| output.find(s).unwrap())
+        .collect();
+        assert!(order.windows(2).all(|w| w[0] < w[1]), "{output}");
+
+        // The default keeps input order.
+        let output = print_all(&schemas);
+        assert!(output.find("schema User").unwrap() < output.find("schema Contact").unwrap());
+    }
+
     #[test]
     fn print_modifiers() {
         let schema = make_schema(
@@ -588,12 +985,16 @@ mod tests {
             vec![make_field_with_mods(
                 "email",
                 FieldType::Text(TextConstraints::with_max_length(255)),
-                vec![FieldModifier::Required, FieldModifier::Indexed],
+                vec![
+                    FieldModifier::Required,
+                    FieldModifier::Indexed,
+                    FieldModifier::Unique,
+                ],
             )],
             vec![],
         );
         let output = print(&schema);
-        assert!(output.contains("text(max: 255) required indexed"));
+        assert!(output.contains("text(max: 255) required indexed unique"));
     }
 
     #[test]
@@ -673,14 +1074,20 @@ mod tests {
         let schema = make_schema(
             "S",
             vec![
-                make_field("a", FieldType::RichText),
+                make_field("a", FieldType::rich_text()),
                 make_field("b", FieldType::Boolean),
                 make_field("c", FieldType::DateTime),
                 make_field("d", FieldType::Json),
+                make_field("e", FieldType::Duration),
+                make_field("f", FieldType::Decimal { scale: 2 }),
+                make_field("g", FieldType::Uuid),
             ],
             vec![],
         );
         let output = print(&schema);
+        assert!(output.contains("e: duration"));
+        assert!(output.contains("f: decimal(scale: 2)"));
+        assert!(output.contains("g: uuid"));
         assert!(output.contains("a: richtext"));
         assert!(output.contains("b: boolean"));
         assert!(output.contains("c: datetime"));
@@ -951,6 +1358,8 @@ mod tests {
                 vec![],
                 vec![FieldAnnotation::Format {
                     format_type: FormatType::Currency,
+                    locale: None,
+                    currency: None,
                 }],
             )],
             vec![],
@@ -959,8 +1368,111 @@ mod tests {
         assert!(output.contains(r#"price: float @format("currency")"#));
     }
 
+    #[test]
+    fn roundtrip_format_locale_options() {
+        let source = r#"schema Invoice {
+    total: float @format("currency", currency: "EUR", locale: "de-DE")
+    rate: float @format("percent", locale: "fr-FR")
+}"#;
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains(r#"@format("currency", currency: "EUR", locale: "de-DE")"#));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].fields, reparsed[0].fields);
+    }
+
     // -- Roundtrip tests (parse -> print -> reparse) --
 
+    #[test]
+    fn roundtrip_array_length_constraints() {
+        let source = "schema S {\n    tags: array(text, min: 1, max: 10)\n    labels: text[]\n}\n";
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains("tags: array(text, min: 1, max: 10)"));
+        assert!(printed.contains("labels: text[]"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].fields, reparsed[0].fields);
+    }
+
+    #[test]
+    fn roundtrip_float_bounds() {
+        let source = "schema S {\n    ratio: float(precision: 2, min: -0.5, max: 1)\n}\n";
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains("ratio: float(precision: 2, min: -0.5, max: 1)"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].fields, reparsed[0].fields);
+    }
+
+    #[test]
+    fn roundtrip_richtext_constraints() {
+        let source = "schema S {\n    body: richtext(max_plaintext: 500, sanitize: true, allowed_tags: [\"p\", \"em\"])\n    notes: richtext\n}\n";
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains(
+            r#"body: richtext(max_plaintext: 500, sanitize: true, allowed_tags: ["p", "em"])"#
+        ));
+        assert!(printed.contains("notes: richtext\n"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].fields, reparsed[0].fields);
+    }
+
+    #[test]
+    fn roundtrip_doc_comments() {
+        let source = "/// Customer record.
+///
+/// Synced from billing.
+@version(2)
+schema Customer {
+    /// Legal name.
+    name: text required
+    address: composite {
+        /// Two-letter code.
+        country: text
+    }
+    /// Left behind by an editor; not attached to anything.
+}
+";
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert_eq!(
+            printed,
+            "/// Customer record.
+///
+/// Synced from billing.
+@version(2)
+schema Customer {
+    /// Legal name.
+    name: text required
+    address: composite {
+        /// Two-letter code.
+        country: text
+    }
+}
+"
+        );
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].doc, reparsed[0].doc);
+        assert_eq!(parsed[0].fields, reparsed[0].fields);
+    }
+
+    #[test]
+    fn roundtrip_text_pattern() {
+        let source = r#"schema S {
+    sku: text(max: 20, pattern: "^[A-Z]{3}-\d{4}$")
+    path: text(pattern: "^C:\\\\[a-z]+ \"q\"$")
+}
+"#;
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(
+            printed.contains(r#"sku: text(max: 20, pattern: "^[A-Z]{3}-\\d{4}$")"#),
+            "got: {printed}"
+        );
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].fields, reparsed[0].fields);
+    }
+
     #[test]
     fn roundtrip_widget() {
         let source = r#"schema S {
@@ -976,6 +1488,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn roundtrip_default_sequence() {
+        let source = "schema Invoice {\n    number: text default(sequence(\"INV-\", 1000))\n}\n";
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains(r#"default(sequence("INV-", 1000))"#));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(
+            parsed[0].fields[0].modifiers,
+            reparsed[0].fields[0].modifiers
+        );
+    }
+
     #[test]
     fn roundtrip_kanban_column() {
         let source = "schema S {\n    stage: text @kanban_column\n}\n";
@@ -1050,6 +1575,166 @@ schema S {
         assert_eq!(parsed[0].annotations, reparsed[0].annotations);
     }
 
+    #[test]
+    fn roundtrip_list_columns() {
+        let source = r#"@list_columns(stage, title)
+schema Deal {
+    title: text
+    stage: text
+    notes: text
+}
+"#;
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains("@list_columns(stage, title)"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].annotations, reparsed[0].annotations);
+    }
+
+    #[test]
+    fn roundtrip_pagination() {
+        let source = r#"@list(page_size: 50, max_page_size: 200)
+schema Deal {
+    title: text
+}
+"#;
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains("@list(page_size: 50, max_page_size: 200)"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].annotations, reparsed[0].annotations);
+    }
+
+    #[test]
+    fn roundtrip_schema_enum_colors() {
+        let source = r#"@enum_colors(lost: "red", won: "green")
+schema Deal {
+    stage: enum("open", "won", "lost")
+}
+"#;
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains(r#"@enum_colors(lost: "red", won: "green")"#));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].annotations, reparsed[0].annotations);
+    }
+
+    #[test]
+    fn roundtrip_ref_label() {
+        let source = r#"@ref_label("{name} (#{id})")
+schema Company {
+    name: text
+}
+"#;
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains("@ref_label(\"{name} (#{id})\")"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].annotations, reparsed[0].annotations);
+    }
+
+    #[test]
+    fn roundtrip_id_prefix() {
+        let source = r#"@id_prefix("inv")
+schema Invoice {
+    number: text
+}
+"#;
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains("@id_prefix(\"inv\")"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].annotations, reparsed[0].annotations);
+    }
+
+    #[test]
+    fn roundtrip_soft_delete() {
+        let source = "@soft_delete\nschema Contact {\n    name: text\n}\n";
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains("@soft_delete\nschema"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].annotations, reparsed[0].annotations);
+    }
+
+    #[test]
+    fn roundtrip_index() {
+        let source = r#"@index(fields: ["agency", "stage"], unique: true)
+@index(fields: ["stage"])
+schema Deal {
+    agency: text
+    stage: text
+}
+"#;
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains("@index(fields: [\"agency\", \"stage\"], unique: true)\n"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].annotations, reparsed[0].annotations);
+    }
+
+    #[test]
+    fn roundtrip_empty_as_null() {
+        let source = r#"@empty_as_null
+schema Contact {
+    name: text
+    bio: richtext @empty_as_null
+}
+"#;
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains("@empty_as_null\nschema"));
+        assert!(printed.contains("bio: richtext @empty_as_null"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].annotations, reparsed[0].annotations);
+        assert_eq!(parsed[0].fields, reparsed[0].fields);
+    }
+
+    #[test]
+    fn roundtrip_immutable() {
+        let source = "schema Account {\n    sku: text required @immutable\n}\n";
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains("sku: text required @immutable"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].fields, reparsed[0].fields);
+    }
+
+    #[test]
+    fn roundtrip_computed() {
+        let source = "schema Person {\n    first_name: text\n    last_name: text\n    full_name: text @computed(expr: \"first_name + ' ' + last_name\")\n}\n";
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(
+            printed.contains(r#"full_name: text @computed(expr: "first_name + ' ' + last_name")"#)
+        );
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].fields, reparsed[0].fields);
+    }
+
+    #[test]
+    fn roundtrip_deprecated() {
+        let source = r#"@deprecated("replaced by Account")
+schema Customer {
+    email: text @deprecated("use contact_email")
+    fax: text @deprecated
+    contact_email: text
+}
+"#;
+        let parsed = crate::parser::parse(source).unwrap();
+        let printed = print(&parsed[0]);
+        assert!(printed.contains("@deprecated(\"replaced by Account\")"));
+        assert!(printed.contains("fax: text @deprecated\n"));
+        let reparsed = crate::parser::parse(&printed).unwrap();
+        assert_eq!(parsed[0].annotations, reparsed[0].annotations);
+        for i in 0..3 {
+            assert_eq!(
+                parsed[0].fields[i].annotations,
+                reparsed[0].fields[i].annotations
+            );
+        }
+    }
+
     #[test]
     fn roundtrip_dashboard_minimal() {
         let source = r#"@dashboard(widgets: ["count"])
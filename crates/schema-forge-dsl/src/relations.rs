@@ -0,0 +1,81 @@
+//! Cross-schema relation checks.
+//!
+//! A single `.schema` file may point at schemas defined in other files, so
+//! relation targets can only be resolved once the whole batch is known.
+
+use crate::error::DslError;
+use crate::lexer::tokenize;
+use crate::token::Token;
+
+/// Report every `-> Target` in `source` whose target `is_defined` rejects.
+///
+/// Works on the token stream rather than the parsed schemas so each error
+/// carries the span of the target name. `source` is expected to parse
+/// cleanly; a source the lexer rejects yields no errors here.
+pub fn check_relation_targets(source: &str, is_defined: impl Fn(&str) -> bool) -> Vec<DslError> {
+    let Ok(tokens) = tokenize(source) else {
+        return Vec::new();
+    };
+
+    let mut schema = "";
+    let mut errors = Vec::new();
+    for (i, tok) in tokens.iter().enumerate() {
+        match tok.token {
+            Token::Schema => {
+                if let Some(name) = tokens.get(i + 1) {
+                    schema = &name.text;
+                }
+            }
+            Token::Arrow => {
+                let Some(target) = tokens.get(i + 1).filter(|t| t.token == Token::Ident) else {
+                    continue;
+                };
+                if is_defined(&target.text) {
+                    continue;
+                }
+                // `field: -> Target` -- the field name sits two tokens back.
+                let field = match i.checked_sub(2).map(|j| (&tokens[j], &tokens[j + 1])) {
+                    Some((name, colon)) if colon.token == Token::Colon => name.text.as_str(),
+                    _ => "",
+                };
+                errors.push(DslError::UnknownRelationTarget {
+                    field: format!("{schema}.{field}"),
+                    target: target.text.clone(),
+                    span: target.span.clone(),
+                });
+            }
+            _ => {}
+        }
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defined_targets_pass() {
+        let source = "schema Project { owner: -> Person  members: -> Person[] }";
+        assert!(check_relation_targets(source, |name| name == "Person").is_empty());
+    }
+
+    #[test]
+    fn undefined_target_names_field_and_span() {
+        let source = "schema Project {\n    title: text\n    owner: -> Persn\n}\n";
+        let errors = check_relation_targets(source, |name| name == "Person");
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            DslError::UnknownRelationTarget {
+                field,
+                target,
+                span,
+            } => {
+                assert_eq!(field, "Project.owner");
+                assert_eq!(target, "Persn");
+                assert_eq!(span.line_col(source), (3, 15));
+            }
+            other => panic!("expected UnknownRelationTarget, got {other:?}"),
+        }
+    }
+}
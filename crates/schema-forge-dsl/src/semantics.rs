@@ -0,0 +1,355 @@
+//! Single-file semantic checks that the parser does not enforce.
+//!
+//! The parser accepts any string for `@display(...)`, `@dashboard(group_by:
+//! ...)`, `default(...)`, and any field name in `@computed(...)`; whether
+//! those values make sense depends on the fields declared around them. These checks need no backend and no other
+//! schema files.
+
+use schema_forge_core::types::{
+    parse_uuid, Cardinality, DefaultValue, FieldType, IsoDuration, SchemaDefinition,
+};
+
+use crate::error::{DslError, Span};
+use crate::lexer::{tokenize, SpannedToken};
+use crate::parser::parse;
+use crate::token::Token;
+
+/// Report every annotation or default in `source` that does not fit the
+/// schema it belongs to:
+///
+/// - `@display("field")` naming a field the schema does not declare
+/// - `@dashboard(group_by: "field")` naming a field that is missing or is
+///   neither an enum nor a to-one relation
+/// - `default(...)` literals the field's type cannot hold, including enum
+///   defaults that are not one of the variants
+/// - `@computed(...)` expressions reading a field that is missing, or a
+///   computed field declared at or below the one being computed
+///
+/// Like [`check_relation_targets`](crate::check_relation_targets), this
+/// walks the token stream so each error carries a span. `source` is
+/// expected to parse cleanly; a source that does not yields no errors here.
+pub fn check_semantics(source: &str) -> Vec<DslError> {
+    let Ok(schemas) = parse(source) else {
+        return Vec::new();
+    };
+    let Ok(tokens) = tokenize(source) else {
+        return Vec::new();
+    };
+
+    let mut errors = Vec::new();
+    let mut declared = 0;
+    let mut depth = 0usize;
+    let mut parens = 0usize;
+    let mut field = "";
+    for (i, tok) in tokens.iter().enumerate() {
+        match tok.token {
+            Token::LBrace => depth += 1,
+            Token::RBrace => depth = depth.saturating_sub(1),
+            Token::LParen => parens += 1,
+            Token::RParen => parens = parens.saturating_sub(1),
+            Token::Schema if depth == 0 => declared += 1,
+            // Schema annotations precede the `schema` keyword they belong to.
+            Token::At if depth == 0 => {
+                if let Some(schema) = schemas.get(declared) {
+                    check_annotation(schema, &tokens[i + 1..], &mut errors);
+                }
+            }
+            Token::Colon if depth == 1 && parens == 0 => field = &tokens[i - 1].text,
+            Token::At if depth == 1 && parens == 0 => {
+                if let Some(schema) = declared.checked_sub(1).and_then(|n| schemas.get(n)) {
+                    check_computed(schema, field, &tokens[i + 1..], &mut errors);
+                }
+            }
+            Token::Default if depth == 1 && parens == 0 => {
+                let Some(schema) = declared.checked_sub(1).and_then(|n| schemas.get(n)) else {
+                    continue;
+                };
+                let Some(field_def) = schema.field(field) else {
+                    continue;
+                };
+                let (Some(value), Some(span)) =
+                    (field_def.default_value(), default_span(&tokens[i + 1..]))
+                else {
+                    continue;
+                };
+                if !default_fits(value, &field_def.field_type) {
+                    errors.push(DslError::DefaultTypeMismatch {
+                        field: format!("{}.{field}", schema.name),
+                        value: source[span.start..span.end].to_string(),
+                        field_type: field_def.field_type.to_string(),
+                        span,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    errors
+}
+
+/// Check one schema annotation; `rest` starts at the annotation name.
+fn check_annotation(schema: &SchemaDefinition, rest: &[SpannedToken], errors: &mut Vec<DslError>) {
+    let Some(name) = rest.first() else {
+        return;
+    };
+    match name.text.as_str() {
+        "display" => {
+            let Some(arg) = rest.get(2).filter(|t| t.token == Token::StringLiteral) else {
+                return;
+            };
+            let field = unquote(&arg.text);
+            if schema.field(field).is_none() {
+                errors.push(DslError::UnknownDisplayField {
+                    schema: schema.name.as_str().to_string(),
+                    field: field.to_string(),
+                    span: arg.span.clone(),
+                });
+            }
+        }
+        "dashboard" => {
+            let args = rest
+                .iter()
+                .skip(2)
+                .take_while(|t| t.token != Token::RParen)
+                .collect::<Vec<_>>();
+            let Some(arg) = args.windows(3).find_map(|w| {
+                (w[0].text == "group_by" && w[1].token == Token::Colon).then_some(w[2])
+            }) else {
+                return;
+            };
+            let field = unquote(&arg.text);
+            // Boards group into one column per enum variant, or per related
+            // entity (plus an unassigned column) for a to-one relation.
+            let groupable = schema.field(field).is_some_and(|f| {
+                matches!(
+                    f.field_type,
+                    FieldType::Enum(_)
+                        | FieldType::Relation {
+                            cardinality: Cardinality::One,
+                            ..
+                        }
+                )
+            });
+            if !groupable {
+                errors.push(DslError::InvalidDashboardGroupBy {
+                    schema: schema.name.as_str().to_string(),
+                    field: field.to_string(),
+                    span: arg.span.clone(),
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Check the references of a `@computed` field annotation; `rest` starts at
+/// the annotation name.
+fn check_computed(
+    schema: &SchemaDefinition,
+    field: &str,
+    rest: &[SpannedToken],
+    errors: &mut Vec<DslError>,
+) {
+    if rest.first().map(|t| t.text.as_str()) != Some("computed") {
+        return;
+    }
+    let Some(position) = schema.fields.iter().position(|f| f.name.as_str() == field) else {
+        return;
+    };
+    let (Some(expr), Some(arg)) = (
+        schema.fields[position].computed_expr(),
+        rest.iter().find(|t| t.token == Token::StringLiteral),
+    ) else {
+        return;
+    };
+    for reference in expr.fields() {
+        let readable = schema
+            .fields
+            .iter()
+            .enumerate()
+            .any(|(i, f)| f.name.as_str() == reference && (f.is_stored() || i < position));
+        if !readable {
+            errors.push(DslError::UnknownComputedReference {
+                field: format!("{}.{field}", schema.name),
+                reference,
+                span: arg.span.clone(),
+            });
+        }
+    }
+}
+
+/// Span of the literal inside `default(...)`; `rest` starts at the `(`.
+fn default_span(rest: &[SpannedToken]) -> Option<Span> {
+    let first = rest.get(1)?;
+    let mut parens = 0usize;
+    let mut last = first;
+    for tok in &rest[1..] {
+        match tok.token {
+            Token::LParen => parens += 1,
+            Token::RParen if parens == 0 => break,
+            Token::RParen => parens -= 1,
+            _ => {}
+        }
+        last = tok;
+    }
+    Some(Span::new(first.span.start, last.span.end))
+}
+
+/// Whether `value` can be stored in a field of `field_type`, following the
+/// conversions the entity routes apply when filling defaults.
+fn default_fits(value: &DefaultValue, field_type: &FieldType) -> bool {
+    match (value, field_type) {
+        (DefaultValue::String(_), FieldType::Text(_) | FieldType::RichText(_)) => true,
+        (DefaultValue::String(s), FieldType::Enum(variants)) => variants.iter().any(|v| v == s),
+        (DefaultValue::String(s), FieldType::Duration) => IsoDuration::parse(s).is_ok(),
+        (DefaultValue::String(s), FieldType::Uuid) => parse_uuid(s).is_ok(),
+        (
+            DefaultValue::Integer(_),
+            FieldType::Integer(_) | FieldType::Float(_) | FieldType::Decimal { .. },
+        ) => true,
+        (DefaultValue::Float(_), FieldType::Float(_) | FieldType::Decimal { .. }) => true,
+        (DefaultValue::Boolean(_), FieldType::Boolean) => true,
+        (DefaultValue::Sequence { .. }, FieldType::Text(_)) => true,
+        _ => false,
+    }
+}
+
+fn unquote(literal: &str) -> &str {
+    literal
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(literal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consistent_schema_passes() {
+        let source = r#"
+@display("name")
+@dashboard(layout: "kanban", group_by: "stage")
+schema Deal {
+    name: text required
+    stage: enum("Lead", "Won") default("Lead")
+    value: decimal(scale: 2) default(0)
+    probability: float default(0.5)
+    open: boolean default(true)
+    number: text default(sequence("D-", 100))
+    cycle: duration default("P3D")
+    external_id: uuid default("67e55044-10b1-426f-9247-bb680e5fe0c8")
+}
+"#;
+        assert!(check_semantics(source).is_empty());
+    }
+
+    #[test]
+    fn unknown_display_field() {
+        let source = "@display(\"title\")\nschema Note {\n    body: text\n}\n";
+        let errors = check_semantics(source);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            DslError::UnknownDisplayField {
+                schema,
+                field,
+                span,
+            } => {
+                assert_eq!(schema, "Note");
+                assert_eq!(field, "title");
+                assert_eq!(span.line_col(source), (1, 10));
+            }
+            other => panic!("expected UnknownDisplayField, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn dashboard_group_by_must_be_enum_or_single_relation() {
+        let source = r#"
+@dashboard(group_by: "name")
+schema A { name: text }
+
+@dashboard(group_by: "missing")
+schema B { name: text }
+
+@dashboard(layout: "kanban", group_by: "project")
+schema Task { project: -> Project }
+
+@dashboard(group_by: "watchers")
+schema Issue { watchers: -> User[] }
+"#;
+        let errors = check_semantics(source);
+        let fields: Vec<_> = errors
+            .iter()
+            .map(|e| match e {
+                DslError::InvalidDashboardGroupBy { schema, field, .. } => {
+                    format!("{schema}.{field}")
+                }
+                other => panic!("expected InvalidDashboardGroupBy, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(fields, ["A.name", "B.missing", "Issue.watchers"]);
+    }
+
+    #[test]
+    fn mismatched_defaults_point_at_the_literal() {
+        let source = "schema Task {\n    done: boolean default(\"no\")\n    status: enum(\"Open\", \"Closed\") default(\"Done\")\n    count: integer default(1.5)\n    title: text default(\"ok\")\n}\n";
+        let errors = check_semantics(source);
+        assert_eq!(errors.len(), 3);
+        match &errors[0] {
+            DslError::DefaultTypeMismatch {
+                field, value, span, ..
+            } => {
+                assert_eq!(field, "Task.done");
+                assert_eq!(value, "\"no\"");
+                assert_eq!(span.line_col(source), (2, 27));
+            }
+            other => panic!("expected DefaultTypeMismatch, got {other:?}"),
+        }
+        let fields: Vec<_> = errors
+            .iter()
+            .filter_map(|e| match e {
+                DslError::DefaultTypeMismatch { field, .. } => Some(field.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fields, ["Task.done", "Task.status", "Task.count"]);
+    }
+
+    #[test]
+    fn computed_fields_read_stored_or_earlier_computed_fields() {
+        let source = r#"
+schema Deal {
+    price: decimal(scale: 2)
+    cost: decimal(scale: 2)
+    margin: decimal(scale: 2) @computed(expr: "price - cost")
+    margin_pct: float @computed(expr: "margin / price * 100")
+    label: text @computed(expr: "concat(title, ' ', later)")
+    later: text @computed(expr: "upper(later)")
+}
+"#;
+        let errors = check_semantics(source);
+        let refs: Vec<_> = errors
+            .iter()
+            .map(|e| match e {
+                DslError::UnknownComputedReference {
+                    field, reference, ..
+                } => format!("{field} -> {reference}"),
+                other => panic!("expected UnknownComputedReference, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            refs,
+            [
+                "Deal.label -> title",
+                "Deal.label -> later",
+                "Deal.later -> later"
+            ]
+        );
+    }
+
+    #[test]
+    fn unparseable_source_yields_nothing() {
+        assert!(check_semantics("schema {").is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-use logos::{Lexer, Logos};
+use logos::{FilterResult, Lexer, Logos};
 
 /// Callback for the triple-quoted string token. After logos has matched
 /// the opening `"""`, scans the remainder for the closing `"""` and
@@ -11,13 +11,58 @@ fn lex_triple_string(lex: &mut Lexer<Token>) -> Option<()> {
     Some(())
 }
 
+/// Callback for `/* ... */`. After logos has matched the opening `/*`,
+/// skips through the closing `*/`. Block comments do not nest: a `/*`
+/// before the closing delimiter is a lex error spanning the whole nested
+/// construct, as is a comment that never closes.
+fn lex_block_comment(lex: &mut Lexer<Token>) -> FilterResult<(), ()> {
+    let rest = lex.remainder().as_bytes();
+    let close = rest.windows(2).position(|w| w == b"*/");
+    let open = rest.windows(2).position(|w| w == b"/*");
+    match (open, close) {
+        (_, None) => {
+            lex.bump(rest.len());
+            FilterResult::Error(())
+        }
+        (Some(open), Some(close)) if open < close => {
+            // Consume up to the delimiter that would close the outermost
+            // comment if nesting were allowed, so the rest of the file
+            // does not cascade into unrelated errors.
+            let mut depth = 1;
+            let mut i = 0;
+            while i < rest.len() && depth > 0 {
+                match &rest[i..rest.len().min(i + 2)] {
+                    b"/*" => {
+                        depth += 1;
+                        i += 2;
+                    }
+                    b"*/" => {
+                        depth -= 1;
+                        i += 2;
+                    }
+                    _ => i += 1,
+                }
+            }
+            lex.bump(i);
+            FilterResult::Error(())
+        }
+        (_, Some(close)) => {
+            lex.bump(close + 2);
+            FilterResult::Skip
+        }
+    }
+}
+
 /// Tokens produced by the SchemaDSL lexer.
 ///
-/// Whitespace and comments are skipped automatically by logos.
+/// Whitespace and `//` line comments are skipped automatically by logos;
+/// `/* ... */` block comments are skipped by [`lex_block_comment`]. `///`
+/// doc comments are kept as [`Token::DocComment`], while four or more
+/// slashes are an ordinary line comment.
 #[derive(Logos, Debug, Clone, PartialEq)]
 #[logos(skip r"[ \t\r\n\f]+")]
-#[logos(skip r"//[^\n]*")]
-#[logos(skip r"/\*([^*]|\*[^/])*\*/")]
+#[logos(skip r"//([^/\r\n][^\r\n]*)?")]
+#[logos(skip r"////[^\r\n]*")]
 pub enum Token {
     // -- Keywords --
     #[token("schema")]
@@ -35,12 +80,21 @@ pub enum Token {
     #[token("float")]
     Float,
 
+    #[token("decimal")]
+    Decimal,
+
     #[token("boolean")]
     Boolean,
 
     #[token("datetime")]
     DateTime,
 
+    #[token("duration")]
+    Duration,
+
+    #[token("uuid")]
+    Uuid,
+
     #[token("enum")]
     Enum,
 
@@ -59,6 +113,9 @@ pub enum Token {
     #[token("indexed")]
     Indexed,
 
+    #[token("unique")]
+    Unique,
+
     #[token("default")]
     Default,
 
@@ -99,6 +156,15 @@ pub enum Token {
     #[token("@")]
     At,
 
+    /// A `/* ... */` block comment. Never produced: the callback either
+    /// skips the comment or reports a lex error.
+    #[token("/*", lex_block_comment)]
+    BlockComment,
+
+    /// A `///` doc comment line, up to but excluding the line break.
+    #[regex(r"///([^/\r\n][^\r\n]*)?")]
+    DocComment,
+
     // -- Literals --
     /// A triple-quoted string literal, e.g. `"""hello world"""`.
     /// May span multiple lines and contain unescaped double quotes
@@ -136,14 +202,18 @@ impl Token {
             Self::RichText => "'richtext'",
             Self::Integer => "'integer'",
             Self::Float => "'float'",
+            Self::Decimal => "'decimal'",
             Self::Boolean => "'boolean'",
             Self::DateTime => "'datetime'",
+            Self::Duration => "'duration'",
+            Self::Uuid => "'uuid'",
             Self::Enum => "'enum'",
             Self::Json => "'json'",
             Self::Composite => "'composite'",
             Self::File => "'file'",
             Self::Required => "'required'",
             Self::Indexed => "'indexed'",
+            Self::Unique => "'unique'",
             Self::Default => "'default'",
             Self::True => "'true'",
             Self::False => "'false'",
@@ -157,6 +227,8 @@ impl Token {
             Self::Comma => "','",
             Self::Arrow => "'->'",
             Self::At => "'@'",
+            Self::BlockComment => "block comment",
+            Self::DocComment => "doc comment",
             Self::StringLiteral => "string literal",
             Self::TripleStringLiteral => "triple-quoted string literal",
             Self::IntegerLiteral => "integer literal",
@@ -182,7 +254,7 @@ mod tests {
 
     #[test]
     fn keywords() {
-        let tokens = lex("schema text richtext integer float boolean datetime enum json composite file required indexed default true false");
+        let tokens = lex("schema text richtext integer float decimal boolean datetime duration uuid enum json composite file required indexed unique default true false");
         assert_eq!(
             tokens,
             vec![
@@ -191,14 +263,18 @@ mod tests {
                 Token::RichText,
                 Token::Integer,
                 Token::Float,
+                Token::Decimal,
                 Token::Boolean,
                 Token::DateTime,
+                Token::Duration,
+                Token::Uuid,
                 Token::Enum,
                 Token::Json,
                 Token::Composite,
                 Token::File,
                 Token::Required,
                 Token::Indexed,
+                Token::Unique,
                 Token::Default,
                 Token::True,
                 Token::False,
@@ -286,6 +362,33 @@ mod tests {
         assert_eq!(tokens, vec![Token::Schema, Token::Ident]);
     }
 
+    #[test]
+    fn doc_comments_are_tokens() {
+        let tokens = lex("/// about\n///\n//// rule\nschema");
+        assert_eq!(
+            tokens,
+            vec![Token::DocComment, Token::DocComment, Token::Schema]
+        );
+    }
+
+    #[test]
+    fn block_comment_may_contain_stars_and_slashes() {
+        let tokens = lex("schema /** a * b / c **/ Contact");
+        assert_eq!(tokens, vec![Token::Schema, Token::Ident]);
+    }
+
+    #[test]
+    fn nested_block_comment_is_a_single_error() {
+        let results: Vec<_> = Token::lexer("schema /* a /* b */ c */ Contact").collect();
+        assert_eq!(results, vec![Ok(Token::Schema), Err(()), Ok(Token::Ident)]);
+    }
+
+    #[test]
+    fn unterminated_block_comment_is_an_error() {
+        let results: Vec<_> = Token::lexer("schema /* never closed").collect();
+        assert_eq!(results, vec![Ok(Token::Schema), Err(())]);
+    }
+
     #[test]
     fn arrow_token() {
         // Ensure -> is a single token, not '-' then '>'
@@ -134,12 +134,12 @@ fn parse_full_crm_schema() {
     // tags array
     let tags = contact.field("tags").expect("Contact.tags");
     assert!(
-        matches!(&tags.field_type, FieldType::Array(inner) if matches!(inner.as_ref(), FieldType::Text(_)))
+        matches!(&tags.field_type, FieldType::Array(inner, _) if matches!(inner.as_ref(), FieldType::Text(_)))
     );
 
     // notes richtext
     let notes = contact.field("notes").expect("Contact.notes");
-    assert!(matches!(notes.field_type, FieldType::RichText));
+    assert!(matches!(notes.field_type, FieldType::RichText(_)));
 
     // last_contacted datetime
     let lc = contact
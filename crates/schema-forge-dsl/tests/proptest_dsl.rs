@@ -22,6 +22,7 @@ fn snake_case_name() -> impl Strategy<Value = String> {
                 | "composite"
                 | "required"
                 | "indexed"
+                | "unique"
                 | "default"
                 | "true"
                 | "false"
@@ -3,27 +3,38 @@
 //! This is the I/O boundary: all database communication happens here.
 //! Pure logic lives in `codegen`, `query`, and `value` modules.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use arc_swap::ArcSwap;
-use schema_forge_backend::entity::{Entity, QueryResult};
+use schema_forge_backend::entity::{Entity, QueryResult, VERSION_FIELD};
 use schema_forge_backend::error::BackendError;
 use schema_forge_backend::traits::{EntityStore, SchemaBackend};
 use schema_forge_core::migration::MigrationStep;
-use schema_forge_core::query::{AggregateQuery, AggregateResult, Query};
+use schema_forge_core::query::{
+    validate_aggregate, validate_sort, AggregateQuery, AggregateResult, Query, QueryError,
+};
 use schema_forge_core::types::{
-    DynamicValue, EntityId, FieldType, SchemaDefinition, SchemaName, WidgetRepair,
+    DynamicValue, EntityId, FieldName, FieldType, SchemaDefinition, SchemaName, WidgetRepair,
+    DELETED_AT_FIELD,
 };
 use sqlx::postgres::{PgArguments, PgPool, PgPoolOptions, PgRow};
 use sqlx::{Arguments, Row};
 
-use crate::codegen::migration_step_to_sql;
-use crate::query::{aggregate_to_sql, count_to_sql, query_to_sql};
-use crate::value::{bind_dynamic_value, row_to_entity};
+use crate::codegen::{migration_step_to_sql, unique_indexes, version_ddl_statements};
+use crate::query::{
+    aggregate_to_sql_with_schema, count_to_sql_with_schema, delete_to_sql_with_schema,
+    query_to_sql_with_schema,
+};
+use crate::value::{bind_dynamic_value, read_column, row_to_entity};
 
 /// The schema metadata table name used to store `SchemaDefinition` records.
 const SCHEMA_META_TABLE: &str = "_schema_metadata";
 
+/// The table holding `default(sequence(...))` counters, one row per
+/// schema field.
+const SEQUENCE_TABLE: &str = "_forge_sequence";
+
 /// Emit a tracing warning for each legacy widget annotation repaired at
 /// metadata load time. Noisy by design — operators should see every stale
 /// row they need to clean up.
@@ -49,6 +60,63 @@ fn log_widget_repairs(schema: &str, repairs: Vec<WidgetRepair>) {
     }
 }
 
+/// Report the first of `errors` as a `ValidationFailed` for the field it
+/// names, falling back to `action` for errors not tied to a field.
+fn validation_error(errors: &[QueryError], action: &str) -> BackendError {
+    let first = &errors[0];
+    let field = match first {
+        QueryError::UnknownField { field, .. } | QueryError::TypeMismatch { field, .. } => {
+            field.clone()
+        }
+        _ => action.to_string(),
+    };
+    BackendError::ValidationFailed {
+        field,
+        reason: format!("cannot {action}: {first}"),
+    }
+}
+
+/// Report a failed insert or update as `BackendError::UniqueConflict` when
+/// it violated the unique index of a `unique` field or a unique `@index`,
+/// and as a `QueryError` prefixed with `action` otherwise.
+fn write_error(
+    e: sqlx::Error,
+    schema: &SchemaName,
+    schema_def: Option<&SchemaDefinition>,
+    action: &str,
+) -> BackendError {
+    let constraint = e
+        .as_database_error()
+        .filter(|db| db.is_unique_violation())
+        .and_then(|db| db.constraint());
+    let index = constraint.zip(schema_def).and_then(|(constraint, def)| {
+        unique_indexes(def)
+            .into_iter()
+            .find(|(name, _)| name == constraint)
+    });
+    match index {
+        Some((_, fields)) => BackendError::UniqueConflict {
+            schema: schema.as_str().to_string(),
+            fields: fields.iter().map(|f| f.as_str().to_string()).collect(),
+        },
+        None => BackendError::QueryError {
+            message: format!("failed to {action}: {e}"),
+        },
+    }
+}
+
+/// Reject sort keys that do not name a field of `schema`, then resolve any
+/// pagination cursor into the query that is actually executed.
+fn prepare_query(query: &Query, schema: &SchemaDefinition) -> Result<Query, BackendError> {
+    validate_sort(&query.sort, schema).map_err(|errors| validation_error(&errors, "sort"))?;
+    query
+        .resolve_cursor()
+        .map_err(|e| BackendError::ValidationFailed {
+            field: "after".to_string(),
+            reason: e.to_string(),
+        })
+}
+
 /// PostgreSQL backend for SchemaForge.
 ///
 /// Wraps a `PgPool` and implements both `SchemaBackend` (DDL/metadata)
@@ -132,7 +200,7 @@ impl PgBackend {
         &self.pool
     }
 
-    /// Ensure the `_schema_metadata` table exists.
+    /// Ensure the `_schema_metadata` and `_forge_sequence` tables exist.
     async fn ensure_metadata_table(&self) -> Result<(), BackendError> {
         sqlx::query(&format!(
             "CREATE TABLE IF NOT EXISTS \"{SCHEMA_META_TABLE}\" (\
@@ -146,6 +214,20 @@ impl PgBackend {
             step: "create _schema_metadata table".to_string(),
             reason: e.to_string(),
         })?;
+        sqlx::query(&format!(
+            "CREATE TABLE IF NOT EXISTS \"{SEQUENCE_TABLE}\" (\
+                \"schema_name\" TEXT NOT NULL, \
+                \"field_name\" TEXT NOT NULL, \
+                \"value\" BIGINT NOT NULL, \
+                PRIMARY KEY (\"schema_name\", \"field_name\")\
+            );"
+        ))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| BackendError::MigrationFailed {
+            step: "create _forge_sequence table".to_string(),
+            reason: e.to_string(),
+        })?;
         Ok(())
     }
 
@@ -190,10 +272,12 @@ impl PgBackend {
     ///
     /// When `schema_def` is provided, per-column `FieldType` context is passed
     /// to `bind_dynamic_value` so array columns can be bound as native Postgres
-    /// arrays (`text[]`, `bigint[]`, etc.) rather than JSONB.
+    /// arrays (`text[]`, `bigint[]`, etc.) rather than JSONB. The version
+    /// column is always bumped; `expected_version` adds a guard on it.
     fn build_update(
         entity: &Entity,
         schema_def: Option<&SchemaDefinition>,
+        expected_version: Option<u64>,
     ) -> Result<(String, PgArguments), BackendError> {
         let table = entity.schema.as_str();
         let mut args = PgArguments::default();
@@ -212,9 +296,22 @@ impl PgBackend {
                 .map(|fd| &fd.field_type);
             bind_dynamic_value(&mut args, val, field_type)?;
         }
+        set_clauses.push(format!("\"{VERSION_FIELD}\" = \"{VERSION_FIELD}\" + 1"));
+
+        let mut guard = String::new();
+        if let Some(version) = expected_version {
+            let version = i64::try_from(version).map_err(|_| BackendError::ValidationFailed {
+                field: VERSION_FIELD.to_string(),
+                reason: format!("version {version} is out of range"),
+            })?;
+            args.add(version).map_err(|e| BackendError::Internal {
+                message: format!("failed to bind version: {e}"),
+            })?;
+            guard = format!(" AND \"{VERSION_FIELD}\" = ${}", entity.fields.len() + 2);
+        }
 
         let sql = format!(
-            "UPDATE \"{table}\" SET {} WHERE \"id\" = $1 RETURNING *;",
+            "UPDATE \"{table}\" SET {} WHERE \"id\" = $1{guard} RETURNING *;",
             set_clauses.join(", ")
         );
 
@@ -438,6 +535,16 @@ impl SchemaBackend for PgBackend {
         // DOUBLE PRECISION whenever the schema is (re)stored — this is
         // the canonical "schema is authoritative" checkpoint. See GH #37.
         self.repair_float_columns(definition).await?;
+        // Same checkpoint: tables created before entity versioning gain
+        // the `_version` column (existing rows start at version 1).
+        for stmt in version_ddl_statements(definition.name.as_str()) {
+            sqlx::query(&stmt)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| BackendError::QueryError {
+                    message: format!("failed to add version column: {e}"),
+                })?;
+        }
 
         let json = serde_json::to_value(definition).map_err(|e| BackendError::Internal {
             message: format!("failed to serialize schema metadata: {e}"),
@@ -516,13 +623,47 @@ impl EntityStore for PgBackend {
         let row: PgRow = sqlx::query_with(&sql, args)
             .fetch_one(&self.pool)
             .await
-            .map_err(|e| BackendError::QueryError {
-                message: format!("failed to create entity: {e}"),
-            })?;
+            .map_err(|e| write_error(e, &entity.schema, schema_def.as_ref(), "create entity"))?;
 
         row_to_entity(&row, &entity.schema, schema_def.as_ref())
     }
 
+    async fn create_many(&self, entities: &[Entity]) -> Result<Vec<Entity>, BackendError> {
+        if entities.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| BackendError::ConnectionError {
+                message: format!("failed to begin transaction: {e}"),
+            })?;
+
+        // Dropping `tx` on an early return rolls the whole batch back.
+        let mut created = Vec::with_capacity(entities.len());
+        for (index, entity) in entities.iter().enumerate() {
+            let schema_def = self.load_schema_metadata(&entity.schema).await?;
+            let (sql, args) = Self::build_insert(entity, schema_def.as_ref())?;
+
+            let row: PgRow = sqlx::query_with(&sql, args)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| BackendError::BatchItemFailed {
+                    index,
+                    reason: e.to_string(),
+                })?;
+            created.push(row_to_entity(&row, &entity.schema, schema_def.as_ref())?);
+        }
+
+        tx.commit().await.map_err(|e| BackendError::QueryError {
+            message: format!("failed to commit batch: {e}"),
+        })?;
+
+        Ok(created)
+    }
+
     async fn get(&self, schema: &SchemaName, id: &EntityId) -> Result<Entity, BackendError> {
         let schema_def = self.load_schema_metadata(schema).await?;
         let table = schema.as_str();
@@ -545,29 +686,85 @@ impl EntityStore for PgBackend {
         }
     }
 
-    async fn update(&self, entity: &Entity) -> Result<Entity, BackendError> {
+    async fn update(
+        &self,
+        entity: &Entity,
+        expected_version: Option<u64>,
+    ) -> Result<Entity, BackendError> {
         let schema_def = self.load_schema_metadata(&entity.schema).await?;
-        let (sql, args) = Self::build_update(entity, schema_def.as_ref())?;
+        let (sql, args) = Self::build_update(entity, schema_def.as_ref(), expected_version)?;
 
         let row: Option<PgRow> = sqlx::query_with(&sql, args)
             .fetch_optional(&self.pool)
             .await
-            .map_err(|e| BackendError::QueryError {
-                message: format!("failed to update entity: {e}"),
-            })?;
+            .map_err(|e| write_error(e, &entity.schema, schema_def.as_ref(), "update entity"))?;
 
-        match row {
-            None => Err(BackendError::EntityNotFound {
+        match (row, expected_version) {
+            (Some(row), _) => row_to_entity(&row, &entity.schema, schema_def.as_ref()),
+            (None, None) => Err(BackendError::EntityNotFound {
                 schema: entity.schema.as_str().to_string(),
                 entity_id: entity.id.as_str().to_string(),
             }),
-            Some(row) => row_to_entity(&row, &entity.schema, schema_def.as_ref()),
+            // Either the row is gone (get reports it) or the version guard
+            // rejected the write.
+            (None, Some(expected)) => {
+                let current = self.get(&entity.schema, &entity.id).await?;
+                Err(BackendError::Conflict {
+                    schema: entity.schema.as_str().to_string(),
+                    entity_id: entity.id.as_str().to_string(),
+                    expected,
+                    actual: current.version,
+                })
+            }
         }
     }
 
+    async fn patch(
+        &self,
+        schema: &SchemaName,
+        id: &EntityId,
+        changes: BTreeMap<String, DynamicValue>,
+        expected_version: Option<u64>,
+    ) -> Result<Entity, BackendError> {
+        if changes.is_empty() {
+            return self.get(schema, id).await?.expect_version(expected_version);
+        }
+        // build_update only SETs the columns present on the entity, so the
+        // untouched columns keep their stored values.
+        self.update(
+            &Entity::with_id(id.clone(), schema.clone(), changes),
+            expected_version,
+        )
+        .await
+    }
+
     async fn delete(&self, schema: &SchemaName, id: &EntityId) -> Result<(), BackendError> {
         let table = schema.as_str();
 
+        let soft_delete = self
+            .load_schema_metadata(schema)
+            .await?
+            .is_some_and(|def| def.is_soft_delete());
+        if soft_delete {
+            let result = sqlx::query(&format!(
+                "UPDATE \"{table}\" SET \"{DELETED_AT_FIELD}\" = now() \
+                 WHERE \"id\" = $1 AND \"{DELETED_AT_FIELD}\" IS NULL;"
+            ))
+            .bind(id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BackendError::QueryError {
+                message: format!("failed to soft-delete entity: {e}"),
+            })?;
+            if result.rows_affected() == 0 {
+                return Err(BackendError::EntityNotFound {
+                    schema: table.to_string(),
+                    entity_id: id.as_str().to_string(),
+                });
+            }
+            return Ok(());
+        }
+
         // Check existence first
         let exists: bool = sqlx::query_scalar(&format!(
             "SELECT EXISTS(SELECT 1 FROM \"{table}\" WHERE \"id\" = $1);"
@@ -597,10 +794,33 @@ impl EntityStore for PgBackend {
         Ok(())
     }
 
+    async fn restore(&self, schema: &SchemaName, id: &EntityId) -> Result<Entity, BackendError> {
+        let table = schema.as_str();
+        let schema_def = self.load_schema_metadata(schema).await?;
+        let row: Option<PgRow> = sqlx::query(&format!(
+            "UPDATE \"{table}\" SET \"{DELETED_AT_FIELD}\" = NULL WHERE \"id\" = $1 RETURNING *;"
+        ))
+        .bind(id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| BackendError::QueryError {
+            message: format!("failed to restore entity: {e}"),
+        })?;
+
+        match row {
+            None => Err(BackendError::EntityNotFound {
+                schema: table.to_string(),
+                entity_id: id.as_str().to_string(),
+            }),
+            Some(row) => row_to_entity(&row, schema, schema_def.as_ref()),
+        }
+    }
+
     async fn query(&self, query: &Query) -> Result<QueryResult, BackendError> {
         let schema_def = self.resolve_schema_for_query(&query.schema).await?;
+        let page = prepare_query(query, &schema_def)?;
         let table = schema_def.name.as_str();
-        let compiled = query_to_sql(query, table);
+        let compiled = query_to_sql_with_schema(&page, table, Some(&schema_def));
         let args = Self::bind_params(&compiled.params)?;
 
         // When `include_total` is set, compute the SELECT and the COUNT(*)
@@ -611,7 +831,7 @@ impl EntityStore for PgBackend {
         let main_fut = sqlx::query_with(&compiled.sql, args).fetch_all(&self.pool);
 
         let rows: Vec<PgRow> = if query.include_total {
-            let count_compiled = count_to_sql(query, table);
+            let count_compiled = count_to_sql_with_schema(query, table, Some(&schema_def));
             let count_args = Self::bind_params(&count_compiled.params)?;
             let count_fut = sqlx::query_with(&count_compiled.sql, count_args)
                 .fetch_one(&self.pool);
@@ -633,7 +853,7 @@ impl EntityStore for PgBackend {
             for row in &rows {
                 entities.push(row_to_entity(row, &schema_name, Some(&schema_def))?);
             }
-            return Ok(QueryResult::new(entities, Some(total as usize)));
+            return Ok(QueryResult::new(entities, Some(total as usize)).with_next_cursor(query));
         } else {
             main_fut.await.map_err(|e| BackendError::QueryError {
                 message: format!("failed to execute query: {e}"),
@@ -645,13 +865,29 @@ impl EntityStore for PgBackend {
         for row in &rows {
             entities.push(row_to_entity(row, &schema_name, Some(&schema_def))?);
         }
-        Ok(QueryResult::new(entities, None))
+        Ok(QueryResult::new(entities, None).with_next_cursor(query))
+    }
+
+    async fn delete_by_query(&self, query: &Query) -> Result<usize, BackendError> {
+        let schema_def = self.resolve_schema_for_query(&query.schema).await?;
+        let table = schema_def.name.as_str();
+        let compiled = delete_to_sql_with_schema(query, table, Some(&schema_def));
+        let args = Self::bind_params(&compiled.params)?;
+
+        let result = sqlx::query_with(&compiled.sql, args)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| BackendError::QueryError {
+                message: format!("failed to delete entities: {e}"),
+            })?;
+
+        Ok(result.rows_affected() as usize)
     }
 
     async fn count(&self, query: &Query) -> Result<usize, BackendError> {
         let schema_def = self.resolve_schema_for_query(&query.schema).await?;
         let table = schema_def.name.as_str();
-        let compiled = count_to_sql(query, table);
+        let compiled = count_to_sql_with_schema(query, table, Some(&schema_def));
         let args = Self::bind_params(&compiled.params)?;
 
         let row: PgRow = sqlx::query_with(&compiled.sql, args)
@@ -673,42 +909,75 @@ impl EntityStore for PgBackend {
         query: &AggregateQuery,
     ) -> Result<Vec<AggregateResult>, BackendError> {
         let schema_def = self.resolve_schema_for_query(&query.schema).await?;
+        validate_aggregate(query, &schema_def)
+            .map_err(|errors| validation_error(&errors, "aggregate"))?;
         let table = schema_def.name.as_str();
-        let compiled = aggregate_to_sql(query, table);
+        let compiled = aggregate_to_sql_with_schema(query, table, Some(&schema_def));
         let args = Self::bind_params(&compiled.params)?;
 
-        let row: Option<PgRow> = sqlx::query_with(&compiled.sql, args)
-            .fetch_optional(&self.pool)
+        let rows: Vec<PgRow> = sqlx::query_with(&compiled.sql, args)
+            .fetch_all(&self.pool)
             .await
             .map_err(|e| BackendError::QueryError {
                 message: format!("failed to execute aggregate query: {e}"),
             })?;
 
-        let mut results = Vec::with_capacity(query.ops.len());
-        match row {
-            Some(row) => {
-                for (i, op) in query.ops.iter().enumerate() {
-                    let key = format!("agg_{i}");
-                    let value: f64 = row
-                        .try_get::<f64, _>(key.as_str())
-                        .or_else(|_| row.try_get::<i64, _>(key.as_str()).map(|v| v as f64))
-                        .unwrap_or(0.0);
-                    results.push(AggregateResult {
-                        op: op.clone(),
-                        value,
-                    });
-                }
+        if rows.is_empty() && query.group_by.is_empty() {
+            return Ok(query
+                .ops
+                .iter()
+                .map(|op| AggregateResult::new(op.clone(), 0.0))
+                .collect());
+        }
+
+        // One row per group (a single row when ungrouped).
+        let mut results = Vec::with_capacity(rows.len() * query.ops.len());
+        for row in &rows {
+            let mut group = BTreeMap::new();
+            for field in &query.group_by {
+                let field_type = schema_def.field(field.as_str()).map(|fd| &fd.field_type);
+                let value = read_column(row, field.as_str(), field_type)?;
+                group.insert(field.as_str().to_string(), value);
             }
-            None => {
-                for op in &query.ops {
-                    results.push(AggregateResult {
-                        op: op.clone(),
-                        value: 0.0,
-                    });
-                }
+            for (i, op) in query.ops.iter().enumerate() {
+                let key = format!("agg_{i}");
+                let value: f64 = row
+                    .try_get::<f64, _>(key.as_str())
+                    .or_else(|_| row.try_get::<i64, _>(key.as_str()).map(|v| v as f64))
+                    .unwrap_or(0.0);
+                results.push(AggregateResult::new(op.clone(), value).with_group(group.clone()));
             }
         }
 
         Ok(results)
     }
+
+    async fn next_sequence_value(
+        &self,
+        schema: &SchemaName,
+        field: &FieldName,
+        start: i64,
+    ) -> Result<i64, BackendError> {
+        // The upsert takes a row lock on the counter, so concurrent callers
+        // serialize on it and each sees a distinct value.
+        let row: PgRow = sqlx::query(&format!(
+            "INSERT INTO \"{SEQUENCE_TABLE}\" (\"schema_name\", \"field_name\", \"value\") \
+             VALUES ($1, $2, $3 + 1) \
+             ON CONFLICT (\"schema_name\", \"field_name\") \
+             DO UPDATE SET \"value\" = \"{SEQUENCE_TABLE}\".\"value\" + 1 \
+             RETURNING \"value\";"
+        ))
+        .bind(schema.as_str())
+        .bind(field.as_str())
+        .bind(start)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| BackendError::QueryError {
+            message: format!("failed to advance sequence {schema}.{field}: {e}"),
+        })?;
+
+        row.try_get("value").map_err(|e| BackendError::Internal {
+            message: format!("failed to read sequence value: {e}"),
+        })
+    }
 }
@@ -3,9 +3,13 @@
 //! No I/O. No side effects. Each function takes schema-forge-core types
 //! and returns one or more PostgreSQL statement strings.
 
-use schema_forge_core::migration::MigrationStep;
+use schema_forge_backend::entity::VERSION_FIELD;
+use std::collections::BTreeMap;
+
+use schema_forge_core::migration::{MigrationStep, ValueTransform};
 use schema_forge_core::types::{
-    Cardinality, FieldDefinition, FieldModifier, FieldType, IntegerConstraints, TextConstraints,
+    Cardinality, EnumVariants, FieldDefinition, FieldModifier, FieldName, FieldType,
+    IntegerConstraints, SchemaDefinition, TextConstraints, DELETED_AT_FIELD,
 };
 
 /// Compile a single `MigrationStep` into a list of PostgreSQL DDL statements.
@@ -19,7 +23,10 @@ use schema_forge_core::types::{
 pub fn migration_step_to_sql(table: &str, step: &MigrationStep) -> Vec<String> {
     match step {
         MigrationStep::CreateSchema { name: _, fields } => {
-            let mut column_defs = vec!["\"id\" TEXT PRIMARY KEY".to_string()];
+            let mut column_defs = vec![
+                "\"id\" TEXT PRIMARY KEY".to_string(),
+                format!("\"{VERSION_FIELD}\" BIGINT NOT NULL DEFAULT 1"),
+            ];
             let mut post_stmts = Vec::new();
 
             for field in fields {
@@ -61,11 +68,12 @@ pub fn migration_step_to_sql(table: &str, step: &MigrationStep) -> Vec<String> {
             // Add default value
             for modifier in &field.modifiers {
                 if let FieldModifier::Default { value } = modifier {
-                    let literal = default_value_to_sql(value);
-                    stmts.push(format!(
-                        "ALTER TABLE \"{table}\" ALTER COLUMN \"{}\" SET DEFAULT {literal};",
-                        field.name
-                    ));
+                    if let Some(literal) = default_value_to_sql(value) {
+                        stmts.push(format!(
+                            "ALTER TABLE \"{table}\" ALTER COLUMN \"{}\" SET DEFAULT {literal};",
+                            field.name
+                        ));
+                    }
                 }
             }
 
@@ -77,6 +85,9 @@ pub fn migration_step_to_sql(table: &str, step: &MigrationStep) -> Vec<String> {
                     field.name
                 ));
             }
+            if field.is_unique() {
+                stmts.push(create_unique_index_stmt(table, &field.name));
+            }
 
             stmts
         }
@@ -94,7 +105,7 @@ pub fn migration_step_to_sql(table: &str, step: &MigrationStep) -> Vec<String> {
             name,
             old_type,
             new_type,
-            transform: _,
+            transform,
         } => {
             let old_is_enum = matches!(old_type, FieldType::Enum(_));
             let new_is_enum = matches!(new_type, FieldType::Enum(_));
@@ -110,8 +121,31 @@ pub fn migration_step_to_sql(table: &str, step: &MigrationStep) -> Vec<String> {
             let old_pg = field_type_to_pg(old_type);
             let new_pg = field_type_to_pg(new_type);
             if old_pg != new_pg {
+                let using = match transform {
+                    // Blank text becomes null; other unparseable text fails
+                    // the cast.
+                    ValueTransform::ParseNumber => {
+                        format!("NULLIF(trim(\"{name}\"), '')::{new_pg}")
+                    }
+                    ValueTransform::Truncate { max_length } => {
+                        format!("left(\"{name}\", {max_length})::{new_pg}")
+                    }
+                    _ => format!("\"{name}\"::{new_pg}"),
+                };
                 stmts.push(format!(
-                    "ALTER TABLE \"{table}\" ALTER COLUMN \"{name}\" TYPE {new_pg} USING \"{name}\"::{new_pg};"
+                    "ALTER TABLE \"{table}\" ALTER COLUMN \"{name}\" TYPE {new_pg} USING {using};"
+                ));
+            }
+
+            // Unmapped values without a default are left for the new CHECK
+            // to reject, failing the migration.
+            if let ValueTransform::MapText { mapping, default } = transform {
+                stmts.extend(map_text_stmts(
+                    table,
+                    name.as_ref(),
+                    new_type,
+                    mapping,
+                    default.as_deref(),
                 ));
             }
 
@@ -129,6 +163,38 @@ pub fn migration_step_to_sql(table: &str, step: &MigrationStep) -> Vec<String> {
 
             stmts
         }
+        MigrationStep::AddEnumVariant {
+            field, variants, ..
+        } => replace_enum_check(table, field.as_ref(), variants, None),
+        MigrationStep::RemoveEnumVariant {
+            field,
+            variant,
+            fallback,
+            variants,
+        } => {
+            let replacement = match fallback {
+                Some(v) => format!("'{}'", escape_sql_string(v)),
+                None => "NULL".to_string(),
+            };
+            let rewrite = format!(
+                "UPDATE \"{table}\" SET \"{field}\" = {replacement} WHERE \"{field}\" = '{}';",
+                escape_sql_string(variant)
+            );
+            replace_enum_check(table, field.as_ref(), variants, Some(rewrite))
+        }
+        MigrationStep::RenameEnumVariant {
+            field,
+            old_variant,
+            new_variant,
+            variants,
+        } => {
+            let rewrite = format!(
+                "UPDATE \"{table}\" SET \"{field}\" = '{}' WHERE \"{field}\" = '{}';",
+                escape_sql_string(new_variant),
+                escape_sql_string(old_variant)
+            );
+            replace_enum_check(table, field.as_ref(), variants, Some(rewrite))
+        }
         MigrationStep::AddIndex { field } => {
             let idx_name = format!("idx_{table}_{field}");
             vec![format!(
@@ -139,6 +205,24 @@ pub fn migration_step_to_sql(table: &str, step: &MigrationStep) -> Vec<String> {
             let idx_name = format!("idx_{table}_{field}");
             vec![format!("DROP INDEX IF EXISTS \"{idx_name}\";")]
         }
+        MigrationStep::AddUnique { field } => vec![create_unique_index_stmt(table, field)],
+        MigrationStep::RemoveUnique { field } => {
+            let idx_name = unique_index_name(table, field);
+            vec![format!("DROP INDEX IF EXISTS \"{idx_name}\";")]
+        }
+        MigrationStep::AddCompositeIndex { fields, unique } => {
+            let idx_name = composite_index_name(table, fields);
+            let columns: Vec<String> = fields.iter().map(|f| format!("\"{f}\"")).collect();
+            let unique = if *unique { "UNIQUE " } else { "" };
+            vec![format!(
+                "CREATE {unique}INDEX IF NOT EXISTS \"{idx_name}\" ON \"{table}\" ({});",
+                columns.join(", ")
+            )]
+        }
+        MigrationStep::RemoveCompositeIndex { fields, .. } => {
+            let idx_name = composite_index_name(table, fields);
+            vec![format!("DROP INDEX IF EXISTS \"{idx_name}\";")]
+        }
         MigrationStep::AddRelation {
             name,
             target,
@@ -184,17 +268,27 @@ pub fn migration_step_to_sql(table: &str, step: &MigrationStep) -> Vec<String> {
                 "ALTER TABLE \"{table}\" ALTER COLUMN \"{field}\" DROP NOT NULL;"
             )]
         }
-        MigrationStep::SetDefault { field, value } => {
-            let literal = default_value_to_sql(value);
-            vec![format!(
+        MigrationStep::SetDefault { field, value, .. } => match default_value_to_sql(value) {
+            Some(literal) => vec![format!(
                 "ALTER TABLE \"{table}\" ALTER COLUMN \"{field}\" SET DEFAULT {literal};"
-            )]
-        }
-        MigrationStep::RemoveDefault { field } => {
+            )],
+            // Sequence defaults are resolved on create, not by the database.
+            None => vec![format!(
+                "ALTER TABLE \"{table}\" ALTER COLUMN \"{field}\" DROP DEFAULT;"
+            )],
+        },
+        MigrationStep::RemoveDefault { field, .. } => {
             vec![format!(
                 "ALTER TABLE \"{table}\" ALTER COLUMN \"{field}\" DROP DEFAULT;"
             )]
         }
+        MigrationStep::EnableSoftDelete => vec![format!(
+            "ALTER TABLE \"{table}\" ADD COLUMN IF NOT EXISTS \"{DELETED_AT_FIELD}\" TIMESTAMPTZ;"
+        )],
+        MigrationStep::DisableSoftDelete => vec![
+            format!("DELETE FROM \"{table}\" WHERE \"{DELETED_AT_FIELD}\" IS NOT NULL;"),
+            format!("ALTER TABLE \"{table}\" DROP COLUMN IF EXISTS \"{DELETED_AT_FIELD}\";"),
+        ],
         _ => {
             // Future MigrationStep variants -- produce a no-op comment.
             vec![format!(
@@ -209,14 +303,19 @@ pub fn field_type_to_pg(field_type: &FieldType) -> String {
     match field_type {
         FieldType::Text(TextConstraints {
             max_length: Some(max),
+            ..
         }) => format!("VARCHAR({max})"),
-        FieldType::Text(_) | FieldType::RichText => "TEXT".to_string(),
+        FieldType::Text(_) | FieldType::RichText(_) => "TEXT".to_string(),
         FieldType::Integer(_) => "BIGINT".to_string(),
-        // `FloatConstraints.precision` is intentionally ignored on Postgres; a future `decimal`
-        // type will handle fixed-scale currency. See issue #7.
+        // `FloatConstraints.precision` is intentionally ignored on Postgres; fixed-scale
+        // amounts such as currency use `decimal` instead. See issue #7.
         FieldType::Float(_) => "DOUBLE PRECISION".to_string(),
+        FieldType::Decimal { scale } => format!("NUMERIC(38, {scale})"),
         FieldType::Boolean => "BOOLEAN".to_string(),
         FieldType::DateTime => "TIMESTAMPTZ".to_string(),
+        // ISO-8601 text; `INTERVAL` would normalize away the written form.
+        FieldType::Duration => "TEXT".to_string(),
+        FieldType::Uuid => "UUID".to_string(),
         FieldType::Enum(_) => "TEXT".to_string(),
         FieldType::Json => "JSONB".to_string(),
         FieldType::Relation {
@@ -224,7 +323,7 @@ pub fn field_type_to_pg(field_type: &FieldType) -> String {
             ..
         } => "TEXT[]".to_string(),
         FieldType::Relation { .. } => "TEXT".to_string(),
-        FieldType::Array(inner) => {
+        FieldType::Array(inner, _) => {
             let inner_type = field_type_to_pg(inner);
             format!("{inner_type}[]")
         }
@@ -234,29 +333,80 @@ pub fn field_type_to_pg(field_type: &FieldType) -> String {
     }
 }
 
+/// `UPDATE`s for a `MapText` transform: one `CASE` rewrite of the mapped
+/// values, so a mapping never applies twice, then the default for values
+/// that are still not a variant of the new enum.
+fn map_text_stmts(
+    table: &str,
+    name: &str,
+    new_type: &FieldType,
+    mapping: &BTreeMap<String, String>,
+    default: Option<&str>,
+) -> Vec<String> {
+    let quote = |v: &str| format!("'{}'", escape_sql_string(v));
+    let mut stmts = Vec::new();
+    if !mapping.is_empty() {
+        let cases: Vec<String> = mapping
+            .iter()
+            .map(|(from, to)| format!("WHEN {} THEN {}", quote(from), quote(to)))
+            .collect();
+        let keys: Vec<String> = mapping.keys().map(|k| quote(k)).collect();
+        stmts.push(format!(
+            "UPDATE \"{table}\" SET \"{name}\" = CASE \"{name}\" {} END WHERE \"{name}\" IN ({});",
+            cases.join(" "),
+            keys.join(", ")
+        ));
+    }
+    if let (Some(default), FieldType::Enum(variants)) = (default, new_type) {
+        let variants: Vec<String> = variants.iter().map(|v| quote(v)).collect();
+        stmts.push(format!(
+            "UPDATE \"{table}\" SET \"{name}\" = {} WHERE \"{name}\" NOT IN ({});",
+            quote(default),
+            variants.join(", ")
+        ));
+    }
+    stmts
+}
+
+/// Swap an enum column's CHECK constraint for one allowing `variants`,
+/// running `rewrite` in between so rows are never checked against either
+/// list mid-change.
+fn replace_enum_check(
+    table: &str,
+    field_name: &str,
+    variants: &EnumVariants,
+    rewrite: Option<String>,
+) -> Vec<String> {
+    let constraint_name = format!("chk_{table}_{field_name}_enum");
+    let mut stmts = vec![format!(
+        "ALTER TABLE \"{table}\" DROP CONSTRAINT IF EXISTS \"{constraint_name}\";"
+    )];
+    stmts.extend(rewrite);
+    let enum_type = FieldType::Enum(variants.clone());
+    for clause in field_check_constraints(table, field_name, &enum_type) {
+        stmts.push(format!("ALTER TABLE \"{table}\" ADD {clause};"));
+    }
+    stmts
+}
+
 /// Build CHECK constraint fragments for a field type.
 ///
 /// Returns constraint clauses to be appended to the column definition.
 fn field_check_constraints(table: &str, field_name: &str, field_type: &FieldType) -> Vec<String> {
     match field_type {
-        FieldType::Integer(IntegerConstraints { min, max }) => {
-            let mut constraints = Vec::new();
-            if min.is_some() || max.is_some() {
-                let constraint_name = format!("chk_{table}_{field_name}_range");
-                let mut parts = Vec::new();
-                if let Some(min_val) = min {
-                    parts.push(format!("\"{field_name}\" >= {min_val}"));
-                }
-                if let Some(max_val) = max {
-                    parts.push(format!("\"{field_name}\" <= {max_val}"));
-                }
-                constraints.push(format!(
-                    "CONSTRAINT \"{constraint_name}\" CHECK ({})",
-                    parts.join(" AND ")
-                ));
-            }
-            constraints
-        }
+        FieldType::Integer(IntegerConstraints { min, max }) => range_check(
+            table,
+            field_name,
+            min.map(|v| v.to_string()),
+            max.map(|v| v.to_string()),
+        ),
+        // Bounds are emitted from their parsed value, never the raw text.
+        FieldType::Float(c) => range_check(
+            table,
+            field_name,
+            c.min_value().map(|v| v.to_string()),
+            c.max_value().map(|v| v.to_string()),
+        ),
         FieldType::Enum(variants) => {
             let constraint_name = format!("chk_{table}_{field_name}_enum");
             let values: Vec<String> = variants.iter().map(|v| format!("'{v}'")).collect();
@@ -280,6 +430,31 @@ fn field_check_constraints(table: &str, field_name: &str, field_type: &FieldType
     }
 }
 
+/// Build the `_range` CHECK constraint for numeric bounds, if any.
+fn range_check(
+    table: &str,
+    field_name: &str,
+    min: Option<String>,
+    max: Option<String>,
+) -> Vec<String> {
+    let mut constraints = Vec::new();
+    if min.is_some() || max.is_some() {
+        let constraint_name = format!("chk_{table}_{field_name}_range");
+        let mut parts = Vec::new();
+        if let Some(min_val) = min {
+            parts.push(format!("\"{field_name}\" >= {min_val}"));
+        }
+        if let Some(max_val) = max {
+            parts.push(format!("\"{field_name}\" <= {max_val}"));
+        }
+        constraints.push(format!(
+            "CONSTRAINT \"{constraint_name}\" CHECK ({})",
+            parts.join(" AND ")
+        ));
+    }
+    constraints
+}
+
 /// Generate a column definition and any extra statements (like indexes) for a field.
 fn field_to_column_def(table: &str, field: &FieldDefinition) -> (String, Vec<String>) {
     let name = &field.name;
@@ -300,8 +475,9 @@ fn field_to_column_def(table: &str, field: &FieldDefinition) -> (String, Vec<Str
     // Default
     for modifier in &field.modifiers {
         if let FieldModifier::Default { value } = modifier {
-            let literal = default_value_to_sql(value);
-            parts.push(format!("DEFAULT {literal}"));
+            if let Some(literal) = default_value_to_sql(value) {
+                parts.push(format!("DEFAULT {literal}"));
+            }
         }
     }
 
@@ -312,18 +488,25 @@ fn field_to_column_def(table: &str, field: &FieldDefinition) -> (String, Vec<Str
             "CREATE INDEX IF NOT EXISTS \"{idx_name}\" ON \"{table}\" (\"{name}\");"
         ));
     }
+    if field.is_unique() {
+        extra_stmts.push(create_unique_index_stmt(table, name));
+    }
 
     (parts.join(" "), extra_stmts)
 }
 
 /// Convert a `DefaultValue` to its PostgreSQL literal representation.
-fn default_value_to_sql(value: &schema_forge_core::types::DefaultValue) -> String {
+///
+/// Returns `None` for sequence defaults, which have no column-level
+/// equivalent and are resolved on create instead.
+fn default_value_to_sql(value: &schema_forge_core::types::DefaultValue) -> Option<String> {
     use schema_forge_core::types::DefaultValue;
     match value {
-        DefaultValue::String(s) => format!("'{}'", escape_sql_string(s)),
-        DefaultValue::Integer(i) => i.to_string(),
-        DefaultValue::Float(s) => s.clone(),
-        DefaultValue::Boolean(b) => b.to_string(),
+        DefaultValue::String(s) => Some(format!("'{}'", escape_sql_string(s))),
+        DefaultValue::Integer(i) => Some(i.to_string()),
+        DefaultValue::Float(s) => Some(s.clone()),
+        DefaultValue::Boolean(b) => Some(b.to_string()),
+        DefaultValue::Sequence { .. } => None,
     }
 }
 
@@ -335,8 +518,11 @@ fn dynamic_value_to_sql_literal(value: &schema_forge_core::types::DynamicValue)
         DynamicValue::Text(s) => format!("'{}'", escape_sql_string(s)),
         DynamicValue::Integer(i) => i.to_string(),
         DynamicValue::Float(f) => format!("{f}"),
+        DynamicValue::Decimal(d) => d.to_string(),
         DynamicValue::Boolean(b) => b.to_string(),
         DynamicValue::DateTime(dt) => format!("'{}'", dt.to_rfc3339()),
+        DynamicValue::Duration(d) => format!("'{d}'"),
+        DynamicValue::Uuid(u) => format!("'{u}'"),
         DynamicValue::Enum(s) => format!("'{}'", escape_sql_string(s)),
         _ => "NULL".to_string(),
     }
@@ -353,6 +539,56 @@ pub fn tenant_ddl_statements(table: &str) -> Vec<String> {
     ]
 }
 
+/// Generate PostgreSQL statements to add the `_version` column used for
+/// optimistic concurrency.
+///
+/// `CreateSchema` declares the column inline; this is re-run idempotently
+/// when schema metadata is stored so tables created before versioning
+/// pick it up. Pure function, no I/O.
+pub fn version_ddl_statements(table: &str) -> Vec<String> {
+    vec![format!(
+        "ALTER TABLE IF EXISTS \"{table}\" ADD COLUMN IF NOT EXISTS \"{VERSION_FIELD}\" BIGINT NOT NULL DEFAULT 1;"
+    )]
+}
+
+/// Name of the index backing an `@index(fields: [...])` annotation. The
+/// `cidx_` prefix keeps it apart from the `idx_` index of an `indexed`
+/// field. A unique violation reports this name as its constraint.
+pub fn composite_index_name(table: &str, fields: &[FieldName]) -> String {
+    let columns: Vec<&str> = fields.iter().map(FieldName::as_str).collect();
+    format!("cidx_{table}_{}", columns.join("_"))
+}
+
+/// Name of the unique index backing a `unique` field.
+pub fn unique_index_name(table: &str, field: &FieldName) -> String {
+    format!("uniq_{table}_{field}")
+}
+
+/// `CREATE UNIQUE INDEX` for a `unique` field. NULLs stay distinct, so
+/// any number of entities may leave an optional unique field unset.
+fn create_unique_index_stmt(table: &str, field: &FieldName) -> String {
+    let idx_name = unique_index_name(table, field);
+    format!("CREATE UNIQUE INDEX IF NOT EXISTS \"{idx_name}\" ON \"{table}\" (\"{field}\");")
+}
+
+/// Every unique index of `definition` with the fields it covers: one per
+/// `unique` field and one per `@index(..., unique: true)`. A unique
+/// violation names one of these as its constraint.
+pub fn unique_indexes(definition: &SchemaDefinition) -> Vec<(String, Vec<FieldName>)> {
+    let table = definition.name.as_str();
+    let fields = definition
+        .fields
+        .iter()
+        .filter(|f| f.is_unique())
+        .map(|f| (unique_index_name(table, &f.name), vec![f.name.clone()]));
+    let composites = definition
+        .indexes()
+        .into_iter()
+        .filter(|(_, unique)| *unique)
+        .map(|(fields, _)| (composite_index_name(table, fields), fields.to_vec()));
+    fields.chain(composites).collect()
+}
+
 /// Escape single quotes in strings for PostgreSQL string literals.
 fn escape_sql_string(s: &str) -> String {
     s.replace('\'', "''")
@@ -361,9 +597,7 @@ fn escape_sql_string(s: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use schema_forge_core::types::{
-        DefaultValue, EnumVariants, FieldName, FloatConstraints, SchemaName,
-    };
+    use schema_forge_core::types::{DefaultValue, EnumVariants, FloatConstraints, SchemaName};
 
     fn text_field(name: &str) -> FieldDefinition {
         FieldDefinition::new(
@@ -389,6 +623,7 @@ mod tests {
         assert_eq!(stmts.len(), 1);
         assert!(stmts[0].starts_with("CREATE TABLE IF NOT EXISTS \"Contact\""));
         assert!(stmts[0].contains("\"id\" TEXT PRIMARY KEY"));
+        assert!(stmts[0].contains("\"_version\" BIGINT NOT NULL DEFAULT 1"));
         assert!(stmts[0].contains("\"name\" TEXT"));
         assert!(stmts[0].contains("\"email\" TEXT"));
     }
@@ -442,6 +677,22 @@ mod tests {
         assert!(stmts[0].contains("\"score\" <= 100"));
     }
 
+    #[test]
+    fn add_field_float_with_bounds() {
+        let step = MigrationStep::AddField {
+            field: FieldDefinition::new(
+                FieldName::new("ratio").unwrap(),
+                FieldType::Float(FloatConstraints::with_bounds(Some("0"), Some("1.5")).unwrap()),
+            ),
+        };
+        let stmts = migration_step_to_sql("Contact", &step);
+        assert_eq!(stmts.len(), 1);
+        assert!(stmts[0].contains("\"ratio\" DOUBLE PRECISION"));
+        assert!(stmts[0].contains(
+            "CONSTRAINT \"chk_Contact_ratio_range\" CHECK (\"ratio\" >= 0 AND \"ratio\" <= 1.5)"
+        ));
+    }
+
     #[test]
     fn add_field_boolean() {
         let step = MigrationStep::AddField {
@@ -531,6 +782,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn soft_delete_steps_add_and_drop_marker_column() {
+        let stmts = migration_step_to_sql("Contact", &MigrationStep::EnableSoftDelete);
+        assert_eq!(
+            stmts,
+            vec!["ALTER TABLE \"Contact\" ADD COLUMN IF NOT EXISTS \"_deleted_at\" TIMESTAMPTZ;"]
+        );
+        let stmts = migration_step_to_sql("Contact", &MigrationStep::DisableSoftDelete);
+        assert_eq!(
+            stmts,
+            vec![
+                "DELETE FROM \"Contact\" WHERE \"_deleted_at\" IS NOT NULL;",
+                "ALTER TABLE \"Contact\" DROP COLUMN IF EXISTS \"_deleted_at\";",
+            ]
+        );
+    }
+
     #[test]
     fn rename_field() {
         let step = MigrationStep::RenameField {
@@ -566,6 +834,51 @@ mod tests {
         assert_eq!(stmts, vec!["DROP INDEX IF EXISTS \"idx_Contact_email\";"]);
     }
 
+    #[test]
+    fn add_and_remove_unique() {
+        let field = FieldName::new("email").unwrap();
+        let add = MigrationStep::AddUnique {
+            field: field.clone(),
+        };
+        assert_eq!(
+            migration_step_to_sql("Contact", &add),
+            vec![
+                "CREATE UNIQUE INDEX IF NOT EXISTS \"uniq_Contact_email\" ON \"Contact\" (\"email\");"
+            ]
+        );
+        let remove = MigrationStep::RemoveUnique { field };
+        assert_eq!(
+            migration_step_to_sql("Contact", &remove),
+            vec!["DROP INDEX IF EXISTS \"uniq_Contact_email\";"]
+        );
+    }
+
+    #[test]
+    fn add_and_remove_composite_index() {
+        let fields = vec![
+            FieldName::new("agency").unwrap(),
+            FieldName::new("stage").unwrap(),
+        ];
+        let add = MigrationStep::AddCompositeIndex {
+            fields: fields.clone(),
+            unique: true,
+        };
+        assert_eq!(
+            migration_step_to_sql("Deal", &add),
+            vec![
+                "CREATE UNIQUE INDEX IF NOT EXISTS \"cidx_Deal_agency_stage\" ON \"Deal\" (\"agency\", \"stage\");"
+            ]
+        );
+        let remove = MigrationStep::RemoveCompositeIndex {
+            fields,
+            unique: true,
+        };
+        assert_eq!(
+            migration_step_to_sql("Deal", &remove),
+            vec!["DROP INDEX IF EXISTS \"cidx_Deal_agency_stage\";"]
+        );
+    }
+
     #[test]
     fn add_relation_one() {
         let step = MigrationStep::AddRelation {
@@ -600,7 +913,7 @@ mod tests {
             field_type_to_pg(&FieldType::Text(TextConstraints::with_max_length(255))),
             "VARCHAR(255)"
         );
-        assert_eq!(field_type_to_pg(&FieldType::RichText), "TEXT");
+        assert_eq!(field_type_to_pg(&FieldType::rich_text()), "TEXT");
         assert_eq!(
             field_type_to_pg(&FieldType::Integer(IntegerConstraints::unconstrained())),
             "BIGINT"
@@ -615,9 +928,15 @@ mod tests {
         );
         assert_eq!(field_type_to_pg(&FieldType::Boolean), "BOOLEAN");
         assert_eq!(field_type_to_pg(&FieldType::DateTime), "TIMESTAMPTZ");
+        assert_eq!(field_type_to_pg(&FieldType::Duration), "TEXT");
+        assert_eq!(field_type_to_pg(&FieldType::Uuid), "UUID");
+        assert_eq!(
+            field_type_to_pg(&FieldType::Decimal { scale: 2 }),
+            "NUMERIC(38, 2)"
+        );
         assert_eq!(field_type_to_pg(&FieldType::Json), "JSONB");
         assert_eq!(
-            field_type_to_pg(&FieldType::Array(Box::new(FieldType::Boolean))),
+            field_type_to_pg(&FieldType::array(FieldType::Boolean)),
             "BOOLEAN[]"
         );
         assert_eq!(field_type_to_pg(&sample_file_field_type()), "JSONB");
@@ -756,6 +1075,7 @@ mod tests {
         let step = MigrationStep::SetDefault {
             field: FieldName::new("status").unwrap(),
             value: DefaultValue::String("active".into()),
+            previous: None,
         };
         let stmts = migration_step_to_sql("Contact", &step);
         assert_eq!(
@@ -764,10 +1084,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_sequence_default_drops_column_default() {
+        let step = MigrationStep::SetDefault {
+            field: FieldName::new("number").unwrap(),
+            value: DefaultValue::Sequence {
+                prefix: "INV-".into(),
+                start: 1000,
+            },
+            previous: None,
+        };
+        let stmts = migration_step_to_sql("Invoice", &step);
+        assert_eq!(
+            stmts,
+            vec!["ALTER TABLE \"Invoice\" ALTER COLUMN \"number\" DROP DEFAULT;"]
+        );
+    }
+
     #[test]
     fn remove_default() {
         let step = MigrationStep::RemoveDefault {
             field: FieldName::new("status").unwrap(),
+            previous: None,
         };
         let stmts = migration_step_to_sql("Contact", &step);
         assert_eq!(
@@ -801,6 +1139,56 @@ mod tests {
         assert!(!stmts.iter().any(|s| s.contains("ALTER COLUMN")));
     }
 
+    #[test]
+    fn add_enum_variant_replaces_check_constraint() {
+        let step = MigrationStep::AddEnumVariant {
+            field: FieldName::new("status").unwrap(),
+            variant: "archived".into(),
+            variants: EnumVariants::new(vec!["active".into(), "archived".into()]).unwrap(),
+        };
+        let stmts = migration_step_to_sql("Contact", &step);
+        assert_eq!(
+            stmts,
+            [
+                "ALTER TABLE \"Contact\" DROP CONSTRAINT IF EXISTS \"chk_Contact_status_enum\";",
+                "ALTER TABLE \"Contact\" ADD CONSTRAINT \"chk_Contact_status_enum\" CHECK (\"status\" IN ('active', 'archived'));",
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_enum_variant_updates_before_new_check() {
+        let step = MigrationStep::RemoveEnumVariant {
+            field: FieldName::new("status").unwrap(),
+            variant: "it's".into(),
+            fallback: None,
+            variants: EnumVariants::new(vec!["a".into()]).unwrap(),
+        };
+        let stmts = migration_step_to_sql("Thing", &step);
+        assert_eq!(stmts.len(), 3);
+        assert_eq!(
+            stmts[1],
+            "UPDATE \"Thing\" SET \"status\" = NULL WHERE \"status\" = 'it''s';"
+        );
+        assert!(stmts[2].contains("CHECK (\"status\" IN ('a'))"));
+    }
+
+    #[test]
+    fn rename_enum_variant_rewrites_values() {
+        let step = MigrationStep::RenameEnumVariant {
+            field: FieldName::new("status").unwrap(),
+            old_variant: "done".into(),
+            new_variant: "closed".into(),
+            variants: EnumVariants::new(vec!["open".into(), "closed".into()]).unwrap(),
+        };
+        let stmts = migration_step_to_sql("Ticket", &step);
+        assert_eq!(stmts.len(), 3);
+        assert_eq!(
+            stmts[1],
+            "UPDATE \"Ticket\" SET \"status\" = 'closed' WHERE \"status\" = 'done';"
+        );
+    }
+
     #[test]
     fn change_type_enum_to_non_enum_drops_check_constraint() {
         let old_type = FieldType::Enum(EnumVariants::new(vec!["a".into(), "b".into()]).unwrap());
@@ -847,6 +1235,107 @@ mod tests {
         assert!(stmts[0].contains("ALTER COLUMN \"count\" TYPE BIGINT"));
     }
 
+    #[test]
+    fn change_type_float_to_decimal_casts_to_numeric() {
+        let step = MigrationStep::ChangeType {
+            name: FieldName::new("amount").unwrap(),
+            old_type: FieldType::Float(Default::default()),
+            new_type: FieldType::Decimal { scale: 2 },
+            transform: ValueTransform::FloatToDecimal { scale: 2 },
+        };
+        let stmts = migration_step_to_sql("Invoice", &step);
+        assert_eq!(
+            stmts,
+            vec![
+                "ALTER TABLE \"Invoice\" ALTER COLUMN \"amount\" TYPE NUMERIC(38, 2) USING \"amount\"::NUMERIC(38, 2);"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn change_type_text_to_uuid_casts_to_uuid() {
+        let step = MigrationStep::ChangeType {
+            name: FieldName::new("external_id").unwrap(),
+            old_type: FieldType::Text(Default::default()),
+            new_type: FieldType::Uuid,
+            transform: ValueTransform::ParseUuid,
+        };
+        let stmts = migration_step_to_sql("Account", &step);
+        assert_eq!(
+            stmts,
+            vec![
+                "ALTER TABLE \"Account\" ALTER COLUMN \"external_id\" TYPE UUID USING \"external_id\"::UUID;"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn change_type_truncate_cuts_long_text() {
+        let step = MigrationStep::ChangeType {
+            name: FieldName::new("bio").unwrap(),
+            old_type: FieldType::Text(TextConstraints::with_max_length(255)),
+            new_type: FieldType::Text(TextConstraints::with_max_length(50)),
+            transform: ValueTransform::Truncate { max_length: 50 },
+        };
+        let stmts = migration_step_to_sql("Contact", &step);
+        assert_eq!(
+            stmts,
+            vec![
+                "ALTER TABLE \"Contact\" ALTER COLUMN \"bio\" TYPE VARCHAR(50) USING left(\"bio\", 50)::VARCHAR(50);"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn change_type_parse_number_nulls_blank_text() {
+        let step = MigrationStep::ChangeType {
+            name: FieldName::new("amount").unwrap(),
+            old_type: FieldType::Text(Default::default()),
+            new_type: FieldType::Integer(IntegerConstraints::unconstrained()),
+            transform: ValueTransform::ParseNumber,
+        };
+        let stmts = migration_step_to_sql("Deal", &step);
+        assert_eq!(
+            stmts,
+            vec![
+                "ALTER TABLE \"Deal\" ALTER COLUMN \"amount\" TYPE BIGINT USING NULLIF(trim(\"amount\"), '')::BIGINT;"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn change_type_map_text_rewrites_before_check() {
+        let step = MigrationStep::ChangeType {
+            name: FieldName::new("status").unwrap(),
+            old_type: FieldType::Text(Default::default()),
+            new_type: FieldType::Enum(
+                EnumVariants::new(vec!["Open".into(), "Won".into()]).unwrap(),
+            ),
+            transform: ValueTransform::MapText {
+                mapping: BTreeMap::from([
+                    ("open".into(), "Open".into()),
+                    ("won't".into(), "Won".into()),
+                ]),
+                default: Some("Open".into()),
+            },
+        };
+        let stmts = migration_step_to_sql("Deal", &step);
+        assert_eq!(stmts.len(), 3, "{stmts:?}");
+        assert_eq!(
+            stmts[0],
+            "UPDATE \"Deal\" SET \"status\" = CASE \"status\" WHEN 'open' THEN 'Open' WHEN 'won''t' THEN 'Won' END WHERE \"status\" IN ('open', 'won''t');"
+        );
+        assert_eq!(
+            stmts[1],
+            "UPDATE \"Deal\" SET \"status\" = 'Open' WHERE \"status\" NOT IN ('Open', 'Won');"
+        );
+        assert!(stmts[2].contains("CHECK"), "{}", stmts[2]);
+    }
+
     #[test]
     fn escape_single_quotes() {
         assert_eq!(escape_sql_string("it's"), "it''s");
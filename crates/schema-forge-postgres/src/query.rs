@@ -5,7 +5,7 @@
 //! parameter placeholders and a parallel list of `DynamicValue` bind values.
 
 use schema_forge_core::query::{AggregateOp, AggregateQuery, FieldPath, Filter, Query, SortOrder};
-use schema_forge_core::types::DynamicValue;
+use schema_forge_core::types::{DynamicValue, SchemaDefinition, DELETED_AT_FIELD};
 
 /// The output of query compilation: a SQL string plus ordered bind values.
 #[derive(Debug, Clone)]
@@ -18,6 +18,17 @@ pub struct CompiledQuery {
 ///
 /// The `table` argument is the PostgreSQL table name (derived from `SchemaName`).
 pub fn query_to_sql(query: &Query, table: &str) -> CompiledQuery {
+    query_to_sql_with_schema(query, table, None)
+}
+
+/// Like [`query_to_sql`] but carries the schema definition, so rows of a
+/// `@soft_delete` schema that carry a `_deleted_at` stamp are excluded
+/// unless the query sets `include_deleted`.
+pub fn query_to_sql_with_schema(
+    query: &Query,
+    table: &str,
+    schema: Option<&SchemaDefinition>,
+) -> CompiledQuery {
     let mut params = Vec::new();
     let select_clause = match &query.projection {
         None => "*".to_string(),
@@ -32,11 +43,11 @@ pub fn query_to_sql(query: &Query, table: &str) -> CompiledQuery {
         }
     };
     let mut sql = format!("SELECT {select_clause} FROM \"{table}\"");
-
-    if let Some(filter) = &query.filter {
-        let where_clause = filter_to_sql(filter, &mut params);
-        sql.push_str(&format!(" WHERE {where_clause}"));
-    }
+    sql.push_str(&where_clause(
+        query.filter.as_ref(),
+        query.hides_deleted(schema),
+        &mut params,
+    ));
 
     if !query.sort.is_empty() {
         sql.push_str(" ORDER BY ");
@@ -70,51 +81,132 @@ pub fn query_to_sql(query: &Query, table: &str) -> CompiledQuery {
 ///
 /// Ignores limit, offset, and sort -- only applies the filter.
 pub fn count_to_sql(query: &Query, table: &str) -> CompiledQuery {
+    count_to_sql_with_schema(query, table, None)
+}
+
+/// Schema-aware variant of [`count_to_sql`].
+pub fn count_to_sql_with_schema(
+    query: &Query,
+    table: &str,
+    schema: Option<&SchemaDefinition>,
+) -> CompiledQuery {
     let mut params = Vec::new();
     let mut sql = format!("SELECT COUNT(*) AS \"count\" FROM \"{table}\"");
+    sql.push_str(&where_clause(
+        query.filter.as_ref(),
+        query.hides_deleted(schema),
+        &mut params,
+    ));
 
-    if let Some(filter) = &query.filter {
-        let where_clause = filter_to_sql(filter, &mut params);
-        sql.push_str(&format!(" WHERE {where_clause}"));
-    }
+    sql.push(';');
+    CompiledQuery { sql, params }
+}
+
+/// Compile a `Query` to a statement removing every matching row.
+///
+/// Ignores limit, offset, and sort. Rows of a `@soft_delete` schema are
+/// stamped with `_deleted_at` instead of deleted, and rows already stamped
+/// are left alone.
+pub fn delete_to_sql_with_schema(
+    query: &Query,
+    table: &str,
+    schema: Option<&SchemaDefinition>,
+) -> CompiledQuery {
+    let mut params = Vec::new();
+    let soft_delete = schema.is_some_and(|s| s.is_soft_delete());
+    let mut sql = if soft_delete {
+        format!("UPDATE \"{table}\" SET \"{DELETED_AT_FIELD}\" = now()")
+    } else {
+        format!("DELETE FROM \"{table}\"")
+    };
+    sql.push_str(&where_clause(
+        query.filter.as_ref(),
+        soft_delete,
+        &mut params,
+    ));
 
     sql.push(';');
     CompiledQuery { sql, params }
 }
 
+/// Build the ` WHERE ...` suffix (empty when there is nothing to filter):
+/// the query's filter, ANDed with the soft-delete guard when
+/// `hide_deleted` is set.
+fn where_clause(
+    filter: Option<&Filter>,
+    hide_deleted: bool,
+    params: &mut Vec<DynamicValue>,
+) -> String {
+    let guard = format!("\"{DELETED_AT_FIELD}\" IS NULL");
+    match (filter, hide_deleted) {
+        (None, false) => String::new(),
+        (None, true) => format!(" WHERE {guard}"),
+        (Some(filter), false) => format!(" WHERE {}", filter_to_sql(filter, params)),
+        (Some(filter), true) => format!(" WHERE ({}) AND {guard}", filter_to_sql(filter, params)),
+    }
+}
+
 /// Compile an `AggregateQuery` to a PostgreSQL SELECT statement with aggregate functions.
 ///
 /// Uses index-based aliases (`agg_0`, `agg_1`, ...) for predictable result keys.
+/// The query's `group_by` columns are selected ahead of the aggregates and
+/// emitted as a `GROUP BY` clause.
 pub fn aggregate_to_sql(query: &AggregateQuery, table: &str) -> CompiledQuery {
+    aggregate_to_sql_with_schema(query, table, None)
+}
+
+/// Schema-aware variant of [`aggregate_to_sql`].
+pub fn aggregate_to_sql_with_schema(
+    query: &AggregateQuery,
+    table: &str,
+    schema: Option<&SchemaDefinition>,
+) -> CompiledQuery {
     let mut params = Vec::new();
 
-    let projections: Vec<String> = query
-        .ops
+    let group_columns: Vec<String> = query
+        .group_by
         .iter()
-        .enumerate()
-        .map(|(i, op)| match op {
-            AggregateOp::Count => format!("COUNT(*) AS \"agg_{i}\""),
-            AggregateOp::Sum { field } => {
-                format!(
-                    "COALESCE(SUM({}), 0) AS \"agg_{i}\"",
-                    field_path_to_sql(field)
-                )
-            }
-            AggregateOp::Avg { field } => {
-                format!(
-                    "COALESCE(AVG({}), 0) AS \"agg_{i}\"",
-                    field_path_to_sql(field)
-                )
-            }
-            _ => format!("COUNT(*) AS \"agg_{i}\""),
-        })
+        .map(|g| format!("\"{}\"", g.as_str()))
         .collect();
+    let aggregates = query.ops.iter().enumerate().map(|(i, op)| match op {
+        AggregateOp::Count => format!("COUNT(*) AS \"agg_{i}\""),
+        AggregateOp::Sum { field } => {
+            format!(
+                "COALESCE(SUM({}), 0) AS \"agg_{i}\"",
+                field_path_to_sql(field)
+            )
+        }
+        AggregateOp::Avg { field } => {
+            format!(
+                "COALESCE(AVG({}), 0) AS \"agg_{i}\"",
+                field_path_to_sql(field)
+            )
+        }
+        AggregateOp::Min { field } => {
+            format!(
+                "COALESCE(MIN({}), 0) AS \"agg_{i}\"",
+                field_path_to_sql(field)
+            )
+        }
+        AggregateOp::Max { field } => {
+            format!(
+                "COALESCE(MAX({}), 0) AS \"agg_{i}\"",
+                field_path_to_sql(field)
+            )
+        }
+        _ => format!("COUNT(*) AS \"agg_{i}\""),
+    });
+    let projections: Vec<String> = group_columns.iter().cloned().chain(aggregates).collect();
 
     let mut sql = format!("SELECT {} FROM \"{table}\"", projections.join(", "));
-
-    if let Some(filter) = &query.filter {
-        let where_clause = filter_to_sql(filter, &mut params);
-        sql.push_str(&format!(" WHERE {where_clause}"));
+    sql.push_str(&where_clause(
+        query.filter.as_ref(),
+        query.hides_deleted(schema),
+        &mut params,
+    ));
+
+    if !group_columns.is_empty() {
+        sql.push_str(&format!(" GROUP BY {}", group_columns.join(", ")));
     }
 
     sql.push(';');
@@ -222,6 +314,51 @@ mod tests {
     use schema_forge_core::query::FieldPath;
     use schema_forge_core::types::SchemaId;
 
+    #[test]
+    fn soft_delete_schema_hides_deleted_rows_by_default() {
+        use schema_forge_core::types::{Annotation, SchemaName};
+
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![],
+            vec![Annotation::SoftDelete],
+        )
+        .unwrap();
+        let q = Query::new(schema.id.clone());
+        assert_eq!(
+            query_to_sql_with_schema(&q, "Contact", Some(&schema)).sql,
+            "SELECT * FROM \"Contact\" WHERE \"_deleted_at\" IS NULL;"
+        );
+        assert_eq!(
+            count_to_sql_with_schema(&q, "Contact", Some(&schema)).sql,
+            "SELECT COUNT(*) AS \"count\" FROM \"Contact\" WHERE \"_deleted_at\" IS NULL;"
+        );
+
+        let filtered = q.clone().with_filter(Filter::eq(
+            FieldPath::single("name"),
+            DynamicValue::Text("Jane".into()),
+        ));
+        let compiled = query_to_sql_with_schema(&filtered, "Contact", Some(&schema));
+        assert_eq!(
+            compiled.sql,
+            "SELECT * FROM \"Contact\" WHERE (\"name\" = $1) AND \"_deleted_at\" IS NULL;"
+        );
+        assert_eq!(compiled.params.len(), 1);
+
+        let agg = AggregateQuery::new(schema.id.clone()).with_op(AggregateOp::Count);
+        assert_eq!(
+            aggregate_to_sql_with_schema(&agg, "Contact", Some(&schema)).sql,
+            "SELECT COUNT(*) AS \"agg_0\" FROM \"Contact\" WHERE \"_deleted_at\" IS NULL;"
+        );
+
+        let all = q.with_deleted(true);
+        assert_eq!(
+            query_to_sql_with_schema(&all, "Contact", Some(&schema)).sql,
+            "SELECT * FROM \"Contact\";"
+        );
+    }
+
     #[test]
     fn simple_select_all() {
         let q = Query::new(SchemaId::new());
@@ -369,6 +506,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn delete_by_query_hard_and_soft() {
+        use schema_forge_core::types::{Annotation, SchemaName};
+
+        let filter = Filter::eq(FieldPath::single("active"), DynamicValue::Boolean(false));
+        let hard = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![],
+            vec![],
+        )
+        .unwrap();
+        let q = Query::new(hard.id.clone())
+            .with_filter(filter.clone())
+            .with_limit(10);
+        let compiled = delete_to_sql_with_schema(&q, "Contact", Some(&hard));
+        assert_eq!(
+            compiled.sql,
+            "DELETE FROM \"Contact\" WHERE \"active\" = $1;"
+        );
+        assert_eq!(compiled.params.len(), 1);
+
+        let soft = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![],
+            vec![Annotation::SoftDelete],
+        )
+        .unwrap();
+        let q = Query::new(soft.id.clone())
+            .with_filter(filter)
+            .with_deleted(true);
+        assert_eq!(
+            delete_to_sql_with_schema(&q, "Contact", Some(&soft)).sql,
+            "UPDATE \"Contact\" SET \"_deleted_at\" = now() \
+             WHERE (\"active\" = $1) AND \"_deleted_at\" IS NULL;"
+        );
+    }
+
     #[test]
     fn count_all() {
         let q = Query::new(SchemaId::new());
@@ -431,6 +607,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn aggregate_min_max_group_by() {
+        use schema_forge_core::types::FieldName;
+
+        let q = AggregateQuery::new(SchemaId::new())
+            .with_op(AggregateOp::Min {
+                field: FieldPath::single("value"),
+            })
+            .with_op(AggregateOp::Max {
+                field: FieldPath::single("value"),
+            })
+            .with_group_by(FieldName::new("stage").unwrap());
+        let compiled = aggregate_to_sql(&q, "Deal");
+        assert_eq!(
+            compiled.sql,
+            "SELECT \"stage\", COALESCE(MIN(\"value\"), 0) AS \"agg_0\", \
+             COALESCE(MAX(\"value\"), 0) AS \"agg_1\" FROM \"Deal\" GROUP BY \"stage\";"
+        );
+    }
+
     #[test]
     fn count_ignores_limit_and_sort() {
         let q = Query::new(SchemaId::new())
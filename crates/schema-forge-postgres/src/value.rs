@@ -6,9 +6,12 @@
 
 use std::collections::BTreeMap;
 
-use schema_forge_backend::entity::Entity;
+use schema_forge_backend::entity::{Entity, VERSION_FIELD};
 use schema_forge_backend::error::BackendError;
-use schema_forge_core::types::{DynamicValue, EntityId, FieldType, SchemaDefinition, SchemaName};
+use schema_forge_core::types::{
+    Decimal, DynamicValue, EntityId, FieldType, IsoDuration, SchemaDefinition, SchemaName, Uuid,
+    DELETED_AT_FIELD,
+};
 use sqlx::postgres::{PgArguments, PgRow};
 use sqlx::{Arguments, Column, Row, ValueRef};
 
@@ -18,7 +21,7 @@ use sqlx::{Arguments, Column, Row, ValueRef};
 /// in the SQL string. Returns an error if the value type is not encodable.
 ///
 /// When binding a `DynamicValue::Array`, `field_type` should be the column's
-/// schema `FieldType::Array(inner)` so the correct native Postgres array type
+/// schema `FieldType::Array(inner, _)` so the correct native Postgres array type
 /// (`text[]`, `bigint[]`, etc.) can be bound. If `field_type` is `None` or the
 /// inner element type is not a sqlx-supported primitive, the array is bound
 /// as JSONB as a fallback.
@@ -46,6 +49,11 @@ pub fn bind_dynamic_value(
                 message: format!("failed to bind float: {e}"),
             })?;
         }
+        DynamicValue::Decimal(d) => {
+            args.add(*d).map_err(|e| BackendError::Internal {
+                message: format!("failed to bind decimal: {e}"),
+            })?;
+        }
         DynamicValue::Boolean(b) => {
             args.add(*b).map_err(|e| BackendError::Internal {
                 message: format!("failed to bind boolean: {e}"),
@@ -56,6 +64,17 @@ pub fn bind_dynamic_value(
                 message: format!("failed to bind datetime: {e}"),
             })?;
         }
+        DynamicValue::Duration(d) => {
+            args.add(d.to_string())
+                .map_err(|e| BackendError::Internal {
+                    message: format!("failed to bind duration: {e}"),
+                })?;
+        }
+        DynamicValue::Uuid(u) => {
+            args.add(*u).map_err(|e| BackendError::Internal {
+                message: format!("failed to bind uuid: {e}"),
+            })?;
+        }
         DynamicValue::Json(v) => {
             args.add(sqlx::types::Json(v))
                 .map_err(|e| BackendError::Internal {
@@ -117,14 +136,16 @@ fn bind_null(args: &mut PgArguments, field_type: Option<&FieldType>) -> Result<(
     // silent fallback was the shape of issue #12.
     let result = match field_type {
         // Stored as text / varchar.
-        Some(FieldType::Text(_) | FieldType::RichText | FieldType::Enum(_)) => {
-            args.add(None::<String>)
-        }
-        // Stored as bigint / double / boolean / timestamptz.
+        Some(
+            FieldType::Text(_) | FieldType::RichText(_) | FieldType::Enum(_) | FieldType::Duration,
+        ) => args.add(None::<String>),
+        // Stored as bigint / double / numeric / boolean / timestamptz.
         Some(FieldType::Integer(_)) => args.add(None::<i64>),
         Some(FieldType::Float(_)) => args.add(None::<f64>),
+        Some(FieldType::Decimal { .. }) => args.add(None::<Decimal>),
         Some(FieldType::Boolean) => args.add(None::<bool>),
         Some(FieldType::DateTime) => args.add(None::<chrono::DateTime<chrono::Utc>>),
+        Some(FieldType::Uuid) => args.add(None::<Uuid>),
         // Stored as jsonb.
         Some(FieldType::Json | FieldType::Composite(_) | FieldType::File(_)) => {
             args.add(None::<sqlx::types::Json<serde_json::Value>>)
@@ -139,7 +160,7 @@ fn bind_null(args: &mut PgArguments, field_type: Option<&FieldType>) -> Result<(
             ..
         }) => args.add(None::<Vec<String>>),
         // Array delegates to the typed-inner helper.
-        Some(FieldType::Array(inner)) => return bind_null_array(args, inner),
+        Some(FieldType::Array(inner, _)) => return bind_null_array(args, inner),
         // No schema context (positional query params). Text is the
         // documented backwards-compatible fallback.
         None => args.add(None::<String>),
@@ -168,13 +189,15 @@ fn bind_null(args: &mut PgArguments, field_type: Option<&FieldType>) -> Result<(
 /// to match [`bind_array`]'s fallback and [`read_array_column`]'s decode.
 fn bind_null_array(args: &mut PgArguments, inner: &FieldType) -> Result<(), BackendError> {
     let result = match inner {
-        FieldType::Text(_) | FieldType::RichText | FieldType::Enum(_) => {
+        FieldType::Text(_) | FieldType::RichText(_) | FieldType::Enum(_) => {
             args.add(None::<Vec<String>>)
         }
         FieldType::Integer(_) => args.add(None::<Vec<i64>>),
         FieldType::Float(_) => args.add(None::<Vec<f64>>),
+        FieldType::Decimal { .. } => args.add(None::<Vec<Decimal>>),
         FieldType::Boolean => args.add(None::<Vec<bool>>),
         FieldType::DateTime => args.add(None::<Vec<chrono::DateTime<chrono::Utc>>>),
+        FieldType::Uuid => args.add(None::<Vec<Uuid>>),
         // Nested arrays, composites, relations, etc. are stored as JSONB.
         _ => args.add(None::<sqlx::types::Json<serde_json::Value>>),
     };
@@ -192,9 +215,9 @@ fn bind_array(
     arr: &[DynamicValue],
     field_type: Option<&FieldType>,
 ) -> Result<(), BackendError> {
-    if let Some(FieldType::Array(inner)) = field_type {
+    if let Some(FieldType::Array(inner, _)) = field_type {
         match inner.as_ref() {
-            FieldType::Text(_) | FieldType::Enum(_) | FieldType::RichText => {
+            FieldType::Text(_) | FieldType::Enum(_) | FieldType::RichText(_) => {
                 let items = array_items_as_strings(arr, inner)?;
                 args.add(items).map_err(|e| BackendError::Internal {
                     message: format!("failed to bind text array: {e}"),
@@ -215,6 +238,13 @@ fn bind_array(
                 })?;
                 return Ok(());
             }
+            FieldType::Decimal { .. } => {
+                let items = array_items_as_decimals(arr, inner)?;
+                args.add(items).map_err(|e| BackendError::Internal {
+                    message: format!("failed to bind decimal array: {e}"),
+                })?;
+                return Ok(());
+            }
             FieldType::Boolean => {
                 let items = array_items_as_bools(arr)?;
                 args.add(items).map_err(|e| BackendError::Internal {
@@ -229,6 +259,13 @@ fn bind_array(
                 })?;
                 return Ok(());
             }
+            FieldType::Uuid => {
+                let items = array_items_as_uuids(arr)?;
+                args.add(items).map_err(|e| BackendError::Internal {
+                    message: format!("failed to bind uuid array: {e}"),
+                })?;
+                return Ok(());
+            }
             // Nested arrays, composites, relations, json, etc. -- fall through to JSONB.
             _ => {}
         }
@@ -263,8 +300,11 @@ fn dynamic_variant_name(value: &DynamicValue) -> &'static str {
         DynamicValue::Text(_) => "Text",
         DynamicValue::Integer(_) => "Integer",
         DynamicValue::Float(_) => "Float",
+        DynamicValue::Decimal(_) => "Decimal",
         DynamicValue::Boolean(_) => "Boolean",
         DynamicValue::DateTime(_) => "DateTime",
+        DynamicValue::Duration(_) => "Duration",
+        DynamicValue::Uuid(_) => "Uuid",
         DynamicValue::Enum(_) => "Enum",
         DynamicValue::Json(_) => "Json",
         DynamicValue::Array(_) => "Array",
@@ -311,6 +351,18 @@ fn array_items_as_floats(arr: &[DynamicValue]) -> Result<Vec<f64>, BackendError>
         .collect()
 }
 
+fn array_items_as_decimals(
+    arr: &[DynamicValue],
+    inner: &FieldType,
+) -> Result<Vec<Decimal>, BackendError> {
+    arr.iter()
+        .map(|item| match item {
+            DynamicValue::Decimal(d) => Ok(*d),
+            other => Err(array_bind_mismatch(inner, other)),
+        })
+        .collect()
+}
+
 fn array_items_as_bools(arr: &[DynamicValue]) -> Result<Vec<bool>, BackendError> {
     arr.iter()
         .map(|item| match item {
@@ -331,6 +383,15 @@ fn array_items_as_datetimes(
         .collect()
 }
 
+fn array_items_as_uuids(arr: &[DynamicValue]) -> Result<Vec<Uuid>, BackendError> {
+    arr.iter()
+        .map(|item| match item {
+            DynamicValue::Uuid(u) => Ok(*u),
+            other => Err(array_bind_mismatch(&FieldType::Uuid, other)),
+        })
+        .collect()
+}
+
 /// Convert a PostgreSQL row to an `Entity`, guided by the schema definition.
 ///
 /// Uses the schema's field definitions to determine the correct type for each
@@ -349,13 +410,31 @@ pub fn row_to_entity(
     })?;
 
     let mut fields = BTreeMap::new();
+    let mut version = 0;
+    let deleted_at_type = FieldType::DateTime;
 
-    // Iterate over columns, skipping "id"
+    // Iterate over columns, skipping "id" and lifting out the version
     for column in row.columns() {
         let col_name = column.name();
         if col_name == "id" {
             continue;
         }
+        if col_name == VERSION_FIELD {
+            let raw: Option<i64> = row.try_get(col_name).map_err(|e| BackendError::Internal {
+                message: format!("failed to read '{VERSION_FIELD}': {e}"),
+            })?;
+            version = raw.and_then(|v| u64::try_from(v).ok()).unwrap_or_default();
+            continue;
+        }
+
+        if col_name == DELETED_AT_FIELD {
+            // The soft-delete stamp only surfaces on deleted rows.
+            let value = read_column(row, col_name, Some(&deleted_at_type))?;
+            if !matches!(value, DynamicValue::Null) {
+                fields.insert(col_name.to_string(), value);
+            }
+            continue;
+        }
 
         let field_type = schema_def
             .and_then(|sd| sd.field(col_name))
@@ -364,12 +443,12 @@ pub fn row_to_entity(
         fields.insert(col_name.to_string(), value);
     }
 
-    Ok(Entity::with_id(entity_id, schema.clone(), fields))
+    Ok(Entity::with_id(entity_id, schema.clone(), fields).with_version(version))
 }
 
 /// Read a single column value from a PostgreSQL row, using the schema's field type
 /// to guide interpretation.
-fn read_column(
+pub(crate) fn read_column(
     row: &PgRow,
     col_name: &str,
     field_type: Option<&FieldType>,
@@ -399,6 +478,12 @@ fn read_column(
             })?;
             Ok(DynamicValue::Float(v))
         }
+        Some(FieldType::Decimal { .. }) => {
+            let v: Decimal = row.try_get(col_name).map_err(|e| BackendError::Internal {
+                message: format!("failed to read decimal column '{col_name}': {e}"),
+            })?;
+            Ok(DynamicValue::Decimal(v))
+        }
         Some(FieldType::Boolean) => {
             let v: bool = row.try_get(col_name).map_err(|e| BackendError::Internal {
                 message: format!("failed to read boolean column '{col_name}': {e}"),
@@ -412,6 +497,22 @@ fn read_column(
                 })?;
             Ok(DynamicValue::DateTime(v))
         }
+        Some(FieldType::Duration) => {
+            let v: String = row.try_get(col_name).map_err(|e| BackendError::Internal {
+                message: format!("failed to read duration column '{col_name}': {e}"),
+            })?;
+            IsoDuration::parse(&v)
+                .map(DynamicValue::Duration)
+                .map_err(|e| BackendError::Internal {
+                    message: format!("failed to read duration column '{col_name}': {e}"),
+                })
+        }
+        Some(FieldType::Uuid) => {
+            let v: Uuid = row.try_get(col_name).map_err(|e| BackendError::Internal {
+                message: format!("failed to read uuid column '{col_name}': {e}"),
+            })?;
+            Ok(DynamicValue::Uuid(v))
+        }
         Some(FieldType::Json) => {
             let v: sqlx::types::Json<serde_json::Value> =
                 row.try_get(col_name).map_err(|e| BackendError::Internal {
@@ -463,7 +564,7 @@ fn read_column(
                 Err(_) => Ok(DynamicValue::Text(v)),
             }
         }
-        Some(FieldType::Array(inner)) => read_array_column(row, col_name, inner),
+        Some(FieldType::Array(inner, _)) => read_array_column(row, col_name, inner),
         // Text, RichText, or unknown -- read as string
         _ => {
             let v: String = row.try_get(col_name).map_err(|e| BackendError::Internal {
@@ -483,7 +584,7 @@ fn read_array_column(
     inner: &FieldType,
 ) -> Result<DynamicValue, BackendError> {
     match inner {
-        FieldType::Text(_) | FieldType::Enum(_) | FieldType::RichText => {
+        FieldType::Text(_) | FieldType::Enum(_) | FieldType::RichText(_) => {
             let v: Vec<String> = row.try_get(col_name).map_err(|e| BackendError::Internal {
                 message: format!("failed to read text array column '{col_name}': {e}"),
             })?;
@@ -507,6 +608,14 @@ fn read_array_column(
                 v.into_iter().map(DynamicValue::Float).collect(),
             ))
         }
+        FieldType::Decimal { .. } => {
+            let v: Vec<Decimal> = row.try_get(col_name).map_err(|e| BackendError::Internal {
+                message: format!("failed to read decimal array column '{col_name}': {e}"),
+            })?;
+            Ok(DynamicValue::Array(
+                v.into_iter().map(DynamicValue::Decimal).collect(),
+            ))
+        }
         FieldType::Boolean => {
             let v: Vec<bool> = row.try_get(col_name).map_err(|e| BackendError::Internal {
                 message: format!("failed to read boolean array column '{col_name}': {e}"),
@@ -524,6 +633,14 @@ fn read_array_column(
                 v.into_iter().map(DynamicValue::DateTime).collect(),
             ))
         }
+        FieldType::Uuid => {
+            let v: Vec<Uuid> = row.try_get(col_name).map_err(|e| BackendError::Internal {
+                message: format!("failed to read uuid array column '{col_name}': {e}"),
+            })?;
+            Ok(DynamicValue::Array(
+                v.into_iter().map(DynamicValue::Uuid).collect(),
+            ))
+        }
         // Nested arrays, composites, relations, json, etc. -- fall back to JSONB.
         _ => {
             let v: sqlx::types::Json<serde_json::Value> =
@@ -542,8 +659,12 @@ fn dynamic_to_json(value: &DynamicValue) -> serde_json::Value {
         DynamicValue::Text(s) | DynamicValue::Enum(s) => serde_json::Value::String(s.clone()),
         DynamicValue::Integer(i) => serde_json::json!(*i),
         DynamicValue::Float(f) => serde_json::json!(*f),
+        // A string keeps every digit; a JSON number would round-trip via f64.
+        DynamicValue::Decimal(d) => serde_json::Value::String(d.to_string()),
         DynamicValue::Boolean(b) => serde_json::json!(*b),
         DynamicValue::DateTime(dt) => serde_json::Value::String(dt.to_rfc3339()),
+        DynamicValue::Duration(d) => serde_json::Value::String(d.to_string()),
+        DynamicValue::Uuid(u) => serde_json::Value::String(u.to_string()),
         DynamicValue::Json(v) => v.clone(),
         DynamicValue::Array(arr) => {
             let items: Vec<serde_json::Value> = arr.iter().map(dynamic_to_json).collect();
@@ -711,9 +832,9 @@ mod tests {
             DynamicValue::Text("rust".into()),
             DynamicValue::Text("aws".into()),
         ]);
-        let ft = FieldType::Array(Box::new(FieldType::Text(
+        let ft = FieldType::array(FieldType::Text(
             schema_forge_core::types::TextConstraints::default(),
-        )));
+        ));
         assert!(bind_dynamic_value(&mut args, &arr, Some(&ft)).is_ok());
     }
 
@@ -721,9 +842,9 @@ mod tests {
     fn bind_array_with_integer_field_type_binds_native_integer_array() {
         let mut args = PgArguments::default();
         let arr = DynamicValue::Array(vec![DynamicValue::Integer(1), DynamicValue::Integer(2)]);
-        let ft = FieldType::Array(Box::new(FieldType::Integer(
+        let ft = FieldType::array(FieldType::Integer(
             schema_forge_core::types::IntegerConstraints::default(),
-        )));
+        ));
         assert!(bind_dynamic_value(&mut args, &arr, Some(&ft)).is_ok());
     }
 
@@ -734,10 +855,28 @@ mod tests {
             DynamicValue::Boolean(true),
             DynamicValue::Boolean(false),
         ]);
-        let ft = FieldType::Array(Box::new(FieldType::Boolean));
+        let ft = FieldType::array(FieldType::Boolean);
         assert!(bind_dynamic_value(&mut args, &arr, Some(&ft)).is_ok());
     }
 
+    #[test]
+    fn bind_array_with_uuid_field_type_rejects_text_items() {
+        let u =
+            schema_forge_core::types::parse_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let ft = FieldType::array(FieldType::Uuid);
+        let mut args = PgArguments::default();
+        let arr = DynamicValue::Array(vec![DynamicValue::Uuid(u)]);
+        assert!(bind_dynamic_value(&mut args, &arr, Some(&ft)).is_ok());
+
+        let mut args = PgArguments::default();
+        let arr = DynamicValue::Array(vec![DynamicValue::Text(u.to_string())]);
+        let err = bind_dynamic_value(&mut args, &arr, Some(&ft)).unwrap_err();
+        assert!(
+            err.to_string().contains("got Text"),
+            "unexpected error: {err}"
+        );
+    }
+
     #[test]
     fn bind_array_without_field_type_falls_back_to_jsonb() {
         let mut args = PgArguments::default();
@@ -772,6 +911,20 @@ mod tests {
         assert!(bind_dynamic_value(&mut args, &DynamicValue::Null, Some(&ft)).is_ok());
     }
 
+    #[test]
+    fn bind_decimal_and_typed_null() {
+        let ft = FieldType::Decimal { scale: 2 };
+        let d = schema_forge_core::types::parse_decimal("19.99", 2).unwrap();
+        let mut args = PgArguments::default();
+        assert!(bind_dynamic_value(&mut args, &DynamicValue::Decimal(d), Some(&ft)).is_ok());
+        let mut args = PgArguments::default();
+        assert!(bind_dynamic_value(&mut args, &DynamicValue::Null, Some(&ft)).is_ok());
+        assert_eq!(
+            dynamic_to_json(&DynamicValue::Decimal(d)),
+            serde_json::json!("19.99")
+        );
+    }
+
     #[test]
     fn bind_null_with_boolean_field_type_uses_typed_none() {
         let mut args = PgArguments::default();
@@ -796,9 +949,9 @@ mod tests {
     #[test]
     fn bind_null_with_array_field_type_uses_typed_none() {
         let mut args = PgArguments::default();
-        let ft = FieldType::Array(Box::new(FieldType::Integer(
+        let ft = FieldType::array(FieldType::Integer(
             schema_forge_core::types::IntegerConstraints::default(),
-        )));
+        ));
         assert!(bind_dynamic_value(&mut args, &DynamicValue::Null, Some(&ft)).is_ok());
     }
 
@@ -880,9 +1033,9 @@ mod tests {
     fn bind_array_type_mismatch_returns_internal_error() {
         let mut args = PgArguments::default();
         let arr = DynamicValue::Array(vec![DynamicValue::Integer(1)]);
-        let ft = FieldType::Array(Box::new(FieldType::Text(
+        let ft = FieldType::array(FieldType::Text(
             schema_forge_core::types::TextConstraints::default(),
-        )));
+        ));
         let err = bind_dynamic_value(&mut args, &arr, Some(&ft))
             .expect_err("expected type mismatch error");
         let msg = format!("{err:?}");
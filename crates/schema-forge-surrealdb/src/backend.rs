@@ -5,22 +5,34 @@
 
 use std::collections::BTreeMap;
 
-use schema_forge_backend::entity::{Entity, QueryResult};
+use schema_forge_backend::entity::{Entity, QueryResult, VERSION_FIELD};
 use schema_forge_backend::error::BackendError;
 use schema_forge_backend::traits::{EntityStore, SchemaBackend};
 use schema_forge_core::migration::MigrationStep;
-use schema_forge_core::query::{AggregateQuery, AggregateResult, Query};
-use schema_forge_core::types::{DynamicValue, EntityId, FieldType, SchemaDefinition, SchemaName};
+use schema_forge_core::query::{
+    validate_aggregate, validate_sort, AggregateQuery, AggregateResult, Query, QueryError,
+};
+use schema_forge_core::types::{
+    DynamicValue, EntityId, FieldName, FieldType, SchemaDefinition, SchemaName, DELETED_AT_FIELD,
+};
 use surrealdb::engine::any::Any;
 use surrealdb::Surreal;
 
-use crate::codegen::migration_step_to_surql;
+use crate::codegen::{migration_step_to_surql, unique_indexes, version_ddl_statements};
 use crate::query::{count_to_surql_with_schema, query_to_surql_with_schema};
 use crate::value::{entity_to_surreal_map, surreal_to_dynamic};
 
 /// The schema metadata table name used to store `SchemaDefinition` records.
 const SCHEMA_META_TABLE: &str = "_schema_metadata";
 
+/// The table holding `default(sequence(...))` counters, keyed by
+/// `[schema, field]`.
+const SEQUENCE_TABLE: &str = "_forge_sequence";
+
+/// How many times a sequence increment is retried after a write conflict
+/// with a concurrent increment of the same counter.
+const SEQUENCE_MAX_RETRIES: usize = 64;
+
 /// SurrealDB backend for SchemaForge.
 ///
 /// Wraps a connected `Surreal<Any>` client and implements both
@@ -149,6 +161,21 @@ impl SurrealBackend {
         }
     }
 
+    /// Whether `table` is defined in the current database.
+    async fn table_exists(&self, table: &str) -> Result<bool, BackendError> {
+        let mut response = self.execute_raw("INFO FOR DB;").await?;
+        let value: surrealdb::Value = response.take(0).map_err(|e| BackendError::QueryError {
+            message: e.to_string(),
+        })?;
+        Ok(match value.into_inner() {
+            surrealdb::sql::Value::Object(info) => matches!(
+                info.get("tables"),
+                Some(surrealdb::sql::Value::Object(tables)) if tables.contains_key(table)
+            ),
+            _ => false,
+        })
+    }
+
     /// Build SET clause assignments for entity fields, resolving relation fields
     /// to proper SurrealDB record reference literals.
     async fn build_field_assignments(&self, entity: &Entity) -> Result<String, BackendError> {
@@ -199,6 +226,32 @@ impl SurrealBackend {
 
         Ok(assignments.join(", "))
     }
+
+    /// Turn a write SurrealDB rejected for a unique index -- a `unique`
+    /// field or a unique `@index` -- into `BackendError::UniqueConflict`
+    /// naming the index's fields. Other errors pass through unchanged.
+    async fn unique_conflict(&self, schema: &SchemaName, err: BackendError) -> BackendError {
+        let BackendError::QueryError { message } = &err else {
+            return err;
+        };
+        if !message.contains("already contains") {
+            return err;
+        }
+        let Ok(Some(definition)) = self.load_schema_metadata(schema).await else {
+            return err;
+        };
+        let fields = unique_indexes(&definition)
+            .into_iter()
+            .find(|(name, _)| message.contains(&format!("`{name}`")))
+            .map(|(_, fields)| fields.iter().map(|f| f.as_str().to_string()).collect());
+        match fields {
+            Some(fields) => BackendError::UniqueConflict {
+                schema: schema.as_str().to_string(),
+                fields,
+            },
+            None => err,
+        }
+    }
 }
 
 impl SchemaBackend for SurrealBackend {
@@ -236,6 +289,15 @@ impl SchemaBackend for SurrealBackend {
             json_escaped = json.replace('\'', "\\'")
         );
         self.execute_raw(&sql).await?;
+
+        // Tables created before entity versioning lack `_version`. Only
+        // touch tables that exist: DEFINE FIELD would otherwise create a
+        // schemaless table that a later DEFINE TABLE ... SCHEMAFULL trips on.
+        if self.table_exists(name).await? {
+            for stmt in version_ddl_statements(name) {
+                self.execute_raw(&stmt).await?;
+            }
+        }
         Ok(())
     }
 
@@ -342,7 +404,10 @@ impl EntityStore for SurrealBackend {
         let set_clause = self.build_field_assignments(entity).await?;
         let sql = format!("CREATE {table}:`{id_str}` SET {set_clause};");
 
-        let rows = self.execute_and_take_rows(&sql).await?;
+        let rows = match self.execute_and_take_rows(&sql).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(self.unique_conflict(&entity.schema, e).await),
+        };
 
         if rows.is_empty() {
             return Err(BackendError::Internal {
@@ -353,6 +418,59 @@ impl EntityStore for SurrealBackend {
         surreal_row_to_entity(&entity.schema, &rows[0])
     }
 
+    async fn create_many(&self, entities: &[Entity]) -> Result<Vec<Entity>, BackendError> {
+        if entities.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // One CREATE per entity inside a single transaction. BEGIN/COMMIT
+        // produce no result slots, so statement `i` is entity `i`.
+        let mut sql = String::from("BEGIN TRANSACTION;");
+        for entity in entities {
+            let set_clause = self.build_field_assignments(entity).await?;
+            sql.push_str(&format!(
+                " CREATE {}:`{}` SET {set_clause};",
+                entity.schema.as_str(),
+                entity.id.as_str()
+            ));
+        }
+        sql.push_str(" COMMIT TRANSACTION;");
+
+        let mut response = self.execute_raw(&sql).await?;
+        let errors = response.take_errors();
+        if !errors.is_empty() {
+            // Statements after the failing one report the cancelled
+            // transaction rather than their own error; blame the first
+            // statement with a real error.
+            let mut errors: Vec<(usize, String)> = errors
+                .into_iter()
+                .map(|(i, e)| (i, e.to_string()))
+                .collect();
+            errors.sort_by_key(|(i, _)| *i);
+            let (index, reason) = errors
+                .iter()
+                .find(|(_, msg)| !msg.contains("failed transaction"))
+                .unwrap_or(&errors[0])
+                .clone();
+            return Err(BackendError::BatchItemFailed { index, reason });
+        }
+
+        let mut created = Vec::with_capacity(entities.len());
+        for (i, entity) in entities.iter().enumerate() {
+            let value: surrealdb::Value =
+                response.take(i).map_err(|e| BackendError::BatchItemFailed {
+                    index: i,
+                    reason: e.to_string(),
+                })?;
+            let row = match value.into_inner() {
+                surrealdb::sql::Value::Array(mut arr) if !arr.is_empty() => arr.0.remove(0),
+                other => other,
+            };
+            created.push(surreal_row_to_entity(&entity.schema, &row)?);
+        }
+        Ok(created)
+    }
+
     async fn get(&self, schema: &SchemaName, id: &EntityId) -> Result<Entity, BackendError> {
         let table = schema.as_str();
         let id_str = id.as_str();
@@ -370,29 +488,92 @@ impl EntityStore for SurrealBackend {
         surreal_row_to_entity(schema, &rows[0])
     }
 
-    async fn update(&self, entity: &Entity) -> Result<Entity, BackendError> {
+    async fn update(
+        &self,
+        entity: &Entity,
+        expected_version: Option<u64>,
+    ) -> Result<Entity, BackendError> {
         let table = entity.schema.as_str();
         let id_str = entity.id.as_str();
 
         let set_clause = self.build_field_assignments(entity).await?;
-        let sql = format!("UPDATE {table}:`{id_str}` SET {set_clause};");
+        // Records written before versioning have no `_version`; treat it as 0.
+        let guard = match expected_version {
+            Some(v) => format!(" WHERE ({VERSION_FIELD} OR 0) = {v}"),
+            None => String::new(),
+        };
+        let sql = format!(
+            "UPDATE {table}:`{id_str}` SET {set_clause}, \
+             {VERSION_FIELD} = ({VERSION_FIELD} OR 0) + 1{guard};"
+        );
 
-        let rows = self.execute_and_take_rows(&sql).await?;
+        let rows = match self.execute_and_take_rows(&sql).await {
+            Ok(rows) => rows,
+            Err(e) => return Err(self.unique_conflict(&entity.schema, e).await),
+        };
 
         if rows.is_empty() {
-            return Err(BackendError::EntityNotFound {
+            let Some(expected) = expected_version else {
+                return Err(BackendError::EntityNotFound {
+                    schema: table.to_string(),
+                    entity_id: id_str.to_string(),
+                });
+            };
+            // Either the record is gone (get reports it) or the version
+            // guard rejected the write.
+            let current = self.get(&entity.schema, &entity.id).await?;
+            return Err(BackendError::Conflict {
                 schema: table.to_string(),
                 entity_id: id_str.to_string(),
+                expected,
+                actual: current.version,
             });
         }
 
         surreal_row_to_entity(&entity.schema, &rows[0])
     }
 
+    async fn patch(
+        &self,
+        schema: &SchemaName,
+        id: &EntityId,
+        changes: BTreeMap<String, DynamicValue>,
+        expected_version: Option<u64>,
+    ) -> Result<Entity, BackendError> {
+        if changes.is_empty() {
+            return self.get(schema, id).await?.expect_version(expected_version);
+        }
+        // UPDATE ... SET only touches the listed fields, so an entity
+        // carrying just the changes is already a merge.
+        self.update(
+            &Entity::with_id(id.clone(), schema.clone(), changes),
+            expected_version,
+        )
+        .await
+    }
+
     async fn delete(&self, schema: &SchemaName, id: &EntityId) -> Result<(), BackendError> {
         let table = schema.as_str();
         let id_str = id.as_str();
 
+        let soft_delete = self
+            .load_schema_metadata(schema)
+            .await?
+            .is_some_and(|def| def.is_soft_delete());
+        if soft_delete {
+            let sql = format!(
+                "UPDATE {table}:`{id_str}` SET {DELETED_AT_FIELD} = time::now() \
+                 WHERE {DELETED_AT_FIELD} IS NONE;"
+            );
+            if self.execute_and_take_rows(&sql).await?.is_empty() {
+                return Err(BackendError::EntityNotFound {
+                    schema: table.to_string(),
+                    entity_id: id_str.to_string(),
+                });
+            }
+            return Ok(());
+        }
+
         // First check if it exists
         let check_sql = format!("SELECT * FROM {table}:`{id_str}`;");
         let rows = self.execute_and_take_rows(&check_sql).await?;
@@ -409,6 +590,20 @@ impl EntityStore for SurrealBackend {
         Ok(())
     }
 
+    async fn restore(&self, schema: &SchemaName, id: &EntityId) -> Result<Entity, BackendError> {
+        let table = schema.as_str();
+        let id_str = id.as_str();
+        let sql = format!("UPDATE {table}:`{id_str}` SET {DELETED_AT_FIELD} = NONE;");
+        let rows = self.execute_and_take_rows(&sql).await?;
+        match rows.first() {
+            Some(row) => surreal_row_to_entity(schema, row),
+            None => Err(BackendError::EntityNotFound {
+                schema: table.to_string(),
+                entity_id: id_str.to_string(),
+            }),
+        }
+    }
+
     async fn query(&self, query: &Query) -> Result<QueryResult, BackendError> {
         // Resolve the table name from the SchemaId by scanning schema metadata.
         let all_schemas = self.list_schema_metadata().await?;
@@ -419,8 +614,10 @@ impl EntityStore for SurrealBackend {
                 message: format!("no schema found for id '{}'", query.schema.as_str()),
             })?;
 
+        let page = prepare_query(query, schema_def)?;
+
         let table = schema_def.name.as_str();
-        let sql = query_to_surql_with_schema(query, table, Some(schema_def));
+        let sql = query_to_surql_with_schema(&page, table, Some(schema_def));
         let rows = self.execute_and_take_rows(&sql).await?;
 
         let schema_name = schema_def.name.clone();
@@ -429,15 +626,15 @@ impl EntityStore for SurrealBackend {
             entities.push(surreal_row_to_entity(&schema_name, row)?);
         }
 
-        // Also compute the total matching rows (ignoring limit/offset) so
-        // paginated list envelopes can report an accurate total. Skipped
-        // on internal lookups that set `include_total = false`.
+        // Also compute the total matching rows (ignoring limit/offset and
+        // the cursor) so paginated list envelopes can report an accurate
+        // total. Skipped on internal lookups that set `include_total = false`.
         let total = if query.include_total {
             Some(self.count(query).await?)
         } else {
             None
         };
-        Ok(QueryResult::new(entities, total))
+        Ok(QueryResult::new(entities, total).with_next_cursor(query))
     }
 
     async fn count(&self, query: &Query) -> Result<usize, BackendError> {
@@ -474,44 +671,121 @@ impl EntityStore for SurrealBackend {
                 message: format!("no schema found for id '{}'", query.schema.as_str()),
             })?;
 
+        validate_aggregate(query, schema_def)
+            .map_err(|errors| validation_error(&errors, "aggregate"))?;
+
         let table = schema_def.name.as_str();
-        let sql = crate::query::aggregate_to_surql(query, table);
+        let sql = crate::query::aggregate_to_surql_with_schema(query, table, Some(schema_def));
         let rows = self.execute_and_take_rows(&sql).await?;
 
-        let mut results = Vec::with_capacity(query.ops.len());
-        if let Some(row) = rows.first() {
-            if let surrealdb::sql::Value::Object(obj) = row {
-                for (i, op) in query.ops.iter().enumerate() {
-                    let key = format!("agg_{i}");
-                    let value = match obj.get(&key) {
-                        Some(surrealdb::sql::Value::Number(n)) => {
-                            let v = n.as_float();
-                            if v.is_nan() {
-                                0.0
-                            } else {
-                                v
-                            }
-                        }
-                        _ => 0.0,
-                    };
-                    results.push(AggregateResult {
-                        op: op.clone(),
-                        value,
-                    });
-                }
-            }
-        } else {
+        if rows.is_empty() && query.group_by.is_empty() {
             // Empty table — return 0 for all ops
-            for op in &query.ops {
-                results.push(AggregateResult {
-                    op: op.clone(),
-                    value: 0.0,
-                });
+            return Ok(query
+                .ops
+                .iter()
+                .map(|op| AggregateResult::new(op.clone(), 0.0))
+                .collect());
+        }
+
+        // One row per group (a single row under GROUP ALL).
+        let mut results = Vec::with_capacity(rows.len() * query.ops.len());
+        for row in &rows {
+            let surrealdb::sql::Value::Object(obj) = row else {
+                continue;
+            };
+            let mut group = BTreeMap::new();
+            for field in &query.group_by {
+                let value = match obj.get(field.as_str()) {
+                    Some(v) => surreal_to_dynamic(v)?,
+                    None => DynamicValue::Null,
+                };
+                group.insert(field.as_str().to_string(), value);
+            }
+            for (i, op) in query.ops.iter().enumerate() {
+                let key = format!("agg_{i}");
+                let value = match obj.get(&key) {
+                    Some(surrealdb::sql::Value::Number(n)) => {
+                        let v = n.as_float();
+                        if v.is_nan() {
+                            0.0
+                        } else {
+                            v
+                        }
+                    }
+                    _ => 0.0,
+                };
+                results.push(AggregateResult::new(op.clone(), value).with_group(group.clone()));
             }
         }
 
         Ok(results)
     }
+
+    async fn next_sequence_value(
+        &self,
+        schema: &SchemaName,
+        field: &FieldName,
+        start: i64,
+    ) -> Result<i64, BackendError> {
+        // Each statement runs in its own transaction. Two creates racing on
+        // the same counter surface as a retryable conflict on one of them,
+        // which never commits, so retrying keeps the sequence gapless.
+        let sql = format!(
+            "UPSERT {SEQUENCE_TABLE}:['{schema}', '{field}'] SET value = (value ?? {start}) + 1 RETURN value;"
+        );
+        let mut attempt = 0;
+        let rows = loop {
+            match self.execute_and_take_rows(&sql).await {
+                Ok(rows) => break rows,
+                Err(BackendError::QueryError { message })
+                    if message.contains("can be retried") && attempt < SEQUENCE_MAX_RETRIES =>
+                {
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        match rows.first() {
+            Some(surrealdb::sql::Value::Object(obj)) => match obj.get("value") {
+                Some(surrealdb::sql::Value::Number(n)) => Ok(n.as_int()),
+                _ => Err(BackendError::Internal {
+                    message: format!("sequence counter for {schema}.{field} has no value"),
+                }),
+            },
+            _ => Err(BackendError::Internal {
+                message: format!("sequence increment for {schema}.{field} returned no row"),
+            }),
+        }
+    }
+}
+
+/// Report the first of `errors` as a `ValidationFailed` for the field it
+/// names, falling back to `action` for errors not tied to a field.
+fn validation_error(errors: &[QueryError], action: &str) -> BackendError {
+    let first = &errors[0];
+    let field = match first {
+        QueryError::UnknownField { field, .. } | QueryError::TypeMismatch { field, .. } => {
+            field.clone()
+        }
+        _ => action.to_string(),
+    };
+    BackendError::ValidationFailed {
+        field,
+        reason: format!("cannot {action}: {first}"),
+    }
+}
+
+/// Reject sort keys that do not name a field of `schema`, then resolve any
+/// pagination cursor into the query that is actually executed.
+fn prepare_query(query: &Query, schema: &SchemaDefinition) -> Result<Query, BackendError> {
+    validate_sort(&query.sort, schema).map_err(|errors| validation_error(&errors, "sort"))?;
+    query
+        .resolve_cursor()
+        .map_err(|e| BackendError::ValidationFailed {
+            field: "after".to_string(),
+            reason: e.to_string(),
+        })
 }
 
 /// Convert a `surrealdb::sql::Value` response row to an `Entity`.
@@ -538,14 +812,21 @@ fn surreal_row_to_entity(
 
             // Convert remaining fields
             let mut fields = BTreeMap::new();
+            let mut version = 0;
             for (k, v) in obj.iter() {
                 if k == "id" {
                     continue;
                 }
+                if k == VERSION_FIELD {
+                    if let surrealdb::sql::Value::Number(n) = v {
+                        version = u64::try_from(n.as_int()).unwrap_or_default();
+                    }
+                    continue;
+                }
                 fields.insert(k.clone(), surreal_to_dynamic(v)?);
             }
 
-            Ok(Entity::with_id(entity_id, schema.clone(), fields))
+            Ok(Entity::with_id(entity_id, schema.clone(), fields).with_version(version))
         }
         other => Err(BackendError::Internal {
             message: format!("expected Object in query result, got: {other}"),
@@ -621,6 +902,351 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn query_stream_walks_every_page_in_sort_order() {
+        use futures::{StreamExt, TryStreamExt};
+        use schema_forge_backend::stream::paged_query_stream;
+        use schema_forge_core::migration::DiffEngine;
+        use schema_forge_core::query::{FieldPath, Query, SortOrder};
+        use schema_forge_core::types::{FieldDefinition, IntegerConstraints, SchemaId};
+
+        let backend = SurrealBackend::connect_memory("test", "test")
+            .await
+            .unwrap();
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Reading").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("value").unwrap(),
+                FieldType::Integer(IntegerConstraints::unconstrained()),
+            )],
+            vec![],
+        )
+        .unwrap();
+        backend
+            .apply_migration(&schema.name, &DiffEngine::create_new(&schema).steps)
+            .await
+            .unwrap();
+        backend.store_schema_metadata(&schema).await.unwrap();
+        for value in [5, 3, 9, 1, 7] {
+            let entity = Entity::for_schema(
+                &schema,
+                [("value".to_string(), DynamicValue::Integer(value))]
+                    .into_iter()
+                    .collect(),
+            );
+            backend.create(&entity).await.unwrap();
+        }
+
+        let query = Query::new(schema.id.clone())
+            .with_sort(FieldPath::single("value"), SortOrder::Ascending);
+        let values = |entities: Vec<Entity>| -> Vec<i64> {
+            entities
+                .iter()
+                .map(|e| match e.field("value") {
+                    Some(DynamicValue::Integer(v)) => *v,
+                    other => panic!("unexpected value {other:?}"),
+                })
+                .collect()
+        };
+
+        let all: Vec<Entity> = paged_query_stream(&backend, &query, 2)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(values(all), [1, 3, 5, 7, 9]);
+
+        // The query's own limit caps the stream across pages.
+        let capped: Vec<Entity> = paged_query_stream(&backend, &query.clone().with_limit(3), 2)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(values(capped), [1, 3, 5]);
+
+        // Taking a prefix drops the stream before later pages are fetched.
+        let prefix: Vec<Entity> = paged_query_stream(&backend, &query, 2)
+            .take(3)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(values(prefix), [1, 3, 5]);
+    }
+
+    #[tokio::test]
+    async fn removed_enum_variants_are_remapped_on_migration() {
+        use schema_forge_core::migration::DiffEngine;
+        use schema_forge_core::types::{
+            DefaultValue, EnumVariants, FieldDefinition, FieldModifier, SchemaId,
+        };
+
+        let backend = SurrealBackend::connect_memory("test", "test")
+            .await
+            .unwrap();
+        let ticket = |variants: &[&str], modifiers: Vec<FieldModifier>| {
+            SchemaDefinition::new(
+                SchemaId::new(),
+                SchemaName::new("Ticket").unwrap(),
+                vec![FieldDefinition::with_modifiers(
+                    FieldName::new("status").unwrap(),
+                    FieldType::Enum(
+                        EnumVariants::new(variants.iter().map(|v| v.to_string()).collect())
+                            .unwrap(),
+                    ),
+                    modifiers,
+                )],
+                vec![],
+            )
+            .unwrap()
+        };
+        let old = ticket(&["Open", "Stale", "Closed"], vec![]);
+        backend
+            .apply_migration(&old.name, &DiffEngine::create_new(&old).steps)
+            .await
+            .unwrap();
+        backend.store_schema_metadata(&old).await.unwrap();
+
+        let mut ids = Vec::new();
+        for status in ["Open", "Stale", "Stale"] {
+            let entity = Entity::new(
+                old.name.clone(),
+                [("status".to_string(), DynamicValue::Enum(status.into()))]
+                    .into_iter()
+                    .collect(),
+            );
+            ids.push(backend.create(&entity).await.unwrap().id);
+        }
+
+        let new = ticket(
+            &["Open", "Closed"],
+            vec![FieldModifier::Default {
+                value: DefaultValue::String("Closed".into()),
+            }],
+        );
+        let plan = DiffEngine::diff(&old, &new);
+        backend
+            .apply_migration(&new.name, &plan.steps)
+            .await
+            .unwrap();
+
+        let mut statuses = Vec::new();
+        for id in &ids {
+            let entity = backend.get(&new.name, id).await.unwrap();
+            statuses.push(match entity.fields.get("status") {
+                Some(DynamicValue::Enum(s) | DynamicValue::Text(s)) => s.clone(),
+                other => panic!("unexpected status {other:?}"),
+            });
+        }
+        assert_eq!(statuses, ["Open", "Closed", "Closed"]);
+    }
+
+    #[tokio::test]
+    async fn contains_filter_skips_unset_optional_fields() {
+        use schema_forge_core::migration::DiffEngine;
+        use schema_forge_core::query::{FieldPath, Filter, Query};
+        use schema_forge_core::types::{FieldDefinition, SchemaId, TextConstraints};
+
+        let backend = SurrealBackend::connect_memory("test", "test")
+            .await
+            .unwrap();
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("nickname").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+            )],
+            vec![],
+        )
+        .unwrap();
+        backend
+            .apply_migration(&schema.name, &DiffEngine::create_new(&schema).steps)
+            .await
+            .unwrap();
+        backend.store_schema_metadata(&schema).await.unwrap();
+        for nickname in [Some("Ace"), None, Some("Jack")] {
+            let fields = nickname
+                .map(|n| ("nickname".to_string(), DynamicValue::Text(n.into())))
+                .into_iter()
+                .collect();
+            backend
+                .create(&Entity::for_schema(&schema, fields))
+                .await
+                .unwrap();
+        }
+
+        let query = Query::new(schema.id.clone())
+            .with_filter(Filter::contains(FieldPath::single("nickname"), "AC"));
+        let result = backend.query(&query).await.unwrap();
+        let mut nicknames: Vec<String> = result
+            .entities
+            .iter()
+            .map(|e| match e.field("nickname") {
+                Some(DynamicValue::Text(n)) => n.clone(),
+                other => panic!("unexpected nickname {other:?}"),
+            })
+            .collect();
+        nicknames.sort();
+        assert_eq!(nicknames, ["Ace", "Jack"]);
+    }
+
+    #[tokio::test]
+    async fn query_filters_on_relation_target_field() {
+        use schema_forge_core::migration::DiffEngine;
+        use schema_forge_core::query::{FieldPath, Filter};
+        use schema_forge_core::types::{Cardinality, FieldDefinition, SchemaId, TextConstraints};
+
+        let backend = SurrealBackend::connect_memory("test", "test")
+            .await
+            .unwrap();
+        let text = || FieldType::Text(TextConstraints::unconstrained());
+        let organization = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Organization").unwrap(),
+            vec![
+                FieldDefinition::new(FieldName::new("name").unwrap(), text()),
+                FieldDefinition::new(FieldName::new("region").unwrap(), text()),
+            ],
+            vec![],
+        )
+        .unwrap();
+        let project = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Project").unwrap(),
+            vec![
+                FieldDefinition::new(FieldName::new("title").unwrap(), text()),
+                FieldDefinition::new(
+                    FieldName::new("organization").unwrap(),
+                    FieldType::Relation {
+                        target: organization.name.clone(),
+                        cardinality: Cardinality::One,
+                    },
+                ),
+            ],
+            vec![],
+        )
+        .unwrap();
+        for schema in [&organization, &project] {
+            backend
+                .apply_migration(&schema.name, &DiffEngine::create_new(schema).steps)
+                .await
+                .unwrap();
+            backend.store_schema_metadata(schema).await.unwrap();
+        }
+
+        let mut org_ids = BTreeMap::new();
+        for (name, region) in [("Acme", "EU"), ("Globex", "US")] {
+            let org = Entity::for_schema(
+                &organization,
+                [
+                    ("name".to_string(), DynamicValue::Text(name.into())),
+                    ("region".to_string(), DynamicValue::Text(region.into())),
+                ]
+                .into_iter()
+                .collect(),
+            );
+            org_ids.insert(name, backend.create(&org).await.unwrap().id);
+        }
+        for (title, org) in [
+            ("Apollo", "Acme"),
+            ("Borealis", "Globex"),
+            ("Cygnus", "Acme"),
+        ] {
+            let entity = Entity::for_schema(
+                &project,
+                [
+                    ("title".to_string(), DynamicValue::Text(title.into())),
+                    (
+                        "organization".to_string(),
+                        DynamicValue::Ref(org_ids[org].clone()),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            );
+            backend.create(&entity).await.unwrap();
+        }
+
+        let query = Query::new(project.id.clone()).with_filter(Filter::eq(
+            FieldPath::parse("organization.region").unwrap(),
+            DynamicValue::Text("EU".into()),
+        ));
+        let result = backend.query(&query).await.unwrap();
+        let mut titles: Vec<String> = result
+            .entities
+            .iter()
+            .map(|e| match e.field("title") {
+                Some(DynamicValue::Text(t)) => t.clone(),
+                other => panic!("unexpected title {other:?}"),
+            })
+            .collect();
+        titles.sort();
+        assert_eq!(titles, ["Apollo", "Cygnus"]);
+        assert_eq!(result.total_count, Some(2));
+    }
+
+    #[tokio::test]
+    async fn sequence_starts_after_start_and_is_per_field() {
+        let backend = SurrealBackend::connect_memory("test", "test")
+            .await
+            .unwrap();
+        let invoice = SchemaName::new("Invoice").unwrap();
+        let number = FieldName::new("number").unwrap();
+        let other = FieldName::new("other").unwrap();
+
+        assert_eq!(
+            backend
+                .next_sequence_value(&invoice, &number, 1000)
+                .await
+                .unwrap(),
+            1001
+        );
+        assert_eq!(
+            backend
+                .next_sequence_value(&invoice, &number, 1000)
+                .await
+                .unwrap(),
+            1002
+        );
+        assert_eq!(
+            backend
+                .next_sequence_value(&invoice, &other, 0)
+                .await
+                .unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_sequence_increments_are_unique_and_gapless() {
+        let backend = std::sync::Arc::new(
+            SurrealBackend::connect_memory("test", "test")
+                .await
+                .unwrap(),
+        );
+        let handles: Vec<_> = (0..20)
+            .map(|_| {
+                let backend = backend.clone();
+                tokio::spawn(async move {
+                    backend
+                        .next_sequence_value(
+                            &SchemaName::new("Invoice").unwrap(),
+                            &FieldName::new("number").unwrap(),
+                            1000,
+                        )
+                        .await
+                        .unwrap()
+                })
+            })
+            .collect();
+
+        let mut values = Vec::new();
+        for handle in handles {
+            values.push(handle.await.unwrap());
+        }
+        values.sort_unstable();
+        assert_eq!(values, (1001..=1020).collect::<Vec<_>>());
+    }
+
     #[test]
     fn extract_id_from_thing() {
         use surrealdb::sql::{Id, Thing};
@@ -3,12 +3,17 @@
 //! No I/O. No side effects. Each function takes schema-forge-core types
 //! and returns one or more SurrealQL statement strings.
 
-use schema_forge_core::migration::MigrationStep;
+use std::collections::BTreeMap;
+
+use schema_forge_backend::entity::VERSION_FIELD;
+use schema_forge_core::migration::{MigrationStep, ValueTransform};
 use schema_forge_core::types::{
-    Cardinality, FieldDefinition, FieldModifier, FieldType, FloatConstraints, IntegerConstraints,
-    TextConstraints,
+    Cardinality, DynamicValue, FieldDefinition, FieldModifier, FieldName, FieldType,
+    IntegerConstraints, SchemaDefinition, TextConstraints, DELETED_AT_FIELD,
 };
 
+use crate::query::dynamic_value_to_surql_literal;
+
 /// Compile a single `MigrationStep` into a list of SurrealQL DDL statements.
 ///
 /// A single step may produce multiple statements (e.g., `CreateSchema` emits
@@ -24,6 +29,7 @@ pub fn migration_step_to_surql(table: &str, step: &MigrationStep) -> Vec<String>
             for field in fields {
                 stmts.extend(define_field_stmts(table, field));
             }
+            stmts.extend(version_ddl_statements(table));
             stmts
         }
         MigrationStep::DropSchema { name: _ } => {
@@ -46,22 +52,90 @@ pub fn migration_step_to_surql(table: &str, step: &MigrationStep) -> Vec<String>
             name,
             old_type: _,
             new_type,
-            transform: _,
+            transform,
         } => {
-            let surql_type = field_type_to_surql(new_type);
-            let assertions = field_assertions(new_type);
-            let flex_prefix = if needs_flexible(new_type) {
-                "FLEXIBLE "
-            } else {
-                ""
-            };
-            let mut stmt =
-                format!("DEFINE FIELD OVERWRITE {name} ON {table} {flex_prefix}TYPE {surql_type}");
-            if !assertions.is_empty() {
-                stmt.push_str(&format!(" ASSERT {}", assertions.join(" AND ")));
+            let mut stmts = vec![redefine_field_type_stmt(table, name.as_ref(), new_type)];
+            // Cast through the float's shortest text form so `19.99` becomes
+            // `19.99dec` rather than its binary approximation.
+            if let ValueTransform::FloatToDecimal { scale } = transform {
+                stmts.push(format!(
+                    "UPDATE {table} SET {name} = math::fixed(<decimal> <string> {name}, {scale}) WHERE {name} != NONE;"
+                ));
+            }
+            // Stored UUIDs read back in canonical lowercase form.
+            if *transform == ValueTransform::ParseUuid {
+                stmts.push(format!(
+                    "UPDATE {table} SET {name} = string::lowercase({name}) WHERE {name} != NONE;"
+                ));
             }
-            stmt.push(';');
-            vec![stmt]
+            if let ValueTransform::Truncate { max_length } = transform {
+                stmts.push(format!(
+                    "UPDATE {table} SET {name} = string::slice({name}, 0, {max_length}) WHERE {name} != NONE AND string::len({name}) > {max_length};"
+                ));
+            }
+            // Blank text becomes NONE; other unparseable text fails the cast.
+            if *transform == ValueTransform::ParseNumber {
+                let trimmed = format!("string::trim({name})");
+                let cast = match new_type {
+                    FieldType::Decimal { scale } => {
+                        format!("math::fixed(<decimal> {trimmed}, {scale})")
+                    }
+                    FieldType::Float(_) => format!("<float> {trimmed}"),
+                    _ => format!("<int> {trimmed}"),
+                };
+                stmts.push(format!(
+                    "UPDATE {table} SET {name} = IF {trimmed} = '' THEN NONE ELSE {cast} END WHERE {name} != NONE;"
+                ));
+            }
+            if let ValueTransform::MapText { mapping, default } = transform {
+                stmts.extend(map_text_stmts(
+                    table,
+                    name.as_ref(),
+                    new_type,
+                    mapping,
+                    default.as_deref(),
+                ));
+            }
+            stmts
+        }
+        MigrationStep::AddEnumVariant {
+            field, variants, ..
+        } => {
+            let enum_type = FieldType::Enum(variants.clone());
+            vec![redefine_field_type_stmt(table, field.as_ref(), &enum_type)]
+        }
+        MigrationStep::RemoveEnumVariant {
+            field,
+            variant,
+            fallback,
+            variants,
+        } => {
+            // Rewrite the orphaned values before the narrower assertion applies.
+            let removed = dynamic_value_to_surql_literal(&DynamicValue::Enum(variant.clone()));
+            let replacement = match fallback {
+                Some(v) => dynamic_value_to_surql_literal(&DynamicValue::Enum(v.clone())),
+                None => "NONE".to_string(),
+            };
+            let enum_type = FieldType::Enum(variants.clone());
+            vec![
+                format!("UPDATE {table} SET {field} = {replacement} WHERE {field} = {removed};"),
+                redefine_field_type_stmt(table, field.as_ref(), &enum_type),
+            ]
+        }
+        MigrationStep::RenameEnumVariant {
+            field,
+            old_variant,
+            new_variant,
+            variants,
+        } => {
+            // Widen the assertion first so the rewritten values pass it.
+            let old_lit = dynamic_value_to_surql_literal(&DynamicValue::Enum(old_variant.clone()));
+            let new_lit = dynamic_value_to_surql_literal(&DynamicValue::Enum(new_variant.clone()));
+            let enum_type = FieldType::Enum(variants.clone());
+            vec![
+                redefine_field_type_stmt(table, field.as_ref(), &enum_type),
+                format!("UPDATE {table} SET {field} = {new_lit} WHERE {field} = {old_lit};"),
+            ]
         }
         MigrationStep::AddIndex { field } => {
             let idx_name = format!("idx_{table}_{field}");
@@ -73,6 +147,29 @@ pub fn migration_step_to_surql(table: &str, step: &MigrationStep) -> Vec<String>
             let idx_name = format!("idx_{table}_{field}");
             vec![format!("REMOVE INDEX {idx_name} ON {table};")]
         }
+        MigrationStep::AddUnique { field } => {
+            let idx_name = unique_index_name(table, field);
+            vec![format!(
+                "DEFINE INDEX {idx_name} ON {table} FIELDS {field} UNIQUE;"
+            )]
+        }
+        MigrationStep::RemoveUnique { field } => {
+            let idx_name = unique_index_name(table, field);
+            vec![format!("REMOVE INDEX IF EXISTS {idx_name} ON {table};")]
+        }
+        MigrationStep::AddCompositeIndex { fields, unique } => {
+            let idx_name = composite_index_name(table, fields);
+            let columns: Vec<&str> = fields.iter().map(FieldName::as_str).collect();
+            let unique = if *unique { " UNIQUE" } else { "" };
+            vec![format!(
+                "DEFINE INDEX {idx_name} ON {table} FIELDS {}{unique};",
+                columns.join(", ")
+            )]
+        }
+        MigrationStep::RemoveCompositeIndex { fields, .. } => {
+            let idx_name = composite_index_name(table, fields);
+            vec![format!("REMOVE INDEX IF EXISTS {idx_name} ON {table};")]
+        }
         MigrationStep::AddRelation {
             name,
             target,
@@ -119,18 +216,28 @@ pub fn migration_step_to_surql(table: &str, step: &MigrationStep) -> Vec<String>
                 "DEFINE FIELD OVERWRITE {field} ON {table} TYPE any;"
             )]
         }
-        MigrationStep::SetDefault { field, value } => {
-            let literal = default_value_to_surql(value);
-            vec![format!(
+        MigrationStep::SetDefault { field, value, .. } => match default_value_to_surql(value) {
+            Some(literal) => vec![format!(
                 "DEFINE FIELD OVERWRITE {field} ON {table} DEFAULT {literal};"
-            )]
-        }
-        MigrationStep::RemoveDefault { field } => {
+            )],
+            // Sequence defaults are resolved on create, not by the database.
+            None => vec![format!(
+                "DEFINE FIELD OVERWRITE {field} ON {table} TYPE any;"
+            )],
+        },
+        MigrationStep::RemoveDefault { field, .. } => {
             // Re-define without VALUE clause.
             vec![format!(
                 "DEFINE FIELD OVERWRITE {field} ON {table} TYPE any;"
             )]
         }
+        MigrationStep::EnableSoftDelete => vec![format!(
+            "DEFINE FIELD OVERWRITE {DELETED_AT_FIELD} ON TABLE {table} TYPE option<datetime>;"
+        )],
+        MigrationStep::DisableSoftDelete => vec![
+            format!("DELETE {table} WHERE {DELETED_AT_FIELD} != NONE;"),
+            format!("REMOVE FIELD IF EXISTS {DELETED_AT_FIELD} ON TABLE {table};"),
+        ],
         _ => {
             // Future MigrationStep variants -- produce a no-op comment.
             vec![format!("-- unsupported migration step for table {table}")]
@@ -138,6 +245,94 @@ pub fn migration_step_to_surql(table: &str, step: &MigrationStep) -> Vec<String>
     }
 }
 
+/// Name of the index backing an `@index(fields: [...])` annotation. The
+/// `cidx_` prefix keeps it apart from the `idx_` index of an `indexed`
+/// field, so `@index(fields: ["email"])` and `email: text indexed` coexist.
+pub fn composite_index_name(table: &str, fields: &[FieldName]) -> String {
+    let columns: Vec<&str> = fields.iter().map(FieldName::as_str).collect();
+    format!("cidx_{table}_{}", columns.join("_"))
+}
+
+/// Name of the unique index backing a `unique` field.
+pub fn unique_index_name(table: &str, field: &FieldName) -> String {
+    format!("uniq_{table}_{field}")
+}
+
+/// Every unique index of `definition` with the fields it covers: one per
+/// `unique` field and one per `@index(..., unique: true)`. Used to name
+/// the fields of a write SurrealDB rejected for a duplicate value.
+pub fn unique_indexes(definition: &SchemaDefinition) -> Vec<(String, Vec<FieldName>)> {
+    let table = definition.name.as_str();
+    let fields = definition
+        .fields
+        .iter()
+        .filter(|f| f.is_unique())
+        .map(|f| (unique_index_name(table, &f.name), vec![f.name.clone()]));
+    let composites = definition
+        .indexes()
+        .into_iter()
+        .filter(|(_, unique)| *unique)
+        .map(|(fields, _)| (composite_index_name(table, fields), fields.to_vec()));
+    fields.chain(composites).collect()
+}
+
+/// `UPDATE`s for a `MapText` transform, run after the new type is defined.
+///
+/// The mapped values are rewritten in one `IF` chain so a mapping never
+/// applies twice. Values still outside the new enum then become the
+/// default or, without one, are rewritten unchanged so the enum assertion
+/// rejects them and fails the migration.
+fn map_text_stmts(
+    table: &str,
+    name: &str,
+    new_type: &FieldType,
+    mapping: &BTreeMap<String, String>,
+    default: Option<&str>,
+) -> Vec<String> {
+    let literal = |v: &str| dynamic_value_to_surql_literal(&DynamicValue::Text(v.to_string()));
+    let mut stmts = Vec::new();
+    if !mapping.is_empty() {
+        let branches: Vec<String> = mapping
+            .iter()
+            .map(|(from, to)| format!("IF {name} = {} THEN {}", literal(from), literal(to)))
+            .collect();
+        let keys: Vec<String> = mapping.keys().map(|k| literal(k)).collect();
+        stmts.push(format!(
+            "UPDATE {table} SET {name} = {} END WHERE {name} IN [{}];",
+            branches.join(" ELSE "),
+            keys.join(", ")
+        ));
+    }
+    if let FieldType::Enum(variants) = new_type {
+        let variants: Vec<String> = variants.iter().map(|v| literal(v)).collect();
+        let replacement = default.map_or_else(|| name.to_string(), literal);
+        stmts.push(format!(
+            "UPDATE {table} SET {name} = {replacement} WHERE {name} != NONE AND {name} NOTINSIDE [{}];",
+            variants.join(", ")
+        ));
+    }
+    stmts
+}
+
+/// `DEFINE FIELD OVERWRITE` giving an existing field a new type and its
+/// matching assertions.
+fn redefine_field_type_stmt(table: &str, name: &str, field_type: &FieldType) -> String {
+    let surql_type = field_type_to_surql(field_type);
+    let assertions = field_assertions(field_type);
+    let flex_prefix = if needs_flexible(field_type) {
+        "FLEXIBLE "
+    } else {
+        ""
+    };
+    let mut stmt =
+        format!("DEFINE FIELD OVERWRITE {name} ON {table} {flex_prefix}TYPE {surql_type}");
+    if !assertions.is_empty() {
+        stmt.push_str(&format!(" ASSERT {}", assertions.join(" AND ")));
+    }
+    stmt.push(';');
+    stmt
+}
+
 /// Returns `true` when a SurrealDB `DEFINE FIELD` for this type must carry
 /// the `FLEXIBLE` keyword. SCHEMAFULL tables otherwise reject unknown
 /// sub-keys on object-valued columns. Applies to `json` fields and the
@@ -154,11 +349,18 @@ fn needs_flexible(field_type: &FieldType) -> bool {
 /// Convert a `FieldType` to its SurrealQL TYPE string.
 pub fn field_type_to_surql(field_type: &FieldType) -> String {
     match field_type {
-        FieldType::Text(_) | FieldType::RichText => "string".to_string(),
+        FieldType::Text(_) | FieldType::RichText(_) => "string".to_string(),
         FieldType::Integer(_) => "int".to_string(),
         FieldType::Float(_) => "float".to_string(),
+        // Native 128-bit decimal, so amounts never pass through a float.
+        FieldType::Decimal { .. } => "decimal".to_string(),
         FieldType::Boolean => "bool".to_string(),
         FieldType::DateTime => "datetime".to_string(),
+        // Kept as ISO-8601 text: SurrealDB's native duration has no
+        // calendar months or years.
+        FieldType::Duration => "string".to_string(),
+        // Canonical hyphenated text, validated by the entity routes.
+        FieldType::Uuid => "string".to_string(),
         FieldType::Enum(_) => "string".to_string(),
         FieldType::Json => "object".to_string(),
         FieldType::Relation {
@@ -169,7 +371,7 @@ pub fn field_type_to_surql(field_type: &FieldType) -> String {
             Cardinality::Many => format!("option<array<record<{target}>>>"),
             _ => format!("option<record<{target}>>"),
         },
-        FieldType::Array(inner) => {
+        FieldType::Array(inner, _) => {
             let inner_type = field_type_to_surql(inner);
             format!("array<{inner_type}>")
         }
@@ -186,6 +388,7 @@ pub fn field_assertions(field_type: &FieldType) -> Vec<String> {
     match field_type {
         FieldType::Text(TextConstraints {
             max_length: Some(max),
+            ..
         }) => {
             vec![format!("string::len($value) <= {max}")]
         }
@@ -199,9 +402,17 @@ pub fn field_assertions(field_type: &FieldType) -> Vec<String> {
             }
             assertions
         }
-        FieldType::Float(FloatConstraints { precision: _ }) => {
-            // Precision is a display concern, not a storage assertion.
-            Vec::new()
+        // Precision is a display concern, not a storage assertion. Bounds
+        // are emitted from their parsed value, never the raw text.
+        FieldType::Float(c) => {
+            let mut assertions = Vec::new();
+            if let Some(min_val) = c.min_value() {
+                assertions.push(format!("$value >= {min_val}"));
+            }
+            if let Some(max_val) = c.max_value() {
+                assertions.push(format!("$value <= {max_val}"));
+            }
+            assertions
         }
         FieldType::Enum(variants) => {
             let values: Vec<String> = variants.iter().map(|v| format!("'{v}'")).collect();
@@ -257,8 +468,9 @@ fn define_field_stmts(table: &str, field: &FieldDefinition) -> Vec<String> {
     // silently overwritten by the default — see issue #49.
     for modifier in &field.modifiers {
         if let FieldModifier::Default { value } = modifier {
-            let literal = default_value_to_surql(value);
-            stmt.push_str(&format!(" DEFAULT {literal}"));
+            if let Some(literal) = default_value_to_surql(value) {
+                stmt.push_str(&format!(" DEFAULT {literal}"));
+            }
         }
     }
 
@@ -270,6 +482,12 @@ fn define_field_stmts(table: &str, field: &FieldDefinition) -> Vec<String> {
         let idx_name = format!("idx_{table}_{name}");
         parts.push(format!("DEFINE INDEX {idx_name} ON {table} FIELDS {name};"));
     }
+    if field.is_unique() {
+        let idx_name = unique_index_name(table, &field.name);
+        parts.push(format!(
+            "DEFINE INDEX {idx_name} ON {table} FIELDS {name} UNIQUE;"
+        ));
+    }
 
     // If composite, emit nested DEFINE FIELD statements. Apply the same
     // option<> wrapping + assertion logic the top-level field gets, so
@@ -308,13 +526,17 @@ fn define_field_stmts(table: &str, field: &FieldDefinition) -> Vec<String> {
 }
 
 /// Convert a `DefaultValue` to its SurrealQL literal representation.
-fn default_value_to_surql(value: &schema_forge_core::types::DefaultValue) -> String {
+///
+/// Returns `None` for sequence defaults, which have no column-level
+/// equivalent and are resolved on create instead.
+fn default_value_to_surql(value: &schema_forge_core::types::DefaultValue) -> Option<String> {
     use schema_forge_core::types::DefaultValue;
     match value {
-        DefaultValue::String(s) => format!("'{s}'"),
-        DefaultValue::Integer(i) => i.to_string(),
-        DefaultValue::Float(s) => s.clone(),
-        DefaultValue::Boolean(b) => b.to_string(),
+        DefaultValue::String(s) => Some(format!("'{s}'")),
+        DefaultValue::Integer(i) => Some(i.to_string()),
+        DefaultValue::Float(s) => Some(s.clone()),
+        DefaultValue::Boolean(b) => Some(b.to_string()),
+        DefaultValue::Sequence { .. } => None,
     }
 }
 
@@ -333,10 +555,22 @@ pub fn tenant_ddl_statements(table: &str) -> Vec<String> {
     ]
 }
 
+/// Generate SurrealQL statements to define the `_version` field used for
+/// optimistic concurrency.
+///
+/// Emitted by `CreateSchema` and re-run idempotently (`OVERWRITE`) when
+/// schema metadata is stored, so tables created before versioning pick
+/// the field up. Records without a stored version read as version 0.
+pub fn version_ddl_statements(table: &str) -> Vec<String> {
+    vec![format!(
+        "DEFINE FIELD OVERWRITE {VERSION_FIELD} ON TABLE {table} TYPE int DEFAULT 1;"
+    )]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use schema_forge_core::types::{DefaultValue, EnumVariants, FieldName, SchemaName};
+    use schema_forge_core::types::{DefaultValue, EnumVariants, FloatConstraints, SchemaName};
 
     fn text_field(name: &str) -> FieldDefinition {
         FieldDefinition::new(
@@ -363,7 +597,28 @@ mod tests {
         // Non-required fields get option<> wrapper
         assert!(stmts[1].contains("DEFINE FIELD name ON Contact TYPE option<string>;"));
         assert!(stmts[2].contains("DEFINE FIELD email ON Contact TYPE option<string>;"));
-        assert_eq!(stmts.len(), 3);
+        assert_eq!(
+            stmts[3],
+            "DEFINE FIELD OVERWRITE _version ON TABLE Contact TYPE int DEFAULT 1;"
+        );
+        assert_eq!(stmts.len(), 4);
+    }
+
+    #[test]
+    fn soft_delete_steps_define_and_drop_marker() {
+        let stmts = migration_step_to_surql("Contact", &MigrationStep::EnableSoftDelete);
+        assert_eq!(
+            stmts,
+            vec!["DEFINE FIELD OVERWRITE _deleted_at ON TABLE Contact TYPE option<datetime>;"]
+        );
+        let stmts = migration_step_to_surql("Contact", &MigrationStep::DisableSoftDelete);
+        assert_eq!(
+            stmts,
+            vec![
+                "DELETE Contact WHERE _deleted_at != NONE;",
+                "REMOVE FIELD IF EXISTS _deleted_at ON TABLE Contact;",
+            ]
+        );
     }
 
     #[test]
@@ -399,6 +654,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn add_field_float_with_bounds() {
+        let step = MigrationStep::AddField {
+            field: FieldDefinition::new(
+                FieldName::new("ratio").unwrap(),
+                FieldType::Float(FloatConstraints::with_bounds(Some("0"), Some("1.5")).unwrap()),
+            ),
+        };
+        let stmts = migration_step_to_surql("Contact", &step);
+        assert_eq!(
+            stmts,
+            vec!["DEFINE FIELD ratio ON Contact TYPE option<float> ASSERT $value >= 0 AND $value <= 1.5;"]
+        );
+    }
+
     #[test]
     fn add_field_integer_with_range() {
         let step = MigrationStep::AddField {
@@ -479,6 +749,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn add_field_with_sequence_default_has_no_column_default() {
+        let step = MigrationStep::AddField {
+            field: FieldDefinition::with_modifiers(
+                FieldName::new("number").unwrap(),
+                FieldType::Text(TextConstraints::unconstrained()),
+                vec![FieldModifier::Default {
+                    value: DefaultValue::Sequence {
+                        prefix: "INV-".into(),
+                        start: 1000,
+                    },
+                }],
+            ),
+        };
+        let stmts = migration_step_to_surql("Invoice", &step);
+        assert_eq!(
+            stmts,
+            vec!["DEFINE FIELD number ON Invoice TYPE option<string>;"]
+        );
+    }
+
     #[test]
     fn add_field_indexed() {
         let step = MigrationStep::AddField {
@@ -530,6 +821,69 @@ mod tests {
         assert_eq!(stmts, vec!["REMOVE INDEX idx_Contact_email ON Contact;"]);
     }
 
+    #[test]
+    fn add_and_remove_unique() {
+        let field = FieldName::new("email").unwrap();
+        let add = MigrationStep::AddUnique {
+            field: field.clone(),
+        };
+        assert_eq!(
+            migration_step_to_surql("Contact", &add),
+            vec!["DEFINE INDEX uniq_Contact_email ON Contact FIELDS email UNIQUE;"]
+        );
+        let remove = MigrationStep::RemoveUnique { field };
+        assert_eq!(
+            migration_step_to_surql("Contact", &remove),
+            vec!["REMOVE INDEX IF EXISTS uniq_Contact_email ON Contact;"]
+        );
+
+        let unique_field = FieldDefinition::with_modifiers(
+            FieldName::new("email").unwrap(),
+            FieldType::Text(TextConstraints::unconstrained()),
+            vec![FieldModifier::Unique],
+        );
+        let stmts = migration_step_to_surql(
+            "Contact",
+            &MigrationStep::AddField {
+                field: unique_field,
+            },
+        );
+        assert!(stmts
+            .contains(&"DEFINE INDEX uniq_Contact_email ON Contact FIELDS email UNIQUE;".into()));
+    }
+
+    #[test]
+    fn add_and_remove_composite_index() {
+        let fields = vec![
+            FieldName::new("agency").unwrap(),
+            FieldName::new("stage").unwrap(),
+        ];
+        let add = MigrationStep::AddCompositeIndex {
+            fields: fields.clone(),
+            unique: true,
+        };
+        assert_eq!(
+            migration_step_to_surql("Deal", &add),
+            vec!["DEFINE INDEX cidx_Deal_agency_stage ON Deal FIELDS agency, stage UNIQUE;"]
+        );
+        let plain = MigrationStep::AddCompositeIndex {
+            fields: fields.clone(),
+            unique: false,
+        };
+        assert_eq!(
+            migration_step_to_surql("Deal", &plain),
+            vec!["DEFINE INDEX cidx_Deal_agency_stage ON Deal FIELDS agency, stage;"]
+        );
+        let remove = MigrationStep::RemoveCompositeIndex {
+            fields,
+            unique: true,
+        };
+        assert_eq!(
+            migration_step_to_surql("Deal", &remove),
+            vec!["REMOVE INDEX IF EXISTS cidx_Deal_agency_stage ON Deal;"]
+        );
+    }
+
     #[test]
     fn add_relation_one() {
         let step = MigrationStep::AddRelation {
@@ -564,7 +918,7 @@ mod tests {
             field_type_to_surql(&FieldType::Text(TextConstraints::unconstrained())),
             "string"
         );
-        assert_eq!(field_type_to_surql(&FieldType::RichText), "string");
+        assert_eq!(field_type_to_surql(&FieldType::rich_text()), "string");
         assert_eq!(
             field_type_to_surql(&FieldType::Integer(IntegerConstraints::unconstrained())),
             "int"
@@ -575,9 +929,15 @@ mod tests {
         );
         assert_eq!(field_type_to_surql(&FieldType::Boolean), "bool");
         assert_eq!(field_type_to_surql(&FieldType::DateTime), "datetime");
+        assert_eq!(field_type_to_surql(&FieldType::Duration), "string");
+        assert_eq!(field_type_to_surql(&FieldType::Uuid), "string");
+        assert_eq!(
+            field_type_to_surql(&FieldType::Decimal { scale: 2 }),
+            "decimal"
+        );
         assert_eq!(field_type_to_surql(&FieldType::Json), "object");
         assert_eq!(
-            field_type_to_surql(&FieldType::Array(Box::new(FieldType::Boolean))),
+            field_type_to_surql(&FieldType::array(FieldType::Boolean)),
             "array<bool>"
         );
     }
@@ -711,6 +1071,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn add_enum_variant_widens_assertion() {
+        let step = MigrationStep::AddEnumVariant {
+            field: FieldName::new("status").unwrap(),
+            variant: "archived".into(),
+            variants: EnumVariants::new(vec!["active".into(), "archived".into()]).unwrap(),
+        };
+        let stmts = migration_step_to_surql("Contact", &step);
+        assert_eq!(
+            stmts,
+            ["DEFINE FIELD OVERWRITE status ON Contact TYPE string ASSERT $value IN ['active', 'archived'];"]
+        );
+    }
+
+    #[test]
+    fn remove_enum_variant_rewrites_rows_first() {
+        let step = MigrationStep::RemoveEnumVariant {
+            field: FieldName::new("status").unwrap(),
+            variant: "stale".into(),
+            fallback: Some("closed".into()),
+            variants: EnumVariants::new(vec!["open".into(), "closed".into()]).unwrap(),
+        };
+        let stmts = migration_step_to_surql("Ticket", &step);
+        assert_eq!(stmts.len(), 2);
+        assert_eq!(
+            stmts[0],
+            "UPDATE Ticket SET status = 'closed' WHERE status = 'stale';"
+        );
+        assert!(stmts[1].ends_with("ASSERT $value IN ['open', 'closed'];"));
+    }
+
+    #[test]
+    fn rename_enum_variant_widens_then_rewrites() {
+        let step = MigrationStep::RenameEnumVariant {
+            field: FieldName::new("status").unwrap(),
+            old_variant: "done".into(),
+            new_variant: "closed".into(),
+            variants: EnumVariants::new(vec!["open".into(), "closed".into()]).unwrap(),
+        };
+        let stmts = migration_step_to_surql("Ticket", &step);
+        assert_eq!(stmts.len(), 2);
+        assert!(stmts[0].starts_with("DEFINE FIELD OVERWRITE status ON Ticket"));
+        assert_eq!(
+            stmts[1],
+            "UPDATE Ticket SET status = 'closed' WHERE status = 'done';"
+        );
+    }
+
     #[test]
     fn change_type_text_with_max_includes_assertion() {
         let step = MigrationStep::ChangeType {
@@ -743,6 +1151,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn change_type_float_to_decimal_converts_rows_after_redefine() {
+        let step = MigrationStep::ChangeType {
+            name: FieldName::new("amount").unwrap(),
+            old_type: FieldType::Float(FloatConstraints::unconstrained()),
+            new_type: FieldType::Decimal { scale: 2 },
+            transform: ValueTransform::FloatToDecimal { scale: 2 },
+        };
+        let stmts = migration_step_to_surql("Invoice", &step);
+        assert_eq!(
+            stmts,
+            vec![
+                "DEFINE FIELD OVERWRITE amount ON Invoice TYPE decimal;".to_string(),
+                "UPDATE Invoice SET amount = math::fixed(<decimal> <string> amount, 2) WHERE amount != NONE;"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn change_type_text_to_uuid_lowercases_rows() {
+        let step = MigrationStep::ChangeType {
+            name: FieldName::new("external_id").unwrap(),
+            old_type: FieldType::Text(TextConstraints::unconstrained()),
+            new_type: FieldType::Uuid,
+            transform: ValueTransform::ParseUuid,
+        };
+        let stmts = migration_step_to_surql("Account", &step);
+        assert_eq!(
+            stmts,
+            vec![
+                "DEFINE FIELD OVERWRITE external_id ON Account TYPE string;".to_string(),
+                "UPDATE Account SET external_id = string::lowercase(external_id) WHERE external_id != NONE;"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn change_type_truncate_cuts_long_text() {
+        let step = MigrationStep::ChangeType {
+            name: FieldName::new("bio").unwrap(),
+            old_type: FieldType::Text(TextConstraints::with_max_length(255)),
+            new_type: FieldType::Text(TextConstraints::with_max_length(50)),
+            transform: ValueTransform::Truncate { max_length: 50 },
+        };
+        let stmts = migration_step_to_surql("Contact", &step);
+        assert_eq!(
+            stmts,
+            vec![
+                "DEFINE FIELD OVERWRITE bio ON Contact TYPE string ASSERT string::len($value) <= 50;"
+                    .to_string(),
+                "UPDATE Contact SET bio = string::slice(bio, 0, 50) WHERE bio != NONE AND string::len(bio) > 50;"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn change_type_parse_number_casts_rows() {
+        let step = MigrationStep::ChangeType {
+            name: FieldName::new("amount").unwrap(),
+            old_type: FieldType::Text(TextConstraints::unconstrained()),
+            new_type: FieldType::Decimal { scale: 2 },
+            transform: ValueTransform::ParseNumber,
+        };
+        let stmts = migration_step_to_surql("Deal", &step);
+        assert_eq!(stmts.len(), 2);
+        assert_eq!(
+            stmts[1],
+            "UPDATE Deal SET amount = IF string::trim(amount) = '' THEN NONE ELSE math::fixed(<decimal> string::trim(amount), 2) END WHERE amount != NONE;"
+        );
+    }
+
+    #[test]
+    fn change_type_map_text_rewrites_then_checks_variants() {
+        let status = || EnumVariants::new(vec!["Open".into(), "Won".into()]).unwrap();
+        let step = |default: Option<&str>| MigrationStep::ChangeType {
+            name: FieldName::new("status").unwrap(),
+            old_type: FieldType::Text(TextConstraints::unconstrained()),
+            new_type: FieldType::Enum(status()),
+            transform: ValueTransform::MapText {
+                mapping: BTreeMap::from([
+                    ("open".into(), "Open".into()),
+                    ("won".into(), "Won".into()),
+                ]),
+                default: default.map(str::to_string),
+            },
+        };
+
+        let stmts = migration_step_to_surql("Deal", &step(Some("Open")));
+        assert_eq!(
+            stmts[1..],
+            [
+                "UPDATE Deal SET status = IF status = 'open' THEN 'Open' ELSE IF status = 'won' THEN 'Won' END WHERE status IN ['open', 'won'];",
+                "UPDATE Deal SET status = 'Open' WHERE status != NONE AND status NOTINSIDE ['Open', 'Won'];",
+            ]
+        );
+
+        // Without a default, leftovers are rewritten as-is so the enum
+        // assertion fails the migration.
+        let stmts = migration_step_to_surql("Deal", &step(None));
+        assert_eq!(
+            stmts[2],
+            "UPDATE Deal SET status = status WHERE status != NONE AND status NOTINSIDE ['Open', 'Won'];"
+        );
+    }
+
     #[test]
     fn tenant_ddl_statements_generates_correct_sql() {
         let stmts = tenant_ddl_statements("Contact");
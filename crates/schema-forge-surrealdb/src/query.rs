@@ -4,7 +4,7 @@
 //! No I/O. No side effects.
 
 use schema_forge_core::query::{AggregateOp, AggregateQuery, FieldPath, Filter, Query, SortOrder};
-use schema_forge_core::types::{DynamicValue, FieldType, SchemaDefinition};
+use schema_forge_core::types::{DynamicValue, FieldType, SchemaDefinition, DELETED_AT_FIELD};
 
 /// Compile a `Query` to a complete SurrealQL SELECT statement.
 ///
@@ -35,10 +35,11 @@ pub fn query_to_surql_with_schema(
         }
     };
     let mut sql = format!("SELECT {select_clause} FROM {table}");
-
-    if let Some(filter) = &query.filter {
-        sql.push_str(&format!(" WHERE {}", filter_to_surql_with_schema(filter, schema)));
-    }
+    sql.push_str(&where_clause(
+        query.filter.as_ref(),
+        schema,
+        query.hides_deleted(schema),
+    ));
 
     if !query.sort.is_empty() {
         sql.push_str(" ORDER BY ");
@@ -82,15 +83,36 @@ pub fn count_to_surql_with_schema(
     schema: Option<&SchemaDefinition>,
 ) -> String {
     let mut sql = format!("SELECT count() FROM {table}");
-
-    if let Some(filter) = &query.filter {
-        sql.push_str(&format!(" WHERE {}", filter_to_surql_with_schema(filter, schema)));
-    }
+    sql.push_str(&where_clause(
+        query.filter.as_ref(),
+        schema,
+        query.hides_deleted(schema),
+    ));
 
     sql.push_str(" GROUP ALL;");
     sql
 }
 
+/// Build the ` WHERE ...` suffix (empty when there is nothing to filter):
+/// the query's filter, ANDed with the soft-delete guard when
+/// `hide_deleted` is set.
+fn where_clause(
+    filter: Option<&Filter>,
+    schema: Option<&SchemaDefinition>,
+    hide_deleted: bool,
+) -> String {
+    let guard = format!("{DELETED_AT_FIELD} IS NONE");
+    match (filter, hide_deleted) {
+        (None, false) => String::new(),
+        (None, true) => format!(" WHERE {guard}"),
+        (Some(filter), false) => format!(" WHERE {}", filter_to_surql_with_schema(filter, schema)),
+        (Some(filter), true) => format!(
+            " WHERE ({}) AND {guard}",
+            filter_to_surql_with_schema(filter, schema)
+        ),
+    }
+}
+
 /// Compile a `Filter` to a SurrealQL WHERE clause fragment (no leading WHERE).
 pub fn filter_to_surql(filter: &Filter) -> String {
     filter_to_surql_with_schema(filter, None)
@@ -148,11 +170,13 @@ pub fn filter_to_surql_with_schema(
                 dynamic_value_to_surql_literal(value)
             )
         }
+        // Case-insensitive, matching the Postgres backend's ILIKE. Unset
+        // fields coalesce to '' so string::lowercase never sees NONE.
         Filter::Contains { path, value } => {
             format!(
-                "{} CONTAINS '{}'",
+                "string::lowercase({} ?? '') CONTAINS '{}'",
                 field_path_to_surql(path),
-                escape_surql_string(value)
+                escape_surql_string(&value.to_lowercase())
             )
         }
         Filter::StartsWith { path, value } => {
@@ -209,6 +233,11 @@ fn value_for_path(
 ) -> String {
     if let Some(schema) = schema {
         let segments: Vec<&str> = path.segments().iter().map(|s| s.as_str()).collect();
+        if segments == ["id"] {
+            // Record ids compare as `Table:key`, so a cursor's id bound must
+            // be a record literal of the queried table.
+            return relation_value_literal(value, schema.name.as_str());
+        }
         if segments.len() == 1 {
             if let Some(field) = schema.field(segments[0]) {
                 if let FieldType::Relation { target, .. } = &field.field_type {
@@ -247,6 +276,9 @@ pub fn dynamic_value_to_surql_literal(value: &DynamicValue) -> String {
         DynamicValue::DateTime(dt) => {
             format!("d'{}'", dt.to_rfc3339())
         }
+        DynamicValue::Duration(d) => format!("'{d}'"),
+        DynamicValue::Uuid(u) => format!("'{u}'"),
+        DynamicValue::Decimal(d) => format!("{d}dec"),
         DynamicValue::Enum(s) => format!("'{}'", escape_surql_string(s)),
         DynamicValue::Json(v) => v.to_string(),
         DynamicValue::Array(arr) => {
@@ -283,31 +315,54 @@ pub fn dynamic_value_to_surql_literal(value: &DynamicValue) -> String {
 /// Compile an `AggregateQuery` to a SurrealQL SELECT statement with aggregate functions.
 ///
 /// Uses index-based aliases (`agg_0`, `agg_1`, ...) for predictable result keys.
-/// Applies an optional WHERE filter and always ends with GROUP ALL.
+/// Applies an optional WHERE filter and ends with `GROUP BY` over the
+/// query's `group_by` fields (selected alongside the aggregates), or
+/// `GROUP ALL` when there are none.
 pub fn aggregate_to_surql(query: &AggregateQuery, table: &str) -> String {
-    let projections: Vec<String> = query
-        .ops
+    aggregate_to_surql_with_schema(query, table, None)
+}
+
+/// Schema-aware variant of [`aggregate_to_surql`].
+pub fn aggregate_to_surql_with_schema(
+    query: &AggregateQuery,
+    table: &str,
+    schema: Option<&SchemaDefinition>,
+) -> String {
+    let group_fields: Vec<&str> = query.group_by.iter().map(|g| g.as_str()).collect();
+    let aggregates = query.ops.iter().enumerate().map(|(i, op)| match op {
+        AggregateOp::Count => format!("count() AS agg_{i}"),
+        AggregateOp::Sum { field } => {
+            format!("math::sum({}) AS agg_{i}", field_path_to_surql(field))
+        }
+        AggregateOp::Avg { field } => {
+            format!("math::mean({}) AS agg_{i}", field_path_to_surql(field))
+        }
+        AggregateOp::Min { field } => {
+            format!("math::min({}) AS agg_{i}", field_path_to_surql(field))
+        }
+        AggregateOp::Max { field } => {
+            format!("math::max({}) AS agg_{i}", field_path_to_surql(field))
+        }
+        _ => format!("count() AS agg_{i}"),
+    });
+    let projections: Vec<String> = group_fields
         .iter()
-        .enumerate()
-        .map(|(i, op)| match op {
-            AggregateOp::Count => format!("count() AS agg_{i}"),
-            AggregateOp::Sum { field } => {
-                format!("math::sum({}) AS agg_{i}", field_path_to_surql(field))
-            }
-            AggregateOp::Avg { field } => {
-                format!("math::mean({}) AS agg_{i}", field_path_to_surql(field))
-            }
-            _ => format!("count() AS agg_{i}"),
-        })
+        .map(|g| g.to_string())
+        .chain(aggregates)
         .collect();
 
     let mut sql = format!("SELECT {} FROM {table}", projections.join(", "));
+    sql.push_str(&where_clause(
+        query.filter.as_ref(),
+        schema,
+        query.hides_deleted(schema),
+    ));
 
-    if let Some(filter) = &query.filter {
-        sql.push_str(&format!(" WHERE {}", filter_to_surql(filter)));
+    if group_fields.is_empty() {
+        sql.push_str(" GROUP ALL;");
+    } else {
+        sql.push_str(&format!(" GROUP BY {};", group_fields.join(", ")));
     }
-
-    sql.push_str(" GROUP ALL;");
     sql
 }
 
@@ -389,6 +444,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn select_with_and_nested_in_or() {
+        let text = |s: &str| DynamicValue::Text(s.into());
+        let q = Query::new(SchemaId::new()).with_filter(Filter::or(vec![
+            Filter::eq(FieldPath::single("status"), text("open")),
+            Filter::and(vec![
+                Filter::eq(FieldPath::single("priority"), text("high")),
+                Filter::eq(FieldPath::single("assignee"), text("me")),
+            ]),
+        ]));
+        let sql = query_to_surql(&q, "Ticket");
+        assert_eq!(
+            sql,
+            "SELECT * FROM Ticket WHERE (status = 'open' OR (priority = 'high' AND assignee = 'me'));"
+        );
+    }
+
     #[test]
     fn select_with_not_filter() {
         let q = Query::new(SchemaId::new()).with_filter(Filter::negate(Filter::eq(
@@ -402,11 +474,11 @@ mod tests {
     #[test]
     fn select_with_contains() {
         let q = Query::new(SchemaId::new())
-            .with_filter(Filter::contains(FieldPath::single("email"), "example.com"));
+            .with_filter(Filter::contains(FieldPath::single("email"), "Example.COM"));
         let sql = query_to_surql(&q, "Contact");
         assert_eq!(
             sql,
-            "SELECT * FROM Contact WHERE email CONTAINS 'example.com';"
+            "SELECT * FROM Contact WHERE string::lowercase(email ?? '') CONTAINS 'example.com';"
         );
     }
 
@@ -446,6 +518,80 @@ mod tests {
         assert_eq!(sql, "SELECT * FROM Contact ORDER BY name ASC, age DESC;");
     }
 
+    #[test]
+    fn select_after_cursor_compares_record_ids() {
+        use schema_forge_core::query::Cursor;
+        use schema_forge_core::types::{
+            FieldDefinition, FieldName, IntegerConstraints, SchemaName,
+        };
+
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![FieldDefinition::new(
+                FieldName::new("age").unwrap(),
+                FieldType::Integer(IntegerConstraints::unconstrained()),
+            )],
+            vec![],
+        )
+        .unwrap();
+        let q = Query::new(schema.id.clone())
+            .with_sort(FieldPath::single("age"), SortOrder::Descending)
+            .with_limit(10)
+            .with_after(Cursor::new(vec![DynamicValue::Integer(30)], "contact_1"))
+            .resolve_cursor()
+            .unwrap();
+        let sql = query_to_surql_with_schema(&q, "Contact", Some(&schema));
+        assert_eq!(
+            sql,
+            "SELECT * FROM Contact WHERE (age < 30 OR (age = 30 AND id > Contact:`contact_1`)) \
+             ORDER BY age DESC, id ASC LIMIT 10;"
+        );
+    }
+
+    #[test]
+    fn soft_delete_schema_hides_deleted_rows_by_default() {
+        use schema_forge_core::types::{Annotation, SchemaName};
+
+        let schema = SchemaDefinition::new(
+            SchemaId::new(),
+            SchemaName::new("Contact").unwrap(),
+            vec![],
+            vec![Annotation::SoftDelete],
+        )
+        .unwrap();
+        let q = Query::new(schema.id.clone());
+        assert_eq!(
+            query_to_surql_with_schema(&q, "Contact", Some(&schema)),
+            "SELECT * FROM Contact WHERE _deleted_at IS NONE;"
+        );
+        assert_eq!(
+            count_to_surql_with_schema(&q, "Contact", Some(&schema)),
+            "SELECT count() FROM Contact WHERE _deleted_at IS NONE GROUP ALL;"
+        );
+
+        let filtered = q.clone().with_filter(Filter::eq(
+            FieldPath::single("name"),
+            DynamicValue::Text("Jane".into()),
+        ));
+        assert_eq!(
+            query_to_surql_with_schema(&filtered, "Contact", Some(&schema)),
+            "SELECT * FROM Contact WHERE (name = 'Jane') AND _deleted_at IS NONE;"
+        );
+
+        let agg = AggregateQuery::new(schema.id.clone()).with_op(AggregateOp::Count);
+        assert_eq!(
+            aggregate_to_surql_with_schema(&agg, "Contact", Some(&schema)),
+            "SELECT count() AS agg_0 FROM Contact WHERE _deleted_at IS NONE GROUP ALL;"
+        );
+
+        let all = q.with_deleted(true);
+        assert_eq!(
+            query_to_surql_with_schema(&all, "Contact", Some(&schema)),
+            "SELECT * FROM Contact;"
+        );
+    }
+
     #[test]
     fn select_with_limit_and_offset() {
         let q = Query::new(SchemaId::new()).with_limit(10).with_offset(20);
@@ -487,6 +633,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dynamic_value_decimal_literal() {
+        let d = schema_forge_core::types::parse_decimal("-12.5", 2).unwrap();
+        assert_eq!(
+            dynamic_value_to_surql_literal(&DynamicValue::Decimal(d)),
+            "-12.50dec"
+        );
+    }
+
     #[test]
     fn dynamic_value_boolean_literal() {
         assert_eq!(
@@ -595,6 +750,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn aggregate_min_max_group_by() {
+        use schema_forge_core::types::FieldName;
+
+        let q = AggregateQuery::new(SchemaId::new())
+            .with_op(AggregateOp::Min {
+                field: FieldPath::single("value"),
+            })
+            .with_op(AggregateOp::Max {
+                field: FieldPath::single("value"),
+            })
+            .with_group_by(FieldName::new("stage").unwrap())
+            .with_group_by(FieldName::new("owner").unwrap());
+        let sql = aggregate_to_surql(&q, "Deal");
+        assert_eq!(
+            sql,
+            "SELECT stage, owner, math::min(value) AS agg_0, math::max(value) AS agg_1 \
+             FROM Deal GROUP BY stage, owner;"
+        );
+    }
+
     #[test]
     fn count_ignores_limit_and_sort() {
         let q = Query::new(SchemaId::new())
@@ -20,12 +20,15 @@ pub fn dynamic_to_surreal(value: &DynamicValue) -> SurrealValue {
         DynamicValue::Text(s) => SurrealValue::from(s.as_str()),
         DynamicValue::Integer(i) => SurrealValue::from(*i),
         DynamicValue::Float(f) => SurrealValue::from(*f),
+        DynamicValue::Decimal(d) => SurrealValue::Number(surrealdb::sql::Number::Decimal(*d)),
         DynamicValue::Boolean(b) => SurrealValue::from(*b),
         DynamicValue::DateTime(dt) => {
             // Store as ISO 8601 string — the literal serializer in backend.rs
             // will wrap it with d'...' for SurrealQL datetime fields.
             SurrealValue::from(dt.to_rfc3339())
         }
+        DynamicValue::Duration(d) => SurrealValue::from(d.to_string()),
+        DynamicValue::Uuid(u) => SurrealValue::from(u.to_string()),
         DynamicValue::Enum(s) => SurrealValue::from(s.as_str()),
         DynamicValue::Json(v) => json_to_surreal(v),
         DynamicValue::Array(arr) => {
@@ -70,8 +73,9 @@ pub fn surreal_to_dynamic(value: &SurrealValue) -> Result<DynamicValue, BackendE
             match n {
                 surrealdb::sql::Number::Int(i) => Ok(DynamicValue::Integer(*i)),
                 surrealdb::sql::Number::Float(f) => Ok(DynamicValue::Float(*f)),
+                surrealdb::sql::Number::Decimal(d) => Ok(DynamicValue::Decimal(*d)),
                 _ => {
-                    // Decimal or future variants -- convert to float.
+                    // Future variants -- convert to float.
                     Ok(DynamicValue::Float((*n).as_float()))
                 }
             }
@@ -237,6 +241,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn decimal_round_trip_is_exact() {
+        let d = schema_forge_core::types::parse_decimal("19.99", 2).unwrap();
+        let sv = dynamic_to_surreal(&DynamicValue::Decimal(d));
+        assert!(matches!(
+            sv,
+            SurrealValue::Number(surrealdb::sql::Number::Decimal(_))
+        ));
+        let back = surreal_to_dynamic(&sv).unwrap();
+        assert_eq!(back, DynamicValue::Decimal(d));
+    }
+
     #[test]
     fn boolean_round_trip() {
         let dv = DynamicValue::Boolean(true);